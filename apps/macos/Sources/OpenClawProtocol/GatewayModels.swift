@@ -1147,6 +1147,7 @@ public struct SessionsPatchParams: Codable, Sendable {
     public let model: AnyCodable?
     public let spawnedby: AnyCodable?
     public let spawndepth: AnyCodable?
+    public let spawntools: AnyCodable?
     public let sendpolicy: AnyCodable?
     public let groupactivation: AnyCodable?
 
@@ -1165,6 +1166,7 @@ public struct SessionsPatchParams: Codable, Sendable {
         model: AnyCodable?,
         spawnedby: AnyCodable?,
         spawndepth: AnyCodable?,
+        spawntools: AnyCodable?,
         sendpolicy: AnyCodable?,
         groupactivation: AnyCodable?)
     {
@@ -1182,6 +1184,7 @@ public struct SessionsPatchParams: Codable, Sendable {
         self.model = model
         self.spawnedby = spawnedby
         self.spawndepth = spawndepth
+        self.spawntools = spawntools
         self.sendpolicy = sendpolicy
         self.groupactivation = groupactivation
     }
@@ -1201,6 +1204,7 @@ public struct SessionsPatchParams: Codable, Sendable {
         case model
         case spawnedby = "spawnedBy"
         case spawndepth = "spawnDepth"
+        case spawntools = "spawnTools"
         case sendpolicy = "sendPolicy"
         case groupactivation = "groupActivation"
     }
@@ -2300,6 +2304,32 @@ public struct ToolsCatalogResult: Codable, Sendable {
     }
 }
 
+public struct ToolsInvokeParams: Codable, Sendable {
+    public let name: String
+    public let args: [String: AnyCodable]?
+    public let action: String?
+    public let sessionkey: String?
+
+    public init(
+        name: String,
+        args: [String: AnyCodable]?,
+        action: String?,
+        sessionkey: String?)
+    {
+        self.name = name
+        self.args = args
+        self.action = action
+        self.sessionkey = sessionkey
+    }
+
+    private enum CodingKeys: String, CodingKey {
+        case name
+        case args
+        case action
+        case sessionkey = "sessionKey"
+    }
+}
+
 public struct SkillsBinsParams: Codable, Sendable {}
 
 public struct SkillsBinsResult: Codable, Sendable {
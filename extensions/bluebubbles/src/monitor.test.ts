@@ -107,6 +107,9 @@ function createMockRuntime(): PluginRuntime {
       formatNativeDependencyHint: vi.fn(
         () => "",
       ) as unknown as PluginRuntime["system"]["formatNativeDependencyHint"],
+      resolveActiveGoalsPrompt: vi.fn(
+        async () => "",
+      ) as unknown as PluginRuntime["system"]["resolveActiveGoalsPrompt"],
     },
     media: {
       loadWebMedia: vi.fn() as unknown as PluginRuntime["media"]["loadWebMedia"],
@@ -121,9 +124,13 @@ function createMockRuntime(): PluginRuntime {
       textToSpeechTelephony: vi.fn() as unknown as PluginRuntime["tts"]["textToSpeechTelephony"],
     },
     tools: {
+      createKbIngestTool: vi.fn() as unknown as PluginRuntime["tools"]["createKbIngestTool"],
+      createKbSearchTool: vi.fn() as unknown as PluginRuntime["tools"]["createKbSearchTool"],
       createMemoryGetTool: vi.fn() as unknown as PluginRuntime["tools"]["createMemoryGetTool"],
+      createMemoryListTool: vi.fn() as unknown as PluginRuntime["tools"]["createMemoryListTool"],
       createMemorySearchTool:
         vi.fn() as unknown as PluginRuntime["tools"]["createMemorySearchTool"],
+      createMemorySetTool: vi.fn() as unknown as PluginRuntime["tools"]["createMemorySetTool"],
       registerMemoryCli: vi.fn() as unknown as PluginRuntime["tools"]["registerMemoryCli"],
     },
     channel: {
@@ -232,6 +232,10 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
         unit: "1",
         description: "Run attempts",
       });
+      const providerRetryCounter = meter.createCounter("openclaw.provider.retry", {
+        unit: "1",
+        description: "Provider calls retried after retryable errors",
+      });
 
       if (logsEnabled) {
         const logExporter = new OTLPLogExporter({
@@ -601,6 +605,16 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
         runAttemptCounter.add(1, { "openclaw.attempt": evt.attempt });
       };
 
+      const recordProviderRetry = (
+        evt: Extract<DiagnosticEventPayload, { type: "provider.retry" }>,
+      ) => {
+        providerRetryCounter.add(1, {
+          "openclaw.provider": evt.provider ?? "unknown",
+          "openclaw.model": evt.model ?? "unknown",
+          "openclaw.attempt": evt.attempt,
+        });
+      };
+
       const recordHeartbeat = (
         evt: Extract<DiagnosticEventPayload, { type: "diagnostic.heartbeat" }>,
       ) => {
@@ -643,6 +657,9 @@ export function createDiagnosticsOtelService(): OpenClawPluginService {
             case "run.attempt":
               recordRunAttempt(evt);
               return;
+            case "provider.retry":
+              recordProviderRetry(evt);
+              return;
             case "diagnostic.heartbeat":
               recordHeartbeat(evt);
               return;
@@ -0,0 +1,17 @@
+import type { OpenClawPluginApi } from "openclaw/plugin-sdk";
+import { emptyPluginConfigSchema } from "openclaw/plugin-sdk";
+import { createEmailPlugin } from "./src/channel.js";
+import { setEmailRuntime } from "./src/runtime.js";
+
+const plugin = {
+  id: "email",
+  name: "Email",
+  description: "Email channel plugin for OpenClaw (IMAP inbox + SMTP replies)",
+  configSchema: emptyPluginConfigSchema(),
+  register(api: OpenClawPluginApi) {
+    setEmailRuntime(api.runtime);
+    api.registerChannel({ plugin: createEmailPlugin() });
+  },
+};
+
+export default plugin;
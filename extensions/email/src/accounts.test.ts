@@ -0,0 +1,67 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { listAccountIds, resolveAccount } from "./accounts.js";
+
+describe("email accounts", () => {
+  afterEach(() => {
+    delete process.env.EMAIL_PASSWORD;
+  });
+
+  it("lists the default account when IMAP is configured, plus named accounts", () => {
+    expect(listAccountIds({})).toEqual([]);
+    expect(
+      listAccountIds({
+        channels: { email: { imap: { host: "imap.test" }, accounts: { reports: {} } } },
+      }),
+    ).toEqual(["default", "reports"]);
+  });
+
+  it("applies defaults and reuses IMAP credentials for SMTP", () => {
+    process.env.EMAIL_PASSWORD = "env-secret";
+    const account = resolveAccount({
+      channels: {
+        email: {
+          imap: { host: "imap.test", user: "Bot@Example.com" },
+          smtp: { host: "smtp.test", port: 587 },
+          pollIntervalSeconds: 1,
+        },
+      },
+    });
+    expect(account.address).toBe("bot@example.com");
+    expect(account.imap).toEqual({
+      host: "imap.test",
+      port: 993,
+      secure: true,
+      user: "Bot@Example.com",
+      password: "env-secret",
+    });
+    expect(account.smtp).toEqual({
+      host: "smtp.test",
+      port: 587,
+      secure: false,
+      user: "Bot@Example.com",
+      password: "env-secret",
+    });
+    expect(account.pollIntervalMs).toBe(10_000);
+    expect(account.dmPolicy).toBe("allowlist");
+    expect(account.mailbox).toBe("INBOX");
+  });
+
+  it("merges named account overrides over the base config", () => {
+    process.env.EMAIL_PASSWORD = "env-secret";
+    const account = resolveAccount(
+      {
+        channels: {
+          email: {
+            imap: { host: "imap.test", user: "bot@example.com", password: "base" },
+            accounts: { reports: { address: "reports@example.com", imap: { user: "reports" } } },
+          },
+        },
+      },
+      "reports",
+    );
+    expect(account.address).toBe("reports@example.com");
+    expect(account.imap.host).toBe("imap.test");
+    expect(account.imap.user).toBe("reports");
+    expect(account.imap.password).toBe("base");
+  });
+});
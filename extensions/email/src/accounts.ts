@@ -0,0 +1,105 @@
+/**
+ * Account resolution: reads config from channels.email, merges per-account
+ * overrides, falls back to EMAIL_PASSWORD for the default account's password.
+ */
+
+import type { OpenClawConfig } from "openclaw/plugin-sdk";
+import { normalizeEmailAddress } from "./message.js";
+import type {
+  EmailChannelConfig,
+  EmailServerConfig,
+  ResolvedEmailAccount,
+  ResolvedEmailServer,
+} from "./types.js";
+
+const DEFAULT_POLL_INTERVAL_SECONDS = 60;
+const MIN_POLL_INTERVAL_SECONDS = 10;
+const DEFAULT_MEDIA_MAX_MB = 20;
+
+function getChannelConfig(cfg: OpenClawConfig): EmailChannelConfig | undefined {
+  return cfg.channels?.email as EmailChannelConfig | undefined;
+}
+
+function parseAllowFrom(raw: string | string[] | undefined): string[] {
+  if (!raw) return [];
+  const entries = Array.isArray(raw) ? raw : raw.split(",");
+  return entries.map((entry) => entry.trim().toLowerCase()).filter(Boolean);
+}
+
+function resolveServer(
+  raw: EmailServerConfig | undefined,
+  defaults: { port: number; user: string; password: string; host?: string },
+): ResolvedEmailServer {
+  const port = raw?.port ?? defaults.port;
+  return {
+    host: raw?.host?.trim() || defaults.host || "",
+    port,
+    // Ports 993/465 are implicit TLS; 143/587 upgrade via STARTTLS.
+    secure: raw?.secure ?? (port === 993 || port === 465),
+    user: raw?.user?.trim() || defaults.user,
+    password: raw?.password ?? defaults.password,
+  };
+}
+
+/**
+ * List all configured account IDs for this channel.
+ * Returns ["default"] if the base config has an IMAP host, plus any named accounts.
+ */
+export function listAccountIds(cfg: OpenClawConfig): string[] {
+  const channelCfg = getChannelConfig(cfg);
+  if (!channelCfg) return [];
+
+  const ids = new Set<string>();
+  if (channelCfg.imap?.host) {
+    ids.add("default");
+  }
+  for (const id of Object.keys(channelCfg.accounts ?? {})) {
+    ids.add(id);
+  }
+  return Array.from(ids);
+}
+
+/** Resolve a specific account by ID with full defaults applied. */
+export function resolveAccount(
+  cfg: OpenClawConfig,
+  accountId?: string | null,
+): ResolvedEmailAccount {
+  const channelCfg = getChannelConfig(cfg) ?? {};
+  const id = accountId || "default";
+  const accountOverride = channelCfg.accounts?.[id] ?? {};
+  const envPassword = id === "default" ? (process.env.EMAIL_PASSWORD ?? "") : "";
+
+  const imapRaw = { ...channelCfg.imap, ...accountOverride.imap };
+  const smtpRaw = { ...channelCfg.smtp, ...accountOverride.smtp };
+  const imap = resolveServer(imapRaw, { port: 993, user: "", password: envPassword });
+  const smtp = resolveServer(smtpRaw, {
+    port: 465,
+    user: imap.user,
+    password: imap.password,
+  });
+  const address = normalizeEmailAddress(
+    accountOverride.address ?? channelCfg.address ?? (imap.user.includes("@") ? imap.user : ""),
+  );
+  const pollSeconds = Math.max(
+    MIN_POLL_INTERVAL_SECONDS,
+    accountOverride.pollIntervalSeconds ??
+      channelCfg.pollIntervalSeconds ??
+      DEFAULT_POLL_INTERVAL_SECONDS,
+  );
+
+  return {
+    accountId: id,
+    enabled: accountOverride.enabled ?? channelCfg.enabled ?? true,
+    address,
+    fromName: accountOverride.fromName ?? channelCfg.fromName,
+    imap,
+    smtp,
+    mailbox: accountOverride.mailbox ?? channelCfg.mailbox ?? "INBOX",
+    pollIntervalMs: pollSeconds * 1000,
+    defaultSubject: accountOverride.defaultSubject ?? channelCfg.defaultSubject ?? "OpenClaw",
+    dmPolicy: accountOverride.dmPolicy ?? channelCfg.dmPolicy ?? "allowlist",
+    allowFrom: parseAllowFrom(accountOverride.allowFrom ?? channelCfg.allowFrom),
+    mediaMaxBytes:
+      (accountOverride.mediaMaxMb ?? channelCfg.mediaMaxMb ?? DEFAULT_MEDIA_MAX_MB) * 1024 * 1024,
+  };
+}
@@ -0,0 +1,460 @@
+/**
+ * Email channel plugin for OpenClaw.
+ *
+ * Polls an IMAP mailbox and replies over SMTP. Each email thread (by root Message-ID) is its
+ * own session, so parallel conversations with the same sender stay separate.
+ */
+
+import {
+  DEFAULT_ACCOUNT_ID,
+  buildChannelConfigSchema,
+  createReplyPrefixOptions,
+  formatPairingApproveHint,
+  PAIRING_APPROVED_MESSAGE,
+  setAccountEnabledInConfigSection,
+  type ChannelGatewayContext,
+  type ChannelOutboundContext,
+  type OpenClawConfig,
+} from "openclaw/plugin-sdk";
+import { z } from "zod";
+import { listAccountIds, resolveAccount } from "./accounts.js";
+import { startInboxPolling } from "./imap.js";
+import {
+  isSenderAllowed,
+  looksLikeEmailAddress,
+  normalizeEmailAddress,
+  replySubject,
+  threadKeyFor,
+} from "./message.js";
+import { getEmailRuntime } from "./runtime.js";
+import { sendEmail } from "./smtp.js";
+import type { InboundEmail, ResolvedEmailAccount } from "./types.js";
+
+const CHANNEL_ID = "email";
+const TEXT_CHUNK_LIMIT = 100_000;
+const EmailConfigSchema = buildChannelConfigSchema(z.object({}).passthrough());
+
+async function loadAttachments(
+  account: ResolvedEmailAccount,
+  mediaUrls: string[],
+  mediaLocalRoots?: readonly string[],
+) {
+  const rt = getEmailRuntime();
+  return await Promise.all(
+    mediaUrls.map(async (mediaUrl) => {
+      const loaded = await rt.media.loadWebMedia(mediaUrl, {
+        maxBytes: account.mediaMaxBytes,
+        optimizeImages: false,
+        localRoots: mediaLocalRoots,
+      });
+      return {
+        content: loaded.buffer,
+        contentType: loaded.contentType,
+        filename: loaded.fileName,
+      };
+    }),
+  );
+}
+
+/** Addresses approved through pairing; empty unless dmPolicy is "pairing". */
+async function readPairedSenders(account: ResolvedEmailAccount): Promise<string[]> {
+  if (account.dmPolicy !== "pairing") return [];
+  const rt = getEmailRuntime();
+  const stored = await rt.channel.pairing.readAllowFromStore(CHANNEL_ID).catch(() => []);
+  return stored.map((entry) => normalizeEmailAddress(entry));
+}
+
+async function sendPairingPrompt(params: {
+  account: ResolvedEmailAccount;
+  message: InboundEmail;
+  log?: { warn?: (msg: string) => void };
+}): Promise<void> {
+  const { account, message } = params;
+  const rt = getEmailRuntime();
+  const { code, created } = await rt.channel.pairing.upsertPairingRequest({
+    channel: CHANNEL_ID,
+    id: normalizeEmailAddress(message.from),
+    meta: { name: message.fromName || undefined },
+  });
+  if (!created) return;
+  try {
+    await sendEmail(account, {
+      to: message.from,
+      subject: replySubject(message.subject),
+      text: rt.channel.pairing.buildPairingReply({
+        channel: CHANNEL_ID,
+        idLine: `Your email address: ${normalizeEmailAddress(message.from)}`,
+        code,
+      }),
+      inReplyTo: message.messageId,
+      references: message.references,
+    });
+  } catch (err) {
+    params.log?.warn?.(`Email: pairing reply to ${message.from} failed: ${String(err)}`);
+  }
+}
+
+async function handleInboundEmail(params: {
+  account: ResolvedEmailAccount;
+  message: InboundEmail;
+  log?: { info?: (msg: string) => void; warn?: (msg: string) => void };
+}): Promise<void> {
+  const { account, message, log } = params;
+  const pairedFrom = await readPairedSenders(account);
+  if (!isSenderAllowed(account, message.from, pairedFrom)) {
+    if (account.dmPolicy === "pairing") {
+      await sendPairingPrompt({ account, message, log });
+    }
+    log?.info?.(`Email: drop message from ${message.from} (dmPolicy=${account.dmPolicy})`);
+    return;
+  }
+
+  const rt = getEmailRuntime();
+  const cfg = await rt.config.loadConfig();
+
+  const mediaPaths: string[] = [];
+  const mediaTypes: string[] = [];
+  for (const attachment of message.attachments) {
+    try {
+      const saved = await rt.channel.media.saveMediaBuffer(
+        attachment.content,
+        attachment.contentType,
+        "inbound",
+        account.mediaMaxBytes,
+        attachment.filename,
+      );
+      mediaPaths.push(saved.path);
+      mediaTypes.push(saved.contentType ?? attachment.contentType ?? "application/octet-stream");
+    } catch (err) {
+      log?.warn?.(`Email: failed to save attachment ${attachment.filename}: ${String(err)}`);
+    }
+  }
+  const subjectLine = message.subject ? `Subject: ${message.subject}\n\n` : "";
+  const rawBody = `${subjectLine}${message.text}`.trim();
+  if (!rawBody && mediaPaths.length === 0) return;
+
+  const route = rt.channel.routing.resolveAgentRoute({
+    cfg,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+    peer: { kind: "direct", id: message.from },
+  });
+  const threadKey = threadKeyFor(message.threadRootId);
+  const sessionKey = `${route.sessionKey}:thread:${threadKey}`;
+  const fromLabel = message.fromName ? `${message.fromName} <${message.from}>` : message.from;
+  const storePath = rt.channel.session.resolveStorePath(cfg.session?.store, {
+    agentId: route.agentId,
+  });
+  const body = rt.channel.reply.formatAgentEnvelope({
+    channel: "Email",
+    from: fromLabel,
+    timestamp: message.date?.getTime(),
+    previousTimestamp: rt.channel.session.readSessionUpdatedAt({ storePath, sessionKey }),
+    envelope: rt.channel.reply.resolveEnvelopeFormatOptions(cfg),
+    body: rawBody || "<media:attachment>",
+  });
+  const ctxPayload = rt.channel.reply.finalizeInboundContext({
+    Body: body,
+    BodyForAgent: rawBody,
+    RawBody: rawBody,
+    CommandBody: message.text,
+    From: `${CHANNEL_ID}:${message.from}`,
+    To: `${CHANNEL_ID}:${account.address}`,
+    SessionKey: sessionKey,
+    ParentSessionKey: route.sessionKey,
+    AccountId: route.accountId,
+    ChatType: "direct",
+    ConversationLabel: message.subject || fromLabel,
+    ThreadLabel: message.subject || undefined,
+    MessageThreadId: threadKey,
+    SenderName: message.fromName,
+    SenderId: message.from,
+    CommandAuthorized:
+      account.allowFrom.includes("*") ||
+      account.allowFrom.includes(message.from) ||
+      pairedFrom.includes(message.from),
+    Provider: CHANNEL_ID,
+    Surface: CHANNEL_ID,
+    MessageSid: message.messageId,
+    MediaPath: mediaPaths[0],
+    MediaType: mediaTypes[0],
+    MediaUrl: mediaPaths[0],
+    MediaPaths: mediaPaths.length > 0 ? mediaPaths : undefined,
+    MediaUrls: mediaPaths.length > 0 ? mediaPaths : undefined,
+    MediaTypes: mediaTypes.length > 0 ? mediaTypes : undefined,
+    OriginatingChannel: CHANNEL_ID,
+    OriginatingTo: `${CHANNEL_ID}:${message.from}`,
+  });
+  await rt.channel.session.recordInboundSession({
+    storePath,
+    sessionKey,
+    ctx: ctxPayload,
+    onRecordError: (err) => {
+      log?.warn?.(`Email: failed updating session meta: ${String(err)}`);
+    },
+  });
+
+  // Collect the whole reply and send it as one email, threaded under the inbound message.
+  const texts: string[] = [];
+  const mediaUrls: string[] = [];
+  const { onModelSelected, ...prefixOptions } = createReplyPrefixOptions({
+    cfg,
+    agentId: route.agentId,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+  });
+  await rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher({
+    ctx: ctxPayload,
+    cfg,
+    dispatcherOptions: {
+      ...prefixOptions,
+      deliver: async (payload: { text?: string; mediaUrls?: string[]; mediaUrl?: string }) => {
+        if (payload.text?.trim()) texts.push(payload.text.trim());
+        mediaUrls.push(...(payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : [])));
+      },
+      onError: (err: unknown) => {
+        log?.warn?.(`Email: reply to ${message.from} failed: ${String(err)}`);
+      },
+    },
+    replyOptions: { onModelSelected, disableBlockStreaming: true },
+  });
+  if (texts.length === 0 && mediaUrls.length === 0) return;
+
+  await sendEmail(account, {
+    to: message.from,
+    subject: replySubject(message.subject),
+    text: texts.join("\n\n"),
+    inReplyTo: message.messageId,
+    references: message.references,
+    attachments: await loadAttachments(account, mediaUrls),
+  });
+}
+
+export function createEmailPlugin() {
+  return {
+    id: CHANNEL_ID,
+
+    meta: {
+      id: CHANNEL_ID,
+      label: "Email",
+      selectionLabel: "Email (IMAP + SMTP)",
+      detailLabel: "Email (IMAP + SMTP)",
+      docsPath: "/channels/email",
+      blurb: "Poll an IMAP inbox and reply over SMTP",
+      order: 96,
+    },
+
+    capabilities: {
+      chatTypes: ["direct" as const],
+      media: true,
+      threads: true,
+      reactions: false,
+      edit: false,
+      unsend: false,
+      reply: true,
+      effects: false,
+      blockStreaming: false,
+    },
+
+    reload: { configPrefixes: [`channels.${CHANNEL_ID}`] },
+
+    configSchema: EmailConfigSchema,
+
+    pairing: {
+      idLabel: "emailAddress",
+      normalizeAllowEntry: (entry: string) => normalizeEmailAddress(entry),
+      notifyApproval: async ({ cfg, id }: { cfg: OpenClawConfig; id: string }) => {
+        const account = resolveAccount(cfg);
+        if (!account.smtp.host) return;
+        await sendEmail(account, {
+          to: normalizeEmailAddress(id),
+          subject: account.defaultSubject,
+          text: PAIRING_APPROVED_MESSAGE,
+        });
+      },
+    },
+
+    config: {
+      listAccountIds: (cfg: OpenClawConfig) => listAccountIds(cfg),
+
+      resolveAccount: (cfg: OpenClawConfig, accountId?: string | null) =>
+        resolveAccount(cfg, accountId),
+
+      defaultAccountId: (_cfg: OpenClawConfig) => DEFAULT_ACCOUNT_ID,
+
+      setAccountEnabled: ({
+        cfg,
+        accountId,
+        enabled,
+      }: {
+        cfg: OpenClawConfig;
+        accountId: string;
+        enabled: boolean;
+      }) => {
+        const channelConfig = cfg?.channels?.[CHANNEL_ID] ?? {};
+        if (accountId === DEFAULT_ACCOUNT_ID) {
+          return {
+            ...cfg,
+            channels: {
+              ...cfg.channels,
+              [CHANNEL_ID]: { ...channelConfig, enabled },
+            },
+          };
+        }
+        return setAccountEnabledInConfigSection({
+          cfg,
+          sectionKey: `channels.${CHANNEL_ID}`,
+          accountId,
+          enabled,
+        });
+      },
+    },
+
+    security: {
+      resolveDmPolicy: ({
+        cfg,
+        accountId,
+        account,
+      }: {
+        cfg: OpenClawConfig;
+        accountId?: string | null;
+        account: ResolvedEmailAccount;
+      }) => {
+        const resolvedAccountId = accountId ?? account.accountId ?? DEFAULT_ACCOUNT_ID;
+        const useAccountPath = Boolean(cfg?.channels?.email?.accounts?.[resolvedAccountId]);
+        const basePath = useAccountPath
+          ? `channels.email.accounts.${resolvedAccountId}.`
+          : "channels.email.";
+        return {
+          policy: account.dmPolicy,
+          allowFrom: account.allowFrom,
+          policyPath: `${basePath}dmPolicy`,
+          allowFromPath: basePath,
+          approveHint: formatPairingApproveHint(CHANNEL_ID),
+          normalizeEntry: (raw: string) => raw.trim().toLowerCase(),
+        };
+      },
+      collectWarnings: ({ account }: { account: ResolvedEmailAccount }) => {
+        const warnings: string[] = [];
+        if (!account.imap.host || !account.imap.user || !account.imap.password) {
+          warnings.push("- Email: imap.host/user/password is not configured. No mail is read.");
+        }
+        if (!account.smtp.host) {
+          warnings.push("- Email: smtp.host is not configured. The bot cannot send replies.");
+        }
+        if (!account.address) {
+          warnings.push(
+            "- Email: address is not configured and imap.user is not an email address.",
+          );
+        }
+        if (account.dmPolicy === "open") {
+          warnings.push(
+            '- Email: dmPolicy="open" lets anyone who emails the inbox talk to the agent. Consider "allowlist".',
+          );
+        }
+        return warnings;
+      },
+    },
+
+    messaging: {
+      normalizeTarget: (target: string) => normalizeEmailAddress(target) || undefined,
+      targetResolver: {
+        looksLikeId: (id: string) => Boolean(id?.trim()) && looksLikeEmailAddress(id),
+        hint: "<email address>",
+      },
+    },
+
+    directory: {
+      self: async () => null,
+      listPeers: async () => [],
+      listGroups: async () => [],
+    },
+
+    outbound: {
+      deliveryMode: "direct" as const,
+      textChunkLimit: TEXT_CHUNK_LIMIT,
+
+      sendText: async ({ cfg, to, text, accountId }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        const address = normalizeEmailAddress(to);
+        const result = await sendEmail(account, {
+          to: address,
+          subject: account.defaultSubject,
+          text,
+        });
+        return { channel: CHANNEL_ID, messageId: result.messageId, chatId: address };
+      },
+
+      sendMedia: async ({
+        cfg,
+        to,
+        text,
+        mediaUrl,
+        accountId,
+        mediaLocalRoots,
+      }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        const address = normalizeEmailAddress(to);
+        const result = await sendEmail(account, {
+          to: address,
+          subject: account.defaultSubject,
+          text: text ?? "",
+          attachments: mediaUrl
+            ? await loadAttachments(account, [mediaUrl], mediaLocalRoots)
+            : undefined,
+        });
+        return { channel: CHANNEL_ID, messageId: result.messageId, chatId: address };
+      },
+    },
+
+    gateway: {
+      startAccount: async (ctx: ChannelGatewayContext<ResolvedEmailAccount>) => {
+        const { cfg, accountId, log } = ctx;
+        const account = resolveAccount(cfg, accountId);
+
+        if (!account.enabled) {
+          log?.info?.(`Email account ${accountId} is disabled, skipping`);
+          return { stop: () => {} };
+        }
+
+        if (!account.imap.host || !account.imap.user || !account.smtp.host || !account.address) {
+          log?.warn?.(
+            `Email account ${accountId} not fully configured (missing imap/smtp host, user, or address)`,
+          );
+          return { stop: () => {} };
+        }
+
+        log?.info?.(
+          `Starting Email channel (account: ${accountId}, mailbox: ${account.mailbox}, every ${account.pollIntervalMs / 1000}s)`,
+        );
+        const poller = startInboxPolling({
+          account,
+          onMessage: (message) => handleInboundEmail({ account, message, log }),
+          log,
+        });
+
+        return {
+          stop: () => {
+            log?.info?.(`Stopping Email channel (account: ${accountId})`);
+            poller.stop();
+          },
+        };
+      },
+
+      stopAccount: async (ctx: ChannelGatewayContext<ResolvedEmailAccount>) => {
+        ctx.log?.info?.(`Email account ${ctx.accountId} stopped`);
+      },
+    },
+
+    agentPrompt: {
+      messageToolHints: () => [
+        "",
+        "### Email Formatting",
+        "Replies are sent as plain-text email in the same thread.",
+        "Write complete, self-contained messages: the reader may not see earlier replies.",
+        "Markdown is sent as-is; prefer simple lists and short paragraphs over tables.",
+        "Attach files (reports, images) as media; they are sent as email attachments.",
+      ],
+    },
+  };
+}
@@ -0,0 +1,133 @@
+/**
+ * IMAP polling: fetch unseen messages, parse them, and mark them seen once handed off.
+ */
+
+import { ImapFlow } from "imapflow";
+import { simpleParser } from "mailparser";
+import {
+  isAutomatedMessage,
+  normalizeEmailAddress,
+  resolveThreading,
+  stripQuotedReply,
+} from "./message.js";
+import type { InboundEmail, ResolvedEmailAccount } from "./types.js";
+
+function createImapClient(account: ResolvedEmailAccount): ImapFlow {
+  return new ImapFlow({
+    host: account.imap.host,
+    port: account.imap.port,
+    secure: account.imap.secure,
+    auth: { user: account.imap.user, pass: account.imap.password },
+    logger: false,
+  });
+}
+
+/** Parse a raw RFC 822 message. Returns null for automated mail and messages we sent. */
+export async function parseInboundEmail(
+  account: ResolvedEmailAccount,
+  uid: number,
+  source: Buffer,
+): Promise<InboundEmail | null> {
+  const parsed = await simpleParser(source);
+  const sender = parsed.from?.value?.[0];
+  const from = normalizeEmailAddress(sender?.address ?? "");
+  if (!from || from === account.address || isAutomatedMessage(parsed.headers)) {
+    return null;
+  }
+  const messageId = parsed.messageId?.trim() || `<uid-${uid}@${account.imap.host}>`;
+  const { threadRootId, references } = resolveThreading({
+    messageId,
+    inReplyTo: parsed.inReplyTo,
+    references: parsed.references,
+  });
+  return {
+    uid,
+    messageId,
+    from,
+    fromName: sender?.name?.trim() || undefined,
+    subject: parsed.subject?.trim() ?? "",
+    threadRootId,
+    references,
+    text: stripQuotedReply(parsed.text ?? ""),
+    date: parsed.date,
+    attachments: parsed.attachments
+      .filter((attachment) => attachment.contentDisposition !== "inline" || !attachment.cid)
+      .filter((attachment) => attachment.size <= account.mediaMaxBytes)
+      .map((attachment) => ({
+        content: attachment.content,
+        contentType: attachment.contentType,
+        filename: attachment.filename,
+      })),
+  };
+}
+
+/**
+ * Poll the mailbox once: hand every unseen message to onMessage, then flag it \Seen.
+ * Messages are flagged even when skipped (automated, own mail) so they are not re-read.
+ */
+export async function pollInboxOnce(params: {
+  account: ResolvedEmailAccount;
+  onMessage: (message: InboundEmail) => Promise<void>;
+  log?: { warn?: (msg: string) => void };
+}): Promise<number> {
+  const { account, onMessage, log } = params;
+  const client = createImapClient(account);
+  await client.connect();
+  let handled = 0;
+  try {
+    const lock = await client.getMailboxLock(account.mailbox);
+    try {
+      // Collect first: IMAP commands cannot be issued while a FETCH is streaming.
+      const fetched: Array<{ uid: number; source: Buffer }> = [];
+      for await (const msg of client.fetch({ seen: false }, { uid: true, source: true })) {
+        if (msg.source) {
+          fetched.push({ uid: msg.uid, source: msg.source });
+        }
+      }
+      for (const { uid, source } of fetched) {
+        try {
+          const message = await parseInboundEmail(account, uid, source);
+          if (message) {
+            await onMessage(message);
+            handled += 1;
+          }
+        } catch (err) {
+          log?.warn?.(`Email: failed to process message uid=${uid}: ${String(err)}`);
+        }
+        await client.messageFlagsAdd(String(uid), ["\\Seen"], { uid: true });
+      }
+    } finally {
+      lock.release();
+    }
+  } finally {
+    await client.logout().catch(() => undefined);
+  }
+  return handled;
+}
+
+/** Poll until aborted; connection errors are logged and retried on the next tick. */
+export function startInboxPolling(params: {
+  account: ResolvedEmailAccount;
+  onMessage: (message: InboundEmail) => Promise<void>;
+  log?: { info?: (msg: string) => void; warn?: (msg: string) => void };
+}): { stop: () => void } {
+  let stopped = false;
+  let timer: ReturnType<typeof setTimeout> | undefined;
+  const tick = async () => {
+    try {
+      await pollInboxOnce(params);
+    } catch (err) {
+      params.log?.warn?.(`Email: IMAP poll failed for ${params.account.imap.host}: ${String(err)}`);
+    }
+    if (!stopped) {
+      timer = setTimeout(() => void tick(), params.account.pollIntervalMs);
+    }
+  };
+  void tick();
+  return {
+    stop: () => {
+      stopped = true;
+      if (timer) clearTimeout(timer);
+    },
+  };
+}
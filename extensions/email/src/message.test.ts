@@ -0,0 +1,104 @@
+import { describe, expect, it } from "vitest";
+import { resolveAccount } from "./accounts.js";
+import {
+  isAutomatedMessage,
+  isSenderAllowed,
+  normalizeEmailAddress,
+  replySubject,
+  resolveThreading,
+  stripQuotedReply,
+  threadKeyFor,
+} from "./message.js";
+import type { EmailDmPolicy } from "./types.js";
+
+describe("normalizeEmailAddress", () => {
+  it("strips display names, prefixes, and case", () => {
+    expect(normalizeEmailAddress('"Ada L" <Ada@Example.com>')).toBe("ada@example.com");
+    expect(normalizeEmailAddress("email:ops@example.com")).toBe("ops@example.com");
+    expect(normalizeEmailAddress("mailto:ops@example.com")).toBe("ops@example.com");
+  });
+});
+
+describe("isSenderAllowed", () => {
+  const account = (allowFrom: string[], dmPolicy: EmailDmPolicy) =>
+    resolveAccount({ channels: { email: { imap: { host: "imap.test" }, allowFrom, dmPolicy } } });
+
+  it("matches full addresses and @domain entries", () => {
+    const allowlist = account(["ada@example.com", "@ops.example.com"], "allowlist");
+    expect(isSenderAllowed(allowlist, "Ada <ADA@example.com>")).toBe(true);
+    expect(isSenderAllowed(allowlist, "oncall@ops.example.com")).toBe(true);
+    expect(isSenderAllowed(allowlist, "eve@example.org")).toBe(false);
+  });
+
+  it("honors open and disabled policies", () => {
+    expect(isSenderAllowed(account([], "open"), "anyone@example.org")).toBe(true);
+    expect(isSenderAllowed(account(["*"], "disabled"), "ada@example.com")).toBe(false);
+  });
+
+  it("adds paired addresses only under the pairing policy", () => {
+    const paired = ["grace@example.org"];
+    const pairing = account(["@ops.example.com"], "pairing");
+    expect(isSenderAllowed(pairing, "Grace <grace@example.org>", paired)).toBe(true);
+    expect(isSenderAllowed(pairing, "oncall@ops.example.com", paired)).toBe(true);
+    expect(isSenderAllowed(pairing, "eve@example.org", paired)).toBe(false);
+    expect(isSenderAllowed(account([], "allowlist"), "grace@example.org", paired)).toBe(false);
+  });
+});
+
+describe("resolveThreading", () => {
+  it("uses the first References entry as the thread root", () => {
+    const result = resolveThreading({
+      messageId: "<c@x>",
+      inReplyTo: "<b@x>",
+      references: "<a@x> <b@x>",
+    });
+    expect(result).toEqual({ threadRootId: "<a@x>", references: ["<a@x>", "<b@x>", "<c@x>"] });
+  });
+
+  it("falls back to In-Reply-To, then the message itself", () => {
+    expect(resolveThreading({ messageId: "<b@x>", inReplyTo: "<a@x>" }).threadRootId).toBe(
+      "<a@x>",
+    );
+    expect(resolveThreading({ messageId: "<a@x>" })).toEqual({
+      threadRootId: "<a@x>",
+      references: ["<a@x>"],
+    });
+  });
+
+  it("derives a stable, case-insensitive thread key", () => {
+    expect(threadKeyFor("<A@X>")).toBe(threadKeyFor("<a@x>"));
+    expect(threadKeyFor("<a@x>")).toMatch(/^[0-9a-f]{16}$/);
+  });
+});
+
+describe("stripQuotedReply", () => {
+  it("drops attribution lines, quotes, and signatures", () => {
+    const text = [
+      "Sounds good, ship it.",
+      "",
+      "On Mon, Oct 12, 2026 at 9:00 AM OpenClaw <bot@example.com> wrote:",
+      "> Ready to deploy?",
+    ].join("\n");
+    expect(stripQuotedReply(text)).toBe("Sounds good, ship it.");
+    expect(stripQuotedReply("Thanks\n-- \nAda\nCEO")).toBe("Thanks");
+    expect(stripQuotedReply("Hi\r\n> quoted")).toBe("Hi");
+  });
+});
+
+describe("replySubject", () => {
+  it("prefixes Re: once", () => {
+    expect(replySubject("Weekly report")).toBe("Re: Weekly report");
+    expect(replySubject("RE: Weekly report")).toBe("RE: Weekly report");
+    expect(replySubject("")).toBe("Re: (no subject)");
+  });
+});
+
+describe("isAutomatedMessage", () => {
+  it("flags auto-replies and list mail", () => {
+    expect(isAutomatedMessage(new Map([["auto-submitted", "auto-replied"]]))).toBe(true);
+    expect(isAutomatedMessage(new Map([["auto-submitted", "no"]]))).toBe(false);
+    expect(isAutomatedMessage(new Map([["precedence", "bulk"]]))).toBe(true);
+    expect(isAutomatedMessage(new Map([["list-id", "<ops.example.com>"]]))).toBe(true);
+    expect(isAutomatedMessage(new Map())).toBe(false);
+  });
+});
@@ -0,0 +1,107 @@
+/**
+ * Pure helpers for email addressing, threading, and reply text extraction.
+ */
+
+import { createHash } from "node:crypto";
+import type { ResolvedEmailAccount } from "./types.js";
+
+export function normalizeEmailAddress(raw: string): string {
+  const trimmed = raw
+    .trim()
+    .replace(/^email:/i, "")
+    .replace(/^mailto:/i, "");
+  const angle = trimmed.match(/<([^>]+)>/);
+  return (angle?.[1] ?? trimmed).trim().toLowerCase();
+}
+
+export function looksLikeEmailAddress(raw: string): boolean {
+  return /^[^\s@<>]+@[^\s@<>]+\.[^\s@<>]+$/.test(normalizeEmailAddress(raw));
+}
+
+/**
+ * Allowlist entries are full addresses, `@domain` suffixes, or `"*"`. `pairedFrom` holds
+ * addresses approved through pairing; they only count under dmPolicy "pairing".
+ */
+export function isSenderAllowed(
+  account: ResolvedEmailAccount,
+  from: string,
+  pairedFrom: string[] = [],
+): boolean {
+  if (account.dmPolicy === "open") return true;
+  if (account.dmPolicy === "disabled") return false;
+  const address = normalizeEmailAddress(from);
+  const allowFrom =
+    account.dmPolicy === "pairing" ? [...account.allowFrom, ...pairedFrom] : account.allowFrom;
+  return allowFrom.some(
+    (entry) =>
+      entry === "*" ||
+      entry === address ||
+      (entry.startsWith("@") && address.endsWith(entry)),
+  );
+}
+
+function splitMessageIds(raw: string | string[] | undefined): string[] {
+  const values = Array.isArray(raw) ? raw : raw ? [raw] : [];
+  return values.flatMap((value) => value.match(/<[^<>\s]+>/g) ?? []);
+}
+
+/**
+ * Resolve the thread root and the References chain for a reply. The first References entry is
+ * the thread root per RFC 5322; clients that only send In-Reply-To fall back to that.
+ */
+export function resolveThreading(params: {
+  messageId: string;
+  inReplyTo?: string;
+  references?: string | string[];
+}): { threadRootId: string; references: string[] } {
+  const references = splitMessageIds(params.references);
+  const inReplyTo = splitMessageIds(params.inReplyTo)[0];
+  if (inReplyTo && !references.includes(inReplyTo)) {
+    references.push(inReplyTo);
+  }
+  const threadRootId = references[0] ?? params.messageId;
+  return { threadRootId, references: [...references, params.messageId] };
+}
+
+/** Short stable id for a thread root, used as the session thread suffix. */
+export function threadKeyFor(threadRootId: string): string {
+  return createHash("sha256").update(threadRootId.toLowerCase()).digest("hex").slice(0, 16);
+}
+
+export function replySubject(subject: string): string {
+  const trimmed = subject.trim();
+  return /^re:/i.test(trimmed) ? trimmed : `Re: ${trimmed || "(no subject)"}`;
+}
+
+const QUOTE_HEADER_RE =
+  /^(On .+ wrote:|Am .+ schrieb .+:|Le .+ a écrit :|-{2,} ?Original Message ?-{2,}|From: .+)$/i;
+
+/**
+ * Drop quoted history and signatures from a plain-text reply so the agent only sees the new
+ * text. Stops at the first attribution line ("On … wrote:"), quote block, or "-- " signature.
+ */
+export function stripQuotedReply(text: string): string {
+  const lines = text.replace(/\r\n/g, "\n").split("\n");
+  const kept: string[] = [];
+  for (const line of lines) {
+    const trimmed = line.trim();
+    if (line === "-- " || trimmed.startsWith(">") || QUOTE_HEADER_RE.test(trimmed)) {
+      break;
+    }
+    kept.push(line);
+  }
+  return kept.join("\n").trim();
+}
+
+/** True for auto-replies, bounces, and list traffic that must never get an agent reply. */
+export function isAutomatedMessage(headers: Map<string, unknown>): boolean {
+  const autoSubmitted = String(headers.get("auto-submitted") ?? "")
+    .trim()
+    .toLowerCase();
+  if (autoSubmitted && autoSubmitted !== "no") return true;
+  const precedence = String(headers.get("precedence") ?? "")
+    .trim()
+    .toLowerCase();
+  if (["bulk", "junk", "list", "auto_reply"].includes(precedence)) return true;
+  return headers.has("list-id") || headers.has("x-autoreply") || headers.has("x-autorespond");
+}
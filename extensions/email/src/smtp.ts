@@ -0,0 +1,69 @@
+/**
+ * SMTP delivery via nodemailer. Replies carry In-Reply-To/References so clients keep the thread.
+ */
+
+import nodemailer from "nodemailer";
+import type { ResolvedEmailAccount } from "./types.js";
+
+export type OutboundEmail = {
+  to: string;
+  subject: string;
+  text: string;
+  inReplyTo?: string;
+  references?: string[];
+  attachments?: Array<{ content: Buffer; contentType?: string; filename?: string }>;
+};
+
+const transports = new Map<string, nodemailer.Transporter>();
+
+function getTransport(account: ResolvedEmailAccount): nodemailer.Transporter {
+  let transport = transports.get(account.accountId);
+  if (!transport) {
+    transport = nodemailer.createTransport({
+      host: account.smtp.host,
+      port: account.smtp.port,
+      secure: account.smtp.secure,
+      auth: { user: account.smtp.user, pass: account.smtp.password },
+    });
+    transports.set(account.accountId, transport);
+  }
+  return transport;
+}
+
+export function formatFromHeader(account: ResolvedEmailAccount): string {
+  if (!account.fromName) return account.address;
+  return `"${account.fromName.replace(/["\\]/g, "")}" <${account.address}>`;
+}
+
+export async function sendEmail(
+  account: ResolvedEmailAccount,
+  message: OutboundEmail,
+): Promise<{ messageId: string }> {
+  const info = await getTransport(account).sendMail({
+    from: formatFromHeader(account),
+    to: message.to,
+    subject: message.subject,
+    text: message.text,
+    ...(message.inReplyTo ? { inReplyTo: message.inReplyTo } : {}),
+    ...(message.references?.length ? { references: message.references } : {}),
+    ...(message.attachments?.length
+      ? {
+          attachments: message.attachments.map((attachment) => ({
+            content: attachment.content,
+            contentType: attachment.contentType,
+            filename: attachment.filename,
+          })),
+        }
+      : {}),
+    // RFC 3834: lets well-behaved auto-responders skip our mail and avoid reply loops.
+    headers: { "Auto-Submitted": message.inReplyTo ? "auto-replied" : "auto-generated" },
+  });
+  return { messageId: info.messageId ?? `email-${Date.now()}` };
+}
+
+export function resetEmailTransports(): void {
+  for (const transport of transports.values()) {
+    transport.close();
+  }
+  transports.clear();
+}
@@ -0,0 +1,82 @@
+/**
+ * Type definitions for the Email channel plugin.
+ */
+
+export type EmailDmPolicy = "open" | "allowlist" | "pairing" | "disabled";
+
+export interface EmailServerConfig {
+  host?: string;
+  port?: number;
+  /** Implicit TLS (993 for IMAP, 465 for SMTP). When false, STARTTLS is used if offered. */
+  secure?: boolean;
+  user?: string;
+  password?: string;
+}
+
+/** Raw per-account config (overrides base config) */
+export interface EmailAccountRaw {
+  enabled?: boolean;
+  /** Sender address for outgoing mail (default: imap.user). */
+  address?: string;
+  /** Display name for outgoing mail. */
+  fromName?: string;
+  imap?: EmailServerConfig;
+  /** SMTP settings; user/password default to the IMAP credentials. */
+  smtp?: EmailServerConfig;
+  /** Mailbox to poll (default: INBOX). */
+  mailbox?: string;
+  /** Poll interval in seconds (default: 60). */
+  pollIntervalSeconds?: number;
+  /** Subject for new (non-reply) outbound mail. */
+  defaultSubject?: string;
+  dmPolicy?: EmailDmPolicy;
+  /** Sender addresses or `@domain` entries; `"*"` allows all. */
+  allowFrom?: string | string[];
+  /** Max attachment size in MB for inbound and outbound mail (default: 20). */
+  mediaMaxMb?: number;
+}
+
+/** Raw channel config from openclaw.json channels.email */
+export interface EmailChannelConfig extends EmailAccountRaw {
+  accounts?: Record<string, EmailAccountRaw>;
+}
+
+export interface ResolvedEmailServer {
+  host: string;
+  port: number;
+  secure: boolean;
+  user: string;
+  password: string;
+}
+
+/** Fully resolved account config with defaults applied */
+export interface ResolvedEmailAccount {
+  accountId: string;
+  enabled: boolean;
+  address: string;
+  fromName?: string;
+  imap: ResolvedEmailServer;
+  smtp: ResolvedEmailServer;
+  mailbox: string;
+  pollIntervalMs: number;
+  defaultSubject: string;
+  dmPolicy: EmailDmPolicy;
+  allowFrom: string[];
+  mediaMaxBytes: number;
+}
+
+/** Inbound email reduced to what the channel needs. */
+export interface InboundEmail {
+  uid: number;
+  messageId: string;
+  from: string;
+  fromName?: string;
+  subject: string;
+  /** Root Message-ID of the thread (first References entry, else this message). */
+  threadRootId: string;
+  /** References chain to carry into replies (ends with this message). */
+  references: string[];
+  text: string;
+  date?: Date;
+  attachments: Array<{ content: Buffer; contentType?: string; filename?: string }>;
+}
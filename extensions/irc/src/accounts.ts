@@ -139,6 +139,7 @@ function resolveNickServConfig(accountId: string, nickserv?: IrcNickServConfig):
   const merged: IrcNickServConfig = {
     ...base,
     service: base.service?.trim() || undefined,
+    account: base.account?.trim() || undefined,
     passwordFile: passwordFile || undefined,
     password: resolvedPassword || undefined,
     registerEmail: base.registerEmail?.trim() || envRegisterEmail || undefined,
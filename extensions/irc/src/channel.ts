@@ -162,6 +162,11 @@ export const ircPlugin: ChannelPlugin<ResolvedIrcAccount, IrcProbe> = {
           );
         }
       }
+      if (account.config.nickserv?.sasl && !account.config.nickserv.password?.trim()) {
+        warnings.push(
+          "- IRC SASL login is enabled but no NickServ password is resolved; the connection will fail. Set channels.irc.nickserv.password, channels.irc.nickserv.passwordFile, or IRC_NICKSERV_PASSWORD.",
+        );
+      }
       return warnings;
     },
   },
@@ -1,5 +1,9 @@
 import { describe, expect, it } from "vitest";
-import { buildIrcNickServCommands } from "./client.js";
+import {
+  buildIrcNickServCommands,
+  buildIrcSaslPlainCommands,
+  resolveIrcSaslCredentials,
+} from "./client.js";
 
 describe("irc client nickserv", () => {
   it("builds IDENTIFY command when password is set", () => {
@@ -41,3 +45,35 @@ describe("irc client nickserv", () => {
     ).toEqual(["PRIVMSG NickServ :IDENTIFY secret JOIN #bad"]);
   });
 });
+
+describe("irc client sasl", () => {
+  it("skips IDENTIFY when SASL is enabled", () => {
+    expect(buildIrcNickServCommands({ password: "secret", sasl: true })).toEqual([]);
+  });
+
+  it("resolves SASL credentials from the NickServ settings", () => {
+    expect(resolveIrcSaslCredentials("openclaw", { password: "secret" })).toBeNull();
+    expect(resolveIrcSaslCredentials("openclaw", { password: "secret", sasl: true })).toEqual({
+      account: "openclaw",
+      password: "secret",
+    });
+    expect(
+      resolveIrcSaslCredentials("openclaw_", { password: "secret", sasl: true, account: "ops" }),
+    ).toEqual({ account: "ops", password: "secret" });
+    expect(() => resolveIrcSaslCredentials("openclaw", { sasl: true })).toThrow(/password/);
+  });
+
+  it("encodes the PLAIN payload", () => {
+    const payload = Buffer.from("openclaw\0openclaw\0secret").toString("base64");
+    expect(buildIrcSaslPlainCommands("openclaw", "secret")).toEqual([`AUTHENTICATE ${payload}`]);
+  });
+
+  it("splits long payloads into 400-byte chunks", () => {
+    // 300 raw bytes encode to exactly 400 base64 chars, so a "+" terminator follows.
+    const password = "x".repeat(300 - 2 * "bot".length - 2);
+    const commands = buildIrcSaslPlainCommands("bot", password);
+    expect(commands).toHaveLength(2);
+    expect(commands[0]).toHaveLength("AUTHENTICATE ".length + 400);
+    expect(commands[1]).toBe("AUTHENTICATE +");
+  });
+});
@@ -9,6 +9,9 @@ import {
 
 const IRC_ERROR_CODES = new Set(["432", "464", "465"]);
 const IRC_NICK_COLLISION_CODES = new Set(["433", "436"]);
+// ERR_NICKLOCKED, ERR_SASLFAIL, ERR_SASLTOOLONG, ERR_SASLABORTED
+const IRC_SASL_FAILURE_CODES = new Set(["902", "904", "905", "906"]);
+const SASL_CHUNK_SIZE = 400;
 
 export type IrcPrivmsgEvent = {
   senderNick: string;
@@ -44,6 +47,8 @@ export type IrcNickServOptions = {
   password?: string;
   register?: boolean;
   registerEmail?: string;
+  sasl?: boolean;
+  account?: string;
 };
 
 export type IrcClient = {
@@ -93,8 +98,40 @@ function buildFallbackNick(nick: string): string {
   return `${base}${suffix}`;
 }
 
+/**
+ * SASL PLAIN credentials when `nickserv.sasl` is on, or null. Uses the services account
+ * (default: nick) and the NickServ password.
+ */
+export function resolveIrcSaslCredentials(
+  nick: string,
+  options?: IrcNickServOptions,
+): { account: string; password: string } | null {
+  if (!options?.sasl || options.enabled === false) {
+    return null;
+  }
+  const password = options.password?.trim();
+  if (!password) {
+    throw new Error("IRC SASL login requires a NickServ password");
+  }
+  return { account: options.account?.trim() || nick.trim(), password };
+}
+
+/** AUTHENTICATE lines carrying a base64 SASL PLAIN payload, split into 400-byte chunks. */
+export function buildIrcSaslPlainCommands(account: string, password: string): string[] {
+  const payload = Buffer.from(`${account}\0${account}\0${password}`, "utf8").toString("base64");
+  const commands: string[] = [];
+  for (let i = 0; i < payload.length; i += SASL_CHUNK_SIZE) {
+    commands.push(`AUTHENTICATE ${payload.slice(i, i + SASL_CHUNK_SIZE)}`);
+  }
+  // An empty or exactly chunk-sized final piece is terminated with "+".
+  if (payload.length % SASL_CHUNK_SIZE === 0) {
+    commands.push("AUTHENTICATE +");
+  }
+  return commands;
+}
+
 export function buildIrcNickServCommands(options?: IrcNickServOptions): string[] {
-  if (!options || options.enabled === false) {
+  if (!options || options.enabled === false || options.sasl) {
     return [];
   }
   const password = sanitizeIrcOutboundText(options.password ?? "");
@@ -126,6 +163,7 @@ export async function connectIrcClient(options: IrcClientOptions): Promise<IrcCl
   }
 
   const desiredNick = options.nick.trim();
+  const saslCredentials = resolveIrcSaslCredentials(desiredNick, options.nickserv);
   let currentNick = desiredNick;
   let ready = false;
   let closed = false;
@@ -300,6 +338,44 @@ export async function connectIrcClient(options: IrcClientOptions): Promise<IrcCl
         continue;
       }
 
+      if (saslCredentials && !ready && line.command === "CAP") {
+        const subcommand = (line.params[1] ?? "").toUpperCase();
+        const caps = (line.trailing ?? line.params[2] ?? "").toLowerCase().split(/\s+/);
+        if (subcommand === "ACK" && caps.includes("sasl")) {
+          sendRaw("AUTHENTICATE PLAIN");
+        } else if (subcommand === "NAK") {
+          fail(new Error("IRC SASL login failed: server does not support SASL"));
+          close();
+          return;
+        }
+        continue;
+      }
+
+      if (saslCredentials && !ready && line.command === "AUTHENTICATE") {
+        if ((line.params[0] ?? line.trailing) === "+") {
+          for (const command of buildIrcSaslPlainCommands(
+            saslCredentials.account,
+            saslCredentials.password,
+          )) {
+            sendRaw(command);
+          }
+        }
+        continue;
+      }
+
+      if (saslCredentials && !ready && line.command === "903") {
+        sendRaw("CAP END");
+        continue;
+      }
+
+      if (saslCredentials && !ready && IRC_SASL_FAILURE_CODES.has(line.command)) {
+        const detail =
+          line.trailing != null ? line.trailing : line.params.join(" ") || "authentication failed";
+        fail(new Error(`IRC SASL login failed (${line.command}): ${detail}`));
+        close();
+        return;
+      }
+
       if (!ready && IRC_NICK_COLLISION_CODES.has(line.command)) {
         if (tryRecoverNickCollision()) {
           continue;
@@ -388,6 +464,10 @@ export async function connectIrcClient(options: IrcClientOptions): Promise<IrcCl
 
   socket.once("connect", () => {
     try {
+      if (saslCredentials) {
+        // Registration pauses until CAP END, which follows a successful SASL exchange.
+        sendRaw("CAP REQ :sasl");
+      }
       if (options.password && options.password.trim()) {
         sendRaw(`PASS ${options.password.trim()}`);
       }
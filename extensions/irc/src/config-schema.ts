@@ -29,6 +29,8 @@ const IrcNickServSchema = z
     passwordFile: z.string().optional(),
     register: z.boolean().optional(),
     registerEmail: z.string().optional(),
+    sasl: z.boolean().optional(),
+    account: z.string().optional(),
   })
   .strict()
   .superRefine((value, ctx) => {
@@ -24,6 +24,8 @@ export function buildIrcConnectOptions(
       password: account.config.nickserv?.password,
       register: account.config.nickserv?.register,
       registerEmail: account.config.nickserv?.registerEmail,
+      sasl: account.config.nickserv?.sasl,
+      account: account.config.nickserv?.account,
     },
     ...overrides,
   };
@@ -27,6 +27,10 @@ export type IrcNickServConfig = {
   passwordFile?: string;
   register?: boolean;
   registerEmail?: string;
+  /** Authenticate with SASL PLAIN during connect instead of IDENTIFY after connect. */
+  sasl?: boolean;
+  /** Services account name for SASL (default: nick). */
+  account?: string;
 };
 
 export type IrcAccountConfig = {
@@ -74,27 +74,43 @@ describe("llm-task tool (json-only)", () => {
     expect((res as any).details.json).toEqual({ foo: "bar" });
   });
 
-  it("throws on invalid json", async () => {
+  it("throws on invalid json after one repair attempt", async () => {
     // oxlint-disable-next-line typescript/no-explicit-any
-    (runEmbeddedPiAgent as any).mockResolvedValueOnce({
-      meta: {},
-      payloads: [{ text: "not-json" }],
-    });
+    (runEmbeddedPiAgent as any)
+      .mockResolvedValueOnce({ meta: {}, payloads: [{ text: "not-json" }] })
+      .mockResolvedValueOnce({ meta: {}, payloads: [{ text: "still not json" }] });
     const tool = createLlmTaskTool(fakeApi());
     await expect(tool.execute("id", { prompt: "x" })).rejects.toThrow(/invalid json/i);
+    expect(runEmbeddedPiAgent).toHaveBeenCalledTimes(2);
   });
 
-  it("throws on schema mismatch", async () => {
+  it("throws on schema mismatch after one repair attempt", async () => {
     // oxlint-disable-next-line typescript/no-explicit-any
-    (runEmbeddedPiAgent as any).mockResolvedValueOnce({
-      meta: {},
-      payloads: [{ text: JSON.stringify({ foo: 1 }) }],
-    });
+    (runEmbeddedPiAgent as any)
+      .mockResolvedValueOnce({ meta: {}, payloads: [{ text: JSON.stringify({ foo: 1 }) }] })
+      .mockResolvedValueOnce({ meta: {}, payloads: [{ text: JSON.stringify({ foo: 2 }) }] });
     const tool = createLlmTaskTool(fakeApi());
     const schema = { type: "object", properties: { foo: { type: "string" } }, required: ["foo"] };
     await expect(tool.execute("id", { prompt: "x", schema })).rejects.toThrow(/match schema/i);
   });
 
+  it("repairs a schema mismatch in the same session", async () => {
+    // oxlint-disable-next-line typescript/no-explicit-any
+    (runEmbeddedPiAgent as any)
+      .mockResolvedValueOnce({ meta: {}, payloads: [{ text: JSON.stringify({ foo: 1 }) }] })
+      .mockResolvedValueOnce({ meta: {}, payloads: [{ text: JSON.stringify({ foo: "1" }) }] });
+    const tool = createLlmTaskTool(fakeApi());
+    const schema = { type: "object", properties: { foo: { type: "string" } }, required: ["foo"] };
+    const res = await tool.execute("id", { prompt: "x", schema });
+    // oxlint-disable-next-line typescript/no-explicit-any
+    expect((res as any).details.json).toEqual({ foo: "1" });
+    // oxlint-disable-next-line typescript/no-explicit-any
+    const calls = (runEmbeddedPiAgent as any).mock.calls.map((call: any[]) => call[0]);
+    expect(calls[1].sessionFile).toBe(calls[0].sessionFile);
+    expect(calls[1].prompt).toContain("did not match schema");
+    expect(calls[0].streamParams.responseFormat).toEqual({ name: "llm_task", schema });
+  });
+
   it("passes provider/model overrides to embedded runner", async () => {
     // oxlint-disable-next-line typescript/no-explicit-any
     (runEmbeddedPiAgent as any).mockResolvedValueOnce({
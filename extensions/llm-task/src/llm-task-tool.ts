@@ -2,11 +2,11 @@ import fs from "node:fs/promises";
 import os from "node:os";
 import path from "node:path";
 import { Type } from "@sinclair/typebox";
-import Ajv from "ajv";
 // NOTE: This extension is intended to be bundled with OpenClaw.
 // When running from source (tests/dev), OpenClaw internals live under src/.
 // When running from a built install, internals live under dist/ (no src/ tree).
 // So we resolve internal imports dynamically with src-first, dist-fallback.
+import { runStructuredOutput } from "../../../src/agents/structured-output.js";
 import type { OpenClawPluginApi } from "../../../src/plugins/types.js";
 
 type RunEmbeddedPiAgentFn = (params: Record<string, unknown>) => Promise<unknown>;
@@ -32,15 +32,6 @@ async function loadRunEmbeddedPiAgent(): Promise<RunEmbeddedPiAgentFn> {
   return mod.runEmbeddedPiAgent as RunEmbeddedPiAgentFn;
 }
 
-function stripCodeFences(s: string): string {
-  const trimmed = s.trim();
-  const m = trimmed.match(/^```(?:json)?\s*([\s\S]*?)\s*```$/i);
-  if (m) {
-    return (m[1] ?? "").trim();
-  }
-  return trimmed;
-}
-
 function collectText(payloads: Array<{ text?: string; isError?: boolean }> | undefined): string {
   const texts = (payloads ?? [])
     .filter((p) => !p.isError && typeof p.text === "string")
@@ -149,6 +140,13 @@ export function createLlmTaskTool(api: OpenClawPluginApi) {
           : undefined) ||
         30_000;
 
+      // oxlint-disable-next-line typescript/no-explicit-any
+      const rawSchema = (params as any).schema as unknown;
+      const schema =
+        rawSchema && typeof rawSchema === "object" && !Array.isArray(rawSchema)
+          ? (rawSchema as Record<string, unknown>)
+          : undefined;
+
       const streamParams = {
         temperature: typeof params.temperature === "number" ? params.temperature : undefined,
         maxTokens:
@@ -157,6 +155,8 @@ export function createLlmTaskTool(api: OpenClawPluginApi) {
             : typeof pluginCfg.maxTokens === "number"
               ? pluginCfg.maxTokens
               : undefined,
+        // Native structured outputs where the provider supports them.
+        responseFormat: schema ? { name: "llm_task", schema } : undefined,
       };
 
       // oxlint-disable-next-line typescript/no-explicit-any
@@ -186,54 +186,41 @@ export function createLlmTaskTool(api: OpenClawPluginApi) {
 
         const runEmbeddedPiAgent = await loadRunEmbeddedPiAgent();
 
-        const result = await runEmbeddedPiAgent({
-          sessionId,
-          sessionFile,
-          workspaceDir: api.config?.agents?.defaults?.workspace ?? process.cwd(),
-          config: api.config,
+        // Invalid JSON or a schema mismatch gets one repair turn in the same session.
+        const result = await runStructuredOutput({
           prompt: fullPrompt,
-          timeoutMs,
-          runId: `llm-task-${Date.now()}`,
-          provider,
-          model,
-          authProfileId,
-          authProfileIdSource: authProfileId ? "user" : "auto",
-          streamParams,
-          disableTools: true,
+          schema,
+          run: async (prompt, attempt) => {
+            const run = await runEmbeddedPiAgent({
+              sessionId,
+              sessionFile,
+              workspaceDir: api.config?.agents?.defaults?.workspace ?? process.cwd(),
+              config: api.config,
+              prompt,
+              timeoutMs,
+              runId: `llm-task-${Date.now()}-${attempt}`,
+              provider,
+              model,
+              authProfileId,
+              authProfileIdSource: authProfileId ? "user" : "auto",
+              streamParams,
+              disableTools: true,
+            });
+            // oxlint-disable-next-line typescript/no-explicit-any
+            return collectText((run as any).payloads);
+          },
         });
-
-        // oxlint-disable-next-line typescript/no-explicit-any
-        const text = collectText((result as any).payloads);
-        if (!text) {
-          throw new Error("LLM returned empty output");
-        }
-
-        const raw = stripCodeFences(text);
-        let parsed: unknown;
-        try {
-          parsed = JSON.parse(raw);
-        } catch {
-          throw new Error("LLM returned invalid JSON");
-        }
-
-        // oxlint-disable-next-line typescript/no-explicit-any
-        const schema = (params as any).schema as unknown;
-        if (schema && typeof schema === "object" && !Array.isArray(schema)) {
-          const ajv = new Ajv.default({ allErrors: true, strict: false });
-          // oxlint-disable-next-line typescript/no-explicit-any
-          const validate = ajv.compile(schema as any);
-          const ok = validate(parsed);
-          if (!ok) {
-            const msg =
-              validate.errors
-                ?.map(
-                  (e: { instancePath?: string; message?: string }) =>
-                    `${e.instancePath || "<root>"} ${e.message || "invalid"}`,
-                )
-                .join("; ") ?? "invalid";
-            throw new Error(`LLM JSON did not match schema: ${msg}`);
+        if (!result.ok) {
+          if (result.error === "empty output") {
+            throw new Error("LLM returned empty output");
           }
+          throw new Error(
+            result.error.startsWith("invalid JSON")
+              ? "LLM returned invalid JSON"
+              : `LLM JSON ${result.error}`,
+          );
         }
+        const parsed = result.value;
 
         return {
           content: [{ type: "text", text: JSON.stringify(parsed, null, 2) }],
@@ -4,6 +4,7 @@ import type {
   OpenClawPluginToolFactory,
 } from "../../src/plugins/types.js";
 import { createLobsterTool } from "./src/lobster-tool.js";
+import { createWorkflowTool, loadWorkflows, resolveWorkflowsDir } from "./src/workflow-tools.js";
 
 export default function register(api: OpenClawPluginApi) {
   api.registerTool(
@@ -15,4 +16,19 @@ export default function register(api: OpenClawPluginApi) {
     }) as OpenClawPluginToolFactory,
     { optional: true },
   );
+
+  // Each workflow file in the workflows directory becomes its own tool (workflow_<name>).
+  api.registerTool(
+    ((ctx) => {
+      if (ctx.sandboxed) {
+        return null;
+      }
+      const dir = resolveWorkflowsDir(api.pluginConfig, ctx.workspaceDir);
+      if (!dir) {
+        return null;
+      }
+      return loadWorkflows(dir, api.logger).map(createWorkflowTool);
+    }) as OpenClawPluginToolFactory,
+    { optional: true },
+  );
 }
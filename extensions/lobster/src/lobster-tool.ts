@@ -235,7 +235,6 @@ export function createLobsterTool(api: OpenClawPluginApi) {
         throw new Error("action required");
       }
 
-      const execPath = "lobster";
       const cwd = resolveCwd(params.cwd);
       const timeoutMs = typeof params.timeoutMs === "number" ? params.timeoutMs : 20_000;
       const maxStdoutBytes =
@@ -247,20 +246,24 @@ export function createLobsterTool(api: OpenClawPluginApi) {
         api.logger.debug(`lobster plugin runtime=${api.runtime.version}`);
       }
 
-      const { stdout } = await runLobsterSubprocessOnce({
-        execPath,
-        argv,
-        cwd,
-        timeoutMs,
-        maxStdoutBytes,
-      });
+      return await runLobsterTool({ argv, cwd, timeoutMs, maxStdoutBytes });
+    },
+  };
+}
+
+/** Run the lobster CLI in tool mode and return its envelope as a tool result. */
+export async function runLobsterTool(params: {
+  argv: string[];
+  cwd: string;
+  timeoutMs: number;
+  maxStdoutBytes: number;
+}) {
+  const { stdout } = await runLobsterSubprocessOnce({ execPath: "lobster", ...params });
 
-      const envelope = parseEnvelope(stdout);
+  const envelope = parseEnvelope(stdout);
 
-      return {
-        content: [{ type: "text", text: JSON.stringify(envelope, null, 2) }],
-        details: envelope,
-      };
-    },
+  return {
+    content: [{ type: "text" as const, text: JSON.stringify(envelope, null, 2) }],
+    details: envelope,
   };
 }
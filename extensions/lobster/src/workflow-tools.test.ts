@@ -0,0 +1,114 @@
+import { EventEmitter } from "node:events";
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { PassThrough } from "node:stream";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+
+const spawnState = vi.hoisted(() => ({
+  spawn: vi.fn(),
+}));
+
+vi.mock("node:child_process", () => ({
+  spawn: (...args: unknown[]) => spawnState.spawn(...args),
+}));
+
+const { createWorkflowTool, loadWorkflows, resolveWorkflowToolName, resolveWorkflowsDir } =
+  await import("./workflow-tools.js");
+
+describe("lobster workflow tools", () => {
+  let dir = "";
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-lobster-workflows-"));
+    spawnState.spawn.mockReset();
+    spawnState.spawn.mockImplementation(() => {
+      const child = new EventEmitter() as EventEmitter & {
+        stdout: PassThrough;
+        stderr: PassThrough;
+        kill: () => boolean;
+      };
+      child.stdout = new PassThrough();
+      child.stderr = new PassThrough();
+      child.kill = () => true;
+      setImmediate(() => {
+        child.stderr.end();
+        child.stdout.end(
+          JSON.stringify({ ok: true, status: "ok", output: [], requiresApproval: null }),
+        );
+        child.emit("exit", 0);
+      });
+      return child;
+    });
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("loads workflow files and skips invalid ones", () => {
+    fs.writeFileSync(
+      path.join(dir, "shutdown.lobster"),
+      [
+        "name: Safe Shutdown",
+        "description: Park the arm and power down.",
+        "args:",
+        "  reason:",
+        "    description: Why we are shutting down",
+        "  delay:",
+        '    default: "0"',
+        "steps:",
+        "  - id: park",
+        "    command: arm park --json",
+        "",
+      ].join("\n"),
+    );
+    fs.writeFileSync(path.join(dir, "broken.lobster"), "name: broken\n");
+    fs.writeFileSync(path.join(dir, "notes.md"), "not a workflow\n");
+    const warn = vi.fn();
+
+    const workflows = loadWorkflows(dir, { info() {}, warn, error() {} });
+
+    expect(workflows).toEqual([
+      {
+        name: "Safe Shutdown",
+        toolName: "workflow_safe_shutdown",
+        description: "Park the arm and power down.",
+        args: {
+          reason: { description: "Why we are shutting down", required: true },
+          delay: { description: undefined, required: false },
+        },
+        file: path.join(dir, "shutdown.lobster"),
+      },
+    ]);
+    expect(warn).toHaveBeenCalledTimes(1);
+  });
+
+  it("runs the workflow file with its arguments", async () => {
+    const file = path.join(dir, "shutdown.lobster");
+    const tool = createWorkflowTool({
+      name: "Safe Shutdown",
+      toolName: "workflow_safe_shutdown",
+      args: { reason: { required: true } },
+      file,
+    });
+
+    const result = await tool.execute("call-1", { reason: "maintenance", extra: "ignored" });
+
+    expect(spawnState.spawn).toHaveBeenCalledWith(
+      "lobster",
+      ["run", "--mode", "tool", file, "--args-json", '{"reason":"maintenance"}'],
+      expect.objectContaining({ cwd: dir }),
+    );
+    expect(result.details).toMatchObject({ ok: true, status: "ok" });
+  });
+
+  it("resolves tool names and the workflows directory", () => {
+    expect(resolveWorkflowToolName("Inbox Triage!")).toBe("workflow_inbox_triage");
+    expect(resolveWorkflowsDir(undefined, "/ws")).toBe(path.join("/ws", "workflows"));
+    expect(resolveWorkflowsDir({ workflowsDir: "routines" }, "/ws")).toBe(
+      path.resolve("/ws", "routines"),
+    );
+    expect(resolveWorkflowsDir(undefined, undefined)).toBeUndefined();
+  });
+});
@@ -0,0 +1,144 @@
+import fs from "node:fs";
+import path from "node:path";
+import { Type, type TSchema } from "@sinclair/typebox";
+import { parse as parseYaml } from "yaml";
+import type { AnyAgentTool, OpenClawPluginApi } from "../../../src/plugins/types.js";
+import { runLobsterTool } from "./lobster-tool.js";
+
+const WORKFLOW_FILE_SUFFIX = ".lobster";
+const DEFAULT_WORKFLOWS_DIR = "workflows";
+const WORKFLOW_TIMEOUT_MS = 120_000;
+const WORKFLOW_MAX_STDOUT_BYTES = 512_000;
+
+export type LobsterWorkflowArg = {
+  description?: string;
+  required: boolean;
+};
+
+export type LobsterWorkflow = {
+  name: string;
+  toolName: string;
+  description?: string;
+  args: Record<string, LobsterWorkflowArg>;
+  file: string;
+};
+
+export function resolveWorkflowToolName(name: string): string {
+  const slug = name
+    .trim()
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, "_")
+    .replace(/^_+|_+$/g, "");
+  return `workflow_${slug || "unnamed"}`;
+}
+
+function readWorkflowArgs(raw: unknown): Record<string, LobsterWorkflowArg> {
+  if (!raw || typeof raw !== "object" || Array.isArray(raw)) {
+    return {};
+  }
+  const args: Record<string, LobsterWorkflowArg> = {};
+  for (const [key, value] of Object.entries(raw as Record<string, unknown>)) {
+    const spec =
+      value && typeof value === "object" && !Array.isArray(value)
+        ? (value as Record<string, unknown>)
+        : {};
+    args[key] = {
+      description: typeof spec.description === "string" ? spec.description : undefined,
+      required: spec.default === undefined && spec.required !== false,
+    };
+  }
+  return args;
+}
+
+/** Load `*.lobster` workflow files (YAML or JSON) from a directory; unreadable files are skipped. */
+export function loadWorkflows(
+  dir: string,
+  logger?: OpenClawPluginApi["logger"],
+): LobsterWorkflow[] {
+  let entries: string[];
+  try {
+    entries = fs.readdirSync(dir);
+  } catch {
+    return [];
+  }
+  const workflows: LobsterWorkflow[] = [];
+  const seen = new Set<string>();
+  for (const entry of entries.toSorted()) {
+    if (!entry.endsWith(WORKFLOW_FILE_SUFFIX)) {
+      continue;
+    }
+    const file = path.join(dir, entry);
+    try {
+      const parsed = parseYaml(fs.readFileSync(file, "utf8")) as Record<string, unknown> | null;
+      if (!parsed || typeof parsed !== "object" || !Array.isArray(parsed.steps)) {
+        throw new Error("missing steps");
+      }
+      const name =
+        typeof parsed.name === "string" && parsed.name.trim()
+          ? parsed.name.trim()
+          : entry.slice(0, -WORKFLOW_FILE_SUFFIX.length);
+      const toolName = resolveWorkflowToolName(name);
+      if (seen.has(toolName)) {
+        throw new Error(`duplicate workflow name "${name}"`);
+      }
+      seen.add(toolName);
+      workflows.push({
+        name,
+        toolName,
+        description: typeof parsed.description === "string" ? parsed.description : undefined,
+        args: readWorkflowArgs(parsed.args),
+        file,
+      });
+    } catch (err) {
+      logger?.warn(`lobster: skipping workflow ${file}: ${String(err)}`);
+    }
+  }
+  return workflows;
+}
+
+export function resolveWorkflowsDir(
+  pluginConfig: Record<string, unknown> | undefined,
+  workspaceDir: string | undefined,
+): string | undefined {
+  const configured =
+    typeof pluginConfig?.workflowsDir === "string" ? pluginConfig.workflowsDir.trim() : "";
+  if (configured) {
+    return path.isAbsolute(configured) || !workspaceDir
+      ? path.resolve(configured)
+      : path.resolve(workspaceDir, configured);
+  }
+  return workspaceDir ? path.join(workspaceDir, DEFAULT_WORKFLOWS_DIR) : undefined;
+}
+
+export function createWorkflowTool(workflow: LobsterWorkflow): AnyAgentTool {
+  const properties: Record<string, TSchema> = {};
+  for (const [key, arg] of Object.entries(workflow.args)) {
+    const schema = Type.String(arg.description ? { description: arg.description } : {});
+    properties[key] = arg.required ? schema : Type.Optional(schema);
+  }
+  const summary = workflow.description?.trim() || `Run the "${workflow.name}" workflow.`;
+  return {
+    name: workflow.toolName,
+    label: `Workflow: ${workflow.name}`,
+    description: `${summary} Runs as one Lobster workflow; if it pauses for approval, resume it with the lobster tool (action "resume").`,
+    parameters: Type.Object(properties),
+    async execute(_id: string, params: Record<string, unknown>) {
+      const args: Record<string, unknown> = {};
+      for (const key of Object.keys(workflow.args)) {
+        if (params[key] !== undefined) {
+          args[key] = params[key];
+        }
+      }
+      const argv = ["run", "--mode", "tool", workflow.file];
+      if (Object.keys(args).length > 0) {
+        argv.push("--args-json", JSON.stringify(args));
+      }
+      return await runLobsterTool({
+        argv,
+        cwd: path.dirname(workflow.file),
+        timeoutMs: WORKFLOW_TIMEOUT_MS,
+        maxStdoutBytes: WORKFLOW_MAX_STDOUT_BYTES,
+      });
+    },
+  } as AnyAgentTool;
+}
@@ -55,6 +55,8 @@ export type MatrixMonitorHandlerParams = {
   dmPolicy: "open" | "pairing" | "allowlist" | "disabled";
   textLimit: number;
   mediaMaxBytes: number;
+  /** Drop messages from rooms without E2EE. */
+  requireEncryption: boolean;
   startupMs: number;
   startupGraceMs: number;
   directTracker: {
@@ -71,6 +73,17 @@ export type MatrixMonitorHandlerParams = {
   accountId?: string | null;
 };
 
+async function isMatrixRoomEncrypted(client: MatrixClient, roomId: string): Promise<boolean> {
+  if (!client.crypto) {
+    return false;
+  }
+  try {
+    return await client.crypto.isRoomEncrypted(roomId);
+  } catch {
+    return false;
+  }
+}
+
 export function createMatrixRoomMessageHandler(params: MatrixMonitorHandlerParams) {
   const {
     client,
@@ -89,6 +102,7 @@ export function createMatrixRoomMessageHandler(params: MatrixMonitorHandlerParam
     dmPolicy,
     textLimit,
     mediaMaxBytes,
+    requireEncryption,
     startupMs,
     startupGraceMs,
     directTracker,
@@ -140,6 +154,16 @@ export function createMatrixRoomMessageHandler(params: MatrixMonitorHandlerParam
         return;
       }
 
+      if (requireEncryption && !(await isMatrixRoomEncrypted(client, roomId))) {
+        logInboundDrop({
+          log: logVerboseMessage,
+          channel: "matrix",
+          reason: "unencrypted room (requireEncryption)",
+          target: roomId,
+        });
+        return;
+      }
+
       const roomInfo = await getRoomInfo(roomId);
       const roomName = roomInfo.name;
       const roomAliases = [roomInfo.canonicalAlias ?? "", ...roomInfo.altAliases].filter(Boolean);
@@ -274,6 +274,12 @@ export async function monitorMatrixProvider(opts: MonitorMatrixOpts = {}): Promi
   const textLimit = core.channel.text.resolveTextChunkLimit(cfg, "matrix");
   const mediaMaxMb = opts.mediaMaxMb ?? accountConfig.mediaMaxMb ?? DEFAULT_MEDIA_MAX_MB;
   const mediaMaxBytes = Math.max(1, mediaMaxMb) * 1024 * 1024;
+  const requireEncryption = accountConfig.requireEncryption === true;
+  if (requireEncryption && !auth.encryption) {
+    logger.warn(
+      "matrix: requireEncryption is set but encryption is disabled; all inbound messages will be ignored (set channels.matrix.encryption=true)",
+    );
+  }
   const startupMs = Date.now();
   const startupGraceMs = 0;
   const directTracker = createDirectRoomTracker(client, { log: logVerboseMessage });
@@ -299,6 +305,7 @@ export async function monitorMatrixProvider(opts: MonitorMatrixOpts = {}): Promi
     dmPolicy,
     textLimit,
     mediaMaxBytes,
+    requireEncryption,
     startupMs,
     startupGraceMs,
     directTracker,
@@ -63,6 +63,8 @@ export type MatrixConfig = {
   initialSyncLimit?: number;
   /** Enable end-to-end encryption (E2EE). Default: false. */
   encryption?: boolean;
+  /** Ignore inbound messages from rooms without E2EE (DMs included). Default: false. */
+  requireEncryption?: boolean;
   /** If true, enforce allowlists for groups + DMs regardless of policy. */
   allowlistOnly?: boolean;
   /** Group message policy (default: allowlist). */
@@ -190,6 +190,21 @@ export async function createMattermostPost(
   });
 }
 
+export async function patchMattermostPost(
+  client: MattermostClient,
+  postId: string,
+  params: { message: string },
+): Promise<MattermostPost> {
+  return await client.request<MattermostPost>(`/posts/${postId}/patch`, {
+    method: "PUT",
+    body: JSON.stringify({ message: params.message }),
+  });
+}
+
+export async function deleteMattermostPost(client: MattermostClient, postId: string) {
+  await client.request<Record<string, unknown>>(`/posts/${postId}`, { method: "DELETE" });
+}
+
 export async function uploadMattermostFile(
   client: MattermostClient,
   params: {
@@ -0,0 +1,78 @@
+import { describe, expect, it } from "vitest";
+import { createMattermostClient } from "./client.js";
+import { createMattermostDraftStream } from "./draft-stream.js";
+
+function createRecordingClient() {
+  const calls: Array<{ method: string; path: string; body?: Record<string, unknown> }> = [];
+  const fetchImpl = async (input: RequestInfo | URL, init?: RequestInit) => {
+    const url = new URL(String(input));
+    calls.push({
+      method: init?.method ?? "GET",
+      path: url.pathname.replace("/api/v4", ""),
+      body: typeof init?.body === "string" ? JSON.parse(init.body) : undefined,
+    });
+    if (init?.method === "DELETE") {
+      return new Response(null, { status: 204 });
+    }
+    return new Response(JSON.stringify({ id: "post-1" }), {
+      status: 200,
+      headers: { "content-type": "application/json" },
+    });
+  };
+  const client = createMattermostClient({
+    baseUrl: "https://chat.example.com",
+    botToken: "test-token",
+    fetchImpl: fetchImpl as typeof fetch,
+  });
+  return { client, calls };
+}
+
+describe("mattermost draft stream", () => {
+  it("creates a preview post, then patches it in place", async () => {
+    const { client, calls } = createRecordingClient();
+    const stream = createMattermostDraftStream({
+      client,
+      channelId: "chan-1",
+      rootId: "root-1",
+      throttleMs: 250,
+    });
+
+    stream.update("Hello");
+    await stream.flush();
+    stream.update("Hello there");
+    await stream.flush();
+
+    expect(calls).toEqual([
+      {
+        method: "POST",
+        path: "/posts",
+        body: { channel_id: "chan-1", message: "Hello", root_id: "root-1" },
+      },
+      { method: "PUT", path: "/posts/post-1/patch", body: { message: "Hello there" } },
+    ]);
+    expect(stream.postId()).toBe("post-1");
+  });
+
+  it("waits for minInitialChars and deletes the preview on clear", async () => {
+    const { client, calls } = createRecordingClient();
+    const stream = createMattermostDraftStream({
+      client,
+      channelId: "chan-1",
+      minInitialChars: 10,
+    });
+
+    stream.update("Hi");
+    await stream.flush();
+    expect(calls).toHaveLength(0);
+
+    stream.update("Hi, here is the answer");
+    await stream.flush();
+    await stream.clear();
+
+    expect(calls.map((call) => `${call.method} ${call.path}`)).toEqual([
+      "POST /posts",
+      "DELETE /posts/post-1",
+    ]);
+    expect(stream.postId()).toBeUndefined();
+  });
+});
@@ -0,0 +1,129 @@
+import {
+  createFinalizableDraftLifecycle,
+  type FinalizableDraftStreamState,
+} from "openclaw/plugin-sdk";
+import {
+  createMattermostPost,
+  deleteMattermostPost,
+  patchMattermostPost,
+  type MattermostClient,
+} from "./client.js";
+
+/** Mattermost's default MaxPostSize is 16383 runes; keep previews well inside it. */
+const MATTERMOST_STREAM_MAX_CHARS = 16_000;
+const DEFAULT_THROTTLE_MS = 1000;
+
+export type MattermostDraftStream = {
+  update: (text: string) => void;
+  flush: () => Promise<void>;
+  postId: () => string | undefined;
+  clear: () => Promise<void>;
+  stop: () => Promise<void>;
+};
+
+/**
+ * Edit-based preview streaming: the first partial reply creates a post, later partials patch
+ * it in place so the reply builds up while the model is still generating.
+ */
+export function createMattermostDraftStream(params: {
+  client: MattermostClient;
+  channelId: string;
+  rootId?: string;
+  maxChars?: number;
+  throttleMs?: number;
+  /** Minimum chars before creating the preview post (avoids one-word notifications). */
+  minInitialChars?: number;
+  log?: (message: string) => void;
+  warn?: (message: string) => void;
+}): MattermostDraftStream {
+  const maxChars = Math.min(
+    params.maxChars ?? MATTERMOST_STREAM_MAX_CHARS,
+    MATTERMOST_STREAM_MAX_CHARS,
+  );
+  const throttleMs = Math.max(250, params.throttleMs ?? DEFAULT_THROTTLE_MS);
+  const { client, channelId } = params;
+
+  const streamState: FinalizableDraftStreamState = { stopped: false, final: false };
+  let streamPostId: string | undefined;
+  let lastSentText = "";
+
+  const sendOrEditStreamMessage = async (text: string): Promise<boolean> => {
+    if (streamState.stopped && !streamState.final) {
+      return false;
+    }
+    const trimmed = text.trimEnd();
+    if (!trimmed) {
+      return false;
+    }
+    if (trimmed.length > maxChars) {
+      streamState.stopped = true;
+      params.warn?.(
+        `mattermost stream preview stopped (text length ${trimmed.length} > ${maxChars})`,
+      );
+      return false;
+    }
+    if (trimmed === lastSentText) {
+      return true;
+    }
+    if (
+      streamPostId === undefined &&
+      params.minInitialChars != null &&
+      !streamState.final &&
+      trimmed.length < params.minInitialChars
+    ) {
+      return false;
+    }
+
+    lastSentText = trimmed;
+    try {
+      if (streamPostId !== undefined) {
+        await patchMattermostPost(client, streamPostId, { message: trimmed });
+        return true;
+      }
+      const post = await createMattermostPost(client, {
+        channelId,
+        message: trimmed,
+        rootId: params.rootId,
+      });
+      if (!post?.id) {
+        streamState.stopped = true;
+        params.warn?.("mattermost stream preview stopped (missing post id from create)");
+        return false;
+      }
+      streamPostId = post.id;
+      return true;
+    } catch (err) {
+      streamState.stopped = true;
+      params.warn?.(
+        `mattermost stream preview failed: ${err instanceof Error ? err.message : String(err)}`,
+      );
+      return false;
+    }
+  };
+
+  const { loop, update, stop, clear } = createFinalizableDraftLifecycle({
+    throttleMs,
+    state: streamState,
+    sendOrEditStreamMessage,
+    readMessageId: () => streamPostId,
+    clearMessageId: () => {
+      streamPostId = undefined;
+    },
+    isValidMessageId: (value: unknown): value is string => typeof value === "string",
+    deleteMessage: async (postId: string) => {
+      await deleteMattermostPost(client, postId);
+    },
+    warn: params.warn,
+    warnPrefix: "mattermost stream preview cleanup failed",
+  });
+
+  params.log?.(`mattermost stream preview ready (maxChars=${maxChars}, throttleMs=${throttleMs})`);
+
+  return {
+    update,
+    flush: loop.flush,
+    postId: () => streamPostId,
+    clear,
+    stop,
+  };
+}
@@ -30,6 +30,7 @@ import {
   fetchMattermostMe,
   fetchMattermostUser,
   normalizeMattermostBaseUrl,
+  patchMattermostPost,
   sendMattermostTyping,
   type MattermostChannel,
   type MattermostPost,
@@ -46,6 +47,7 @@ import {
   type MattermostEventPayload,
   type MattermostWebSocketFactory,
 } from "./monitor-websocket.js";
+import { createMattermostDraftStream } from "./draft-stream.js";
 import { runWithReconnect } from "./reconnect.js";
 import { sendMessageMattermost } from "./send.js";
 
@@ -754,13 +756,52 @@ export async function monitorMattermostProvider(opts: MonitorMattermostOpts = {}
         });
       },
     });
+    // Edit-based preview streaming; block streaming already splits replies, so skip it then.
+    const draftStream =
+      account.config.streaming === true && account.blockStreaming !== true
+        ? createMattermostDraftStream({
+            client,
+            channelId,
+            rootId: threadRootId,
+            maxChars: textLimit,
+            minInitialChars: 30,
+            log: logVerboseMessage,
+            warn: logVerboseMessage,
+          })
+        : undefined;
+    let finalizedViaPreview = false;
+
     const { dispatcher, replyOptions, markDispatchIdle } =
       core.channel.reply.createReplyDispatcherWithTyping({
         ...prefixOptions,
         humanDelay: core.channel.reply.resolveHumanDelayConfig(cfg, route.agentId),
-        deliver: async (payload: ReplyPayload) => {
+        deliver: async (payload: ReplyPayload, info) => {
           const mediaUrls = payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : []);
           const text = core.channel.text.convertMarkdownTables(payload.text ?? "", tableMode);
+          if (draftStream && info.kind === "final" && !finalizedViaPreview) {
+            await draftStream.stop();
+            const previewPostId = draftStream.postId();
+            const finalText = text.trim();
+            if (
+              previewPostId &&
+              mediaUrls.length === 0 &&
+              !payload.isError &&
+              finalText &&
+              finalText.length <= textLimit
+            ) {
+              try {
+                await patchMattermostPost(client, previewPostId, { message: finalText });
+                finalizedViaPreview = true;
+                runtime.log?.(`delivered reply to ${to}`);
+                return;
+              } catch (err) {
+                logVerboseMessage(
+                  `mattermost: preview final edit failed; falling back to send (${String(err)})`,
+                );
+              }
+            }
+            await draftStream.clear();
+          }
           if (mediaUrls.length === 0) {
             const chunkMode = core.channel.text.resolveChunkMode(
               cfg,
@@ -797,17 +838,35 @@ export async function monitorMattermostProvider(opts: MonitorMattermostOpts = {}
         onReplyStart: typingCallbacks.onReplyStart,
       });
 
-    await core.channel.reply.dispatchReplyFromConfig({
-      ctx: ctxPayload,
-      cfg,
-      dispatcher,
-      replyOptions: {
-        ...replyOptions,
-        disableBlockStreaming:
-          typeof account.blockStreaming === "boolean" ? !account.blockStreaming : undefined,
-        onModelSelected,
-      },
-    });
+    try {
+      await core.channel.reply.dispatchReplyFromConfig({
+        ctx: ctxPayload,
+        cfg,
+        dispatcher,
+        replyOptions: {
+          ...replyOptions,
+          disableBlockStreaming: draftStream
+            ? true
+            : typeof account.blockStreaming === "boolean"
+              ? !account.blockStreaming
+              : undefined,
+          onPartialReply: draftStream
+            ? (payload) => {
+                if (payload.text) {
+                  draftStream.update(payload.text);
+                }
+              }
+            : undefined,
+          onModelSelected,
+        },
+      });
+    } finally {
+      // stop() flushes any pending preview before clear() removes an unfinished one.
+      await draftStream?.stop();
+      if (!finalizedViaPreview) {
+        await draftStream?.clear();
+      }
+    }
     markDispatchIdle();
     if (historyKey) {
       clearHistoryEntriesIfEnabled({
@@ -0,0 +1,17 @@
+import type { OpenClawPluginApi } from "openclaw/plugin-sdk";
+import { emptyPluginConfigSchema } from "openclaw/plugin-sdk";
+import { createMqttPlugin } from "./src/channel.js";
+import { setMqttRuntime } from "./src/runtime.js";
+
+const plugin = {
+  id: "mqtt",
+  name: "MQTT",
+  description: "MQTT channel plugin for OpenClaw (command topic in, reply topic out)",
+  configSchema: emptyPluginConfigSchema(),
+  register(api: OpenClawPluginApi) {
+    setMqttRuntime(api.runtime);
+    api.registerChannel({ plugin: createMqttPlugin() });
+  },
+};
+
+export default plugin;
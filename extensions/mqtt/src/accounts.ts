@@ -0,0 +1,79 @@
+/**
+ * Account resolution: reads config from channels.mqtt, merges per-account
+ * overrides, falls back to MQTT_PASSWORD for the default account's password.
+ */
+
+import type { OpenClawConfig } from "openclaw/plugin-sdk";
+import { randomBytes } from "node:crypto";
+import { resolveTriggers } from "./triggers.js";
+import type { MqttChannelConfig, MqttQos, ResolvedMqttAccount } from "./types.js";
+
+const DEFAULT_COMMAND_TOPIC = "openclaw/command";
+const DEFAULT_REPLY_TOPIC = "openclaw/reply";
+const DEFAULT_QOS: MqttQos = 1;
+
+// Stable per process so reconnects resume the same broker session.
+const clientIdSuffix = randomBytes(4).toString("hex");
+
+function getChannelConfig(cfg: OpenClawConfig): MqttChannelConfig | undefined {
+  return cfg.channels?.mqtt as MqttChannelConfig | undefined;
+}
+
+function parseAllowFrom(raw: string | string[] | undefined): string[] {
+  if (!raw) return [];
+  const entries = Array.isArray(raw) ? raw : raw.split(",");
+  return entries.map((entry) => entry.trim()).filter(Boolean);
+}
+
+function resolveQos(raw: unknown): MqttQos {
+  return raw === 0 || raw === 1 || raw === 2 ? raw : DEFAULT_QOS;
+}
+
+/**
+ * List all configured account IDs for this channel.
+ * Returns ["default"] if the base config has a broker URL, plus any named accounts.
+ */
+export function listAccountIds(cfg: OpenClawConfig): string[] {
+  const channelCfg = getChannelConfig(cfg);
+  if (!channelCfg) return [];
+
+  const ids = new Set<string>();
+  if (channelCfg.brokerUrl) {
+    ids.add("default");
+  }
+  for (const id of Object.keys(channelCfg.accounts ?? {})) {
+    ids.add(id);
+  }
+  return Array.from(ids);
+}
+
+/** Resolve a specific account by ID with full defaults applied. */
+export function resolveAccount(
+  cfg: OpenClawConfig,
+  accountId?: string | null,
+): ResolvedMqttAccount {
+  const channelCfg = getChannelConfig(cfg) ?? {};
+  const id = accountId || "default";
+  const accountOverride = channelCfg.accounts?.[id] ?? {};
+  const envPassword = id === "default" ? process.env.MQTT_PASSWORD : undefined;
+
+  return {
+    accountId: id,
+    enabled: accountOverride.enabled ?? channelCfg.enabled ?? true,
+    brokerUrl: (accountOverride.brokerUrl ?? channelCfg.brokerUrl ?? "").trim(),
+    username: accountOverride.username ?? channelCfg.username,
+    password: accountOverride.password ?? channelCfg.password ?? envPassword,
+    clientId:
+      accountOverride.clientId ?? channelCfg.clientId ?? `openclaw-${id}-${clientIdSuffix}`,
+    commandTopic:
+      accountOverride.commandTopic?.trim() ||
+      channelCfg.commandTopic?.trim() ||
+      DEFAULT_COMMAND_TOPIC,
+    replyTopic:
+      accountOverride.replyTopic?.trim() || channelCfg.replyTopic?.trim() || DEFAULT_REPLY_TOPIC,
+    qos: resolveQos(accountOverride.qos ?? channelCfg.qos),
+    dmPolicy: accountOverride.dmPolicy ?? channelCfg.dmPolicy ?? "allowlist",
+    allowFrom: parseAllowFrom(accountOverride.allowFrom ?? channelCfg.allowFrom),
+    triggers: resolveTriggers(accountOverride.triggers ?? channelCfg.triggers),
+  };
+}
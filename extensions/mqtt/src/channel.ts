@@ -0,0 +1,357 @@
+/**
+ * MQTT channel plugin for OpenClaw.
+ *
+ * Subscribes to a command topic and publishes each reply to the command's reply topic with
+ * its correlation id, so devices and automations on an existing message bus can talk to the
+ * agent. Each sender id (payload `from`, else the topic) gets its own session.
+ */
+
+import {
+  DEFAULT_ACCOUNT_ID,
+  buildChannelConfigSchema,
+  createReplyPrefixOptions,
+  setAccountEnabledInConfigSection,
+  type ChannelGatewayContext,
+  type ChannelOutboundContext,
+  type OpenClawConfig,
+} from "openclaw/plugin-sdk";
+import { z } from "zod";
+import { listAccountIds, resolveAccount } from "./accounts.js";
+import { publishMqtt, startMqttSubscriber } from "./client.js";
+import { buildMqttReplyPayload, isSenderAllowed, normalizeMqttTopic } from "./protocol.js";
+import { getMqttRuntime } from "./runtime.js";
+import { buildTriggerPrompt, createTriggerEngine } from "./triggers.js";
+import type { MqttCommand, ResolvedMqttAccount } from "./types.js";
+
+const CHANNEL_ID = "mqtt";
+const TEXT_CHUNK_LIMIT = 64_000;
+const MqttConfigSchema = buildChannelConfigSchema(z.object({}).passthrough());
+
+async function handleMqttCommand(params: {
+  account: ResolvedMqttAccount;
+  command: MqttCommand;
+  /** Config-defined triggers skip the sender allowlist. */
+  trusted?: boolean;
+  log?: { info?: (msg: string) => void; warn?: (msg: string) => void };
+}): Promise<void> {
+  const { account, command, log } = params;
+  if (!params.trusted && !isSenderAllowed(account, command.from)) {
+    log?.info?.(`MQTT: drop command from ${command.from} (dmPolicy=${account.dmPolicy})`);
+    return;
+  }
+
+  const rt = getMqttRuntime();
+  const cfg = await rt.config.loadConfig();
+  const route = rt.channel.routing.resolveAgentRoute({
+    cfg,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+    peer: { kind: "direct", id: command.from },
+  });
+  const storePath = rt.channel.session.resolveStorePath(cfg.session?.store, {
+    agentId: route.agentId,
+  });
+  // Triggers are proactive runs, so they check the agent's standing goals like heartbeats do.
+  const goalsPrompt = params.trusted
+    ? await rt.system.resolveActiveGoalsPrompt(route.agentId).catch(() => "")
+    : "";
+  const agentText = goalsPrompt ? `${command.text}\n\n${goalsPrompt}` : command.text;
+  const body = rt.channel.reply.formatAgentEnvelope({
+    channel: "MQTT",
+    from: command.from,
+    timestamp: Date.now(),
+    previousTimestamp: rt.channel.session.readSessionUpdatedAt({
+      storePath,
+      sessionKey: route.sessionKey,
+    }),
+    envelope: rt.channel.reply.resolveEnvelopeFormatOptions(cfg),
+    body: agentText,
+  });
+  const ctxPayload = rt.channel.reply.finalizeInboundContext({
+    Body: body,
+    BodyForAgent: agentText,
+    RawBody: command.text,
+    CommandBody: command.text,
+    From: `${CHANNEL_ID}:${command.from}`,
+    To: `${CHANNEL_ID}:${command.replyTopic}`,
+    SessionKey: route.sessionKey,
+    AccountId: route.accountId,
+    ChatType: "direct",
+    ConversationLabel: command.from,
+    SenderId: command.from,
+    CommandAuthorized:
+      !params.trusted &&
+      (account.allowFrom.includes("*") || account.allowFrom.includes(command.from)),
+    Provider: CHANNEL_ID,
+    Surface: CHANNEL_ID,
+    MessageSid: command.correlationId,
+    OriginatingChannel: CHANNEL_ID,
+    OriginatingTo: `${CHANNEL_ID}:${command.replyTopic}`,
+  });
+  await rt.channel.session.recordInboundSession({
+    storePath,
+    sessionKey: route.sessionKey,
+    ctx: ctxPayload,
+    onRecordError: (err) => {
+      log?.warn?.(`MQTT: failed updating session meta: ${String(err)}`);
+    },
+  });
+
+  // Collect the whole reply: one command, one correlated reply message.
+  const texts: string[] = [];
+  const mediaUrls: string[] = [];
+  const { onModelSelected, ...prefixOptions } = createReplyPrefixOptions({
+    cfg,
+    agentId: route.agentId,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+  });
+  let failure: string | undefined;
+  try {
+    await rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher({
+      ctx: ctxPayload,
+      cfg,
+      dispatcherOptions: {
+        ...prefixOptions,
+        deliver: async (payload: { text?: string; mediaUrls?: string[]; mediaUrl?: string }) => {
+          if (payload.text?.trim()) texts.push(payload.text.trim());
+          mediaUrls.push(...(payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : [])));
+        },
+        onError: (err: unknown) => {
+          log?.warn?.(`MQTT: reply to ${command.from} failed: ${String(err)}`);
+        },
+      },
+      replyOptions: { onModelSelected, disableBlockStreaming: true },
+    });
+  } catch (err) {
+    log?.warn?.(`MQTT: agent run for ${command.from} failed: ${String(err)}`);
+    failure = "agent run failed";
+  }
+  if (!failure && texts.length === 0 && mediaUrls.length === 0) return;
+
+  // Callers waiting on a correlation id always get an answer, even when the run failed.
+  await publishMqtt(
+    account,
+    command.replyTopic,
+    buildMqttReplyPayload({
+      id: command.correlationId,
+      text: texts.join("\n\n"),
+      mediaUrls,
+      error: failure,
+    }),
+    { correlationData: command.correlationData },
+  );
+}
+
+export function createMqttPlugin() {
+  return {
+    id: CHANNEL_ID,
+
+    meta: {
+      id: CHANNEL_ID,
+      label: "MQTT",
+      selectionLabel: "MQTT (command/reply topics)",
+      detailLabel: "MQTT (command/reply topics)",
+      docsPath: "/channels/mqtt",
+      blurb: "Take prompts from an MQTT command topic and publish correlated replies",
+      order: 98,
+    },
+
+    capabilities: {
+      chatTypes: ["direct" as const],
+      media: false,
+      threads: false,
+      reactions: false,
+      edit: false,
+      unsend: false,
+      reply: true,
+      effects: false,
+      blockStreaming: false,
+    },
+
+    reload: { configPrefixes: [`channels.${CHANNEL_ID}`] },
+
+    configSchema: MqttConfigSchema,
+
+    config: {
+      listAccountIds: (cfg: OpenClawConfig) => listAccountIds(cfg),
+
+      resolveAccount: (cfg: OpenClawConfig, accountId?: string | null) =>
+        resolveAccount(cfg, accountId),
+
+      defaultAccountId: (_cfg: OpenClawConfig) => DEFAULT_ACCOUNT_ID,
+
+      setAccountEnabled: ({
+        cfg,
+        accountId,
+        enabled,
+      }: {
+        cfg: OpenClawConfig;
+        accountId: string;
+        enabled: boolean;
+      }) => {
+        const channelConfig = cfg?.channels?.[CHANNEL_ID] ?? {};
+        if (accountId === DEFAULT_ACCOUNT_ID) {
+          return {
+            ...cfg,
+            channels: {
+              ...cfg.channels,
+              [CHANNEL_ID]: { ...channelConfig, enabled },
+            },
+          };
+        }
+        return setAccountEnabledInConfigSection({
+          cfg,
+          sectionKey: `channels.${CHANNEL_ID}`,
+          accountId,
+          enabled,
+        });
+      },
+    },
+
+    security: {
+      resolveDmPolicy: ({
+        cfg,
+        accountId,
+        account,
+      }: {
+        cfg: OpenClawConfig;
+        accountId?: string | null;
+        account: ResolvedMqttAccount;
+      }) => {
+        const resolvedAccountId = accountId ?? account.accountId ?? DEFAULT_ACCOUNT_ID;
+        const useAccountPath = Boolean(cfg?.channels?.mqtt?.accounts?.[resolvedAccountId]);
+        const basePath = useAccountPath
+          ? `channels.mqtt.accounts.${resolvedAccountId}.`
+          : "channels.mqtt.";
+        return {
+          policy: account.dmPolicy,
+          allowFrom: account.allowFrom,
+          policyPath: `${basePath}dmPolicy`,
+          allowFromPath: basePath,
+          // No pairing: sender ids are self-declared, so access is config-only.
+          approveHint: `Add sender ids to ${basePath}allowFrom`,
+          normalizeEntry: (raw: string) => raw.trim(),
+        };
+      },
+      collectWarnings: ({ account }: { account: ResolvedMqttAccount }) => {
+        const warnings: string[] = [];
+        if (!account.brokerUrl) {
+          warnings.push("- MQTT: brokerUrl is not configured. The channel will not connect.");
+        }
+        if (account.dmPolicy === "open" || account.allowFrom.includes("*")) {
+          warnings.push(
+            "- MQTT: any client that can publish to the command topic can talk to the agent. Restrict publish rights with broker ACLs.",
+          );
+        }
+        return warnings;
+      },
+    },
+
+    messaging: {
+      normalizeTarget: (target: string) => normalizeMqttTopic(target),
+      targetResolver: {
+        looksLikeId: (id: string) => Boolean(normalizeMqttTopic(id ?? "")),
+        hint: "<topic>",
+      },
+    },
+
+    directory: {
+      self: async () => null,
+      listPeers: async () => [],
+      listGroups: async () => [],
+    },
+
+    outbound: {
+      deliveryMode: "direct" as const,
+      textChunkLimit: TEXT_CHUNK_LIMIT,
+
+      sendText: async ({ cfg, to, text, accountId }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        const topic = normalizeMqttTopic(to);
+        if (!topic) {
+          throw new Error(`MQTT: invalid publish topic "${to}"`);
+        }
+        await publishMqtt(account, topic, buildMqttReplyPayload({ text }));
+        return { channel: CHANNEL_ID, messageId: `mqtt-${Date.now()}`, chatId: topic };
+      },
+
+      sendMedia: async ({ cfg, to, text, mediaUrl, accountId }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        const topic = normalizeMqttTopic(to);
+        if (!topic) {
+          throw new Error(`MQTT: invalid publish topic "${to}"`);
+        }
+        await publishMqtt(
+          account,
+          topic,
+          buildMqttReplyPayload({ text: text ?? "", mediaUrls: mediaUrl ? [mediaUrl] : [] }),
+        );
+        return { channel: CHANNEL_ID, messageId: `mqtt-${Date.now()}`, chatId: topic };
+      },
+    },
+
+    gateway: {
+      startAccount: async (ctx: ChannelGatewayContext<ResolvedMqttAccount>) => {
+        const { cfg, accountId, log } = ctx;
+        const account = resolveAccount(cfg, accountId);
+
+        if (!account.enabled) {
+          log?.info?.(`MQTT account ${accountId} is disabled, skipping`);
+          return { stop: () => {} };
+        }
+
+        if (!account.brokerUrl) {
+          log?.warn?.(`MQTT account ${accountId} has no brokerUrl, skipping`);
+          return { stop: () => {} };
+        }
+
+        log?.info?.(
+          `Starting MQTT channel (account: ${accountId}, topic: ${account.commandTopic})`,
+        );
+        const triggers = createTriggerEngine(account.triggers);
+        const subscriber = startMqttSubscriber({
+          account,
+          onCommand: (command) => handleMqttCommand({ account, command, log }),
+          triggerTopics: triggers.topics,
+          onTopicMessage: (topic, payload) => {
+            for (const event of triggers.evaluate(topic, payload)) {
+              log?.info?.(`MQTT: trigger ${event.trigger.name} fired on ${topic}`);
+              const command: MqttCommand = {
+                from: `trigger:${event.trigger.name}`,
+                text: buildTriggerPrompt(event),
+                replyTopic: event.trigger.replyTopic ?? account.replyTopic,
+                topic,
+              };
+              handleMqttCommand({ account, command, trusted: true, log }).catch((err) => {
+                log?.warn?.(`MQTT: trigger ${event.trigger.name} failed: ${String(err)}`);
+              });
+            }
+          },
+          log,
+        });
+
+        return {
+          stop: async () => {
+            log?.info?.(`Stopping MQTT channel (account: ${accountId})`);
+            await subscriber.stop();
+          },
+        };
+      },
+
+      stopAccount: async (ctx: ChannelGatewayContext<ResolvedMqttAccount>) => {
+        ctx.log?.info?.(`MQTT account ${ctx.accountId} stopped`);
+      },
+    },
+
+    agentPrompt: {
+      messageToolHints: () => [
+        "",
+        "### MQTT Formatting",
+        "Replies are published as JSON to a device or automation, not shown in a chat app.",
+        "Keep replies short and plain: no Markdown tables or headings.",
+        "When the command asks for a value or state, answer with just that value.",
+      ],
+    },
+  };
+}
@@ -0,0 +1,100 @@
+/**
+ * Broker connections. The gateway keeps one subscriber connection per account; outbound
+ * sends reuse it when connected and otherwise open a short-lived publish connection.
+ */
+
+import mqtt, { type IClientOptions, type MqttClient } from "mqtt";
+import { parseMqttCommand, topicMatchesFilter } from "./protocol.js";
+import type { MqttCommand, ResolvedMqttAccount } from "./types.js";
+
+const RECONNECT_PERIOD_MS = 5_000;
+const CONNECT_TIMEOUT_MS = 15_000;
+
+const activeClients = new Map<string, MqttClient>();
+
+function buildClientOptions(account: ResolvedMqttAccount, clientId: string): IClientOptions {
+  return {
+    clientId,
+    username: account.username,
+    password: account.password,
+    protocolVersion: 5,
+    reconnectPeriod: RECONNECT_PERIOD_MS,
+    connectTimeout: CONNECT_TIMEOUT_MS,
+  };
+}
+
+export function startMqttSubscriber(params: {
+  account: ResolvedMqttAccount;
+  onCommand: (command: MqttCommand) => Promise<void>;
+  /** Extra topic filters (triggers); every message on them is passed to `onTopicMessage`. */
+  triggerTopics?: string[];
+  onTopicMessage?: (topic: string, payload: Buffer) => void;
+  log?: { info?: (msg: string) => void; warn?: (msg: string) => void };
+}): { stop: () => Promise<void> } {
+  const { account, log } = params;
+  const client = mqtt.connect(account.brokerUrl, buildClientOptions(account, account.clientId));
+  activeClients.set(account.accountId, client);
+
+  const triggerTopics = params.triggerTopics ?? [];
+  const topics = Array.from(new Set([account.commandTopic, ...triggerTopics]));
+
+  client.on("connect", () => {
+    log?.info?.(`MQTT: connected, subscribing to ${topics.join(", ")}`);
+    client.subscribe(topics, { qos: account.qos }, (err) => {
+      if (err) {
+        log?.warn?.(`MQTT: subscribe to ${topics.join(", ")} failed: ${String(err)}`);
+      }
+    });
+  });
+  client.on("error", (err) => {
+    log?.warn?.(`MQTT: connection error: ${String(err)}`);
+  });
+  client.on("message", (topic, payload, packet) => {
+    if (triggerTopics.some((filter) => topicMatchesFilter(filter, topic))) {
+      params.onTopicMessage?.(topic, payload);
+    }
+    // Persistent broker sessions can still deliver topics from an earlier commandTopic.
+    if (!topicMatchesFilter(account.commandTopic, topic)) return;
+    const command = parseMqttCommand({ account, topic, payload, properties: packet.properties });
+    if (!command) return;
+    params.onCommand(command).catch((err) => {
+      log?.warn?.(`MQTT: command from ${command.from} failed: ${String(err)}`);
+    });
+  });
+
+  return {
+    stop: async () => {
+      if (activeClients.get(account.accountId) === client) {
+        activeClients.delete(account.accountId);
+      }
+      await client.endAsync();
+    },
+  };
+}
+
+export async function publishMqtt(
+  account: ResolvedMqttAccount,
+  topic: string,
+  payload: string,
+  opts?: { correlationData?: Buffer },
+): Promise<void> {
+  const publishOptions = {
+    qos: account.qos,
+    ...(opts?.correlationData ? { properties: { correlationData: opts.correlationData } } : {}),
+  };
+  const active = activeClients.get(account.accountId);
+  if (active?.connected) {
+    await active.publishAsync(topic, payload, publishOptions);
+    return;
+  }
+  // A second connection must not reuse the subscriber's client id, or the broker drops it.
+  const client = await mqtt.connectAsync(account.brokerUrl, {
+    ...buildClientOptions(account, `${account.clientId}-pub`),
+    reconnectPeriod: 0,
+  });
+  try {
+    await client.publishAsync(topic, payload, publishOptions);
+  } finally {
+    await client.endAsync();
+  }
+}
@@ -0,0 +1,102 @@
+import { describe, expect, it } from "vitest";
+import { resolveAccount } from "./accounts.js";
+import {
+  buildMqttReplyPayload,
+  isSenderAllowed,
+  normalizeMqttTopic,
+  parseMqttCommand,
+  topicMatchesFilter,
+} from "./protocol.js";
+
+const account = (overrides: Record<string, unknown> = {}) =>
+  resolveAccount({ channels: { mqtt: { brokerUrl: "mqtt://broker.test", ...overrides } } });
+
+describe("topicMatchesFilter", () => {
+  it("handles exact topics and wildcards", () => {
+    expect(topicMatchesFilter("home/cmd", "home/cmd")).toBe(true);
+    expect(topicMatchesFilter("home/+/cmd", "home/kitchen/cmd")).toBe(true);
+    expect(topicMatchesFilter("home/+/cmd", "home/kitchen/sub/cmd")).toBe(false);
+    expect(topicMatchesFilter("home/#", "home/kitchen/sub/cmd")).toBe(true);
+    expect(topicMatchesFilter("home/cmd", "home/cmd/extra")).toBe(false);
+  });
+});
+
+describe("normalizeMqttTopic", () => {
+  it("strips the channel prefix and rejects wildcards", () => {
+    expect(normalizeMqttTopic("mqtt:home/reply")).toBe("home/reply");
+    expect(normalizeMqttTopic("home/+/reply")).toBeUndefined();
+    expect(normalizeMqttTopic("  ")).toBeUndefined();
+  });
+});
+
+describe("parseMqttCommand", () => {
+  it("reads JSON commands with id, sender, and reply topic", () => {
+    const command = parseMqttCommand({
+      account: account(),
+      topic: "openclaw/command",
+      payload: Buffer.from(
+        JSON.stringify({ id: "c1", from: "panel", text: "lights off", replyTo: "panel/reply" }),
+      ),
+    });
+    expect(command).toMatchObject({
+      correlationId: "c1",
+      from: "panel",
+      text: "lights off",
+      replyTopic: "panel/reply",
+    });
+  });
+
+  it("falls back to MQTT 5 properties, the topic, and the default reply topic", () => {
+    const correlationData = Buffer.from("req-7");
+    const plain = parseMqttCommand({
+      account: account({ replyTopic: "bus/replies" }),
+      topic: "home/kitchen/cmd",
+      payload: Buffer.from("what's the temperature?"),
+      properties: { correlationData },
+    });
+    expect(plain).toMatchObject({
+      correlationId: "req-7",
+      correlationData,
+      from: "home/kitchen/cmd",
+      replyTopic: "bus/replies",
+    });
+
+    const withResponseTopic = parseMqttCommand({
+      account: account(),
+      topic: "openclaw/command",
+      payload: Buffer.from("hi"),
+      properties: { responseTopic: "client/42/reply" },
+    });
+    expect(withResponseTopic?.replyTopic).toBe("client/42/reply");
+  });
+
+  it("ignores empty payloads and our own replies", () => {
+    const base = { account: account(), topic: "openclaw/command" };
+    expect(parseMqttCommand({ ...base, payload: Buffer.from("  ") })).toBeNull();
+    expect(
+      parseMqttCommand({
+        ...base,
+        payload: Buffer.from(buildMqttReplyPayload({ id: "c1", text: "done" })),
+      }),
+    ).toBeNull();
+  });
+});
+
+describe("buildMqttReplyPayload", () => {
+  it("includes the correlation id and omits empty fields", () => {
+    expect(JSON.parse(buildMqttReplyPayload({ id: "c1", text: "ok", mediaUrls: [] }))).toEqual({
+      id: "c1",
+      from: "openclaw",
+      text: "ok",
+    });
+  });
+});
+
+describe("isSenderAllowed", () => {
+  it("applies dmPolicy and allowFrom", () => {
+    expect(isSenderAllowed(account({ allowFrom: ["panel"] }), "panel")).toBe(true);
+    expect(isSenderAllowed(account({ allowFrom: ["panel"] }), "other")).toBe(false);
+    expect(isSenderAllowed(account({ dmPolicy: "open" }), "other")).toBe(true);
+    expect(isSenderAllowed(account({ dmPolicy: "disabled", allowFrom: ["*"] }), "x")).toBe(false);
+  });
+});
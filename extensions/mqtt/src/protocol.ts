@@ -0,0 +1,126 @@
+/**
+ * Command/reply message format.
+ *
+ * Commands are JSON (`{ "id", "from", "text", "replyTo" }`) or plain UTF-8 text. MQTT 5
+ * response topic and correlation data are honored when the payload does not set them.
+ * Replies are JSON: `{ "id", "text", "mediaUrls"?, "from": "openclaw" }`.
+ */
+
+import type { MqttCommand, ResolvedMqttAccount } from "./types.js";
+
+export type MqttPacketProperties = {
+  responseTopic?: string;
+  correlationData?: Buffer;
+};
+
+export type MqttReply = {
+  id?: string;
+  text: string;
+  mediaUrls?: string[];
+  error?: string;
+};
+
+const REPLY_SENDER = "openclaw";
+
+/** True if `topic` matches the subscription `filter` (`+` = one level, `#` = the rest). */
+export function topicMatchesFilter(filter: string, topic: string): boolean {
+  const filterLevels = filter.split("/");
+  const topicLevels = topic.split("/");
+  for (let i = 0; i < filterLevels.length; i++) {
+    const level = filterLevels[i];
+    if (level === "#") {
+      return true;
+    }
+    if (i >= topicLevels.length) {
+      return false;
+    }
+    if (level !== "+" && level !== topicLevels[i]) {
+      return false;
+    }
+  }
+  return filterLevels.length === topicLevels.length;
+}
+
+/** Publish targets are concrete topics: strip a `mqtt:` prefix and reject wildcards. */
+export function normalizeMqttTopic(raw: string): string | undefined {
+  const topic = raw.trim().replace(/^mqtt:/i, "");
+  if (!topic || topic.includes("+") || topic.includes("#")) {
+    return undefined;
+  }
+  return topic;
+}
+
+function readString(value: unknown): string | undefined {
+  if (typeof value === "string" && value.trim()) {
+    return value.trim();
+  }
+  return typeof value === "number" ? String(value) : undefined;
+}
+
+/** Parse a command payload. Returns null for empty payloads and replies echoed back to us. */
+export function parseMqttCommand(params: {
+  account: ResolvedMqttAccount;
+  topic: string;
+  payload: Buffer;
+  properties?: MqttPacketProperties;
+}): MqttCommand | null {
+  const raw = params.payload.toString("utf8").trim();
+  if (!raw) {
+    return null;
+  }
+  let fields: Record<string, unknown> | undefined;
+  if (raw.startsWith("{")) {
+    try {
+      fields = JSON.parse(raw) as Record<string, unknown>;
+    } catch {
+      fields = undefined;
+    }
+  }
+  const text = fields ? (readString(fields.text) ?? readString(fields.message)) : raw;
+  if (!text || readString(fields?.from) === REPLY_SENDER) {
+    return null;
+  }
+  const correlationData = params.properties?.correlationData;
+  const correlationId =
+    readString(fields?.id) ??
+    readString(fields?.correlationId) ??
+    (correlationData ? correlationData.toString("utf8") : undefined);
+  const replyTopic =
+    normalizeMqttTopic(readString(fields?.replyTo) ?? "") ??
+    normalizeMqttTopic(params.properties?.responseTopic ?? "") ??
+    params.account.replyTopic;
+  return {
+    correlationId,
+    correlationData,
+    from: readString(fields?.from) ?? params.topic,
+    text,
+    replyTopic,
+    topic: params.topic,
+  };
+}
+
+/** True for reply payloads we published ourselves (`"from": "openclaw"`). */
+export function isMqttReplyPayload(raw: string): boolean {
+  if (!raw.startsWith("{")) return false;
+  try {
+    return (JSON.parse(raw) as { from?: unknown }).from === REPLY_SENDER;
+  } catch {
+    return false;
+  }
+}
+
+export function buildMqttReplyPayload(reply: MqttReply): string {
+  return JSON.stringify({
+    ...(reply.id ? { id: reply.id } : {}),
+    from: REPLY_SENDER,
+    text: reply.text,
+    ...(reply.mediaUrls?.length ? { mediaUrls: reply.mediaUrls } : {}),
+    ...(reply.error ? { error: reply.error } : {}),
+  });
+}
+
+export function isSenderAllowed(account: ResolvedMqttAccount, sender: string): boolean {
+  if (account.dmPolicy === "disabled") return false;
+  if (account.dmPolicy === "open") return true;
+  return account.allowFrom.includes("*") || account.allowFrom.includes(sender);
+}
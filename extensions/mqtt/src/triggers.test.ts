@@ -0,0 +1,120 @@
+import { describe, expect, it } from "vitest";
+import { resolveAccount } from "./accounts.js";
+import { buildTriggerPrompt, createTriggerEngine, resolveTriggers } from "./triggers.js";
+
+describe("resolveTriggers", () => {
+  it("applies defaults and drops entries without a topic", () => {
+    const triggers = resolveTriggers([
+      { topic: "garden/soil", field: "moisture", below: 20 },
+      { name: "no-topic" },
+      { name: "door", topic: "home/+/door", equals: "open", cooldownSeconds: 5 },
+    ]);
+    expect(triggers).toEqual([
+      { name: "trigger-1", topic: "garden/soil", field: "moisture", below: 20, cooldownMs: 60_000 },
+      { name: "door", topic: "home/+/door", equals: "open", cooldownMs: 5_000 },
+    ]);
+  });
+
+  it("reads triggers from account config", () => {
+    const account = resolveAccount({
+      channels: {
+        mqtt: { brokerUrl: "mqtt://broker.test", triggers: [{ topic: "alarm/#" }] },
+      },
+    });
+    expect(account.triggers.map((trigger) => trigger.topic)).toEqual(["alarm/#"]);
+  });
+});
+
+describe("createTriggerEngine", () => {
+  it("fires once when a threshold is crossed and re-arms after recovery", () => {
+    let now = 0;
+    const engine = createTriggerEngine(
+      resolveTriggers([
+        { name: "soil-dry", topic: "garden/+", field: "moisture", below: 20, cooldownSeconds: 0 },
+      ]),
+      () => now,
+    );
+    expect(engine.topics).toEqual(["garden/+"]);
+    expect(engine.evaluate("garden/soil", '{"moisture":35}')).toEqual([]);
+    const [event] = engine.evaluate("garden/soil", '{"moisture":12}');
+    expect(event).toMatchObject({ topic: "garden/soil", value: "12", condition: "below 20" });
+    now += 1000;
+    expect(engine.evaluate("garden/soil", '{"moisture":11}')).toEqual([]);
+    expect(engine.evaluate("garden/soil", '{"moisture":40}')).toEqual([]);
+    expect(engine.evaluate("garden/soil", '{"moisture":15}')).toHaveLength(1);
+  });
+
+  it("fires a condition that became true during the cooldown once the cooldown ends", () => {
+    let now = 0;
+    const engine = createTriggerEngine(
+      resolveTriggers([
+        { name: "too-hot", topic: "greenhouse/temp", above: 30, cooldownSeconds: 60 },
+      ]),
+      () => now,
+    );
+    expect(engine.evaluate("greenhouse/temp", "31")).toHaveLength(1);
+    now += 10_000;
+    expect(engine.evaluate("greenhouse/temp", "25")).toEqual([]);
+    now += 10_000;
+    expect(engine.evaluate("greenhouse/temp", "33")).toEqual([]);
+    now += 40_000;
+    expect(engine.evaluate("greenhouse/temp", "33")).toHaveLength(1);
+    now += 10_000;
+    expect(engine.evaluate("greenhouse/temp", "34")).toEqual([]);
+  });
+
+  it("applies the cooldown to unconditional triggers and ignores our own replies", () => {
+    let now = 0;
+    const engine = createTriggerEngine(
+      resolveTriggers([{ name: "doorbell", topic: "home/doorbell", cooldownSeconds: 30 }]),
+      () => now,
+    );
+    expect(engine.evaluate("home/doorbell", "ring")).toHaveLength(1);
+    now += 10_000;
+    expect(engine.evaluate("home/doorbell", "ring")).toEqual([]);
+    now += 30_000;
+    expect(engine.evaluate("home/doorbell", '{"from":"openclaw","text":"hi"}')).toEqual([]);
+    expect(engine.evaluate("home/doorbell", "ring")).toHaveLength(1);
+  });
+
+  it("matches string values with equals", () => {
+    const engine = createTriggerEngine(
+      resolveTriggers([{ topic: "home/+/door", field: "state", equals: "open" }]),
+    );
+    expect(engine.evaluate("home/back/door", '{"state":"closed"}')).toEqual([]);
+    expect(engine.evaluate("home/back/door", '{"state":"open"}')).toHaveLength(1);
+  });
+});
+
+describe("buildTriggerPrompt", () => {
+  it("describes the event and appends the configured instruction", () => {
+    const [trigger] = resolveTriggers([
+      {
+        name: "soil-dry",
+        topic: "garden/soil",
+        field: "moisture",
+        below: 20,
+        prompt: "Turn on the irrigation valve for 5 minutes.",
+      },
+    ]);
+    const text = buildTriggerPrompt({
+      trigger,
+      topic: "garden/soil",
+      value: "12",
+      payload: '{"moisture":12}',
+      condition: "below 20",
+    });
+    expect(text).toBe(
+      [
+        '[MQTT trigger "soil-dry"]',
+        "Topic: garden/soil",
+        "Field: moisture",
+        "Value: 12",
+        "Condition: below 20",
+        'Payload: {"moisture":12}',
+        "",
+        "Turn on the irrigation valve for 5 minutes.",
+      ].join("\n"),
+    );
+  });
+});
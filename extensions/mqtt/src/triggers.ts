@@ -0,0 +1,153 @@
+/**
+ * Event triggers: messages on configured topics wake the agent with a structured event prompt.
+ *
+ * Without a condition every message fires (subject to the cooldown). With `above`, `below`, or
+ * `equals` a trigger is edge-triggered: it fires when the condition becomes true and re-arms once
+ * a reading no longer matches, so a sensor stuck past its limit does not wake the agent forever.
+ */
+
+import { isMqttReplyPayload, topicMatchesFilter } from "./protocol.js";
+import type { MqttTrigger, MqttTriggerRaw } from "./types.js";
+
+const DEFAULT_COOLDOWN_SECONDS = 60;
+const MAX_PAYLOAD_CHARS = 2_000;
+
+export type MqttTriggerEvent = {
+  trigger: MqttTrigger;
+  topic: string;
+  value: string;
+  payload: string;
+  condition?: string;
+};
+
+type TriggerTopicState = {
+  /** Whether the condition matched on the previous reading. */
+  matched: boolean;
+  lastFiredAtMs?: number;
+};
+
+function readFiniteNumber(value: unknown): number | undefined {
+  return typeof value === "number" && Number.isFinite(value) ? value : undefined;
+}
+
+export function resolveTriggers(raw: MqttTriggerRaw[] | undefined): MqttTrigger[] {
+  if (!Array.isArray(raw)) return [];
+  const triggers: MqttTrigger[] = [];
+  for (const [index, entry] of raw.entries()) {
+    const topic = entry?.topic?.trim();
+    if (!topic) continue;
+    const cooldownSeconds = readFiniteNumber(entry.cooldownSeconds) ?? DEFAULT_COOLDOWN_SECONDS;
+    triggers.push({
+      name: entry.name?.trim() || `trigger-${index + 1}`,
+      topic,
+      field: entry.field?.trim() || undefined,
+      above: readFiniteNumber(entry.above),
+      below: readFiniteNumber(entry.below),
+      equals: entry.equals === undefined ? undefined : String(entry.equals),
+      prompt: entry.prompt?.trim() || undefined,
+      cooldownMs: Math.max(0, cooldownSeconds) * 1000,
+      replyTopic: entry.replyTopic?.trim() || undefined,
+    });
+  }
+  return triggers;
+}
+
+function readField(payload: string, field: string | undefined): unknown {
+  if (!field) return payload;
+  let current: unknown;
+  try {
+    current = JSON.parse(payload);
+  } catch {
+    return undefined;
+  }
+  for (const key of field.split(".")) {
+    if (!current || typeof current !== "object") return undefined;
+    current = (current as Record<string, unknown>)[key];
+  }
+  return current;
+}
+
+function hasCondition(trigger: MqttTrigger): boolean {
+  return trigger.above !== undefined || trigger.below !== undefined || trigger.equals !== undefined;
+}
+
+/** Returns the condition that matched (e.g. `below 20`), null when the reading does not match. */
+function matchCondition(trigger: MqttTrigger, value: unknown): string | null {
+  if (trigger.equals !== undefined) {
+    return value !== undefined && String(value) === trigger.equals ? `= ${trigger.equals}` : null;
+  }
+  const numeric = typeof value === "string" ? Number(value.trim()) : value;
+  if (typeof numeric !== "number" || !Number.isFinite(numeric)) return null;
+  if (trigger.above !== undefined && numeric > trigger.above) return `above ${trigger.above}`;
+  if (trigger.below !== undefined && numeric < trigger.below) return `below ${trigger.below}`;
+  return null;
+}
+
+function formatValue(value: unknown): string {
+  if (typeof value === "string") return value.trim();
+  return JSON.stringify(value) ?? "";
+}
+
+export function createTriggerEngine(triggers: MqttTrigger[], nowMs: () => number = Date.now) {
+  const states = new Map<string, TriggerTopicState>();
+
+  return {
+    /** Topic filters to subscribe to. */
+    topics: Array.from(new Set(triggers.map((trigger) => trigger.topic))),
+
+    /** Evaluate one message; returns the events that should wake the agent. */
+    evaluate(topic: string, payload: Buffer | string): MqttTriggerEvent[] {
+      const text = payload.toString().trim();
+      const events: MqttTriggerEvent[] = [];
+      // A reply topic that matches a trigger filter must not wake the agent again.
+      if (!text || isMqttReplyPayload(text)) return events;
+      for (const trigger of triggers) {
+        if (!topicMatchesFilter(trigger.topic, topic)) continue;
+        const key = `${trigger.name}\u0000${topic}`;
+        const state = states.get(key) ?? { matched: false };
+        states.set(key, state);
+        const value = readField(text, trigger.field);
+        let condition: string | undefined;
+        if (hasCondition(trigger)) {
+          const matched = matchCondition(trigger, value);
+          if (matched === null) {
+            state.matched = false;
+            continue;
+          }
+          if (state.matched) continue;
+          condition = matched;
+        } else if (value === undefined) {
+          continue;
+        }
+        const now = nowMs();
+        // Held back by the cooldown: stay un-matched so the next matching reading fires once the
+        // cooldown ends, instead of waiting for the condition to clear first.
+        if (state.lastFiredAtMs !== undefined && now - state.lastFiredAtMs < trigger.cooldownMs) {
+          continue;
+        }
+        state.matched = condition !== undefined;
+        state.lastFiredAtMs = now;
+        events.push({ trigger, topic, value: formatValue(value), payload: text, condition });
+      }
+      return events;
+    },
+  };
+}
+
+export function buildTriggerPrompt(event: MqttTriggerEvent): string {
+  const payload =
+    event.payload.length > MAX_PAYLOAD_CHARS
+      ? `${event.payload.slice(0, MAX_PAYLOAD_CHARS)}…`
+      : event.payload;
+  const lines = [
+    `[MQTT trigger "${event.trigger.name}"]`,
+    `Topic: ${event.topic}`,
+    ...(event.trigger.field ? [`Field: ${event.trigger.field}`] : []),
+    `Value: ${event.value}`,
+    ...(event.condition ? [`Condition: ${event.condition}`] : []),
+    `Payload: ${payload}`,
+    "",
+    event.trigger.prompt ?? "A device event fired. Decide whether anything needs to happen.",
+  ];
+  return lines.join("\n");
+}
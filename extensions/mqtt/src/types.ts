@@ -0,0 +1,97 @@
+/**
+ * Type definitions for the MQTT channel plugin.
+ */
+
+export type MqttDmPolicy = "open" | "allowlist" | "disabled";
+export type MqttQos = 0 | 1 | 2;
+
+/** Raw per-account config (overrides base config) */
+export interface MqttAccountRaw {
+  enabled?: boolean;
+  /** Broker URL: mqtt://, mqtts://, ws://, or wss:// (e.g. mqtt://broker.local:1883). */
+  brokerUrl?: string;
+  username?: string;
+  password?: string;
+  /** Client id (default: openclaw-<accountId>-<random>). */
+  clientId?: string;
+  /** Topic filter for incoming prompts; MQTT wildcards allowed (default: openclaw/command). */
+  commandTopic?: string;
+  /** Topic for replies when the command does not name one (default: openclaw/reply). */
+  replyTopic?: string;
+  /** QoS for subscriptions and replies (default: 1). */
+  qos?: MqttQos;
+  dmPolicy?: MqttDmPolicy;
+  /** Sender ids allowed to send commands; `"*"` allows all. */
+  allowFrom?: string | string[];
+  /** Topics that wake the agent with an event prompt (sensor readings, device events). */
+  triggers?: MqttTriggerRaw[];
+}
+
+/** Raw trigger config: fires on `topic` messages, optionally when a value crosses a limit. */
+export interface MqttTriggerRaw {
+  /** Trigger name; also the session sender id (`trigger:<name>`). */
+  name?: string;
+  /** Topic filter; MQTT wildcards allowed. */
+  topic?: string;
+  /** Dot path to the value in a JSON payload (e.g. `sensors.moisture`); default: whole payload. */
+  field?: string;
+  /** Fire when the numeric value rises above this. */
+  above?: number;
+  /** Fire when the numeric value drops below this. */
+  below?: number;
+  /** Fire when the value equals this (string compare). */
+  equals?: string | number | boolean;
+  /** Instruction appended to the event prompt. */
+  prompt?: string;
+  /** Minimum seconds between runs for one trigger + topic (default: 60). */
+  cooldownSeconds?: number;
+  /** Topic for the agent's reply (default: the account replyTopic). */
+  replyTopic?: string;
+}
+
+/** Raw channel config from openclaw.json channels.mqtt */
+export interface MqttChannelConfig extends MqttAccountRaw {
+  accounts?: Record<string, MqttAccountRaw>;
+}
+
+/** Fully resolved account config with defaults applied */
+export interface ResolvedMqttAccount {
+  accountId: string;
+  enabled: boolean;
+  brokerUrl: string;
+  username?: string;
+  password?: string;
+  clientId: string;
+  commandTopic: string;
+  replyTopic: string;
+  qos: MqttQos;
+  dmPolicy: MqttDmPolicy;
+  allowFrom: string[];
+  triggers: MqttTrigger[];
+}
+
+export interface MqttTrigger {
+  name: string;
+  topic: string;
+  field?: string;
+  above?: number;
+  below?: number;
+  equals?: string;
+  prompt?: string;
+  cooldownMs: number;
+  replyTopic?: string;
+}
+
+/** A prompt received on the command topic. */
+export interface MqttCommand {
+  /** Correlation id echoed in the reply (payload `id`, else MQTT 5 correlation data). */
+  correlationId?: string;
+  /** Raw MQTT 5 correlation data, echoed back as a reply property. */
+  correlationData?: Buffer;
+  /** Sender id: payload `from`, else the topic the command arrived on. */
+  from: string;
+  text: string;
+  /** Where the reply goes: payload `replyTo`, MQTT 5 response topic, else the account default. */
+  replyTopic: string;
+  topic: string;
+}
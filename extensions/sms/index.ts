@@ -0,0 +1,17 @@
+import type { OpenClawPluginApi } from "openclaw/plugin-sdk";
+import { emptyPluginConfigSchema } from "openclaw/plugin-sdk";
+import { createSmsPlugin } from "./src/channel.js";
+import { setSmsRuntime } from "./src/runtime.js";
+
+const plugin = {
+  id: "sms",
+  name: "SMS",
+  description: "Twilio SMS channel plugin for OpenClaw",
+  configSchema: emptyPluginConfigSchema(),
+  register(api: OpenClawPluginApi) {
+    setSmsRuntime(api.runtime);
+    api.registerChannel({ plugin: createSmsPlugin() });
+  },
+};
+
+export default plugin;
@@ -0,0 +1,82 @@
+/**
+ * Account resolution: reads config from channels.sms, merges per-account overrides,
+ * falls back to TWILIO_* environment variables for the default account.
+ */
+
+import type { OpenClawConfig } from "openclaw/plugin-sdk";
+import type { ResolvedSmsAccount, SmsChannelConfig } from "./types.js";
+
+export const DEFAULT_WEBHOOK_PATH = "/webhook/sms";
+// Twilio rejects bodies over 1600 characters, which is ten GSM-7 segments.
+const MAX_TWILIO_SEGMENTS = 10;
+
+function getChannelConfig(cfg: OpenClawConfig): SmsChannelConfig | undefined {
+  return cfg.channels?.sms as SmsChannelConfig | undefined;
+}
+
+/** Normalize to E.164 (`+` and digits). */
+export function normalizePhoneNumber(raw: string): string {
+  const digits = raw
+    .trim()
+    .replace(/^sms:/i, "")
+    .replace(/[^\d+]/g, "");
+  if (!digits) return "";
+  return digits.startsWith("+") ? `+${digits.slice(1).replace(/\+/g, "")}` : `+${digits}`;
+}
+
+function parseAllowFrom(raw: string | string[] | undefined): string[] {
+  if (!raw) return [];
+  const entries = Array.isArray(raw) ? raw : raw.split(",");
+  return entries
+    .map((entry) => (entry.trim() === "*" ? "*" : normalizePhoneNumber(entry)))
+    .filter(Boolean);
+}
+
+/**
+ * List all configured account IDs for this channel.
+ * Returns ["default"] if the base config (or env) has an account SID, plus any named accounts.
+ */
+export function listAccountIds(cfg: OpenClawConfig): string[] {
+  const channelCfg = getChannelConfig(cfg);
+  if (!channelCfg) return [];
+
+  const ids = new Set<string>();
+  if (channelCfg.accountSid || process.env.TWILIO_ACCOUNT_SID) {
+    ids.add("default");
+  }
+  for (const id of Object.keys(channelCfg.accounts ?? {})) {
+    ids.add(id);
+  }
+  return Array.from(ids);
+}
+
+/** Resolve a specific account by ID with full defaults applied. */
+export function resolveAccount(cfg: OpenClawConfig, accountId?: string | null): ResolvedSmsAccount {
+  const channelCfg = getChannelConfig(cfg) ?? {};
+  const id = accountId || "default";
+  const accountOverride = channelCfg.accounts?.[id] ?? {};
+  const env = id === "default" ? process.env : {};
+  const maxSegments =
+    accountOverride.maxSegmentsPerMessage ??
+    channelCfg.maxSegmentsPerMessage ??
+    MAX_TWILIO_SEGMENTS;
+
+  // Merge: account override > base channel config > env var
+  return {
+    accountId: id,
+    enabled: accountOverride.enabled ?? channelCfg.enabled ?? true,
+    accountSid: accountOverride.accountSid ?? channelCfg.accountSid ?? env.TWILIO_ACCOUNT_SID ?? "",
+    authToken: accountOverride.authToken ?? channelCfg.authToken ?? env.TWILIO_AUTH_TOKEN ?? "",
+    fromNumber: normalizePhoneNumber(
+      accountOverride.fromNumber ?? channelCfg.fromNumber ?? env.TWILIO_FROM_NUMBER ?? "",
+    ),
+    messagingServiceSid:
+      accountOverride.messagingServiceSid ?? channelCfg.messagingServiceSid ?? "",
+    webhookPath: accountOverride.webhookPath ?? channelCfg.webhookPath ?? DEFAULT_WEBHOOK_PATH,
+    publicUrl: accountOverride.publicUrl ?? channelCfg.publicUrl ?? "",
+    dmPolicy: accountOverride.dmPolicy ?? channelCfg.dmPolicy ?? "allowlist",
+    allowFrom: parseAllowFrom(accountOverride.allowFrom ?? channelCfg.allowFrom),
+    maxSegmentsPerMessage: Math.min(MAX_TWILIO_SEGMENTS, Math.max(1, Math.floor(maxSegments))),
+    gsmTransliterate: accountOverride.gsmTransliterate ?? channelCfg.gsmTransliterate ?? true,
+  };
+}
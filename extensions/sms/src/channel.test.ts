@@ -0,0 +1,148 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { InboundSms } from "./types.js";
+
+vi.mock("openclaw/plugin-sdk", () => ({
+  DEFAULT_ACCOUNT_ID: "default",
+  PAIRING_APPROVED_MESSAGE: "approved",
+  buildChannelConfigSchema: vi.fn((schema: unknown) => ({ schema })),
+  createReplyPrefixOptions: vi.fn(() => ({ onModelSelected: vi.fn() })),
+  formatPairingApproveHint: vi.fn((channel: string) => `approve ${channel}`),
+  registerPluginHttpRoute: vi.fn(() => () => {}),
+  setAccountEnabledInConfigSection: vi.fn(),
+}));
+
+const webhook = vi.hoisted(() => ({
+  deliver: undefined as ((message: InboundSms) => Promise<void>) | undefined,
+}));
+
+vi.mock("./webhook-handler.js", () => ({
+  createWebhookHandler: vi.fn((deps: { deliver: (message: InboundSms) => Promise<void> }) => {
+    webhook.deliver = deps.deliver;
+    return vi.fn();
+  }),
+}));
+
+vi.mock("./send.js", () => ({
+  sendSms: vi.fn(async () => ({ sid: "SM1", messages: 1, segments: 1 })),
+}));
+
+const rt = vi.hoisted(() => ({
+  config: { loadConfig: vi.fn() },
+  channel: {
+    pairing: {
+      readAllowFromStore: vi.fn(),
+      upsertPairingRequest: vi.fn(),
+      buildPairingReply: vi.fn((params: { code: string }) => `pairing code ${params.code}`),
+    },
+    routing: {
+      resolveAgentRoute: vi.fn(() => ({
+        agentId: "main",
+        accountId: "default",
+        sessionKey: "agent:main:sms:direct:+15550002222",
+      })),
+    },
+    session: {
+      resolveStorePath: vi.fn(() => "/tmp/sessions.json"),
+      readSessionUpdatedAt: vi.fn(() => undefined),
+      recordInboundSession: vi.fn(async () => {}),
+    },
+    reply: {
+      formatAgentEnvelope: vi.fn((params: { body: string }) => params.body),
+      resolveEnvelopeFormatOptions: vi.fn(() => ({})),
+      finalizeInboundContext: vi.fn((ctx: Record<string, unknown>) => ctx),
+      dispatchReplyWithBufferedBlockDispatcher: vi.fn(async () => {}),
+    },
+    media: { saveMediaBuffer: vi.fn() },
+  },
+}));
+
+vi.mock("./runtime.js", () => ({
+  getSmsRuntime: () => rt,
+}));
+
+const { sendSms } = await import("./send.js");
+const { createSmsPlugin } = await import("./channel.js");
+
+const SENDER = "+15550002222";
+
+function smsConfig(dmPolicy: string, allowFrom: string[] = []) {
+  return {
+    channels: {
+      sms: {
+        accountSid: "AC123",
+        authToken: "token",
+        fromNumber: "+15550001111",
+        publicUrl: "https://gw.example.com/webhook/sms",
+        dmPolicy,
+        allowFrom,
+      },
+    },
+  };
+}
+
+async function receive(cfg: Record<string, unknown>) {
+  rt.config.loadConfig.mockResolvedValue(cfg);
+  await createSmsPlugin().gateway.startAccount({
+    cfg,
+    accountId: "default",
+    log: { info: vi.fn(), warn: vi.fn() },
+  } as never);
+  await webhook.deliver?.({
+    messageSid: "SM0",
+    from: SENDER,
+    to: "+15550001111",
+    body: "hello",
+    media: [],
+  });
+}
+
+describe("sms channel pairing", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    webhook.deliver = undefined;
+    rt.channel.pairing.readAllowFromStore.mockResolvedValue([]);
+    rt.channel.pairing.upsertPairingRequest.mockResolvedValue({ code: "ABCD1234", created: true });
+  });
+
+  it("texts a pairing code to unknown senders once and drops the message", async () => {
+    await receive(smsConfig("pairing"));
+    expect(rt.channel.pairing.upsertPairingRequest).toHaveBeenCalledWith({
+      channel: "sms",
+      id: SENDER,
+    });
+    expect(sendSms).toHaveBeenCalledWith(expect.anything(), SENDER, {
+      text: "pairing code ABCD1234",
+    });
+    expect(rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher).not.toHaveBeenCalled();
+
+    vi.mocked(sendSms).mockClear();
+    rt.channel.pairing.upsertPairingRequest.mockResolvedValue({ code: "ABCD1234", created: false });
+    await receive(smsConfig("pairing"));
+    expect(sendSms).not.toHaveBeenCalled();
+  });
+
+  it("lets senders approved through pairing in and authorizes their commands", async () => {
+    rt.channel.pairing.readAllowFromStore.mockResolvedValue(["15550002222"]);
+    await receive(smsConfig("pairing"));
+    expect(rt.channel.pairing.upsertPairingRequest).not.toHaveBeenCalled();
+    expect(rt.channel.reply.finalizeInboundContext).toHaveBeenCalledWith(
+      expect.objectContaining({ SenderId: SENDER, CommandAuthorized: true }),
+    );
+  });
+
+  it("ignores the pairing store and sends no code under allowlist", async () => {
+    rt.channel.pairing.readAllowFromStore.mockResolvedValue([SENDER]);
+    await receive(smsConfig("allowlist"));
+    expect(rt.channel.pairing.readAllowFromStore).not.toHaveBeenCalled();
+    expect(rt.channel.pairing.upsertPairingRequest).not.toHaveBeenCalled();
+    expect(rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher).not.toHaveBeenCalled();
+  });
+
+  it("texts approved senders from the default account", async () => {
+    await createSmsPlugin().pairing.notifyApproval({
+      cfg: smsConfig("pairing") as never,
+      id: "15550002222",
+    });
+    expect(sendSms).toHaveBeenCalledWith(expect.anything(), SENDER, { text: "approved" });
+  });
+});
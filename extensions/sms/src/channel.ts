@@ -0,0 +1,419 @@
+/**
+ * Twilio SMS channel plugin for OpenClaw.
+ *
+ * Inbound via Twilio's messaging webhook, outbound via the Messages REST API.
+ */
+
+import {
+  DEFAULT_ACCOUNT_ID,
+  buildChannelConfigSchema,
+  createReplyPrefixOptions,
+  formatPairingApproveHint,
+  PAIRING_APPROVED_MESSAGE,
+  registerPluginHttpRoute,
+  setAccountEnabledInConfigSection,
+  type ChannelGatewayContext,
+  type ChannelOutboundContext,
+  type OpenClawConfig,
+} from "openclaw/plugin-sdk";
+import { z } from "zod";
+import { listAccountIds, normalizePhoneNumber, resolveAccount } from "./accounts.js";
+import { getSmsRuntime } from "./runtime.js";
+import { sendSms } from "./send.js";
+import { fetchTwilioMedia } from "./twilio.js";
+import type { InboundSms, ResolvedSmsAccount } from "./types.js";
+import { createWebhookHandler } from "./webhook-handler.js";
+
+const CHANNEL_ID = "sms";
+// Long replies are split by segment budget in send.ts; this only bounds a single payload.
+const TEXT_CHUNK_LIMIT = 10_000;
+const INBOUND_MEDIA_MAX_BYTES = 5 * 1024 * 1024;
+const SmsConfigSchema = buildChannelConfigSchema(z.object({}).passthrough());
+
+/** Config allowlist plus, under dmPolicy "pairing", numbers approved through pairing. */
+async function resolveEffectiveAllowFrom(account: ResolvedSmsAccount): Promise<string[]> {
+  if (account.dmPolicy !== "pairing") return account.allowFrom;
+  const rt = getSmsRuntime();
+  const stored = await rt.channel.pairing.readAllowFromStore(CHANNEL_ID).catch(() => []);
+  return [...account.allowFrom, ...stored.map((entry) => normalizePhoneNumber(entry))];
+}
+
+function isSenderAllowed(account: ResolvedSmsAccount, allowFrom: string[], from: string): boolean {
+  if (account.dmPolicy === "open") return true;
+  if (account.dmPolicy === "disabled") return false;
+  return allowFrom.includes("*") || allowFrom.includes(from);
+}
+
+async function sendPairingPrompt(params: {
+  account: ResolvedSmsAccount;
+  from: string;
+  log?: { warn?: (msg: string) => void };
+}): Promise<void> {
+  const rt = getSmsRuntime();
+  const { code, created } = await rt.channel.pairing.upsertPairingRequest({
+    channel: CHANNEL_ID,
+    id: params.from,
+  });
+  // Only the first message gets a code; repeats would cost a text each.
+  if (!created) return;
+  try {
+    await sendSms(params.account, params.from, {
+      text: rt.channel.pairing.buildPairingReply({
+        channel: CHANNEL_ID,
+        idLine: `Your phone number: ${params.from}`,
+        code,
+      }),
+    });
+  } catch (err) {
+    params.log?.warn?.(`SMS: pairing reply to ${params.from} failed: ${String(err)}`);
+  }
+}
+
+function isConfigured(account: ResolvedSmsAccount): boolean {
+  return Boolean(
+    account.accountSid &&
+    account.authToken &&
+    (account.fromNumber || account.messagingServiceSid),
+  );
+}
+
+async function handleInboundSms(params: {
+  account: ResolvedSmsAccount;
+  message: InboundSms;
+  log?: { info?: (msg: string) => void; warn?: (msg: string) => void };
+}): Promise<void> {
+  const { account, message, log } = params;
+  const allowFrom = await resolveEffectiveAllowFrom(account);
+  if (!isSenderAllowed(account, allowFrom, message.from)) {
+    if (account.dmPolicy === "pairing") {
+      await sendPairingPrompt({ account, from: message.from, log });
+    }
+    log?.info?.(`SMS: drop message from ${message.from} (dmPolicy=${account.dmPolicy})`);
+    return;
+  }
+
+  const rt = getSmsRuntime();
+  const cfg = await rt.config.loadConfig();
+
+  const mediaPaths: string[] = [];
+  const mediaTypes: string[] = [];
+  for (const media of message.media) {
+    try {
+      const fetched = await fetchTwilioMedia(account, media.url, INBOUND_MEDIA_MAX_BYTES);
+      const saved = await rt.channel.media.saveMediaBuffer(
+        fetched.buffer,
+        fetched.contentType ?? media.contentType,
+        "inbound",
+        INBOUND_MEDIA_MAX_BYTES,
+      );
+      mediaPaths.push(saved.path);
+      mediaTypes.push(saved.contentType ?? media.contentType ?? "application/octet-stream");
+    } catch (err) {
+      log?.warn?.(`SMS: failed to download MMS media: ${String(err)}`);
+    }
+  }
+  const rawBody = message.body.trim();
+  if (!rawBody && mediaPaths.length === 0) return;
+
+  const route = rt.channel.routing.resolveAgentRoute({
+    cfg,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+    peer: { kind: "direct", id: message.from },
+  });
+  const storePath = rt.channel.session.resolveStorePath(cfg.session?.store, {
+    agentId: route.agentId,
+  });
+  const body = rt.channel.reply.formatAgentEnvelope({
+    channel: "SMS",
+    from: message.from,
+    previousTimestamp: rt.channel.session.readSessionUpdatedAt({
+      storePath,
+      sessionKey: route.sessionKey,
+    }),
+    envelope: rt.channel.reply.resolveEnvelopeFormatOptions(cfg),
+    body: rawBody || "<media:attachment>",
+  });
+  const ctxPayload = rt.channel.reply.finalizeInboundContext({
+    Body: body,
+    BodyForAgent: rawBody,
+    RawBody: rawBody,
+    CommandBody: rawBody,
+    From: `${CHANNEL_ID}:${message.from}`,
+    To: `${CHANNEL_ID}:${message.to}`,
+    SessionKey: route.sessionKey,
+    AccountId: route.accountId,
+    ChatType: "direct",
+    ConversationLabel: message.from,
+    SenderId: message.from,
+    SenderE164: message.from,
+    CommandAuthorized: allowFrom.includes("*") || allowFrom.includes(message.from),
+    Provider: CHANNEL_ID,
+    Surface: CHANNEL_ID,
+    MessageSid: message.messageSid,
+    MediaPath: mediaPaths[0],
+    MediaType: mediaTypes[0],
+    MediaUrl: mediaPaths[0],
+    MediaPaths: mediaPaths.length > 0 ? mediaPaths : undefined,
+    MediaUrls: mediaPaths.length > 0 ? mediaPaths : undefined,
+    MediaTypes: mediaTypes.length > 0 ? mediaTypes : undefined,
+    OriginatingChannel: CHANNEL_ID,
+    OriginatingTo: `${CHANNEL_ID}:${message.from}`,
+  });
+  await rt.channel.session.recordInboundSession({
+    storePath,
+    sessionKey: ctxPayload.SessionKey ?? route.sessionKey,
+    ctx: ctxPayload,
+    onRecordError: (err) => {
+      log?.warn?.(`SMS: failed updating session meta: ${String(err)}`);
+    },
+  });
+
+  const { onModelSelected, ...prefixOptions } = createReplyPrefixOptions({
+    cfg,
+    agentId: route.agentId,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+  });
+  await rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher({
+    ctx: ctxPayload,
+    cfg,
+    dispatcherOptions: {
+      ...prefixOptions,
+      deliver: async (payload: { text?: string; mediaUrls?: string[]; mediaUrl?: string }) => {
+        const candidates = payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : []);
+        const mediaUrls = candidates.filter((url) => /^https?:\/\//i.test(url));
+        if (!payload.text?.trim() && mediaUrls.length === 0) return;
+        await sendSms(account, message.from, { text: payload.text, mediaUrls });
+      },
+      onError: (err: unknown) => {
+        log?.warn?.(`SMS: reply to ${message.from} failed: ${String(err)}`);
+      },
+    },
+    // One SMS per reply: block streaming would send partial texts as separate messages.
+    replyOptions: { onModelSelected, disableBlockStreaming: true },
+  });
+}
+
+export function createSmsPlugin() {
+  return {
+    id: CHANNEL_ID,
+
+    meta: {
+      id: CHANNEL_ID,
+      label: "SMS",
+      selectionLabel: "SMS (Twilio)",
+      detailLabel: "SMS (Twilio)",
+      docsPath: "/channels/sms",
+      blurb: "Text messages via Twilio",
+      order: 97,
+    },
+
+    capabilities: {
+      chatTypes: ["direct" as const],
+      media: true,
+      threads: false,
+      reactions: false,
+      edit: false,
+      unsend: false,
+      reply: false,
+      effects: false,
+      blockStreaming: false,
+    },
+
+    reload: { configPrefixes: [`channels.${CHANNEL_ID}`] },
+
+    configSchema: SmsConfigSchema,
+
+    pairing: {
+      idLabel: "phoneNumber",
+      normalizeAllowEntry: (entry: string) => normalizePhoneNumber(entry),
+      notifyApproval: async ({ cfg, id }: { cfg: OpenClawConfig; id: string }) => {
+        const account = resolveAccount(cfg);
+        if (!isConfigured(account)) return;
+        await sendSms(account, normalizePhoneNumber(id), { text: PAIRING_APPROVED_MESSAGE });
+      },
+    },
+
+    config: {
+      listAccountIds: (cfg: OpenClawConfig) => listAccountIds(cfg),
+
+      resolveAccount: (cfg: OpenClawConfig, accountId?: string | null) =>
+        resolveAccount(cfg, accountId),
+
+      defaultAccountId: (_cfg: OpenClawConfig) => DEFAULT_ACCOUNT_ID,
+
+      setAccountEnabled: ({
+        cfg,
+        accountId,
+        enabled,
+      }: {
+        cfg: OpenClawConfig;
+        accountId: string;
+        enabled: boolean;
+      }) => {
+        const channelConfig = cfg?.channels?.[CHANNEL_ID] ?? {};
+        if (accountId === DEFAULT_ACCOUNT_ID) {
+          return {
+            ...cfg,
+            channels: {
+              ...cfg.channels,
+              [CHANNEL_ID]: { ...channelConfig, enabled },
+            },
+          };
+        }
+        return setAccountEnabledInConfigSection({
+          cfg,
+          sectionKey: `channels.${CHANNEL_ID}`,
+          accountId,
+          enabled,
+        });
+      },
+    },
+
+    security: {
+      resolveDmPolicy: ({
+        cfg,
+        accountId,
+        account,
+      }: {
+        cfg: OpenClawConfig;
+        accountId?: string | null;
+        account: ResolvedSmsAccount;
+      }) => {
+        const resolvedAccountId = accountId ?? account.accountId ?? DEFAULT_ACCOUNT_ID;
+        const useAccountPath = Boolean(cfg?.channels?.sms?.accounts?.[resolvedAccountId]);
+        const basePath = useAccountPath
+          ? `channels.sms.accounts.${resolvedAccountId}.`
+          : "channels.sms.";
+        return {
+          policy: account.dmPolicy,
+          allowFrom: account.allowFrom,
+          policyPath: `${basePath}dmPolicy`,
+          allowFromPath: basePath,
+          approveHint: formatPairingApproveHint(CHANNEL_ID),
+          normalizeEntry: (raw: string) => normalizePhoneNumber(raw),
+        };
+      },
+      collectWarnings: ({ account }: { account: ResolvedSmsAccount }) => {
+        const warnings: string[] = [];
+        if (!isConfigured(account)) {
+          warnings.push(
+            "- SMS: accountSid, authToken, and fromNumber (or messagingServiceSid) are required.",
+          );
+        }
+        if (!account.publicUrl) {
+          warnings.push(
+            "- SMS: publicUrl is not set. Signature checks rebuild the URL from request headers, which fails behind proxies that rewrite Host.",
+          );
+        }
+        if (account.dmPolicy === "open") {
+          warnings.push(
+            '- SMS: dmPolicy="open" lets any phone number talk to the agent (and costs money per reply). Consider "allowlist".',
+          );
+        }
+        return warnings;
+      },
+    },
+
+    messaging: {
+      normalizeTarget: (target: string) => normalizePhoneNumber(target) || undefined,
+      targetResolver: {
+        looksLikeId: (id: string) => /^(sms:)?\+?\d{7,15}$/i.test(id?.trim() ?? ""),
+        hint: "<E.164 phone number>",
+      },
+    },
+
+    directory: {
+      self: async () => null,
+      listPeers: async () => [],
+      listGroups: async () => [],
+    },
+
+    outbound: {
+      deliveryMode: "direct" as const,
+      textChunkLimit: TEXT_CHUNK_LIMIT,
+
+      sendText: async ({ cfg, to, text, accountId }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        if (!isConfigured(account)) {
+          throw new Error("SMS (Twilio) is not configured");
+        }
+        const target = normalizePhoneNumber(to);
+        const result = await sendSms(account, target, { text });
+        return { channel: CHANNEL_ID, messageId: result.sid, chatId: target };
+      },
+
+      sendMedia: async ({ cfg, to, text, mediaUrl, accountId }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        if (!isConfigured(account)) {
+          throw new Error("SMS (Twilio) is not configured");
+        }
+        const target = normalizePhoneNumber(to);
+        const result = await sendSms(account, target, {
+          text,
+          mediaUrls: mediaUrl ? [mediaUrl] : [],
+        });
+        return { channel: CHANNEL_ID, messageId: result.sid, chatId: target };
+      },
+    },
+
+    gateway: {
+      startAccount: async (ctx: ChannelGatewayContext<ResolvedSmsAccount>) => {
+        const { cfg, accountId, log } = ctx;
+        const account = resolveAccount(cfg, accountId);
+
+        if (!account.enabled) {
+          log?.info?.(`SMS account ${accountId} is disabled, skipping`);
+          return { stop: () => {} };
+        }
+
+        if (!isConfigured(account)) {
+          log?.warn?.(
+            `SMS account ${accountId} not fully configured (missing accountSid, authToken, or fromNumber)`,
+          );
+          return { stop: () => {} };
+        }
+
+        log?.info?.(`Starting SMS channel (account: ${accountId}, path: ${account.webhookPath})`);
+
+        const handler = createWebhookHandler({
+          account,
+          deliver: (message) => handleInboundSms({ account, message, log }),
+          log,
+        });
+
+        const unregister = registerPluginHttpRoute({
+          path: account.webhookPath,
+          pluginId: CHANNEL_ID,
+          accountId: account.accountId,
+          log: (msg: string) => log?.info?.(msg),
+          handler,
+        });
+
+        return {
+          stop: () => {
+            log?.info?.(`Stopping SMS channel (account: ${accountId})`);
+            if (typeof unregister === "function") unregister();
+          },
+        };
+      },
+
+      stopAccount: async (ctx: ChannelGatewayContext<ResolvedSmsAccount>) => {
+        ctx.log?.info?.(`SMS account ${ctx.accountId} stopped`);
+      },
+    },
+
+    agentPrompt: {
+      messageToolHints: () => [
+        "",
+        "### SMS Formatting",
+        "SMS is plain text: no Markdown, bold, tables, or code blocks.",
+        "Keep replies short. Every 153 characters is another billed segment, and emoji or",
+        "non-Latin characters cut that to 67.",
+        "Lead with the essential fact (e.g. 'Freezer 2: -4°C, above -15°C threshold').",
+        "Images must be public https URLs; local files cannot be sent by SMS.",
+      ],
+    },
+  };
+}
@@ -0,0 +1,19 @@
+/**
+ * Plugin runtime singleton.
+ * Stores the PluginRuntime from api.runtime (set during register()).
+ */
+
+import type { PluginRuntime } from "openclaw/plugin-sdk";
+
+let runtime: PluginRuntime | null = null;
+
+export function setSmsRuntime(r: PluginRuntime): void {
+  runtime = r;
+}
+
+export function getSmsRuntime(): PluginRuntime {
+  if (!runtime) {
+    throw new Error("SMS runtime not initialized - plugin not registered");
+  }
+  return runtime;
+}
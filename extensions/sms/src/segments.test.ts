@@ -0,0 +1,78 @@
+import { describe, expect, it } from "vitest";
+import {
+  countSmsSegments,
+  isGsm7,
+  markdownToSmsText,
+  splitSmsText,
+  transliterateToGsm7,
+} from "./segments.js";
+
+describe("countSmsSegments", () => {
+  it("counts GSM-7 single and concatenated segments", () => {
+    expect(countSmsSegments("a".repeat(160))).toEqual({
+      encoding: "GSM-7",
+      units: 160,
+      segments: 1,
+    });
+    expect(countSmsSegments("a".repeat(161)).segments).toBe(2);
+    expect(countSmsSegments("a".repeat(306)).segments).toBe(2);
+    expect(countSmsSegments("a".repeat(307)).segments).toBe(3);
+  });
+
+  it("counts extension characters as two septets", () => {
+    expect(countSmsSegments("€".repeat(80))).toMatchObject({ units: 160, segments: 1 });
+    expect(countSmsSegments("€".repeat(81)).segments).toBe(2);
+  });
+
+  it("switches to UCS-2 for characters outside GSM-7", () => {
+    expect(isGsm7("Freezer 2: -4°C")).toBe(false);
+    expect(countSmsSegments("é".repeat(70)).encoding).toBe("GSM-7");
+    expect(countSmsSegments(`${"a".repeat(69)}✓`)).toEqual({
+      encoding: "UCS-2",
+      units: 70,
+      segments: 1,
+    });
+    expect(countSmsSegments(`${"a".repeat(70)}✓`).segments).toBe(2);
+    // Emoji are surrogate pairs: two UTF-16 units each.
+    expect(countSmsSegments("🔥").units).toBe(2);
+  });
+});
+
+describe("transliterateToGsm7", () => {
+  it("replaces typographic punctuation with GSM-7 equivalents", () => {
+    const text = transliterateToGsm7("“Door” opened — it’s 3…");
+    expect(text).toBe('"Door" opened - it\'s 3...');
+    expect(isGsm7(text)).toBe(true);
+  });
+});
+
+describe("markdownToSmsText", () => {
+  it("strips markup and keeps link targets", () => {
+    expect(
+      markdownToSmsText("## Alert\n**Freezer** is `warm`\n* see [dashboard](https://x.test)"),
+    ).toBe("Alert\n\nFreezer is warm\n\n- see dashboard (https://x.test)");
+  });
+});
+
+describe("splitSmsText", () => {
+  it("keeps short text as one message", () => {
+    expect(splitSmsText("  hello  ", 1)).toEqual(["hello"]);
+    expect(splitSmsText("   ", 1)).toEqual([]);
+  });
+
+  it("splits at word boundaries within the segment budget", () => {
+    const words = Array.from({ length: 60 }, (_, i) => `word${i}`).join(" ");
+    const messages = splitSmsText(words, 1);
+    expect(messages.length).toBeGreaterThan(1);
+    for (const message of messages) {
+      expect(countSmsSegments(message).segments).toBe(1);
+      expect(message).not.toMatch(/^\s|\s$/);
+    }
+    expect(messages.join(" ")).toBe(words);
+  });
+
+  it("cuts words longer than a message", () => {
+    const messages = splitSmsText("x".repeat(400), 1);
+    expect(messages.map((m) => m.length)).toEqual([160, 160, 80]);
+  });
+});
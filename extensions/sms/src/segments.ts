@@ -0,0 +1,145 @@
+/**
+ * SMS segmentation: GSM-7 vs UCS-2 detection, segment counting, and splitting long replies
+ * into messages that stay within a segment budget.
+ */
+
+import { markdownToPlainText } from "openclaw/plugin-sdk";
+
+const GSM7_BASIC =
+  "@£$¥èéùìòÇ\nØø\rÅåΔ_ΦΓΛΩΠΨΣΘΞÆæßÉ !\"#¤%&'()*+,-./0123456789:;<=>?" +
+  "¡ABCDEFGHIJKLMNOPQRSTUVWXYZÄÖÑÜ§¿abcdefghijklmnopqrstuvwxyzäöñüà";
+// Extension table characters cost two septets (escape + char).
+const GSM7_EXTENDED = "^{}\\[~]|€\f";
+
+const GSM7_BASIC_SET = new Set(GSM7_BASIC);
+const GSM7_EXTENDED_SET = new Set(GSM7_EXTENDED);
+
+const GSM7_SINGLE = 160;
+const GSM7_MULTI = 153;
+const UCS2_SINGLE = 70;
+const UCS2_MULTI = 67;
+
+export type SmsEncoding = "GSM-7" | "UCS-2";
+
+export type SmsSegmentInfo = {
+  encoding: SmsEncoding;
+  /** Septets for GSM-7, UTF-16 code units for UCS-2. */
+  units: number;
+  segments: number;
+};
+
+// A single non-GSM character (curly quote, em dash) switches the whole message to UCS-2 and
+// more than halves the characters per segment.
+const GSM7_TRANSLITERATIONS: Record<string, string> = {
+  "‘": "'",
+  "’": "'",
+  "‚": "'",
+  "‛": "'",
+  "′": "'",
+  "“": '"',
+  "”": '"',
+  "„": '"',
+  "″": '"',
+  "–": "-",
+  "—": "-",
+  "−": "-",
+  "…": "...",
+  "\u00a0": " ",
+  "\u2009": " ",
+  "\u202f": " ",
+  "\t": " ",
+  "•": "*",
+  "·": "*",
+  "\u200b": "",
+  "\ufeff": "",
+};
+
+export function transliterateToGsm7(text: string): string {
+  return Array.from(text, (char) => GSM7_TRANSLITERATIONS[char] ?? char).join("");
+}
+
+export function isGsm7(text: string): boolean {
+  for (const char of text) {
+    if (!GSM7_BASIC_SET.has(char) && !GSM7_EXTENDED_SET.has(char)) {
+      return false;
+    }
+  }
+  return true;
+}
+
+export function countSmsSegments(text: string): SmsSegmentInfo {
+  if (isGsm7(text)) {
+    let units = 0;
+    for (const char of text) {
+      units += GSM7_EXTENDED_SET.has(char) ? 2 : 1;
+    }
+    const segments = units <= GSM7_SINGLE ? 1 : Math.ceil(units / GSM7_MULTI);
+    return { encoding: "GSM-7", units, segments };
+  }
+  const units = text.length;
+  const segments = units <= UCS2_SINGLE ? 1 : Math.ceil(units / UCS2_MULTI);
+  return { encoding: "UCS-2", units, segments };
+}
+
+/**
+ * Reduce Markdown to plain text: SMS has no formatting and shows markup literally. "-" bullets
+ * keep lists in GSM-7.
+ */
+export function markdownToSmsText(markdown: string): string {
+  return markdownToPlainText(markdown, { bullet: "-" });
+}
+
+function fits(text: string, maxSegments: number): boolean {
+  return countSmsSegments(text).segments <= maxSegments;
+}
+
+function hardSplit(token: string, maxSegments: number): string[] {
+  const parts: string[] = [];
+  let current = "";
+  for (const char of token) {
+    if (current && !fits(current + char, maxSegments)) {
+      parts.push(current);
+      current = "";
+    }
+    current += char;
+  }
+  if (current) parts.push(current);
+  return parts;
+}
+
+/**
+ * Split text into messages of at most `maxSegments` segments each, breaking at whitespace
+ * (words longer than a message are cut). The carrier reassembles segments within a message;
+ * separate messages may arrive out of order, so callers should keep this budget generous.
+ */
+export function splitSmsText(text: string, maxSegments: number): string[] {
+  const trimmed = text.trim();
+  if (!trimmed) return [];
+  if (fits(trimmed, maxSegments)) return [trimmed];
+
+  const messages: string[] = [];
+  let current = "";
+  const flush = () => {
+    const value = current.trim();
+    if (value) messages.push(value);
+    current = "";
+  };
+  for (const token of trimmed.split(/(\s+)/)) {
+    if (!token) continue;
+    if (fits(current + token, maxSegments)) {
+      current += token;
+      continue;
+    }
+    flush();
+    if (/^\s+$/.test(token)) continue;
+    if (fits(token, maxSegments)) {
+      current = token;
+      continue;
+    }
+    const pieces = hardSplit(token, maxSegments);
+    messages.push(...pieces.slice(0, -1));
+    current = pieces.at(-1) ?? "";
+  }
+  flush();
+  return messages;
+}
@@ -0,0 +1,52 @@
+/**
+ * Outbound SMS: Markdown to plain text, optional GSM-7 transliteration, segment-aware split.
+ */
+
+import {
+  countSmsSegments,
+  markdownToSmsText,
+  splitSmsText,
+  transliterateToGsm7,
+} from "./segments.js";
+import { sendTwilioMessage } from "./twilio.js";
+import type { ResolvedSmsAccount } from "./types.js";
+
+export function prepareSmsMessages(account: ResolvedSmsAccount, text: string): string[] {
+  const plain = markdownToSmsText(text);
+  const normalized = account.gsmTransliterate ? transliterateToGsm7(plain) : plain;
+  return splitSmsText(normalized, account.maxSegmentsPerMessage);
+}
+
+/**
+ * Send text (and optional public media URLs) as one or more SMS. Media rides on the first
+ * message. Returns the SID of the last message sent.
+ */
+export async function sendSms(
+  account: ResolvedSmsAccount,
+  to: string,
+  params: { text?: string; mediaUrls?: string[] },
+): Promise<{ sid: string; messages: number; segments: number }> {
+  const messages = prepareSmsMessages(account, params.text ?? "");
+  const mediaUrls = params.mediaUrls ?? [];
+  for (const mediaUrl of mediaUrls) {
+    if (!/^https?:\/\//i.test(mediaUrl)) {
+      throw new Error(`SMS media must be a public http(s) URL (got ${mediaUrl})`);
+    }
+  }
+  if (messages.length === 0 && mediaUrls.length === 0) {
+    throw new Error("SMS send requires text or media");
+  }
+  const bodies = messages.length > 0 ? messages : [""];
+  let sid = "";
+  let segments = 0;
+  for (const [index, body] of bodies.entries()) {
+    const result = await sendTwilioMessage(account, {
+      to,
+      body,
+      mediaUrls: index === 0 ? mediaUrls : undefined,
+    });
+    sid = result.sid;
+    segments += body ? countSmsSegments(body).segments : 0;
+  }
+  return { sid, messages: bodies.length, segments };
+}
@@ -0,0 +1,100 @@
+/**
+ * Twilio Messaging REST client and webhook signature validation.
+ */
+
+import * as crypto from "node:crypto";
+import type { ResolvedSmsAccount } from "./types.js";
+
+const TWILIO_API_BASE = "https://api.twilio.com/2010-04-01";
+
+function basicAuth(account: ResolvedSmsAccount): string {
+  return `Basic ${Buffer.from(`${account.accountSid}:${account.authToken}`).toString("base64")}`;
+}
+
+/**
+ * Send one SMS (or MMS when mediaUrls are given). Twilio concatenates up to 10 segments
+ * into a single message on the handset; callers split longer text first.
+ */
+export async function sendTwilioMessage(
+  account: ResolvedSmsAccount,
+  params: { to: string; body: string; mediaUrls?: string[] },
+): Promise<{ sid: string }> {
+  const form = new URLSearchParams();
+  form.append("To", params.to);
+  if (account.messagingServiceSid) {
+    form.append("MessagingServiceSid", account.messagingServiceSid);
+  } else {
+    form.append("From", account.fromNumber);
+  }
+  if (params.body) {
+    form.append("Body", params.body);
+  }
+  for (const mediaUrl of params.mediaUrls ?? []) {
+    form.append("MediaUrl", mediaUrl);
+  }
+
+  const res = await fetch(
+    `${TWILIO_API_BASE}/Accounts/${encodeURIComponent(account.accountSid)}/Messages.json`,
+    {
+      method: "POST",
+      headers: {
+        Authorization: basicAuth(account),
+        "Content-Type": "application/x-www-form-urlencoded",
+      },
+      body: form,
+    },
+  );
+  const payload = (await res.json().catch(() => ({}))) as {
+    sid?: string;
+    code?: number;
+    message?: string;
+  };
+  if (!res.ok) {
+    const code = payload.code ? ` (code ${payload.code})` : "";
+    throw new Error(`Twilio API error ${res.status}${code}: ${payload.message ?? res.statusText}`);
+  }
+  return { sid: payload.sid ?? `sms-${Date.now()}` };
+}
+
+/** Download MMS media; Twilio media URLs require account credentials. */
+export async function fetchTwilioMedia(
+  account: ResolvedSmsAccount,
+  url: string,
+  maxBytes: number,
+): Promise<{ buffer: Buffer; contentType?: string }> {
+  const res = await fetch(url, { headers: { Authorization: basicAuth(account) } });
+  if (!res.ok) {
+    throw new Error(`Twilio media download failed: ${res.status}`);
+  }
+  const buffer = Buffer.from(await res.arrayBuffer());
+  if (buffer.byteLength > maxBytes) {
+    throw new Error(`Twilio media exceeds ${Math.round(maxBytes / (1024 * 1024))}MB limit`);
+  }
+  return { buffer, contentType: res.headers.get("content-type") ?? undefined };
+}
+
+/**
+ * Validate X-Twilio-Signature: base64 HMAC-SHA1 over the full webhook URL followed by
+ * the POST parameters sorted by name (key + value, no separators).
+ */
+export function validateTwilioSignature(
+  authToken: string,
+  signature: string | undefined,
+  url: string,
+  params: URLSearchParams,
+): boolean {
+  if (!signature || !authToken) {
+    return false;
+  }
+  let dataToSign = url;
+  const sortedParams = Array.from(params.entries()).toSorted((a, b) =>
+    a[0] < b[0] ? -1 : a[0] > b[0] ? 1 : 0,
+  );
+  for (const [key, value] of sortedParams) {
+    dataToSign += key + value;
+  }
+  const expected = crypto.createHmac("sha1", authToken).update(dataToSign).digest("base64");
+  const a = Buffer.from(signature);
+  const b = Buffer.from(expected);
+  return a.length === b.length && crypto.timingSafeEqual(a, b);
+}
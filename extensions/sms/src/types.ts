@@ -0,0 +1,58 @@
+/**
+ * Type definitions for the Twilio SMS channel plugin.
+ */
+
+export type SmsDmPolicy = "open" | "allowlist" | "pairing" | "disabled";
+
+/** Raw per-account config (overrides base config) */
+export interface SmsAccountRaw {
+  enabled?: boolean;
+  accountSid?: string;
+  authToken?: string;
+  /** Sending number in E.164 form. */
+  fromNumber?: string;
+  /** Messaging Service SID; used instead of fromNumber when set. */
+  messagingServiceSid?: string;
+  webhookPath?: string;
+  /**
+   * Public URL Twilio calls for this webhook (e.g. https://gw.example.com/webhook/sms).
+   * Needed for signature checks behind reverse proxies that rewrite Host or protocol.
+   */
+  publicUrl?: string;
+  dmPolicy?: SmsDmPolicy;
+  allowFrom?: string | string[];
+  /** Max segments per outbound SMS before splitting into another message (1-10, default 10). */
+  maxSegmentsPerMessage?: number;
+  /** Replace curly quotes, dashes, and similar characters so text stays in GSM-7 (default true). */
+  gsmTransliterate?: boolean;
+}
+
+/** Raw channel config from openclaw.json channels.sms */
+export interface SmsChannelConfig extends SmsAccountRaw {
+  accounts?: Record<string, SmsAccountRaw>;
+}
+
+/** Fully resolved account config with defaults applied */
+export interface ResolvedSmsAccount {
+  accountId: string;
+  enabled: boolean;
+  accountSid: string;
+  authToken: string;
+  fromNumber: string;
+  messagingServiceSid: string;
+  webhookPath: string;
+  publicUrl: string;
+  dmPolicy: SmsDmPolicy;
+  allowFrom: string[];
+  maxSegmentsPerMessage: number;
+  gsmTransliterate: boolean;
+}
+
+/** Inbound SMS/MMS from a Twilio messaging webhook. */
+export interface InboundSms {
+  messageSid: string;
+  from: string;
+  to: string;
+  body: string;
+  media: Array<{ url: string; contentType?: string }>;
+}
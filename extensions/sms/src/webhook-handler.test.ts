@@ -0,0 +1,142 @@
+import * as crypto from "node:crypto";
+import { EventEmitter } from "node:events";
+import type { IncomingMessage, ServerResponse } from "node:http";
+import { describe, expect, it, vi } from "vitest";
+import type { ResolvedSmsAccount } from "./types.js";
+import { createWebhookHandler, parseInboundSms, resolveSignedUrl } from "./webhook-handler.js";
+
+const PUBLIC_URL = "https://gw.example.com/webhook/sms";
+
+function makeAccount(overrides: Partial<ResolvedSmsAccount> = {}): ResolvedSmsAccount {
+  return {
+    accountId: "default",
+    enabled: true,
+    accountSid: "AC123",
+    authToken: "auth-token",
+    fromNumber: "+15550001111",
+    messagingServiceSid: "",
+    webhookPath: "/webhook/sms",
+    publicUrl: PUBLIC_URL,
+    dmPolicy: "open",
+    allowFrom: [],
+    maxSegmentsPerMessage: 10,
+    gsmTransliterate: true,
+    ...overrides,
+  };
+}
+
+function sign(url: string, params: URLSearchParams, token = "auth-token"): string {
+  const data =
+    url +
+    Array.from(params.entries())
+      .toSorted((a, b) => (a[0] < b[0] ? -1 : a[0] > b[0] ? 1 : 0))
+      .map(([key, value]) => key + value)
+      .join("");
+  return crypto.createHmac("sha1", token).update(data).digest("base64");
+}
+
+function makeReq(
+  method: string,
+  body: string,
+  headers: Record<string, string> = {},
+  url = "/webhook/sms",
+): IncomingMessage {
+  const req = new EventEmitter() as IncomingMessage;
+  req.method = method;
+  req.url = url;
+  req.headers = headers;
+  req.socket = { remoteAddress: "127.0.0.1" } as any;
+  process.nextTick(() => {
+    req.emit("data", Buffer.from(body));
+    req.emit("end");
+  });
+  return req;
+}
+
+function makeRes(): ServerResponse & { _status: number; _body: string } {
+  const res = {
+    _status: 0,
+    _body: "",
+    writeHead(statusCode: number) {
+      res._status = statusCode;
+    },
+    end(body?: string) {
+      res._body = body ?? "";
+    },
+  } as any;
+  return res;
+}
+
+const inbound = new URLSearchParams({
+  MessageSid: "SM1",
+  From: "+15552223333",
+  To: "+15550001111",
+  Body: "status?",
+  NumMedia: "1",
+  MediaUrl0: "https://api.twilio.com/media/ME1",
+  MediaContentType0: "image/jpeg",
+});
+
+describe("parseInboundSms", () => {
+  it("extracts sender, body, and MMS media", () => {
+    expect(parseInboundSms(inbound)).toEqual({
+      messageSid: "SM1",
+      from: "+15552223333",
+      to: "+15550001111",
+      body: "status?",
+      media: [{ url: "https://api.twilio.com/media/ME1", contentType: "image/jpeg" }],
+    });
+    expect(parseInboundSms(new URLSearchParams({ Body: "x" }))).toBeNull();
+  });
+});
+
+describe("resolveSignedUrl", () => {
+  it("uses publicUrl, else forwarded headers", () => {
+    const req = makeReq("POST", "", { host: "internal:18789" }, "/webhook/sms?x=1");
+    expect(resolveSignedUrl(req, makeAccount())).toBe(PUBLIC_URL + "?x=1");
+    const proxied = makeReq("POST", "", {
+      host: "internal:18789",
+      "x-forwarded-proto": "https",
+      "x-forwarded-host": "sms.example.com",
+    });
+    expect(resolveSignedUrl(proxied, makeAccount({ publicUrl: "" }))).toBe(
+      "https://sms.example.com/webhook/sms",
+    );
+  });
+});
+
+describe("createWebhookHandler", () => {
+  it("acknowledges signed requests with empty TwiML and delivers the message", async () => {
+    const deliver = vi.fn().mockResolvedValue(undefined);
+    const handler = createWebhookHandler({ account: makeAccount(), deliver });
+    const res = makeRes();
+    await handler(
+      makeReq("POST", inbound.toString(), { "x-twilio-signature": sign(PUBLIC_URL, inbound) }),
+      res,
+    );
+    expect(res._status).toBe(200);
+    expect(res._body).toContain("<Response></Response>");
+    expect(deliver).toHaveBeenCalledWith(expect.objectContaining({ from: "+15552223333" }));
+  });
+
+  it("rejects requests with a bad signature", async () => {
+    const deliver = vi.fn();
+    const handler = createWebhookHandler({ account: makeAccount(), deliver });
+    const res = makeRes();
+    await handler(
+      makeReq("POST", inbound.toString(), {
+        "x-twilio-signature": sign(PUBLIC_URL, inbound, "wrong-token"),
+      }),
+      res,
+    );
+    expect(res._status).toBe(403);
+    expect(deliver).not.toHaveBeenCalled();
+  });
+
+  it("rejects non-POST requests", async () => {
+    const handler = createWebhookHandler({ account: makeAccount(), deliver: vi.fn() });
+    const res = makeRes();
+    await handler(makeReq("GET", ""), res);
+    expect(res._status).toBe(405);
+  });
+});
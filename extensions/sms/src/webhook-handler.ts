@@ -0,0 +1,131 @@
+/**
+ * Inbound webhook handler for Twilio Messaging ("A message comes in").
+ * Validates X-Twilio-Signature, acknowledges with empty TwiML, and delivers asynchronously.
+ */
+
+import type { IncomingMessage, ServerResponse } from "node:http";
+import { normalizePhoneNumber } from "./accounts.js";
+import { validateTwilioSignature } from "./twilio.js";
+import type { InboundSms, ResolvedSmsAccount } from "./types.js";
+
+const MAX_BODY_BYTES = 65_536;
+const EMPTY_TWIML = '<?xml version="1.0" encoding="UTF-8"?><Response></Response>';
+
+function readBody(req: IncomingMessage): Promise<string> {
+  return new Promise((resolve, reject) => {
+    const chunks: Buffer[] = [];
+    let size = 0;
+    req.on("data", (chunk: Buffer) => {
+      size += chunk.length;
+      if (size > MAX_BODY_BYTES) {
+        req.destroy();
+        reject(new Error("Request body too large"));
+        return;
+      }
+      chunks.push(chunk);
+    });
+    req.on("end", () => resolve(Buffer.concat(chunks).toString("utf-8")));
+    req.on("error", reject);
+  });
+}
+
+function headerValue(value: string | string[] | undefined): string | undefined {
+  return Array.isArray(value) ? value[0] : value;
+}
+
+/**
+ * The URL Twilio signed. Prefer the configured public URL; otherwise rebuild it from
+ * forwarded headers so signatures verify behind a TLS-terminating proxy.
+ */
+export function resolveSignedUrl(req: IncomingMessage, account: ResolvedSmsAccount): string {
+  const pathAndQuery = req.url ?? account.webhookPath;
+  if (account.publicUrl) {
+    const base = new URL(account.publicUrl);
+    const query = pathAndQuery.includes("?") ? pathAndQuery.slice(pathAndQuery.indexOf("?")) : "";
+    return `${base.origin}${base.pathname}${base.search || query}`;
+  }
+  const proto = headerValue(req.headers["x-forwarded-proto"])?.split(",")[0]?.trim() || "https";
+  const host =
+    headerValue(req.headers["x-forwarded-host"])?.split(",")[0]?.trim() || req.headers.host;
+  return `${proto}://${host}${pathAndQuery}`;
+}
+
+export function parseInboundSms(params: URLSearchParams): InboundSms | null {
+  const messageSid = params.get("MessageSid") ?? params.get("SmsSid") ?? "";
+  const from = normalizePhoneNumber(params.get("From") ?? "");
+  if (!messageSid || !from) return null;
+  const numMedia = Math.min(10, Number.parseInt(params.get("NumMedia") ?? "0", 10) || 0);
+  const media: InboundSms["media"] = [];
+  for (let i = 0; i < numMedia; i++) {
+    const url = params.get(`MediaUrl${i}`);
+    if (url) {
+      media.push({ url, contentType: params.get(`MediaContentType${i}`) ?? undefined });
+    }
+  }
+  return {
+    messageSid,
+    from,
+    to: normalizePhoneNumber(params.get("To") ?? ""),
+    body: params.get("Body") ?? "",
+    media,
+  };
+}
+
+export interface WebhookHandlerDeps {
+  account: ResolvedSmsAccount;
+  deliver: (msg: InboundSms) => Promise<void>;
+  log?: {
+    info: (...args: unknown[]) => void;
+    warn: (...args: unknown[]) => void;
+    error: (...args: unknown[]) => void;
+  };
+}
+
+export function createWebhookHandler(deps: WebhookHandlerDeps) {
+  const { account, deliver, log } = deps;
+
+  return async (req: IncomingMessage, res: ServerResponse) => {
+    if (req.method !== "POST") {
+      res.writeHead(405, { "Content-Type": "text/plain" });
+      res.end("Method not allowed");
+      return;
+    }
+
+    let body: string;
+    try {
+      body = await readBody(req);
+    } catch (err) {
+      log?.error("Failed to read request body", err);
+      res.writeHead(400, { "Content-Type": "text/plain" });
+      res.end("Invalid request body");
+      return;
+    }
+
+    const params = new URLSearchParams(body);
+    const signature = headerValue(req.headers["x-twilio-signature"]);
+    if (
+      !validateTwilioSignature(account.authToken, signature, resolveSignedUrl(req, account), params)
+    ) {
+      log?.warn(`Invalid Twilio signature from ${req.socket?.remoteAddress}`);
+      res.writeHead(403, { "Content-Type": "text/plain" });
+      res.end("Invalid signature");
+      return;
+    }
+
+    // Reply through the REST API, not TwiML, so slow agent turns don't hit Twilio's timeout.
+    res.writeHead(200, { "Content-Type": "text/xml" });
+    res.end(EMPTY_TWIML);
+
+    const message = parseInboundSms(params);
+    if (!message) {
+      log?.warn("Twilio webhook without MessageSid/From");
+      return;
+    }
+    try {
+      await deliver(message);
+    } catch (err) {
+      const errMsg = err instanceof Error ? err.message : String(err);
+      log?.error(`Failed to process SMS ${message.messageSid}: ${errMsg}`);
+    }
+  };
+}
@@ -0,0 +1,17 @@
+import type { OpenClawPluginApi } from "openclaw/plugin-sdk";
+import { emptyPluginConfigSchema } from "openclaw/plugin-sdk";
+import { createSshPlugin } from "./src/channel.js";
+import { setSshRuntime } from "./src/runtime.js";
+
+const plugin = {
+  id: "ssh",
+  name: "SSH",
+  description: "SSH channel plugin for OpenClaw (built-in server with public-key auth)",
+  configSchema: emptyPluginConfigSchema(),
+  register(api: OpenClawPluginApi) {
+    setSshRuntime(api.runtime);
+    api.registerChannel({ plugin: createSshPlugin() });
+  },
+};
+
+export default plugin;
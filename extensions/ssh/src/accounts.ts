@@ -0,0 +1,39 @@
+/**
+ * Account resolution: reads config from channels.ssh. The channel runs one SSH server,
+ * so there is exactly one account ("default").
+ */
+
+import type { OpenClawConfig } from "openclaw/plugin-sdk";
+import type { ResolvedSshAccount, SshChannelConfig } from "./types.js";
+
+const DEFAULT_PORT = 2222;
+
+function getChannelConfig(cfg: OpenClawConfig): SshChannelConfig | undefined {
+  return cfg.channels?.ssh as SshChannelConfig | undefined;
+}
+
+export function listAccountIds(cfg: OpenClawConfig): string[] {
+  return getChannelConfig(cfg) ? ["default"] : [];
+}
+
+/** Resolve the SSH account with full defaults applied. */
+export function resolveAccount(cfg: OpenClawConfig, accountId?: string | null): ResolvedSshAccount {
+  const channelCfg = getChannelConfig(cfg) ?? {};
+  const authorizedKeys = (channelCfg.authorizedKeys ?? [])
+    .map((entry) => entry.trim())
+    .filter(Boolean);
+  const authorizedKeysFile = channelCfg.authorizedKeysFile?.trim() || undefined;
+  const port = channelCfg.port;
+
+  return {
+    accountId: accountId || "default",
+    enabled: channelCfg.enabled ?? true,
+    configured: authorizedKeys.length > 0 || Boolean(authorizedKeysFile),
+    host: channelCfg.host?.trim() || "0.0.0.0",
+    port: typeof port === "number" && Number.isInteger(port) && port > 0 ? port : DEFAULT_PORT,
+    hostKeyPath: channelCfg.hostKeyPath?.trim() || undefined,
+    authorizedKeys,
+    authorizedKeysFile,
+    banner: channelCfg.banner?.trim() || undefined,
+  };
+}
@@ -0,0 +1,301 @@
+/**
+ * SSH channel plugin for OpenClaw.
+ *
+ * Runs an SSH server inside the Gateway. Holders of an authorized key get a line-mode
+ * chat with the agent (`ssh robot@board`) or a one-shot answer
+ * (`ssh robot@board "what's on my calendar?"`), with no client software beyond ssh.
+ * Each key gets its own session; the login name is ignored.
+ */
+
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import {
+  DEFAULT_ACCOUNT_ID,
+  buildChannelConfigSchema,
+  createReplyPrefixOptions,
+  type ChannelGatewayContext,
+  type ChannelOutboundContext,
+  type OpenClawConfig,
+} from "openclaw/plugin-sdk";
+import { z } from "zod";
+import { listAccountIds, resolveAccount } from "./accounts.js";
+import { parseAuthorizedKeys, sshKeyLabel } from "./keys.js";
+import { getSshRuntime } from "./runtime.js";
+import { ensureHostKey, startSshServer, type SshChatSession } from "./server.js";
+import type { ResolvedSshAccount, SshAuthorizedKey } from "./types.js";
+
+const CHANNEL_ID = "ssh";
+const TEXT_CHUNK_LIMIT = 16_000;
+const SshConfigSchema = buildChannelConfigSchema(z.object({}).passthrough());
+
+type Log = { info?: (msg: string) => void; warn?: (msg: string) => void };
+
+// Open shell sessions of the running server, for outbound delivery.
+let activeSessions: () => SshChatSession[] = () => [];
+
+function expandHome(file: string): string {
+  return file.startsWith("~/") ? path.join(os.homedir(), file.slice(2)) : file;
+}
+
+async function loadAuthorizedKeys(
+  account: ResolvedSshAccount,
+  log?: { warn?: (msg: string) => void },
+): Promise<SshAuthorizedKey[]> {
+  const lines = [...account.authorizedKeys];
+  if (account.authorizedKeysFile) {
+    try {
+      const text = await fs.readFile(expandHome(account.authorizedKeysFile), "utf8");
+      lines.push(...text.split(/\r?\n/));
+    } catch (err) {
+      if ((err as NodeJS.ErrnoException).code !== "ENOENT") throw err;
+    }
+  }
+  return parseAuthorizedKeys(lines, {
+    onRejected: (line, reason) => log?.warn?.(`SSH: skipping key (${reason}): ${line.trim()}`),
+  });
+}
+
+async function handlePrompt(params: {
+  account: ResolvedSshAccount;
+  key: SshAuthorizedKey;
+  text: string;
+  reply: (text: string) => void;
+  log?: Log;
+}): Promise<void> {
+  const { account, key, text, log } = params;
+  const rt = getSshRuntime();
+  const cfg = await rt.config.loadConfig();
+  const senderId = key.fingerprint;
+  const label = sshKeyLabel(key);
+  const route = rt.channel.routing.resolveAgentRoute({
+    cfg,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+    peer: { kind: "direct", id: senderId },
+  });
+  const storePath = rt.channel.session.resolveStorePath(cfg.session?.store, {
+    agentId: route.agentId,
+  });
+  const body = rt.channel.reply.formatAgentEnvelope({
+    channel: "SSH",
+    from: label,
+    timestamp: Date.now(),
+    previousTimestamp: rt.channel.session.readSessionUpdatedAt({
+      storePath,
+      sessionKey: route.sessionKey,
+    }),
+    envelope: rt.channel.reply.resolveEnvelopeFormatOptions(cfg),
+    body: text,
+  });
+  const ctxPayload = rt.channel.reply.finalizeInboundContext({
+    Body: body,
+    BodyForAgent: text,
+    RawBody: text,
+    CommandBody: text,
+    From: `${CHANNEL_ID}:${senderId}`,
+    To: `${CHANNEL_ID}:${senderId}`,
+    SessionKey: route.sessionKey,
+    AccountId: route.accountId,
+    ChatType: "direct",
+    ConversationLabel: label,
+    SenderId: senderId,
+    SenderName: key.comment,
+    // Only holders of an authorized key get this far; they are operators of the Gateway.
+    CommandAuthorized: true,
+    Provider: CHANNEL_ID,
+    Surface: CHANNEL_ID,
+    OriginatingChannel: CHANNEL_ID,
+    OriginatingTo: `${CHANNEL_ID}:${senderId}`,
+  });
+  await rt.channel.session.recordInboundSession({
+    storePath,
+    sessionKey: route.sessionKey,
+    ctx: ctxPayload,
+    onRecordError: (err) => {
+      log?.warn?.(`SSH: failed updating session meta: ${String(err)}`);
+    },
+  });
+
+  const { onModelSelected, ...prefixOptions } = createReplyPrefixOptions({
+    cfg,
+    agentId: route.agentId,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+  });
+  await rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher({
+    ctx: ctxPayload,
+    cfg,
+    dispatcherOptions: {
+      ...prefixOptions,
+      deliver: async (payload: { text?: string; mediaUrls?: string[]; mediaUrl?: string }) => {
+        const media = payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : []);
+        const lines = [payload.text?.trim(), ...media.map((url) => `Attachment: ${url}`)];
+        const out = lines.filter(Boolean).join("\n");
+        if (out) params.reply(out);
+      },
+      onError: (err: unknown) => {
+        log?.warn?.(`SSH: reply to ${label} failed: ${String(err)}`);
+      },
+    },
+    replyOptions: { onModelSelected },
+  });
+}
+
+function findSessions(target: string): SshChatSession[] {
+  const wanted = target.trim();
+  return activeSessions().filter(
+    (session) => session.key.fingerprint === wanted || session.key.comment === wanted,
+  );
+}
+
+export function createSshPlugin() {
+  return {
+    id: CHANNEL_ID,
+
+    meta: {
+      id: CHANNEL_ID,
+      label: "SSH",
+      selectionLabel: "SSH (built-in server, public-key auth)",
+      detailLabel: "SSH (built-in server, public-key auth)",
+      docsPath: "/channels/ssh",
+      blurb: "Chat with the agent from any terminal: ssh into the gateway with an authorized key",
+      order: 100,
+    },
+
+    capabilities: {
+      chatTypes: ["direct" as const],
+      media: false,
+      threads: false,
+      reactions: false,
+      edit: false,
+      unsend: false,
+      reply: true,
+      effects: false,
+      blockStreaming: true,
+    },
+
+    reload: { configPrefixes: [`channels.${CHANNEL_ID}`] },
+
+    configSchema: SshConfigSchema,
+
+    config: {
+      listAccountIds: (cfg: OpenClawConfig) => listAccountIds(cfg),
+
+      resolveAccount: (cfg: OpenClawConfig, accountId?: string | null) =>
+        resolveAccount(cfg, accountId),
+
+      defaultAccountId: (_cfg: OpenClawConfig) => DEFAULT_ACCOUNT_ID,
+
+      isConfigured: (account: ResolvedSshAccount) => account.configured,
+
+      setAccountEnabled: ({ cfg, enabled }: { cfg: OpenClawConfig; enabled: boolean }) => ({
+        ...cfg,
+        channels: {
+          ...cfg.channels,
+          [CHANNEL_ID]: { ...cfg?.channels?.[CHANNEL_ID], enabled },
+        },
+      }),
+    },
+
+    security: {
+      collectWarnings: ({ account }: { account: ResolvedSshAccount }) => {
+        const warnings: string[] = [];
+        if (!account.configured) {
+          warnings.push(
+            "- SSH: no authorized keys (channels.ssh.authorizedKeys or authorizedKeysFile). The server will not start.",
+          );
+        }
+        if (account.host !== "127.0.0.1" && account.host !== "::1") {
+          warnings.push(
+            `- SSH: the server listens on ${account.host}:${account.port}. Every authorized key can run owner commands; keep the list short.`,
+          );
+        }
+        return warnings;
+      },
+    },
+
+    messaging: {
+      normalizeTarget: (target: string) => target.trim() || undefined,
+      targetResolver: {
+        looksLikeId: (id: string) => Boolean(id?.trim()),
+        hint: "<key comment or SHA256 fingerprint>",
+      },
+    },
+
+    directory: {
+      self: async () => null,
+      listPeers: async () => [],
+      listGroups: async () => [],
+    },
+
+    outbound: {
+      deliveryMode: "direct" as const,
+      textChunkLimit: TEXT_CHUNK_LIMIT,
+
+      // Messages (cron, message tool) print in every open terminal of that key.
+      sendText: async ({ to, text }: ChannelOutboundContext) => {
+        const sessions = findSessions(to);
+        if (sessions.length === 0) {
+          throw new Error(`SSH: no open session for "${to}"`);
+        }
+        for (const session of sessions) session.notify(text);
+        return { channel: CHANNEL_ID, messageId: `ssh-${Date.now()}`, chatId: to };
+      },
+    },
+
+    gateway: {
+      startAccount: async (ctx: ChannelGatewayContext<ResolvedSshAccount>) => {
+        const { cfg, accountId, log } = ctx;
+        const account = resolveAccount(cfg, accountId);
+
+        if (!account.enabled) {
+          log?.info?.(`SSH account ${accountId} is disabled, skipping`);
+          return { stop: () => {} };
+        }
+
+        if (!account.configured) {
+          log?.warn?.(`SSH account ${accountId} has no authorized keys, skipping`);
+          return { stop: () => {} };
+        }
+
+        const rt = getSshRuntime();
+        const hostKeyPath = account.hostKeyPath
+          ? expandHome(account.hostKeyPath)
+          : path.join(rt.state.resolveStateDir(), "ssh", "host_ed25519");
+        const hostKey = await ensureHostKey(hostKeyPath, log);
+        const server = await startSshServer({
+          account,
+          hostKey,
+          loadKeys: () => loadAuthorizedKeys(account, log),
+          onPrompt: ({ session, text, reply }) =>
+            handlePrompt({ account, key: session.key, text, reply, log }),
+          log,
+        });
+        activeSessions = server.sessions;
+        log?.info?.(`Starting SSH channel on ${account.host}:${account.port}`);
+
+        return {
+          stop: async () => {
+            log?.info?.("Stopping SSH channel");
+            activeSessions = () => [];
+            await server.stop();
+          },
+        };
+      },
+
+      stopAccount: async (ctx: ChannelGatewayContext<ResolvedSshAccount>) => {
+        ctx.log?.info?.(`SSH account ${ctx.accountId} stopped`);
+      },
+    },
+
+    agentPrompt: {
+      messageToolHints: () => [
+        "",
+        "### SSH Formatting",
+        "Replies on the ssh channel are printed as plain text in a terminal.",
+        "Markdown is not rendered: prefer short paragraphs and simple `-` lists; avoid tables.",
+      ],
+    },
+  };
+}
@@ -0,0 +1,85 @@
+import { createHash } from "node:crypto";
+import { describe, expect, it } from "vitest";
+import {
+  findAuthorizedKey,
+  fingerprintSshKey,
+  hasAuthorizedKeyOptions,
+  parseAuthorizedKeyLine,
+  parseAuthorizedKeys,
+  sshKeyLabel,
+} from "./keys.js";
+
+function sshString(value: Buffer): Buffer {
+  const length = Buffer.alloc(4);
+  length.writeUInt32BE(value.length);
+  return Buffer.concat([length, value]);
+}
+
+function ed25519Blob(fill: number): Buffer {
+  return Buffer.concat([
+    sshString(Buffer.from("ssh-ed25519")),
+    sshString(Buffer.alloc(32, fill)),
+  ]);
+}
+
+const BLOB_A = ed25519Blob(1);
+const BLOB_B = ed25519Blob(2);
+const LINE_A = `ssh-ed25519 ${BLOB_A.toString("base64")} alice@laptop`;
+const LINE_B = `ssh-ed25519 ${BLOB_B.toString("base64")}`;
+
+describe("fingerprintSshKey", () => {
+  it("matches the unpadded base64 SHA256 format printed by ssh-keygen -l", () => {
+    const digest = createHash("sha256").update(BLOB_A).digest("base64").replace(/=+$/, "");
+    expect(fingerprintSshKey(BLOB_A)).toBe(`SHA256:${digest}`);
+    expect(fingerprintSshKey(BLOB_A)).not.toContain("=");
+  });
+});
+
+describe("parseAuthorizedKeyLine", () => {
+  it("parses type, blob, and comment", () => {
+    const key = parseAuthorizedKeyLine(LINE_A);
+    expect(key).toMatchObject({ type: "ssh-ed25519", comment: "alice@laptop" });
+    expect(key?.blob.equals(BLOB_A)).toBe(true);
+    expect(key?.fingerprint).toBe(fingerprintSshKey(BLOB_A));
+  });
+
+  it("rejects keys with options it cannot enforce", () => {
+    expect(parseAuthorizedKeyLine(`no-pty,command="echo hi there" ${LINE_A}`)).toBeNull();
+    expect(parseAuthorizedKeyLine(`from="10.0.0.0/8" ${LINE_B}`)).toBeNull();
+    expect(hasAuthorizedKeyOptions(`from="10.0.0.0/8" ${LINE_B}`)).toBe(true);
+    expect(hasAuthorizedKeyOptions(LINE_A)).toBe(false);
+  });
+
+  it("ignores comments, blank lines, and keys whose blob does not match the type", () => {
+    expect(parseAuthorizedKeyLine("# ssh-ed25519 AAAA")).toBeNull();
+    expect(parseAuthorizedKeyLine("   ")).toBeNull();
+    expect(parseAuthorizedKeyLine(`ssh-rsa ${BLOB_A.toString("base64")}`)).toBeNull();
+    expect(parseAuthorizedKeyLine("ssh-ed25519 AAAA")).toBeNull();
+  });
+});
+
+describe("parseAuthorizedKeys", () => {
+  it("parses a file and drops duplicates", () => {
+    const keys = parseAuthorizedKeys(`# team\n${LINE_A}\r\n\n${LINE_B}\n${LINE_A} again\n`);
+    expect(keys.map((key) => key.fingerprint)).toEqual([
+      fingerprintSshKey(BLOB_A),
+      fingerprintSshKey(BLOB_B),
+    ]);
+  });
+
+  it("reports keys skipped for carrying options", () => {
+    const rejected: string[] = [];
+    const keys = parseAuthorizedKeys([`command="uptime" ${LINE_A}`, LINE_B], {
+      onRejected: (line) => rejected.push(line),
+    });
+    expect(keys.map((key) => key.fingerprint)).toEqual([fingerprintSshKey(BLOB_B)]);
+    expect(rejected).toEqual([`command="uptime" ${LINE_A}`]);
+  });
+
+  it("finds the key offered by a client and labels it", () => {
+    const keys = parseAuthorizedKeys([LINE_A, LINE_B]);
+    expect(sshKeyLabel(findAuthorizedKey(keys, BLOB_A)!)).toBe("alice@laptop");
+    expect(sshKeyLabel(findAuthorizedKey(keys, BLOB_B)!)).toBe(fingerprintSshKey(BLOB_B));
+    expect(findAuthorizedKey(keys, ed25519Blob(3))).toBeUndefined();
+  });
+});
@@ -0,0 +1,81 @@
+/**
+ * authorized_keys parsing and key fingerprints.
+ */
+
+import { createHash } from "node:crypto";
+import type { SshAuthorizedKey } from "./types.js";
+
+const KEY_LINE_RE = /^((?:ssh|ecdsa|sk)-[\w@.-]+)\s+([A-Za-z0-9+/]+={0,2})(?:\s+(.*))?$/;
+// A key preceded by options (`command="..."`, `from="..."`, `no-pty`, ...).
+const KEY_WITH_OPTIONS_RE = /\s(?:ssh|ecdsa|sk)-[\w@.-]+\s+[A-Za-z0-9+/]+={0,2}(?:\s|$)/;
+
+/** OpenSSH-style SHA256 fingerprint of a public key blob. */
+export function fingerprintSshKey(blob: Buffer): string {
+  const digest = createHash("sha256").update(blob).digest("base64");
+  return `SHA256:${digest.replace(/=+$/, "")}`;
+}
+
+/** True when an authorized_keys line carries options before the key type. */
+export function hasAuthorizedKeyOptions(line: string): boolean {
+  const trimmed = line.trim();
+  return !KEY_LINE_RE.test(trimmed) && KEY_WITH_OPTIONS_RE.test(trimmed);
+}
+
+/**
+ * Parse one authorized_keys line; comments, blanks, and malformed lines return null.
+ * Options are not enforced, so keys that carry them are rejected rather than granted
+ * wider access than the line describes.
+ */
+export function parseAuthorizedKeyLine(line: string): SshAuthorizedKey | null {
+  const trimmed = line.trim();
+  if (!trimmed || trimmed.startsWith("#")) return null;
+  const match = KEY_LINE_RE.exec(trimmed);
+  if (!match) return null;
+  const [, type, base64, comment] = match;
+  const blob = Buffer.from(base64!, "base64");
+  // The blob starts with its own key type; a mismatch means a truncated or pasted-wrong key.
+  if (blob.length < 4) return null;
+  const typeLength = blob.readUInt32BE(0);
+  if (blob.subarray(4, 4 + typeLength).toString("ascii") !== type) return null;
+  return {
+    type: type!,
+    base64: base64!,
+    blob,
+    comment: comment?.trim() || undefined,
+    fingerprint: fingerprintSshKey(blob),
+  };
+}
+
+/** Parse authorized_keys text (or a list of lines) into keys, dropping duplicates. */
+export function parseAuthorizedKeys(
+  input: string | string[],
+  params?: { onRejected?: (line: string, reason: string) => void },
+): SshAuthorizedKey[] {
+  const lines = Array.isArray(input) ? input : input.split(/\r?\n/);
+  const keys: SshAuthorizedKey[] = [];
+  const seen = new Set<string>();
+  for (const line of lines) {
+    if (hasAuthorizedKeyOptions(line)) {
+      params?.onRejected?.(line, "authorized_keys options are not supported");
+      continue;
+    }
+    const key = parseAuthorizedKeyLine(line);
+    if (!key || seen.has(key.fingerprint)) continue;
+    seen.add(key.fingerprint);
+    keys.push(key);
+  }
+  return keys;
+}
+
+/** Find the authorized key matching a public key blob offered by a client. */
+export function findAuthorizedKey(
+  keys: SshAuthorizedKey[],
+  blob: Buffer,
+): SshAuthorizedKey | undefined {
+  return keys.find((key) => key.blob.equals(blob));
+}
+
+/** Human label for a key: its comment, else the fingerprint. */
+export function sshKeyLabel(key: SshAuthorizedKey): string {
+  return key.comment ?? key.fingerprint;
+}
@@ -0,0 +1,208 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import ssh2 from "ssh2";
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { parseAuthorizedKeyLine } from "./keys.js";
+import {
+  ensureHostKey,
+  startSshServer,
+  toTerminalText,
+  verifySignature,
+  type SshChatSession,
+} from "./server.js";
+import type { ResolvedSshAccount, SshAuthorizedKey } from "./types.js";
+
+const hostKey = ssh2.utils.generateKeyPairSync("ed25519");
+const alice = ssh2.utils.generateKeyPairSync("ed25519", { comment: "alice@laptop" });
+const mallory = ssh2.utils.generateKeyPairSync("ed25519", { comment: "mallory" });
+const aliceKey = parseAuthorizedKeyLine(alice.public)!;
+
+const account: ResolvedSshAccount = {
+  accountId: "default",
+  enabled: true,
+  configured: true,
+  host: "127.0.0.1",
+  port: 0,
+  authorizedKeys: [alice.public],
+};
+
+type Server = Awaited<ReturnType<typeof startSshServer>>;
+let server: Server | undefined;
+
+afterEach(async () => {
+  await server?.stop();
+  server = undefined;
+});
+
+async function startServer(params?: {
+  keys?: SshAuthorizedKey[];
+  onPrompt?: Parameters<typeof startSshServer>[0]["onPrompt"];
+}): Promise<Server> {
+  server = await startSshServer({
+    account,
+    hostKey: hostKey.private,
+    loadKeys: async () => params?.keys ?? [aliceKey],
+    onPrompt:
+      params?.onPrompt ??
+      (async ({ text, reply }) => {
+        reply(`echo: ${text}`);
+      }),
+  });
+  return server;
+}
+
+function connect(port: number, privateKey: string): Promise<ssh2.Client> {
+  return new Promise((resolve, reject) => {
+    const client = new ssh2.Client();
+    client.once("ready", () => resolve(client));
+    client.once("error", reject);
+    client.connect({ host: "127.0.0.1", port, username: "robot", privateKey });
+  });
+}
+
+function exec(client: ssh2.Client, command: string): Promise<{ stdout: string; code: number }> {
+  return new Promise((resolve, reject) => {
+    client.exec(command, (err, stream) => {
+      if (err) {
+        reject(err);
+        return;
+      }
+      let stdout = "";
+      stream.on("data", (chunk: Buffer) => (stdout += chunk.toString()));
+      stream.stderr.on("data", () => {});
+      stream.on("close", (code: number) => resolve({ stdout, code }));
+    });
+  });
+}
+
+describe("verifySignature", () => {
+  const data = Buffer.from("session-id and auth request");
+  const sign = (privateKey: string, payload: Buffer) =>
+    (ssh2.utils.parseKey(privateKey) as ssh2.ParsedKey).sign(payload);
+
+  it("accepts a signature made by the authorized key", () => {
+    expect(verifySignature(aliceKey, { blob: data, signature: sign(alice.private, data) })).toBe(
+      true,
+    );
+  });
+
+  it("rejects signatures from another key, over other data, or missing", () => {
+    expect(verifySignature(aliceKey, { blob: data, signature: sign(mallory.private, data) })).toBe(
+      false,
+    );
+    const other = Buffer.from("something else");
+    expect(verifySignature(aliceKey, { blob: other, signature: sign(alice.private, data) })).toBe(
+      false,
+    );
+    expect(verifySignature(aliceKey, { blob: data })).toBe(false);
+  });
+});
+
+describe("startSshServer", () => {
+  it("runs a one-shot prompt for an authorized key", async () => {
+    const onPrompt = vi.fn(
+      async ({ text, reply }: { text: string; reply: (chunk: string) => void }) => {
+        reply(`echo: ${text}`);
+      },
+    );
+    const { port } = await startServer({ onPrompt });
+    const client = await connect(port, alice.private);
+    try {
+      const result = await exec(client, "is the garage door closed?");
+      expect(result).toEqual({ stdout: "echo: is the garage door closed?\n", code: 0 });
+      expect(onPrompt).toHaveBeenCalledWith(
+        expect.objectContaining({
+          text: "is the garage door closed?",
+          session: expect.objectContaining({ key: aliceKey }),
+        }),
+      );
+    } finally {
+      client.end();
+    }
+  });
+
+  it("rejects keys that are not authorized", async () => {
+    const { port } = await startServer();
+    await expect(connect(port, mallory.private)).rejects.toThrow(/authentication/i);
+  });
+
+  it("rejects every key when none are authorized", async () => {
+    const { port } = await startServer({ keys: [] });
+    await expect(connect(port, alice.private)).rejects.toThrow(/authentication/i);
+  });
+
+  it("exits non-zero for an empty command or a failing prompt", async () => {
+    const { port } = await startServer({
+      onPrompt: async () => {
+        throw new Error("agent unavailable");
+      },
+    });
+    const client = await connect(port, alice.private);
+    try {
+      expect((await exec(client, "   ")).code).toBe(2);
+      expect((await exec(client, "hello")).code).toBe(1);
+    } finally {
+      client.end();
+    }
+  });
+
+  it("tracks shell sessions, delivers notifications, and closes on /exit", async () => {
+    const srv = await startServer();
+    const client = await connect(srv.port, alice.private);
+    let openSessions: SshChatSession[] = [];
+    try {
+      const output = await new Promise<string>((resolve, reject) => {
+        client.shell(false, (err, stream) => {
+          if (err) {
+            reject(err);
+            return;
+          }
+          let out = "";
+          let step = 0;
+          stream.on("data", (chunk: Buffer) => {
+            out += chunk.toString();
+            if (step === 0 && out.includes("/exit or Ctrl-D")) {
+              step = 1;
+              openSessions = srv.sessions();
+              openSessions[0]?.notify("reminder: water the plants");
+              stream.write("hello\n");
+            } else if (step === 1 && out.includes("echo: hello")) {
+              step = 2;
+              stream.write("/exit\n");
+            }
+          });
+          stream.on("close", () => resolve(out));
+        });
+      });
+      expect(openSessions.map((session) => session.key)).toEqual([aliceKey]);
+      expect(output).toContain("reminder: water the plants\n");
+      expect(output).toContain("echo: hello\n");
+      expect(output).toContain("bye\n");
+      await vi.waitFor(() => expect(srv.sessions()).toHaveLength(0));
+    } finally {
+      client.end();
+    }
+  });
+});
+
+describe("ensureHostKey", () => {
+  it("generates a host key once and reuses it", async () => {
+    const dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-ssh-"));
+    try {
+      const file = path.join(dir, "ssh", "host_ed25519");
+      const first = await ensureHostKey(file);
+      expect(first).toContain("PRIVATE KEY");
+      expect(await ensureHostKey(file)).toBe(first);
+      expect(await fs.readFile(`${file}.pub`, "utf8")).toMatch(/^ssh-ed25519 /);
+    } finally {
+      await fs.rm(dir, { recursive: true, force: true });
+    }
+  });
+});
+
+describe("toTerminalText", () => {
+  it("converts newlines to CRLF", () => {
+    expect(toTerminalText("a\nb\r\nc")).toBe("a\r\nb\r\nc");
+  });
+});
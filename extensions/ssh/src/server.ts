@@ -0,0 +1,298 @@
+/**
+ * Embedded SSH server: public-key auth against the configured authorized keys, then
+ * either an interactive line-mode chat (`ssh robot@board`) or a one-shot prompt
+ * (`ssh robot@board "is the garage door closed?"`). No shell or port forwarding.
+ */
+
+import { randomUUID } from "node:crypto";
+import fs from "node:fs/promises";
+import type { AddressInfo } from "node:net";
+import path from "node:path";
+import { createInterface } from "node:readline";
+import ssh2 from "ssh2";
+import { findAuthorizedKey } from "./keys.js";
+import type { ResolvedSshAccount, SshAuthorizedKey } from "./types.js";
+
+type Log = { info?: (msg: string) => void; warn?: (msg: string) => void };
+
+type SessionStream = ssh2.ServerChannel & { columns?: number; rows?: number };
+
+/** One logged-in shell session; used to route outbound messages to open terminals. */
+export interface SshChatSession {
+  id: string;
+  key: SshAuthorizedKey;
+  /** Print text above the prompt without disturbing the line being typed. */
+  notify: (text: string) => void;
+}
+
+export type SshPromptHandler = (params: {
+  session: { id: string; key: SshAuthorizedKey };
+  text: string;
+  /** Write part of the reply; called once per reply block. */
+  reply: (text: string) => void;
+}) => Promise<void>;
+
+const PROMPT = "> ";
+const EXIT_COMMANDS = new Set(["/exit", "/quit", "/logout"]);
+
+/** Terminals in raw mode need CRLF; bare LF walks the cursor down without returning. */
+export function toTerminalText(text: string): string {
+  return text.replace(/\r?\n/g, "\r\n");
+}
+
+/** Load the host key, generating an ed25519 key on first start. */
+export async function ensureHostKey(file: string, log?: Log): Promise<string> {
+  try {
+    return await fs.readFile(file, "utf8");
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code !== "ENOENT") throw err;
+  }
+  const pair = ssh2.utils.generateKeyPairSync("ed25519");
+  await fs.mkdir(path.dirname(file), { recursive: true });
+  await fs.writeFile(file, pair.private, { mode: 0o600 });
+  await fs.writeFile(`${file}.pub`, `${pair.public}\n`, { mode: 0o644 });
+  log?.info?.(`SSH: generated host key ${file}`);
+  return pair.private;
+}
+
+/** Check a publickey auth signature against the authorized key (not the key the client sent). */
+export function verifySignature(
+  key: SshAuthorizedKey,
+  ctx: { blob?: Buffer; signature?: Buffer; hashAlgo?: string },
+): boolean {
+  const parsed = ssh2.utils.parseKey(`${key.type} ${key.base64}`);
+  const publicKey = Array.isArray(parsed) ? parsed[0] : parsed;
+  if (!publicKey || publicKey instanceof Error || !ctx.blob || !ctx.signature) return false;
+  return publicKey.verify(ctx.blob, ctx.signature, ctx.hashAlgo) === true;
+}
+
+function runShell(params: {
+  stream: SessionStream;
+  terminal: boolean;
+  key: SshAuthorizedKey;
+  banner?: string;
+  onPrompt: SshPromptHandler;
+  sessions: Map<string, SshChatSession>;
+  log?: Log;
+}): void {
+  const { stream, terminal, key } = params;
+  const id = randomUUID();
+  const write = (text: string) => {
+    if (stream.writable) stream.write(terminal ? toTerminalText(text) : text);
+  };
+  const rl = createInterface({ input: stream, output: stream, terminal, prompt: PROMPT });
+  let busy = false;
+  let queue: Promise<void> = Promise.resolve();
+
+  const notify = (text: string) => {
+    if (terminal) write("\r\x1b[K");
+    write(`${text.trim()}\n`);
+    if (!busy) rl.prompt(true);
+  };
+  params.sessions.set(id, { id, key, notify });
+
+  const runPrompt = async (text: string) => {
+    busy = true;
+    let wrote = false;
+    try {
+      await params.onPrompt({
+        session: { id, key },
+        text,
+        reply: (chunk) => {
+          write(`${wrote ? "\n" : ""}${chunk.trim()}\n`);
+          wrote = true;
+        },
+      });
+    } catch (err) {
+      write(`error: ${String(err)}\n`);
+    } finally {
+      busy = false;
+    }
+    rl.prompt();
+  };
+
+  rl.on("line", (line) => {
+    const text = line.trim();
+    if (EXIT_COMMANDS.has(text.toLowerCase())) {
+      rl.close();
+      return;
+    }
+    if (!text) {
+      if (!busy) rl.prompt();
+      return;
+    }
+    queue = queue.then(() => runPrompt(text));
+  });
+  rl.on("SIGINT", () => {
+    write(busy ? "^C (still waiting for the reply; /exit to leave)\n" : "^C\n");
+    if (!busy) rl.prompt();
+  });
+  rl.on("close", () => {
+    params.sessions.delete(id);
+    if (stream.writable) {
+      write("bye\n");
+      stream.exit(0);
+      stream.end();
+    }
+  });
+  stream.on("close", () => {
+    params.sessions.delete(id);
+    rl.close();
+  });
+
+  if (params.banner) write(`${params.banner}\n`);
+  write("Type a message and press Enter. /exit or Ctrl-D to leave.\n");
+  rl.prompt();
+  params.log?.info?.(`SSH: session opened for ${key.comment ?? key.fingerprint}`);
+}
+
+async function runExec(params: {
+  stream: SessionStream;
+  command: string;
+  key: SshAuthorizedKey;
+  onPrompt: SshPromptHandler;
+}): Promise<void> {
+  const { stream } = params;
+  const text = params.command.trim();
+  let code = 0;
+  if (text) {
+    try {
+      await params.onPrompt({
+        session: { id: randomUUID(), key: params.key },
+        text,
+        reply: (chunk) => stream.write(`${chunk.trim()}\n`),
+      });
+    } catch (err) {
+      stream.stderr.write(`error: ${String(err)}\n`);
+      code = 1;
+    }
+  } else {
+    stream.stderr.write("usage: ssh <host> <message>\n");
+    code = 2;
+  }
+  stream.exit(code);
+  stream.end();
+}
+
+/** Start the SSH server; resolves once it is listening. */
+export async function startSshServer(params: {
+  account: ResolvedSshAccount;
+  hostKey: string;
+  /** Called on every login, so edits to authorized_keys apply without a restart. */
+  loadKeys: () => Promise<SshAuthorizedKey[]>;
+  onPrompt: SshPromptHandler;
+  log?: Log;
+}): Promise<{
+  /** Bound port; differs from account.port when that is 0. */
+  port: number;
+  sessions: () => SshChatSession[];
+  stop: () => Promise<void>;
+}> {
+  const { account, log } = params;
+  const sessions = new Map<string, SshChatSession>();
+  const clients = new Set<ssh2.Connection>();
+
+  const server = new ssh2.Server({ hostKeys: [params.hostKey] }, (client, info) => {
+    clients.add(client);
+    let authorized: SshAuthorizedKey | undefined;
+
+    client.on("authentication", (ctx) => {
+      if (ctx.method !== "publickey") {
+        ctx.reject(["publickey"]);
+        return;
+      }
+      params.loadKeys().then(
+        (keys) => {
+          const key = findAuthorizedKey(keys, ctx.key.data);
+          if (!key) {
+            ctx.reject(["publickey"]);
+            return;
+          }
+          // Without a signature the client only asks whether this key would be accepted.
+          if (!ctx.signature) {
+            ctx.accept();
+            return;
+          }
+          if (!verifySignature(key, ctx)) {
+            ctx.reject(["publickey"]);
+            return;
+          }
+          authorized = key;
+          ctx.accept();
+        },
+        (err) => {
+          log?.warn?.(`SSH: failed loading authorized keys: ${String(err)}`);
+          ctx.reject();
+        },
+      );
+    });
+
+    client.on("ready", () => {
+      const key = authorized!;
+      log?.info?.(`SSH: ${key.comment ?? key.fingerprint} logged in from ${info.ip}`);
+      client.on("session", (acceptSession) => {
+        const session = acceptSession();
+        let pty: { cols: number; rows: number } | null = null;
+        let stream: SessionStream | null = null;
+
+        session.on("pty", (accept, _reject, ptyInfo) => {
+          pty = { cols: ptyInfo.cols, rows: ptyInfo.rows };
+          accept?.();
+        });
+        session.on("window-change", (accept, _reject, size) => {
+          pty = { cols: size.cols, rows: size.rows };
+          if (stream) {
+            // readline wraps long input using the output's column count.
+            stream.columns = size.cols;
+            stream.rows = size.rows;
+            stream.emit("resize");
+          }
+          accept?.();
+        });
+        session.on("shell", (accept) => {
+          stream = accept() as SessionStream;
+          if (pty) {
+            stream.columns = pty.cols;
+            stream.rows = pty.rows;
+          }
+          runShell({
+            stream,
+            terminal: Boolean(pty),
+            key,
+            banner: account.banner,
+            onPrompt: params.onPrompt,
+            sessions,
+            log,
+          });
+        });
+        session.on("exec", (accept, _reject, execInfo) => {
+          stream = accept() as SessionStream;
+          void runExec({ stream, command: execInfo.command, key, onPrompt: params.onPrompt });
+        });
+        session.on("subsystem", (_accept, reject) => reject?.());
+        session.on("env", (accept) => accept?.());
+      });
+    });
+
+    client.on("error", (err) => log?.info?.(`SSH: client ${info.ip}: ${err.message}`));
+    client.on("close", () => clients.delete(client));
+  });
+
+  await new Promise<void>((resolve, reject) => {
+    server.once("error", reject);
+    server.listen(account.port, account.host, () => {
+      server.off("error", reject);
+      resolve();
+    });
+  });
+  server.on("error", (err: Error) => log?.warn?.(`SSH: server error: ${err.message}`));
+
+  return {
+    port: (server.address() as AddressInfo).port,
+    sessions: () => [...sessions.values()],
+    stop: async () => {
+      for (const client of clients) client.end();
+      await new Promise<void>((resolve) => server.close(() => resolve()));
+    },
+  };
+}
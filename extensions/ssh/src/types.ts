@@ -0,0 +1,47 @@
+/**
+ * Type definitions for the SSH channel plugin.
+ */
+
+/** Raw channel config from openclaw.json channels.ssh */
+export interface SshChannelConfig {
+  enabled?: boolean;
+  /** Listen address (default: 0.0.0.0). */
+  host?: string;
+  /** Listen port (default: 2222). */
+  port?: number;
+  /** OpenSSH host private key (default: <stateDir>/ssh/host_ed25519, generated on first start). */
+  hostKeyPath?: string;
+  /** Public keys allowed to log in, in authorized_keys format (one key per entry). */
+  authorizedKeys?: string[];
+  /** An authorized_keys file to read keys from (e.g. ~/.ssh/authorized_keys). */
+  authorizedKeysFile?: string;
+  /** Text shown when a shell session opens. */
+  banner?: string;
+}
+
+/** One public key from authorized_keys. */
+export interface SshAuthorizedKey {
+  /** Key type, e.g. ssh-ed25519. */
+  type: string;
+  /** Base64 public key blob as written in the file. */
+  base64: string;
+  /** Decoded public key blob. */
+  blob: Buffer;
+  /** Trailing comment, usually user@host. */
+  comment?: string;
+  /** OpenSSH-style fingerprint: SHA256:<base64, unpadded>. */
+  fingerprint: string;
+}
+
+/** Fully resolved account config with defaults applied */
+export interface ResolvedSshAccount {
+  accountId: string;
+  enabled: boolean;
+  configured: boolean;
+  host: string;
+  port: number;
+  hostKeyPath?: string;
+  authorizedKeys: string[];
+  authorizedKeysFile?: string;
+  banner?: string;
+}
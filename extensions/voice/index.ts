@@ -0,0 +1,17 @@
+import type { OpenClawPluginApi } from "openclaw/plugin-sdk";
+import { emptyPluginConfigSchema } from "openclaw/plugin-sdk";
+import { createVoicePlugin } from "./src/channel.js";
+import { setVoiceRuntime } from "./src/runtime.js";
+
+const plugin = {
+  id: "voice",
+  name: "Voice",
+  description: "Voice channel plugin for OpenClaw (wake word, speech-to-text, spoken replies)",
+  configSchema: emptyPluginConfigSchema(),
+  register(api: OpenClawPluginApi) {
+    setVoiceRuntime(api.runtime);
+    api.registerChannel({ plugin: createVoicePlugin() });
+  },
+};
+
+export default plugin;
@@ -0,0 +1,51 @@
+import { afterEach, describe, expect, it } from "vitest";
+import { listAccountIds, parseCommandLine, resolveAccount } from "./accounts.js";
+
+describe("voice accounts", () => {
+  afterEach(() => {
+    delete process.env.PICOVOICE_ACCESS_KEY;
+  });
+
+  it("has a single default account once channels.voice exists", () => {
+    expect(listAccountIds({})).toEqual([]);
+    expect(listAccountIds({ channels: { voice: {} } })).toEqual(["default"]);
+  });
+
+  it("needs an access key for porcupine and a detector command for the command engine", () => {
+    expect(resolveAccount({ channels: { voice: {} } }).configured).toBe(false);
+    process.env.PICOVOICE_ACCESS_KEY = "pv-key";
+    const porcupine = resolveAccount({ channels: { voice: {} } });
+    expect(porcupine.configured).toBe(true);
+    expect(porcupine.wakeWord).toMatchObject({ engine: "porcupine", accessKey: "pv-key" });
+
+    const command = resolveAccount({
+      channels: { voice: { wakeWord: { engine: "command", command: "  detect  --model hey " } } },
+    });
+    expect(command.configured).toBe(true);
+    expect(command.wakeWord.command).toEqual(["detect", "--model", "hey"]);
+  });
+
+  it("applies defaults and clamps invalid tuning values", () => {
+    const account = resolveAccount({
+      channels: {
+        voice: {
+          wakeWord: { engine: "command", command: ["detect"], sensitivity: 3 },
+          silenceMs: -5,
+          speakerId: " kitchen ",
+        },
+      },
+    });
+    expect(account.wakeWord.sensitivity).toBe(1);
+    expect(account.silenceMs).toBe(1200);
+    expect(account.speakerId).toBe("kitchen");
+    expect(account.captureCommand[0]).toBe("arecord");
+    expect(account.playbackCommand[0]).toBe("ffplay");
+    expect(account.speakReplies).toBe(true);
+  });
+
+  it("parses command lines from strings or argv arrays", () => {
+    expect(parseCommandLine(undefined)).toEqual([]);
+    expect(parseCommandLine("aplay  -q {file}")).toEqual(["aplay", "-q", "{file}"]);
+    expect(parseCommandLine([" aplay", "", "{file} "])).toEqual(["aplay", "{file}"]);
+  });
+});
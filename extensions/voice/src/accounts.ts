@@ -0,0 +1,78 @@
+/**
+ * Account resolution: reads config from channels.voice. The channel drives the local
+ * microphone and speaker, so there is exactly one account ("default").
+ */
+
+import type { OpenClawConfig } from "openclaw/plugin-sdk";
+import type { ResolvedVoiceAccount, VoiceChannelConfig, VoiceCommandLine } from "./types.js";
+
+export const SAMPLE_RATE = 16_000;
+
+const DEFAULT_CAPTURE_COMMAND = [
+  "arecord",
+  "-q",
+  "-t",
+  "raw",
+  "-f",
+  "S16_LE",
+  "-c",
+  "1",
+  "-r",
+  "16000",
+];
+const DEFAULT_PLAYBACK_COMMAND = ["ffplay", "-nodisp", "-autoexit", "-loglevel", "quiet"];
+const DEFAULT_KEYWORD = "computer";
+
+function getChannelConfig(cfg: OpenClawConfig): VoiceChannelConfig | undefined {
+  return cfg.channels?.voice as VoiceChannelConfig | undefined;
+}
+
+/** Split a configured command line into argv; strings split on whitespace. */
+export function parseCommandLine(raw: VoiceCommandLine | undefined): string[] {
+  if (!raw) return [];
+  const parts = Array.isArray(raw) ? raw : raw.split(/\s+/);
+  return parts.map((part) => part.trim()).filter(Boolean);
+}
+
+function positive(raw: unknown, fallback: number): number {
+  return typeof raw === "number" && Number.isFinite(raw) && raw > 0 ? raw : fallback;
+}
+
+export function listAccountIds(cfg: OpenClawConfig): string[] {
+  return getChannelConfig(cfg) ? ["default"] : [];
+}
+
+/** Resolve the voice account with full defaults applied. */
+export function resolveAccount(
+  cfg: OpenClawConfig,
+  accountId?: string | null,
+): ResolvedVoiceAccount {
+  const channelCfg = getChannelConfig(cfg) ?? {};
+  const wake = channelCfg.wakeWord ?? {};
+  const engine = wake.engine === "command" ? "command" : "porcupine";
+  const accessKey = wake.accessKey?.trim() || process.env.PICOVOICE_ACCESS_KEY?.trim() || undefined;
+  const command = parseCommandLine(wake.command);
+  const captureCommand = parseCommandLine(channelCfg.captureCommand);
+  const playbackCommand = parseCommandLine(channelCfg.playbackCommand);
+
+  return {
+    accountId: accountId || "default",
+    enabled: channelCfg.enabled ?? true,
+    configured: engine === "porcupine" ? Boolean(accessKey) : command.length > 0,
+    wakeWord: {
+      engine,
+      accessKey,
+      keyword: wake.keyword?.trim() || DEFAULT_KEYWORD,
+      sensitivity: Math.min(1, Math.max(0, wake.sensitivity ?? 0.5)),
+      command,
+    },
+    captureCommand: captureCommand.length ? captureCommand : DEFAULT_CAPTURE_COMMAND,
+    playbackCommand: playbackCommand.length ? playbackCommand : DEFAULT_PLAYBACK_COMMAND,
+    silenceMs: positive(channelCfg.silenceMs, 1200),
+    maxUtteranceMs: positive(channelCfg.maxUtteranceMs, 15_000),
+    noSpeechTimeoutMs: positive(channelCfg.noSpeechTimeoutMs, 5000),
+    speechThreshold: positive(channelCfg.speechThreshold, 600),
+    speakerId: channelCfg.speakerId?.trim() || "local",
+    speakReplies: channelCfg.speakReplies ?? true,
+  };
+}
@@ -0,0 +1,88 @@
+import { describe, expect, it } from "vitest";
+import {
+  buildPlaybackArgv,
+  createUtteranceSegmenter,
+  encodeWav,
+  int16FramesToBuffer,
+  rmsS16le,
+} from "./audio.js";
+
+const SAMPLE_RATE = 16_000;
+
+/** 100 ms of a constant-amplitude signal. */
+function chunk(amplitude: number): Buffer {
+  const samples = new Int16Array(SAMPLE_RATE / 10).fill(amplitude);
+  return int16FramesToBuffer([samples]);
+}
+
+function segmenter() {
+  return createUtteranceSegmenter({
+    sampleRate: SAMPLE_RATE,
+    silenceMs: 300,
+    maxUtteranceMs: 2000,
+    noSpeechTimeoutMs: 500,
+    speechThreshold: 600,
+  });
+}
+
+describe("rmsS16le", () => {
+  it("measures signal level", () => {
+    expect(rmsS16le(chunk(0))).toBe(0);
+    expect(rmsS16le(chunk(-1000))).toBe(1000);
+    expect(rmsS16le(Buffer.alloc(0))).toBe(0);
+  });
+});
+
+describe("createUtteranceSegmenter", () => {
+  it("ends after trailing silence once speech was heard", () => {
+    const seg = segmenter();
+    expect(seg.push(chunk(0))).toBe("continue");
+    expect(seg.push(chunk(2000))).toBe("continue");
+    expect(seg.push(chunk(0))).toBe("continue");
+    expect(seg.push(chunk(0))).toBe("continue");
+    expect(seg.push(chunk(0))).toBe("end");
+    expect(seg.heardSpeech).toBe(true);
+  });
+
+  it("reports no-speech when nobody talks", () => {
+    const seg = segmenter();
+    const states = Array.from({ length: 5 }, () => seg.push(chunk(100)));
+    expect(states).toEqual(["continue", "continue", "continue", "continue", "no-speech"]);
+  });
+
+  it("caps long utterances", () => {
+    const seg = segmenter();
+    const states = Array.from({ length: 20 }, () => seg.push(chunk(2000)));
+    expect(states.at(-1)).toBe("end");
+    expect(states.slice(0, -1)).not.toContain("end");
+  });
+});
+
+describe("encodeWav", () => {
+  it("writes a 16-bit mono PCM header", () => {
+    const pcm = chunk(5);
+    const wav = encodeWav(pcm, SAMPLE_RATE);
+    expect(wav.toString("ascii", 0, 4)).toBe("RIFF");
+    expect(wav.toString("ascii", 8, 12)).toBe("WAVE");
+    expect(wav.readUInt32LE(24)).toBe(SAMPLE_RATE);
+    expect(wav.readUInt16LE(34)).toBe(16);
+    expect(wav.readUInt32LE(40)).toBe(pcm.length);
+    expect(wav.length).toBe(44 + pcm.length);
+  });
+});
+
+describe("buildPlaybackArgv", () => {
+  it("fills the {file} placeholder or appends the file", () => {
+    expect(buildPlaybackArgv(["mpg123", "-q"], "/tmp/a.mp3")).toEqual([
+      "mpg123",
+      "-q",
+      "/tmp/a.mp3",
+    ]);
+    expect(buildPlaybackArgv(["play", "{file}", "gain", "-3"], "/tmp/a.mp3")).toEqual([
+      "play",
+      "/tmp/a.mp3",
+      "gain",
+      "-3",
+    ]);
+  });
+});
@@ -0,0 +1,99 @@
+/**
+ * PCM helpers: level metering, utterance endpointing, and WAV encoding for
+ * 16-bit mono little-endian audio.
+ */
+
+export type SegmenterResult = "continue" | "end" | "no-speech";
+
+/** Root-mean-square level of a chunk of signed 16-bit LE samples (0..32767). */
+export function rmsS16le(chunk: Buffer): number {
+  const samples = Math.floor(chunk.length / 2);
+  if (samples === 0) return 0;
+  let sum = 0;
+  for (let i = 0; i < samples; i++) {
+    const sample = chunk.readInt16LE(i * 2);
+    sum += sample * sample;
+  }
+  return Math.sqrt(sum / samples);
+}
+
+/**
+ * Energy-based endpointing for one utterance: waits for speech, then ends after
+ * `silenceMs` of quiet, at `maxUtteranceMs`, or reports `no-speech` when nobody
+ * talks within `noSpeechTimeoutMs`.
+ */
+export function createUtteranceSegmenter(params: {
+  sampleRate: number;
+  silenceMs: number;
+  maxUtteranceMs: number;
+  noSpeechTimeoutMs: number;
+  speechThreshold: number;
+}) {
+  const bytesPerMs = (params.sampleRate * 2) / 1000;
+  let elapsedMs = 0;
+  let silentMs = 0;
+  let heardSpeech = false;
+
+  return {
+    get heardSpeech() {
+      return heardSpeech;
+    },
+    push(chunk: Buffer): SegmenterResult {
+      const chunkMs = chunk.length / bytesPerMs;
+      elapsedMs += chunkMs;
+      if (rmsS16le(chunk) >= params.speechThreshold) {
+        heardSpeech = true;
+        silentMs = 0;
+      } else if (heardSpeech) {
+        silentMs += chunkMs;
+      }
+      if (!heardSpeech) {
+        return elapsedMs >= params.noSpeechTimeoutMs ? "no-speech" : "continue";
+      }
+      if (silentMs >= params.silenceMs || elapsedMs >= params.maxUtteranceMs) {
+        return "end";
+      }
+      return "continue";
+    },
+  };
+}
+
+/** Wrap raw PCM in a WAV (RIFF) container. */
+export function encodeWav(pcm: Buffer, sampleRate: number): Buffer {
+  const header = Buffer.alloc(44);
+  header.write("RIFF", 0, "ascii");
+  header.writeUInt32LE(36 + pcm.length, 4);
+  header.write("WAVE", 8, "ascii");
+  header.write("fmt ", 12, "ascii");
+  header.writeUInt32LE(16, 16);
+  header.writeUInt16LE(1, 20); // PCM
+  header.writeUInt16LE(1, 22); // mono
+  header.writeUInt32LE(sampleRate, 24);
+  header.writeUInt32LE(sampleRate * 2, 28); // byte rate
+  header.writeUInt16LE(2, 32); // block align
+  header.writeUInt16LE(16, 34); // bits per sample
+  header.write("data", 36, "ascii");
+  header.writeUInt32LE(pcm.length, 40);
+  return Buffer.concat([header, pcm]);
+}
+
+/** Convert Int16Array frames (as produced by PvRecorder) to a little-endian buffer. */
+export function int16FramesToBuffer(frames: Int16Array[]): Buffer {
+  const total = frames.reduce((sum, frame) => sum + frame.length, 0);
+  const out = Buffer.alloc(total * 2);
+  let offset = 0;
+  for (const frame of frames) {
+    for (const sample of frame) {
+      out.writeInt16LE(sample, offset);
+      offset += 2;
+    }
+  }
+  return out;
+}
+
+/** Fill `{file}` in a playback command, or append the file when there is no placeholder. */
+export function buildPlaybackArgv(command: string[], file: string): string[] {
+  return command.some((part) => part.includes("{file}"))
+    ? command.map((part) => part.replaceAll("{file}", file))
+    : [...command, file];
+}
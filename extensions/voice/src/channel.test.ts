@@ -0,0 +1,167 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+
+vi.mock("openclaw/plugin-sdk", () => ({
+  DEFAULT_ACCOUNT_ID: "default",
+  buildChannelConfigSchema: vi.fn((schema: unknown) => ({ schema })),
+  createReplyPrefixOptions: vi.fn(() => ({ onModelSelected: vi.fn() })),
+}));
+
+const listener = vi.hoisted(() => ({
+  onUtterance: undefined as ((pcm: Buffer) => Promise<void>) | undefined,
+  stop: vi.fn(async () => {}),
+}));
+
+vi.mock("./listener.js", () => ({
+  startVoiceListener: vi.fn(async (params: { onUtterance: (pcm: Buffer) => Promise<void> }) => {
+    listener.onUtterance = params.onUtterance;
+    return { stop: listener.stop };
+  }),
+}));
+
+const rt = vi.hoisted(() => ({
+  config: { loadConfig: vi.fn() },
+  media: { transcribeAudioFile: vi.fn() },
+  tts: { textToSpeech: vi.fn() },
+  system: { runCommandWithTimeout: vi.fn() },
+  channel: {
+    routing: { resolveAgentRoute: vi.fn() },
+    session: {
+      resolveStorePath: vi.fn(() => "/tmp/sessions.json"),
+      readSessionUpdatedAt: vi.fn(() => undefined),
+      recordInboundSession: vi.fn(async () => {}),
+    },
+    reply: {
+      formatAgentEnvelope: vi.fn((params: { body: string }) => `[Voice] ${params.body}`),
+      resolveEnvelopeFormatOptions: vi.fn(() => ({})),
+      finalizeInboundContext: vi.fn((ctx: Record<string, unknown>) => ctx),
+      dispatchReplyWithBufferedBlockDispatcher: vi.fn(),
+    },
+  },
+}));
+
+vi.mock("./runtime.js", () => ({
+  getVoiceRuntime: () => rt,
+}));
+
+const { startVoiceListener } = await import("./listener.js");
+const { createVoicePlugin } = await import("./channel.js");
+
+const cfg = {
+  channels: {
+    voice: {
+      wakeWord: { engine: "command", command: "wake-detector --model hey" },
+      playbackCommand: "aplay -q {file}",
+    },
+  },
+};
+
+function gatewayCtx(config: Record<string, unknown> = cfg) {
+  return {
+    cfg: config,
+    accountId: "default",
+    log: { info: vi.fn(), warn: vi.fn(), error: vi.fn() },
+  } as never;
+}
+
+describe("voice channel", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    listener.onUtterance = undefined;
+    rt.config.loadConfig.mockResolvedValue(cfg);
+    rt.media.transcribeAudioFile.mockResolvedValue({ ok: true, text: " how warm is it? " });
+    rt.tts.textToSpeech.mockResolvedValue({ success: true, audioPath: "/tmp/reply.mp3" });
+    rt.system.runCommandWithTimeout.mockResolvedValue({ code: 0 });
+    rt.channel.routing.resolveAgentRoute.mockReturnValue({
+      agentId: "main",
+      accountId: "default",
+      sessionKey: "agent:main:voice:direct:local",
+    });
+    rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher.mockImplementation(
+      async (params: {
+        dispatcherOptions: { deliver: (payload: { text?: string }) => Promise<void> };
+      }) => {
+        await params.dispatcherOptions.deliver({ text: "It is 21 degrees." });
+      },
+    );
+  });
+
+  it("skips disabled and unconfigured accounts without opening the microphone", async () => {
+    const plugin = createVoicePlugin();
+    await plugin.gateway.startAccount(
+      gatewayCtx({ channels: { voice: { ...cfg.channels.voice, enabled: false } } }),
+    );
+    await plugin.gateway.startAccount(
+      gatewayCtx({ channels: { voice: { wakeWord: { engine: "command" } } } }),
+    );
+    expect(startVoiceListener).not.toHaveBeenCalled();
+  });
+
+  it("transcribes an utterance, records the session, and speaks the reply", async () => {
+    const plugin = createVoicePlugin();
+    const handle = await plugin.gateway.startAccount(gatewayCtx());
+    expect(listener.onUtterance).toBeDefined();
+
+    await listener.onUtterance?.(Buffer.alloc(3200));
+
+    expect(rt.media.transcribeAudioFile).toHaveBeenCalledWith(
+      expect.objectContaining({ mime: "audio/wav" }),
+    );
+    expect(rt.channel.routing.resolveAgentRoute).toHaveBeenCalledWith(
+      expect.objectContaining({ channel: "voice", peer: { kind: "direct", id: "local" } }),
+    );
+    expect(rt.channel.reply.finalizeInboundContext).toHaveBeenCalledWith(
+      expect.objectContaining({
+        BodyForAgent: "how warm is it?",
+        From: "voice:local",
+        SessionKey: "agent:main:voice:direct:local",
+        CommandAuthorized: false,
+      }),
+    );
+    expect(rt.channel.session.recordInboundSession).toHaveBeenCalledWith(
+      expect.objectContaining({ sessionKey: "agent:main:voice:direct:local" }),
+    );
+    expect(rt.tts.textToSpeech).toHaveBeenCalledWith(
+      expect.objectContaining({ text: "It is 21 degrees.", channel: "voice" }),
+    );
+    expect(rt.system.runCommandWithTimeout).toHaveBeenCalledWith(
+      ["aplay", "-q", "/tmp/reply.mp3"],
+      expect.any(Object),
+    );
+
+    await (handle as { stop: () => Promise<void> }).stop();
+    expect(listener.stop).toHaveBeenCalled();
+  });
+
+  it("ignores empty transcriptions", async () => {
+    rt.media.transcribeAudioFile.mockResolvedValue({ ok: true, text: "  " });
+    const plugin = createVoicePlugin();
+    await plugin.gateway.startAccount(gatewayCtx());
+    await listener.onUtterance?.(Buffer.alloc(3200));
+    expect(rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher).not.toHaveBeenCalled();
+    expect(rt.tts.textToSpeech).not.toHaveBeenCalled();
+  });
+
+  it("only logs replies when speakReplies is off", async () => {
+    const quiet = { channels: { voice: { ...cfg.channels.voice, speakReplies: false } } };
+    rt.config.loadConfig.mockResolvedValue(quiet);
+    const plugin = createVoicePlugin();
+    await plugin.gateway.startAccount(gatewayCtx(quiet));
+    await listener.onUtterance?.(Buffer.alloc(3200));
+    expect(rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher).toHaveBeenCalled();
+    expect(rt.tts.textToSpeech).not.toHaveBeenCalled();
+  });
+
+  it("speaks outbound announcements on the device", async () => {
+    const plugin = createVoicePlugin();
+    const result = await plugin.outbound.sendText({
+      cfg,
+      to: "local",
+      text: "The greenhouse door is open.",
+    } as never);
+    expect(result.channel).toBe("voice");
+    expect(rt.tts.textToSpeech).toHaveBeenCalledWith(
+      expect.objectContaining({ text: "The greenhouse door is open." }),
+    );
+    expect(rt.system.runCommandWithTimeout).toHaveBeenCalledTimes(1);
+  });
+});
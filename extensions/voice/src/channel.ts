@@ -0,0 +1,318 @@
+/**
+ * Voice channel plugin for OpenClaw.
+ *
+ * A hands-free assistant on the device running the Gateway: listens on the local
+ * microphone for a wake word, records the utterance, transcribes it with the
+ * `tools.media.audio` pipeline, runs the agent, and speaks the reply with the
+ * configured TTS provider.
+ */
+
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import {
+  DEFAULT_ACCOUNT_ID,
+  buildChannelConfigSchema,
+  createReplyPrefixOptions,
+  type ChannelGatewayContext,
+  type ChannelOutboundContext,
+  type OpenClawConfig,
+} from "openclaw/plugin-sdk";
+import { z } from "zod";
+import { SAMPLE_RATE, listAccountIds, resolveAccount } from "./accounts.js";
+import { buildPlaybackArgv, encodeWav } from "./audio.js";
+import { startVoiceListener } from "./listener.js";
+import { getVoiceRuntime } from "./runtime.js";
+import type { ResolvedVoiceAccount } from "./types.js";
+
+const CHANNEL_ID = "voice";
+const TEXT_CHUNK_LIMIT = 4000;
+const PLAYBACK_TIMEOUT_MS = 5 * 60_000;
+const VoiceConfigSchema = buildChannelConfigSchema(z.object({}).passthrough());
+
+type Log = { info?: (msg: string) => void; warn?: (msg: string) => void };
+
+// One speaker: replies and outbound announcements are spoken one after another.
+let speechQueue: Promise<void> = Promise.resolve();
+
+function speak(account: ResolvedVoiceAccount, text: string, log?: Log): Promise<void> {
+  const run = async () => {
+    const rt = getVoiceRuntime();
+    const cfg = await rt.config.loadConfig();
+    const result = await rt.tts.textToSpeech({ text, cfg, channel: CHANNEL_ID });
+    if (!result.success || !result.audioPath) {
+      log?.warn?.(`Voice: text-to-speech failed: ${result.error ?? "no audio"}`);
+      return;
+    }
+    const playback = await rt.system.runCommandWithTimeout(
+      buildPlaybackArgv(account.playbackCommand, result.audioPath),
+      { timeoutMs: PLAYBACK_TIMEOUT_MS },
+    );
+    if (playback.code !== 0) {
+      log?.warn?.(`Voice: playback exited with ${playback.code ?? playback.termination}`);
+    }
+  };
+  const next = speechQueue.then(run, run);
+  speechQueue = next.catch(() => {});
+  return next;
+}
+
+async function transcribe(pcm: Buffer): Promise<string | null> {
+  const rt = getVoiceRuntime();
+  const cfg = await rt.config.loadConfig();
+  const dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-voice-"));
+  try {
+    const file = path.join(dir, "utterance.wav");
+    await fs.writeFile(file, encodeWav(pcm, SAMPLE_RATE));
+    const result = await rt.media.transcribeAudioFile({
+      cfg,
+      source: file,
+      mime: "audio/wav",
+      localRoots: [dir],
+    });
+    if (!result.ok) {
+      throw new Error(result.error);
+    }
+    return result.text.trim() || null;
+  } finally {
+    await fs.rm(dir, { recursive: true, force: true });
+  }
+}
+
+async function handleUtterance(params: {
+  account: ResolvedVoiceAccount;
+  pcm: Buffer;
+  log?: Log;
+}): Promise<void> {
+  const { account, log } = params;
+  const text = await transcribe(params.pcm);
+  if (!text) {
+    log?.info?.("Voice: empty transcription, ignoring");
+    return;
+  }
+  log?.info?.(`Voice: heard "${text}"`);
+
+  const rt = getVoiceRuntime();
+  const cfg = await rt.config.loadConfig();
+  const speaker = account.speakerId;
+  const route = rt.channel.routing.resolveAgentRoute({
+    cfg,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+    peer: { kind: "direct", id: speaker },
+  });
+  const storePath = rt.channel.session.resolveStorePath(cfg.session?.store, {
+    agentId: route.agentId,
+  });
+  const body = rt.channel.reply.formatAgentEnvelope({
+    channel: "Voice",
+    from: speaker,
+    timestamp: Date.now(),
+    previousTimestamp: rt.channel.session.readSessionUpdatedAt({
+      storePath,
+      sessionKey: route.sessionKey,
+    }),
+    envelope: rt.channel.reply.resolveEnvelopeFormatOptions(cfg),
+    body: text,
+  });
+  const ctxPayload = rt.channel.reply.finalizeInboundContext({
+    Body: body,
+    BodyForAgent: text,
+    RawBody: text,
+    CommandBody: text,
+    From: `${CHANNEL_ID}:${speaker}`,
+    To: `${CHANNEL_ID}:${speaker}`,
+    SessionKey: route.sessionKey,
+    AccountId: route.accountId,
+    ChatType: "direct",
+    ConversationLabel: speaker,
+    SenderId: speaker,
+    // Anyone in earshot can talk to the device; spoken input never runs control commands.
+    CommandAuthorized: false,
+    Provider: CHANNEL_ID,
+    Surface: CHANNEL_ID,
+    OriginatingChannel: CHANNEL_ID,
+    OriginatingTo: `${CHANNEL_ID}:${speaker}`,
+  });
+  await rt.channel.session.recordInboundSession({
+    storePath,
+    sessionKey: route.sessionKey,
+    ctx: ctxPayload,
+    onRecordError: (err) => {
+      log?.warn?.(`Voice: failed updating session meta: ${String(err)}`);
+    },
+  });
+
+  const texts: string[] = [];
+  const { onModelSelected, ...prefixOptions } = createReplyPrefixOptions({
+    cfg,
+    agentId: route.agentId,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+  });
+  await rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher({
+    ctx: ctxPayload,
+    cfg,
+    dispatcherOptions: {
+      ...prefixOptions,
+      deliver: async (payload: { text?: string }) => {
+        if (payload.text?.trim()) texts.push(payload.text.trim());
+      },
+      onError: (err: unknown) => {
+        log?.warn?.(`Voice: reply failed: ${String(err)}`);
+      },
+    },
+    replyOptions: { onModelSelected, disableBlockStreaming: true },
+  });
+
+  const reply = texts.join("\n\n");
+  if (!reply) return;
+  log?.info?.(`Voice: reply "${reply}"`);
+  if (account.speakReplies) {
+    // Wait for playback so the microphone loop resumes only after the device stops talking.
+    await speak(account, reply, log);
+  }
+}
+
+export function createVoicePlugin() {
+  return {
+    id: CHANNEL_ID,
+
+    meta: {
+      id: CHANNEL_ID,
+      label: "Voice",
+      selectionLabel: "Voice (local microphone + speaker)",
+      detailLabel: "Voice (local microphone + speaker)",
+      docsPath: "/channels/voice",
+      blurb: "Hands-free assistant on the device: wake word, transcription, spoken replies",
+      order: 99,
+    },
+
+    capabilities: {
+      chatTypes: ["direct" as const],
+      media: false,
+      threads: false,
+      reactions: false,
+      edit: false,
+      unsend: false,
+      reply: true,
+      effects: false,
+      blockStreaming: false,
+    },
+
+    reload: { configPrefixes: [`channels.${CHANNEL_ID}`] },
+
+    configSchema: VoiceConfigSchema,
+
+    config: {
+      listAccountIds: (cfg: OpenClawConfig) => listAccountIds(cfg),
+
+      resolveAccount: (cfg: OpenClawConfig, accountId?: string | null) =>
+        resolveAccount(cfg, accountId),
+
+      defaultAccountId: (_cfg: OpenClawConfig) => DEFAULT_ACCOUNT_ID,
+
+      isConfigured: (account: ResolvedVoiceAccount) => account.configured,
+
+      setAccountEnabled: ({ cfg, enabled }: { cfg: OpenClawConfig; enabled: boolean }) => ({
+        ...cfg,
+        channels: {
+          ...cfg.channels,
+          [CHANNEL_ID]: { ...cfg?.channels?.[CHANNEL_ID], enabled },
+        },
+      }),
+    },
+
+    security: {
+      collectWarnings: ({ account }: { account: ResolvedVoiceAccount }) => {
+        const warnings: string[] = [];
+        if (!account.configured) {
+          warnings.push(
+            account.wakeWord.engine === "porcupine"
+              ? "- Voice: no Picovoice access key (channels.voice.wakeWord.accessKey or PICOVOICE_ACCESS_KEY). The channel will not listen."
+              : "- Voice: channels.voice.wakeWord.command is empty. The channel will not listen.",
+          );
+        }
+        warnings.push(
+          "- Voice: anyone within earshot of the microphone can talk to the agent. Keep its tools scoped accordingly.",
+        );
+        return warnings;
+      },
+    },
+
+    messaging: {
+      normalizeTarget: (target: string) => target.trim() || undefined,
+      targetResolver: {
+        looksLikeId: (id: string) => Boolean(id?.trim()),
+        hint: "<speaker>",
+      },
+    },
+
+    directory: {
+      self: async () => null,
+      listPeers: async () => [],
+      listGroups: async () => [],
+    },
+
+    outbound: {
+      deliveryMode: "direct" as const,
+      textChunkLimit: TEXT_CHUNK_LIMIT,
+
+      // Announcements (cron, message tool) are spoken on the device speaker.
+      sendText: async ({ cfg, to, text, accountId }: ChannelOutboundContext) => {
+        await speak(resolveAccount(cfg, accountId), text);
+        return { channel: CHANNEL_ID, messageId: `voice-${Date.now()}`, chatId: to };
+      },
+    },
+
+    gateway: {
+      startAccount: async (ctx: ChannelGatewayContext<ResolvedVoiceAccount>) => {
+        const { cfg, accountId, log } = ctx;
+        const account = resolveAccount(cfg, accountId);
+
+        if (!account.enabled) {
+          log?.info?.(`Voice account ${accountId} is disabled, skipping`);
+          return { stop: () => {} };
+        }
+
+        if (!account.configured) {
+          log?.warn?.(`Voice account ${accountId} has no wake word engine configured, skipping`);
+          return { stop: () => {} };
+        }
+
+        const wakeLabel =
+          account.wakeWord.engine === "porcupine" ? account.wakeWord.keyword : "command";
+        log?.info?.(`Starting voice channel (wake word: ${wakeLabel})`);
+        const listener = await startVoiceListener({
+          account,
+          onUtterance: (pcm) =>
+            handleUtterance({ account, pcm, log }).catch((err) => {
+              log?.warn?.(`Voice: turn failed: ${String(err)}`);
+            }),
+          log,
+        });
+
+        return {
+          stop: async () => {
+            log?.info?.("Stopping voice channel");
+            await listener.stop();
+          },
+        };
+      },
+
+      stopAccount: async (ctx: ChannelGatewayContext<ResolvedVoiceAccount>) => {
+        ctx.log?.info?.(`Voice account ${ctx.accountId} stopped`);
+      },
+    },
+
+    agentPrompt: {
+      messageToolHints: () => [
+        "",
+        "### Voice Formatting",
+        "Replies on the voice channel are spoken aloud by text-to-speech.",
+        "Answer in one to three short, plain sentences. No Markdown, lists, tables, code, or URLs.",
+        "Spell out symbols and units the way you would say them.",
+      ],
+    },
+  };
+}
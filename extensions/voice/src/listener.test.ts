@@ -0,0 +1,44 @@
+import { describe, expect, it } from "vitest";
+import { resolveAccount } from "./accounts.js";
+import { recordWithCommand } from "./listener.js";
+
+// Writes 100 ms PCM chunks (16 kHz mono s16le) at the given amplitudes, then exits.
+function captureScript(amplitudes: number[]): string {
+  return [
+    `const chunk = (a) => Buffer.from(new Int16Array(1600).fill(a).buffer);`,
+    `for (const a of ${JSON.stringify(amplitudes)}) process.stdout.write(chunk(a));`,
+  ].join("\n");
+}
+
+function account(amplitudes: number[]) {
+  return resolveAccount({
+    channels: {
+      voice: {
+        wakeWord: { engine: "command", command: "detect" },
+        captureCommand: [process.execPath, "-e", captureScript(amplitudes)],
+        silenceMs: 300,
+        noSpeechTimeoutMs: 500,
+      },
+    },
+  });
+}
+
+describe("recordWithCommand", () => {
+  it("returns the recorded utterance once speech was heard", async () => {
+    const pcm = await recordWithCommand(account([2000, 2000, 0, 0, 0, 0, 0]));
+    expect(pcm).not.toBeNull();
+    // At least the speech and the trailing silence that ended the utterance.
+    expect(pcm!.length).toBeGreaterThanOrEqual(5 * 3200);
+  });
+
+  it("returns null when nobody speaks after the wake word", async () => {
+    await expect(recordWithCommand(account([0, 0, 0, 0, 0, 0, 0]))).resolves.toBeNull();
+  });
+
+  it("rejects when the capture command cannot start", async () => {
+    const missing = resolveAccount({
+      channels: { voice: { captureCommand: "openclaw-missing-capture-binary" } },
+    });
+    await expect(recordWithCommand(missing)).rejects.toThrow("capture command failed");
+  });
+});
@@ -0,0 +1,200 @@
+/**
+ * Microphone loop: wait for the wake word, record one utterance, hand it to the
+ * channel, repeat. Wake detection is paused while an utterance is handled, so the
+ * assistant does not wake itself up while it speaks.
+ */
+
+import { spawn } from "node:child_process";
+import { createInterface } from "node:readline";
+import { SAMPLE_RATE } from "./accounts.js";
+import { createUtteranceSegmenter, int16FramesToBuffer } from "./audio.js";
+import type { ResolvedVoiceAccount } from "./types.js";
+
+type Log = { info?: (msg: string) => void; warn?: (msg: string) => void };
+
+type PorcupineModule = {
+  Porcupine: new (
+    accessKey: string,
+    keywords: string[],
+    sensitivities: number[],
+  ) => { frameLength: number; process(frame: Int16Array): number; release(): void };
+};
+
+type PvRecorderModule = {
+  PvRecorder: new (
+    frameLength: number,
+    deviceIndex?: number,
+  ) => { start(): void; stop(): void; read(): Promise<Int16Array>; release(): void };
+};
+
+const DETECTOR_RESTART_MS = 5000;
+
+function createSegmenter(account: ResolvedVoiceAccount) {
+  return createUtteranceSegmenter({
+    sampleRate: SAMPLE_RATE,
+    silenceMs: account.silenceMs,
+    maxUtteranceMs: account.maxUtteranceMs,
+    noSpeechTimeoutMs: account.noSpeechTimeoutMs,
+    speechThreshold: account.speechThreshold,
+  });
+}
+
+/** Record one utterance with the capture command (raw 16 kHz mono s16le on stdout). */
+export function recordWithCommand(account: ResolvedVoiceAccount): Promise<Buffer | null> {
+  const [bin, ...args] = account.captureCommand;
+  const segmenter = createSegmenter(account);
+  const chunks: Buffer[] = [];
+  return new Promise((resolve, reject) => {
+    const child = spawn(bin!, args, { stdio: ["ignore", "pipe", "ignore"] });
+    let settled = false;
+    const finish = (pcm: Buffer | null) => {
+      if (settled) return;
+      settled = true;
+      child.kill("SIGTERM");
+      resolve(pcm);
+    };
+    child.stdout.on("data", (chunk: Buffer) => {
+      chunks.push(chunk);
+      const state = segmenter.push(chunk);
+      if (state === "end") finish(Buffer.concat(chunks));
+      if (state === "no-speech") finish(null);
+    });
+    child.on("error", (err) => {
+      if (!settled) {
+        settled = true;
+        reject(new Error(`capture command failed: ${err.message}`));
+      }
+    });
+    child.on("close", () => finish(segmenter.heardSpeech ? Buffer.concat(chunks) : null));
+  });
+}
+
+async function runPorcupineLoop(params: {
+  account: ResolvedVoiceAccount;
+  onUtterance: (pcm: Buffer) => Promise<void>;
+  isStopped: () => boolean;
+  log?: Log;
+}): Promise<void> {
+  const { account, log } = params;
+  let porcupineModule: PorcupineModule;
+  let recorderModule: PvRecorderModule;
+  try {
+    porcupineModule = (await import("@picovoice/porcupine-node")) as unknown as PorcupineModule;
+    recorderModule = (await import("@picovoice/pvrecorder-node")) as unknown as PvRecorderModule;
+  } catch {
+    throw new Error(
+      "wake word engine porcupine needs @picovoice/porcupine-node and @picovoice/pvrecorder-node",
+    );
+  }
+  const porcupine = new porcupineModule.Porcupine(
+    account.wakeWord.accessKey ?? "",
+    [account.wakeWord.keyword],
+    [account.wakeWord.sensitivity],
+  );
+  const recorder = new recorderModule.PvRecorder(porcupine.frameLength);
+  try {
+    recorder.start();
+    while (!params.isStopped()) {
+      const frame = await recorder.read();
+      if (porcupine.process(frame) < 0) continue;
+      log?.info?.("Voice: wake word detected");
+      // Porcupine already owns the microphone; record the utterance from the same frames.
+      const segmenter = createSegmenter(account);
+      const frames: Int16Array[] = [];
+      let state: ReturnType<typeof segmenter.push> = "continue";
+      while (state === "continue" && !params.isStopped()) {
+        const next = await recorder.read();
+        frames.push(next);
+        state = segmenter.push(int16FramesToBuffer([next]));
+      }
+      recorder.stop();
+      if (state === "end") {
+        await params.onUtterance(int16FramesToBuffer(frames));
+      }
+      if (!params.isStopped()) recorder.start();
+    }
+  } finally {
+    recorder.stop();
+    recorder.release();
+    porcupine.release();
+  }
+}
+
+function runCommandDetector(params: {
+  account: ResolvedVoiceAccount;
+  onUtterance: (pcm: Buffer) => Promise<void>;
+  isStopped: () => boolean;
+  log?: Log;
+}): { stop: () => void } {
+  const { account, log } = params;
+  let child: ReturnType<typeof spawn> | null = null;
+  let busy = false;
+  let restartTimer: ReturnType<typeof setTimeout> | null = null;
+
+  const handleWake = async () => {
+    busy = true;
+    try {
+      log?.info?.("Voice: wake word detected");
+      const pcm = await recordWithCommand(account);
+      if (pcm) await params.onUtterance(pcm);
+    } catch (err) {
+      log?.warn?.(`Voice: ${String(err)}`);
+    } finally {
+      busy = false;
+    }
+  };
+
+  const start = () => {
+    const [bin, ...args] = account.wakeWord.command;
+    const proc = spawn(bin!, args, { stdio: ["ignore", "pipe", "inherit"] });
+    child = proc;
+    createInterface({ input: proc.stdout! }).on("line", (line) => {
+      // Detections while a turn is running are the assistant hearing itself.
+      if (!line.trim() || busy || params.isStopped()) return;
+      void handleWake();
+    });
+    proc.on("error", (err) => log?.warn?.(`Voice: wake word detector failed: ${err.message}`));
+    proc.on("close", (code) => {
+      child = null;
+      if (params.isStopped()) return;
+      log?.warn?.(`Voice: wake word detector exited (code ${code ?? "?"}); restarting`);
+      restartTimer = setTimeout(start, DETECTOR_RESTART_MS);
+    });
+  };
+
+  start();
+  return {
+    stop: () => {
+      if (restartTimer) clearTimeout(restartTimer);
+      child?.kill("SIGTERM");
+    },
+  };
+}
+
+/** Start listening for the wake word; resolves once the microphone loop is running. */
+export async function startVoiceListener(params: {
+  account: ResolvedVoiceAccount;
+  onUtterance: (pcm: Buffer) => Promise<void>;
+  log?: Log;
+}): Promise<{ stop: () => Promise<void> }> {
+  let stopped = false;
+  const isStopped = () => stopped;
+  if (params.account.wakeWord.engine === "command") {
+    const detector = runCommandDetector({ ...params, isStopped });
+    return {
+      stop: async () => {
+        stopped = true;
+        detector.stop();
+      },
+    };
+  }
+  const loop = runPorcupineLoop({ ...params, isStopped }).catch((err) => {
+    params.log?.warn?.(`Voice: ${String(err)}`);
+  });
+  return {
+    stop: async () => {
+      stopped = true;
+      await loop;
+    },
+  };
+}
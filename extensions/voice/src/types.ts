@@ -0,0 +1,65 @@
+/**
+ * Type definitions for the voice channel plugin.
+ */
+
+export type VoiceWakeEngine = "porcupine" | "command";
+
+/** A command line: either an argv array or a string split on whitespace. */
+export type VoiceCommandLine = string | string[];
+
+export interface VoiceWakeWordConfig {
+  /** `porcupine` (in-process, needs a Picovoice access key) or `command` (external detector). */
+  engine?: VoiceWakeEngine;
+  /** Picovoice access key (default: PICOVOICE_ACCESS_KEY). */
+  accessKey?: string;
+  /** Built-in Porcupine keyword (e.g. "jarvis", "computer") or a path to a `.ppn` file. */
+  keyword?: string;
+  /** Porcupine sensitivity, 0..1 (default: 0.5). */
+  sensitivity?: number;
+  /** Detector for engine `command`: prints one line to stdout per detected wake word. */
+  command?: VoiceCommandLine;
+}
+
+/** Raw channel config from openclaw.json channels.voice */
+export interface VoiceChannelConfig {
+  enabled?: boolean;
+  wakeWord?: VoiceWakeWordConfig;
+  /** Records 16 kHz mono signed 16-bit PCM to stdout (default: arecord). */
+  captureCommand?: VoiceCommandLine;
+  /** Plays an audio file; `{file}` is replaced by its path, else appended (default: ffplay). */
+  playbackCommand?: VoiceCommandLine;
+  /** Silence that ends an utterance, in ms (default: 1200). */
+  silenceMs?: number;
+  /** Hard cap on one utterance, in ms (default: 15000). */
+  maxUtteranceMs?: number;
+  /** Give up when nobody speaks this long after the wake word, in ms (default: 5000). */
+  noSpeechTimeoutMs?: number;
+  /** RMS level (0..32767) that counts as speech (default: 600). */
+  speechThreshold?: number;
+  /** Sender id for the session and allowlists (default: "local"). */
+  speakerId?: string;
+  /** Speak replies (default: true). When false, replies are only logged. */
+  speakReplies?: boolean;
+}
+
+/** Fully resolved account config with defaults applied */
+export interface ResolvedVoiceAccount {
+  accountId: string;
+  enabled: boolean;
+  configured: boolean;
+  wakeWord: {
+    engine: VoiceWakeEngine;
+    accessKey?: string;
+    keyword: string;
+    sensitivity: number;
+    command: string[];
+  };
+  captureCommand: string[];
+  playbackCommand: string[];
+  silenceMs: number;
+  maxUtteranceMs: number;
+  noSpeechTimeoutMs: number;
+  speechThreshold: number;
+  speakerId: string;
+  speakReplies: boolean;
+}
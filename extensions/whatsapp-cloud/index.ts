@@ -0,0 +1,17 @@
+import type { OpenClawPluginApi } from "openclaw/plugin-sdk";
+import { emptyPluginConfigSchema } from "openclaw/plugin-sdk";
+import { createWhatsAppCloudPlugin } from "./src/channel.js";
+import { setWhatsAppCloudRuntime } from "./src/runtime.js";
+
+const plugin = {
+  id: "whatsapp-cloud",
+  name: "WhatsApp Cloud API",
+  description: "WhatsApp Business Cloud API channel plugin for OpenClaw",
+  configSchema: emptyPluginConfigSchema(),
+  register(api: OpenClawPluginApi) {
+    setWhatsAppCloudRuntime(api.runtime);
+    api.registerChannel({ plugin: createWhatsAppCloudPlugin() });
+  },
+};
+
+export default plugin;
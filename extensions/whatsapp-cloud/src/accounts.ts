@@ -0,0 +1,103 @@
+/**
+ * Account resolution: reads config from channels.whatsapp-cloud,
+ * merges per-account overrides, falls back to environment variables.
+ */
+
+import type { OpenClawConfig } from "openclaw/plugin-sdk";
+import type {
+  ResolvedWhatsAppCloudAccount,
+  WhatsAppCloudChannelConfig,
+  WhatsAppCloudTemplateConfig,
+} from "./types.js";
+
+export const DEFAULT_API_VERSION = "v21.0";
+export const DEFAULT_WEBHOOK_PATH = "/webhook/whatsapp-cloud";
+const DEFAULT_TEMPLATE_LANGUAGE = "en_US";
+
+function getChannelConfig(cfg: OpenClawConfig): WhatsAppCloudChannelConfig | undefined {
+  return cfg.channels?.["whatsapp-cloud"] as WhatsAppCloudChannelConfig | undefined;
+}
+
+/** Normalize a phone number to the digits-only form the Cloud API uses for wa_id. */
+export function normalizeWhatsAppCloudNumber(raw: string): string {
+  return raw
+    .trim()
+    .replace(/^whatsapp[-_]?cloud:/i, "")
+    .replace(/[^\d]/g, "");
+}
+
+function parseAllowFrom(raw: string | string[] | undefined): string[] {
+  if (!raw) return [];
+  const entries = Array.isArray(raw) ? raw : raw.split(",");
+  return entries
+    .map((entry) => (entry.trim() === "*" ? "*" : normalizeWhatsAppCloudNumber(entry)))
+    .filter(Boolean);
+}
+
+function resolveTemplate(
+  raw: WhatsAppCloudTemplateConfig | undefined,
+): ResolvedWhatsAppCloudAccount["alertTemplate"] {
+  const name = raw?.name?.trim();
+  if (!name) return undefined;
+  return { name, language: raw?.language?.trim() || DEFAULT_TEMPLATE_LANGUAGE };
+}
+
+/**
+ * List all configured account IDs for this channel.
+ * Returns ["default"] if there's a base config, plus any named accounts.
+ */
+export function listAccountIds(cfg: OpenClawConfig): string[] {
+  const channelCfg = getChannelConfig(cfg);
+  if (!channelCfg) return [];
+
+  const ids = new Set<string>();
+  if (channelCfg.accessToken || process.env.WHATSAPP_CLOUD_ACCESS_TOKEN) {
+    ids.add("default");
+  }
+  for (const id of Object.keys(channelCfg.accounts ?? {})) {
+    ids.add(id);
+  }
+  return Array.from(ids);
+}
+
+/**
+ * Resolve a specific account by ID with full defaults applied.
+ * Falls back to env vars for the "default" account.
+ */
+export function resolveAccount(
+  cfg: OpenClawConfig,
+  accountId?: string | null,
+): ResolvedWhatsAppCloudAccount {
+  const channelCfg = getChannelConfig(cfg) ?? {};
+  const id = accountId || "default";
+  const accountOverride = channelCfg.accounts?.[id] ?? {};
+  const env = id === "default" ? process.env : {};
+
+  // Merge: account override > base channel config > env var
+  return {
+    accountId: id,
+    enabled: accountOverride.enabled ?? channelCfg.enabled ?? true,
+    accessToken:
+      accountOverride.accessToken ??
+      channelCfg.accessToken ??
+      env.WHATSAPP_CLOUD_ACCESS_TOKEN ??
+      "",
+    phoneNumberId:
+      accountOverride.phoneNumberId ??
+      channelCfg.phoneNumberId ??
+      env.WHATSAPP_CLOUD_PHONE_NUMBER_ID ??
+      "",
+    appSecret:
+      accountOverride.appSecret ?? channelCfg.appSecret ?? env.WHATSAPP_CLOUD_APP_SECRET ?? "",
+    verifyToken:
+      accountOverride.verifyToken ??
+      channelCfg.verifyToken ??
+      env.WHATSAPP_CLOUD_VERIFY_TOKEN ??
+      "",
+    webhookPath: accountOverride.webhookPath ?? channelCfg.webhookPath ?? DEFAULT_WEBHOOK_PATH,
+    apiVersion: accountOverride.apiVersion ?? channelCfg.apiVersion ?? DEFAULT_API_VERSION,
+    dmPolicy: accountOverride.dmPolicy ?? channelCfg.dmPolicy ?? "allowlist",
+    allowFrom: parseAllowFrom(accountOverride.allowFrom ?? channelCfg.allowFrom),
+    alertTemplate: resolveTemplate(accountOverride.alertTemplate ?? channelCfg.alertTemplate),
+  };
+}
@@ -0,0 +1,464 @@
+/**
+ * WhatsApp Business Cloud API channel plugin for OpenClaw.
+ *
+ * Inbound via Meta's webhook, outbound via the Graph API. Unlike the built-in WhatsApp
+ * channel (WhatsApp Web session), this uses an official business phone number.
+ */
+
+import {
+  DEFAULT_ACCOUNT_ID,
+  buildChannelConfigSchema,
+  createReplyPrefixOptions,
+  formatPairingApproveHint,
+  PAIRING_APPROVED_MESSAGE,
+  registerPluginHttpRoute,
+  setAccountEnabledInConfigSection,
+  type ChannelGatewayContext,
+  type ChannelOutboundContext,
+  type OpenClawConfig,
+} from "openclaw/plugin-sdk";
+import { z } from "zod";
+import { listAccountIds, normalizeWhatsAppCloudNumber, resolveAccount } from "./accounts.js";
+import { downloadMedia, recordInboundActivity } from "./client.js";
+import { getWhatsAppCloudRuntime } from "./runtime.js";
+import { sendWhatsAppCloudMedia, sendWhatsAppCloudText } from "./send.js";
+import type { ResolvedWhatsAppCloudAccount, WhatsAppCloudInboundMessage } from "./types.js";
+import { createWebhookHandler } from "./webhook-handler.js";
+
+const CHANNEL_ID = "whatsapp-cloud";
+const TEXT_CHUNK_LIMIT = 4096;
+const INBOUND_MEDIA_MAX_BYTES = 16 * 1024 * 1024;
+const WhatsAppCloudConfigSchema = buildChannelConfigSchema(z.object({}).passthrough());
+
+/** Config allowlist plus, under dmPolicy "pairing", numbers approved through pairing. */
+async function resolveEffectiveAllowFrom(account: ResolvedWhatsAppCloudAccount): Promise<string[]> {
+  if (account.dmPolicy !== "pairing") return account.allowFrom;
+  const rt = getWhatsAppCloudRuntime();
+  const stored = await rt.channel.pairing.readAllowFromStore(CHANNEL_ID).catch(() => []);
+  return [...account.allowFrom, ...stored.map((entry) => normalizeWhatsAppCloudNumber(entry))];
+}
+
+function isSenderAllowed(
+  account: ResolvedWhatsAppCloudAccount,
+  allowFrom: string[],
+  from: string,
+): boolean {
+  if (account.dmPolicy === "open") return true;
+  if (account.dmPolicy === "disabled") return false;
+  return allowFrom.includes("*") || allowFrom.includes(from);
+}
+
+async function sendPairingPrompt(params: {
+  account: ResolvedWhatsAppCloudAccount;
+  message: WhatsAppCloudInboundMessage;
+  log?: { warn?: (msg: string) => void };
+}): Promise<void> {
+  const { account, message } = params;
+  const rt = getWhatsAppCloudRuntime();
+  const { code, created } = await rt.channel.pairing.upsertPairingRequest({
+    channel: CHANNEL_ID,
+    id: message.from,
+    meta: { name: message.senderName || undefined },
+  });
+  if (!created) return;
+  // The inbound message opens the 24h window, so the code goes out as a plain text.
+  recordInboundActivity(account.accountId, message.from, message.timestamp);
+  try {
+    await sendWhatsAppCloudText(
+      account,
+      message.from,
+      rt.channel.pairing.buildPairingReply({
+        channel: CHANNEL_ID,
+        idLine: `Your WhatsApp number: +${message.from}`,
+        code,
+      }),
+    );
+  } catch (err) {
+    params.log?.warn?.(`WhatsApp Cloud: pairing reply to ${message.from} failed: ${String(err)}`);
+  }
+}
+
+async function handleInboundMessage(params: {
+  account: ResolvedWhatsAppCloudAccount;
+  message: WhatsAppCloudInboundMessage;
+  log?: { info?: (msg: string) => void; warn?: (msg: string) => void };
+}): Promise<void> {
+  const { account, message, log } = params;
+  const allowFrom = await resolveEffectiveAllowFrom(account);
+  if (!isSenderAllowed(account, allowFrom, message.from)) {
+    if (account.dmPolicy === "pairing") {
+      await sendPairingPrompt({ account, message, log });
+    }
+    log?.warn?.(`WhatsApp Cloud: drop message from ${message.from} (dmPolicy=${account.dmPolicy})`);
+    return;
+  }
+  recordInboundActivity(account.accountId, message.from, message.timestamp);
+
+  const rt = getWhatsAppCloudRuntime();
+  const cfg = await rt.config.loadConfig();
+
+  let mediaPath: string | undefined;
+  let mediaType: string | undefined;
+  if (message.mediaId) {
+    try {
+      const downloaded = await downloadMedia(account, message.mediaId);
+      const saved = await rt.channel.media.saveMediaBuffer(
+        downloaded.buffer,
+        downloaded.contentType,
+        "inbound",
+        INBOUND_MEDIA_MAX_BYTES,
+      );
+      mediaPath = saved.path;
+      mediaType = saved.contentType;
+    } catch (err) {
+      log?.warn?.(`WhatsApp Cloud: failed to download media ${message.mediaId}: ${String(err)}`);
+    }
+  }
+  const rawBody = message.text || (mediaPath ? "<media:attachment>" : "");
+  if (!rawBody) return;
+
+  const route = rt.channel.routing.resolveAgentRoute({
+    cfg,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+    peer: { kind: "direct", id: message.from },
+  });
+  const fromLabel = message.senderName || `+${message.from}`;
+  const storePath = rt.channel.session.resolveStorePath(cfg.session?.store, {
+    agentId: route.agentId,
+  });
+  const body = rt.channel.reply.formatAgentEnvelope({
+    channel: "WhatsApp",
+    from: fromLabel,
+    timestamp: message.timestamp,
+    previousTimestamp: rt.channel.session.readSessionUpdatedAt({
+      storePath,
+      sessionKey: route.sessionKey,
+    }),
+    envelope: rt.channel.reply.resolveEnvelopeFormatOptions(cfg),
+    body: rawBody,
+  });
+  const ctxPayload = rt.channel.reply.finalizeInboundContext({
+    Body: body,
+    BodyForAgent: rawBody,
+    RawBody: rawBody,
+    CommandBody: rawBody,
+    From: `${CHANNEL_ID}:${message.from}`,
+    To: `${CHANNEL_ID}:${account.phoneNumberId}`,
+    SessionKey: route.sessionKey,
+    AccountId: route.accountId,
+    ChatType: "direct",
+    ConversationLabel: fromLabel,
+    SenderName: message.senderName,
+    SenderId: message.from,
+    SenderE164: `+${message.from}`,
+    CommandAuthorized: allowFrom.includes("*") || allowFrom.includes(message.from),
+    Provider: CHANNEL_ID,
+    Surface: CHANNEL_ID,
+    MessageSid: message.id,
+    MediaPath: mediaPath,
+    MediaType: mediaType,
+    MediaUrl: mediaPath,
+    OriginatingChannel: CHANNEL_ID,
+    OriginatingTo: `${CHANNEL_ID}:${message.from}`,
+  });
+  await rt.channel.session.recordInboundSession({
+    storePath,
+    sessionKey: ctxPayload.SessionKey ?? route.sessionKey,
+    ctx: ctxPayload,
+    onRecordError: (err) => {
+      log?.warn?.(`WhatsApp Cloud: failed updating session meta: ${String(err)}`);
+    },
+  });
+
+  const { onModelSelected, ...prefixOptions } = createReplyPrefixOptions({
+    cfg,
+    agentId: route.agentId,
+    channel: CHANNEL_ID,
+    accountId: account.accountId,
+  });
+  await rt.channel.reply.dispatchReplyWithBufferedBlockDispatcher({
+    ctx: ctxPayload,
+    cfg,
+    dispatcherOptions: {
+      ...prefixOptions,
+      deliver: async (payload: { text?: string; mediaUrls?: string[]; mediaUrl?: string }) => {
+        const mediaUrls = payload.mediaUrls?.length
+          ? payload.mediaUrls
+          : payload.mediaUrl
+            ? [payload.mediaUrl]
+            : [];
+        const text = payload.text ?? "";
+        if (mediaUrls.length > 0) {
+          for (const [index, mediaUrl] of mediaUrls.entries()) {
+            await sendWhatsAppCloudMedia(account, message.from, {
+              mediaUrl,
+              caption: index === 0 ? text : undefined,
+            });
+          }
+          return;
+        }
+        if (!text) return;
+        const chunkMode = rt.channel.text.resolveChunkMode(cfg, CHANNEL_ID, account.accountId);
+        for (const chunk of rt.channel.text.chunkMarkdownTextWithMode(
+          text,
+          TEXT_CHUNK_LIMIT,
+          chunkMode,
+        )) {
+          await sendWhatsAppCloudText(account, message.from, chunk);
+        }
+      },
+      onError: (err: unknown) => {
+        log?.warn?.(`WhatsApp Cloud: reply to ${message.from} failed: ${String(err)}`);
+      },
+    },
+    replyOptions: { onModelSelected },
+  });
+}
+
+export function createWhatsAppCloudPlugin() {
+  return {
+    id: CHANNEL_ID,
+
+    meta: {
+      id: CHANNEL_ID,
+      label: "WhatsApp Cloud API",
+      selectionLabel: "WhatsApp (Business Cloud API)",
+      detailLabel: "WhatsApp (Business Cloud API)",
+      docsPath: "/channels/whatsapp-cloud",
+      blurb: "Official WhatsApp Business Cloud API with template messages for alerts",
+      order: 95,
+    },
+
+    capabilities: {
+      chatTypes: ["direct" as const],
+      media: true,
+      threads: false,
+      reactions: false,
+      edit: false,
+      unsend: false,
+      reply: false,
+      effects: false,
+      blockStreaming: false,
+    },
+
+    reload: { configPrefixes: [`channels.${CHANNEL_ID}`] },
+
+    configSchema: WhatsAppCloudConfigSchema,
+
+    pairing: {
+      idLabel: "whatsappNumber",
+      normalizeAllowEntry: (entry: string) => normalizeWhatsAppCloudNumber(entry),
+      notifyApproval: async ({ cfg, id }: { cfg: OpenClawConfig; id: string }) => {
+        const account = resolveAccount(cfg);
+        if (!account.accessToken || !account.phoneNumberId) return;
+        await sendWhatsAppCloudText(
+          account,
+          normalizeWhatsAppCloudNumber(id),
+          PAIRING_APPROVED_MESSAGE,
+        );
+      },
+    },
+
+    config: {
+      listAccountIds: (cfg: OpenClawConfig) => listAccountIds(cfg),
+
+      resolveAccount: (cfg: OpenClawConfig, accountId?: string | null) =>
+        resolveAccount(cfg, accountId),
+
+      defaultAccountId: (_cfg: OpenClawConfig) => DEFAULT_ACCOUNT_ID,
+
+      setAccountEnabled: ({
+        cfg,
+        accountId,
+        enabled,
+      }: {
+        cfg: OpenClawConfig;
+        accountId: string;
+        enabled: boolean;
+      }) => {
+        const channelConfig = cfg?.channels?.[CHANNEL_ID] ?? {};
+        if (accountId === DEFAULT_ACCOUNT_ID) {
+          return {
+            ...cfg,
+            channels: {
+              ...cfg.channels,
+              [CHANNEL_ID]: { ...channelConfig, enabled },
+            },
+          };
+        }
+        return setAccountEnabledInConfigSection({
+          cfg,
+          sectionKey: `channels.${CHANNEL_ID}`,
+          accountId,
+          enabled,
+        });
+      },
+    },
+
+    security: {
+      resolveDmPolicy: ({
+        cfg,
+        accountId,
+        account,
+      }: {
+        cfg: OpenClawConfig;
+        accountId?: string | null;
+        account: ResolvedWhatsAppCloudAccount;
+      }) => {
+        const resolvedAccountId = accountId ?? account.accountId ?? DEFAULT_ACCOUNT_ID;
+        const channelCfg = cfg?.channels?.[CHANNEL_ID];
+        const useAccountPath = Boolean(channelCfg?.accounts?.[resolvedAccountId]);
+        const basePath = useAccountPath
+          ? `channels.${CHANNEL_ID}.accounts.${resolvedAccountId}.`
+          : `channels.${CHANNEL_ID}.`;
+        return {
+          policy: account.dmPolicy,
+          allowFrom: account.allowFrom,
+          policyPath: `${basePath}dmPolicy`,
+          allowFromPath: basePath,
+          approveHint: formatPairingApproveHint(CHANNEL_ID),
+          normalizeEntry: (raw: string) => normalizeWhatsAppCloudNumber(raw),
+        };
+      },
+      collectWarnings: ({ account }: { account: ResolvedWhatsAppCloudAccount }) => {
+        const warnings: string[] = [];
+        if (!account.accessToken || !account.phoneNumberId) {
+          warnings.push(
+            "- WhatsApp Cloud: accessToken or phoneNumberId is not configured. The bot cannot send messages.",
+          );
+        }
+        if (!account.appSecret) {
+          warnings.push(
+            "- WhatsApp Cloud: appSecret is not configured. The webhook will reject all notifications.",
+          );
+        }
+        if (!account.alertTemplate) {
+          warnings.push(
+            "- WhatsApp Cloud: alertTemplate is not configured. Proactive messages to users who have not written in the last 24h will be rejected by WhatsApp.",
+          );
+        }
+        if (account.dmPolicy === "open") {
+          warnings.push(
+            '- WhatsApp Cloud: dmPolicy="open" allows any WhatsApp user to message the bot. Consider "allowlist".',
+          );
+        }
+        return warnings;
+      },
+    },
+
+    messaging: {
+      normalizeTarget: (target: string) => normalizeWhatsAppCloudNumber(target) || undefined,
+      targetResolver: {
+        looksLikeId: (id: string) => {
+          const trimmed = id?.trim();
+          if (!trimmed) return false;
+          return /^whatsapp[-_]?cloud:/i.test(trimmed) || /^\+?\d{7,15}$/.test(trimmed);
+        },
+        hint: "<E.164 phone number>",
+      },
+    },
+
+    directory: {
+      self: async () => null,
+      listPeers: async () => [],
+      listGroups: async () => [],
+    },
+
+    outbound: {
+      deliveryMode: "gateway" as const,
+      textChunkLimit: TEXT_CHUNK_LIMIT,
+
+      sendText: async ({ cfg, to, text, accountId }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        if (!account.accessToken || !account.phoneNumberId) {
+          throw new Error("WhatsApp Cloud accessToken/phoneNumberId not configured");
+        }
+        const result = await sendWhatsAppCloudText(account, normalizeWhatsAppCloudNumber(to), text);
+        return { channel: CHANNEL_ID, messageId: result.messageId, chatId: to };
+      },
+
+      sendMedia: async ({
+        cfg,
+        to,
+        text,
+        mediaUrl,
+        accountId,
+        mediaLocalRoots,
+      }: ChannelOutboundContext) => {
+        const account = resolveAccount(cfg, accountId);
+        if (!account.accessToken || !account.phoneNumberId) {
+          throw new Error("WhatsApp Cloud accessToken/phoneNumberId not configured");
+        }
+        if (!mediaUrl) {
+          throw new Error("No media URL provided");
+        }
+        const result = await sendWhatsAppCloudMedia(account, normalizeWhatsAppCloudNumber(to), {
+          mediaUrl,
+          caption: text,
+          mediaLocalRoots,
+        });
+        return { channel: CHANNEL_ID, messageId: result.messageId, chatId: to };
+      },
+    },
+
+    gateway: {
+      startAccount: async (ctx: ChannelGatewayContext<ResolvedWhatsAppCloudAccount>) => {
+        const { cfg, accountId, log } = ctx;
+        const account = resolveAccount(cfg, accountId);
+
+        if (!account.enabled) {
+          log?.info?.(`WhatsApp Cloud account ${accountId} is disabled, skipping`);
+          return { stop: () => {} };
+        }
+
+        if (!account.accessToken || !account.phoneNumberId || !account.appSecret) {
+          log?.warn?.(
+            `WhatsApp Cloud account ${accountId} not fully configured (missing accessToken, phoneNumberId, or appSecret)`,
+          );
+          return { stop: () => {} };
+        }
+
+        log?.info?.(
+          `Starting WhatsApp Cloud channel (account: ${accountId}, path: ${account.webhookPath})`,
+        );
+
+        const handler = createWebhookHandler({
+          account,
+          deliver: (message) => handleInboundMessage({ account, message, log }),
+          log,
+        });
+
+        const unregister = registerPluginHttpRoute({
+          path: account.webhookPath,
+          pluginId: CHANNEL_ID,
+          accountId: account.accountId,
+          log: (msg: string) => log?.info?.(msg),
+          handler,
+        });
+
+        return {
+          stop: () => {
+            log?.info?.(`Stopping WhatsApp Cloud channel (account: ${accountId})`);
+            if (typeof unregister === "function") unregister();
+          },
+        };
+      },
+
+      stopAccount: async (ctx: ChannelGatewayContext<ResolvedWhatsAppCloudAccount>) => {
+        ctx.log?.info?.(`WhatsApp Cloud account ${ctx.accountId} stopped`);
+      },
+    },
+
+    agentPrompt: {
+      messageToolHints: () => [
+        "",
+        "### WhatsApp Formatting",
+        "WhatsApp supports *bold*, _italic_, ~strikethrough~, and ```monospace```.",
+        "No Markdown tables or headings; use short lines and lists.",
+        "Messages to users who have not written in the last 24h are sent through an approved",
+        "template as a single line, so keep proactive alerts short.",
+      ],
+    },
+  };
+}
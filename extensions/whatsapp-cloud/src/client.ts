@@ -0,0 +1,207 @@
+/**
+ * WhatsApp Business Cloud API client (Graph API).
+ * Sends text, media, and template messages; tracks the 24h customer service window.
+ */
+
+import type { ResolvedWhatsAppCloudAccount } from "./types.js";
+
+const GRAPH_BASE_URL = "https://graph.facebook.com";
+
+/** Free-form messages are only allowed within 24h of the user's last inbound message. */
+export const SERVICE_WINDOW_MS = 24 * 60 * 60 * 1000;
+
+// Template text parameters reject newlines, tabs, and runs of more than four spaces.
+const TEMPLATE_PARAM_MAX_CHARS = 1024;
+
+export type WhatsAppCloudSendResult = { messageId: string };
+
+export type WhatsAppCloudMediaType = "image" | "video" | "audio" | "document";
+
+// Last inbound timestamp per account + sender, used to decide whether a template is required.
+const lastInboundAt = new Map<string, number>();
+
+function windowKey(accountId: string, waId: string): string {
+  return `${accountId}:${waId}`;
+}
+
+export function recordInboundActivity(accountId: string, waId: string, atMs = Date.now()): void {
+  lastInboundAt.set(windowKey(accountId, waId), atMs);
+}
+
+/** True when free-form messages can be sent (the user wrote within the last 24h). */
+export function isServiceWindowOpen(accountId: string, waId: string, nowMs = Date.now()): boolean {
+  const last = lastInboundAt.get(windowKey(accountId, waId));
+  return last !== undefined && nowMs - last < SERVICE_WINDOW_MS;
+}
+
+/** Flatten free-form text into a single template body parameter. */
+export function toTemplateParameter(text: string): string {
+  const flattened = text
+    .replace(/\s*\n+\s*/g, " · ")
+    .replace(/\t/g, " ")
+    .replace(/ {4,}/g, "   ")
+    .trim();
+  return flattened.length > TEMPLATE_PARAM_MAX_CHARS
+    ? `${flattened.slice(0, TEMPLATE_PARAM_MAX_CHARS - 1)}…`
+    : flattened;
+}
+
+export function mediaTypeForMime(contentType: string | undefined): WhatsAppCloudMediaType {
+  if (contentType?.startsWith("image/")) return "image";
+  if (contentType?.startsWith("video/")) return "video";
+  if (contentType?.startsWith("audio/")) return "audio";
+  return "document";
+}
+
+function graphUrl(account: ResolvedWhatsAppCloudAccount, path: string): string {
+  return `${GRAPH_BASE_URL}/${account.apiVersion}/${path}`;
+}
+
+async function graphRequest<T>(
+  account: ResolvedWhatsAppCloudAccount,
+  url: string,
+  init: RequestInit = {},
+): Promise<T> {
+  const res = await fetch(url, {
+    ...init,
+    headers: { Authorization: `Bearer ${account.accessToken}`, ...init.headers },
+  });
+  if (!res.ok) {
+    const body = (await res.json().catch(() => null)) as {
+      error?: { message?: string; code?: number };
+    } | null;
+    const detail = body?.error?.message ?? res.statusText;
+    const code = body?.error?.code ? ` (code ${body.error.code})` : "";
+    throw new Error(`WhatsApp Cloud API error ${res.status}${code}: ${detail}`);
+  }
+  return (await res.json()) as T;
+}
+
+async function postMessage(
+  account: ResolvedWhatsAppCloudAccount,
+  to: string,
+  message: Record<string, unknown>,
+): Promise<WhatsAppCloudSendResult> {
+  const result = await graphRequest<{ messages?: Array<{ id?: string }> }>(
+    account,
+    graphUrl(account, `${account.phoneNumberId}/messages`),
+    {
+      method: "POST",
+      headers: { "Content-Type": "application/json" },
+      body: JSON.stringify({
+        messaging_product: "whatsapp",
+        recipient_type: "individual",
+        to,
+        ...message,
+      }),
+    },
+  );
+  return { messageId: result.messages?.[0]?.id ?? `wac-${Date.now()}` };
+}
+
+export async function sendText(
+  account: ResolvedWhatsAppCloudAccount,
+  to: string,
+  text: string,
+): Promise<WhatsAppCloudSendResult> {
+  return await postMessage(account, to, {
+    type: "text",
+    text: { body: text, preview_url: /https?:\/\//.test(text) },
+  });
+}
+
+/**
+ * Send an approved template. The text becomes the template's first body parameter
+ * (`{{1}}`), so alert templates should consist of a single variable.
+ */
+export async function sendTemplate(
+  account: ResolvedWhatsAppCloudAccount,
+  to: string,
+  template: { name: string; language: string },
+  text?: string,
+): Promise<WhatsAppCloudSendResult> {
+  const parameter = text ? toTemplateParameter(text) : "";
+  return await postMessage(account, to, {
+    type: "template",
+    template: {
+      name: template.name,
+      language: { code: template.language },
+      ...(parameter
+        ? { components: [{ type: "body", parameters: [{ type: "text", text: parameter }] }] }
+        : {}),
+    },
+  });
+}
+
+/** Upload media to the phone number and return its media id. */
+export async function uploadMedia(
+  account: ResolvedWhatsAppCloudAccount,
+  media: { buffer: Buffer; contentType?: string; fileName?: string },
+): Promise<string> {
+  const contentType = media.contentType ?? "application/octet-stream";
+  const form = new FormData();
+  form.append("messaging_product", "whatsapp");
+  form.append("type", contentType);
+  form.append(
+    "file",
+    new Blob([new Uint8Array(media.buffer)], { type: contentType }),
+    media.fileName ?? "file",
+  );
+  const result = await graphRequest<{ id?: string }>(
+    account,
+    graphUrl(account, `${account.phoneNumberId}/media`),
+    { method: "POST", body: form },
+  );
+  if (!result.id) {
+    throw new Error("WhatsApp Cloud media upload returned no id");
+  }
+  return result.id;
+}
+
+export async function sendMedia(
+  account: ResolvedWhatsAppCloudAccount,
+  to: string,
+  params: {
+    type: WhatsAppCloudMediaType;
+    mediaId: string;
+    caption?: string;
+    fileName?: string;
+  },
+): Promise<WhatsAppCloudSendResult> {
+  const caption = params.type === "audio" ? undefined : params.caption?.trim() || undefined;
+  return await postMessage(account, to, {
+    type: params.type,
+    [params.type]: {
+      id: params.mediaId,
+      ...(caption ? { caption } : {}),
+      ...(params.type === "document" && params.fileName ? { filename: params.fileName } : {}),
+    },
+  });
+}
+
+/** Resolve a media id from an inbound message and download it. */
+export async function downloadMedia(
+  account: ResolvedWhatsAppCloudAccount,
+  mediaId: string,
+): Promise<{ buffer: Buffer; contentType?: string }> {
+  const meta = await graphRequest<{ url?: string; mime_type?: string }>(
+    account,
+    graphUrl(account, encodeURIComponent(mediaId)),
+  );
+  if (!meta.url) {
+    throw new Error(`WhatsApp Cloud media ${mediaId} has no download url`);
+  }
+  const res = await fetch(meta.url, {
+    headers: { Authorization: `Bearer ${account.accessToken}` },
+  });
+  if (!res.ok) {
+    throw new Error(`WhatsApp Cloud media download failed: ${res.status}`);
+  }
+  return { buffer: Buffer.from(await res.arrayBuffer()), contentType: meta.mime_type };
+}
+
+export const __testing = {
+  resetServiceWindows() {
+    lastInboundAt.clear();
+  },
+} as const;
@@ -0,0 +1,96 @@
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { __testing, recordInboundActivity, toTemplateParameter } from "./client.js";
+import { sendWhatsAppCloudText } from "./send.js";
+import type { ResolvedWhatsAppCloudAccount } from "./types.js";
+
+function makeAccount(
+  overrides: Partial<ResolvedWhatsAppCloudAccount> = {},
+): ResolvedWhatsAppCloudAccount {
+  return {
+    accountId: "default",
+    enabled: true,
+    accessToken: "token",
+    phoneNumberId: "1000",
+    appSecret: "app-secret",
+    verifyToken: "verify-me",
+    webhookPath: "/webhook/whatsapp-cloud",
+    apiVersion: "v21.0",
+    dmPolicy: "allowlist",
+    allowFrom: [],
+    alertTemplate: { name: "openclaw_alert", language: "en_US" },
+    ...overrides,
+  };
+}
+
+describe("sendWhatsAppCloudText", () => {
+  const fetchMock = vi.fn();
+
+  beforeEach(() => {
+    __testing.resetServiceWindows();
+    fetchMock.mockReset();
+    fetchMock.mockResolvedValue(
+      new Response(JSON.stringify({ messages: [{ id: "wamid.out" }] }), { status: 200 }),
+    );
+    vi.stubGlobal("fetch", fetchMock);
+  });
+
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  function sentBody(): Record<string, any> {
+    const [url, init] = fetchMock.mock.calls[0] ?? [];
+    expect(url).toBe("https://graph.facebook.com/v21.0/1000/messages");
+    return JSON.parse(String(init?.body));
+  }
+
+  it("sends free-form text inside the 24h window", async () => {
+    recordInboundActivity("default", "15551234567");
+    const result = await sendWhatsAppCloudText(makeAccount(), "15551234567", "hello");
+    expect(result.messageId).toBe("wamid.out");
+    expect(sentBody()).toMatchObject({ type: "text", to: "15551234567", text: { body: "hello" } });
+  });
+
+  it("wraps proactive alerts in the configured template", async () => {
+    await sendWhatsAppCloudText(makeAccount(), "15551234567", "Disk full\non nas01");
+    expect(sentBody()).toMatchObject({
+      type: "template",
+      template: {
+        name: "openclaw_alert",
+        language: { code: "en_US" },
+        components: [
+          { type: "body", parameters: [{ type: "text", text: "Disk full · on nas01" }] },
+        ],
+      },
+    });
+  });
+
+  it("uses the template again once the window has expired", async () => {
+    recordInboundActivity("default", "15551234567", Date.now() - 25 * 60 * 60 * 1000);
+    await sendWhatsAppCloudText(makeAccount(), "15551234567", "ping");
+    expect(sentBody().type).toBe("template");
+  });
+
+  it("falls back to free-form text when no template is configured", async () => {
+    await sendWhatsAppCloudText(makeAccount({ alertTemplate: undefined }), "15551234567", "hi");
+    expect(sentBody().type).toBe("text");
+  });
+
+  it("surfaces Graph API errors", async () => {
+    fetchMock.mockResolvedValueOnce(
+      new Response(JSON.stringify({ error: { message: "Re-engagement message", code: 131047 } }), {
+        status: 400,
+      }),
+    );
+    await expect(
+      sendWhatsAppCloudText(makeAccount({ alertTemplate: undefined }), "15551234567", "hi"),
+    ).rejects.toThrow("WhatsApp Cloud API error 400 (code 131047): Re-engagement message");
+  });
+});
+
+describe("toTemplateParameter", () => {
+  it("flattens whitespace and caps length", () => {
+    expect(toTemplateParameter("a\n\n b\tc     d")).toBe("a · b c   d");
+    expect(toTemplateParameter("x".repeat(2000))).toHaveLength(1024);
+  });
+});
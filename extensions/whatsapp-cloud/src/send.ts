@@ -0,0 +1,64 @@
+/**
+ * Outbound delivery: free-form messages inside the 24h customer service window,
+ * the configured alert template outside it.
+ */
+
+import {
+  isServiceWindowOpen,
+  mediaTypeForMime,
+  sendMedia,
+  sendTemplate,
+  sendText,
+  uploadMedia,
+  type WhatsAppCloudSendResult,
+} from "./client.js";
+import { getWhatsAppCloudRuntime } from "./runtime.js";
+import type { ResolvedWhatsAppCloudAccount } from "./types.js";
+
+const MEDIA_MAX_BYTES = 16 * 1024 * 1024;
+
+function shouldUseTemplate(account: ResolvedWhatsAppCloudAccount, to: string): boolean {
+  return Boolean(account.alertTemplate) && !isServiceWindowOpen(account.accountId, to);
+}
+
+/**
+ * Send text to a WhatsApp user. Outside the 24h window (proactive alerts, cron deliveries)
+ * the text is wrapped in `alertTemplate`; without a template the Cloud API rejects the send.
+ */
+export async function sendWhatsAppCloudText(
+  account: ResolvedWhatsAppCloudAccount,
+  to: string,
+  text: string,
+): Promise<WhatsAppCloudSendResult> {
+  if (account.alertTemplate && shouldUseTemplate(account, to)) {
+    return await sendTemplate(account, to, account.alertTemplate, text);
+  }
+  return await sendText(account, to, text);
+}
+
+/**
+ * Upload and send media with an optional caption. Outside the 24h window media cannot be
+ * sent free-form, so the caption (or the media URL) goes out through the alert template.
+ */
+export async function sendWhatsAppCloudMedia(
+  account: ResolvedWhatsAppCloudAccount,
+  to: string,
+  params: { mediaUrl: string; caption?: string; mediaLocalRoots?: readonly string[] },
+): Promise<WhatsAppCloudSendResult> {
+  if (account.alertTemplate && shouldUseTemplate(account, to)) {
+    const fallback = /^https?:\/\//i.test(params.mediaUrl) ? params.mediaUrl : undefined;
+    const text = [params.caption?.trim(), fallback].filter(Boolean).join(" ");
+    return await sendTemplate(account, to, account.alertTemplate, text);
+  }
+  const loaded = await getWhatsAppCloudRuntime().media.loadWebMedia(params.mediaUrl, {
+    maxBytes: MEDIA_MAX_BYTES,
+    localRoots: params.mediaLocalRoots,
+  });
+  const mediaId = await uploadMedia(account, loaded);
+  return await sendMedia(account, to, {
+    type: mediaTypeForMime(loaded.contentType),
+    mediaId,
+    caption: params.caption,
+    fileName: loaded.fileName,
+  });
+}
@@ -0,0 +1,59 @@
+/**
+ * Type definitions for the WhatsApp Business Cloud API channel plugin.
+ */
+
+export type WhatsAppCloudDmPolicy = "open" | "allowlist" | "pairing" | "disabled";
+
+/** Approved message template used when the 24h customer service window is closed. */
+export interface WhatsAppCloudTemplateConfig {
+  /** Template name as approved in WhatsApp Manager. */
+  name: string;
+  /** Template language code (default: "en_US"). */
+  language?: string;
+}
+
+/** Raw per-account config (overrides base config) */
+export interface WhatsAppCloudAccountRaw {
+  enabled?: boolean;
+  accessToken?: string;
+  phoneNumberId?: string;
+  appSecret?: string;
+  verifyToken?: string;
+  webhookPath?: string;
+  apiVersion?: string;
+  dmPolicy?: WhatsAppCloudDmPolicy;
+  allowFrom?: string | string[];
+  alertTemplate?: WhatsAppCloudTemplateConfig;
+}
+
+/** Raw channel config from openclaw.json channels.whatsapp-cloud */
+export interface WhatsAppCloudChannelConfig extends WhatsAppCloudAccountRaw {
+  accounts?: Record<string, WhatsAppCloudAccountRaw>;
+}
+
+/** Fully resolved account config with defaults applied */
+export interface ResolvedWhatsAppCloudAccount {
+  accountId: string;
+  enabled: boolean;
+  accessToken: string;
+  phoneNumberId: string;
+  appSecret: string;
+  verifyToken: string;
+  webhookPath: string;
+  apiVersion: string;
+  dmPolicy: WhatsAppCloudDmPolicy;
+  allowFrom: string[];
+  alertTemplate?: { name: string; language: string };
+}
+
+/** Inbound message extracted from a Cloud API webhook notification. */
+export interface WhatsAppCloudInboundMessage {
+  id: string;
+  from: string;
+  senderName?: string;
+  timestamp?: number;
+  text: string;
+  /** Media id for image/document/audio/video messages (download via the Graph API). */
+  mediaId?: string;
+  mediaType?: string;
+}
@@ -0,0 +1,196 @@
+import * as crypto from "node:crypto";
+import { EventEmitter } from "node:events";
+import type { IncomingMessage, ServerResponse } from "node:http";
+import { describe, it, expect, vi } from "vitest";
+import type { ResolvedWhatsAppCloudAccount } from "./types.js";
+import { createWebhookHandler, parseWebhookMessages, verifySignature } from "./webhook-handler.js";
+
+function makeAccount(
+  overrides: Partial<ResolvedWhatsAppCloudAccount> = {},
+): ResolvedWhatsAppCloudAccount {
+  return {
+    accountId: "default",
+    enabled: true,
+    accessToken: "token",
+    phoneNumberId: "1000",
+    appSecret: "app-secret",
+    verifyToken: "verify-me",
+    webhookPath: "/webhook/whatsapp-cloud",
+    apiVersion: "v21.0",
+    dmPolicy: "open",
+    allowFrom: [],
+    ...overrides,
+  };
+}
+
+function makeReq(
+  method: string,
+  opts: { url?: string; body?: string; headers?: Record<string, string> } = {},
+): IncomingMessage {
+  const req = new EventEmitter() as IncomingMessage;
+  req.method = method;
+  req.url = opts.url ?? "/webhook/whatsapp-cloud";
+  req.headers = opts.headers ?? {};
+  req.socket = { remoteAddress: "127.0.0.1" } as any;
+  process.nextTick(() => {
+    if (opts.body) req.emit("data", Buffer.from(opts.body));
+    req.emit("end");
+  });
+  return req;
+}
+
+function makeRes(): ServerResponse & { _status: number; _body: string } {
+  const res = {
+    _status: 0,
+    _body: "",
+    writeHead(statusCode: number) {
+      res._status = statusCode;
+    },
+    end(body?: string) {
+      res._body = body ?? "";
+    },
+  } as any;
+  return res;
+}
+
+function sign(body: string, secret = "app-secret"): string {
+  return `sha256=${crypto.createHmac("sha256", secret).update(body).digest("hex")}`;
+}
+
+function makePayload(message: Record<string, unknown>, phoneNumberId = "1000") {
+  return {
+    object: "whatsapp_business_account",
+    entry: [
+      {
+        changes: [
+          {
+            field: "messages",
+            value: {
+              metadata: { phone_number_id: phoneNumberId },
+              contacts: [{ wa_id: "15551234567", profile: { name: "Ada" } }],
+              messages: [message],
+            },
+          },
+        ],
+      },
+    ],
+  };
+}
+
+describe("verifySignature", () => {
+  it("accepts a matching HMAC and rejects tampering", () => {
+    const body = Buffer.from('{"a":1}');
+    expect(verifySignature(body, sign('{"a":1}'), "app-secret")).toBe(true);
+    expect(verifySignature(body, sign('{"a":2}'), "app-secret")).toBe(false);
+    expect(verifySignature(body, sign('{"a":1}', "other"), "app-secret")).toBe(false);
+    expect(verifySignature(body, undefined, "app-secret")).toBe(false);
+    expect(verifySignature(body, sign('{"a":1}'), "")).toBe(false);
+  });
+});
+
+describe("parseWebhookMessages", () => {
+  it("extracts text messages with the sender profile name", () => {
+    const messages = parseWebhookMessages(
+      makePayload({
+        id: "wamid.1",
+        from: "15551234567",
+        timestamp: "1700000000",
+        type: "text",
+        text: { body: "hello" },
+      }),
+      "1000",
+    );
+    expect(messages).toEqual([
+      {
+        id: "wamid.1",
+        from: "15551234567",
+        senderName: "Ada",
+        timestamp: 1_700_000_000_000,
+        text: "hello",
+      },
+    ]);
+  });
+
+  it("extracts media ids with captions and template button replies", () => {
+    const [image] = parseWebhookMessages(
+      makePayload({
+        id: "wamid.2",
+        from: "15551234567",
+        type: "image",
+        image: { id: "media-1", caption: "look" },
+      }),
+      "1000",
+    );
+    expect(image).toMatchObject({ text: "look", mediaId: "media-1", mediaType: "image" });
+
+    const [button] = parseWebhookMessages(
+      makePayload({ id: "wamid.3", from: "15551234567", type: "button", button: { text: "Ack" } }),
+      "1000",
+    );
+    expect(button?.text).toBe("Ack");
+  });
+
+  it("skips other phone numbers, status callbacks, and unsupported types", () => {
+    const text = { id: "wamid.1", from: "15551234567", type: "text", text: { body: "hi" } };
+    expect(parseWebhookMessages(makePayload(text, "2000"), "1000")).toEqual([]);
+    expect(
+      parseWebhookMessages(
+        makePayload({ id: "wamid.4", from: "15551234567", type: "reaction" }),
+        "1000",
+      ),
+    ).toEqual([]);
+    expect(parseWebhookMessages({ object: "page", entry: [] }, "1000")).toEqual([]);
+  });
+});
+
+describe("createWebhookHandler", () => {
+  it("answers the verification handshake with the challenge", async () => {
+    const handler = createWebhookHandler({ account: makeAccount(), deliver: vi.fn() });
+    const res = makeRes();
+    await handler(
+      makeReq("GET", {
+        url: "/webhook/whatsapp-cloud?hub.mode=subscribe&hub.verify_token=verify-me&hub.challenge=42",
+      }),
+      res,
+    );
+    expect(res._status).toBe(200);
+    expect(res._body).toBe("42");
+  });
+
+  it("rejects the handshake with a wrong verify token", async () => {
+    const handler = createWebhookHandler({ account: makeAccount(), deliver: vi.fn() });
+    const res = makeRes();
+    await handler(
+      makeReq("GET", {
+        url: "/webhook/whatsapp-cloud?hub.mode=subscribe&hub.verify_token=nope&hub.challenge=42",
+      }),
+      res,
+    );
+    expect(res._status).toBe(403);
+  });
+
+  it("rejects notifications without a valid signature", async () => {
+    const deliver = vi.fn();
+    const handler = createWebhookHandler({ account: makeAccount(), deliver });
+    const body = JSON.stringify(makePayload({ id: "m", from: "1", type: "text", text: {} }));
+    const res = makeRes();
+    await handler(
+      makeReq("POST", { body, headers: { "x-hub-signature-256": sign(body, "wrong") } }),
+      res,
+    );
+    expect(res._status).toBe(401);
+    expect(deliver).not.toHaveBeenCalled();
+  });
+
+  it("acknowledges signed notifications and delivers each message", async () => {
+    const deliver = vi.fn().mockResolvedValue(undefined);
+    const handler = createWebhookHandler({ account: makeAccount(), deliver });
+    const body = JSON.stringify(
+      makePayload({ id: "wamid.1", from: "15551234567", type: "text", text: { body: "hi" } }),
+    );
+    const res = makeRes();
+    await handler(makeReq("POST", { body, headers: { "x-hub-signature-256": sign(body) } }), res);
+    expect(res._status).toBe(200);
+    expect(deliver).toHaveBeenCalledWith(expect.objectContaining({ id: "wamid.1", text: "hi" }));
+  });
+});
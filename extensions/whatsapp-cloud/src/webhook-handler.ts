@@ -0,0 +1,228 @@
+/**
+ * Inbound webhook handler for the WhatsApp Business Cloud API.
+ * Answers the GET subscription handshake, verifies X-Hub-Signature-256 on POST
+ * notifications, and hands extracted messages to deliver().
+ */
+
+import * as crypto from "node:crypto";
+import type { IncomingMessage, ServerResponse } from "node:http";
+import { normalizeWhatsAppCloudNumber } from "./accounts.js";
+import type { ResolvedWhatsAppCloudAccount, WhatsAppCloudInboundMessage } from "./types.js";
+
+const MAX_BODY_BYTES = 1_048_576; // 1MB
+
+type CloudWebhookMessage = {
+  id?: string;
+  from?: string;
+  timestamp?: string;
+  type?: string;
+  text?: { body?: string };
+  button?: { text?: string };
+  interactive?: {
+    button_reply?: { title?: string };
+    list_reply?: { title?: string };
+  };
+  image?: { id?: string; caption?: string };
+  document?: { id?: string; caption?: string; filename?: string };
+  audio?: { id?: string };
+  video?: { id?: string; caption?: string };
+};
+
+type CloudWebhookPayload = {
+  object?: string;
+  entry?: Array<{
+    changes?: Array<{
+      field?: string;
+      value?: {
+        metadata?: { phone_number_id?: string };
+        contacts?: Array<{ wa_id?: string; profile?: { name?: string } }>;
+        messages?: CloudWebhookMessage[];
+      };
+    }>;
+  }>;
+};
+
+const MEDIA_TYPES = ["image", "document", "audio", "video"] as const;
+
+/** Read the full request body as a buffer (the signature covers the raw bytes). */
+function readBody(req: IncomingMessage): Promise<Buffer> {
+  return new Promise((resolve, reject) => {
+    const chunks: Buffer[] = [];
+    let size = 0;
+    req.on("data", (chunk: Buffer) => {
+      size += chunk.length;
+      if (size > MAX_BODY_BYTES) {
+        req.destroy();
+        reject(new Error("Request body too large"));
+        return;
+      }
+      chunks.push(chunk);
+    });
+    req.on("end", () => resolve(Buffer.concat(chunks)));
+    req.on("error", reject);
+  });
+}
+
+function respond(res: ServerResponse, statusCode: number, body: string) {
+  res.writeHead(statusCode, { "Content-Type": "text/plain" });
+  res.end(body);
+}
+
+/** Constant-time check of Meta's `sha256=<hex>` HMAC over the raw body. */
+export function verifySignature(rawBody: Buffer, header: unknown, appSecret: string): boolean {
+  if (typeof header !== "string" || !header.startsWith("sha256=") || !appSecret) {
+    return false;
+  }
+  const expected = crypto.createHmac("sha256", appSecret).update(rawBody).digest();
+  const provided = Buffer.from(header.slice("sha256=".length), "hex");
+  return provided.length === expected.length && crypto.timingSafeEqual(provided, expected);
+}
+
+function describeMessage(message: CloudWebhookMessage): Pick<
+  WhatsAppCloudInboundMessage,
+  "text" | "mediaId" | "mediaType"
+> | null {
+  switch (message.type) {
+    case "text":
+      return { text: message.text?.body ?? "" };
+    case "button":
+      return { text: message.button?.text ?? "" };
+    case "interactive":
+      return {
+        text:
+          message.interactive?.button_reply?.title ?? message.interactive?.list_reply?.title ?? "",
+      };
+    default:
+      break;
+  }
+  const mediaType = MEDIA_TYPES.find((type) => type === message.type);
+  const media = mediaType ? message[mediaType] : undefined;
+  if (!mediaType || !media?.id) {
+    return null;
+  }
+  return {
+    text: "caption" in media ? (media.caption ?? "") : "",
+    mediaId: media.id,
+    mediaType,
+  };
+}
+
+/**
+ * Extract user messages addressed to this phone number. Status callbacks (sent/delivered/read)
+ * and unsupported message types (reactions, locations, contacts) are skipped.
+ */
+export function parseWebhookMessages(
+  payload: unknown,
+  phoneNumberId: string,
+): WhatsAppCloudInboundMessage[] {
+  const body = payload as CloudWebhookPayload;
+  if (body?.object !== "whatsapp_business_account") {
+    return [];
+  }
+  const messages: WhatsAppCloudInboundMessage[] = [];
+  for (const entry of body.entry ?? []) {
+    for (const change of entry.changes ?? []) {
+      const value = change.value;
+      if (change.field !== "messages" || !value?.messages?.length) continue;
+      if (phoneNumberId && value.metadata?.phone_number_id !== phoneNumberId) continue;
+      for (const message of value.messages) {
+        const described = describeMessage(message);
+        const from = normalizeWhatsAppCloudNumber(message.from ?? "");
+        if (!described || !from || !message.id) continue;
+        if (!described.text && !described.mediaId) continue;
+        const contact = value.contacts?.find((c) => c.wa_id === message.from);
+        const timestamp = Number(message.timestamp);
+        messages.push({
+          id: message.id,
+          from,
+          senderName: contact?.profile?.name,
+          timestamp: Number.isFinite(timestamp) ? timestamp * 1000 : undefined,
+          ...described,
+        });
+      }
+    }
+  }
+  return messages;
+}
+
+export interface WebhookHandlerDeps {
+  account: ResolvedWhatsAppCloudAccount;
+  deliver: (msg: WhatsAppCloudInboundMessage) => Promise<void>;
+  log?: {
+    info: (...args: unknown[]) => void;
+    warn: (...args: unknown[]) => void;
+    error: (...args: unknown[]) => void;
+  };
+}
+
+/**
+ * Create an HTTP request handler for the Cloud API webhook.
+ *
+ * GET answers Meta's verification handshake (`hub.mode=subscribe` + `hub.verify_token`).
+ * POST must carry a valid X-Hub-Signature-256; the handler acknowledges with 200 right away
+ * (Meta retries slow webhooks) and delivers each message afterwards.
+ */
+export function createWebhookHandler(deps: WebhookHandlerDeps) {
+  const { account, deliver, log } = deps;
+
+  return async (req: IncomingMessage, res: ServerResponse) => {
+    if (req.method === "GET") {
+      const url = new URL(req.url ?? "/", "http://localhost");
+      const mode = url.searchParams.get("hub.mode");
+      const token = url.searchParams.get("hub.verify_token") ?? "";
+      const challenge = url.searchParams.get("hub.challenge") ?? "";
+      const expected = Buffer.from(account.verifyToken);
+      const provided = Buffer.from(token);
+      const tokenOk =
+        expected.length > 0 &&
+        expected.length === provided.length &&
+        crypto.timingSafeEqual(expected, provided);
+      if (mode === "subscribe" && tokenOk) {
+        respond(res, 200, challenge);
+      } else {
+        log?.warn(`Rejected webhook verification from ${req.socket?.remoteAddress}`);
+        respond(res, 403, "Forbidden");
+      }
+      return;
+    }
+
+    if (req.method !== "POST") {
+      respond(res, 405, "Method not allowed");
+      return;
+    }
+
+    let rawBody: Buffer;
+    try {
+      rawBody = await readBody(req);
+    } catch (err) {
+      log?.error("Failed to read request body", err);
+      respond(res, 400, "Invalid request body");
+      return;
+    }
+
+    if (!verifySignature(rawBody, req.headers?.["x-hub-signature-256"], account.appSecret)) {
+      log?.warn(`Invalid webhook signature from ${req.socket?.remoteAddress}`);
+      respond(res, 401, "Invalid signature");
+      return;
+    }
+
+    let payload: unknown;
+    try {
+      payload = JSON.parse(rawBody.toString("utf-8"));
+    } catch {
+      respond(res, 400, "Invalid JSON");
+      return;
+    }
+
+    respond(res, 200, "OK");
+
+    for (const message of parseWebhookMessages(payload, account.phoneNumberId)) {
+      try {
+        await deliver(message);
+      } catch (err) {
+        const errMsg = err instanceof Error ? err.message : String(err);
+        log?.error(`Failed to process WhatsApp Cloud message ${message.id}: ${errMsg}`);
+      }
+    }
+  };
+}
@@ -0,0 +1,153 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import {
+  type AssistantMessage,
+  type AssistantMessageEvent,
+  createAssistantMessageEventStream,
+} from "@mariozechner/pi-ai";
+import { describe, expect, it } from "vitest";
+import type { OpenClawAgentMiddleware } from "../plugins/types.js";
+import { runToolWithMiddleware, wrapStreamFnWithMiddleware } from "./agent-middleware.js";
+
+const model = { api: "anthropic-messages", provider: "anthropic", id: "claude-sonnet-4-5" };
+
+function assistant(text: string): AssistantMessage {
+  return {
+    role: "assistant",
+    content: [{ type: "text", text }],
+    api: "anthropic-messages",
+    provider: "anthropic",
+    model: "claude-sonnet-4-5",
+    usage: {
+      input: 1,
+      output: 1,
+      cacheRead: 0,
+      cacheWrite: 0,
+      totalTokens: 2,
+      cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+    },
+    stopReason: "stop",
+    timestamp: 0,
+  };
+}
+
+function fakeProvider(reply: string, seen: unknown[]): StreamFn {
+  return ((_model, context) => {
+    seen.push(context);
+    const stream = createAssistantMessageEventStream();
+    const message = assistant(reply);
+    queueMicrotask(() => {
+      stream.push({ type: "start", partial: message });
+      stream.push({ type: "text_delta", contentIndex: 0, delta: reply, partial: message });
+      stream.push({ type: "done", reason: "stop", message });
+      stream.end();
+    });
+    return stream;
+  }) as StreamFn;
+}
+
+async function collect(streamFn: StreamFn, context: unknown): Promise<AssistantMessageEvent[]> {
+  const events: AssistantMessageEvent[] = [];
+  const stream = await streamFn(
+    model as Parameters<StreamFn>[0],
+    context as Parameters<StreamFn>[1],
+    {},
+  );
+  for await (const event of stream) {
+    events.push(event);
+  }
+  return events;
+}
+
+describe("agent middleware", () => {
+  it("rewrites provider requests and responses", async () => {
+    const seen: unknown[] = [];
+    const redact: OpenClawAgentMiddleware = {
+      id: "redact",
+      beforeModelCall: (event) => ({
+        systemPrompt: `${event.systemPrompt} Be terse.`,
+        messages: event.messages.map((message) =>
+          message.role === "user" && typeof message.content === "string"
+            ? { ...message, content: message.content.replace(/\d{3}-\d{4}/g, "[phone]") }
+            : message,
+        ),
+      }),
+      afterModelResponse: ({ message }) => ({
+        message: {
+          ...message,
+          content: [{ type: "text", text: "[redacted reply]" }],
+        },
+      }),
+    };
+    const wrapped = wrapStreamFnWithMiddleware(fakeProvider("secret 555-1234", seen), [redact], {
+      sessionKey: "agent:main:main",
+    });
+
+    const events = await collect(wrapped, {
+      systemPrompt: "You are helpful.",
+      messages: [{ role: "user", content: "call 555-1234", timestamp: 0 }],
+    });
+
+    expect(seen).toEqual([
+      {
+        systemPrompt: "You are helpful. Be terse.",
+        messages: [{ role: "user", content: "call [phone]", timestamp: 0 }],
+      },
+    ]);
+    // Deltas are held back so the original text never streams out.
+    expect(events.map((event) => event.type)).toEqual(["done"]);
+    const done = events[0] as Extract<AssistantMessageEvent, { type: "done" }>;
+    expect(done.message.content).toEqual([{ type: "text", text: "[redacted reply]" }]);
+  });
+
+  it("passes the stream through untouched without response middleware", async () => {
+    const seen: unknown[] = [];
+    const logger: OpenClawAgentMiddleware = {
+      id: "logger",
+      beforeModelCall: () => {
+        throw new Error("boom");
+      },
+    };
+    const provider = fakeProvider("hi", seen);
+
+    expect(wrapStreamFnWithMiddleware(provider, [], {})).toBe(provider);
+    const events = await collect(wrapStreamFnWithMiddleware(provider, [logger], {}), {
+      messages: [],
+    });
+    expect(events.map((event) => event.type)).toEqual(["start", "text_delta", "done"]);
+    expect(seen).toEqual([{ messages: [] }]);
+  });
+
+  it("wraps tool execution outermost-first", async () => {
+    const order: string[] = [];
+    const wrapper = (id: string): OpenClawAgentMiddleware => ({
+      id,
+      wrapToolCall: async (event, next) => {
+        order.push(`${id}:before`);
+        const result = await next({ ...(event.params as object), [id]: true });
+        order.push(`${id}:after`);
+        return { ...result, details: { wrappedBy: id } };
+      },
+    });
+
+    const result = await runToolWithMiddleware({
+      middlewares: [wrapper("outer"), { id: "noop" }, wrapper("inner")],
+      toolName: "exec",
+      toolCallId: "call-1",
+      params: { command: "ls" },
+      ctx: { toolName: "exec" },
+      execute: async (params) => {
+        order.push(`exec:${JSON.stringify(params)}`);
+        return { content: [{ type: "text", text: "ok" }], details: {} };
+      },
+    });
+
+    expect(order).toEqual([
+      "outer:before",
+      "inner:before",
+      'exec:{"command":"ls","outer":true,"inner":true}',
+      "inner:after",
+      "outer:after",
+    ]);
+    expect(result.details).toEqual({ wrappedBy: "outer" });
+  });
+});
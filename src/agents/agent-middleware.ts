@@ -0,0 +1,155 @@
+import type { AgentToolResult, StreamFn } from "@mariozechner/pi-agent-core";
+import {
+  type AssistantMessage,
+  type AssistantMessageEvent,
+  createAssistantMessageEventStream,
+} from "@mariozechner/pi-ai";
+import { logWarn } from "../logger.js";
+import { getActivePluginRegistry } from "../plugins/runtime.js";
+import type {
+  OpenClawAgentMiddleware,
+  PluginHookAgentContext,
+  PluginHookToolContext,
+} from "../plugins/types.js";
+
+/** Middleware registered by plugins (`api.registerMiddleware`), in registration order. */
+export function listAgentMiddlewares(): OpenClawAgentMiddleware[] {
+  return (getActivePluginRegistry()?.middlewares ?? []).map((entry) => entry.middleware);
+}
+
+/** Terminal `error` event for stream wrappers whose inner stream or hook throws. */
+export function buildStreamErrorEvent(
+  model: Parameters<StreamFn>[0],
+  err: unknown,
+): Extract<AssistantMessageEvent, { type: "error" }> {
+  return {
+    type: "error",
+    reason: "error",
+    error: {
+      role: "assistant",
+      content: [],
+      stopReason: "error",
+      errorMessage: err instanceof Error ? err.message : String(err),
+      api: model.api,
+      provider: model.provider,
+      model: model.id,
+      usage: {
+        input: 0,
+        output: 0,
+        cacheRead: 0,
+        cacheWrite: 0,
+        totalTokens: 0,
+        cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+      },
+      timestamp: Date.now(),
+    },
+  };
+}
+
+/**
+ * Wraps the provider stream so `beforeModelCall` can rewrite each outbound request and
+ * `afterModelResponse` the final assistant message. With response middleware registered, deltas
+ * are held back and only the rewritten message is emitted, so unredacted text never streams.
+ * A throwing middleware is logged and skipped, like plugin hooks.
+ */
+export function wrapStreamFnWithMiddleware(
+  streamFn: StreamFn,
+  middlewares: OpenClawAgentMiddleware[],
+  ctx: PluginHookAgentContext,
+): StreamFn {
+  const before = middlewares.filter((middleware) => middleware.beforeModelCall);
+  const after = middlewares.filter((middleware) => middleware.afterModelResponse);
+  if (before.length === 0 && after.length === 0) {
+    return streamFn;
+  }
+  return (model, context, options) => {
+    const stream = createAssistantMessageEventStream();
+
+    const run = async () => {
+      let nextContext = context;
+      for (const middleware of before) {
+        try {
+          const result = await middleware.beforeModelCall?.(
+            {
+              provider: model.provider,
+              model: model.id,
+              systemPrompt: nextContext.systemPrompt,
+              messages: nextContext.messages,
+            },
+            ctx,
+          );
+          if (result) {
+            nextContext = {
+              ...nextContext,
+              ...(result.systemPrompt !== undefined ? { systemPrompt: result.systemPrompt } : {}),
+              ...(result.messages ? { messages: result.messages } : {}),
+            };
+          }
+        } catch (err) {
+          logWarn(`middleware ${middleware.id} beforeModelCall failed: ${String(err)}`);
+        }
+      }
+      const inner = await streamFn(model, nextContext, options);
+      for await (const event of inner) {
+        if (after.length === 0 || event.type === "error") {
+          stream.push(event);
+          continue;
+        }
+        if (event.type !== "done") {
+          continue;
+        }
+        let message: AssistantMessage = event.message;
+        for (const middleware of after) {
+          try {
+            const result = await middleware.afterModelResponse?.(
+              { provider: model.provider, model: model.id, message },
+              ctx,
+            );
+            message = result?.message ?? message;
+          } catch (err) {
+            logWarn(`middleware ${middleware.id} afterModelResponse failed: ${String(err)}`);
+          }
+        }
+        stream.push({ ...event, message });
+      }
+    };
+
+    queueMicrotask(() => {
+      void run()
+        .catch((err) => {
+          stream.push(buildStreamErrorEvent(model, err));
+        })
+        .finally(() => {
+          stream.end();
+        });
+    });
+    return stream;
+  };
+}
+
+/**
+ * Runs a tool through each middleware's `wrapToolCall`; the first registered is outermost and
+ * `execute` is innermost. A middleware may change params, short-circuit, or rewrite the result.
+ */
+export async function runToolWithMiddleware(params: {
+  middlewares: OpenClawAgentMiddleware[];
+  toolName: string;
+  toolCallId: string;
+  params: unknown;
+  ctx: PluginHookToolContext;
+  execute: (params: unknown) => Promise<AgentToolResult<unknown>>;
+}): Promise<AgentToolResult<unknown>> {
+  const wrappers = params.middlewares.filter((middleware) => middleware.wrapToolCall);
+  const call = (index: number, toolParams: unknown): Promise<AgentToolResult<unknown>> => {
+    const middleware = wrappers[index];
+    if (!middleware?.wrapToolCall) {
+      return params.execute(toolParams);
+    }
+    return middleware.wrapToolCall(
+      { toolName: params.toolName, toolCallId: params.toolCallId, params: toolParams },
+      (nextParams) => call(index + 1, nextParams),
+      params.ctx,
+    );
+  };
+  return call(0, params.params);
+}
@@ -0,0 +1,146 @@
+import path from "node:path";
+import type { Model } from "@mariozechner/pi-ai";
+import { afterEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import {
+  __testing,
+  convertToLlamaChatHistory,
+  createLlamaCppStreamFn,
+  resolveLlamaCppModelPath,
+  resolveLlamaCppOptions,
+} from "./llama-cpp-stream.js";
+import { applyExtraParamsToAgent } from "./pi-embedded-runner/extra-params.js";
+
+const generateResponse = vi.hoisted(() =>
+  vi.fn(async (_history: unknown[], _options: Record<string, unknown>) => ({
+    response: "ok",
+    functionCalls: [],
+    metadata: { stopReason: "eogToken" },
+  })),
+);
+
+vi.mock("../memory/node-llama.js", () => ({
+  importNodeLlamaCpp: async () => ({
+    LlamaLogLevel: { error: "error" },
+    resolveModelFile: async (modelPath: string) => modelPath,
+    getLlama: async () => ({
+      loadModel: async () => ({
+        createContext: async () => ({
+          getSequence: () => ({ tokenMeter: { usedInputTokens: 0, usedOutputTokens: 0 } }),
+        }),
+      }),
+    }),
+    LlamaChat: class {
+      generateResponse = generateResponse;
+      dispose() {}
+    },
+  }),
+}));
+
+describe("resolveLlamaCppModelPath", () => {
+  it("resolves relative ids against the model dir", () => {
+    expect(resolveLlamaCppModelPath("qwen.gguf", "/opt/models")).toBe(
+      path.resolve("/opt/models", "qwen.gguf"),
+    );
+    expect(resolveLlamaCppModelPath("qwen.gguf", "file:///opt/models")).toBe(
+      path.resolve("/opt/models", "qwen.gguf"),
+    );
+  });
+
+  it("keeps absolute paths and remote URIs", () => {
+    expect(resolveLlamaCppModelPath("/srv/m.gguf", "/opt/models")).toBe("/srv/m.gguf");
+    expect(resolveLlamaCppModelPath("hf:org/repo:Q4_K_M", "/opt/models")).toBe(
+      "hf:org/repo:Q4_K_M",
+    );
+  });
+});
+
+describe("resolveLlamaCppOptions", () => {
+  it("reads positive integers and gpu layer modes", () => {
+    expect(
+      resolveLlamaCppOptions("/m", { contextSize: 8192.7, threads: 4, gpuLayers: "max" }),
+    ).toEqual({ modelDir: "/m", contextSize: 8192, threads: 4, gpuLayers: "max" });
+  });
+
+  it("drops invalid values", () => {
+    expect(
+      resolveLlamaCppOptions("/m", { contextSize: -1, threads: "4", gpuLayers: "all" }),
+    ).toEqual({ modelDir: "/m", contextSize: undefined, threads: undefined, gpuLayers: undefined });
+    expect(resolveLlamaCppOptions("/m", { gpuLayers: 0 }).gpuLayers).toBe(0);
+  });
+});
+
+describe("convertToLlamaChatHistory", () => {
+  it("folds tool results into the calling model turn", () => {
+    const history = convertToLlamaChatHistory(
+      [
+        { role: "user", content: "weather?" },
+        {
+          role: "assistant",
+          content: [
+            { type: "text", text: "Checking." },
+            { type: "toolCall", id: "c1", name: "weather", arguments: { city: "Oslo" } },
+          ],
+        },
+        { role: "toolResult", toolCallId: "c1", content: [{ type: "text", text: "rain" }] },
+        { role: "assistant", content: [{ type: "text", text: "It rains." }] },
+      ],
+      "be brief",
+    );
+    expect(history).toEqual([
+      { type: "system", text: "be brief" },
+      { type: "user", text: "weather?" },
+      {
+        type: "model",
+        response: [
+          "Checking.",
+          { type: "functionCall", name: "weather", params: { city: "Oslo" }, result: "rain" },
+        ],
+      },
+      { type: "model", response: ["It rains."] },
+    ]);
+  });
+});
+
+describe("createLlamaCppStreamFn", () => {
+  afterEach(() => {
+    __testing.reset();
+    generateResponse.mockClear();
+  });
+
+  it("sends temperature, maxTokens, topP, and stop from model params", async () => {
+    const cfg: OpenClawConfig = {
+      agents: {
+        defaults: {
+          models: {
+            "llamacpp/qwen.gguf": {
+              params: { temperature: 0.2, maxTokens: 256, topP: 0.9, stop: ["<END>"] },
+            },
+          },
+        },
+      },
+    };
+    const agent = { streamFn: createLlamaCppStreamFn(resolveLlamaCppOptions("/m", undefined)) };
+    applyExtraParamsToAgent(agent, cfg, "llamacpp", "qwen.gguf");
+    const model = {
+      id: "qwen.gguf",
+      api: "llama-cpp",
+      provider: "llamacpp",
+      contextWindow: 4096,
+    } as unknown as Model<"openai-completions">;
+
+    const stream = await agent.streamFn(model, { messages: [] }, {});
+    const message = await stream.result();
+
+    expect(message.stopReason).toBe("stop");
+    expect(generateResponse).toHaveBeenCalledWith(
+      expect.any(Array),
+      expect.objectContaining({
+        temperature: 0.2,
+        maxTokens: 256,
+        topP: 0.9,
+        customStopTriggers: ["<END>"],
+      }),
+    );
+  });
+});
@@ -0,0 +1,329 @@
+import path from "node:path";
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import type {
+  AssistantMessage,
+  StopReason,
+  TextContent,
+  Tool,
+  ToolCall,
+  Usage,
+} from "@mariozechner/pi-ai";
+import { createAssistantMessageEventStream } from "@mariozechner/pi-ai";
+import type {
+  ChatHistoryFunctionCall,
+  ChatHistoryItem,
+  ChatModelFunctions,
+  Llama,
+  LlamaContext,
+  LlamaModel,
+} from "node-llama-cpp";
+import { createAsyncLock } from "../infra/json-files.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { importNodeLlamaCpp } from "../memory/node-llama.js";
+
+const log = createSubsystemLogger("llama-cpp");
+
+/**
+ * In-process llama.cpp settings, read from the model's `params`
+ * (`agents.defaults.models["<provider>/<model>"].params`).
+ */
+export type LlamaCppRuntimeOptions = {
+  /** Directory that relative GGUF model ids resolve against (the provider `baseUrl`). */
+  modelDir: string;
+  /** KV cache size in tokens. Default: the model's `contextWindow`. */
+  contextSize?: number;
+  /** CPU threads for inference. Default: node-llama-cpp's choice (all performance cores). */
+  threads?: number;
+  /** Layers to offload to the GPU. Default: "auto". */
+  gpuLayers?: number | "auto" | "max";
+};
+
+type LoadedModel = {
+  model: LlamaModel;
+  context: LlamaContext;
+  /** One context sequence per model: runs are serialized. */
+  withLock: <T>(fn: () => Promise<T>) => Promise<T>;
+};
+
+let llamaPromise: Promise<Llama> | null = null;
+const loadedModels = new Map<string, Promise<LoadedModel>>();
+
+export function resolveLlamaCppOptions(
+  modelDir: string,
+  params: Record<string, unknown> | undefined,
+): LlamaCppRuntimeOptions {
+  const positiveInt = (value: unknown) =>
+    typeof value === "number" && Number.isFinite(value) && value > 0
+      ? Math.floor(value)
+      : undefined;
+  const gpuLayers = params?.gpuLayers;
+  return {
+    modelDir,
+    contextSize: positiveInt(params?.contextSize),
+    threads: positiveInt(params?.threads),
+    gpuLayers:
+      gpuLayers === "auto" || gpuLayers === "max"
+        ? gpuLayers
+        : typeof gpuLayers === "number" && gpuLayers >= 0
+          ? Math.floor(gpuLayers)
+          : undefined,
+  };
+}
+
+/** GGUF ids are absolute paths, `hf:` / `https:` URIs, or paths relative to the provider dir. */
+export function resolveLlamaCppModelPath(modelId: string, modelDir: string): string {
+  if (/^(hf:|https?:)/i.test(modelId) || path.isAbsolute(modelId)) {
+    return modelId;
+  }
+  const dir = modelDir.replace(/^file:\/\//i, "");
+  return path.resolve(dir, modelId);
+}
+
+async function loadModel(
+  modelId: string,
+  options: LlamaCppRuntimeOptions,
+  defaultContextSize: number | undefined,
+): Promise<LoadedModel> {
+  const modelPath = resolveLlamaCppModelPath(modelId, options.modelDir);
+  const contextSize = options.contextSize ?? defaultContextSize;
+  const key = JSON.stringify([modelPath, contextSize, options.threads, options.gpuLayers]);
+  let pending = loadedModels.get(key);
+  if (!pending) {
+    pending = (async () => {
+      // Lazy-load node-llama-cpp so the optional dependency is only needed when this
+      // provider is configured.
+      const { getLlama, resolveModelFile, LlamaLogLevel } = await importNodeLlamaCpp();
+      llamaPromise ??= getLlama({ logLevel: LlamaLogLevel.error });
+      const llama = await llamaPromise;
+      const resolved = await resolveModelFile(
+        modelPath,
+        /^(hf:|https?:)/i.test(modelPath) ? options.modelDir : undefined,
+      );
+      log.info(`loading ${resolved} (context=${contextSize ?? "auto"})`);
+      const model = await llama.loadModel({
+        modelPath: resolved,
+        gpuLayers: options.gpuLayers ?? "auto",
+      });
+      const context = await model.createContext({
+        contextSize: contextSize ?? "auto",
+        ...(options.threads ? { threads: options.threads } : {}),
+      });
+      return { model, context, withLock: createAsyncLock() };
+    })();
+    pending.catch(() => loadedModels.delete(key));
+    loadedModels.set(key, pending);
+  }
+  return await pending;
+}
+
+type InputPart =
+  | { type: "text"; text: string }
+  | { type: "image"; data: string }
+  | { type: "toolCall"; id: string; name: string; arguments: Record<string, unknown> };
+
+function textOf(content: unknown): string {
+  if (typeof content === "string") {
+    return content;
+  }
+  if (!Array.isArray(content)) {
+    return "";
+  }
+  return (content as InputPart[])
+    .filter((part): part is { type: "text"; text: string } => part.type === "text")
+    .map((part) => part.text)
+    .join("");
+}
+
+/**
+ * Convert agent messages to node-llama-cpp chat history. Tool calls and their results are
+ * folded into the model turn that made them, which is how node-llama-cpp renders function
+ * calling for the model's chat template.
+ */
+export function convertToLlamaChatHistory(
+  messages: Array<{ role: string; content: unknown; toolCallId?: string }>,
+  system?: string,
+): ChatHistoryItem[] {
+  const history: ChatHistoryItem[] = system ? [{ type: "system", text: system }] : [];
+  const callsById = new Map<string, ChatHistoryFunctionCall>();
+  for (const msg of messages) {
+    if (msg.role === "user") {
+      history.push({ type: "user", text: textOf(msg.content) });
+    } else if (msg.role === "assistant") {
+      const response: Array<string | ChatHistoryFunctionCall> = [];
+      const text = textOf(msg.content);
+      if (text) {
+        response.push(text);
+      }
+      for (const part of Array.isArray(msg.content) ? (msg.content as InputPart[]) : []) {
+        if (part.type === "toolCall") {
+          const call: ChatHistoryFunctionCall = {
+            type: "functionCall",
+            name: part.name,
+            params: part.arguments,
+            result: undefined,
+          };
+          callsById.set(part.id, call);
+          response.push(call);
+        }
+      }
+      history.push({ type: "model", response });
+    } else if (msg.role === "toolResult" || msg.role === "tool") {
+      const call = msg.toolCallId ? callsById.get(msg.toolCallId) : undefined;
+      if (call) {
+        call.result = textOf(msg.content);
+      }
+    }
+  }
+  return history;
+}
+
+function buildFunctions(tools: Tool[] | undefined): ChatModelFunctions | undefined {
+  if (!tools?.length) {
+    return undefined;
+  }
+  const functions: ChatModelFunctions = {};
+  for (const tool of tools) {
+    functions[tool.name] = {
+      description: tool.description,
+      params: tool.parameters as Record<string, unknown>,
+    };
+  }
+  return functions;
+}
+
+/**
+ * Sampling settings in OpenAI-style field names. There is no HTTP request, but the object is
+ * handed to `onPayload` so `topP`/`stop` model params land here like they do for other APIs.
+ */
+type LlamaCppRequest = {
+  model: string;
+  max_tokens?: number;
+  temperature?: number;
+  top_p?: number;
+  stop?: string[];
+};
+
+function emptyUsage(): Usage {
+  return {
+    input: 0,
+    output: 0,
+    cacheRead: 0,
+    cacheWrite: 0,
+    totalTokens: 0,
+    cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+  };
+}
+
+/**
+ * StreamFn that runs a GGUF model in-process through node-llama-cpp (no server, no network
+ * once the model file is local). Selected for models with `api: "llama-cpp"`.
+ */
+export function createLlamaCppStreamFn(options: LlamaCppRuntimeOptions): StreamFn {
+  return (model, context, streamOptions) => {
+    const stream = createAssistantMessageEventStream();
+
+    const run = async () => {
+      try {
+        const loaded = await loadModel(model.id, options, model.contextWindow);
+        const { LlamaChat } = await importNodeLlamaCpp();
+        const message = await loaded.withLock(async () => {
+          const sequence = loaded.context.getSequence();
+          const chat = new LlamaChat({ contextSequence: sequence });
+          const before = { ...sequence.tokenMeter };
+          try {
+            const request: LlamaCppRequest = {
+              model: model.id,
+              ...(typeof streamOptions?.maxTokens === "number"
+                ? { max_tokens: streamOptions.maxTokens }
+                : {}),
+              ...(typeof streamOptions?.temperature === "number"
+                ? { temperature: streamOptions.temperature }
+                : {}),
+            };
+            streamOptions?.onPayload?.(request);
+            const result = await chat.generateResponse(
+              convertToLlamaChatHistory(context.messages ?? [], context.systemPrompt),
+              {
+                functions: buildFunctions(context.tools),
+                ...(typeof request.max_tokens === "number"
+                  ? { maxTokens: request.max_tokens }
+                  : {}),
+                ...(typeof request.temperature === "number"
+                  ? { temperature: request.temperature }
+                  : {}),
+                ...(typeof request.top_p === "number" ? { topP: request.top_p } : {}),
+                ...(request.stop?.length ? { customStopTriggers: request.stop } : {}),
+                signal: streamOptions?.signal,
+                stopOnAbortSignal: true,
+              },
+            );
+            const content: (TextContent | ToolCall)[] = [];
+            if (result.response) {
+              content.push({ type: "text", text: result.response });
+            }
+            for (const [index, call] of (result.functionCalls ?? []).entries()) {
+              content.push({
+                type: "toolCall",
+                id: `llamacpp_call_${Date.now()}_${index}`,
+                name: call.functionName,
+                arguments: (call.params ?? {}) as Record<string, unknown>,
+              });
+            }
+            const input = sequence.tokenMeter.usedInputTokens - before.usedInputTokens;
+            const output = sequence.tokenMeter.usedOutputTokens - before.usedOutputTokens;
+            const stopReason: StopReason = result.functionCalls?.length
+              ? "toolUse"
+              : result.metadata.stopReason === "maxTokens"
+                ? "length"
+                : "stop";
+            return {
+              role: "assistant",
+              content,
+              stopReason,
+              api: model.api,
+              provider: model.provider,
+              model: model.id,
+              usage: { ...emptyUsage(), input, output, totalTokens: input + output },
+              timestamp: Date.now(),
+            } satisfies AssistantMessage;
+          } finally {
+            chat.dispose({ disposeSequence: true });
+          }
+        });
+        stream.push({
+          type: "done",
+          reason: message.stopReason as Extract<StopReason, "stop" | "length" | "toolUse">,
+          message,
+        });
+      } catch (err) {
+        stream.push({
+          type: "error",
+          reason: "error",
+          error: {
+            role: "assistant" as const,
+            content: [],
+            stopReason: "error" as StopReason,
+            errorMessage: err instanceof Error ? err.message : String(err),
+            api: model.api,
+            provider: model.provider,
+            model: model.id,
+            usage: emptyUsage(),
+            timestamp: Date.now(),
+          },
+        });
+      } finally {
+        stream.end();
+      }
+    };
+
+    queueMicrotask(() => void run());
+    return stream;
+  };
+}
+
+export const __testing = {
+  reset() {
+    llamaPromise = null;
+    loadedModels.clear();
+  },
+} as const;
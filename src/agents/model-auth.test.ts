@@ -32,6 +32,25 @@ describe("resolveAwsSdkEnvVarName", () => {
     expect(resolveAwsSdkEnvVarName(env)).toBe("AWS_PROFILE");
   });
 
+  it("detects IAM role credentials from EKS web identity and ECS task roles", () => {
+    expect(
+      resolveAwsSdkEnvVarName({
+        AWS_WEB_IDENTITY_TOKEN_FILE: "/var/run/secrets/eks.amazonaws.com/serviceaccount/token",
+        AWS_ROLE_ARN: "arn:aws:iam::123456789012:role/openclaw",
+      } as NodeJS.ProcessEnv),
+    ).toBe("AWS_WEB_IDENTITY_TOKEN_FILE");
+    expect(
+      resolveAwsSdkEnvVarName({
+        AWS_WEB_IDENTITY_TOKEN_FILE: "/var/run/secrets/token",
+      } as NodeJS.ProcessEnv),
+    ).toBeUndefined();
+    expect(
+      resolveAwsSdkEnvVarName({
+        AWS_CONTAINER_CREDENTIALS_RELATIVE_URI: "/v2/credentials/abc",
+      } as NodeJS.ProcessEnv),
+    ).toBe("AWS_CONTAINER_CREDENTIALS_RELATIVE_URI");
+  });
+
   it("returns undefined when no AWS auth env is set", () => {
     expect(resolveAwsSdkEnvVarName({} as NodeJS.ProcessEnv)).toBeUndefined();
   });
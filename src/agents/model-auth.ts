@@ -24,6 +24,20 @@ const AWS_BEARER_ENV = "AWS_BEARER_TOKEN_BEDROCK";
 const AWS_ACCESS_KEY_ENV = "AWS_ACCESS_KEY_ID";
 const AWS_SECRET_KEY_ENV = "AWS_SECRET_ACCESS_KEY";
 const AWS_PROFILE_ENV = "AWS_PROFILE";
+// IAM role credentials injected by the platform: EKS service accounts (IRSA) and ECS tasks.
+const AWS_WEB_IDENTITY_ENV = "AWS_WEB_IDENTITY_TOKEN_FILE";
+const AWS_ROLE_ARN_ENV = "AWS_ROLE_ARN";
+const AWS_CONTAINER_CREDENTIALS_ENVS = [
+  "AWS_CONTAINER_CREDENTIALS_RELATIVE_URI",
+  "AWS_CONTAINER_CREDENTIALS_FULL_URI",
+] as const;
+
+function resolveAwsRoleEnvVarName(env: NodeJS.ProcessEnv): string | undefined {
+  if (env[AWS_WEB_IDENTITY_ENV]?.trim() && env[AWS_ROLE_ARN_ENV]?.trim()) {
+    return AWS_WEB_IDENTITY_ENV;
+  }
+  return AWS_CONTAINER_CREDENTIALS_ENVS.find((key) => env[key]?.trim());
+}
 
 function resolveProviderConfig(
   cfg: OpenClawConfig | undefined,
@@ -87,7 +101,7 @@ export function resolveAwsSdkEnvVarName(env: NodeJS.ProcessEnv = process.env): s
   if (env[AWS_PROFILE_ENV]?.trim()) {
     return AWS_PROFILE_ENV;
   }
-  return undefined;
+  return resolveAwsRoleEnvVarName(env);
 }
 
 function resolveAwsSdkAuthInfo(): { mode: "aws-sdk"; source: string } {
@@ -122,6 +136,14 @@ function resolveAwsSdkAuthInfo(): { mode: "aws-sdk"; source: string } {
       }),
     };
   }
+  const roleEnv = resolveAwsRoleEnvVarName(process.env);
+  if (roleEnv) {
+    return {
+      mode: "aws-sdk",
+      source:
+        roleEnv === AWS_WEB_IDENTITY_ENV ? "aws-sdk web identity role" : "aws-sdk container role",
+    };
+  }
   return { mode: "aws-sdk", source: "aws-sdk default chain" };
 }
 
@@ -212,6 +234,11 @@ export async function resolveApiKeyForProvider(params: {
     return resolveAwsSdkAuthInfo();
   }
 
+  if (resolveProviderConfig(cfg, provider)?.api === "llama-cpp") {
+    // In-process llama.cpp inference needs no credentials.
+    return { apiKey: "llama-cpp-local", source: "llama-cpp (in-process)", mode: "api-key" };
+  }
+
   if (provider === "openai") {
     const hasCodex = listProfilesForProvider(store, "openai-codex").length > 0;
     if (hasCodex) {
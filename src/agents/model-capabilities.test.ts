@@ -0,0 +1,46 @@
+import { describe, expect, it } from "vitest";
+import { resolveModelCapabilities } from "./model-capabilities.js";
+
+describe("resolveModelCapabilities", () => {
+  it("derives capabilities from model metadata", () => {
+    expect(
+      resolveModelCapabilities({
+        api: "openai-completions",
+        input: ["text", "image"],
+        reasoning: true,
+        contextWindow: 128_000,
+        maxTokens: 16_384,
+      }),
+    ).toEqual({
+      tools: true,
+      vision: true,
+      streaming: true,
+      jsonMode: true,
+      reasoning: true,
+      contextWindow: 128_000,
+      maxTokens: 16_384,
+    });
+  });
+
+  it("honors compat.supportsTools and non-streaming backends", () => {
+    const caps = resolveModelCapabilities({
+      api: "llama-cpp",
+      input: ["text"],
+      contextWindow: 0,
+      compat: { supportsTools: false },
+    });
+    expect(caps).toMatchObject({
+      tools: false,
+      vision: false,
+      streaming: false,
+      jsonMode: false,
+      reasoning: false,
+    });
+    expect(caps.contextWindow).toBeUndefined();
+  });
+
+  it("reports JSON mode only for APIs with native schemas", () => {
+    expect(resolveModelCapabilities({ api: "openai-responses" }).jsonMode).toBe(true);
+    expect(resolveModelCapabilities({ api: "anthropic-messages" }).jsonMode).toBe(false);
+  });
+});
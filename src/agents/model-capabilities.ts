@@ -0,0 +1,47 @@
+import { supportsNativeJsonResponseFormat } from "./structured-output.js";
+
+/** What a resolved model can do, derived from its catalog/config metadata. */
+export type ModelCapabilities = {
+  /** Accepts tool definitions and returns tool calls. */
+  tools: boolean;
+  /** Accepts image input. */
+  vision: boolean;
+  /** Emits incremental text deltas (vs. one final message). */
+  streaming: boolean;
+  /** Accepts a JSON schema natively (`response_format` / `text.format`). */
+  jsonMode: boolean;
+  /** Has a reasoning/thinking mode. */
+  reasoning: boolean;
+  /** Max context in tokens, when known. */
+  contextWindow?: number;
+  /** Max output tokens, when known. */
+  maxTokens?: number;
+};
+
+// APIs whose stream functions only emit the final message.
+const NON_STREAMING_APIS = new Set(["llama-cpp"]);
+
+export function resolveModelCapabilities(model: {
+  api?: string;
+  input?: string[];
+  reasoning?: boolean;
+  contextWindow?: number;
+  maxTokens?: number;
+  compat?: unknown;
+}): ModelCapabilities {
+  const compat =
+    model.compat && typeof model.compat === "object"
+      ? (model.compat as { supportsTools?: unknown })
+      : undefined;
+  const positive = (value: number | undefined) =>
+    typeof value === "number" && Number.isFinite(value) && value > 0 ? value : undefined;
+  return {
+    tools: compat?.supportsTools !== false,
+    vision: model.input?.includes("image") ?? false,
+    streaming: !NON_STREAMING_APIS.has(model.api ?? ""),
+    jsonMode: supportsNativeJsonResponseFormat(model.api),
+    reasoning: model.reasoning === true,
+    contextWindow: positive(model.contextWindow),
+    maxTokens: positive(model.maxTokens),
+  };
+}
@@ -2,13 +2,13 @@ import crypto from "node:crypto";
 import fs from "node:fs/promises";
 import os from "node:os";
 import path from "node:path";
-import { describe, expect, it, vi } from "vitest";
+import { beforeEach, describe, expect, it, vi } from "vitest";
 import type { OpenClawConfig } from "../config/config.js";
 import type { AuthProfileStore } from "./auth-profiles.js";
 import { saveAuthProfileStore } from "./auth-profiles.js";
 import { AUTH_STORE_VERSION } from "./auth-profiles/constants.js";
 import { isAnthropicBillingError } from "./live-auth-keys.js";
-import { runWithModelFallback } from "./model-fallback.js";
+import { _modelCircuitInternals, runWithModelFallback } from "./model-fallback.js";
 import { makeModelFallbackCfg } from "./test-helpers/model-fallback-config-fixture.js";
 
 const makeCfg = makeModelFallbackCfg;
@@ -162,6 +162,10 @@ async function expectSkippedUnavailableProvider(params: {
 }
 
 describe("runWithModelFallback", () => {
+  beforeEach(() => {
+    _modelCircuitInternals.modelCircuits.clear();
+  });
+
   it("normalizes openai gpt-5.3 codex to openai-codex before running", async () => {
     const cfg = makeCfg();
     const run = vi.fn().mockResolvedValueOnce("ok");
@@ -652,6 +656,66 @@ describe("runWithModelFallback", () => {
   });
 });
 
+describe("runWithModelFallback outage circuit", () => {
+  beforeEach(() => {
+    _modelCircuitInternals.modelCircuits.clear();
+  });
+
+  it("skips a primary that keeps timing out until the circuit window passes", async () => {
+    const cfg = makeCfg();
+    const timeout = () => Object.assign(new Error("timeout"), { code: "ETIMEDOUT" });
+    const run = vi.fn(async (provider: string) => {
+      if (provider === "openai") {
+        throw timeout();
+      }
+      return "ok";
+    });
+
+    for (let i = 0; i < _modelCircuitInternals.MODEL_CIRCUIT_FAILURE_THRESHOLD; i += 1) {
+      await runWithModelFallback({ cfg, provider: "openai", model: "gpt-4.1-mini", run });
+    }
+    run.mockClear();
+
+    const skipped = await runWithModelFallback({
+      cfg,
+      provider: "openai",
+      model: "gpt-4.1-mini",
+      run,
+    });
+    expect(skipped.result).toBe("ok");
+    expect(run.mock.calls.map(([provider]) => provider)).toEqual(["anthropic"]);
+    expect(skipped.attempts[0]?.error).toContain("skipped after");
+    expect(skipped.attempts[0]?.reason).toBe("timeout");
+
+    vi.useFakeTimers();
+    try {
+      vi.setSystemTime(Date.now() + _modelCircuitInternals.MODEL_CIRCUIT_OPEN_MS + 1);
+      run.mockClear();
+      await runWithModelFallback({ cfg, provider: "openai", model: "gpt-4.1-mini", run });
+      expect(run.mock.calls.map(([provider]) => provider)).toEqual(["openai", "anthropic"]);
+    } finally {
+      vi.useRealTimers();
+    }
+  });
+
+  it("does not open the circuit for rate limits", async () => {
+    const cfg = makeCfg();
+    const run = vi.fn(async (provider: string) => {
+      if (provider === "openai") {
+        throw Object.assign(new Error("rate limited"), { status: 429 });
+      }
+      return "ok";
+    });
+
+    for (let i = 0; i < _modelCircuitInternals.MODEL_CIRCUIT_FAILURE_THRESHOLD + 1; i += 1) {
+      await runWithModelFallback({ cfg, provider: "openai", model: "gpt-4.1-mini", run });
+    }
+    expect(run.mock.calls.filter(([provider]) => provider === "openai")).toHaveLength(
+      _modelCircuitInternals.MODEL_CIRCUIT_FAILURE_THRESHOLD + 1,
+    );
+  });
+});
+
 describe("isAnthropicBillingError", () => {
   it("does not false-positive on plain 'a 402' prose", () => {
     const samples = [
@@ -277,6 +277,42 @@ export const _probeThrottleInternals = {
   resolveProbeThrottleKey,
 } as const;
 
+// Outage circuit: after repeated timeouts/5xx on the same model, skip straight to the next
+// fallback for a while instead of waiting out the full timeout on every run. Rate limits,
+// auth, and billing failures are tracked per auth profile (cooldowns) instead.
+const modelCircuits = new Map<string, { consecutiveFailures: number; openUntil: number }>();
+const MODEL_CIRCUIT_FAILURE_THRESHOLD = 3;
+const MODEL_CIRCUIT_OPEN_MS = 2 * 60 * 1000;
+
+function resolveModelCircuitKey(candidate: ModelCandidate, agentDir?: string): string {
+  return resolveProbeThrottleKey(modelKey(candidate.provider, candidate.model), agentDir);
+}
+
+function isModelCircuitOpen(key: string, now: number): boolean {
+  const circuit = modelCircuits.get(key);
+  return Boolean(circuit && now < circuit.openUntil);
+}
+
+function recordModelCircuitFailure(key: string, reason: FailoverReason | undefined, now: number) {
+  if (reason !== "timeout") {
+    return;
+  }
+  const consecutiveFailures = (modelCircuits.get(key)?.consecutiveFailures ?? 0) + 1;
+  modelCircuits.set(key, {
+    consecutiveFailures,
+    openUntil:
+      consecutiveFailures >= MODEL_CIRCUIT_FAILURE_THRESHOLD ? now + MODEL_CIRCUIT_OPEN_MS : 0,
+  });
+}
+
+/** @internal – exposed for unit tests only */
+export const _modelCircuitInternals = {
+  modelCircuits,
+  MODEL_CIRCUIT_FAILURE_THRESHOLD,
+  MODEL_CIRCUIT_OPEN_MS,
+  resolveModelCircuitKey,
+} as const;
+
 export async function runWithModelFallback<T>(params: {
   cfg: OpenClawConfig | undefined;
   provider: string;
@@ -303,6 +339,17 @@ export async function runWithModelFallback<T>(params: {
 
   for (let i = 0; i < candidates.length; i += 1) {
     const candidate = candidates[i];
+    const circuitKey = resolveModelCircuitKey(candidate, params.agentDir);
+    if (i < candidates.length - 1 && isModelCircuitOpen(circuitKey, Date.now())) {
+      // Never skip the last candidate: with nothing left to fail over to, try it anyway.
+      attempts.push({
+        provider: candidate.provider,
+        model: candidate.model,
+        error: `Model ${candidate.provider}/${candidate.model} skipped after ${MODEL_CIRCUIT_FAILURE_THRESHOLD}+ consecutive timeouts`,
+        reason: "timeout",
+      });
+      continue;
+    }
     if (authStore) {
       const profileIds = resolveAuthProfileOrder({
         cfg: params.cfg,
@@ -350,6 +397,7 @@ export async function runWithModelFallback<T>(params: {
     }
     try {
       const result = await params.run(candidate.provider, candidate.model);
+      modelCircuits.delete(circuitKey);
       return {
         result,
         provider: candidate.provider,
@@ -379,6 +427,7 @@ export async function runWithModelFallback<T>(params: {
 
       lastError = normalized;
       const described = describeFailoverError(normalized);
+      recordModelCircuitFailure(circuitKey, described.reason, Date.now());
       attempts.push({
         provider: candidate.provider,
         model: candidate.model,
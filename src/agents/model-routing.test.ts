@@ -0,0 +1,59 @@
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import type { ModelCatalogEntry } from "./model-catalog.js";
+import { hasImageAttachments, resolveTaskModelRef, resolveVisionRoute } from "./model-routing.js";
+import { buildModelAliasIndex } from "./model-selection.js";
+
+const catalog: ModelCatalogEntry[] = [
+  { id: "deepseek-chat", name: "DeepSeek Chat", provider: "deepseek", input: ["text"] },
+  { id: "gpt-5.2", name: "GPT-5.2", provider: "openai", input: ["text", "image"] },
+];
+
+const cfg: OpenClawConfig = {
+  agents: {
+    defaults: {
+      models: { "openai/gpt-5.2": { alias: "eyes" } },
+      modelRouting: { vision: "eyes", cron: "openai/gpt-5-mini" },
+    },
+  },
+};
+
+describe("model routing", () => {
+  const aliasIndex = buildModelAliasIndex({ cfg, defaultProvider: "anthropic" });
+
+  it("resolves task routes from provider/model refs and aliases", () => {
+    expect(
+      resolveTaskModelRef({ cfg, task: "cron", defaultProvider: "anthropic", aliasIndex }),
+    ).toEqual({ provider: "openai", model: "gpt-5-mini" });
+    expect(
+      resolveTaskModelRef({ cfg, task: "vision", defaultProvider: "anthropic", aliasIndex }),
+    ).toEqual({ provider: "openai", model: "gpt-5.2" });
+    expect(resolveTaskModelRef({ cfg: {}, task: "vision", defaultProvider: "anthropic" })).toBe(
+      null,
+    );
+  });
+
+  it("routes image turns away from text-only models only", () => {
+    const route = (provider: string, model: string) =>
+      resolveVisionRoute({
+        cfg,
+        catalog,
+        provider,
+        model,
+        defaultProvider: "anthropic",
+        aliasIndex,
+      });
+
+    expect(route("deepseek", "deepseek-chat")).toEqual({ provider: "openai", model: "gpt-5.2" });
+    expect(route("openai", "gpt-5.2")).toBeNull();
+    // Unknown to the catalog: leave the selection alone.
+    expect(route("ollama", "llava:13b")).toBeNull();
+  });
+
+  it("detects image attachments from media types", () => {
+    expect(hasImageAttachments({ MediaTypes: ["audio/ogg", "image/jpeg"] })).toBe(true);
+    expect(hasImageAttachments({ MediaType: "image/png" })).toBe(true);
+    expect(hasImageAttachments({ MediaType: "application/pdf" })).toBe(false);
+    expect(hasImageAttachments({})).toBe(false);
+  });
+});
@@ -0,0 +1,60 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { ModelCatalogEntry } from "./model-catalog.js";
+import { findModelInCatalog, modelSupportsVision } from "./model-catalog.js";
+import type { ModelAliasIndex, ModelRef } from "./model-selection.js";
+import { resolveModelRefFromString } from "./model-selection.js";
+
+export type ModelRouteTask = "vision" | "cron";
+
+/** Resolve the configured `agents.defaults.modelRouting.<task>` model, if any. */
+export function resolveTaskModelRef(params: {
+  cfg: OpenClawConfig;
+  task: ModelRouteTask;
+  defaultProvider: string;
+  aliasIndex?: ModelAliasIndex;
+}): ModelRef | null {
+  const raw = params.cfg.agents?.defaults?.modelRouting?.[params.task]?.trim();
+  if (!raw) {
+    return null;
+  }
+  return (
+    resolveModelRefFromString({
+      raw,
+      defaultProvider: params.defaultProvider,
+      aliasIndex: params.aliasIndex,
+    })?.ref ?? null
+  );
+}
+
+export function hasImageAttachments(ctx: { MediaType?: string; MediaTypes?: string[] }): boolean {
+  const types = ctx.MediaTypes?.length ? ctx.MediaTypes : ctx.MediaType ? [ctx.MediaType] : [];
+  return types.some((type) => type?.trim().toLowerCase().startsWith("image/"));
+}
+
+/**
+ * Route an image-bearing turn to `modelRouting.vision` when the selected model cannot read images.
+ * Unknown models (not in the catalog) are left alone.
+ */
+export function resolveVisionRoute(params: {
+  cfg: OpenClawConfig;
+  catalog: ModelCatalogEntry[];
+  provider: string;
+  model: string;
+  defaultProvider: string;
+  aliasIndex?: ModelAliasIndex;
+}): ModelRef | null {
+  const route = resolveTaskModelRef({
+    cfg: params.cfg,
+    task: "vision",
+    defaultProvider: params.defaultProvider,
+    aliasIndex: params.aliasIndex,
+  });
+  if (!route || (route.provider === params.provider && route.model === params.model)) {
+    return null;
+  }
+  const current = findModelInCatalog(params.catalog, params.provider, params.model);
+  if (!current || modelSupportsVision(current)) {
+    return null;
+  }
+  return route;
+}
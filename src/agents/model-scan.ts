@@ -44,6 +44,8 @@ type OpenRouterModelPricing = {
   image: number;
   webSearch: number;
   internalReasoning: number;
+  inputCacheRead: number;
+  inputCacheWrite: number;
 };
 
 export type ProbeResult = {
@@ -137,6 +139,8 @@ function parseOpenRouterPricing(value: unknown): OpenRouterModelPricing | null {
   const image = parseNumberString(obj.image) ?? 0;
   const webSearch = parseNumberString(obj.web_search) ?? 0;
   const internalReasoning = parseNumberString(obj.internal_reasoning) ?? 0;
+  const inputCacheRead = parseNumberString(obj.input_cache_read) ?? 0;
+  const inputCacheWrite = parseNumberString(obj.input_cache_write) ?? 0;
 
   if (prompt === null || completion === null) {
     return null;
@@ -148,6 +152,8 @@ function parseOpenRouterPricing(value: unknown): OpenRouterModelPricing | null {
     image,
     webSearch,
     internalReasoning,
+    inputCacheRead,
+    inputCacheWrite,
   };
 }
 
@@ -174,7 +180,9 @@ async function withTimeout<T>(
   }
 }
 
-async function fetchOpenRouterModels(fetchImpl: typeof fetch): Promise<OpenRouterModelMeta[]> {
+export async function fetchOpenRouterModels(
+  fetchImpl: typeof fetch,
+): Promise<OpenRouterModelMeta[]> {
   const res = await fetchImpl(OPENROUTER_MODELS_URL, {
     headers: { Accept: "application/json" },
   });
@@ -4,6 +4,7 @@ import {
   convertToOllamaMessages,
   buildAssistantMessage,
   parseNdjsonStream,
+  resolveOllamaKeepAlive,
 } from "./ollama-stream.js";
 
 describe("convertToOllamaMessages", () => {
@@ -303,8 +304,9 @@ async function withMockNdjsonFetch(
 async function createOllamaTestStream(params: {
   baseUrl: string;
   options?: { maxTokens?: number; signal?: AbortSignal };
+  keepAlive?: string | number;
 }) {
-  const streamFn = createOllamaStreamFn(params.baseUrl);
+  const streamFn = createOllamaStreamFn(params.baseUrl, { keepAlive: params.keepAlive });
   return streamFn(
     {
       id: "qwen3:32b",
@@ -357,10 +359,31 @@ describe("createOllamaStreamFn", () => {
         };
         expect(requestBody.options.num_ctx).toBe(131072);
         expect(requestBody.options.num_predict).toBe(123);
+        expect(requestBody).not.toHaveProperty("keep_alive");
       },
     );
   });
 
+  it("forwards keep_alive when configured", async () => {
+    await withMockNdjsonFetch(
+      [
+        '{"model":"m","created_at":"t","message":{"role":"assistant","content":"ok"},"done":true,"prompt_eval_count":1,"eval_count":1}',
+      ],
+      async (fetchMock) => {
+        const stream = await createOllamaTestStream({
+          baseUrl: "http://ollama-host:11434",
+          keepAlive: resolveOllamaKeepAlive(" 24h "),
+        });
+        await collectStreamEvents(stream);
+        const [, requestInit] = fetchMock.mock.calls[0] as unknown as [string, RequestInit];
+        expect(JSON.parse(requestInit.body as string)).toMatchObject({ keep_alive: "24h" });
+      },
+    );
+    expect(resolveOllamaKeepAlive(-1)).toBe(-1);
+    expect(resolveOllamaKeepAlive("")).toBeUndefined();
+    expect(resolveOllamaKeepAlive({})).toBeUndefined();
+  });
+
   it("accumulates reasoning chunks when content is empty", async () => {
     await withMockNdjsonFetch(
       [
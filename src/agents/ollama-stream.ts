@@ -23,6 +23,9 @@ interface OllamaChatRequest {
   stream: boolean;
   tools?: OllamaTool[];
   options?: Record<string, unknown>;
+  keep_alive?: string | number;
+  /** JSON schema constraining the reply (structured outputs). */
+  format?: Record<string, unknown>;
 }
 
 interface OllamaChatMessage {
@@ -411,7 +414,25 @@ function resolveOllamaChatUrl(baseUrl: string): string {
   return `${apiBase}/api/chat`;
 }
 
-export function createOllamaStreamFn(baseUrl: string): StreamFn {
+/**
+ * Normalize a `keepAlive` model param to Ollama's `keep_alive`: a duration string ("10m",
+ * "24h"), or seconds as a number (0 unloads right after the reply, negative keeps it loaded).
+ */
+export function resolveOllamaKeepAlive(value: unknown): string | number | undefined {
+  if (typeof value === "number") {
+    return Number.isFinite(value) ? value : undefined;
+  }
+  if (typeof value === "string") {
+    const trimmed = value.trim();
+    return trimmed ? trimmed : undefined;
+  }
+  return undefined;
+}
+
+export function createOllamaStreamFn(
+  baseUrl: string,
+  opts?: { keepAlive?: string | number; format?: Record<string, unknown> },
+): StreamFn {
   const chatUrl = resolveOllamaChatUrl(baseUrl);
 
   return (model, context, options) => {
@@ -442,7 +463,10 @@ export function createOllamaStreamFn(baseUrl: string): StreamFn {
           stream: true,
           ...(ollamaTools.length > 0 ? { tools: ollamaTools } : {}),
           options: ollamaOptions,
+          ...(opts?.keepAlive !== undefined ? { keep_alive: opts.keepAlive } : {}),
+          ...(opts?.format ? { format: opts.format } : {}),
         };
+        options?.onPayload?.(body);
 
         const headers: Record<string, string> = {
           "Content-Type": "application/json",
@@ -0,0 +1,60 @@
+import { describe, expect, it, vi } from "vitest";
+import { createSessionsSpawnTool } from "./tools/sessions-spawn-tool.js";
+
+vi.mock("../config/config.js", async () => {
+  const actual = await vi.importActual("../config/config.js");
+  return {
+    ...actual,
+    loadConfig: () => ({
+      routing: {
+        sessions: {
+          mainKey: "agent:test:main",
+        },
+      },
+    }),
+  };
+});
+
+vi.mock("../gateway/call.js", () => {
+  return {
+    callGateway: vi.fn(async ({ method }: { method: string }) => {
+      if (method === "agent") {
+        return { runId: "run-123" };
+      }
+      return {};
+    }),
+  };
+});
+
+type GatewayCall = { method: string; params?: Record<string, unknown> };
+
+async function getGatewayCalls(): Promise<GatewayCall[]> {
+  const { callGateway } = await import("../gateway/call.js");
+  return (callGateway as unknown as ReturnType<typeof vi.fn>).mock.calls.map(
+    (call) => call[0] as GatewayCall,
+  );
+}
+
+describe("sessions_spawn tools and systemPrompt", () => {
+  it("stores the tool subset on the child and adds role instructions", async () => {
+    const tool = createSessionsSpawnTool({ agentSessionKey: "agent:test:main" });
+    const result = await tool.execute("call-1", {
+      task: "Watch the CAN bus log for error frames",
+      tools: ["read", "exec"],
+      systemPrompt: "You are a log analyst. Report only anomalies.",
+    });
+    expect(result.details).toMatchObject({ status: "accepted" });
+
+    const calls = await getGatewayCalls();
+    const toolsPatch = calls.find(
+      (call) => call.method === "sessions.patch" && call.params?.spawnTools !== undefined,
+    );
+    expect(toolsPatch?.params?.spawnTools).toEqual(["read", "exec"]);
+
+    const agentCall = calls.find((call) => call.method === "agent");
+    const systemPrompt = String(agentCall?.params?.extraSystemPrompt ?? "");
+    expect(systemPrompt).toContain("## Instructions From Your Requester");
+    expect(systemPrompt).toContain("You are a log analyst. Report only anomalies.");
+    expect(systemPrompt).toContain("limited you to these tools: read, exec");
+  });
+});
@@ -2,6 +2,7 @@ import type { OpenClawConfig } from "../config/config.js";
 import { resolvePluginTools } from "../plugins/tools.js";
 import type { GatewayMessageChannel } from "../utils/message-channel.js";
 import { resolveSessionAgentId } from "./agent-scope.js";
+import { wrapToolWithBeforeToolCallHook } from "./pi-tools.before-tool-call.js";
 import type { SandboxFsBridge } from "./sandbox/fs-bridge.js";
 import { createAgentsListTool } from "./tools/agents-list-tool.js";
 import { createBrowserTool } from "./tools/browser-tool.js";
@@ -9,17 +10,28 @@ import { createCanvasTool } from "./tools/canvas-tool.js";
 import type { AnyAgentTool } from "./tools/common.js";
 import { createCronTool } from "./tools/cron-tool.js";
 import { createGatewayTool } from "./tools/gateway-tool.js";
+import { createGoalSetTool, createGoalStatusTool } from "./tools/goal-tools.js";
 import { createImageTool } from "./tools/image-tool.js";
 import { createMessageTool } from "./tools/message-tool.js";
 import { createNodesTool } from "./tools/nodes-tool.js";
+import { createRemindMeTool } from "./tools/remind-tool.js";
 import { createSessionStatusTool } from "./tools/session-status-tool.js";
 import { createSessionsHistoryTool } from "./tools/sessions-history-tool.js";
 import { createSessionsListTool } from "./tools/sessions-list-tool.js";
 import { createSessionsSendTool } from "./tools/sessions-send-tool.js";
 import { createSessionsSpawnTool } from "./tools/sessions-spawn-tool.js";
 import { createSubagentsTool } from "./tools/subagents-tool.js";
+import {
+  createTaskAddTool,
+  createTaskCompleteTool,
+  createTaskListTool,
+} from "./tools/task-tools.js";
+import { createTranscribeTool } from "./tools/transcribe-tool.js";
 import { createTtsTool } from "./tools/tts-tool.js";
+import { createUndoLastTool } from "./tools/undo-tool.js";
 import { createWebFetchTool, createWebSearchTool } from "./tools/web-tools.js";
+import { resolveToolAutonomy } from "./tool-autonomy.js";
+import { resolveToolGuardrails } from "./tool-guardrails.js";
 import { resolveWorkspaceRoot } from "./workspace-dir.js";
 
 export function createOpenClawTools(options?: {
@@ -65,6 +77,8 @@ export function createOpenClawTools(options?: {
   requesterSenderId?: string | null;
   /** Whether the requesting sender is an owner. */
   senderIsOwner?: boolean;
+  /** Looks up the tool an undo replays; agent runs pass their hook-wrapped tools. */
+  resolveUndoTool?: (name: string) => AnyAgentTool | undefined;
 }): AnyAgentTool[] {
   const workspaceDir = resolveWorkspaceRoot(options?.workspaceDir);
   const imageTool = options?.agentDir?.trim()
@@ -112,14 +126,60 @@ export function createOpenClawTools(options?: {
       agentSessionKey: options?.agentSessionKey,
       config: options?.config,
     }),
+    createUndoLastTool({
+      agentSessionKey: options?.agentSessionKey,
+      resolveTool:
+        options?.resolveUndoTool ??
+        createUndoToolResolver(() => allTools, {
+          config: options?.config,
+          sessionKey: options?.agentSessionKey,
+        }),
+    }),
     createCronTool({
       agentSessionKey: options?.agentSessionKey,
     }),
+    createRemindMeTool({
+      agentSessionKey: options?.agentSessionKey,
+      agentChannel: options?.agentChannel,
+      agentTo: options?.agentTo,
+      requesterSenderId: options?.requesterSenderId ?? undefined,
+      config: options?.config,
+    }),
+    createTaskAddTool({
+      agentSessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
+    createTaskListTool({
+      agentSessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
+    createTaskCompleteTool({
+      agentSessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
+    createGoalSetTool({
+      agentSessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
+    createGoalStatusTool({
+      agentSessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
     ...(messageTool ? [messageTool] : []),
     createTtsTool({
       agentChannel: options?.agentChannel,
       config: options?.config,
     }),
+    // Sandboxed sessions see container paths the host-side audio pipeline cannot read.
+    ...(options?.sandboxed
+      ? []
+      : [
+          createTranscribeTool({
+            config: options?.config,
+            agentDir: options?.agentDir,
+            workspaceDir,
+          }),
+        ]),
     createGatewayTool({
       agentSessionKey: options?.agentSessionKey,
       config: options?.config,
@@ -183,5 +243,30 @@ export function createOpenClawTools(options?: {
     toolAllowlist: options?.pluginToolAllowlist,
   });
 
-  return [...tools, ...pluginTools];
+  const allTools = [...tools, ...pluginTools];
+  return allTools;
+}
+
+/**
+ * Undo entries replay built-in or plugin tools. The replay goes through the same checks as a
+ * model call (autonomy, plugin `before_tool_call` hooks, guardrails), so a reported
+ * `details.undo` cannot set a value a rule forbids.
+ */
+export function createUndoToolResolver(
+  listTools: () => AnyAgentTool[],
+  params: { config?: OpenClawConfig; sessionKey?: string },
+): (name: string) => AnyAgentTool | undefined {
+  return (name) => {
+    const tool = listTools().find((candidate) => candidate.name === name);
+    if (!tool) {
+      return undefined;
+    }
+    const agentId = resolveSessionAgentId({ sessionKey: params.sessionKey, config: params.config });
+    return wrapToolWithBeforeToolCallHook(tool, {
+      agentId,
+      sessionKey: params.sessionKey,
+      autonomy: resolveToolAutonomy({ cfg: params.config, agentId, sessionKey: params.sessionKey }),
+      guardrails: resolveToolGuardrails({ cfg: params.config, agentId }),
+    });
+  };
 }
@@ -0,0 +1,99 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withFetchPreconnect } from "../test-utils/fetch-mock.js";
+import { resolveModelCostConfig } from "../utils/usage-format.js";
+import {
+  getOpenRouterCatalogEntry,
+  isOpenRouterInUse,
+  loadOpenRouterCatalog,
+  resetOpenRouterCatalogForTest,
+} from "./openrouter-catalog.js";
+
+function createFetchFixture(payload: unknown, calls: { count: number }): typeof fetch {
+  return withFetchPreconnect(async () => {
+    calls.count += 1;
+    return new Response(JSON.stringify(payload), {
+      status: 200,
+      headers: { "content-type": "application/json" },
+    });
+  });
+}
+
+const CATALOG = {
+  data: [
+    {
+      id: "acme/sonnet",
+      name: "Acme Sonnet",
+      context_length: 200_000,
+      max_completion_tokens: 64_000,
+      pricing: {
+        prompt: "0.000003",
+        completion: "0.000015",
+        input_cache_read: "0.0000003",
+        input_cache_write: "0.00000375",
+      },
+    },
+  ],
+};
+
+describe("openrouter catalog", () => {
+  afterEach(() => {
+    resetOpenRouterCatalogForTest();
+  });
+
+  it("converts per-token prices to per-1M rates and caches them", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-openrouter-catalog-"));
+    const cachePath = path.join(root, "openrouter-models.json");
+    const calls = { count: 0 };
+    const fetchImpl = createFetchFixture(CATALOG, calls);
+
+    expect(await loadOpenRouterCatalog({ fetchImpl, cachePath, now: 1_000 })).toBe(1);
+    const entry = getOpenRouterCatalogEntry("acme/sonnet");
+    expect(entry?.cost.input).toBeCloseTo(3, 6);
+    expect(entry?.cost.output).toBeCloseTo(15, 6);
+    expect(entry?.cost.cacheRead).toBeCloseTo(0.3, 6);
+    expect(entry?.cost.cacheWrite).toBeCloseTo(3.75, 6);
+    expect(entry).toMatchObject({ contextWindow: 200_000, maxTokens: 64_000 });
+    expect(
+      resolveModelCostConfig({
+        provider: "openrouter",
+        model: "acme/sonnet",
+        config: {} as OpenClawConfig,
+      }),
+    ).toBe(entry?.cost);
+
+    resetOpenRouterCatalogForTest();
+    await loadOpenRouterCatalog({ fetchImpl, cachePath, now: 2_000 });
+    expect(calls.count).toBe(1);
+    expect(getOpenRouterCatalogEntry("acme/sonnet")?.contextWindow).toBe(200_000);
+  });
+
+  it("falls back to a stale cache when the fetch fails", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-openrouter-catalog-"));
+    const cachePath = path.join(root, "openrouter-models.json");
+    await loadOpenRouterCatalog({
+      fetchImpl: createFetchFixture(CATALOG, { count: 0 }),
+      cachePath,
+      now: 0,
+    });
+    resetOpenRouterCatalogForTest();
+
+    const failing = withFetchPreconnect(async () => new Response("down", { status: 503 }));
+    await loadOpenRouterCatalog({ fetchImpl: failing, cachePath, maxAgeMs: 1, now: 10 });
+    expect(getOpenRouterCatalogEntry("acme/sonnet")).toBeDefined();
+  });
+
+  it("detects OpenRouter models in config", () => {
+    expect(isOpenRouterInUse({} as OpenClawConfig, {})).toBe(false);
+    expect(
+      isOpenRouterInUse(
+        { agents: { defaults: { model: { primary: "openrouter/acme/sonnet" } } } },
+        {},
+      ),
+    ).toBe(true);
+    expect(isOpenRouterInUse({} as OpenClawConfig, { OPENROUTER_API_KEY: "sk-or" })).toBe(true);
+  });
+});
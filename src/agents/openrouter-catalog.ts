@@ -0,0 +1,107 @@
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { readJsonFile, writeJsonAtomic } from "../infra/json-files.js";
+import type { ModelCostConfig } from "../utils/usage-format.js";
+import type { OpenRouterModelMeta } from "./model-scan.js";
+
+/** Refresh the cached catalog once a day; prices rarely change faster than that. */
+const OPENROUTER_CATALOG_MAX_AGE_MS = 24 * 60 * 60 * 1000;
+const PER_MILLION = 1_000_000;
+
+export type OpenRouterCatalogEntry = {
+  /** USD per 1M tokens, matching `models.providers.*.models[].cost`. */
+  cost: ModelCostConfig;
+  contextWindow?: number;
+  maxTokens?: number;
+};
+
+type OpenRouterCatalogCache = {
+  version: 1;
+  fetchedAt: number;
+  models: Record<string, OpenRouterCatalogEntry>;
+};
+
+let catalog = new Map<string, OpenRouterCatalogEntry>();
+
+export function resolveOpenRouterCatalogPath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cache", "openrouter-models.json");
+}
+
+/** Pricing and limits for an OpenRouter model id, once the catalog has been loaded. */
+export function getOpenRouterCatalogEntry(modelId: string): OpenRouterCatalogEntry | undefined {
+  return catalog.get(modelId.trim());
+}
+
+export function resetOpenRouterCatalogForTest(): void {
+  catalog = new Map();
+}
+
+/** Convert OpenRouter's per-token USD strings into per-1M rates. */
+export function toOpenRouterCatalogEntry(meta: OpenRouterModelMeta): OpenRouterCatalogEntry {
+  const pricing = meta.pricing;
+  return {
+    cost: {
+      input: (pricing?.prompt ?? 0) * PER_MILLION,
+      output: (pricing?.completion ?? 0) * PER_MILLION,
+      cacheRead: (pricing?.inputCacheRead ?? 0) * PER_MILLION,
+      cacheWrite: (pricing?.inputCacheWrite ?? 0) * PER_MILLION,
+    },
+    contextWindow: meta.contextLength ?? undefined,
+    maxTokens: meta.maxCompletionTokens ?? undefined,
+  };
+}
+
+/** True when the config or environment points at OpenRouter, so startup should load prices. */
+export function isOpenRouterInUse(
+  cfg: OpenClawConfig,
+  env: NodeJS.ProcessEnv = process.env,
+): boolean {
+  if (env.OPENROUTER_API_KEY?.trim() || cfg.models?.providers?.openrouter) {
+    return true;
+  }
+  const defaults = cfg.agents?.defaults;
+  const model = defaults?.model;
+  const refs = [
+    ...(typeof model === "string" ? [model] : [model?.primary, ...(model?.fallbacks ?? [])]),
+    ...Object.keys(defaults?.models ?? {}),
+  ];
+  return refs.some((ref) => ref?.trim().toLowerCase().startsWith("openrouter/"));
+}
+
+/**
+ * Load the OpenRouter catalog into memory: the on-disk cache when it is fresh, otherwise
+ * `/api/v1/models` (falling back to a stale cache when the fetch fails). Returns the number
+ * of models loaded.
+ */
+export async function loadOpenRouterCatalog(params?: {
+  fetchImpl?: typeof fetch;
+  cachePath?: string;
+  maxAgeMs?: number;
+  now?: number;
+}): Promise<number> {
+  const cachePath = params?.cachePath ?? resolveOpenRouterCatalogPath();
+  const now = params?.now ?? Date.now();
+  const maxAgeMs = params?.maxAgeMs ?? OPENROUTER_CATALOG_MAX_AGE_MS;
+  const cached = await readJsonFile<OpenRouterCatalogCache>(cachePath);
+  const usable = cached?.version === 1 && cached.models ? cached : null;
+  if (usable && now - usable.fetchedAt < maxAgeMs) {
+    catalog = new Map(Object.entries(usable.models));
+    return catalog.size;
+  }
+  try {
+    const { fetchOpenRouterModels } = await import("./model-scan.js");
+    const entries = await fetchOpenRouterModels(params?.fetchImpl ?? fetch);
+    const models = Object.fromEntries(
+      entries.map((meta) => [meta.id, toOpenRouterCatalogEntry(meta)]),
+    );
+    catalog = new Map(Object.entries(models));
+    await writeJsonAtomic(cachePath, { version: 1, fetchedAt: now, models });
+  } catch (err) {
+    if (!usable) {
+      throw err;
+    }
+    catalog = new Map(Object.entries(usable.models));
+  }
+  return catalog.size;
+}
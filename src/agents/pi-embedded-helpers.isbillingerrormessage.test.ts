@@ -1,6 +1,7 @@
 import { describe, expect, it } from "vitest";
 import {
   classifyFailoverReason,
+  extractRetryAfterMs,
   isAuthErrorMessage,
   isBillingErrorMessage,
   isCloudCodeAssistFormatError,
@@ -10,6 +11,7 @@ import {
   isFailoverErrorMessage,
   isImageDimensionErrorMessage,
   isLikelyContextOverflowError,
+  isRetryableProviderError,
   isTimeoutErrorMessage,
   isTransientHttpError,
   parseImageDimensionError,
@@ -281,6 +283,35 @@ describe("isTransientHttpError", () => {
   });
 });
 
+describe("isRetryableProviderError", () => {
+  it("retries rate limits, overloads, transient 5xx, and network drops", () => {
+    expect(isRetryableProviderError("429 Too Many Requests")).toBe(true);
+    expect(isRetryableProviderError("502 Bad Gateway")).toBe(true);
+    expect(isRetryableProviderError('{"type":"overloaded_error","message":"Overloaded"}')).toBe(
+      true,
+    );
+    expect(isRetryableProviderError("fetch failed")).toBe(true);
+    expect(isRetryableProviderError("read ECONNRESET")).toBe(true);
+  });
+
+  it("treats auth, billing, quota, and context errors as fatal", () => {
+    expect(isRetryableProviderError("401 invalid api key")).toBe(false);
+    expect(isRetryableProviderError("Your credit balance is too low")).toBe(false);
+    expect(isRetryableProviderError("429 You exceeded your current quota")).toBe(false);
+    expect(isRetryableProviderError("prompt is too long: 210000 tokens > 200000")).toBe(false);
+  });
+});
+
+describe("extractRetryAfterMs", () => {
+  it("reads retry hints from provider error text", () => {
+    expect(extractRetryAfterMs("429 rate limited (retry-after: 7)")).toBe(7000);
+    expect(extractRetryAfterMs("Rate limit reached. Please try again in 1.5s.")).toBe(1500);
+    expect(extractRetryAfterMs("Please try again in 250ms")).toBe(250);
+    expect(extractRetryAfterMs('{"retryDelay": "13s"}')).toBe(13000);
+    expect(extractRetryAfterMs("429 Too Many Requests")).toBeUndefined();
+  });
+});
+
 describe("isFailoverErrorMessage", () => {
   it("matches auth/rate/billing/timeout", () => {
     const samples = [
@@ -11,6 +11,7 @@ export {
   BILLING_ERROR_USER_MESSAGE,
   formatBillingErrorMessage,
   classifyFailoverReason,
+  extractRetryAfterMs,
   formatRawAssistantErrorForUi,
   formatAssistantErrorText,
   getApiErrorPayloadFingerprint,
@@ -34,6 +35,7 @@ export {
   isRawApiErrorPayload,
   isRateLimitAssistantError,
   isRateLimitErrorMessage,
+  isRetryableProviderError,
   isTransientHttpError,
   isTimeoutErrorMessage,
   parseImageDimensionError,
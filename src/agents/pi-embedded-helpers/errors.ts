@@ -829,6 +829,76 @@ export function classifyFailoverReason(raw: string): FailoverReason | null {
   return null;
 }
 
+const NETWORK_ERROR_RE =
+  /\b(?:econnreset|econnrefused|etimedout|eai_again|enotfound|epipe|und_err_socket)\b|fetch failed|socket hang up|network error|connection (?:reset|closed|error)/i;
+const QUOTA_ERROR_RE = /quota|usage limit|resource has been exhausted|resource_exhausted/i;
+const RETRY_AFTER_RE =
+  /retry[- ]after["']?\s*[:=]?\s*["']?(\d+(?:\.\d+)?)\s*(ms|milliseconds?|s|sec(?:onds?)?)?\b/i;
+const TRY_AGAIN_IN_RE =
+  /(?:try again|retry) in\s+(\d+(?:\.\d+)?)\s*(ms|milliseconds?|s|sec(?:onds?)?|m|min(?:utes?)?)\b/i;
+const RETRY_DELAY_RE = /"retryDelay"\s*:\s*"(\d+(?:\.\d+)?)s"/i;
+
+function toRetryMs(value: string, unit: string | undefined): number | undefined {
+  const amount = Number.parseFloat(value);
+  if (!Number.isFinite(amount) || amount < 0) {
+    return undefined;
+  }
+  const normalized = unit?.toLowerCase() ?? "s";
+  if (normalized.startsWith("ms") || normalized.startsWith("milli")) {
+    return Math.round(amount);
+  }
+  if (normalized.startsWith("m")) {
+    return Math.round(amount * 60_000);
+  }
+  return Math.round(amount * 1000);
+}
+
+/**
+ * Extract a provider-requested retry delay from an error message: `Retry-After` header echoes,
+ * OpenAI's "Please try again in 1.5s", or Gemini's `"retryDelay": "13s"`.
+ */
+export function extractRetryAfterMs(raw: string): number | undefined {
+  if (!raw) {
+    return undefined;
+  }
+  const retryAfter = raw.match(RETRY_AFTER_RE);
+  if (retryAfter) {
+    return toRetryMs(retryAfter[1], retryAfter[2]);
+  }
+  const tryAgain = raw.match(TRY_AGAIN_IN_RE);
+  if (tryAgain) {
+    return toRetryMs(tryAgain[1], tryAgain[2]);
+  }
+  const retryDelay = raw.match(RETRY_DELAY_RE);
+  if (retryDelay) {
+    return toRetryMs(retryDelay[1], "s");
+  }
+  return undefined;
+}
+
+/**
+ * Whether a provider failure is worth retrying with backoff: 429 rate limits, overloads,
+ * transient 5xx, and network drops. Auth, billing/quota, format, and context errors are fatal.
+ */
+export function isRetryableProviderError(raw: string): boolean {
+  if (!raw) {
+    return false;
+  }
+  if (isContextOverflowError(raw) || isBillingErrorMessage(raw)) {
+    return false;
+  }
+  if (isTransientHttpError(raw) || isJsonApiInternalServerError(raw)) {
+    return true;
+  }
+  if (isOverloadedErrorMessage(raw) || NETWORK_ERROR_RE.test(raw)) {
+    return true;
+  }
+  if (isRateLimitErrorMessage(raw)) {
+    return !QUOTA_ERROR_RE.test(raw) || extractRetryAfterMs(raw) !== undefined;
+  }
+  return false;
+}
+
 export function isFailoverErrorMessage(raw: string): boolean {
   return classifyFailoverReason(raw) !== null;
 }
@@ -58,6 +58,11 @@ import {
   resolveSkillsPromptForRun,
   type SkillSnapshot,
 } from "../skills.js";
+import {
+  buildSystemPromptTemplateVars,
+  renderContextFileTemplates,
+  renderSystemPromptTemplate,
+} from "../system-prompt-template.js";
 import { resolveTranscriptPolicy } from "../transcript-policy.js";
 import {
   compactWithSafetyTimeout,
@@ -483,11 +488,18 @@ export async function compactEmbeddedPiSessionDirect(
     });
     const ttsHint = params.config ? buildTtsSystemPromptHint(params.config) : undefined;
     const ownerDisplay = resolveOwnerDisplaySetting(params.config);
+    const templateVars = buildSystemPromptTemplateVars({
+      runtimeInfo: { ...runtimeInfo, agentId: sessionAgentId },
+      userTimezone,
+      tools,
+    });
     const appendPrompt = buildEmbeddedSystemPrompt({
       workspaceDir: effectiveWorkspace,
       defaultThinkLevel: params.thinkLevel,
       reasoningLevel: params.reasoningLevel ?? "off",
-      extraSystemPrompt: params.extraSystemPrompt,
+      extraSystemPrompt: params.extraSystemPrompt
+        ? renderSystemPromptTemplate(params.extraSystemPrompt, templateVars)
+        : undefined,
       ownerNumbers: params.ownerNumbers,
       ownerDisplay: ownerDisplay.ownerDisplay,
       ownerDisplaySecret: ownerDisplay.ownerDisplaySecret,
@@ -508,7 +520,7 @@ export async function compactEmbeddedPiSessionDirect(
       userTimezone,
       userTime,
       userTimeFormat,
-      contextFiles,
+      contextFiles: renderContextFileTemplates(contextFiles, templateVars),
       memoryCitationsMode: params.config?.memory?.citations,
     });
     const systemPromptOverride = createSystemPromptOverride(appendPrompt);
@@ -0,0 +1,74 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import type { Context, Model } from "@mariozechner/pi-ai";
+import { createAssistantMessageEventStream } from "@mariozechner/pi-ai";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import { applyExtraParamsToAgent } from "./extra-params.js";
+
+function runOpenAIPayload(params: {
+  cfg?: OpenClawConfig;
+  baseUrl?: string;
+  sessionId?: string;
+}): Record<string, unknown> {
+  const payload: Record<string, unknown> = { model: "gpt-5.2", input: [] };
+  const baseStreamFn: StreamFn = (_model, _context, options) => {
+    options?.onPayload?.(payload);
+    return createAssistantMessageEventStream();
+  };
+  const agent = { streamFn: baseStreamFn };
+
+  applyExtraParamsToAgent(
+    agent,
+    params.cfg,
+    "openai",
+    "gpt-5.2",
+    undefined,
+    undefined,
+    params.sessionId,
+  );
+
+  const model = {
+    api: "openai-responses",
+    provider: "openai",
+    id: "gpt-5.2",
+    baseUrl: params.baseUrl ?? "https://api.openai.com/v1",
+  } as Model<"openai-responses">;
+  const context: Context = { messages: [] };
+  void agent.streamFn?.(model, context, {});
+  return payload;
+}
+
+function cfgWithParams(params: Record<string, unknown>): OpenClawConfig {
+  return { agents: { defaults: { models: { "openai/gpt-5.2": { params } } } } };
+}
+
+describe("extra-params: OpenAI prompt caching", () => {
+  it("routes requests by session with prompt_cache_key", () => {
+    const payload = runOpenAIPayload({ sessionId: "session-123" });
+    expect(payload.prompt_cache_key).toBe("session-123");
+    expect(payload.prompt_cache_retention).toBeUndefined();
+  });
+
+  it("prefers a configured promptCacheKey and maps long retention to 24h", () => {
+    const payload = runOpenAIPayload({
+      cfg: cfgWithParams({ promptCacheKey: "shared-agent", cacheRetention: "long" }),
+      sessionId: "session-123",
+    });
+    expect(payload.prompt_cache_key).toBe("shared-agent");
+    expect(payload.prompt_cache_retention).toBe("24h");
+  });
+
+  it("leaves the payload alone for cacheRetention none or proxied base URLs", () => {
+    const disabled = runOpenAIPayload({
+      cfg: cfgWithParams({ cacheRetention: "none" }),
+      sessionId: "session-123",
+    });
+    expect(disabled.prompt_cache_key).toBeUndefined();
+
+    const proxied = runOpenAIPayload({
+      baseUrl: "https://proxy.example.com/v1",
+      sessionId: "session-123",
+    });
+    expect(proxied.prompt_cache_key).toBeUndefined();
+  });
+});
@@ -0,0 +1,89 @@
+import type { StreamFn } from "@mariozechner/pi-agent-core";
+import type { Context, Model } from "@mariozechner/pi-ai";
+import { describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import {
+  applyExtraParamsToAgent,
+  type GenerationTask,
+  resolveExtraParams,
+} from "./extra-params.js";
+
+vi.mock("@mariozechner/pi-ai", () => ({
+  streamSimple: vi.fn(() => ({
+    push: vi.fn(),
+    result: vi.fn(),
+  })),
+}));
+
+const cfg: OpenClawConfig = {
+  agents: {
+    defaults: {
+      models: {
+        "openai/gpt-5.2": { params: { temperature: 0.7, topP: 0.95, stop: ["<END>"] } },
+      },
+      taskParams: { cron: { temperature: 0.1, maxTokens: 1024 } },
+    },
+  },
+};
+
+function runSamplingCase(api: string, task?: GenerationTask) {
+  const payload: Record<string, unknown> = { model: "gpt-5.2", options: { num_ctx: 8192 } };
+  let streamOptions: Record<string, unknown> | undefined;
+  const baseStreamFn: StreamFn = (_model, _context, options) => {
+    streamOptions = options as Record<string, unknown>;
+    options?.onPayload?.(payload);
+    return {} as ReturnType<StreamFn>;
+  };
+  const agent = { streamFn: baseStreamFn };
+  applyExtraParamsToAgent(agent, cfg, "openai", "gpt-5.2", undefined, undefined, "s1", task);
+  const context: Context = { messages: [] };
+  const model = { api, provider: "openai", id: "gpt-5.2" } as unknown as Model<"openai-responses">;
+  void agent.streamFn?.(model, context, {});
+  return { payload, streamOptions };
+}
+
+describe("extra-params: generation params", () => {
+  it("layers task params over model params", () => {
+    const resolve = (task: GenerationTask) =>
+      resolveExtraParams({ cfg, provider: "openai", modelId: "gpt-5.2", task });
+    expect(resolve("cron")).toEqual({
+      temperature: 0.1,
+      maxTokens: 1024,
+      topP: 0.95,
+      stop: ["<END>"],
+    });
+    expect(resolve("chat")).toEqual({ temperature: 0.7, topP: 0.95, stop: ["<END>"] });
+    expect(resolveExtraParams({ cfg, provider: "anthropic", modelId: "x", task: "cron" })).toEqual({
+      temperature: 0.1,
+      maxTokens: 1024,
+    });
+  });
+
+  it("passes temperature and maxTokens as stream options", () => {
+    const { streamOptions } = runSamplingCase("openai-completions", "cron");
+    expect(streamOptions).toMatchObject({ temperature: 0.1, maxTokens: 1024 });
+  });
+
+  it("writes topP and stop using each API's field names", () => {
+    expect(runSamplingCase("openai-completions").payload).toMatchObject({
+      top_p: 0.95,
+      stop: ["<END>"],
+    });
+    expect(runSamplingCase("anthropic-messages").payload).toMatchObject({
+      top_p: 0.95,
+      stop_sequences: ["<END>"],
+    });
+    expect(runSamplingCase("llama-cpp").payload).toMatchObject({
+      top_p: 0.95,
+      stop: ["<END>"],
+    });
+    const responses = runSamplingCase("openai-responses").payload;
+    expect(responses.top_p).toBe(0.95);
+    expect(responses).not.toHaveProperty("stop");
+    expect(runSamplingCase("ollama").payload.options).toEqual({
+      num_ctx: 8192,
+      top_p: 0.95,
+      stop: ["<END>"],
+    });
+  });
+});
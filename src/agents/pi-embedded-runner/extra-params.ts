@@ -3,6 +3,11 @@ import type { SimpleStreamOptions } from "@mariozechner/pi-ai";
 import { streamSimple } from "@mariozechner/pi-ai";
 import type { ThinkLevel } from "../../auto-reply/thinking.js";
 import type { OpenClawConfig } from "../../config/config.js";
+import {
+  applyJsonResponseFormat,
+  type JsonResponseFormat,
+  resolveJsonResponseFormat,
+} from "../structured-output.js";
 import { log } from "./logger.js";
 
 const OPENROUTER_APP_HEADERS: Record<string, string> = {
@@ -15,21 +20,121 @@ const ANTHROPIC_1M_MODEL_PREFIXES = ["claude-opus-4", "claude-sonnet-4"] as cons
 // Codex responses (chatgpt.com/backend-api/codex/responses) require `store=false`.
 const OPENAI_RESPONSES_APIS = new Set(["openai-responses"]);
 const OPENAI_RESPONSES_PROVIDERS = new Set(["openai"]);
+const OPENAI_PROMPT_CACHE_APIS = new Set(["openai-responses", "openai-completions"]);
+const OPENAI_PROMPT_CACHE_KEY_MAX_CHARS = 64;
+
+/** Kind of run, used to pick `agents.defaults.taskParams.<task>`. */
+export type GenerationTask = "chat" | "cron" | "subagent";
 
 /**
  * Resolve provider-specific extra params from model config.
- * Used to pass through stream params like temperature/maxTokens.
- *
- * @internal Exported for testing only
+ * Used to pass through stream params like temperature/maxTokens/topP/stop (and Ollama
+ * `keepAlive`). Task params (`agents.defaults.taskParams.<task>`) override model params.
  */
 export function resolveExtraParams(params: {
   cfg: OpenClawConfig | undefined;
   provider: string;
   modelId: string;
+  task?: GenerationTask;
 }): Record<string, unknown> | undefined {
   const modelKey = `${params.provider}/${params.modelId}`;
   const modelConfig = params.cfg?.agents?.defaults?.models?.[modelKey];
-  return modelConfig?.params ? { ...modelConfig.params } : undefined;
+  const taskParams = params.task
+    ? params.cfg?.agents?.defaults?.taskParams?.[params.task]
+    : undefined;
+  if (!modelConfig?.params && !taskParams) {
+    return undefined;
+  }
+  return { ...modelConfig?.params, ...taskParams };
+}
+
+type SamplingParams = { topP?: number; stop?: string[] };
+
+function resolveSamplingParams(extraParams: Record<string, unknown>): SamplingParams | undefined {
+  const sampling: SamplingParams = {};
+  const topP = extraParams.topP ?? extraParams.top_p;
+  if (typeof topP === "number" && Number.isFinite(topP)) {
+    sampling.topP = topP;
+  }
+  const stop = typeof extraParams.stop === "string" ? [extraParams.stop] : extraParams.stop;
+  if (Array.isArray(stop)) {
+    const sequences = stop.filter((entry): entry is string => typeof entry === "string" && !!entry);
+    if (sequences.length > 0) {
+      sampling.stop = sequences;
+    }
+  }
+  return Object.keys(sampling).length > 0 ? sampling : undefined;
+}
+
+/**
+ * Write `topP`/`stop` into the request body using each API's field names. pi-ai stream
+ * options only carry temperature and maxTokens, so these go through `onPayload`.
+ * The Responses API has no stop sequences; unsupported APIs are left unchanged.
+ */
+export function applySamplingParamsToPayload(
+  payload: Record<string, unknown>,
+  api: string | undefined,
+  sampling: SamplingParams,
+): void {
+  switch (api) {
+    case "openai-completions":
+    case "llama-cpp":
+      if (sampling.topP !== undefined) {
+        payload.top_p = sampling.topP;
+      }
+      if (sampling.stop) {
+        payload.stop = sampling.stop;
+      }
+      return;
+    case "openai-responses":
+    case "openai-codex-responses":
+    case "azure-openai-responses":
+      if (sampling.topP !== undefined) {
+        payload.top_p = sampling.topP;
+      }
+      return;
+    case "anthropic-messages":
+      if (sampling.topP !== undefined) {
+        payload.top_p = sampling.topP;
+      }
+      if (sampling.stop) {
+        payload.stop_sequences = sampling.stop;
+      }
+      return;
+    case "ollama": {
+      const options =
+        payload.options && typeof payload.options === "object"
+          ? (payload.options as Record<string, unknown>)
+          : {};
+      payload.options = {
+        ...options,
+        ...(sampling.topP !== undefined ? { top_p: sampling.topP } : {}),
+        ...(sampling.stop ? { stop: sampling.stop } : {}),
+      };
+      return;
+    }
+    default:
+      return;
+  }
+}
+
+function createSamplingParamsWrapper(
+  baseStreamFn: StreamFn | undefined,
+  sampling: SamplingParams,
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  return (model, context, options) => {
+    const originalOnPayload = options?.onPayload;
+    return underlying(model, context, {
+      ...options,
+      onPayload: (payload) => {
+        if (payload && typeof payload === "object") {
+          applySamplingParamsToPayload(payload as Record<string, unknown>, model.api, sampling);
+        }
+        originalOnPayload?.(payload);
+      },
+    });
+  };
 }
 
 type CacheRetention = "none" | "short" | "long";
@@ -188,6 +293,78 @@ function createOpenAIResponsesStoreWrapper(baseStreamFn: StreamFn | undefined):
   };
 }
 
+function resolveOpenAIPromptCacheKey(
+  extraParams: Record<string, unknown> | undefined,
+  sessionId: string | undefined,
+): string | undefined {
+  const configured =
+    typeof extraParams?.promptCacheKey === "string" ? extraParams.promptCacheKey.trim() : "";
+  const key = configured || sessionId?.trim();
+  return key ? key.slice(0, OPENAI_PROMPT_CACHE_KEY_MAX_CHARS) : undefined;
+}
+
+/**
+ * OpenAI caches prompt prefixes automatically; `prompt_cache_key` keeps requests from the same
+ * session routed to the same cache, and `cacheRetention: "long"` opts into 24h retention.
+ * `cacheRetention: "none"` leaves the request untouched.
+ */
+function createOpenAIPromptCacheWrapper(
+  baseStreamFn: StreamFn | undefined,
+  params: { cacheKey?: string; cacheRetention?: unknown },
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  return (model, context, options) => {
+    if (
+      params.cacheRetention === "none" ||
+      model.provider !== "openai" ||
+      !OPENAI_PROMPT_CACHE_APIS.has(model.api) ||
+      !isDirectOpenAIBaseUrl(model.baseUrl)
+    ) {
+      return underlying(model, context, options);
+    }
+
+    const originalOnPayload = options?.onPayload;
+    return underlying(model, context, {
+      ...options,
+      onPayload: (payload) => {
+        if (payload && typeof payload === "object") {
+          const payloadObj = payload as Record<string, unknown>;
+          if (params.cacheKey && payloadObj.prompt_cache_key === undefined) {
+            payloadObj.prompt_cache_key = params.cacheKey;
+          }
+          if (params.cacheRetention === "long" && payloadObj.prompt_cache_retention === undefined) {
+            payloadObj.prompt_cache_retention = "24h";
+          }
+        }
+        originalOnPayload?.(payload);
+      },
+    });
+  };
+}
+
+/**
+ * Request schema-constrained JSON (`streamParams.responseFormat`) on APIs with native support.
+ * Other APIs pass through; callers validate the reply themselves (see structured-output.ts).
+ */
+function createJsonResponseFormatWrapper(
+  baseStreamFn: StreamFn | undefined,
+  format: JsonResponseFormat,
+): StreamFn {
+  const underlying = baseStreamFn ?? streamSimple;
+  return (model, context, options) => {
+    const originalOnPayload = options?.onPayload;
+    return underlying(model, context, {
+      ...options,
+      onPayload: (payload) => {
+        if (payload && typeof payload === "object") {
+          applyJsonResponseFormat(payload as Record<string, unknown>, model.api, format);
+        }
+        originalOnPayload?.(payload);
+      },
+    });
+  };
+}
+
 function isAnthropic1MModel(modelId: string): boolean {
   const normalized = modelId.trim().toLowerCase();
   return ANTHROPIC_1M_MODEL_PREFIXES.some((prefix) => normalized.startsWith(prefix));
@@ -444,7 +621,8 @@ function createZaiToolStreamWrapper(
 
 /**
  * Apply extra params (like temperature) to an agent's streamFn.
- * Also adds OpenRouter app attribution headers when using the OpenRouter provider.
+ * Also adds OpenRouter app attribution headers when using the OpenRouter provider, and
+ * OpenAI prompt-cache routing (keyed by `sessionId`) for direct OpenAI models.
  *
  * @internal Exported for testing
  */
@@ -455,11 +633,14 @@ export function applyExtraParamsToAgent(
   modelId: string,
   extraParamsOverride?: Record<string, unknown>,
   thinkingLevel?: ThinkLevel,
+  sessionId?: string,
+  task?: GenerationTask,
 ): void {
   const extraParams = resolveExtraParams({
     cfg,
     provider,
     modelId,
+    task,
   });
   const override =
     extraParamsOverride && Object.keys(extraParamsOverride).length > 0
@@ -499,6 +680,25 @@ export function applyExtraParamsToAgent(
     }
   }
 
+  if (provider === "openai") {
+    agent.streamFn = createOpenAIPromptCacheWrapper(agent.streamFn, {
+      cacheKey: resolveOpenAIPromptCacheKey(merged, sessionId),
+      cacheRetention: merged.cacheRetention,
+    });
+  }
+
+  const sampling = resolveSamplingParams(merged);
+  if (sampling) {
+    log.debug(`applying sampling params for ${provider}/${modelId}: ${JSON.stringify(sampling)}`);
+    agent.streamFn = createSamplingParamsWrapper(agent.streamFn, sampling);
+  }
+
+  const responseFormat = resolveJsonResponseFormat(merged.responseFormat);
+  if (responseFormat) {
+    log.debug(`requesting JSON response format for ${provider}/${modelId}`);
+    agent.streamFn = createJsonResponseFormatWrapper(agent.streamFn, responseFormat);
+  }
+
   // Work around upstream pi-ai hardcoding `store: false` for Responses API.
   // Force `store=true` for direct OpenAI/OpenAI Codex providers so multi-turn
   // server-side conversation state is preserved.
@@ -6,6 +6,7 @@ vi.mock("../pi-model-discovery.js", () => ({
 }));
 
 import type { OpenClawConfig } from "../../config/config.js";
+import { withEnv } from "../../test-utils/env.js";
 import { buildInlineProviderModels, resolveModel } from "./model.js";
 import {
   buildOpenAICodexForwardCompatExpectation,
@@ -300,6 +301,44 @@ describe("resolveModel", () => {
     });
   });
 
+  it("routes built-in openai models through OPENAI_BASE_URL", () => {
+    const template = {
+      id: "gpt-5.2",
+      name: "GPT-5.2",
+      provider: "openai",
+      api: "openai-responses",
+      baseUrl: "https://api.openai.com/v1",
+      reasoning: true,
+      input: ["text", "image"],
+      cost: { input: 1.75, output: 14, cacheRead: 0.175, cacheWrite: 0 },
+      contextWindow: 400000,
+      maxTokens: 128000,
+    };
+    mockDiscoveredModel({ provider: "openai", modelId: "gpt-5.2", templateModel: template });
+
+    withEnv({ OPENAI_BASE_URL: "https://llm-gateway.internal/v1/" }, () => {
+      expect(resolveModel("openai", "gpt-5.2", "/tmp/agent").model?.baseUrl).toBe(
+        "https://llm-gateway.internal/v1",
+      );
+    });
+    withEnv({ OPENAI_BASE_URL: undefined }, () => {
+      expect(resolveModel("openai", "gpt-5.2", "/tmp/agent").model?.baseUrl).toBe(
+        "https://api.openai.com/v1",
+      );
+    });
+
+    mockDiscoveredModel({
+      provider: "openai",
+      modelId: "gpt-5.2",
+      templateModel: { ...template, baseUrl: "https://proxy.example.com/v1" },
+    });
+    withEnv({ OPENAI_BASE_URL: "https://llm-gateway.internal/v1" }, () => {
+      expect(resolveModel("openai", "gpt-5.2", "/tmp/agent").model?.baseUrl).toBe(
+        "https://proxy.example.com/v1",
+      );
+    });
+  });
+
   it("includes auth hint for unknown ollama models (#17328)", () => {
     // resetMockDiscoverModels() in beforeEach already sets find → null
     const result = resolveModel("ollama", "gemma3:4b", "/tmp/agent");
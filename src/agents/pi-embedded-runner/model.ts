@@ -7,6 +7,7 @@ import { buildModelAliasLines } from "../model-alias-lines.js";
 import { normalizeModelCompat } from "../model-compat.js";
 import { resolveForwardCompatModel } from "../model-forward-compat.js";
 import { normalizeProviderId } from "../model-selection.js";
+import { getOpenRouterCatalogEntry } from "../openrouter-catalog.js";
 import {
   discoverAuthStorage,
   discoverModels,
@@ -26,6 +27,36 @@ type InlineProviderConfig = {
 
 export { buildModelAliasLines };
 
+const OPENAI_DEFAULT_BASE_URL = "https://api.openai.com/v1";
+
+/** `OPENAI_BASE_URL` without trailing slashes; undefined when unset or blank. */
+export function resolveOpenAiBaseUrlOverride(
+  env: NodeJS.ProcessEnv = process.env,
+): string | undefined {
+  return env.OPENAI_BASE_URL?.trim().replace(/\/+$/, "") || undefined;
+}
+
+/**
+ * Point built-in `openai` models at `OPENAI_BASE_URL` (the OpenAI SDK convention), so
+ * OpenAI-compatible gateways work without redefining the catalog under `models.providers`.
+ * Models whose base URL was already customized are left alone. The variable is process-wide,
+ * so the gateway names the effective host at startup.
+ */
+export function applyOpenAiBaseUrlOverride(
+  model: Model<Api>,
+  env: NodeJS.ProcessEnv = process.env,
+): Model<Api> {
+  const override = resolveOpenAiBaseUrlOverride(env);
+  if (
+    !override ||
+    normalizeProviderId(model.provider) !== "openai" ||
+    (model.baseUrl && model.baseUrl.replace(/\/+$/, "") !== OPENAI_DEFAULT_BASE_URL)
+  ) {
+    return model;
+  }
+  return { ...model, baseUrl: override };
+}
+
 export function buildInlineProviderModels(
   providers: Record<string, InlineProviderConfig>,
 ): InlineModelEntry[] {
@@ -83,6 +114,7 @@ export function resolveModel(
     // OpenRouter is a pass-through proxy — any model ID available on OpenRouter
     // should work without being pre-registered in the local catalog.
     if (normalizedProvider === "openrouter") {
+      const catalogEntry = getOpenRouterCatalogEntry(modelId);
       const fallbackModel: Model<Api> = normalizeModelCompat({
         id: modelId,
         name: modelId,
@@ -91,10 +123,11 @@ export function resolveModel(
         baseUrl: "https://openrouter.ai/api/v1",
         reasoning: false,
         input: ["text"],
-        cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0 },
-        contextWindow: DEFAULT_CONTEXT_TOKENS,
+        // Pricing and limits come from the OpenRouter catalog loaded at gateway startup.
+        cost: catalogEntry?.cost ?? { input: 0, output: 0, cacheRead: 0, cacheWrite: 0 },
+        contextWindow: catalogEntry?.contextWindow ?? DEFAULT_CONTEXT_TOKENS,
         // Align with OPENROUTER_DEFAULT_MAX_TOKENS in models-config.providers.ts
-        maxTokens: 8192,
+        maxTokens: catalogEntry?.maxTokens ?? 8192,
       } as Model<Api>);
       return { model: fallbackModel, authStorage, modelRegistry };
     }
@@ -120,7 +153,11 @@ export function resolveModel(
       modelRegistry,
     };
   }
-  return { model: normalizeModelCompat(model), authStorage, modelRegistry };
+  return {
+    model: normalizeModelCompat(applyOpenAiBaseUrlOverride(model)),
+    authStorage,
+    modelRegistry,
+  };
 }
 
 /**
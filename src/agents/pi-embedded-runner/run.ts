@@ -619,6 +619,8 @@ export async function runEmbeddedPiAgent(
             onReasoningStream: params.onReasoningStream,
             onReasoningEnd: params.onReasoningEnd,
             onToolResult: params.onToolResult,
+            toolProgressIntervalMs: params.toolProgressIntervalMs,
+            toolStepMaxChars: params.toolStepMaxChars,
             onAgentEvent: params.onAgentEvent,
             extraSystemPrompt: params.extraSystemPrompt,
             inputProvenance: params.inputProvenance,
@@ -1131,6 +1133,13 @@ export async function runEmbeddedPiAgent(
               agentMeta,
               aborted,
               systemPromptReport: attempt.systemPromptReport,
+              toolError: attempt.lastToolError
+                ? {
+                    toolName: attempt.lastToolError.toolName,
+                    meta: attempt.lastToolError.meta,
+                    error: attempt.lastToolError.error,
+                  }
+                : undefined,
               // Handle client tool calls (OpenResponses hosted tools)
               stopReason: attempt.clientToolCall ? "tool_calls" : undefined,
               pendingToolCalls: attempt.clientToolCall
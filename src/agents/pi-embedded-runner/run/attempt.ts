@@ -27,6 +27,7 @@ import { buildTtsSystemPromptHint } from "../../../tts/tts.js";
 import { resolveUserPath } from "../../../utils.js";
 import { normalizeMessageChannel } from "../../../utils/message-channel.js";
 import { isReasoningTagProvider } from "../../../utils/provider-utils.js";
+import { listAgentMiddlewares, wrapStreamFnWithMiddleware } from "../../agent-middleware.js";
 import { resolveOpenClawAgentDir } from "../../agent-paths.js";
 import { resolveSessionAgentIds } from "../../agent-scope.js";
 import { createAnthropicPayloadLogger } from "../../anthropic-payload-log.js";
@@ -40,9 +41,15 @@ import { DEFAULT_CONTEXT_TOKENS } from "../../defaults.js";
 import { resolveOpenClawDocsPath } from "../../docs-path.js";
 import { isTimeoutError } from "../../failover-error.js";
 import { resolveImageSanitizationLimits } from "../../image-sanitization.js";
+import { createLlamaCppStreamFn, resolveLlamaCppOptions } from "../../llama-cpp-stream.js";
 import { resolveModelAuthMode } from "../../model-auth.js";
+import { resolveModelCapabilities } from "../../model-capabilities.js";
 import { resolveDefaultModelForAgent } from "../../model-selection.js";
-import { createOllamaStreamFn, OLLAMA_NATIVE_BASE_URL } from "../../ollama-stream.js";
+import {
+  createOllamaStreamFn,
+  OLLAMA_NATIVE_BASE_URL,
+  resolveOllamaKeepAlive,
+} from "../../ollama-stream.js";
 import { resolveOwnerDisplaySetting } from "../../owner-display.js";
 import {
   isCloudCodeAssistFormatError,
@@ -57,7 +64,7 @@ import { toClientToolDefinitions } from "../../pi-tool-definition-adapter.js";
 import { createOpenClawCodingTools, resolveToolLoopDetectionConfig } from "../../pi-tools.js";
 import { resolveSandboxContext } from "../../sandbox.js";
 import { resolveSandboxRuntimeStatus } from "../../sandbox/runtime-status.js";
-import { repairSessionFileIfNeeded } from "../../session-file-repair.js";
+import { repairSessionFileIfNeeded, syncSessionFile } from "../../session-file-repair.js";
 import { guardSessionManager } from "../../session-tool-result-guard-wrapper.js";
 import { sanitizeToolUseResultPairing } from "../../session-transcript-repair.js";
 import {
@@ -72,14 +79,20 @@ import {
   resolveSkillsPromptForRun,
 } from "../../skills.js";
 import { buildSystemPromptParams } from "../../system-prompt-params.js";
+import {
+  buildSystemPromptTemplateVars,
+  renderContextFileTemplates,
+  renderSystemPromptTemplate,
+} from "../../system-prompt-template.js";
 import { buildSystemPromptReport } from "../../system-prompt-report.js";
 import { sanitizeToolCallIdsForCloudCodeAssist } from "../../tool-call-id.js";
+import { createParallelToolRunner, resolveParallelToolPolicy } from "../../tool-parallel.js";
 import { resolveTranscriptPolicy } from "../../transcript-policy.js";
 import { DEFAULT_BOOTSTRAP_FILENAME } from "../../workspace.js";
 import { isRunnerAbortError } from "../abort.js";
 import { appendCacheTtlTimestamp, isCacheTtlEligibleProvider } from "../cache-ttl.js";
 import { buildEmbeddedExtensionFactories } from "../extensions.js";
-import { applyExtraParamsToAgent } from "../extra-params.js";
+import { applyExtraParamsToAgent, resolveExtraParams } from "../extra-params.js";
 import {
   logToolSchemasForGoogle,
   sanitizeSessionHistory,
@@ -111,7 +124,7 @@ import {
   selectCompactionTimeoutSnapshot,
   shouldFlagCompactionTimeout,
 } from "./compaction-timeout.js";
-import { detectAndLoadPromptImages } from "./images.js";
+import { buildImagesUnsupportedNote, detectAndLoadPromptImages } from "./images.js";
 import type { EmbeddedRunAttemptParams, EmbeddedRunAttemptResult } from "./types.js";
 
 type PromptBuildHookRunner = {
@@ -356,9 +369,13 @@ export async function runEmbeddedAttempt(
       config: params.config,
       agentId: params.agentId,
     });
-    // Check if the model supports native image input
-    const modelHasVision = params.model.input?.includes("image") ?? false;
-    const toolsRaw = params.disableTools
+    const capabilities = resolveModelCapabilities(params.model);
+    const modelHasVision = capabilities.vision;
+    if (!capabilities.tools && !params.disableTools) {
+      log.info(`tools disabled: ${params.provider}/${params.modelId} has no tool calling`);
+    }
+    const disableTools = params.disableTools || !capabilities.tools;
+    const toolsRaw = disableTools
       ? []
       : createOpenClawCodingTools({
           agentId: sessionAgentId,
@@ -505,12 +522,17 @@ export async function runEmbeddedAttempt(
     });
     const ttsHint = params.config ? buildTtsSystemPromptHint(params.config) : undefined;
     const ownerDisplay = resolveOwnerDisplaySetting(params.config);
+    // Evaluated on every run so {{placeholders}} in bootstrap files track the live runtime.
+    const templateVars = buildSystemPromptTemplateVars({ runtimeInfo, userTimezone, tools });
+    const renderedContextFiles = renderContextFileTemplates(contextFiles, templateVars);
 
     const appendPrompt = buildEmbeddedSystemPrompt({
       workspaceDir: effectiveWorkspace,
       defaultThinkLevel: params.thinkLevel,
       reasoningLevel: params.reasoningLevel ?? "off",
-      extraSystemPrompt: params.extraSystemPrompt,
+      extraSystemPrompt: params.extraSystemPrompt
+        ? renderSystemPromptTemplate(params.extraSystemPrompt, templateVars)
+        : undefined,
       ownerNumbers: params.ownerNumbers,
       ownerDisplay: ownerDisplay.ownerDisplay,
       ownerDisplaySecret: ownerDisplay.ownerDisplaySecret,
@@ -532,7 +554,7 @@ export async function runEmbeddedAttempt(
       userTimezone,
       userTime,
       userTimeFormat,
-      contextFiles,
+      contextFiles: renderedContextFiles,
       memoryCitationsMode: params.config?.memory?.citations,
     });
     const systemPromptReport = buildSystemPromptReport({
@@ -554,7 +576,7 @@ export async function runEmbeddedAttempt(
       })(),
       systemPrompt: appendPrompt,
       bootstrapFiles: hookAdjustedBootstrapFiles,
-      injectedFiles: contextFiles,
+      injectedFiles: renderedContextFiles,
       skillsPrompt,
       tools,
     });
@@ -647,21 +669,36 @@ export async function runEmbeddedAttempt(
         cfg: params.config,
         agentId: sessionAgentId,
       });
-      const clientToolDefs = params.clientTools
-        ? toClientToolDefinitions(
-            params.clientTools,
-            (toolName, toolParams) => {
-              clientToolCallDetected = { name: toolName, params: toolParams };
-            },
-            {
-              agentId: sessionAgentId,
-              sessionKey: params.sessionKey,
-              loopDetection: clientToolLoopDetection,
-            },
-          )
-        : [];
+      const clientToolDefs =
+        params.clientTools && capabilities.tools
+          ? toClientToolDefinitions(
+              params.clientTools,
+              (toolName, toolParams) => {
+                clientToolCallDetected = { name: toolName, params: toolParams };
+              },
+              {
+                agentId: sessionAgentId,
+                sessionKey: params.sessionKey,
+                loopDetection: clientToolLoopDetection,
+              },
+            )
+          : [];
+
+      const parallelToolPolicy = resolveParallelToolPolicy({
+        cfg: params.config,
+        agentId: sessionAgentId,
+      });
+      const parallelTools = parallelToolPolicy
+        ? createParallelToolRunner({
+            policy: parallelToolPolicy,
+            signal: runAbortController.signal,
+          })
+        : undefined;
 
-      const allCustomTools = [...customTools, ...clientToolDefs];
+      const allCustomTools = [
+        ...(parallelTools?.wrapTools(customTools) ?? customTools),
+        ...clientToolDefs,
+      ];
 
       ({ session } = await createAgentSession({
         cwd: resolvedWorkspace,
@@ -689,6 +726,7 @@ export async function runEmbeddedAttempt(
             params.model.contextWindow ?? params.model.maxTokens ?? DEFAULT_CONTEXT_TOKENS,
           ),
         ),
+        tokenizerModel: { provider: params.provider, model: params.modelId },
       });
       const cacheTrace = createCacheTrace({
         cfg: params.config,
@@ -722,7 +760,34 @@ export async function runEmbeddedAttempt(
         const providerBaseUrl =
           typeof providerConfig?.baseUrl === "string" ? providerConfig.baseUrl.trim() : "";
         const ollamaBaseUrl = modelBaseUrl || providerBaseUrl || OLLAMA_NATIVE_BASE_URL;
-        activeSession.agent.streamFn = createOllamaStreamFn(ollamaBaseUrl);
+        const keepAlive = resolveOllamaKeepAlive(
+          resolveExtraParams({
+            cfg: params.config,
+            provider: params.provider,
+            modelId: params.modelId,
+          })?.keepAlive,
+        );
+        activeSession.agent.streamFn = createOllamaStreamFn(ollamaBaseUrl, {
+          keepAlive,
+          format: params.streamParams?.responseFormat?.schema,
+        });
+      } else if (params.model.api === "llama-cpp") {
+        // In-process llama.cpp: the provider baseUrl is the directory holding GGUF files.
+        const providerConfig = params.config?.models?.providers?.[params.model.provider];
+        const modelDir =
+          (typeof params.model.baseUrl === "string" ? params.model.baseUrl.trim() : "") ||
+          providerConfig?.baseUrl?.trim() ||
+          effectiveWorkspace;
+        activeSession.agent.streamFn = createLlamaCppStreamFn(
+          resolveLlamaCppOptions(
+            modelDir,
+            resolveExtraParams({
+              cfg: params.config,
+              provider: params.provider,
+              modelId: params.modelId,
+            }),
+          ),
+        );
       } else {
         // Force a stable streamFn reference so vitest can reliably mock @mariozechner/pi-ai.
         activeSession.agent.streamFn = streamSimple;
@@ -735,6 +800,12 @@ export async function runEmbeddedAttempt(
         params.modelId,
         params.streamParams,
         params.thinkLevel,
+        activeSession.sessionId,
+        isCronSessionKey(params.sessionKey)
+          ? "cron"
+          : isSubagentSessionKey(params.sessionKey)
+            ? "subagent"
+            : "chat",
       );
 
       if (cacheTrace) {
@@ -801,6 +872,23 @@ export async function runEmbeddedAttempt(
         );
       }
 
+      // Plugin middleware wraps last so it sees (and rewrites) exactly what the provider gets.
+      activeSession.agent.streamFn = wrapStreamFnWithMiddleware(
+        activeSession.agent.streamFn,
+        listAgentMiddlewares(),
+        {
+          agentId: sessionAgentId,
+          sessionKey: params.sessionKey,
+          sessionId: params.sessionId,
+          workspaceDir: params.workspaceDir,
+          messageProvider: params.messageProvider ?? undefined,
+        },
+      );
+      // Sees the final (post-middleware) tool calls of each turn.
+      if (parallelTools) {
+        activeSession.agent.streamFn = parallelTools.wrapStreamFn(activeSession.agent.streamFn);
+      }
+
       try {
         const prior = await sanitizeSessionHistory({
           messages: activeSession.messages,
@@ -905,6 +993,8 @@ export async function runEmbeddedAttempt(
         shouldEmitToolResult: params.shouldEmitToolResult,
         shouldEmitToolOutput: params.shouldEmitToolOutput,
         onToolResult: params.onToolResult,
+        toolProgressIntervalMs: params.toolProgressIntervalMs,
+        toolStepMaxChars: params.toolStepMaxChars,
         onReasoningStream: params.onReasoningStream,
         onReasoningEnd: params.onReasoningEnd,
         onBlockReply: params.onBlockReply,
@@ -1068,6 +1158,16 @@ export async function runEmbeddedAttempt(
           );
         }
 
+        if (!modelHasVision) {
+          const imageNote = buildImagesUnsupportedNote({
+            prompt: effectivePrompt,
+            inlineImageCount: params.images?.length,
+          });
+          if (imageNote) {
+            effectivePrompt = `${effectivePrompt}\n\n${imageNote}`;
+          }
+        }
+
         try {
           // Detect and load images referenced in the prompt for vision-capable models.
           // This eliminates the need for an explicit "view" tool call by injecting
@@ -1383,6 +1483,7 @@ export async function runEmbeddedAttempt(
         sessionManager,
       });
       session?.dispose();
+      await syncSessionFile(params.sessionFile);
       await sessionLock.release();
     }
   } finally {
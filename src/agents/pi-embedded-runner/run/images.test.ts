@@ -4,6 +4,7 @@ import path from "node:path";
 import { describe, expect, it } from "vitest";
 import { createHostSandboxFsBridge } from "../../test-helpers/host-sandbox-fs-bridge.js";
 import {
+  buildImagesUnsupportedNote,
   detectAndLoadPromptImages,
   detectImageReferences,
   loadImageFromRef,
@@ -205,6 +206,22 @@ describe("modelSupportsImages", () => {
   });
 });
 
+describe("buildImagesUnsupportedNote", () => {
+  it("names attached image paths and inline images", () => {
+    const note = buildImagesUnsupportedNote({
+      prompt: "what is this? [media attached: /tmp/inbound/photo.jpg (image/jpeg)]",
+      inlineImageCount: 2,
+    });
+    expect(note).toContain("cannot view images");
+    expect(note).toContain("image files: /tmp/inbound/photo.jpg");
+    expect(note).toContain("2 inline images");
+  });
+
+  it("returns undefined without images", () => {
+    expect(buildImagesUnsupportedNote({ prompt: "hello" })).toBeUndefined();
+  });
+});
+
 describe("loadImageFromRef", () => {
   it("allows sandbox-validated host paths outside default media roots", async () => {
     const homeDir = os.homedir();
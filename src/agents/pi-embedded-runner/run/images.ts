@@ -253,6 +253,35 @@ export function modelSupportsImages(model: { input?: string[] }): boolean {
   return model.input?.includes("image") ?? false;
 }
 
+/**
+ * Text fallback for models without vision: names the image paths referenced in the prompt (and
+ * counts inline attachments) so the model can reach for the image tool or tell the user it
+ * cannot see the image, instead of the images being dropped silently.
+ * Returns undefined when the prompt carries no images.
+ */
+export function buildImagesUnsupportedNote(params: {
+  prompt: string;
+  inlineImageCount?: number;
+}): string | undefined {
+  const paths = detectImageReferences(params.prompt).map((ref) => ref.resolved);
+  const inline = params.inlineImageCount ?? 0;
+  if (paths.length === 0 && inline === 0) {
+    return undefined;
+  }
+  const parts: string[] = [];
+  if (paths.length > 0) {
+    parts.push(`image files: ${paths.join(", ")}`);
+  }
+  if (inline > 0) {
+    parts.push(`${inline} inline image${inline === 1 ? "" : "s"}`);
+  }
+  return (
+    `[The current model cannot view images. Attached: ${parts.join("; ")}. ` +
+    "Use the image tool to inspect them if it is available; " +
+    "otherwise tell the user you cannot see them.]"
+  );
+}
+
 function extractTextFromMessage(message: unknown): string {
   if (!message || typeof message !== "object") {
     return "";
@@ -93,6 +93,10 @@ export type RunEmbeddedPiAgentParams = {
   onReasoningStream?: (payload: { text?: string; mediaUrls?: string[] }) => void | Promise<void>;
   onReasoningEnd?: () => void | Promise<void>;
   onToolResult?: (payload: { text?: string; mediaUrls?: string[] }) => void | Promise<void>;
+  /** Minimum gap between tool progress messages; 0 disables them. */
+  toolProgressIntervalMs?: number;
+  /** Result length for per-call tool step lines; unset disables them. */
+  toolStepMaxChars?: number;
   onAgentEvent?: (evt: { stream: string; data: Record<string, unknown> }) => void;
   lane?: string;
   enqueue?: typeof enqueueCommand;
@@ -113,6 +113,55 @@ describe("installToolResultContextGuard", () => {
     expectCompactedToolResultsWithoutContextNotice(contextForNextCall, 1, 2);
   });
 
+  it("caps dense tool output by token count, not just chars", async () => {
+    const agent = makeGuardableAgent();
+    installToolResultContextGuard({ agent, contextWindowTokens: 1_000 });
+
+    // 900 CJK chars fit the char cap but are ~900 tokens against a 500-token share.
+    const contextForNextCall = [makeUser("hi"), makeToolResult("call_1", "漢".repeat(900))];
+    await agent.transformContext?.(contextForNextCall, new AbortController().signal);
+
+    const text = getToolResultText(contextForNextCall[1]);
+    expect(text).toContain(CONTEXT_LIMIT_TRUNCATION_NOTICE);
+    expect(text.length).toBeLessThan(600);
+  });
+
+  it("drops the oldest turns by token count when history overflows, keeping summaries and the current turn", async () => {
+    const agent = makeGuardableAgent();
+    installToolResultContextGuard({ agent, contextWindowTokens: 1_000 });
+
+    const summary = { role: "compactionSummary", summary: "earlier", timestamp: 1 };
+    const assistant = (text: string) =>
+      ({ role: "assistant", content: [{ type: "text", text }] }) as unknown as AgentMessage;
+    // Digit runs tokenize in groups of three, so ~2.6k chars fit a chars/4 budget (3k) but are
+    // ~880 tokens against the 750-token budget.
+    const digits = (count: number) => "1234567890".repeat(count / 10);
+    const contextForNextCall = [
+      summary as unknown as AgentMessage,
+      makeUser(digits(1_200)),
+      assistant(digits(450)),
+      makeUser(digits(600)),
+      assistant(digits(300)),
+      makeUser("now"),
+      makeToolResult("call_now", "fresh output"),
+    ];
+
+    const transformed = (await agent.transformContext?.(
+      contextForNextCall,
+      new AbortController().signal,
+    )) as AgentMessage[];
+
+    expect(transformed.map((msg) => msg.role)).toEqual([
+      "compactionSummary",
+      "user",
+      "assistant",
+      "user",
+      "toolResult",
+    ]);
+    expect(transformed[1]).toBe(contextForNextCall[3]);
+    expect(getToolResultText(transformed[4])).toBe("fresh output");
+  });
+
   it("keeps compacting oldest-first until context is back under budget", async () => {
     const agent = makeGuardableAgent();
 
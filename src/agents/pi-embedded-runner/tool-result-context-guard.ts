@@ -1,4 +1,6 @@
 import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { countMessageTokens, countTokens, forgetMessageTokens } from "../tokenizer.js";
+import { log } from "./logger.js";
 
 const CHARS_PER_TOKEN_ESTIMATE = 4;
 // Keep a conservative input budget to absorb tokenizer variance and provider framing overhead.
@@ -214,6 +216,28 @@ function truncateToolResultToChars(msg: AgentMessage, maxChars: number): AgentMe
   return replaceToolResultText(msg, truncatedText);
 }
 
+/**
+ * Char caps assume ~2 chars per token, which dense output (CJK text, base64, minified JSON)
+ * can undercut. Re-check the capped text with the tokenizer and shrink it proportionally.
+ */
+function truncateToolResultToTokens(
+  msg: AgentMessage,
+  maxTokens: number,
+  tokenizerModel?: { provider?: string; model?: string },
+): AgentMessage {
+  const rawText = getToolResultText(msg);
+  // Every token covers at least one char, so short text cannot exceed the budget.
+  if (rawText.length <= maxTokens) {
+    return msg;
+  }
+  const tokens = countTokens(rawText, tokenizerModel);
+  if (tokens <= maxTokens) {
+    return msg;
+  }
+  const maxChars = Math.floor((rawText.length * maxTokens) / tokens);
+  return replaceToolResultText(msg, truncateTextToBudget(rawText, maxChars));
+}
+
 function compactExistingToolResultsInPlace(params: {
   messages: AgentMessage[];
   charsNeeded: number;
@@ -256,6 +280,7 @@ function applyMessageMutationInPlace(target: AgentMessage, source: AgentMessage)
     return;
   }
 
+  forgetMessageTokens(target);
   const targetRecord = target as unknown as Record<string, unknown>;
   const sourceRecord = source as unknown as Record<string, unknown>;
   for (const key of Object.keys(targetRecord)) {
@@ -266,10 +291,65 @@ function applyMessageMutationInPlace(target: AgentMessage, source: AgentMessage)
   Object.assign(targetRecord, sourceRecord);
 }
 
+const TURN_ROLES = new Set(["user", "assistant", "toolResult", "tool"]);
+
+/**
+ * Last resort when compacting tool output is not enough: drop whole turns (user message
+ * through its assistant replies and tool results) from the front of the request until it
+ * fits. Leading summaries and the current turn (from the last user message on) are always
+ * kept, so pairing stays valid and the latest tool results survive. The transcript is
+ * untouched; only this request is trimmed. Counts use the model's tokenizer, since a
+ * chars/4 guess under-counts code, JSON, and non-Latin history; they are cached per message,
+ * so each turn only encodes what is new.
+ */
+function dropOldestTurnsToBudget(
+  messages: AgentMessage[],
+  contextBudgetTokens: number,
+  tokenizerModel?: { provider?: string; model?: string },
+): { messages: AgentMessage[]; dropped: number } {
+  const tokens = messages.map((msg) => countMessageTokens(msg, tokenizerModel));
+  let total = tokens.reduce((sum, count) => sum + count, 0);
+  if (total <= contextBudgetTokens) {
+    return { messages, dropped: 0 };
+  }
+  const roleOf = (msg: AgentMessage) => (msg as { role?: unknown }).role as string | undefined;
+  let start = 0;
+  while (start < messages.length && !TURN_ROLES.has(roleOf(messages[start]) ?? "")) {
+    start += 1;
+  }
+  let lastUserIndex = -1;
+  for (let i = messages.length - 1; i >= start; i--) {
+    if (roleOf(messages[i]) === "user") {
+      lastUserIndex = i;
+      break;
+    }
+  }
+  let cut = start;
+  while (total > contextBudgetTokens && cut < lastUserIndex) {
+    let next = cut + 1;
+    while (next < lastUserIndex && roleOf(messages[next]) !== "user") {
+      next += 1;
+    }
+    for (let i = cut; i < next; i++) {
+      total -= tokens[i];
+    }
+    cut = next;
+  }
+  if (cut === start) {
+    return { messages, dropped: 0 };
+  }
+  return {
+    messages: [...messages.slice(0, start), ...messages.slice(cut)],
+    dropped: cut - start,
+  };
+}
+
 function enforceToolResultContextBudgetInPlace(params: {
   messages: AgentMessage[];
   contextBudgetChars: number;
   maxSingleToolResultChars: number;
+  maxSingleToolResultTokens: number;
+  tokenizerModel?: { provider?: string; model?: string };
 }): void {
   const { messages, contextBudgetChars, maxSingleToolResultChars } = params;
 
@@ -278,7 +358,11 @@ function enforceToolResultContextBudgetInPlace(params: {
     if (!isToolResultMessage(message)) {
       continue;
     }
-    const truncated = truncateToolResultToChars(message, maxSingleToolResultChars);
+    const truncated = truncateToolResultToTokens(
+      truncateToolResultToChars(message, maxSingleToolResultChars),
+      params.maxSingleToolResultTokens,
+      params.tokenizerModel,
+    );
     applyMessageMutationInPlace(message, truncated);
   }
 
@@ -294,21 +378,53 @@ function enforceToolResultContextBudgetInPlace(params: {
   });
 }
 
+function enforceContextBudget(params: {
+  messages: AgentMessage[];
+  contextBudgetChars: number;
+  contextBudgetTokens: number;
+  maxSingleToolResultChars: number;
+  maxSingleToolResultTokens: number;
+  tokenizerModel?: { provider?: string; model?: string };
+}): AgentMessage[] {
+  enforceToolResultContextBudgetInPlace(params);
+  const trimmed = dropOldestTurnsToBudget(
+    params.messages,
+    params.contextBudgetTokens,
+    params.tokenizerModel,
+  );
+  if (trimmed.dropped > 0) {
+    log.warn(
+      `context guard: dropped ${trimmed.dropped} oldest message(s) from the request to fit the context window`,
+    );
+  }
+  return trimmed.messages;
+}
+
 export function installToolResultContextGuard(params: {
   agent: GuardableAgent;
   contextWindowTokens: number;
+  /** Model the context is built for; picks the tokenizer encoding. */
+  tokenizerModel?: { provider?: string; model?: string };
 }): () => void {
   const contextWindowTokens = Math.max(1, Math.floor(params.contextWindowTokens));
   const contextBudgetChars = Math.max(
     1_024,
     Math.floor(contextWindowTokens * CHARS_PER_TOKEN_ESTIMATE * CONTEXT_INPUT_HEADROOM_RATIO),
   );
+  const contextBudgetTokens = Math.max(
+    256,
+    Math.floor(contextWindowTokens * CONTEXT_INPUT_HEADROOM_RATIO),
+  );
   const maxSingleToolResultChars = Math.max(
     1_024,
     Math.floor(
       contextWindowTokens * TOOL_RESULT_CHARS_PER_TOKEN_ESTIMATE * SINGLE_TOOL_RESULT_CONTEXT_SHARE,
     ),
   );
+  const maxSingleToolResultTokens = Math.max(
+    256,
+    Math.floor(contextWindowTokens * SINGLE_TOOL_RESULT_CONTEXT_SHARE),
+  );
 
   // Agent.transformContext is private in pi-coding-agent, so access it via a
   // narrow runtime view to keep callsites type-safe while preserving behavior.
@@ -321,13 +437,14 @@ export function installToolResultContextGuard(params: {
       : messages;
 
     const contextMessages = Array.isArray(transformed) ? transformed : messages;
-    enforceToolResultContextBudgetInPlace({
+    return enforceContextBudget({
       messages: contextMessages,
       contextBudgetChars,
+      contextBudgetTokens,
       maxSingleToolResultChars,
+      maxSingleToolResultTokens,
+      tokenizerModel: params.tokenizerModel,
     });
-
-    return contextMessages;
   }) as GuardableTransformContext;
 
   return () => {
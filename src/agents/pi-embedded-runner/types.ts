@@ -46,6 +46,12 @@ export type EmbeddedPiRunMeta = {
   };
   /** Stop reason for the agent run (e.g., "completed", "tool_calls"). */
   stopReason?: string;
+  /** Tool failure the run ended with (not followed by a successful retry of the same action). */
+  toolError?: {
+    toolName: string;
+    meta?: string;
+    error?: string;
+  };
   /** Pending tool calls when stopReason is "tool_calls". */
   pendingToolCalls?: Array<{
     id: string;
@@ -71,6 +71,8 @@ export function handleMessageStart(
   // may deliver late text_end updates after message_end, which would otherwise
   // re-trigger block replies.
   ctx.resetAssistantMessageState(ctx.state.assistantTexts.length);
+  // A previous message that never reached message_end (aborted stream) still cost tokens.
+  ctx.flushStreamingUsage();
   // Use assistant message_start as the earliest "writing" signal for typing.
   void ctx.params.onAssistantMessageStart?.();
 }
@@ -85,6 +87,7 @@ export function handleMessageUpdate(
   }
 
   ctx.noteLastAssistant(msg);
+  ctx.noteStreamingUsage((msg as { usage?: unknown }).usage);
 
   const assistantEvent = evt.assistantMessageEvent;
   const assistantRecord =
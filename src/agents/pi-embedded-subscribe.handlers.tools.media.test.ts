@@ -21,6 +21,7 @@ function createMockContext(overrides?: {
       toolMetaById: new Map(),
       toolMetas: [],
       toolSummaryById: new Set(),
+      toolProgressById: new Map(),
       pendingMessagingTexts: new Map(),
       pendingMessagingTargets: new Map(),
       pendingMessagingMediaUrls: new Map(),
@@ -53,6 +54,8 @@ function createMockContext(overrides?: {
     resolveCompactionRetry: vi.fn(),
     maybeResolveCompactionWait: vi.fn(),
     recordAssistantUsage: vi.fn(),
+    noteStreamingUsage: vi.fn(),
+    flushStreamingUsage: vi.fn(),
     incrementCompactionCount: vi.fn(),
     getUsageTotals: vi.fn(() => undefined),
     getCompactionCount: vi.fn(() => 0),
@@ -251,4 +254,45 @@ describe("handleToolExecutionEnd media emission", () => {
       mediaUrls: ["/tmp/canvas-output.png"],
     });
   });
+
+  it("emits structured attachments in both verbose and non-verbose modes", async () => {
+    const result = {
+      content: [{ type: "text", text: "Chart rendered" }],
+      details: { attachments: [{ source: "/tmp/chart.png", kind: "image" }] },
+    };
+    for (const verbose of [false, true]) {
+      const onToolResult = vi.fn();
+      const ctx = createMockContext({ shouldEmitToolOutput: verbose, onToolResult });
+
+      await handleToolExecutionEnd(ctx, {
+        type: "tool_execution_end",
+        toolName: "canvas",
+        toolCallId: "tc-1",
+        isError: false,
+        result,
+      });
+
+      expect(onToolResult).toHaveBeenCalledWith({ mediaUrls: ["/tmp/chart.png"] });
+    }
+  });
+
+  it("keeps the local-file guard for structured attachments of untrusted tools", async () => {
+    const onToolResult = vi.fn();
+    const ctx = createMockContext({ shouldEmitToolOutput: false, onToolResult });
+
+    await handleToolExecutionEnd(ctx, {
+      type: "tool_execution_end",
+      toolName: "plugin_tool",
+      toolCallId: "tc-1",
+      isError: false,
+      result: {
+        content: [{ type: "text", text: "done" }],
+        details: {
+          attachments: [{ source: "/etc/passwd" }, { source: "https://example.com/a.mp3" }],
+        },
+      },
+    });
+
+    expect(onToolResult).toHaveBeenCalledWith({ mediaUrls: ["https://example.com/a.mp3"] });
+  });
 });
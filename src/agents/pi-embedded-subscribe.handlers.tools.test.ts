@@ -4,14 +4,17 @@ import type { MessagingToolSend } from "./pi-embedded-messaging.js";
 import {
   handleToolExecutionEnd,
   handleToolExecutionStart,
+  handleToolExecutionUpdate,
 } from "./pi-embedded-subscribe.handlers.tools.js";
 import type {
   ToolCallSummary,
   ToolHandlerContext,
 } from "./pi-embedded-subscribe.handlers.types.js";
+import { toolProgressResult } from "./tools/common.js";
 
 type ToolExecutionStartEvent = Extract<AgentEvent, { type: "tool_execution_start" }>;
 type ToolExecutionEndEvent = Extract<AgentEvent, { type: "tool_execution_end" }>;
+type ToolExecutionUpdateEvent = Extract<AgentEvent, { type: "tool_execution_update" }>;
 
 function createTestContext(): {
   ctx: ToolHandlerContext;
@@ -37,6 +40,7 @@ function createTestContext(): {
       toolMetaById: new Map<string, ToolCallSummary>(),
       toolMetas: [],
       toolSummaryById: new Set<string>(),
+      toolProgressById: new Map(),
       pendingMessagingTargets: new Map<string, MessagingToolSend>(),
       pendingMessagingTexts: new Map<string, string>(),
       pendingMessagingMediaUrls: new Map<string, string[]>(),
@@ -301,3 +305,117 @@ describe("messaging tool media URL tracking", () => {
     expect(ctx.state.pendingMessagingMediaUrls.has("tool-m3")).toBe(false);
   });
 });
+
+describe("tool progress messages", () => {
+  function progressUpdate(message: string, percent?: number): ToolExecutionUpdateEvent {
+    return {
+      type: "tool_execution_update",
+      toolName: "camera",
+      toolCallId: "tool-p1",
+      args: {},
+      partialResult: toolProgressResult({ message, percent }),
+    };
+  }
+
+  it("sends throttled progress once the tool has run for an interval", async () => {
+    vi.useFakeTimers();
+    try {
+      const { ctx } = createTestContext();
+      const onToolResult = vi.fn();
+      ctx.params.onToolResult = onToolResult;
+      ctx.params.toolProgressIntervalMs = 1000;
+
+      await handleToolExecutionStart(ctx, {
+        type: "tool_execution_start",
+        toolName: "camera",
+        toolCallId: "tool-p1",
+        args: {},
+      });
+      handleToolExecutionUpdate(ctx, progressUpdate("capturing image"));
+      expect(onToolResult).not.toHaveBeenCalled();
+
+      vi.advanceTimersByTime(1000);
+      handleToolExecutionUpdate(ctx, progressUpdate("flashing firmware", 40));
+      expect(onToolResult).toHaveBeenCalledWith({ text: "⏳ flashing firmware (40%)" });
+
+      vi.advanceTimersByTime(500);
+      handleToolExecutionUpdate(ctx, progressUpdate("flashing firmware", 60));
+      vi.advanceTimersByTime(1000);
+      handleToolExecutionUpdate(ctx, progressUpdate("flashing firmware", 40));
+      expect(onToolResult).toHaveBeenCalledTimes(1);
+
+      handleToolExecutionUpdate(ctx, progressUpdate("flashing firmware", 80));
+      expect(onToolResult).toHaveBeenLastCalledWith({ text: "⏳ flashing firmware (80%)" });
+    } finally {
+      vi.useRealTimers();
+    }
+  });
+
+  it("stays quiet when the interval is 0", async () => {
+    const { ctx } = createTestContext();
+    const onToolResult = vi.fn();
+    ctx.params.onToolResult = onToolResult;
+    ctx.params.toolProgressIntervalMs = 0;
+
+    handleToolExecutionUpdate(ctx, progressUpdate("capturing image"));
+
+    expect(onToolResult).not.toHaveBeenCalled();
+  });
+});
+
+describe("tool step lines", () => {
+  async function runTool(ctx: ToolHandlerContext, result: unknown, isError = false) {
+    await handleToolExecutionStart(ctx, {
+      type: "tool_execution_start",
+      toolName: "uno_q_adc_read",
+      toolCallId: "tool-s1",
+      args: { ch: 2, label: "battery   sense" },
+    });
+    await handleToolExecutionEnd(ctx, {
+      type: "tool_execution_end",
+      toolName: "uno_q_adc_read",
+      toolCallId: "tool-s1",
+      isError,
+      result,
+    });
+  }
+
+  it("sends one line per finished call with a truncated result", async () => {
+    const { ctx } = createTestContext();
+    const onToolResult = vi.fn();
+    ctx.params.onToolResult = onToolResult;
+    ctx.params.toolStepMaxChars = 5;
+
+    await runTool(ctx, { content: [{ type: "text", text: "512\n" }] });
+    await runTool(ctx, { content: [{ type: "text", text: "reading: 1023 raw" }] });
+
+    expect(onToolResult).toHaveBeenNthCalledWith(1, {
+      text: "→ uno_q_adc_read(ch=2, label=battery sense) = 512",
+    });
+    expect(onToolResult).toHaveBeenNthCalledWith(2, {
+      text: "→ uno_q_adc_read(ch=2, label=battery sense) = read…",
+    });
+  });
+
+  it("reports failures and stays quiet when off or verbose", async () => {
+    const { ctx } = createTestContext();
+    const onToolResult = vi.fn();
+    ctx.params.onToolResult = onToolResult;
+
+    await runTool(ctx, { content: [{ type: "text", text: "512" }] });
+    expect(onToolResult).not.toHaveBeenCalled();
+
+    ctx.params.toolStepMaxChars = 0;
+    await runTool(ctx, { details: { status: "error", error: "channel busy" } }, true);
+    expect(onToolResult).toHaveBeenCalledWith({
+      text: "→ uno_q_adc_read(ch=2, label=battery sense) failed",
+    });
+
+    onToolResult.mockClear();
+    ctx.shouldEmitToolResult = () => true;
+    await runTool(ctx, { content: [{ type: "text", text: "512" }] });
+    expect(onToolResult).not.toHaveBeenCalledWith(
+      expect.objectContaining({ text: expect.stringContaining("→") }),
+    );
+  });
+});
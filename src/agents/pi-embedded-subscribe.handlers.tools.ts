@@ -20,10 +20,19 @@ import {
 import { inferToolMetaFromArgs } from "./pi-embedded-utils.js";
 import { buildToolMutationState, isSameToolMutationAction } from "./tool-mutation.js";
 import { normalizeToolName } from "./tool-policy.js";
+import {
+  formatToolProgress,
+  readToolProgress,
+  readToolResultAttachments,
+  type ToolProgress,
+} from "./tools/common.js";
 
 /** Track tool execution start times and args for after_tool_call hook */
 const toolStartData = new Map<string, { startTime: number; args: unknown }>();
 
+const DEFAULT_TOOL_PROGRESS_INTERVAL_MS = 10_000;
+const TOOL_STEP_ARG_MAX_CHARS = 40;
+
 function isCronAddAction(args: unknown): boolean {
   if (!args || typeof args !== "object") {
     return false;
@@ -147,6 +156,20 @@ function emitToolResultOutput(params: {
     if (outputText) {
       ctx.emitToolOutput(toolName, meta, outputText);
     }
+    // Structured attachments are not part of the text, so emitToolOutput cannot pick them up.
+    const attachmentUrls = isToolError
+      ? []
+      : readToolResultAttachments((result as { details?: unknown } | undefined)?.details)
+          .map((attachment) => attachment.source)
+          .filter((source) => !outputText?.includes(source));
+    const mediaUrls = filterToolResultMediaUrls(toolName, attachmentUrls);
+    if (mediaUrls.length > 0) {
+      try {
+        void ctx.params.onToolResult({ mediaUrls });
+      } catch {
+        // ignore delivery failures
+      }
+    }
     return;
   }
 
@@ -258,6 +281,93 @@ export async function handleToolExecutionStart(
   }
 }
 
+/**
+ * Send a tool's progress line to the chat, regardless of verbose level. Quick tools stay quiet:
+ * the first line waits one interval after the tool starts, later lines one interval apart.
+ */
+function maybeEmitToolProgress(
+  ctx: ToolHandlerContext,
+  toolCallId: string,
+  progress: ToolProgress,
+) {
+  const intervalMs = ctx.params.toolProgressIntervalMs ?? DEFAULT_TOOL_PROGRESS_INTERVAL_MS;
+  if (!ctx.params.onToolResult || intervalMs <= 0) {
+    return;
+  }
+  const now = Date.now();
+  const text = formatToolProgress(progress);
+  const last = ctx.state.toolProgressById.get(toolCallId);
+  const since = last?.sentAt ?? toolStartData.get(toolCallId)?.startTime ?? now;
+  if (last?.text === text || now - since < intervalMs) {
+    return;
+  }
+  ctx.state.toolProgressById.set(toolCallId, { sentAt: now, text });
+  try {
+    void ctx.params.onToolResult({ text });
+  } catch {
+    // ignore delivery failures
+  }
+}
+
+function truncateToolStepText(value: unknown, maxChars: number): string {
+  const text = typeof value === "string" ? value : (JSON.stringify(value) ?? String(value));
+  const flat = text.replace(/\s+/g, " ").trim();
+  return flat.length > maxChars ? `${flat.slice(0, Math.max(0, maxChars - 1))}…` : flat;
+}
+
+function formatToolStep(params: {
+  toolName: string;
+  args: unknown;
+  isError: boolean;
+  resultText?: string;
+  maxChars: number;
+}): string {
+  const args =
+    params.args && typeof params.args === "object" && !Array.isArray(params.args)
+      ? Object.entries(params.args as Record<string, unknown>)
+          .filter(([, value]) => value !== undefined)
+          .map(([key, value]) => `${key}=${truncateToolStepText(value, TOOL_STEP_ARG_MAX_CHARS)}`)
+          .join(", ")
+      : "";
+  const call = `→ ${params.toolName}(${args})`;
+  const result =
+    params.maxChars > 0 && params.resultText
+      ? truncateToolStepText(params.resultText, params.maxChars)
+      : "";
+  if (params.isError) {
+    return result ? `${call} failed: ${result}` : `${call} failed`;
+  }
+  return result ? `${call} = ${result}` : call;
+}
+
+/**
+ * Send one "→ tool(args) = result" line per finished call (messages.toolSteps). Skipped when
+ * verbose mode already reports tool activity, so the chat does not get both.
+ */
+function maybeEmitToolStep(
+  ctx: ToolHandlerContext,
+  params: { toolName: string; args: unknown; isToolError: boolean; sanitizedResult: unknown },
+) {
+  const maxChars = ctx.params.toolStepMaxChars;
+  if (!ctx.params.onToolResult || maxChars === undefined || ctx.shouldEmitToolResult()) {
+    return;
+  }
+  const text = formatToolStep({
+    toolName: params.toolName,
+    args: params.args,
+    isError: params.isToolError,
+    resultText: params.isToolError
+      ? extractToolErrorMessage(params.sanitizedResult)
+      : extractToolResultText(params.sanitizedResult),
+    maxChars,
+  });
+  try {
+    void ctx.params.onToolResult({ text });
+  } catch {
+    // ignore delivery failures
+  }
+}
+
 export function handleToolExecutionUpdate(
   ctx: ToolHandlerContext,
   evt: AgentEvent & {
@@ -288,6 +398,10 @@ export function handleToolExecutionUpdate(
       toolCallId,
     },
   });
+  const progress = readToolProgress(partial);
+  if (progress) {
+    maybeEmitToolProgress(ctx, toolCallId, progress);
+  }
 }
 
 export async function handleToolExecutionEnd(
@@ -312,6 +426,7 @@ export async function handleToolExecutionEnd(
   ctx.state.toolMetas.push({ toolName, meta });
   ctx.state.toolMetaById.delete(toolCallId);
   ctx.state.toolSummaryById.delete(toolCallId);
+  ctx.state.toolProgressById.delete(toolCallId);
   if (isToolError) {
     const errorMessage = extractToolErrorMessage(sanitizedResult);
     ctx.state.lastToolError = {
@@ -410,6 +525,7 @@ export async function handleToolExecutionEnd(
   );
 
   emitToolResultOutput({ ctx, toolName, meta, isToolError, result, sanitizedResult });
+  maybeEmitToolStep(ctx, { toolName, args: startData?.args, isToolError, sanitizedResult });
 
   // Run after_tool_call plugin hook (fire-and-forget)
   const hookRunnerAfter = ctx.hookRunner ?? getGlobalHookRunner();
@@ -35,6 +35,8 @@ export type EmbeddedPiSubscribeState = {
   toolMetas: Array<{ toolName?: string; meta?: string }>;
   toolMetaById: Map<string, ToolCallSummary>;
   toolSummaryById: Set<string>;
+  /** Last progress line sent per tool call, for throttling. */
+  toolProgressById: Map<string, { sentAt: number; text: string }>;
   lastToolError?: ToolErrorSummary;
 
   blockReplyBreak: "text_end" | "message_end";
@@ -120,6 +122,10 @@ export type EmbeddedPiSubscribeContext = {
   resolveCompactionRetry: () => void;
   maybeResolveCompactionWait: () => void;
   recordAssistantUsage: (usage: unknown) => void;
+  /** Track usage reported mid-stream for the in-flight assistant message. */
+  noteStreamingUsage: (usage: unknown) => void;
+  /** Count partial usage of an assistant message that never reached message_end. */
+  flushStreamingUsage: () => void;
   incrementCompactionCount: () => void;
   getUsageTotals: () => NormalizedUsage | undefined;
   getCompactionCount: () => number;
@@ -132,7 +138,12 @@ export type EmbeddedPiSubscribeContext = {
  */
 export type ToolHandlerParams = Pick<
   SubscribeEmbeddedPiSessionParams,
-  "runId" | "onBlockReplyFlush" | "onAgentEvent" | "onToolResult"
+  | "runId"
+  | "onBlockReplyFlush"
+  | "onAgentEvent"
+  | "onToolResult"
+  | "toolProgressIntervalMs"
+  | "toolStepMaxChars"
 >;
 
 export type ToolHandlerState = Pick<
@@ -140,6 +151,7 @@ export type ToolHandlerState = Pick<
   | "toolMetaById"
   | "toolMetas"
   | "toolSummaryById"
+  | "toolProgressById"
   | "lastToolError"
   | "pendingMessagingTargets"
   | "pendingMessagingTexts"
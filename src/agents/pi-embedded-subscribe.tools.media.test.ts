@@ -1,5 +1,6 @@
 import { describe, expect, it } from "vitest";
 import { extractToolResultMediaPaths } from "./pi-embedded-subscribe.tools.js";
+import { jsonResult, withToolAttachments } from "./tools/common.js";
 
 describe("extractToolResultMediaPaths", () => {
   it("returns empty array for null/undefined", () => {
@@ -229,4 +230,32 @@ describe("extractToolResultMediaPaths", () => {
     };
     expect(extractToolResultMediaPaths(result)).toEqual(["/tmp/page1.png", "/tmp/page2.png"]);
   });
+
+  it("prefers structured details.attachments over MEDIA: text", () => {
+    const result = withToolAttachments(
+      { content: [{ type: "text", text: "MEDIA:/tmp/from-text.png" }], details: { ok: true } },
+      [
+        { source: "/tmp/chart.png", kind: "image" },
+        { source: " /tmp/clip.ogg ", kind: "audio" },
+        { source: "/tmp/chart.png" },
+      ],
+    );
+    expect(result.details).toMatchObject({ ok: true });
+    expect(extractToolResultMediaPaths(result)).toEqual(["/tmp/chart.png", "/tmp/clip.ogg"]);
+  });
+
+  it("reads attachments without content blocks and skips malformed entries", () => {
+    const result = withToolAttachments(jsonResult({ rows: 3 }), [
+      { source: "https://example.com/report.pdf", kind: "file" },
+    ]);
+    expect(extractToolResultMediaPaths({ details: result.details })).toEqual([
+      "https://example.com/report.pdf",
+    ]);
+    expect(
+      extractToolResultMediaPaths({
+        content: [{ type: "text", text: "MEDIA:/tmp/fallback.png" }],
+        details: { attachments: [{ source: "  " }, { path: "/tmp/x.png" }, "nope"] },
+      }),
+    ).toEqual(["/tmp/fallback.png"]);
+  });
 });
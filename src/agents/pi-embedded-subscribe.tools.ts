@@ -5,6 +5,7 @@ import { truncateUtf16Safe } from "../utils.js";
 import { collectTextContentBlocks } from "./content-blocks.js";
 import { type MessagingToolSend } from "./pi-embedded-messaging.js";
 import { normalizeToolName } from "./tool-policy.js";
+import { readToolResultAttachments } from "./tools/common.js";
 
 const TOOL_RESULT_MAX_CHARS = 8000;
 const TOOL_ERROR_MAX_CHARS = 400;
@@ -186,8 +187,9 @@ export function filterToolResultMediaUrls(
  * Extract media file paths from a tool result.
  *
  * Strategy (first match wins):
- * 1. Parse `MEDIA:` tokens from text content blocks (all OpenClaw tools).
- * 2. Fall back to `details.path` when image content exists (OpenClaw imageResult).
+ * 1. Structured `details.attachments` (see `withToolAttachments`).
+ * 2. Parse `MEDIA:` tokens from text content blocks (all OpenClaw tools).
+ * 3. Fall back to `details.path` when image content exists (OpenClaw imageResult).
  *
  * Returns an empty array when no media is found (e.g. Pi SDK `read` tool
  * returns base64 image data but no file path; those need a different delivery
@@ -198,6 +200,10 @@ export function extractToolResultMediaPaths(result: unknown): string[] {
     return [];
   }
   const record = result as Record<string, unknown>;
+  const attachments = readToolResultAttachments(record.details);
+  if (attachments.length > 0) {
+    return Array.from(new Set(attachments.map((attachment) => attachment.source)));
+  }
   const content = Array.isArray(record.content) ? record.content : null;
   if (!content) {
     return [];
@@ -19,7 +19,12 @@ import type {
 import { filterToolResultMediaUrls } from "./pi-embedded-subscribe.tools.js";
 import type { SubscribeEmbeddedPiSessionParams } from "./pi-embedded-subscribe.types.js";
 import { formatReasoningMessage, stripDowngradedToolCallText } from "./pi-embedded-utils.js";
-import { hasNonzeroUsage, normalizeUsage, type UsageLike } from "./usage.js";
+import {
+  createStreamingUsageAccumulator,
+  hasNonzeroUsage,
+  type NormalizedUsage,
+  type UsageLike,
+} from "./usage.js";
 
 const THINKING_TAG_SCAN_RE = /<\s*(\/?)\s*(?:think(?:ing)?|thought|antthinking)\s*>/gi;
 const FINAL_TAG_SCAN_RE = /<\s*(\/?)\s*final\s*>/gi;
@@ -40,6 +45,7 @@ export function subscribeEmbeddedPiSession(params: SubscribeEmbeddedPiSessionPar
     toolMetas: [],
     toolMetaById: new Map(),
     toolSummaryById: new Set(),
+    toolProgressById: new Map(),
     lastToolError: undefined,
     blockReplyBreak: params.blockReplyBreak ?? "text_end",
     reasoningMode,
@@ -256,8 +262,10 @@ export function subscribeEmbeddedPiSession(params: SubscribeEmbeddedPiSessionPar
       state.compactionRetryPromise = null;
     }
   };
-  const recordAssistantUsage = (usageLike: unknown) => {
-    const usage = normalizeUsage((usageLike ?? undefined) as UsageLike | undefined);
+  // Usage for the in-flight assistant message; folded into usageTotals at message_end, or
+  // when the next message starts / the run reads totals after an aborted stream.
+  const streamingUsage = createStreamingUsageAccumulator();
+  const addToUsageTotals = (usage: NormalizedUsage | undefined) => {
     if (!hasNonzeroUsage(usage)) {
       return;
     }
@@ -270,24 +278,41 @@ export function subscribeEmbeddedPiSession(params: SubscribeEmbeddedPiSessionPar
       (usage.input ?? 0) + (usage.output ?? 0) + (usage.cacheRead ?? 0) + (usage.cacheWrite ?? 0);
     usageTotals.total += usageTotal;
   };
+  const recordAssistantUsage = (usageLike: unknown) => {
+    addToUsageTotals(streamingUsage.finalize((usageLike ?? undefined) as UsageLike | undefined));
+  };
+  const noteStreamingUsage = (usageLike: unknown) => {
+    streamingUsage.update((usageLike ?? undefined) as UsageLike | undefined);
+  };
+  const flushStreamingUsage = () => {
+    addToUsageTotals(streamingUsage.finalize());
+  };
   const getUsageTotals = () => {
-    const hasUsage =
-      usageTotals.input > 0 ||
-      usageTotals.output > 0 ||
-      usageTotals.cacheRead > 0 ||
-      usageTotals.cacheWrite > 0 ||
-      usageTotals.total > 0;
+    // Include partial usage of an in-flight (e.g. aborted) stream without closing it.
+    const pending = streamingUsage.pending();
+    const input = usageTotals.input + (pending?.input ?? 0);
+    const output = usageTotals.output + (pending?.output ?? 0);
+    const cacheRead = usageTotals.cacheRead + (pending?.cacheRead ?? 0);
+    const cacheWrite = usageTotals.cacheWrite + (pending?.cacheWrite ?? 0);
+    const pendingTotal = pending
+      ? (pending.total ??
+        (pending.input ?? 0) +
+          (pending.output ?? 0) +
+          (pending.cacheRead ?? 0) +
+          (pending.cacheWrite ?? 0))
+      : 0;
+    const total = usageTotals.total + pendingTotal;
+    const hasUsage = input > 0 || output > 0 || cacheRead > 0 || cacheWrite > 0 || total > 0;
     if (!hasUsage) {
       return undefined;
     }
-    const derivedTotal =
-      usageTotals.input + usageTotals.output + usageTotals.cacheRead + usageTotals.cacheWrite;
+    const derivedTotal = input + output + cacheRead + cacheWrite;
     return {
-      input: usageTotals.input || undefined,
-      output: usageTotals.output || undefined,
-      cacheRead: usageTotals.cacheRead || undefined,
-      cacheWrite: usageTotals.cacheWrite || undefined,
-      total: usageTotals.total || derivedTotal || undefined,
+      input: input || undefined,
+      output: output || undefined,
+      cacheRead: cacheRead || undefined,
+      cacheWrite: cacheWrite || undefined,
+      total: total || derivedTotal || undefined,
     };
   };
   const incrementCompactionCount = () => {
@@ -577,6 +602,7 @@ export function subscribeEmbeddedPiSession(params: SubscribeEmbeddedPiSessionPar
     toolMetas.length = 0;
     toolMetaById.clear();
     toolSummaryById.clear();
+    state.toolProgressById.clear();
     state.lastToolError = undefined;
     messagingToolSentTexts.length = 0;
     messagingToolSentTextsNormalized.length = 0;
@@ -622,6 +648,8 @@ export function subscribeEmbeddedPiSession(params: SubscribeEmbeddedPiSessionPar
     resolveCompactionRetry,
     maybeResolveCompactionWait,
     recordAssistantUsage,
+    noteStreamingUsage,
+    flushStreamingUsage,
     incrementCompactionCount,
     getUsageTotals,
     getCompactionCount: () => compactionCount,
@@ -17,6 +17,10 @@ export type SubscribeEmbeddedPiSessionParams = {
   shouldEmitToolResult?: () => boolean;
   shouldEmitToolOutput?: () => boolean;
   onToolResult?: (payload: { text?: string; mediaUrls?: string[] }) => void | Promise<void>;
+  /** Min gap between tool progress lines sent via onToolResult (0 disables; default 10s). */
+  toolProgressIntervalMs?: number;
+  /** Per-call "→ tool(args) = result" lines, result capped at this length; unset = off. */
+  toolStepMaxChars?: number;
   onReasoningStream?: (payload: { text?: string; mediaUrls?: string[] }) => void | Promise<void>;
   /** Called when a thinking/reasoning block ends (</think> tag processed). */
   onReasoningEnd?: () => void | Promise<void>;
@@ -47,3 +47,41 @@ describe("pi tool definition adapter", () => {
     });
   });
 });
+
+describe("pi tool definition adapter arguments", () => {
+  const makeEchoTool = (calls: unknown[]) =>
+    ({
+      name: "echo",
+      label: "Echo",
+      description: "echoes params",
+      parameters: Type.Object({}),
+      execute: async (_id: string, params: unknown) => {
+        calls.push(params);
+        return { content: [{ type: "text" as const, text: "ok" }], details: { ok: true } };
+      },
+    }) satisfies AgentTool;
+
+  it("decodes double-encoded JSON arguments", async () => {
+    const calls: unknown[] = [];
+    const [def] = toToolDefinitions([makeEchoTool(calls)]);
+    await def?.execute("call3", '{"path":"a.txt"}', undefined, undefined, extensionContext);
+    expect(calls).toEqual([{ path: "a.txt" }]);
+  });
+
+  it("rejects pseudo-JSON arguments before execute", async () => {
+    const calls: unknown[] = [];
+    const [def] = toToolDefinitions([makeEchoTool(calls)]);
+    const result = await def?.execute(
+      "call4",
+      "{path: a.txt",
+      undefined,
+      undefined,
+      extensionContext,
+    );
+    expect(calls).toEqual([]);
+    expect(result?.details).toMatchObject({ status: "error", tool: "echo" });
+    expect(String((result?.details as { error?: unknown }).error)).toContain(
+      "Malformed tool arguments",
+    );
+  });
+});
@@ -7,6 +7,7 @@ import type { ToolDefinition } from "@mariozechner/pi-coding-agent";
 import { logDebug, logError } from "../logger.js";
 import { getGlobalHookRunner } from "../plugins/hook-runner-global.js";
 import { isPlainObject } from "../utils.js";
+import { listAgentMiddlewares, runToolWithMiddleware } from "./agent-middleware.js";
 import type { ClientToolDefinition } from "./pi-embedded-runner/run/params.js";
 import type { HookContext } from "./pi-tools.before-tool-call.js";
 import {
@@ -14,6 +15,7 @@ import {
   isToolWrappedWithBeforeToolCallHook,
   runBeforeToolCallHook,
 } from "./pi-tools.before-tool-call.js";
+import { parseToolCallArguments } from "./tool-call-arguments.js";
 import { normalizeToolName } from "./tool-policy.js";
 import { jsonResult } from "./tools/common.js";
 
@@ -97,9 +99,14 @@ export function toToolDefinitions(tools: AnyAgentTool[]): ToolDefinition[] {
       description: tool.description ?? "",
       parameters: tool.parameters,
       execute: async (...args: ToolExecuteArgs): Promise<AgentToolResult<unknown>> => {
-        const { toolCallId, params, onUpdate, signal } = splitToolExecuteArgs(args);
+        const { toolCallId, params: rawParams, onUpdate, signal } = splitToolExecuteArgs(args);
+        const parsedArgs = parseToolCallArguments(rawParams);
+        const params = parsedArgs.ok ? parsedArgs.params : rawParams;
         let executeParams = params;
         try {
+          if (!parsedArgs.ok) {
+            throw new Error(parsedArgs.error);
+          }
           if (!beforeHookWrapped) {
             const hookOutcome = await runBeforeToolCallHook({
               toolName: name,
@@ -111,7 +118,14 @@ export function toToolDefinitions(tools: AnyAgentTool[]): ToolDefinition[] {
             }
             executeParams = hookOutcome.params;
           }
-          const result = await tool.execute(toolCallId, executeParams, signal, onUpdate);
+          const result = await runToolWithMiddleware({
+            middlewares: listAgentMiddlewares(),
+            toolName: name,
+            toolCallId,
+            params: executeParams,
+            ctx: { toolName: name },
+            execute: (toolParams) => tool.execute(toolCallId, toolParams, signal, onUpdate),
+          });
           const afterParams = beforeHookWrapped
             ? (consumeAdjustedParamsForToolCall(toolCallId) ?? executeParams)
             : executeParams;
@@ -203,7 +217,12 @@ export function toClientToolDefinitions(
       description: func.description ?? "",
       parameters: func.parameters as ToolDefinition["parameters"],
       execute: async (...args: ToolExecuteArgs): Promise<AgentToolResult<unknown>> => {
-        const { toolCallId, params } = splitToolExecuteArgs(args);
+        const { toolCallId, params: rawParams } = splitToolExecuteArgs(args);
+        const parsedArgs = parseToolCallArguments(rawParams);
+        if (!parsedArgs.ok) {
+          throw new Error(parsedArgs.error);
+        }
+        const params = parsedArgs.params;
         const outcome = await runBeforeToolCallHook({
           toolName: func.name,
           params,
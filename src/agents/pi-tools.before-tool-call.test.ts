@@ -319,3 +319,65 @@ describe("before_tool_call loop detection behavior", () => {
     });
   });
 });
+
+describe("before_tool_call per-run call budget", () => {
+  beforeEach(() => {
+    resetDiagnosticSessionStateForTest();
+    // oxlint-disable-next-line typescript/no-explicit-any
+    mockGetGlobalHookRunner.mockReturnValue({ hasHooks: vi.fn(() => false) } as any);
+  });
+
+  it("refuses calls past tools.maxCallsPerRun across all tools of the run", async () => {
+    const callBudget = { limit: 2, used: 0 };
+    const ctx = { agentId: "main", sessionKey: "budget", callBudget };
+    const readExecute = vi.fn().mockResolvedValue({ content: [], details: {} });
+    const execExecute = vi.fn().mockResolvedValue({ content: [], details: {} });
+    const read = wrapToolWithBeforeToolCallHook(
+      { name: "read", execute: readExecute } as unknown as AnyAgentTool,
+      ctx,
+    );
+    const exec = wrapToolWithBeforeToolCallHook(
+      { name: "exec", execute: execExecute } as unknown as AnyAgentTool,
+      ctx,
+    );
+
+    await read.execute("c1", { path: "a" }, undefined, undefined);
+    await exec.execute("c2", { command: "ls" }, undefined, undefined);
+    await expect(read.execute("c3", { path: "b" }, undefined, undefined)).rejects.toThrow(
+      "Tool call limit reached (2 calls this run)",
+    );
+    expect(readExecute).toHaveBeenCalledTimes(1);
+    expect(execExecute).toHaveBeenCalledTimes(1);
+    expect(callBudget.used).toBe(2);
+  });
+
+  it("does not charge the budget for calls that autonomy or guardrails block", async () => {
+    const callBudget = { limit: 1, used: 0 };
+    const ctx = {
+      agentId: "main",
+      sessionKey: "budget-blocked",
+      callBudget,
+      autonomy: { mode: "observe" as const },
+      guardrails: [{ tools: ["read"], param: "path", match: "^/etc/" }],
+    };
+    const execute = vi.fn().mockResolvedValue({ content: [], details: {} });
+    const exec = wrapToolWithBeforeToolCallHook(
+      { name: "exec", execute } as unknown as AnyAgentTool,
+      ctx,
+    );
+    const read = wrapToolWithBeforeToolCallHook(
+      { name: "read", execute } as unknown as AnyAgentTool,
+      ctx,
+    );
+
+    await expect(exec.execute("c1", { command: "ls" }, undefined, undefined)).rejects.toThrow(
+      "observe-only",
+    );
+    await expect(
+      read.execute("c2", { path: "/etc/shadow" }, undefined, undefined),
+    ).rejects.toThrow();
+    expect(callBudget.used).toBe(0);
+    await read.execute("c3", { path: "notes.md" }, undefined, undefined);
+    expect(callBudget.used).toBe(1);
+  });
+});
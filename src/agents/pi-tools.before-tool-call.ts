@@ -1,18 +1,32 @@
-import type { ToolLoopDetectionConfig } from "../config/types.tools.js";
+import type { ToolGuardrailRule, ToolLoopDetectionConfig } from "../config/types.tools.js";
 import type { SessionState } from "../logging/diagnostic-session-state.js";
 import { createSubsystemLogger } from "../logging/subsystem.js";
 import { getGlobalHookRunner } from "../plugins/hook-runner-global.js";
 import { isPlainObject } from "../utils.js";
+import { checkToolAutonomy, type ToolAutonomyState } from "./tool-autonomy.js";
+import { applyToolGuardrails } from "./tool-guardrails.js";
 import { normalizeToolName } from "./tool-policy.js";
 import type { AnyAgentTool } from "./tools/common.js";
+import { readToolUndoAction, recordUndoAction, UNDO_TOOL_CALL_PREFIX } from "./undo-journal.js";
+
+/** Shared by every tool of one run; counts calls against `tools.maxCallsPerRun`. */
+export type ToolCallBudget = {
+  limit: number;
+  used: number;
+};
 
 export type HookContext = {
   agentId?: string;
   sessionKey?: string;
   loopDetection?: ToolLoopDetectionConfig;
+  callBudget?: ToolCallBudget;
+  autonomy?: ToolAutonomyState;
+  guardrails?: ToolGuardrailRule[];
 };
 
-type HookOutcome = { blocked: true; reason: string } | { blocked: false; params: unknown };
+type HookOutcome =
+  | { blocked: true; reason: string }
+  | { blocked: false; params: unknown; notice?: string };
 
 const log = createSubsystemLogger("agents/tools");
 const BEFORE_TOOL_CALL_WRAPPED = Symbol("beforeToolCallWrapped");
@@ -80,6 +94,30 @@ export async function runBeforeToolCallHook(args: {
   const toolName = normalizeToolName(args.toolName || "tool");
   const params = args.params;
 
+  const budget = args.ctx?.callBudget;
+  if (budget) {
+    if (budget.used >= budget.limit) {
+      log.warn(`Blocking ${toolName}: tool call limit reached (${budget.limit} per run)`);
+      return {
+        blocked: true,
+        reason: `Tool call limit reached (${budget.limit} calls this run). Do not call more tools. Reply now with what you found so far, say what is unfinished, and suggest how to continue.`,
+      };
+    }
+  }
+
+  if (args.ctx?.autonomy) {
+    const reason = checkToolAutonomy({
+      autonomy: args.ctx.autonomy,
+      toolName,
+      toolParams: params,
+      sessionKey: args.ctx.sessionKey,
+    });
+    if (reason) {
+      log.info(`Blocking ${toolName}: autonomy=${args.ctx.autonomy.mode}`);
+      return { blocked: true, reason };
+    }
+  }
+
   if (args.ctx?.sessionKey) {
     const { getDiagnosticSessionState } = await import("../logging/diagnostic-session-state.js");
     const { logToolLoopAction } = await import("../logging/diagnostic.js");
@@ -132,9 +170,33 @@ export async function runBeforeToolCallHook(args: {
     recordToolCall(sessionState, toolName, params, args.toolCallId, args.ctx.loopDetection);
   }
 
+  const pluginOutcome = await runPluginBeforeToolCallHook({ ...args, toolName, params });
+  // Config guardrails run last so plugin rewrites cannot slip past them.
+  const outcome =
+    pluginOutcome.blocked || !args.ctx?.guardrails?.length
+      ? pluginOutcome
+      : applyToolGuardrails({
+          rules: args.ctx.guardrails,
+          toolName,
+          params: pluginOutcome.params,
+        });
+  // Only calls that will actually run count against the budget.
+  if (budget && !outcome.blocked) {
+    budget.used += 1;
+  }
+  return outcome;
+}
+
+async function runPluginBeforeToolCallHook(args: {
+  toolName: string;
+  params: unknown;
+  toolCallId?: string;
+  ctx?: HookContext;
+}): Promise<HookOutcome> {
+  const { toolName, params } = args;
   const hookRunner = getGlobalHookRunner();
   if (!hookRunner?.hasHooks("before_tool_call")) {
-    return { blocked: false, params: args.params };
+    return { blocked: false, params };
   }
 
   try {
@@ -172,6 +234,28 @@ export async function runBeforeToolCallHook(args: {
   return { blocked: false, params };
 }
 
+/** Journals the prior state a reversible tool reported (`details.undo`) for undo_last / `/undo`. */
+async function journalUndoAction(args: {
+  ctx?: HookContext;
+  toolName: string;
+  toolCallId?: string;
+  result: unknown;
+}) {
+  const sessionKey = args.ctx?.sessionKey;
+  if (!sessionKey || args.toolCallId?.startsWith(UNDO_TOOL_CALL_PREFIX)) {
+    return;
+  }
+  const action = readToolUndoAction(args.result);
+  if (!action) {
+    return;
+  }
+  try {
+    await recordUndoAction({ sessionKey, sourceTool: args.toolName, action });
+  } catch (err) {
+    log.warn(`undo journal write failed: tool=${args.toolName} error=${String(err)}`);
+  }
+}
+
 export function wrapToolWithBeforeToolCallHook(
   tool: AnyAgentTool,
   ctx?: HookContext,
@@ -204,7 +288,16 @@ export function wrapToolWithBeforeToolCallHook(
       }
       const normalizedToolName = normalizeToolName(toolName || "tool");
       try {
-        const result = await execute(toolCallId, outcome.params, signal, onUpdate);
+        const executed = await execute(toolCallId, outcome.params, signal, onUpdate);
+        // Tell the model when a guardrail rewrote its arguments.
+        const result =
+          outcome.notice && Array.isArray(executed?.content)
+            ? {
+                ...executed,
+                content: [{ type: "text" as const, text: outcome.notice }, ...executed.content],
+              }
+            : executed;
+        await journalUndoAction({ ctx, toolName, toolCallId, result });
         await recordLoopOutcome({
           ctx,
           toolName: normalizedToolName,
@@ -55,6 +55,9 @@ const SUBAGENT_TOOL_DENY_ALWAYS = [
   // Memory - pass relevant info in spawn prompt instead
   "memory_search",
   "memory_get",
+  "memory_set",
+  "memory_list",
+  "kb_ingest",
   // Direct session sends - subagents communicate through announce chain
   "sessions_send",
 ];
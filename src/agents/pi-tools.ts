@@ -47,8 +47,14 @@ import {
 } from "./pi-tools.read.js";
 import { cleanToolSchemaForGemini, normalizeToolParameters } from "./pi-tools.schema.js";
 import type { AnyAgentTool } from "./pi-tools.types.js";
+import { wrapToolWithoutImageResults } from "./pi-tools.vision.js";
 import type { SandboxContext } from "./sandbox.js";
-import { getSubagentDepthFromSessionStore } from "./subagent-depth.js";
+import {
+  getSubagentDepthFromSessionStore,
+  getSubagentSpawnToolsFromSessionStore,
+} from "./subagent-depth.js";
+import { resolveToolAutonomy } from "./tool-autonomy.js";
+import { resolveToolGuardrails } from "./tool-guardrails.js";
 import {
   applyToolPolicyPipeline,
   buildDefaultToolPolicyPipelineSteps,
@@ -133,6 +139,20 @@ function resolveFsConfig(params: { cfg?: OpenClawConfig; agentId?: string }) {
   };
 }
 
+export function resolveToolCallLimit(params: {
+  cfg?: OpenClawConfig;
+  agentId?: string;
+}): number | undefined {
+  const agent =
+    params.agentId && params.cfg
+      ? resolveAgentConfig(params.cfg, params.agentId)?.tools?.maxCallsPerRun
+      : undefined;
+  const limit = agent ?? params.cfg?.tools?.maxCallsPerRun;
+  return typeof limit === "number" && Number.isFinite(limit) && limit > 0
+    ? Math.floor(limit)
+    : undefined;
+}
+
 export function resolveToolLoopDetectionConfig(params: {
   cfg?: OpenClawConfig;
   agentId?: string;
@@ -276,6 +296,12 @@ export function createOpenClawCodingTools(options?: {
           getSubagentDepthFromSessionStore(options.sessionKey, { cfg: options.config }),
         )
       : undefined;
+  // Tool subset the parent passed to sessions_spawn; applied on top of the subagent policy.
+  const spawnTools =
+    subagentPolicy && options?.sessionKey
+      ? getSubagentSpawnToolsFromSessionStore(options.sessionKey, { cfg: options.config })
+      : undefined;
+  const spawnPolicy = spawnTools ? { allow: spawnTools } : undefined;
   const allowBackground = isToolAllowedByPolicies("process", [
     profilePolicyWithAlsoAllow,
     providerProfilePolicyWithAlsoAllow,
@@ -286,6 +312,7 @@ export function createOpenClawCodingTools(options?: {
     groupPolicy,
     sandbox?.tools,
     subagentPolicy,
+    spawnPolicy,
   ]);
   const execConfig = resolveExecConfig({ cfg: options?.config, agentId });
   const fsConfig = resolveFsConfig({ cfg: options?.config, agentId });
@@ -411,6 +438,8 @@ export function createOpenClawCodingTools(options?: {
               : undefined,
           workspaceOnly: applyPatchWorkspaceOnly,
         });
+  // Filled once the final tools are wrapped below; undo_last only reads it at call time.
+  let hookedTools: AnyAgentTool[] = [];
   const tools: AnyAgentTool[] = [
     ...base,
     ...(sandboxRoot
@@ -443,6 +472,8 @@ export function createOpenClawCodingTools(options?: {
     // Channel docking: include channel-defined agent tools (login, etc.).
     ...listChannelAgentTools({ cfg: options?.config }),
     ...createOpenClawTools({
+      // Undo replays use this run's wrapped tools: same hooks, guardrails, and call budget.
+      resolveUndoTool: (name) => hookedTools.find((tool) => tool.name === name),
       sandboxBrowserBridgeUrl: sandbox?.browser?.bridgeUrl,
       allowHostBrowserControl: sandbox ? sandbox.browserAllowHostControl : true,
       agentSessionKey: options?.sessionKey,
@@ -469,6 +500,7 @@ export function createOpenClawCodingTools(options?: {
         groupPolicy,
         sandbox?.tools,
         subagentPolicy,
+        spawnPolicy,
       ]),
       currentChannelId: options?.currentChannelId,
       currentThreadTs: options?.currentThreadTs,
@@ -504,6 +536,7 @@ export function createOpenClawCodingTools(options?: {
       }),
       { policy: sandbox?.tools, label: "sandbox tools.allow" },
       { policy: subagentPolicy, label: "subagent tools.allow" },
+      { policy: spawnPolicy, label: "sessions_spawn tools" },
     ],
   });
   // Always normalize tool JSON Schemas before handing them to pi-agent/pi-ai.
@@ -512,19 +545,35 @@ export function createOpenClawCodingTools(options?: {
   const normalized = subagentFiltered.map((tool) =>
     normalizeToolParameters(tool, { modelProvider: options?.modelProvider }),
   );
-  const withHooks = normalized.map((tool) =>
+  const maxCallsPerRun = resolveToolCallLimit({ cfg: options?.config, agentId });
+  const callBudget = maxCallsPerRun ? { limit: maxCallsPerRun, used: 0 } : undefined;
+  const guardrails = resolveToolGuardrails({ cfg: options?.config, agentId });
+  const autonomy = resolveToolAutonomy({
+    cfg: options?.config,
+    agentId,
+    sessionKey: options?.sessionKey,
+  });
+  hookedTools = normalized.map((tool) =>
     wrapToolWithBeforeToolCallHook(tool, {
       agentId,
       sessionKey: options?.sessionKey,
       loopDetection: resolveToolLoopDetectionConfig({ cfg: options?.config, agentId }),
+      callBudget,
+      autonomy,
+      guardrails,
     }),
   );
   const withAbort = options?.abortSignal
-    ? withHooks.map((tool) => wrapToolWithAbortSignal(tool, options.abortSignal))
-    : withHooks;
+    ? hookedTools.map((tool) => wrapToolWithAbortSignal(tool, options.abortSignal))
+    : hookedTools;
+  // Images in tool results only reach models that accept image input.
+  const withVision =
+    options?.modelHasVision === false
+      ? withAbort.map((tool) => wrapToolWithoutImageResults(tool))
+      : withAbort;
 
   // NOTE: Keep canonical (lowercase) tool names here.
   // pi-ai's Anthropic OAuth transport remaps tool names to Claude Code-style names
   // on the wire and maps them back for tool dispatch.
-  return withAbort;
+  return withVision;
 }
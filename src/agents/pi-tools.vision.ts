@@ -0,0 +1,17 @@
+import type { AnyAgentTool } from "./pi-tools.types.js";
+import { omitToolResultImages } from "./tool-images.js";
+
+/** Strip image blocks from tool results when the session model has no vision input. */
+export function wrapToolWithoutImageResults(tool: AnyAgentTool): AnyAgentTool {
+  const execute = tool.execute;
+  if (!execute) {
+    return tool;
+  }
+  return {
+    ...tool,
+    execute: async (toolCallId, params, signal, onUpdate) => {
+      const result = await execute(toolCallId, params, signal, onUpdate);
+      return omitToolResultImages(result, tool.name);
+    },
+  };
+}
@@ -0,0 +1,87 @@
+import { describe, expect, it } from "vitest";
+import {
+  buildReflectionPrompt,
+  formatPlanMessage,
+  parsePlan,
+  parsePlanDecision,
+  planNeedsApproval,
+  resolvePlanningConfig,
+} from "./planner.js";
+
+const config = resolvePlanningConfig({ agents: { defaults: { planning: { enabled: true } } } })!;
+
+describe("planner", () => {
+  it("parses JSON plans, including fenced replies", () => {
+    const plan = parsePlan(
+      '```json\n{"steps":[{"description":"Read the current firmware version","tools":["nodes"]},' +
+        '{"description":"Flash v2.1","tools":["exec"],"highImpact":true},"Report back",{"x":1}]}\n```',
+    );
+    expect(plan).toEqual({
+      steps: [
+        { description: "Read the current firmware version", tools: ["nodes"] },
+        { description: "Flash v2.1", tools: ["exec"], highImpact: true },
+        { description: "Report back" },
+      ],
+    });
+    expect(parsePlan('{"steps":[]}')).toEqual({ steps: [] });
+  });
+
+  it("falls back to numbered lists", () => {
+    expect(parsePlan("Plan:\n1. Check the log\n2) Restart the service\nDone")).toEqual({
+      steps: [{ description: "Check the log" }, { description: "Restart the service" }],
+    });
+    expect(parsePlan("no plan here")).toBeNull();
+  });
+
+  it("requires approval for high-impact steps or tools", () => {
+    const readOnly = { steps: [{ description: "Read the sensor", tools: ["read"] }] };
+    const flashing = { steps: [{ description: "Flash the board", tools: ["exec"] }] };
+    const flagged = { steps: [{ description: "Email the team", highImpact: true }] };
+
+    expect(planNeedsApproval(readOnly, config)).toBe(false);
+    expect(planNeedsApproval(flashing, config)).toBe(true);
+    expect(planNeedsApproval(flagged, config)).toBe(true);
+    expect(planNeedsApproval(readOnly, { ...config, approval: "always" })).toBe(true);
+    expect(planNeedsApproval(flashing, { ...config, approval: "off" })).toBe(false);
+    expect(formatPlanMessage(flashing, { config, needsApproval: true })).toBe(
+      '🗒️ Plan:\n1. Flash the board (exec) ⚠️\n\nReply "approve" to run this plan, "cancel" to drop it, or say what to change.',
+    );
+  });
+
+  it("reads approve and cancel replies", () => {
+    expect(parsePlanDecision("Approve")).toBe("approve");
+    expect(parsePlanDecision("go ahead!")).toBe("approve");
+    expect(parsePlanDecision("/cancel")).toBe("cancel");
+    expect(parsePlanDecision("use v2.0 instead")).toBeUndefined();
+  });
+
+  it("feeds tool failures back for reflection", () => {
+    const prompt = buildReflectionPrompt({
+      plan: { steps: [{ description: "Read the sensor" }, { description: "Flash v2.1" }] },
+      failure: { toolName: "exec", meta: "esptool write_flash", error: "port busy" },
+      attempt: 1,
+      maxAttempts: 2,
+    });
+    expect(prompt).toContain("[Reflection 1/2] The plan did not finish");
+    expect(prompt).toContain("Tool: exec\nCall: esptool write_flash\nError: port busy");
+    expect(prompt).toContain("1. Read the sensor\n2. Flash v2.1");
+  });
+
+  it("lets agents override the defaults", () => {
+    const cfg = {
+      agents: {
+        defaults: { planning: { enabled: true, approval: "always" as const } },
+        list: [{ id: "lab", planning: { enabled: false } }, { id: "bench" }],
+      },
+    };
+    expect(resolvePlanningConfig(cfg, "lab")).toBeUndefined();
+    expect(resolvePlanningConfig(cfg, "bench")).toMatchObject({
+      approval: "always",
+      highImpactTools: ["exec", "process", "gateway", "nodes"],
+      showPlan: true,
+      timeoutMs: 60_000,
+      maxReflections: 1,
+    });
+    expect(resolvePlanningConfig({})).toBeUndefined();
+  });
+});
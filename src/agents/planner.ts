@@ -0,0 +1,247 @@
+import { completeSimple, type TextContent } from "@mariozechner/pi-ai";
+import type { OpenClawConfig } from "../config/config.js";
+import type { AgentPlanningConfig } from "../config/types.agents-shared.js";
+import { resolveAgentConfig } from "./agent-scope.js";
+import { getApiKeyForModel, requireApiKey } from "./model-auth.js";
+import { buildModelAliasIndex, resolveModelRefFromString } from "./model-selection.js";
+import { resolveModel } from "./pi-embedded-runner/model.js";
+import { isToolAllowedByPolicyName } from "./pi-tools.policy.js";
+
+export type PlanStep = {
+  description: string;
+  tools?: string[];
+  highImpact?: boolean;
+};
+
+export type AgentPlan = { steps: PlanStep[] };
+
+export type ResolvedPlanningConfig = {
+  model?: string;
+  approval: NonNullable<AgentPlanningConfig["approval"]>;
+  highImpactTools: string[];
+  showPlan: boolean;
+  timeoutMs: number;
+  maxReflections: number;
+};
+
+const DEFAULT_HIGH_IMPACT_TOOLS = ["exec", "process", "gateway", "nodes"];
+const DEFAULT_TIMEOUT_SECONDS = 60;
+const DEFAULT_MAX_REFLECTIONS = 1;
+const MAX_PLAN_STEPS = 12;
+
+const APPROVE_RE = /^(approved?|yes|y|ok(ay)?|go( ahead)?|proceed|run it|do it|lgtm)[.!]*$/i;
+const CANCEL_RE = /^(cancel|no|n|stop|abort|reject|never ?mind)[.!]*$/i;
+
+const PLANNER_INSTRUCTIONS = [
+  "You plan work for an assistant agent that can call tools (shell, files, devices, messaging, web).",
+  "Break the user's request into a short ordered plan the agent will carry out. Do not do the work yourself.",
+  'Reply with JSON only: {"steps":[{"description":"...","tools":["tool_name"],"highImpact":false}]}.',
+  "Set highImpact to true for steps that are hard to undo or touch hardware, money, other people, or data (firmware updates, flashing, deleting, sending messages, payments).",
+  'If the request needs no tools (a plain question or chat), reply {"steps":[]}.',
+].join("\n");
+
+/** Agent entry overrides `agents.defaults.planning`; undefined when planning is off. */
+export function resolvePlanningConfig(
+  cfg: OpenClawConfig,
+  agentId?: string,
+): ResolvedPlanningConfig | undefined {
+  const merged: AgentPlanningConfig = {
+    ...cfg.agents?.defaults?.planning,
+    ...(agentId ? resolveAgentConfig(cfg, agentId)?.planning : undefined),
+  };
+  if (merged.enabled !== true) {
+    return undefined;
+  }
+  return {
+    model: merged.model?.trim() || undefined,
+    approval: merged.approval ?? "high-impact",
+    highImpactTools: merged.highImpactTools ?? DEFAULT_HIGH_IMPACT_TOOLS,
+    showPlan: merged.showPlan !== false,
+    timeoutMs: (merged.timeoutSeconds ?? DEFAULT_TIMEOUT_SECONDS) * 1000,
+    maxReflections: merged.maxReflections ?? DEFAULT_MAX_REFLECTIONS,
+  };
+}
+
+function normalizeStep(raw: unknown): PlanStep | null {
+  if (typeof raw === "string") {
+    return raw.trim() ? { description: raw.trim() } : null;
+  }
+  if (!raw || typeof raw !== "object") {
+    return null;
+  }
+  const candidate = raw as { description?: unknown; tools?: unknown; highImpact?: unknown };
+  const description = typeof candidate.description === "string" ? candidate.description.trim() : "";
+  if (!description) {
+    return null;
+  }
+  const tools = Array.isArray(candidate.tools)
+    ? candidate.tools.filter((tool): tool is string => typeof tool === "string" && !!tool.trim())
+    : [];
+  return {
+    description,
+    ...(tools.length > 0 ? { tools } : {}),
+    ...(candidate.highImpact === true ? { highImpact: true } : {}),
+  };
+}
+
+/** Parses the planner's JSON reply; falls back to a numbered list. Null when nothing usable. */
+export function parsePlan(text: string): AgentPlan | null {
+  const start = text.indexOf("{");
+  const end = text.lastIndexOf("}");
+  if (start >= 0 && end > start) {
+    try {
+      const parsed = JSON.parse(text.slice(start, end + 1)) as { steps?: unknown };
+      if (Array.isArray(parsed.steps)) {
+        const steps = parsed.steps.map(normalizeStep).filter((step) => step !== null);
+        return { steps: steps.slice(0, MAX_PLAN_STEPS) };
+      }
+    } catch {
+      // fall through to the numbered-list fallback
+    }
+  }
+  const steps = text
+    .split("\n")
+    .map((line) => /^\s*\d+[.)]\s+(.+)$/.exec(line)?.[1]?.trim())
+    .filter((line): line is string => Boolean(line))
+    .map((description) => ({ description }));
+  return steps.length > 0 ? { steps: steps.slice(0, MAX_PLAN_STEPS) } : null;
+}
+
+export function isHighImpactStep(step: PlanStep, config: ResolvedPlanningConfig): boolean {
+  return (
+    step.highImpact === true ||
+    (step.tools ?? []).some((tool) =>
+      isToolAllowedByPolicyName(tool, { allow: config.highImpactTools }),
+    )
+  );
+}
+
+export function planNeedsApproval(plan: AgentPlan, config: ResolvedPlanningConfig): boolean {
+  if (config.approval === "off" || plan.steps.length === 0) {
+    return false;
+  }
+  return config.approval === "always" || plan.steps.some((step) => isHighImpactStep(step, config));
+}
+
+export function formatPlanMessage(
+  plan: AgentPlan,
+  params: { config: ResolvedPlanningConfig; needsApproval: boolean },
+): string {
+  const lines = ["🗒️ Plan:"];
+  plan.steps.forEach((step, index) => {
+    const marker = isHighImpactStep(step, params.config) ? " ⚠️" : "";
+    const tools = step.tools?.length ? ` (${step.tools.join(", ")})` : "";
+    lines.push(`${index + 1}. ${step.description}${tools}${marker}`);
+  });
+  if (params.needsApproval) {
+    lines.push("", 'Reply "approve" to run this plan, "cancel" to drop it, or say what to change.');
+  }
+  return lines.join("\n");
+}
+
+/** Extra system prompt for the executor run. */
+export function buildExecutorPlanPrompt(plan: AgentPlan, approved: boolean): string {
+  return [
+    "## Execution Plan",
+    approved
+      ? "The user approved this plan. Carry it out step by step with your tools."
+      : "Carry out this plan step by step with your tools.",
+    "If a step fails or turns out to be wrong, stop and report instead of improvising high-impact actions.",
+    ...plan.steps.map((step, index) => `${index + 1}. ${step.description}`),
+  ].join("\n");
+}
+
+/** Follow-up prompt that feeds a failed plan run back to the agent for another attempt. */
+export function buildReflectionPrompt(params: {
+  plan: AgentPlan;
+  failure: { toolName: string; meta?: string; error?: string };
+  attempt: number;
+  maxAttempts: number;
+}): string {
+  const { failure } = params;
+  const trace = [
+    `Tool: ${failure.toolName}`,
+    failure.meta ? `Call: ${failure.meta}` : undefined,
+    `Error: ${failure.error?.trim() || "(no error message)"}`,
+  ].filter(Boolean);
+  return [
+    `[Reflection ${params.attempt}/${params.maxAttempts}] The plan did not finish: a tool call failed.`,
+    ...trace,
+    "",
+    "Plan:",
+    ...params.plan.steps.map((step, index) => `${index + 1}. ${step.description}`),
+    "",
+    "Work out why it failed, adjust the remaining steps, and try again.",
+    "Do not repeat the same failing call unchanged. If the failure cannot be fixed, explain it to the user and stop.",
+  ].join("\n");
+}
+
+export function parsePlanDecision(text: string): "approve" | "cancel" | undefined {
+  const normalized = text.trim().replace(/^\//, "");
+  if (APPROVE_RE.test(normalized)) {
+    return "approve";
+  }
+  if (CANCEL_RE.test(normalized)) {
+    return "cancel";
+  }
+  return undefined;
+}
+
+function isTextContentBlock(block: { type: string }): block is TextContent {
+  return block.type === "text";
+}
+
+/** One-shot planner call; the executor model is used when `planning.model` is unset. */
+export async function requestPlan(params: {
+  cfg: OpenClawConfig;
+  config: ResolvedPlanningConfig;
+  prompt: string;
+  provider: string;
+  model: string;
+  agentDir?: string;
+}): Promise<AgentPlan | null> {
+  let ref = { provider: params.provider, model: params.model };
+  if (params.config.model) {
+    const aliasIndex = buildModelAliasIndex({ cfg: params.cfg, defaultProvider: params.provider });
+    const override = resolveModelRefFromString({
+      raw: params.config.model,
+      defaultProvider: params.provider,
+      aliasIndex,
+    });
+    if (override) {
+      ref = override.ref;
+    }
+  }
+  const resolved = resolveModel(ref.provider, ref.model, params.agentDir, params.cfg);
+  if (!resolved.model) {
+    throw new Error(resolved.error ?? `Unknown planner model: ${ref.provider}/${ref.model}`);
+  }
+  const apiKey = requireApiKey(
+    await getApiKeyForModel({ model: resolved.model, cfg: params.cfg }),
+    ref.provider,
+  );
+  const controller = new AbortController();
+  const timeout = setTimeout(() => controller.abort(), params.config.timeoutMs);
+  try {
+    const res = await completeSimple(
+      resolved.model,
+      {
+        messages: [
+          {
+            role: "user",
+            content: `${PLANNER_INSTRUCTIONS}\n\n<request>\n${params.prompt}\n</request>`,
+            timestamp: Date.now(),
+          },
+        ],
+      },
+      { apiKey, maxTokens: 1500, temperature: 0.2, signal: controller.signal },
+    );
+    const text = res.content
+      .filter(isTextContentBlock)
+      .map((block) => block.text)
+      .join("\n");
+    return parsePlan(text);
+  } finally {
+    clearTimeout(timeout);
+  }
+}
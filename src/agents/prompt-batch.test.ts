@@ -0,0 +1,101 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { pollPromptBatch, resolvePromptBatchClient, submitPromptBatch } from "./prompt-batch.js";
+
+type Call = { url: string; init?: RequestInit };
+
+function stubFetch(responses: Array<unknown>) {
+  const calls: Call[] = [];
+  const fetchMock = vi.fn(async (url: string, init?: RequestInit) => {
+    calls.push({ url, init });
+    const next = responses.shift();
+    const body = typeof next === "string" ? next : JSON.stringify(next);
+    return new Response(body, { status: 200 });
+  });
+  vi.stubGlobal("fetch", fetchMock);
+  return calls;
+}
+
+describe("prompt batches", () => {
+  afterEach(() => {
+    vi.unstubAllGlobals();
+  });
+
+  it("submits and reads OpenAI chat completion batches", async () => {
+    const client = resolvePromptBatchClient({ provider: "openai", apiKey: "sk-test" });
+    const calls = stubFetch([
+      { id: "file-1" },
+      { id: "batch_1", status: "validating" },
+      { id: "batch_1", status: "completed", output_file_id: "file-out" },
+      JSON.stringify({
+        custom_id: "job-1",
+        response: {
+          status_code: 200,
+          body: {
+            choices: [{ message: { content: "Daily summary" } }],
+            usage: { prompt_tokens: 1200, completion_tokens: 80 },
+          },
+        },
+      }),
+    ]);
+
+    const batchId = await submitPromptBatch(client, [
+      { customId: "job-1", model: "gpt-5-mini", prompt: "Summarize" },
+    ]);
+    expect(batchId).toBe("batch_1");
+    expect(calls[0]?.url).toBe("https://api.openai.com/v1/files");
+    expect(JSON.parse(String(calls[1]?.init?.body))).toMatchObject({
+      input_file_id: "file-1",
+      endpoint: "/v1/chat/completions",
+      completion_window: "24h",
+    });
+
+    await expect(pollPromptBatch(client, batchId)).resolves.toEqual({
+      state: "completed",
+      results: [
+        { customId: "job-1", text: "Daily summary", usage: { input: 1200, output: 80 } },
+      ],
+    });
+    expect(calls[3]?.url).toBe("https://api.openai.com/v1/files/file-out/content");
+  });
+
+  it("submits and reads Anthropic message batches", async () => {
+    const client = resolvePromptBatchClient({ provider: "anthropic", apiKey: "sk-ant" });
+    const calls = stubFetch([
+      { id: "msgbatch_1", processing_status: "in_progress" },
+      { processing_status: "in_progress" },
+      { processing_status: "ended", results_url: "https://api.anthropic.com/results/1" },
+      [
+        JSON.stringify({
+          custom_id: "job-1",
+          result: {
+            type: "succeeded",
+            message: {
+              content: [{ type: "text", text: "All sensors nominal." }],
+              usage: { input_tokens: 900, output_tokens: 12 },
+            },
+          },
+        }),
+        JSON.stringify({ custom_id: "job-2", result: { type: "expired" } }),
+      ].join("\n"),
+    ]);
+
+    const batchId = await submitPromptBatch(client, [
+      { customId: "job-1", model: "claude-sonnet-4-5", prompt: "Summarize" },
+    ]);
+    const headers = calls[0]?.init?.headers as Record<string, string>;
+    expect(headers["x-api-key"]).toBe("sk-ant");
+    expect(JSON.parse(String(calls[0]?.init?.body)).requests[0].params.max_tokens).toBe(4096);
+
+    await expect(pollPromptBatch(client, batchId)).resolves.toEqual({
+      state: "pending",
+      status: "in_progress",
+    });
+    await expect(pollPromptBatch(client, batchId)).resolves.toEqual({
+      state: "completed",
+      results: [
+        { customId: "job-1", text: "All sensors nominal.", usage: { input: 900, output: 12 } },
+        { customId: "job-2", error: "request expired" },
+      ],
+    });
+  });
+});
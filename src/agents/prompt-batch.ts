@@ -0,0 +1,319 @@
+import type { OpenClawConfig } from "../config/config.js";
+
+/**
+ * Provider batch APIs for one-shot prompts (OpenAI `/v1/batches`, Anthropic Message Batches).
+ * Requests complete asynchronously (usually within minutes, at most 24h) and are billed at
+ * roughly half the synchronous price.
+ */
+export type PromptBatchProvider = "openai" | "anthropic";
+
+export type PromptBatchClient = {
+  provider: PromptBatchProvider;
+  baseUrl: string;
+  apiKey: string;
+  headers?: Record<string, string>;
+};
+
+export type PromptBatchRequest = {
+  customId: string;
+  model: string;
+  prompt: string;
+  system?: string;
+  maxTokens?: number;
+};
+
+export type PromptBatchResult = {
+  customId: string;
+  text?: string;
+  error?: string;
+  usage?: { input: number; output: number };
+};
+
+export type PromptBatchStatus =
+  | { state: "pending"; status: string }
+  | { state: "completed"; results: PromptBatchResult[] }
+  | { state: "failed"; error: string };
+
+const DEFAULT_BASE_URLS: Record<PromptBatchProvider, string> = {
+  openai: "https://api.openai.com/v1",
+  anthropic: "https://api.anthropic.com",
+};
+const ANTHROPIC_VERSION = "2023-06-01";
+// Anthropic requires max_tokens on every request.
+const DEFAULT_MAX_TOKENS = 4096;
+const OPENAI_FAILED_STATES = new Set(["failed", "expired", "cancelled", "canceled"]);
+
+export function supportsPromptBatch(provider: string): provider is PromptBatchProvider {
+  return provider === "openai" || provider === "anthropic";
+}
+
+export function resolvePromptBatchClient(params: {
+  provider: PromptBatchProvider;
+  apiKey: string;
+  cfg?: OpenClawConfig;
+}): PromptBatchClient {
+  const providerConfig = params.cfg?.models?.providers?.[params.provider];
+  const baseUrl = (providerConfig?.baseUrl?.trim() || DEFAULT_BASE_URLS[params.provider]).replace(
+    /\/+$/,
+    "",
+  );
+  return {
+    provider: params.provider,
+    baseUrl,
+    apiKey: params.apiKey,
+    headers: providerConfig?.headers,
+  };
+}
+
+function buildHeaders(client: PromptBatchClient, json = true): Record<string, string> {
+  const auth: Record<string, string> =
+    client.provider === "anthropic"
+      ? { "x-api-key": client.apiKey, "anthropic-version": ANTHROPIC_VERSION }
+      : { Authorization: `Bearer ${client.apiKey}` };
+  return {
+    ...(json ? { "Content-Type": "application/json" } : {}),
+    ...client.headers,
+    ...auth,
+  };
+}
+
+async function requestBatchApi<T>(
+  url: string,
+  init: RequestInit & { parse: "json" | "text" },
+  errorPrefix: string,
+): Promise<T> {
+  const { parse, ...rest } = init;
+  const res = await fetch(url, rest);
+  if (!res.ok) {
+    const detail = await res.text().catch(() => "");
+    throw new Error(`${errorPrefix}: ${res.status} ${detail}`.trim());
+  }
+  return (parse === "json" ? await res.json() : await res.text()) as T;
+}
+
+function parseJsonl(text: string): unknown[] {
+  return text
+    .split("\n")
+    .map((line) => line.trim())
+    .filter(Boolean)
+    .flatMap((line) => {
+      try {
+        return [JSON.parse(line) as unknown];
+      } catch {
+        return [];
+      }
+    });
+}
+
+/** Submit prompts as one provider batch. Returns the provider batch id. */
+export async function submitPromptBatch(
+  client: PromptBatchClient,
+  requests: PromptBatchRequest[],
+  metadata?: Record<string, string>,
+): Promise<string> {
+  if (requests.length === 0) {
+    throw new Error("prompt batch requires at least one request");
+  }
+  if (client.provider === "anthropic") {
+    const created = await requestBatchApi<{ id?: string }>(
+      `${client.baseUrl}/v1/messages/batches`,
+      {
+        method: "POST",
+        headers: buildHeaders(client),
+        body: JSON.stringify({
+          requests: requests.map((request) => ({
+            custom_id: request.customId,
+            params: {
+              model: request.model,
+              max_tokens: request.maxTokens ?? DEFAULT_MAX_TOKENS,
+              ...(request.system ? { system: request.system } : {}),
+              messages: [{ role: "user", content: request.prompt }],
+            },
+          })),
+        }),
+        parse: "json",
+      },
+      "anthropic batch create failed",
+    );
+    if (!created.id) {
+      throw new Error("anthropic batch create failed: missing batch id");
+    }
+    return created.id;
+  }
+
+  const jsonl = requests
+    .map((request) =>
+      JSON.stringify({
+        custom_id: request.customId,
+        method: "POST",
+        url: "/v1/chat/completions",
+        body: {
+          model: request.model,
+          messages: [
+            ...(request.system ? [{ role: "system", content: request.system }] : []),
+            { role: "user", content: request.prompt },
+          ],
+          ...(request.maxTokens ? { max_completion_tokens: request.maxTokens } : {}),
+        },
+      }),
+    )
+    .join("\n");
+  const form = new FormData();
+  form.append("purpose", "batch");
+  form.append("file", new Blob([jsonl], { type: "application/jsonl" }), "openclaw-batch.jsonl");
+  const file = await requestBatchApi<{ id?: string }>(
+    `${client.baseUrl}/files`,
+    { method: "POST", headers: buildHeaders(client, false), body: form, parse: "json" },
+    "openai batch file upload failed",
+  );
+  if (!file.id) {
+    throw new Error("openai batch file upload failed: missing file id");
+  }
+  const created = await requestBatchApi<{ id?: string }>(
+    `${client.baseUrl}/batches`,
+    {
+      method: "POST",
+      headers: buildHeaders(client),
+      body: JSON.stringify({
+        input_file_id: file.id,
+        endpoint: "/v1/chat/completions",
+        completion_window: "24h",
+        ...(metadata ? { metadata } : {}),
+      }),
+      parse: "json",
+    },
+    "openai batch create failed",
+  );
+  if (!created.id) {
+    throw new Error("openai batch create failed: missing batch id");
+  }
+  return created.id;
+}
+
+type OpenAiBatchLine = {
+  custom_id?: string;
+  response?: {
+    status_code?: number;
+    body?: {
+      choices?: Array<{ message?: { content?: string | null } }>;
+      usage?: { prompt_tokens?: number; completion_tokens?: number };
+      error?: { message?: string };
+    };
+  };
+  error?: { message?: string } | null;
+};
+
+type AnthropicBatchLine = {
+  custom_id?: string;
+  result?: {
+    type?: string;
+    message?: {
+      content?: Array<{ type?: string; text?: string }>;
+      usage?: { input_tokens?: number; output_tokens?: number };
+    };
+    error?: { error?: { message?: string }; message?: string };
+  };
+};
+
+function parseOpenAiResultLine(line: OpenAiBatchLine): PromptBatchResult {
+  const customId = line.custom_id ?? "";
+  const body = line.response?.body;
+  if (line.error?.message || (line.response?.status_code ?? 200) >= 400) {
+    return {
+      customId,
+      error: line.error?.message ?? body?.error?.message ?? `HTTP ${line.response?.status_code}`,
+    };
+  }
+  return {
+    customId,
+    text: body?.choices?.[0]?.message?.content ?? "",
+    usage: {
+      input: body?.usage?.prompt_tokens ?? 0,
+      output: body?.usage?.completion_tokens ?? 0,
+    },
+  };
+}
+
+function parseAnthropicResultLine(line: AnthropicBatchLine): PromptBatchResult {
+  const customId = line.custom_id ?? "";
+  const result = line.result;
+  if (result?.type !== "succeeded") {
+    const message = result?.error?.error?.message ?? result?.error?.message;
+    return { customId, error: message ?? `request ${result?.type ?? "failed"}` };
+  }
+  const text = (result.message?.content ?? [])
+    .filter((block) => block.type === "text" && typeof block.text === "string")
+    .map((block) => block.text)
+    .join("");
+  return {
+    customId,
+    text,
+    usage: {
+      input: result.message?.usage?.input_tokens ?? 0,
+      output: result.message?.usage?.output_tokens ?? 0,
+    },
+  };
+}
+
+/** Check a batch once; fetches and parses the results when the batch has finished. */
+export async function pollPromptBatch(
+  client: PromptBatchClient,
+  batchId: string,
+): Promise<PromptBatchStatus> {
+  if (client.provider === "anthropic") {
+    const batch = await requestBatchApi<{ processing_status?: string; results_url?: string }>(
+      `${client.baseUrl}/v1/messages/batches/${encodeURIComponent(batchId)}`,
+      { headers: buildHeaders(client), parse: "json" },
+      "anthropic batch status failed",
+    );
+    const status = batch.processing_status ?? "unknown";
+    if (status !== "ended") {
+      return { state: "pending", status };
+    }
+    if (!batch.results_url) {
+      return { state: "failed", error: `anthropic batch ${batchId} ended without results` };
+    }
+    const content = await requestBatchApi<string>(
+      batch.results_url,
+      { headers: buildHeaders(client), parse: "text" },
+      "anthropic batch results failed",
+    );
+    return {
+      state: "completed",
+      results: parseJsonl(content).map((line) =>
+        parseAnthropicResultLine(line as AnthropicBatchLine),
+      ),
+    };
+  }
+
+  const batch = await requestBatchApi<{
+    status?: string;
+    output_file_id?: string | null;
+    errors?: { data?: Array<{ message?: string }> } | null;
+  }>(
+    `${client.baseUrl}/batches/${encodeURIComponent(batchId)}`,
+    { headers: buildHeaders(client), parse: "json" },
+    "openai batch status failed",
+  );
+  const status = batch.status ?? "unknown";
+  if (OPENAI_FAILED_STATES.has(status)) {
+    const detail = batch.errors?.data?.[0]?.message;
+    const suffix = detail ? `: ${detail}` : "";
+    return { state: "failed", error: `openai batch ${batchId} ${status}${suffix}` };
+  }
+  if (status !== "completed") {
+    return { state: "pending", status };
+  }
+  if (!batch.output_file_id) {
+    return { state: "failed", error: `openai batch ${batchId} completed without output file` };
+  }
+  const content = await requestBatchApi<string>(
+    `${client.baseUrl}/files/${encodeURIComponent(batch.output_file_id)}/content`,
+    { headers: buildHeaders(client), parse: "text" },
+    "openai batch file content failed",
+  );
+  return {
+    state: "completed",
+    results: parseJsonl(content).map((line) => parseOpenAiResultLine(line as OpenAiBatchLine)),
+  };
+}
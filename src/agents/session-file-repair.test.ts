@@ -2,7 +2,7 @@ import fs from "node:fs/promises";
 import os from "node:os";
 import path from "node:path";
 import { afterEach, describe, expect, it, vi } from "vitest";
-import { repairSessionFileIfNeeded } from "./session-file-repair.js";
+import { repairSessionFileIfNeeded, syncSessionFile } from "./session-file-repair.js";
 
 function buildSessionHeaderAndMessage() {
   const header = {
@@ -98,3 +98,15 @@ describe("repairSessionFileIfNeeded", () => {
     expect(warn).toHaveBeenCalledTimes(1);
   });
 });
+
+describe("syncSessionFile", () => {
+  it("fsyncs an existing transcript and skips a missing one", async () => {
+    const { file } = await createTempSessionPath();
+    const { header, message } = buildSessionHeaderAndMessage();
+    await fs.writeFile(file, `${JSON.stringify(header)}\n${JSON.stringify(message)}\n`, "utf-8");
+
+    await expect(syncSessionFile(file)).resolves.toBe(true);
+    await expect(syncSessionFile(`${file}.missing`)).resolves.toBe(false);
+    await expect(fs.readFile(file, "utf-8")).resolves.toContain('"msg-1"');
+  });
+});
@@ -107,3 +107,21 @@ export async function repairSessionFileIfNeeded(params: {
   );
   return { repaired: true, droppedLines, backupPath };
 }
+
+/**
+ * Flush the transcript to disk at the end of a turn. Appends go through the OS page cache, so
+ * without this a power cut can lose the whole turn even though the session store points at it.
+ * Best-effort: a missing file or a filesystem that refuses fsync is not worth failing a reply.
+ */
+export async function syncSessionFile(sessionFile: string): Promise<boolean> {
+  let handle: fs.FileHandle | undefined;
+  try {
+    handle = await fs.open(sessionFile, "r+");
+    await handle.sync();
+    return true;
+  } catch {
+    return false;
+  } finally {
+    await handle?.close().catch(() => undefined);
+  }
+}
@@ -0,0 +1,62 @@
+import { describe, expect, it, vi } from "vitest";
+import {
+  applyJsonResponseFormat,
+  parseStructuredOutput,
+  resolveJsonResponseFormat,
+  runStructuredOutput,
+} from "./structured-output.js";
+
+const schema = {
+  type: "object",
+  properties: { label: { type: "string" } },
+  required: ["label"],
+};
+
+describe("structured output", () => {
+  it("maps the response format onto OpenAI payloads only", () => {
+    const completions: Record<string, unknown> = {};
+    expect(applyJsonResponseFormat(completions, "openai-completions", { schema })).toBe(true);
+    expect(completions.response_format).toEqual({
+      type: "json_schema",
+      json_schema: { name: "result", schema, strict: false },
+    });
+
+    const responses: Record<string, unknown> = { text: { verbosity: "low" } };
+    applyJsonResponseFormat(responses, "openai-responses", { name: "triage v2", schema });
+    expect(responses.text).toEqual({
+      verbosity: "low",
+      format: { type: "json_schema", name: "triage_v2", schema, strict: false },
+    });
+
+    const anthropic: Record<string, unknown> = {};
+    expect(applyJsonResponseFormat(anthropic, "anthropic-messages", { schema })).toBe(false);
+    expect(anthropic).toEqual({});
+    expect(resolveJsonResponseFormat({ schema: [] })).toBeUndefined();
+  });
+
+  it("parses fenced JSON and reports schema mismatches", () => {
+    expect(parseStructuredOutput('```json\n{"label":"bug"}\n```', schema)).toEqual({
+      ok: true,
+      value: { label: "bug" },
+    });
+    const mismatch = parseStructuredOutput('{"label":1}', schema);
+    expect(mismatch.ok).toBe(false);
+    expect(!mismatch.ok && mismatch.error).toMatch(/did not match schema: label/);
+    expect(parseStructuredOutput("nope").ok).toBe(false);
+  });
+
+  it("asks for one repair and stops there", async () => {
+    const run = vi
+      .fn<(prompt: string, attempt: number) => Promise<string>>()
+      .mockResolvedValueOnce("label: bug")
+      .mockResolvedValueOnce('{"label":"bug"}');
+    const repaired = await runStructuredOutput({ prompt: "classify", schema, run });
+    expect(repaired).toEqual({ ok: true, value: { label: "bug" }, attempts: 2 });
+    expect(run.mock.calls[1]?.[0]).toContain("PREVIOUS_REPLY:\nlabel: bug");
+
+    const failing = vi.fn(async () => "still prose");
+    const failed = await runStructuredOutput({ prompt: "classify", schema, run: failing });
+    expect(failed.ok).toBe(false);
+    expect(failing).toHaveBeenCalledTimes(2);
+  });
+});
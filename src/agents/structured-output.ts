@@ -0,0 +1,134 @@
+import { validateJsonSchemaValue } from "../plugins/schema-validator.js";
+
+/** Schema-constrained JSON output requested from the provider (`streamParams.responseFormat`). */
+export type JsonResponseFormat = {
+  /** Schema name sent to providers that require one. Default: "result". */
+  name?: string;
+  schema: Record<string, unknown>;
+};
+
+export type StructuredOutputResult =
+  | { ok: true; value: unknown }
+  | { ok: false; error: string; raw: string };
+
+const OPENAI_RESPONSES_APIS = new Set([
+  "openai-responses",
+  "openai-codex-responses",
+  "azure-openai-responses",
+]);
+
+/** True when `api` accepts a JSON schema natively (see `applyJsonResponseFormat`). */
+export function supportsNativeJsonResponseFormat(api: string | undefined): boolean {
+  return api === "openai-completions" || (api !== undefined && OPENAI_RESPONSES_APIS.has(api));
+}
+
+export function resolveJsonResponseFormat(value: unknown): JsonResponseFormat | undefined {
+  if (!value || typeof value !== "object" || Array.isArray(value)) {
+    return undefined;
+  }
+  const { name, schema } = value as { name?: unknown; schema?: unknown };
+  if (!schema || typeof schema !== "object" || Array.isArray(schema)) {
+    return undefined;
+  }
+  return {
+    ...(typeof name === "string" && name.trim() ? { name: name.trim() } : {}),
+    schema: schema as Record<string, unknown>,
+  };
+}
+
+/**
+ * Add native structured-output fields to an outgoing request payload: `response_format` for
+ * Chat Completions, `text.format` for the Responses API. Other APIs (Anthropic, Gemini) are
+ * left alone and rely on the prompt plus local validation.
+ * Returns true when the payload was changed.
+ */
+export function applyJsonResponseFormat(
+  payload: Record<string, unknown>,
+  api: string | undefined,
+  format: JsonResponseFormat,
+): boolean {
+  const name = (format.name ?? "result").replace(/[^a-zA-Z0-9_-]/g, "_").slice(0, 64);
+  if (api === "openai-completions") {
+    payload.response_format = {
+      type: "json_schema",
+      json_schema: { name, schema: format.schema, strict: false },
+    };
+    return true;
+  }
+  if (api && OPENAI_RESPONSES_APIS.has(api)) {
+    const text =
+      payload.text && typeof payload.text === "object"
+        ? (payload.text as Record<string, unknown>)
+        : {};
+    payload.text = {
+      ...text,
+      format: { type: "json_schema", name, schema: format.schema, strict: false },
+    };
+    return true;
+  }
+  return false;
+}
+
+function stripCodeFences(text: string): string {
+  const trimmed = text.trim();
+  const match = trimmed.match(/^```(?:json)?\s*([\s\S]*?)\s*```$/i);
+  return match ? (match[1] ?? "").trim() : trimmed;
+}
+
+/** Parse model output as JSON (tolerating a Markdown fence) and validate it against `schema`. */
+export function parseStructuredOutput(
+  text: string,
+  schema?: Record<string, unknown>,
+): StructuredOutputResult {
+  const raw = stripCodeFences(text);
+  if (!raw) {
+    return { ok: false, error: "empty output", raw };
+  }
+  let value: unknown;
+  try {
+    value = JSON.parse(raw);
+  } catch (err) {
+    return { ok: false, error: `invalid JSON (${(err as Error).message})`, raw };
+  }
+  if (schema) {
+    const result = validateJsonSchemaValue({
+      schema,
+      cacheKey: `structured-output:${JSON.stringify(schema)}`,
+      value,
+    });
+    if (!result.ok) {
+      return { ok: false, error: `did not match schema: ${result.errors.join("; ")}`, raw };
+    }
+  }
+  return { ok: true, value };
+}
+
+export function buildStructuredOutputRepairPrompt(failure: { error: string; raw: string }): string {
+  return [
+    `Your previous reply was rejected: ${failure.error}.`,
+    "Reply again with ONLY the corrected JSON value. No markdown fences, no commentary.",
+    "",
+    "PREVIOUS_REPLY:",
+    failure.raw.slice(0, 4000),
+  ].join("\n");
+}
+
+/**
+ * Run a JSON-producing prompt, validate the reply, and on failure ask the model once to repair
+ * its output. `run` receives the prompt to send and returns the model's text reply.
+ */
+export async function runStructuredOutput(params: {
+  prompt: string;
+  schema?: Record<string, unknown>;
+  run: (prompt: string, attempt: number) => Promise<string>;
+}): Promise<StructuredOutputResult & { attempts: number }> {
+  const first = parseStructuredOutput(await params.run(params.prompt, 1), params.schema);
+  if (first.ok) {
+    return { ...first, attempts: 1 };
+  }
+  const repaired = parseStructuredOutput(
+    await params.run(buildStructuredOutputRepairPrompt(first), 2),
+    params.schema,
+  );
+  return { ...repaired, attempts: 2 };
+}
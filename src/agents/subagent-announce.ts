@@ -867,6 +867,10 @@ export function buildSubagentSystemPrompt(params: {
   childDepth?: number;
   /** Config value: max allowed spawn depth. */
   maxSpawnDepth?: number;
+  /** Role/instructions from the parent's sessions_spawn `systemPrompt`. */
+  instructions?: string;
+  /** Tool allowlist from the parent's sessions_spawn `tools`. */
+  tools?: string[];
 }) {
   const taskText =
     typeof params.task === "string" && params.task.trim()
@@ -913,6 +917,18 @@ export function buildSubagentSystemPrompt(params: {
     "",
   ];
 
+  const instructions = params.instructions?.trim();
+  if (instructions) {
+    lines.push("## Instructions From Your Requester", instructions, "");
+  }
+  if (params.tools?.length) {
+    lines.push(
+      "## Tools",
+      `Your requester limited you to these tools: ${params.tools.join(", ")}.`,
+      "",
+    );
+  }
+
   if (canSpawn) {
     lines.push(
       "## Sub-Agent Spawning",
@@ -9,6 +9,7 @@ type SessionDepthEntry = {
   sessionId?: unknown;
   spawnDepth?: unknown;
   spawnedBy?: unknown;
+  spawnTools?: unknown;
 };
 
 function normalizeSpawnDepth(value: unknown): number | undefined {
@@ -174,3 +175,28 @@ export function getSubagentDepthFromSessionStore(
 
   return depthFromStore(raw) ?? fallbackDepth;
 }
+
+/** Tool allowlist the parent set with `sessions_spawn({ tools })`, if any. */
+export function getSubagentSpawnToolsFromSessionStore(
+  sessionKey: string | undefined | null,
+  opts?: {
+    cfg?: OpenClawConfig;
+    store?: Record<string, SessionDepthEntry>;
+  },
+): string[] | undefined {
+  const raw = normalizeSessionKey(sessionKey);
+  if (!raw) {
+    return undefined;
+  }
+  const entry = resolveEntryForSessionKey({
+    sessionKey: raw,
+    cfg: opts?.cfg,
+    store: opts?.store,
+    cache: new Map(),
+  });
+  const tools = entry?.spawnTools;
+  if (!Array.isArray(tools)) {
+    return undefined;
+  }
+  return tools.filter((tool): tool is string => typeof tool === "string" && tool.trim() !== "");
+}
@@ -10,7 +10,11 @@ import { resolveAgentConfig } from "./agent-scope.js";
 import { AGENT_LANE_SUBAGENT } from "./lanes.js";
 import { resolveSubagentSpawnModelSelection } from "./model-selection.js";
 import { buildSubagentSystemPrompt } from "./subagent-announce.js";
-import { getSubagentDepthFromSessionStore } from "./subagent-depth.js";
+import { isToolAllowedByPolicyName } from "./pi-tools.policy.js";
+import {
+  getSubagentDepthFromSessionStore,
+  getSubagentSpawnToolsFromSessionStore,
+} from "./subagent-depth.js";
 import { countActiveRunsForSession, registerSubagentRun } from "./subagent-registry.js";
 import { readStringParam } from "./tools/common.js";
 import {
@@ -33,6 +37,10 @@ export type SpawnSubagentParams = {
   mode?: SpawnSubagentMode;
   cleanup?: "delete" | "keep";
   expectsCompletionMessage?: boolean;
+  /** Tool allowlist for the child (narrows, never widens, the subagent tool policy). */
+  tools?: string[];
+  /** Extra instructions appended to the child's subagent system prompt. */
+  systemPrompt?: string;
 };
 
 export type SpawnSubagentContext = {
@@ -284,6 +292,20 @@ export async function spawnSubagentDirect(
     }
     thinkingOverride = normalized;
   }
+  // A restricted parent can only hand down a subset of its own tools.
+  const parentTools = getSubagentSpawnToolsFromSessionStore(requesterInternalKey, { cfg });
+  const requestedTools = params.tools
+    ?.map((tool) => tool.trim())
+    .filter(
+      (tool) => tool && (!parentTools || isToolAllowedByPolicyName(tool, { allow: parentTools })),
+    );
+  const spawnTools = requestedTools ?? parentTools;
+  if (parentTools && requestedTools?.length === 0) {
+    return {
+      status: "error",
+      error: `tools must be a subset of this session's tools (${parentTools.join(", ")})`,
+    };
+  }
   try {
     await callGateway({
       method: "sessions.patch",
@@ -300,6 +322,24 @@ export async function spawnSubagentDirect(
     };
   }
 
+  if (spawnTools) {
+    try {
+      await callGateway({
+        method: "sessions.patch",
+        params: { key: childSessionKey, spawnTools },
+        timeoutMs: 10_000,
+      });
+    } catch (err) {
+      const messageText =
+        err instanceof Error ? err.message : typeof err === "string" ? err : "error";
+      return {
+        status: "error",
+        error: messageText,
+        childSessionKey,
+      };
+    }
+  }
+
   if (resolvedModel) {
     try {
       await callGateway({
@@ -379,6 +419,8 @@ export async function spawnSubagentDirect(
     task,
     childDepth,
     maxSpawnDepth,
+    instructions: params.systemPrompt,
+    tools: spawnTools,
   });
   const childTaskMessage = [
     `[Subagent Context] You are running as a subagent (depth ${childDepth}/${maxSpawnDepth}). Results auto-announce to your requester; do not busy-poll for status.`,
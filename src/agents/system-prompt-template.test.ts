@@ -0,0 +1,53 @@
+import { describe, expect, it } from "vitest";
+import {
+  buildSystemPromptTemplateVars,
+  renderContextFileTemplates,
+  renderSystemPromptTemplate,
+} from "./system-prompt-template.js";
+
+describe("system prompt templating", () => {
+  const vars = buildSystemPromptTemplateVars({
+    runtimeInfo: {
+      agentId: "kitchen",
+      host: "pi5",
+      os: "Linux 6.6.31",
+      arch: "arm64",
+      node: "v22.12.0",
+      model: "openai/gpt-5.2",
+      channel: "telegram",
+    },
+    userTimezone: "America/Chicago",
+    tools: [{ name: "read" }, { name: "exec" }, { name: "nodes" }],
+    now: new Date("2026-03-01T03:30:00Z"),
+  });
+
+  it("builds variables from the runtime", () => {
+    expect(vars).toMatchObject({
+      agentId: "kitchen",
+      host: "pi5",
+      arch: "arm64",
+      timezone: "America/Chicago",
+      // 03:30Z is still the previous evening in Chicago.
+      date: "2026-02-28",
+      tools: "read, exec, nodes",
+    });
+  });
+
+  it("renders known placeholders and leaves unknown ones verbatim", () => {
+    expect(
+      renderSystemPromptTemplate(
+        "You run on {{ host }} ({{arch}}) as {{agentId}}. Tools: {{tools}}. Keep {{user_name}}.",
+        vars,
+      ),
+    ).toBe("You run on pi5 (arm64) as kitchen. Tools: read, exec, nodes. Keep {{user_name}}.");
+    expect(renderSystemPromptTemplate("{{defaultModel}}|", vars)).toBe("|");
+  });
+
+  it("returns unchanged context files as-is", () => {
+    const plain = { path: "SOUL.md", content: "No placeholders here." };
+    const templated = { path: "AGENTS.md", content: "Today is {{date}}." };
+    const [first, second] = renderContextFileTemplates([plain, templated], vars);
+    expect(first).toBe(plain);
+    expect(second).toEqual({ path: "AGENTS.md", content: "Today is 2026-02-28." });
+  });
+});
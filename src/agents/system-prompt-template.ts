@@ -0,0 +1,96 @@
+import type { EmbeddedContextFile } from "./pi-embedded-helpers.js";
+import type { RuntimeInfoInput } from "./system-prompt-params.js";
+
+/** Values available as `{{name}}` placeholders in bootstrap files and `extraSystemPrompt`. */
+export type SystemPromptTemplateVars = {
+  agentId?: string;
+  model?: string;
+  defaultModel?: string;
+  host?: string;
+  os?: string;
+  arch?: string;
+  channel?: string;
+  timezone?: string;
+  /** Current date (YYYY-MM-DD) in the user timezone. Day-granular so the prompt cache survives. */
+  date?: string;
+  /** Comma-separated names of the tools enabled for this run. */
+  tools?: string;
+};
+
+const PLACEHOLDER_RE = /{{\s*(\w+)\s*}}/g;
+
+const TEMPLATE_KEYS = new Set<string>([
+  "agentId",
+  "model",
+  "defaultModel",
+  "host",
+  "os",
+  "arch",
+  "channel",
+  "timezone",
+  "date",
+  "tools",
+]);
+
+function formatDateInTimezone(now: Date, timeZone: string): string | undefined {
+  try {
+    // en-CA formats as YYYY-MM-DD.
+    return new Intl.DateTimeFormat("en-CA", {
+      timeZone,
+      year: "numeric",
+      month: "2-digit",
+      day: "2-digit",
+    }).format(now);
+  } catch {
+    return undefined;
+  }
+}
+
+export function buildSystemPromptTemplateVars(params: {
+  runtimeInfo: RuntimeInfoInput;
+  userTimezone: string;
+  tools?: Array<{ name: string }>;
+  now?: Date;
+}): SystemPromptTemplateVars {
+  const { runtimeInfo } = params;
+  const toolNames = (params.tools ?? []).map((tool) => tool.name).filter(Boolean);
+  return {
+    agentId: runtimeInfo.agentId,
+    model: runtimeInfo.model,
+    defaultModel: runtimeInfo.defaultModel,
+    host: runtimeInfo.host,
+    os: runtimeInfo.os,
+    arch: runtimeInfo.arch,
+    channel: runtimeInfo.channel,
+    timezone: params.userTimezone,
+    date: formatDateInTimezone(params.now ?? new Date(), params.userTimezone),
+    tools: toolNames.length > 0 ? toolNames.join(", ") : undefined,
+  };
+}
+
+/**
+ * Replace known `{{name}}` placeholders. Unknown names are left verbatim so literal braces in
+ * prompts (templates for other tools, code samples) survive; known names without a value
+ * render as an empty string.
+ */
+export function renderSystemPromptTemplate(text: string, vars: SystemPromptTemplateVars): string {
+  if (!text.includes("{{")) {
+    return text;
+  }
+  return text.replace(PLACEHOLDER_RE, (match, key: string) => {
+    if (!TEMPLATE_KEYS.has(key)) {
+      return match;
+    }
+    return vars[key as keyof SystemPromptTemplateVars] ?? "";
+  });
+}
+
+export function renderContextFileTemplates(
+  files: EmbeddedContextFile[],
+  vars: SystemPromptTemplateVars,
+): EmbeddedContextFile[] {
+  return files.map((file) => {
+    const content = renderSystemPromptTemplate(file.content, vars);
+    return content === file.content ? file : { ...file, content };
+  });
+}
@@ -56,6 +56,16 @@ function buildMemorySection(params: {
     "## Memory Recall",
     "Before answering anything about prior work, decisions, dates, people, preferences, or todos: run memory_search on MEMORY.md + memory/*.md; then use memory_get to pull only the needed lines. If low confidence after search, say you checked.",
   ];
+  if (params.availableTools.has("memory_set")) {
+    lines.push(
+      'When the user asks you to remember a fact ("pin 7 drives the pump relay"), save it with memory_set; read it back with memory_get (key) or memory_list.',
+    );
+  }
+  if (params.availableTools.has("kb_search")) {
+    lines.push(
+      "For wiring, pinout, register-map, or spec questions, search ingested datasheets and manuals with kb_search first and cite the document page; do not guess register values.",
+    );
+  }
   if (params.citationsMode === "off") {
     lines.push(
       "Citations are disabled: do not mention file paths or line numbers in replies unless the user explicitly asks.",
@@ -255,6 +265,14 @@ export function buildAgentSystemPrompt(params: {
     canvas: "Present/eval/snapshot the Canvas",
     nodes: "List/describe/notify/camera/screen on paired nodes",
     cron: "Manage cron jobs and wake events (use for reminders; when scheduling a reminder, write the systemEvent text as something that will read like a reminder when it fires, and mention that it is a reminder depending on the time gap between setting and firing; include recent context in reminder text if appropriate)",
+    remind_me:
+      'Remind the user at a natural time ("tomorrow 9am") in their timezone; delivered to this chat',
+    task_add: "Queue a follow-up for yourself (priority, optional due time that wakes you)",
+    task_list: "List your queued follow-ups by priority and due time",
+    task_complete: "Mark a queued follow-up done",
+    goal_set:
+      "Set a standing goal that persists across sessions, or record progress / change its status",
+    goal_status: "Show standing goals with status and latest progress",
     message: "Send messages and channel actions",
     gateway: "Restart, apply config, or run updates on the running OpenClaw process",
     agents_list: "List agent ids allowed for sessions_spawn",
@@ -266,6 +284,7 @@ export function buildAgentSystemPrompt(params: {
     session_status:
       "Show a /status-equivalent status card (usage + time + Reasoning/Verbose/Elevated); use for model-use questions (📊 session_status); optional per-session model override",
     image: "Analyze an image with the configured image model",
+    transcribe: "Transcribe an audio file to text",
   };
 
   const toolOrder = [
@@ -284,6 +303,12 @@ export function buildAgentSystemPrompt(params: {
     "canvas",
     "nodes",
     "cron",
+    "remind_me",
+    "task_add",
+    "task_list",
+    "task_complete",
+    "goal_set",
+    "goal_status",
     "message",
     "gateway",
     "agents_list",
@@ -293,6 +318,7 @@ export function buildAgentSystemPrompt(params: {
     "subagents",
     "session_status",
     "image",
+    "transcribe",
   ];
 
   const rawToolNames = (params.toolNames ?? []).map((tool) => tool.trim());
@@ -0,0 +1,64 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { afterEach, describe, expect, it } from "vitest";
+import {
+  __testing,
+  countMessageTokens,
+  countTokens,
+  estimateTokensHeuristic,
+  forgetMessageTokens,
+  resolveTokenizerEncoding,
+} from "./tokenizer.js";
+
+describe("tokenizer", () => {
+  afterEach(() => {
+    __testing.reset();
+  });
+
+  it("maps OpenAI models to their BPE encoding and approximates others", () => {
+    expect(resolveTokenizerEncoding({ model: "gpt-5.2" })).toEqual({
+      encoding: "o200k_base",
+      exact: true,
+    });
+    expect(resolveTokenizerEncoding({ model: "openai/gpt-4o-mini" }).encoding).toBe("o200k_base");
+    expect(resolveTokenizerEncoding({ model: "gpt-4-turbo" })).toEqual({
+      encoding: "cl100k_base",
+      exact: true,
+    });
+    expect(resolveTokenizerEncoding({ provider: "anthropic", model: "claude-opus-4-6" })).toEqual({
+      encoding: "cl100k_base",
+      exact: false,
+    });
+  });
+
+  it("estimates tokens by script, digits, words, and symbols", () => {
+    expect(estimateTokensHeuristic("")).toBe(0);
+    expect(estimateTokensHeuristic("hello world")).toBe(2);
+    expect(estimateTokensHeuristic("1234567")).toBe(3);
+    expect(estimateTokensHeuristic('{"a":1}')).toBe(7);
+    expect(estimateTokensHeuristic("你好世界")).toBe(4);
+    // Denser than chars/4 for CJK, sparser for plain prose.
+    expect(estimateTokensHeuristic("漢".repeat(400))).toBe(400);
+  });
+
+  it("counts with the heuristic until an encoder is loaded", () => {
+    expect(countTokens("")).toBe(0);
+    expect(countTokens("hello world", { model: "gpt-5.2" })).toBe(2);
+  });
+
+  it("caches message counts until the message is forgotten", () => {
+    const msg = { role: "user", content: "hello world", timestamp: 1 } as AgentMessage;
+    expect(countMessageTokens(msg)).toBe(2);
+
+    (msg as { content: string }).content = "hello brave new world";
+    expect(countMessageTokens(msg)).toBe(2);
+    forgetMessageTokens(msg);
+    expect(countMessageTokens(msg)).toBe(4);
+    expect(
+      countMessageTokens({
+        role: "user",
+        content: [{ type: "image", data: "", mimeType: "image/png" }],
+        timestamp: 1,
+      } as AgentMessage),
+    ).toBe(2_000);
+  });
+});
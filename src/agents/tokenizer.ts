@@ -0,0 +1,237 @@
+import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+
+const log = createSubsystemLogger("tokenizer");
+
+export type TokenizerEncoding = "o200k_base" | "cl100k_base";
+
+type Encoder = { encode: (text: string) => number[] };
+
+const encoders = new Map<TokenizerEncoding, Encoder>();
+const loading = new Map<TokenizerEncoding, Promise<Encoder | null>>();
+
+// gpt-4o, gpt-4.1, gpt-5*, o1/o3/o4 use o200k_base; older OpenAI models use cl100k_base.
+const O200K_MODEL_RE = /^(gpt-4o|gpt-4\.1|gpt-5|chatgpt-4o|o\d)/i;
+const CL100K_MODEL_RE = /^(gpt-4|gpt-3\.5|text-embedding-)/i;
+
+/**
+ * Pick the BPE encoding for a model. Only OpenAI models map exactly; other providers
+ * (Anthropic, Gemini, open-weight models) use cl100k_base as a close approximation.
+ */
+export function resolveTokenizerEncoding(params: {
+  provider?: string;
+  model?: string;
+}): { encoding: TokenizerEncoding; exact: boolean } {
+  const model = params.model?.trim() ?? "";
+  const base = model.slice(model.lastIndexOf("/") + 1);
+  if (O200K_MODEL_RE.test(base)) {
+    return { encoding: "o200k_base", exact: true };
+  }
+  if (CL100K_MODEL_RE.test(base)) {
+    return { encoding: "cl100k_base", exact: true };
+  }
+  return { encoding: "cl100k_base", exact: false };
+}
+
+async function importEncoder(encoding: TokenizerEncoding): Promise<Encoder> {
+  const { Tiktoken } = await import("js-tiktoken/lite");
+  const ranks =
+    encoding === "o200k_base"
+      ? (await import("js-tiktoken/ranks/o200k_base")).default
+      : (await import("js-tiktoken/ranks/cl100k_base")).default;
+  return new Tiktoken(ranks);
+}
+
+/**
+ * Load (once) the BPE ranks for an encoding. Resolves null when js-tiktoken is unavailable;
+ * counts then fall back to the heuristic.
+ */
+export async function loadTokenizer(encoding: TokenizerEncoding): Promise<Encoder | null> {
+  const cached = encoders.get(encoding);
+  if (cached) {
+    return cached;
+  }
+  let pending = loading.get(encoding);
+  if (!pending) {
+    pending = importEncoder(encoding)
+      .then((encoder) => {
+        encoders.set(encoding, encoder);
+        return encoder;
+      })
+      .catch((err) => {
+        log.debug(`tokenizer ${encoding} unavailable; using heuristic: ${String(err)}`);
+        return null;
+      });
+    loading.set(encoding, pending);
+  }
+  return await pending;
+}
+
+const CJK_RE = /[\p{Script=Han}\p{Script=Hiragana}\p{Script=Katakana}\p{Script=Hangul}]/u;
+const SEGMENT_RE =
+  /[\p{Script=Han}\p{Script=Hiragana}\p{Script=Katakana}\p{Script=Hangul}]|\p{N}+|\p{L}+|[^\s\p{L}\p{N}]/gu;
+
+/**
+ * Offline token estimate shaped like BPE output: CJK characters ~1 token each, digits in
+ * groups of three, words ~1 token per 6 letters, and 1 token per punctuation/symbol.
+ * Tracks real tokenizers far closer than chars/4 on code, JSON, and non-Latin text.
+ */
+export function estimateTokensHeuristic(text: string): number {
+  if (!text) {
+    return 0;
+  }
+  let tokens = 0;
+  for (const match of text.matchAll(SEGMENT_RE)) {
+    const segment = match[0];
+    if (CJK_RE.test(segment)) {
+      tokens += 1;
+    } else if (/^\p{N}/u.test(segment)) {
+      tokens += Math.ceil(segment.length / 3);
+    } else if (/^\p{L}/u.test(segment)) {
+      tokens += Math.max(1, Math.ceil(segment.length / 6));
+    } else {
+      tokens += 1;
+    }
+  }
+  return tokens;
+}
+
+/**
+ * Count tokens for `text` as the given model would see it. Synchronous: uses the BPE encoder
+ * when it has been loaded (see `loadTokenizer`) and the heuristic otherwise, starting the
+ * load in the background so later counts are exact.
+ */
+export function countTokens(
+  text: string,
+  params: { provider?: string; model?: string } = {},
+): number {
+  if (!text) {
+    return 0;
+  }
+  const { encoding } = resolveTokenizerEncoding(params);
+  const encoder = encoders.get(encoding);
+  if (encoder) {
+    return encoder.encode(text).length;
+  }
+  void loadTokenizer(encoding);
+  return estimateTokensHeuristic(text);
+}
+
+// Providers bill images by size, not content; ~2k tokens matches a typical screenshot.
+const IMAGE_TOKEN_ESTIMATE = 2_000;
+
+type MessageTokenCount = { key: string; tokens: number };
+
+let messageTokenCache = new WeakMap<object, MessageTokenCount>();
+
+function serializeForTokens(value: unknown): string {
+  if (typeof value === "string") {
+    return value;
+  }
+  if (value === undefined) {
+    return "";
+  }
+  try {
+    return JSON.stringify(value) ?? "";
+  } catch {
+    return "";
+  }
+}
+
+function blockType(block: unknown): unknown {
+  return block && typeof block === "object" ? (block as { type?: unknown }).type : undefined;
+}
+
+/** The text a model reads for one message, plus how many images it carries. */
+function messageTokenText(msg: AgentMessage): { text: string; images: number } {
+  const parts: string[] = [];
+  let images = 0;
+  const addBlock = (block: unknown) => {
+    const type = blockType(block);
+    if (type === "text" && typeof (block as { text?: unknown }).text === "string") {
+      parts.push((block as { text: string }).text);
+    } else if (type === "image") {
+      images += 1;
+    } else {
+      parts.push(serializeForTokens(block));
+    }
+  };
+
+  const role = (msg as { role?: unknown }).role;
+  const content = (msg as { content?: unknown }).content;
+  if (role === "user") {
+    if (typeof content === "string") {
+      parts.push(content);
+    } else if (Array.isArray(content)) {
+      for (const block of content) {
+        addBlock(block);
+      }
+    }
+  } else if (role === "assistant") {
+    for (const block of Array.isArray(content) ? content : []) {
+      if (!block || typeof block !== "object") {
+        continue;
+      }
+      const typed = block as { type?: unknown; thinking?: unknown; arguments?: unknown };
+      if (typed.type === "thinking" && typeof typed.thinking === "string") {
+        parts.push(typed.thinking);
+      } else if (typed.type === "toolCall") {
+        parts.push(serializeForTokens(typed.arguments ?? {}));
+      } else {
+        addBlock(block);
+      }
+    }
+  } else if (role === "toolResult" || role === "tool" || blockType(msg) === "toolResult") {
+    if (typeof content === "string") {
+      parts.push(content);
+    } else if (Array.isArray(content)) {
+      for (const block of content) {
+        addBlock(block);
+      }
+    }
+    parts.push(serializeForTokens((msg as { details?: unknown }).details));
+  } else {
+    // Summaries and custom messages: count what they serialize to.
+    parts.push(serializeForTokens(msg));
+  }
+  return { text: parts.join("\n"), images };
+}
+
+/**
+ * Tokens the model reads for one message (images estimated). Counts are cached per message
+ * object, so re-checking a long history each turn only encodes the new messages; heuristic
+ * counts are redone once the encoder has loaded. Call `forgetMessageTokens` after mutating a
+ * message in place.
+ */
+export function countMessageTokens(
+  msg: AgentMessage,
+  params: { provider?: string; model?: string } = {},
+): number {
+  if (!msg || typeof msg !== "object") {
+    return 0;
+  }
+  const { encoding } = resolveTokenizerEncoding(params);
+  const key = encoders.has(encoding) ? encoding : `${encoding}:heuristic`;
+  const cached = messageTokenCache.get(msg);
+  if (cached?.key === key) {
+    return cached.tokens;
+  }
+  const { text, images } = messageTokenText(msg);
+  const tokens = countTokens(text, params) + images * IMAGE_TOKEN_ESTIMATE;
+  messageTokenCache.set(msg, { key, tokens });
+  return tokens;
+}
+
+export function forgetMessageTokens(msg: AgentMessage): void {
+  if (msg && typeof msg === "object") {
+    messageTokenCache.delete(msg);
+  }
+}
+
+export const __testing = {
+  reset() {
+    encoders.clear();
+    loading.clear();
+    messageTokenCache = new WeakMap();
+  },
+} as const;
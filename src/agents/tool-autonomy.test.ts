@@ -0,0 +1,93 @@
+import { afterEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import {
+  checkToolAutonomy,
+  resetToolAutonomyActionsForTest,
+  resolveToolAutonomy,
+  resolveToolAutonomyAction,
+} from "./tool-autonomy.js";
+
+describe("tool autonomy", () => {
+  afterEach(() => {
+    resetToolAutonomyActionsForTest();
+  });
+
+  it("resolves agent config over global config, defaulting to auto", () => {
+    expect(resolveToolAutonomy({ cfg: {} })).toBeUndefined();
+    const cfg = {
+      tools: { autonomy: { mode: "ask", actionTools: ["mqtt_*"] } },
+      agents: { list: [{ id: "ops", tools: { autonomy: { mode: "observe" } } }] },
+    } as OpenClawConfig;
+    expect(resolveToolAutonomy({ cfg, agentId: "main" })).toEqual({
+      mode: "ask",
+      actionTools: ["mqtt_*"],
+    });
+    expect(resolveToolAutonomy({ cfg, agentId: "ops" })).toEqual({
+      mode: "observe",
+      actionTools: ["mqtt_*"],
+    });
+  });
+
+  it("never gates read-only calls", () => {
+    const autonomy = { mode: "observe" as const };
+    expect(checkToolAutonomy({ autonomy, toolName: "read", toolParams: { path: "a" } })).toBeNull();
+    expect(
+      checkToolAutonomy({ autonomy, toolName: "cron", toolParams: { action: "list" } }),
+    ).toBeNull();
+  });
+
+  it("blocks side-effecting calls in observe mode, including configured action tools", () => {
+    const autonomy = { mode: "observe" as const, actionTools: ["mqtt_*"] };
+    expect(checkToolAutonomy({ autonomy, toolName: "exec", toolParams: {} })).toContain(
+      "observe-only",
+    );
+    expect(checkToolAutonomy({ autonomy, toolName: "mqtt_publish", toolParams: {} })).toContain(
+      "observe-only",
+    );
+  });
+
+  it("treats unclassified plugin tools as side-effecting unless listed as read-only", () => {
+    const autonomy = { mode: "ask" as const, readOnlyTools: ["adc_*"] };
+    expect(checkToolAutonomy({ autonomy, toolName: "relay_set", toolParams: {} })).toContain(
+      "ask-before-acting",
+    );
+    expect(checkToolAutonomy({ autonomy, toolName: "task_add", toolParams: {} })).toContain(
+      "ask-before-acting",
+    );
+    expect(checkToolAutonomy({ autonomy, toolName: "adc_read", toolParams: {} })).toBeNull();
+    expect(checkToolAutonomy({ autonomy, toolName: "task_list", toolParams: {} })).toBeNull();
+  });
+
+  it("grants an approved call once, for the same tool and params only", () => {
+    const autonomy = { mode: "ask" as const };
+    const sessionKey = "agent:main:main";
+    const call = { autonomy, toolName: "write", toolParams: { path: "x" }, sessionKey };
+    const actionId = (reason: string | null) => reason?.match(/\/approve (act-[0-9a-f]+)/)?.[1];
+
+    const id = actionId(checkToolAutonomy(call));
+    expect(id).toBeDefined();
+    expect(actionId(checkToolAutonomy(call))).toBe(id);
+    const approve = (key: string) =>
+      resolveToolAutonomyAction({ id: id!, sessionKey: key, approve: true });
+    expect(approve("agent:main:other")).toBeNull();
+
+    expect(approve(sessionKey)).toBe("write");
+    expect(checkToolAutonomy({ ...call, toolParams: { path: "y" } })).toContain("/approve act-");
+    expect(checkToolAutonomy({ ...call, toolName: "edit" })).toContain("/approve act-");
+    expect(checkToolAutonomy(call)).toBeNull();
+    expect(checkToolAutonomy(call)).toContain("/approve act-");
+    expect(approve(sessionKey)).toBeNull();
+  });
+
+  it("drops declined actions without granting them", () => {
+    const autonomy = { mode: "ask" as const };
+    const call = { autonomy, toolName: "exec", toolParams: { command: "reboot" }, sessionKey: "s" };
+    const id = checkToolAutonomy(call)?.match(/\/approve (act-[0-9a-f]+)/)?.[1];
+
+    expect(resolveToolAutonomyAction({ id: id!, sessionKey: "s", approve: false })).toBe("exec");
+    expect(checkToolAutonomy(call)).toContain("/approve act-");
+    expect(checkToolAutonomy({ ...call, sessionKey: undefined })).toContain(
+      "cannot be approved from here",
+    );
+  });
+});
@@ -0,0 +1,234 @@
+import crypto from "node:crypto";
+import type { OpenClawConfig } from "../config/config.js";
+import { loadSessionStore, resolveStorePath } from "../config/sessions.js";
+import type { ToolAutonomyMode } from "../config/types.tools.js";
+import { parseAgentSessionKey } from "../sessions/session-key-utils.js";
+import { resolveAgentConfig } from "./agent-scope.js";
+import { isToolAllowedByPolicyName } from "./pi-tools.policy.js";
+import { hashToolCall } from "./tool-loop-detection.js";
+import { isLikelyMutatingToolName, isMutatingToolCall } from "./tool-mutation.js";
+
+export const TOOL_AUTONOMY_MODES: readonly ToolAutonomyMode[] = ["observe", "ask", "auto"];
+
+/** Autonomy resolved for one run; checked on every tool call. */
+export type ToolAutonomyState = {
+  mode: ToolAutonomyMode;
+  actionTools?: string[];
+  readOnlyTools?: string[];
+};
+
+/** Which tools count as side-effecting beyond the built-in classification. */
+export type ToolSideEffectOverrides = {
+  actionTools?: string[];
+  readOnlyTools?: string[];
+};
+
+// Built-in tools that never change anything. Tools that are neither here nor classified by
+// tool-mutation (plugin actuators, skills, workflows) are side-effecting unless configured
+// in readOnlyTools.
+const READ_ONLY_TOOL_NAMES = new Set([
+  "read",
+  "ls",
+  "grep",
+  "find",
+  "web_search",
+  "web_fetch",
+  "image",
+  "transcribe",
+  "memory_search",
+  "memory_get",
+  "memory_list",
+  "kb_search",
+  "task_list",
+  "goal_status",
+  "sessions_list",
+  "sessions_history",
+  "agents_list",
+]);
+
+// "ask" mode: a blocked call is remembered under a short id. `/approve <id>` turns it into a
+// single-use grant for that exact call (tool name + params hash) in that session.
+const ACTION_ID_PREFIX = "act-";
+const ACTION_TTL_MS = 60 * 60 * 1000;
+const MAX_PENDING_ACTIONS = 256;
+
+type ToolAutonomyAction = {
+  id: string;
+  sessionKey: string;
+  toolName: string;
+  callHash: string;
+  createdAtMs: number;
+};
+
+const pendingActions = new Map<string, ToolAutonomyAction>();
+const grantedActions = new Map<string, ToolAutonomyAction>();
+
+export function normalizeToolAutonomyMode(value: unknown): ToolAutonomyMode | undefined {
+  if (typeof value !== "string") {
+    return undefined;
+  }
+  const normalized = value.trim().toLowerCase();
+  return TOOL_AUTONOMY_MODES.find((mode) => mode === normalized);
+}
+
+function readSessionAutonomy(
+  cfg: OpenClawConfig,
+  sessionKey: string | undefined,
+): ToolAutonomyMode | undefined {
+  const agentId = sessionKey ? parseAgentSessionKey(sessionKey)?.agentId : undefined;
+  if (!sessionKey || !agentId) {
+    return undefined;
+  }
+  try {
+    const store = loadSessionStore(resolveStorePath(cfg.session?.store, { agentId }));
+    return normalizeToolAutonomyMode(store[sessionKey]?.autonomy);
+  } catch {
+    return undefined;
+  }
+}
+
+/** Session override (/autonomy) wins over the agent config, which wins over tools.autonomy. */
+export function resolveToolAutonomy(params: {
+  cfg?: OpenClawConfig;
+  agentId?: string;
+  sessionKey?: string;
+}): ToolAutonomyState | undefined {
+  if (!params.cfg) {
+    return undefined;
+  }
+  const agent = params.agentId
+    ? resolveAgentConfig(params.cfg, params.agentId)?.tools?.autonomy
+    : undefined;
+  const global = params.cfg.tools?.autonomy;
+  const mode =
+    readSessionAutonomy(params.cfg, params.sessionKey) ?? agent?.mode ?? global?.mode ?? "auto";
+  if (mode === "auto") {
+    return undefined;
+  }
+  return {
+    mode,
+    actionTools: agent?.actionTools ?? global?.actionTools,
+    readOnlyTools: agent?.readOnlyTools ?? global?.readOnlyTools,
+  };
+}
+
+function matchesToolList(toolName: string, list?: string[]): boolean {
+  return Boolean(list?.length) && isToolAllowedByPolicyName(toolName, { allow: list });
+}
+
+/** Fails closed: unknown tools are side-effecting unless listed in readOnlyTools. */
+export function isSideEffectingToolCall(
+  toolName: string,
+  params: unknown,
+  overrides?: ToolSideEffectOverrides,
+): boolean {
+  if (matchesToolList(toolName, overrides?.actionTools)) {
+    return true;
+  }
+  if (matchesToolList(toolName, overrides?.readOnlyTools)) {
+    return false;
+  }
+  const normalized = toolName.trim().toLowerCase();
+  if (READ_ONLY_TOOL_NAMES.has(normalized) || isLikelyMutatingToolName(normalized)) {
+    return isMutatingToolCall(normalized, params);
+  }
+  return true;
+}
+
+/** Returns the block reason when autonomy does not allow this call, otherwise null. */
+export function checkToolAutonomy(params: {
+  autonomy: ToolAutonomyState;
+  toolName: string;
+  toolParams: unknown;
+  sessionKey?: string;
+}): string | null {
+  const { autonomy } = params;
+  if (!isSideEffectingToolCall(params.toolName, params.toolParams, autonomy)) {
+    return null;
+  }
+  if (autonomy.mode === "observe") {
+    return `Autonomy is observe-only: ${params.toolName} would change something, so it was not run. Do not retry. Report what you found and what you would do.`;
+  }
+  const sessionKey = params.sessionKey;
+  if (!sessionKey) {
+    return `Autonomy is ask-before-acting: ${params.toolName} was not run and cannot be approved from here. Do not retry. Tell the user what you wanted to do.`;
+  }
+  const callHash = hashToolCall(params.toolName, params.toolParams);
+  const now = Date.now();
+  const grantKey = `${sessionKey}\n${callHash}`;
+  const grant = grantedActions.get(grantKey);
+  if (grant) {
+    grantedActions.delete(grantKey);
+    if (now - grant.createdAtMs <= ACTION_TTL_MS) {
+      return null;
+    }
+  }
+  const action = rememberPendingAction({ sessionKey, toolName: params.toolName, callHash, now });
+  return `Autonomy is ask-before-acting: ${params.toolName} was not run. Do not retry now. Tell the user exactly what you want to do and that they can approve it with /approve ${action.id} (or decline with /approve ${action.id} deny). Once it is approved, call ${params.toolName} again with exactly the same parameters; any other call needs its own approval.`;
+}
+
+function rememberPendingAction(params: {
+  sessionKey: string;
+  toolName: string;
+  callHash: string;
+  now: number;
+}): ToolAutonomyAction {
+  for (const [id, action] of pendingActions) {
+    if (params.now - action.createdAtMs > ACTION_TTL_MS) {
+      pendingActions.delete(id);
+    } else if (action.sessionKey === params.sessionKey && action.callHash === params.callHash) {
+      return action;
+    }
+  }
+  const action: ToolAutonomyAction = {
+    id: `${ACTION_ID_PREFIX}${crypto.randomBytes(3).toString("hex")}`,
+    sessionKey: params.sessionKey,
+    toolName: params.toolName,
+    callHash: params.callHash,
+    createdAtMs: params.now,
+  };
+  pendingActions.set(action.id, action);
+  if (pendingActions.size > MAX_PENDING_ACTIONS) {
+    const oldest = pendingActions.keys().next().value;
+    if (oldest) {
+      pendingActions.delete(oldest);
+    }
+  }
+  return action;
+}
+
+export function isToolAutonomyActionId(value: string): boolean {
+  return value.toLowerCase().startsWith(ACTION_ID_PREFIX);
+}
+
+/**
+ * Resolves a blocked "ask" call from `/approve <id>`. Approval grants that one call (same tool,
+ * same params, same session) exactly once; returns the tool name, or null for unknown ids.
+ */
+export function resolveToolAutonomyAction(params: {
+  id: string;
+  sessionKey: string | undefined;
+  approve: boolean;
+}): string | null {
+  const action = pendingActions.get(params.id.toLowerCase());
+  if (
+    !action ||
+    action.sessionKey !== params.sessionKey ||
+    Date.now() - action.createdAtMs > ACTION_TTL_MS
+  ) {
+    return null;
+  }
+  pendingActions.delete(action.id);
+  if (params.approve) {
+    grantedActions.set(`${action.sessionKey}\n${action.callHash}`, {
+      ...action,
+      createdAtMs: Date.now(),
+    });
+  }
+  return action.toolName;
+}
+
+export function resetToolAutonomyActionsForTest(): void {
+  pendingActions.clear();
+  grantedActions.clear();
+}
@@ -0,0 +1,24 @@
+import { describe, expect, it } from "vitest";
+import { parseToolCallArguments } from "./tool-call-arguments.js";
+
+describe("parseToolCallArguments", () => {
+  it("passes objects through and treats missing arguments as empty", () => {
+    expect(parseToolCallArguments({ a: 1 })).toEqual({ ok: true, params: { a: 1 } });
+    expect(parseToolCallArguments(undefined)).toEqual({ ok: true, params: {} });
+    expect(parseToolCallArguments("  ")).toEqual({ ok: true, params: {} });
+  });
+
+  it("decodes JSON object strings", () => {
+    expect(parseToolCallArguments('{"command":"ls"}')).toEqual({
+      ok: true,
+      params: { command: "ls" },
+    });
+  });
+
+  it("rejects pseudo-JSON, arrays, and scalars", () => {
+    for (const raw of ["{command: ls}", '["ls"]', "ls -la", [1], 42]) {
+      const result = parseToolCallArguments(raw);
+      expect(result.ok).toBe(false);
+    }
+  });
+});
@@ -0,0 +1,53 @@
+import { isPlainObject } from "../utils.js";
+
+const MAX_ERROR_PREVIEW_CHARS = 120;
+
+export type ToolCallArguments =
+  | { ok: true; params: Record<string, unknown> }
+  | { ok: false; error: string };
+
+function preview(value: string): string {
+  const compact = value.replace(/\s+/g, " ").trim();
+  return compact.length > MAX_ERROR_PREVIEW_CHARS
+    ? `${compact.slice(0, MAX_ERROR_PREVIEW_CHARS)}…`
+    : compact;
+}
+
+/**
+ * Normalize the arguments of a structured tool call before they reach `tool.execute`.
+ *
+ * Provider adapters hand us parsed JSON objects. Some OpenAI-compatible backends double-encode
+ * the arguments as a JSON string; that is decoded here. Anything else (pseudo-JSON, arrays,
+ * scalars) is rejected so the model gets an error result instead of the tool guessing.
+ */
+export function parseToolCallArguments(raw: unknown): ToolCallArguments {
+  if (raw === undefined || raw === null) {
+    return { ok: true, params: {} };
+  }
+  if (isPlainObject(raw)) {
+    return { ok: true, params: raw };
+  }
+  if (typeof raw === "string") {
+    const trimmed = raw.trim();
+    if (!trimmed) {
+      return { ok: true, params: {} };
+    }
+    try {
+      const parsed: unknown = JSON.parse(trimmed);
+      if (isPlainObject(parsed)) {
+        return { ok: true, params: parsed };
+      }
+    } catch {
+      // fall through to the error below
+    }
+    return {
+      ok: false,
+      error: `Malformed tool arguments: expected a JSON object, got "${preview(trimmed)}". Retry the call with valid JSON arguments.`,
+    };
+  }
+  const kind = Array.isArray(raw) ? "array" : typeof raw;
+  return {
+    ok: false,
+    error: `Malformed tool arguments: expected a JSON object, got ${kind}. Retry the call with valid JSON arguments.`,
+  };
+}
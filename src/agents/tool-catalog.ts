@@ -113,6 +113,38 @@ const CORE_TOOL_DEFINITIONS: CoreToolDefinition[] = [
     profiles: ["coding"],
     includeInOpenClawGroup: true,
   },
+  {
+    id: "memory_set",
+    label: "memory_set",
+    description: "Save key/value facts",
+    sectionId: "memory",
+    profiles: ["coding"],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "memory_list",
+    label: "memory_list",
+    description: "List saved facts",
+    sectionId: "memory",
+    profiles: ["coding"],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "kb_search",
+    label: "kb_search",
+    description: "Search reference documents",
+    sectionId: "memory",
+    profiles: ["coding"],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "kb_ingest",
+    label: "kb_ingest",
+    description: "Add documents to the knowledge base",
+    sectionId: "memory",
+    profiles: ["coding"],
+    includeInOpenClawGroup: true,
+  },
   {
     id: "sessions_list",
     label: "sessions_list",
@@ -193,6 +225,54 @@ const CORE_TOOL_DEFINITIONS: CoreToolDefinition[] = [
     profiles: [],
     includeInOpenClawGroup: true,
   },
+  {
+    id: "remind_me",
+    label: "remind_me",
+    description: "Set a reminder",
+    sectionId: "automation",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "task_add",
+    label: "task_add",
+    description: "Queue a follow-up",
+    sectionId: "automation",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "task_list",
+    label: "task_list",
+    description: "List queued follow-ups",
+    sectionId: "automation",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "task_complete",
+    label: "task_complete",
+    description: "Finish a follow-up",
+    sectionId: "automation",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "goal_set",
+    label: "goal_set",
+    description: "Set or update a standing goal",
+    sectionId: "automation",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
+  {
+    id: "goal_status",
+    label: "goal_status",
+    description: "Show goal status and progress",
+    sectionId: "automation",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
   {
     id: "gateway",
     label: "gateway",
@@ -209,6 +289,14 @@ const CORE_TOOL_DEFINITIONS: CoreToolDefinition[] = [
     profiles: [],
     includeInOpenClawGroup: true,
   },
+  {
+    id: "undo_last",
+    label: "undo_last",
+    description: "Undo the last hardware action",
+    sectionId: "nodes",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
   {
     id: "agents_list",
     label: "agents_list",
@@ -233,6 +321,14 @@ const CORE_TOOL_DEFINITIONS: CoreToolDefinition[] = [
     profiles: [],
     includeInOpenClawGroup: true,
   },
+  {
+    id: "transcribe",
+    label: "transcribe",
+    description: "Speech-to-text transcription",
+    sectionId: "media",
+    profiles: [],
+    includeInOpenClawGroup: true,
+  },
 ];
 
 const CORE_TOOL_BY_ID = new Map<string, CoreToolDefinition>(
@@ -0,0 +1,97 @@
+import { describe, expect, it } from "vitest";
+import type { ToolGuardrailRule } from "../config/types.tools.js";
+import { applyToolGuardrails, resolveToolGuardrails } from "./tool-guardrails.js";
+
+const rules: ToolGuardrailRule[] = [
+  { name: "no boot pins", tools: ["gpio_*"], param: "pin", oneOf: [0, 2] },
+  {
+    name: "heater duty",
+    tools: ["pwm_set"],
+    param: "options.duty",
+    max: 60,
+    clamp: true,
+    message: "the heater must stay at or below 60%",
+  },
+  { tools: ["pwm_set"], param: "frequency", min: 100, max: 20000 },
+  { name: "shell", tools: ["exec"], param: "command", match: "\\brm\\s+-rf\\s+/" },
+];
+
+describe("applyToolGuardrails", () => {
+  it("passes calls that no rule rejects", () => {
+    expect(applyToolGuardrails({ rules, toolName: "gpio_write", params: { pin: 5 } })).toEqual({
+      blocked: false,
+      params: { pin: 5 },
+    });
+    expect(applyToolGuardrails({ rules, toolName: "read", params: { pin: 0 } })).toEqual({
+      blocked: false,
+      params: { pin: 0 },
+    });
+  });
+
+  it("rejects forbidden values and banned patterns", () => {
+    const pin = applyToolGuardrails({ rules, toolName: "gpio_write", params: { pin: "2" } });
+    expect(pin).toEqual({
+      blocked: true,
+      reason: 'Guardrail "no boot pins": pin="2" is not allowed',
+    });
+    const shell = applyToolGuardrails({
+      rules,
+      toolName: "exec",
+      params: { command: "cd /tmp && RM -rf /" },
+    });
+    expect(shell.blocked).toBe(true);
+  });
+
+  it("clamps numbers when configured and explains the rewrite", () => {
+    const outcome = applyToolGuardrails({
+      rules,
+      toolName: "pwm_set",
+      params: { channel: 1, options: { duty: 85 } },
+    });
+    expect(outcome).toEqual({
+      blocked: false,
+      params: { channel: 1, options: { duty: 60 } },
+      notice:
+        'Guardrail "heater duty": the heater must stay at or below 60% (options.duty=85 was changed to 60 (allowed -∞..60))',
+    });
+  });
+
+  it("rejects out-of-range numbers without clamp", () => {
+    const outcome = applyToolGuardrails({
+      rules,
+      toolName: "pwm_set",
+      params: { frequency: 50 },
+    });
+    expect(outcome).toEqual({
+      blocked: true,
+      reason: "Guardrail: frequency=50 is outside the allowed range 100..20000",
+    });
+  });
+
+  it("fails closed when a bounded argument is not a number", () => {
+    for (const duty of ["100%", [100], {}, true]) {
+      const outcome = applyToolGuardrails({
+        rules,
+        toolName: "pwm_set",
+        params: { options: { duty } },
+      });
+      expect(outcome.blocked, JSON.stringify(duty)).toBe(true);
+    }
+    expect(
+      applyToolGuardrails({ rules, toolName: "pwm_set", params: { frequency: "400" } }),
+    ).toEqual({ blocked: false, params: { frequency: "400" } });
+  });
+});
+
+describe("resolveToolGuardrails", () => {
+  it("prefers per-agent rules", () => {
+    const agentRules: ToolGuardrailRule[] = [{ tools: ["exec"], match: "sudo" }];
+    const cfg = {
+      tools: { guardrails: rules },
+      agents: { list: [{ id: "ops", tools: { guardrails: agentRules } }] },
+    };
+    expect(resolveToolGuardrails({ cfg, agentId: "main" })).toBe(rules);
+    expect(resolveToolGuardrails({ cfg, agentId: "ops" })).toBe(agentRules);
+    expect(resolveToolGuardrails({ cfg: {} })).toBeUndefined();
+  });
+});
@@ -0,0 +1,137 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { ToolGuardrailRule } from "../config/types.tools.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import { isPlainObject } from "../utils.js";
+import { resolveAgentConfig } from "./agent-scope.js";
+import { isToolAllowedByPolicyName } from "./pi-tools.policy.js";
+
+const log = createSubsystemLogger("agents/guardrails");
+
+export type ToolGuardrailOutcome =
+  | { blocked: true; reason: string }
+  | { blocked: false; params: unknown; notice?: string };
+
+export function resolveToolGuardrails(params: {
+  cfg?: OpenClawConfig;
+  agentId?: string;
+}): ToolGuardrailRule[] | undefined {
+  const agent =
+    params.agentId && params.cfg
+      ? resolveAgentConfig(params.cfg, params.agentId)?.tools?.guardrails
+      : undefined;
+  const rules = agent ?? params.cfg?.tools?.guardrails;
+  return rules?.length ? rules : undefined;
+}
+
+function readParam(params: unknown, pathExpr: string): unknown {
+  let current = params;
+  for (const key of pathExpr.split(".")) {
+    if (!isPlainObject(current)) {
+      return undefined;
+    }
+    current = current[key];
+  }
+  return current;
+}
+
+function writeParam(params: unknown, pathExpr: string, value: unknown): unknown {
+  const [key, ...rest] = pathExpr.split(".");
+  const base = isPlainObject(params) ? params : {};
+  return {
+    ...base,
+    [key]: rest.length > 0 ? writeParam(base[key], rest.join("."), value) : value,
+  };
+}
+
+function toNumber(value: unknown): number | undefined {
+  if (typeof value === "number") {
+    return Number.isFinite(value) ? value : undefined;
+  }
+  if (typeof value === "string" && value.trim()) {
+    const parsed = Number(value.trim());
+    return Number.isFinite(parsed) ? parsed : undefined;
+  }
+  return undefined;
+}
+
+function describeRule(rule: ToolGuardrailRule, detail: string): string {
+  const label = rule.name ? `Guardrail "${rule.name}"` : "Guardrail";
+  return rule.message ? `${label}: ${rule.message} (${detail})` : `${label}: ${detail}`;
+}
+
+/**
+ * Check one tool call against the configured rules. Returns the (possibly clamped) params, or a
+ * rejection whose reason is fed back to the model as the tool error.
+ */
+export function applyToolGuardrails(params: {
+  rules: ToolGuardrailRule[];
+  toolName: string;
+  params: unknown;
+}): ToolGuardrailOutcome {
+  let current = params.params;
+  const notices: string[] = [];
+  for (const rule of params.rules) {
+    if (!isToolAllowedByPolicyName(params.toolName, { allow: rule.tools })) {
+      continue;
+    }
+    const value = rule.param ? readParam(current, rule.param) : current;
+    if (value === undefined) {
+      continue;
+    }
+    const argLabel = rule.param ? `${rule.param}=${JSON.stringify(value)}` : "arguments";
+
+    if (rule.oneOf?.some((forbidden) => String(forbidden) === String(value))) {
+      log.warn(`Blocking ${params.toolName}: ${argLabel} is forbidden`);
+      return { blocked: true, reason: describeRule(rule, `${argLabel} is not allowed`) };
+    }
+
+    if (rule.match !== undefined) {
+      const text = typeof value === "string" ? value : JSON.stringify(value);
+      let pattern: RegExp | undefined;
+      try {
+        pattern = new RegExp(rule.match, "i");
+      } catch {
+        log.warn(`Ignoring guardrail with invalid regex: ${rule.match}`);
+      }
+      if (pattern?.test(text)) {
+        log.warn(`Blocking ${params.toolName}: ${argLabel} matches /${rule.match}/`);
+        return { blocked: true, reason: describeRule(rule, `${argLabel} is not allowed`) };
+      }
+    }
+
+    if (rule.param && (rule.min !== undefined || rule.max !== undefined)) {
+      const numeric = toNumber(value);
+      const range = `${rule.min ?? "-∞"}..${rule.max ?? "∞"}`;
+      if (numeric === undefined) {
+        // Bounds are safety limits: a value we cannot compare ("100%", [100], {}) fails closed.
+        log.warn(`Blocking ${params.toolName}: ${argLabel} is not a number (bounded ${range})`);
+        return {
+          blocked: true,
+          reason: describeRule(rule, `${argLabel} must be a number in the range ${range}`),
+        };
+      }
+      const bound =
+        rule.min !== undefined && numeric < rule.min
+          ? rule.min
+          : rule.max !== undefined && numeric > rule.max
+            ? rule.max
+            : undefined;
+      if (bound === undefined) {
+        continue;
+      }
+      if (!rule.clamp) {
+        log.warn(`Blocking ${params.toolName}: ${argLabel} outside ${range}`);
+        return {
+          blocked: true,
+          reason: describeRule(rule, `${argLabel} is outside the allowed range ${range}`),
+        };
+      }
+      log.info(`Clamping ${params.toolName}: ${argLabel} -> ${bound}`);
+      current = writeParam(current, rule.param, bound);
+      notices.push(describeRule(rule, `${argLabel} was changed to ${bound} (allowed ${range})`));
+    }
+  }
+  return notices.length > 0
+    ? { blocked: false, params: current, notice: notices.join("\n") }
+    : { blocked: false, params: current };
+}
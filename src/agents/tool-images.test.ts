@@ -1,6 +1,10 @@
 import sharp from "sharp";
 import { describe, expect, it } from "vitest";
-import { sanitizeContentBlocksImages, sanitizeImageBlocks } from "./tool-images.js";
+import {
+  omitToolResultImages,
+  sanitizeContentBlocksImages,
+  sanitizeImageBlocks,
+} from "./tool-images.js";
 
 describe("tool image sanitizing", () => {
   const getImageBlock = (
@@ -107,4 +111,21 @@ describe("tool image sanitizing", () => {
     const image = getImageBlock(out);
     expect(image.mimeType).toBe("image/jpeg");
   });
+
+  it("replaces images with a note for models without vision input", () => {
+    const result = omitToolResultImages(
+      {
+        content: [
+          { type: "text", text: "MEDIA:/tmp/snap.jpg" },
+          { type: "image", data: "/9j/AAAA", mimeType: "image/jpeg" },
+        ],
+        details: {},
+      },
+      "nodes",
+    );
+    expect(result.content.some((block) => block.type === "image")).toBe(false);
+    expect(JSON.stringify(result.content)).toContain(
+      "[nodes] image omitted: the current model does not accept image input. Saved at /tmp/snap.jpg",
+    );
+  });
 });
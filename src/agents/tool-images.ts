@@ -351,3 +351,33 @@ export async function sanitizeToolResultImages(
   const next = await sanitizeContentBlocksImages(content, label, opts);
   return { ...result, content: next };
 }
+
+/**
+ * Replace image blocks with a short text note for models without image input, so tool
+ * results (camera snaps, screenshots) never send bytes the provider would reject or ignore.
+ */
+export function omitToolResultImages(
+  result: AgentToolResult<unknown>,
+  label: string,
+): AgentToolResult<unknown> {
+  const content = Array.isArray(result.content) ? result.content : [];
+  if (!content.some(isImageBlock)) {
+    return result;
+  }
+  const mediaPath = content
+    .filter(isTextBlock)
+    .map((block) => parseMediaPathFromText(block.text))
+    .find(Boolean);
+  const hint = mediaPath
+    ? ` Saved at ${mediaPath}; use the image tool to analyze it.`
+    : " Use the image tool to analyze it.";
+  const next = content.map((block) =>
+    isImageBlock(block)
+      ? ({
+          type: "text",
+          text: `[${label}] image omitted: the current model does not accept image input.${hint}`,
+        } satisfies TextContentBlock)
+      : block,
+  );
+  return { ...result, content: next };
+}
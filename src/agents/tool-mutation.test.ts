@@ -18,6 +18,42 @@ describe("tool mutation helpers", () => {
     ).toBe(true);
   });
 
+  it("treats memory_set as mutating and memory reads as read-only", () => {
+    expect(isMutatingToolCall("memory_set", { key: "pump relay pin", value: "GPIO 7" })).toBe(true);
+    expect(isMutatingToolCall("memory_list", {})).toBe(false);
+    expect(isMutatingToolCall("memory_get", { key: "pump relay pin" })).toBe(false);
+    expect(buildToolActionFingerprint("memory_set", { key: "Pump relay pin" })).toBe(
+      "tool=memory_set|key=pump relay pin",
+    );
+  });
+
+  it("treats task_add and task_complete as mutating and task_list as read-only", () => {
+    expect(isMutatingToolCall("task_add", { title: "Flush the coolant loop" })).toBe(true);
+    expect(isMutatingToolCall("task_complete", { id: "t1" })).toBe(true);
+    expect(isMutatingToolCall("task_list", { includeDone: true })).toBe(false);
+    expect(buildToolActionFingerprint("task_complete", { id: "T1" })).toBe(
+      "tool=task_complete|id=t1",
+    );
+  });
+
+  it("treats remind_me as mutating", () => {
+    expect(isMutatingToolCall("remind_me", { text: "water the ferns", when: "tomorrow 9am" })).toBe(
+      true,
+    );
+  });
+
+  it("treats kb_ingest as mutating and kb_search as read-only", () => {
+    expect(isMutatingToolCall("kb_ingest", { path: "docs/bme280.pdf" })).toBe(true);
+    expect(isMutatingToolCall("kb_search", { query: "i2c address" })).toBe(false);
+  });
+
+  it("treats goal_set as mutating and goal_status as read-only", () => {
+    expect(isMutatingToolCall("goal_set", { objective: "keep the greenhouse at 18-24C" })).toBe(
+      true,
+    );
+    expect(isMutatingToolCall("goal_status", {})).toBe(false);
+  });
+
   it("builds stable fingerprints for mutating calls and omits read-only calls", () => {
     const writeFingerprint = buildToolActionFingerprint(
       "write",
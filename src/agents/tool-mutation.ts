@@ -7,11 +7,18 @@ const MUTATING_TOOL_NAMES = new Set([
   "process",
   "message",
   "sessions_send",
+  "memory_set",
+  "task_add",
+  "task_complete",
+  "remind_me",
+  "kb_ingest",
+  "goal_set",
   "cron",
   "gateway",
   "canvas",
   "nodes",
   "session_status",
+  "undo_last",
 ]);
 
 const READ_ONLY_ACTIONS = new Set([
@@ -107,6 +114,12 @@ export function isMutatingToolCall(toolName: string, args: unknown): boolean {
     case "exec":
     case "bash":
     case "sessions_send":
+    case "memory_set":
+    case "task_add":
+    case "task_complete":
+    case "remind_me":
+    case "kb_ingest":
+    case "goal_set":
       return true;
     case "process":
       return action != null && PROCESS_MUTATING_ACTIONS.has(action);
@@ -118,6 +131,8 @@ export function isMutatingToolCall(toolName: string, args: unknown): boolean {
       );
     case "session_status":
       return typeof record?.model === "string" && record.model.trim().length > 0;
+    case "undo_last":
+      return action !== "list";
     default: {
       if (normalized === "cron" || normalized === "gateway" || normalized === "canvas") {
         return action == null || !READ_ONLY_ACTIONS.has(action);
@@ -163,6 +178,7 @@ export function buildToolActionFingerprint(
     "sessionKey",
     "jobId",
     "id",
+    "key",
     "model",
   ]) {
     const value = normalizeFingerprintValue(record?.[key]);
@@ -0,0 +1,177 @@
+import type { AgentToolResult } from "@mariozechner/pi-agent-core";
+import type { ToolDefinition } from "@mariozechner/pi-coding-agent";
+import { describe, expect, it } from "vitest";
+import {
+  createParallelToolRunner,
+  planParallelToolCalls,
+  resolveParallelToolPolicy,
+} from "./tool-parallel.js";
+
+const call = (id: string, name: string, args: Record<string, unknown> = {}) => ({
+  id,
+  name,
+  arguments: args,
+});
+
+describe("parallel tool calls", () => {
+  it("plans read-only calls up to the first side-effecting call", () => {
+    const policy = resolveParallelToolPolicy({
+      cfg: {
+        tools: {
+          autonomy: { readOnlyTools: ["adc_read"] },
+          parallel: { enabled: true, tools: ["nodes"], exclusive: ["adc_read"] },
+        },
+      },
+    });
+    expect(policy).toBeDefined();
+    const planned = planParallelToolCalls(
+      [
+        call("1", "adc_read", { channel: 0 }),
+        call("2", "nodes", { action: "camera_snap" }),
+        call("3", "adc_read", { channel: 1 }),
+        call("4", "session_status"),
+        call("5", "write", { path: "log.txt" }),
+        call("6", "read", { path: "log.txt" }),
+      ],
+      policy!,
+    );
+    expect(planned.map((entry) => entry.id)).toEqual(["1", "2", "4"]);
+
+    expect(planParallelToolCalls([call("1", "read"), call("2", "exec")], policy!)).toEqual([]);
+    expect(resolveParallelToolPolicy({ cfg: { tools: { parallel: { enabled: false } } } })).toBe(
+      undefined,
+    );
+    expect(resolveParallelToolPolicy({ cfg: {} })).toBeUndefined();
+  });
+
+  it("runs planned calls concurrently and hands results to the agent loop", async () => {
+    const runner = createParallelToolRunner({
+      policy: { maxConcurrency: 4, readOnlyTools: ["sensors", "camera"] },
+    });
+    const started: string[] = [];
+    const release = new Map<string, () => void>();
+    const tool = (name: string): ToolDefinition =>
+      ({
+        name,
+        label: name,
+        description: "",
+        parameters: {},
+        execute: async (toolCallId: string) => {
+          started.push(toolCallId);
+          await new Promise<void>((resolve) => release.set(toolCallId, resolve));
+          return { content: [{ type: "text", text: `${name}:${toolCallId}` }], details: {} };
+        },
+      }) as unknown as ToolDefinition;
+    const [sensors, camera] = runner.wrapTools([tool("sensors"), tool("camera")]);
+    const streamFn = runner.wrapStreamFn((async () => ({
+      async *[Symbol.asyncIterator]() {
+        yield {
+          type: "done",
+          reason: "toolUse",
+          message: {
+            role: "assistant",
+            content: [
+              { type: "toolCall", id: "a", name: "sensors", arguments: {} },
+              { type: "toolCall", id: "b", name: "camera", arguments: {} },
+            ],
+          },
+        };
+      },
+    })) as never);
+
+    const stream = await streamFn({} as never, {} as never, {});
+    for await (const _event of stream) {
+      // drain
+    }
+    await new Promise((resolve) => setImmediate(resolve));
+    // Both calls are running before the loop asks for the first one.
+    expect(started).toEqual(["a", "b"]);
+
+    const execute = (tool: ToolDefinition, id: string) =>
+      (tool.execute as (id: string, params: unknown) => Promise<AgentToolResult<unknown>>)(id, {});
+    release.get("b")?.();
+    release.get("a")?.();
+    await expect(execute(sensors, "a")).resolves.toMatchObject({
+      content: [{ text: "sensors:a" }],
+    });
+    await expect(execute(camera, "b")).resolves.toMatchObject({ content: [{ text: "camera:b" }] });
+    expect(started).toEqual(["a", "b"]);
+  });
+
+  it("replays progress updates from prefetched calls to the agent loop", async () => {
+    const runner = createParallelToolRunner({
+      policy: { maxConcurrency: 4, readOnlyTools: ["scan"] },
+    });
+    const tool = {
+      name: "scan",
+      label: "scan",
+      description: "",
+      parameters: {},
+      execute: async (
+        toolCallId: string,
+        _params: unknown,
+        _signal: unknown,
+        onUpdate?: (partial: AgentToolResult<unknown>) => void,
+      ) => {
+        onUpdate?.({ content: [{ type: "text", text: `${toolCallId}:50%` }], details: {} });
+        return { content: [{ type: "text", text: `${toolCallId}:done` }], details: {} };
+      },
+    } as unknown as ToolDefinition;
+    const [scan] = runner.wrapTools([tool]);
+    const streamFn = runner.wrapStreamFn((async () => ({
+      async *[Symbol.asyncIterator]() {
+        yield {
+          type: "done",
+          reason: "toolUse",
+          message: {
+            role: "assistant",
+            content: [
+              { type: "toolCall", id: "a", name: "scan", arguments: {} },
+              { type: "toolCall", id: "b", name: "scan", arguments: {} },
+            ],
+          },
+        };
+      },
+    })) as never);
+    for await (const _event of await streamFn({} as never, {} as never, {})) {
+      // drain
+    }
+    await new Promise((resolve) => setImmediate(resolve));
+
+    const updates: string[] = [];
+    const result = await (
+      scan.execute as (
+        id: string,
+        params: unknown,
+        signal: undefined,
+        onUpdate: (partial: AgentToolResult<unknown>) => void,
+      ) => Promise<AgentToolResult<unknown>>
+    )("b", {}, undefined, (partial) => {
+      const [block] = partial.content;
+      updates.push(block?.type === "text" ? block.text : "");
+    });
+    expect(updates).toEqual(["b:50%"]);
+    expect(result).toMatchObject({ content: [{ text: "b:done" }] });
+  });
+
+  it("ends the stream with an error event when the provider stream throws", async () => {
+    const runner = createParallelToolRunner({ policy: { maxConcurrency: 4 } });
+    const streamFn = runner.wrapStreamFn((async () => ({
+      [Symbol.asyncIterator]: () => ({
+        next: async () => {
+          throw new Error("socket hang up");
+        },
+      }),
+    })) as never);
+    const events: Array<{ type: string; error?: { errorMessage?: string } }> = [];
+    for await (const event of await streamFn(
+      { api: "openai-completions", provider: "local", id: "m" } as never,
+      {} as never,
+      {},
+    )) {
+      events.push(event as never);
+    }
+    expect(events).toHaveLength(1);
+    expect(events[0]).toMatchObject({ type: "error", error: { errorMessage: "socket hang up" } });
+  });
+});
@@ -0,0 +1,213 @@
+import type { AgentToolResult, StreamFn } from "@mariozechner/pi-agent-core";
+import { createAssistantMessageEventStream } from "@mariozechner/pi-ai";
+import type { ToolDefinition } from "@mariozechner/pi-coding-agent";
+import type { OpenClawConfig } from "../config/config.js";
+import { logDebug } from "../logger.js";
+import { buildStreamErrorEvent } from "./agent-middleware.js";
+import { resolveAgentConfig } from "./agent-scope.js";
+import { isToolAllowedByPolicyName } from "./pi-tools.policy.js";
+import { isSideEffectingToolCall } from "./tool-autonomy.js";
+
+const DEFAULT_MAX_CONCURRENCY = 4;
+
+export type ParallelToolPolicy = {
+  maxConcurrency: number;
+  /** Side-effecting tools allowed to run in parallel anyway. */
+  tools?: string[];
+  /** Tools that hold a per-tool lock. */
+  exclusive?: string[];
+  /** From `tools.autonomy`, so both features agree on what is side-effecting. */
+  actionTools?: string[];
+  readOnlyTools?: string[];
+};
+
+export type ParallelToolCall = { id: string; name: string; arguments: unknown };
+
+/** Undefined unless `tools.parallel.enabled` is true (opt-in). */
+export function resolveParallelToolPolicy(params: {
+  cfg?: OpenClawConfig;
+  agentId?: string;
+}): ParallelToolPolicy | undefined {
+  const parallel = params.cfg?.tools?.parallel;
+  if (parallel?.enabled !== true) {
+    return undefined;
+  }
+  const agentAutonomy =
+    params.cfg && params.agentId
+      ? resolveAgentConfig(params.cfg, params.agentId)?.tools?.autonomy
+      : undefined;
+  return {
+    maxConcurrency: parallel?.maxConcurrency ?? DEFAULT_MAX_CONCURRENCY,
+    tools: parallel?.tools,
+    exclusive: parallel?.exclusive,
+    actionTools: agentAutonomy?.actionTools ?? params.cfg?.tools?.autonomy?.actionTools,
+    readOnlyTools: agentAutonomy?.readOnlyTools ?? params.cfg?.tools?.autonomy?.readOnlyTools,
+  };
+}
+
+function matches(toolName: string, list?: string[]): boolean {
+  return Boolean(list?.length) && isToolAllowedByPolicyName(toolName, { allow: list });
+}
+
+/**
+ * Picks the calls of one assistant turn that can run concurrently: calls before the first
+ * side-effecting call (later calls may depend on its effect), skipping a second call to an
+ * exclusive tool while the first holds its lock. Returns [] when fewer than two qualify.
+ */
+export function planParallelToolCalls(
+  calls: ParallelToolCall[],
+  policy: ParallelToolPolicy,
+): ParallelToolCall[] {
+  const planned: ParallelToolCall[] = [];
+  const locks = new Set<string>();
+  for (const call of calls) {
+    const sideEffecting = isSideEffectingToolCall(call.name, call.arguments, policy);
+    if (sideEffecting && !matches(call.name, policy.tools)) {
+      break;
+    }
+    if (matches(call.name, policy.exclusive)) {
+      if (locks.has(call.name)) {
+        continue;
+      }
+      locks.add(call.name);
+    }
+    planned.push(call);
+  }
+  return planned.length > 1 ? planned : [];
+}
+
+type ToolExecute = ToolDefinition["execute"];
+type ToolUpdateCallback = (partial: AgentToolResult<unknown>) => void;
+
+/** A started call; progress updates are buffered until the loop reaches it. */
+type PrefetchedCall = {
+  result: Promise<AgentToolResult<unknown>>;
+  updates: AgentToolResult<unknown>[];
+  onUpdate?: ToolUpdateCallback;
+};
+
+/**
+ * Runs independent tool calls concurrently. The agent loop still executes tool calls one by one,
+ * so this starts the planned calls as soon as the model's turn completes and hands each result
+ * back when the loop reaches that call. Prefetched calls go through the same `execute` (hooks,
+ * guardrails, middleware); their progress updates are replayed once the loop reaches them.
+ */
+export function createParallelToolRunner(params: {
+  policy: ParallelToolPolicy;
+  signal?: AbortSignal;
+}) {
+  const executors = new Map<string, ToolExecute>();
+  const pending = new Map<string, PrefetchedCall>();
+  // Calls the loop already started itself; never run those a second time.
+  const started = new Set<string>();
+
+  const prefetch = (calls: ParallelToolCall[]) => {
+    pending.clear();
+    const planned = planParallelToolCalls(
+      calls.filter((call) => executors.has(call.name) && !started.has(call.id)),
+      params.policy,
+    );
+    if (planned.length === 0) {
+      return;
+    }
+    logDebug(`tools: running ${planned.length} calls in parallel`);
+    let active = 0;
+    const queue: Array<() => void> = [];
+    const release = () => {
+      active -= 1;
+      queue.shift()?.();
+    };
+    for (const call of planned) {
+      const execute = executors.get(call.name);
+      if (!execute) {
+        continue;
+      }
+      const entry: PrefetchedCall = {
+        updates: [],
+        result: new Promise<void>((resolve) => {
+          if (active < params.policy.maxConcurrency) {
+            active += 1;
+            resolve();
+          } else {
+            queue.push(() => {
+              active += 1;
+              resolve();
+            });
+          }
+        }).then(() =>
+          (execute as (...args: unknown[]) => Promise<AgentToolResult<unknown>>)(
+            call.id,
+            call.arguments,
+            params.signal,
+            (partial: AgentToolResult<unknown>) => {
+              if (entry.onUpdate) {
+                entry.onUpdate(partial);
+              } else {
+                entry.updates.push(partial);
+              }
+            },
+          ).finally(release),
+        ),
+      };
+      // The loop may never reach this call (abort, steering); don't surface it as unhandled.
+      entry.result.catch(() => {});
+      started.add(call.id);
+      pending.set(call.id, entry);
+    }
+  };
+
+  return {
+    /** Routes each tool's `execute` through the prefetched result when one exists. */
+    wrapTools(tools: ToolDefinition[]): ToolDefinition[] {
+      return tools.map((tool) => {
+        executors.set(tool.name, tool.execute);
+        const execute = ((...args: Parameters<ToolExecute>) => {
+          const toolCallId = args[0];
+          const prefetched = pending.get(toolCallId);
+          if (prefetched) {
+            pending.delete(toolCallId);
+            const onUpdate = args[3] as ToolUpdateCallback | undefined;
+            if (onUpdate) {
+              for (const partial of prefetched.updates.splice(0)) {
+                onUpdate(partial);
+              }
+              prefetched.onUpdate = onUpdate;
+            }
+            return prefetched.result;
+          }
+          started.add(toolCallId);
+          return tool.execute(...args);
+        }) as ToolExecute;
+        return { ...tool, execute };
+      });
+    },
+    /** Watches completed assistant turns and starts their independent tool calls. */
+    wrapStreamFn(streamFn: StreamFn): StreamFn {
+      return async (model, context, options) => {
+        const inner = await streamFn(model, context, options);
+        const stream = createAssistantMessageEventStream();
+        void (async () => {
+          for await (const event of inner) {
+            if (event.type === "done") {
+              prefetch(
+                event.message.content.flatMap((block) =>
+                  block.type === "toolCall"
+                    ? [{ id: block.id, name: block.name, arguments: block.arguments }]
+                    : [],
+                ),
+              );
+            }
+            stream.push(event);
+          }
+        })()
+          .catch((err) => {
+            stream.push(buildStreamErrorEvent(model, err));
+          })
+          .finally(() => {
+            stream.end();
+          });
+        return stream;
+      };
+    },
+  };
+}
@@ -221,6 +221,100 @@ export function jsonResult(payload: unknown): AgentToolResult<unknown> {
   };
 }
 
+export type ToolResultAttachmentKind = "image" | "audio" | "video" | "file";
+
+export type ToolResultAttachment = {
+  /** Local file path or http(s) URL. */
+  source: string;
+  /** What the file is; channels still pick the upload type from the detected MIME type. */
+  kind?: ToolResultAttachmentKind;
+};
+
+/**
+ * Declare files a tool produced for the user. They travel in `details.attachments` and are
+ * uploaded natively by the channel (photo, audio, document) instead of relying on `MEDIA:`
+ * lines in the tool text; the CLI prints their paths.
+ */
+export function withToolAttachments(
+  result: AgentToolResult<unknown>,
+  attachments: ToolResultAttachment[],
+): AgentToolResult<unknown> {
+  const details =
+    result.details && typeof result.details === "object" && !Array.isArray(result.details)
+      ? (result.details as Record<string, unknown>)
+      : {};
+  return { ...result, details: { ...details, attachments } };
+}
+
+/** Valid `details.attachments` entries of a tool result (sources trimmed, blanks dropped). */
+export function readToolResultAttachments(details: unknown): ToolResultAttachment[] {
+  if (!details || typeof details !== "object") {
+    return [];
+  }
+  const raw = (details as { attachments?: unknown }).attachments;
+  if (!Array.isArray(raw)) {
+    return [];
+  }
+  const attachments: ToolResultAttachment[] = [];
+  for (const entry of raw) {
+    const source =
+      entry && typeof entry === "object" ? (entry as { source?: unknown }).source : undefined;
+    if (typeof source !== "string" || !source.trim()) {
+      continue;
+    }
+    const kind = (entry as { kind?: unknown }).kind;
+    attachments.push({
+      source: source.trim(),
+      ...(typeof kind === "string" ? { kind: kind as ToolResultAttachmentKind } : {}),
+    });
+  }
+  return attachments;
+}
+
+export type ToolProgress = {
+  message: string;
+  /** 0-100; omitted for open-ended steps. */
+  percent?: number;
+};
+
+/**
+ * Partial result for a tool's `onUpdate` callback. Chat channels show it as a short progress
+ * line ("⏳ flashing firmware (40%)") so long tool runs do not look like a dead bot.
+ */
+export function toolProgressResult(progress: ToolProgress): AgentToolResult<unknown> {
+  return {
+    content: [{ type: "text", text: formatToolProgress(progress) }],
+    details: { progress },
+  };
+}
+
+/** `details.progress` of a partial tool result, if it is a valid progress entry. */
+export function readToolProgress(partial: unknown): ToolProgress | undefined {
+  const details =
+    partial && typeof partial === "object" ? (partial as { details?: unknown }).details : undefined;
+  const raw = details && typeof details === "object" ? (details as { progress?: unknown }) : {};
+  const progress = raw.progress;
+  if (!progress || typeof progress !== "object") {
+    return undefined;
+  }
+  const message = (progress as { message?: unknown }).message;
+  if (typeof message !== "string" || !message.trim()) {
+    return undefined;
+  }
+  const percent = (progress as { percent?: unknown }).percent;
+  return {
+    message: message.trim(),
+    ...(typeof percent === "number" && Number.isFinite(percent)
+      ? { percent: Math.min(100, Math.max(0, Math.round(percent))) }
+      : {}),
+  };
+}
+
+export function formatToolProgress(progress: ToolProgress): string {
+  const percent = progress.percent !== undefined ? ` (${progress.percent}%)` : "";
+  return `⏳ ${progress.message}${percent}`;
+}
+
 export function wrapOwnerOnlyToolExecution(
   tool: AnyAgentTool,
   senderIsOwner: boolean,
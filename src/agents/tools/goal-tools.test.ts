@@ -0,0 +1,86 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { resolveActiveGoalsPrompt } from "../../goals/store.js";
+import { createGoalSetTool, createGoalStatusTool } from "./goal-tools.js";
+
+type GoalDetails = {
+  id: string;
+  objective: string;
+  status: string;
+  lastProgress?: { note: string };
+  progress?: Array<{ note: string }>;
+};
+
+describe("goal tools", () => {
+  let dir = "";
+  let storePath = "";
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-goals-"));
+    storePath = path.join(dir, "goals.json");
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  const options = (agentSessionKey = "agent:main:main") => ({ agentSessionKey, storePath });
+
+  it("sets a goal, records progress, and reports status", async () => {
+    const set = createGoalSetTool(options());
+    const created = await set.execute("1", { objective: "Keep the greenhouse between 18-24C" });
+    const { id } = (created.details as { goal: GoalDetails }).goal;
+
+    await set.execute("2", { id, progress: "21.5C, vents closed" });
+    await set.execute("3", { id, progress: "25.1C, opened vents" });
+
+    const status = createGoalStatusTool(options());
+    const list = (await status.execute("4", {})).details as { goals: GoalDetails[] };
+    expect(list.goals).toEqual([
+      expect.objectContaining({
+        id,
+        status: "active",
+        lastProgress: expect.objectContaining({ note: "25.1C, opened vents" }),
+      }),
+    ]);
+
+    const single = (await status.execute("5", { id })).details as { goal: GoalDetails };
+    expect(single.goal.progress?.map((entry) => entry.note)).toEqual([
+      "21.5C, vents closed",
+      "25.1C, opened vents",
+    ]);
+
+    const prompt = await resolveActiveGoalsPrompt("main", storePath);
+    expect(prompt).toContain(`[${id}] Keep the greenhouse between 18-24C`);
+    expect(prompt).toContain("25.1C, opened vents");
+  });
+
+  it("drops finished goals from the active list and the prompt", async () => {
+    const set = createGoalSetTool(options());
+    const created = await set.execute("1", { objective: "Water the tomatoes daily" });
+    const { id } = (created.details as { goal: GoalDetails }).goal;
+    await set.execute("2", { id, status: "achieved", progress: "season over" });
+
+    const status = createGoalStatusTool(options());
+    expect(((await status.execute("3", {})).details as { count: number }).count).toBe(0);
+    const all = (await status.execute("4", { includeInactive: true })).details as {
+      goals: GoalDetails[];
+    };
+    expect(all.goals.map((goal) => goal.status)).toEqual(["achieved"]);
+    expect(await resolveActiveGoalsPrompt("main", storePath)).toBe("");
+  });
+
+  it("keeps goals per agent and rejects unknown ids", async () => {
+    await createGoalSetTool(options()).execute("1", { objective: "main goal" });
+    const other = createGoalStatusTool(options("agent:work:main"));
+    expect(((await other.execute("2", {})).details as { count: number }).count).toBe(0);
+    await expect(
+      createGoalSetTool(options()).execute("3", { id: "missing", progress: "x" }),
+    ).rejects.toThrow("unknown goal");
+    await expect(createGoalSetTool(options()).execute("4", {})).rejects.toThrow(
+      "objective required",
+    );
+  });
+});
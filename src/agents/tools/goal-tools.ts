@@ -0,0 +1,140 @@
+import { Type } from "@sinclair/typebox";
+import type { OpenClawConfig } from "../../config/config.js";
+import {
+  addGoal,
+  DEFAULT_GOAL_STORE_PATH,
+  GOAL_STATUSES,
+  getGoal,
+  listGoals,
+  updateGoal,
+  type AgentGoal,
+  type GoalStatus,
+} from "../../goals/store.js";
+import { resolveSessionAgentId } from "../agent-scope.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { type AnyAgentTool, jsonResult, readStringParam, ToolInputError } from "./common.js";
+
+const GoalSetSchema = Type.Object({
+  id: Type.Optional(Type.String({ description: "Existing goal id; omit to create a new goal" })),
+  objective: Type.Optional(Type.String()),
+  status: optionalStringEnum(GOAL_STATUSES),
+  progress: Type.Optional(
+    Type.String({ description: "Short progress note, e.g. the latest reading and what you did" }),
+  ),
+});
+
+const GoalStatusSchema = Type.Object({
+  id: Type.Optional(Type.String()),
+  includeInactive: Type.Optional(Type.Boolean()),
+});
+
+type GoalToolOptions = {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+  storePath?: string;
+};
+
+function resolveGoalToolContext(options?: GoalToolOptions) {
+  return {
+    storePath: options?.storePath ?? DEFAULT_GOAL_STORE_PATH,
+    agentId: resolveSessionAgentId({
+      sessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
+  };
+}
+
+function readGoalStatus(params: Record<string, unknown>): GoalStatus | undefined {
+  const raw = readStringParam(params, "status");
+  if (!raw) {
+    return undefined;
+  }
+  if (!(GOAL_STATUSES as readonly string[]).includes(raw)) {
+    throw new ToolInputError(`status must be one of: ${GOAL_STATUSES.join(", ")}`);
+  }
+  return raw as GoalStatus;
+}
+
+function formatGoal(goal: AgentGoal, opts?: { history?: boolean }) {
+  const last = goal.progress.at(-1);
+  return {
+    id: goal.id,
+    objective: goal.objective,
+    status: goal.status,
+    since: new Date(goal.createdAt).toISOString(),
+    updatedAt: new Date(goal.updatedAt).toISOString(),
+    ...(opts?.history
+      ? {
+          progress: goal.progress.map((entry) => ({
+            at: new Date(entry.at).toISOString(),
+            note: entry.note,
+          })),
+        }
+      : last
+        ? { lastProgress: { at: new Date(last.at).toISOString(), note: last.note } }
+        : {}),
+  };
+}
+
+export function createGoalSetTool(options?: GoalToolOptions): AnyAgentTool {
+  return {
+    label: "Goal Set",
+    name: "goal_set",
+    description:
+      'Set a standing goal that persists across sessions ("keep the greenhouse between 18-24°C"); heartbeats and device triggers check active goals. With id, update it: record a progress note, change the objective, or set status (paused, achieved, abandoned, active).',
+    parameters: GoalSetSchema,
+    execute: async (_toolCallId, params) => {
+      const { storePath, agentId } = resolveGoalToolContext(options);
+      const id = readStringParam(params, "id");
+      const objective = readStringParam(params, "objective");
+      const status = readGoalStatus(params);
+      const progress = readStringParam(params, "progress");
+      if (!id) {
+        if (!objective) {
+          throw new ToolInputError("objective required to set a new goal");
+        }
+        const goal = await addGoal(storePath, {
+          agentId,
+          objective,
+          ...(options?.agentSessionKey ? { sessionKey: options.agentSessionKey } : {}),
+        });
+        const updated =
+          status || progress
+            ? await updateGoal(storePath, { agentId, id: goal.id, status, progress })
+            : null;
+        return jsonResult({ goal: formatGoal(updated ?? goal) });
+      }
+      const goal = await updateGoal(storePath, { agentId, id, status, objective, progress });
+      if (!goal) {
+        throw new ToolInputError(`unknown goal: ${id}`);
+      }
+      return jsonResult({ goal: formatGoal(goal) });
+    },
+  };
+}
+
+export function createGoalStatusTool(options?: GoalToolOptions): AnyAgentTool {
+  return {
+    label: "Goal Status",
+    name: "goal_status",
+    description:
+      "Show your standing goals with their status and latest progress. Pass id for one goal's full progress history; includeInactive also lists paused, achieved, and abandoned goals.",
+    parameters: GoalStatusSchema,
+    execute: async (_toolCallId, params) => {
+      const { storePath, agentId } = resolveGoalToolContext(options);
+      const id = readStringParam(params, "id");
+      if (id) {
+        const goal = await getGoal(storePath, { agentId, id });
+        if (!goal) {
+          throw new ToolInputError(`unknown goal: ${id}`);
+        }
+        return jsonResult({ goal: formatGoal(goal, { history: true }) });
+      }
+      const goals = await listGoals(storePath, {
+        agentId,
+        includeInactive: params.includeInactive === true,
+      });
+      return jsonResult({ goals: goals.map((goal) => formatGoal(goal)), count: goals.length });
+    },
+  };
+}
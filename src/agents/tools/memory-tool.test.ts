@@ -1,9 +1,17 @@
-import { beforeEach, describe, expect, it } from "vitest";
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
 import {
   resetMemoryToolMockState,
   setMemorySearchImpl,
 } from "../../../test/helpers/memory-tool-manager-mock.js";
-import { createMemorySearchTool } from "./memory-tool.js";
+import {
+  createMemoryGetTool,
+  createMemoryListTool,
+  createMemorySearchTool,
+  createMemorySetTool,
+} from "./memory-tool.js";
 
 describe("memory_search unavailable payloads", () => {
   beforeEach(() => {
@@ -56,3 +64,57 @@ describe("memory_search unavailable payloads", () => {
     });
   });
 });
+
+describe("memory_set / memory_list", () => {
+  let workspace = "";
+
+  beforeEach(() => {
+    resetMemoryToolMockState({ searchImpl: async () => [] });
+    workspace = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-memory-facts-"));
+  });
+
+  afterEach(() => {
+    fs.rmSync(workspace, { recursive: true, force: true });
+  });
+
+  it("saves facts that memory_get and memory_list read back", async () => {
+    const config = { agents: { list: [{ id: "main", default: true, workspace }] } };
+    const setTool = createMemorySetTool({ config });
+    const getTool = createMemoryGetTool({ config });
+    const listTool = createMemoryListTool({ config });
+    if (!setTool || !getTool || !listTool) {
+      throw new Error("tool missing");
+    }
+
+    await setTool.execute("set-1", { key: "pump relay pin", value: "GPIO 7" });
+    const replaced = await setTool.execute("set-2", { key: "Pump Relay Pin", value: "GPIO 8" });
+    await setTool.execute("set-3", { key: "heater pin", value: "GPIO 12" });
+
+    expect(replaced.details).toEqual({
+      key: "Pump Relay Pin",
+      value: "GPIO 8",
+      previous: "GPIO 7",
+      path: "memory/facts.md",
+    });
+    expect((await getTool.execute("get", { key: "pump relay pin" })).details).toEqual({
+      key: "Pump Relay Pin",
+      value: "GPIO 8",
+      found: true,
+      path: "memory/facts.md",
+    });
+    expect((await listTool.execute("list", { prefix: "heater" })).details).toEqual({
+      facts: [{ key: "heater pin", value: "GPIO 12" }],
+      count: 1,
+      path: "memory/facts.md",
+    });
+
+    await setTool.execute("forget", { key: "heater pin", value: "" });
+    expect(fs.readFileSync(path.join(workspace, "memory", "facts.md"), "utf-8")).toContain(
+      "- `Pump Relay Pin`: GPIO 8\n",
+    );
+    expect((await getTool.execute("get", { key: "heater pin" })).details).toMatchObject({
+      value: null,
+      found: false,
+    });
+  });
+});
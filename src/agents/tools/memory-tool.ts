@@ -2,10 +2,23 @@ import { Type } from "@sinclair/typebox";
 import type { OpenClawConfig } from "../../config/config.js";
 import type { MemoryCitationsMode } from "../../config/types.memory.js";
 import { resolveMemoryBackendConfig } from "../../memory/backend-config.js";
+import path from "node:path";
+import {
+  getMemoryFact,
+  MEMORY_FACTS_RELATIVE_PATH,
+  readMemoryFacts,
+  resolveMemoryFactsPath,
+  setMemoryFact,
+} from "../../memory/facts.js";
 import { getMemorySearchManager } from "../../memory/index.js";
+import { filterKbResults, ingestKbDocument, listKbDocuments } from "../../memory/kb.js";
 import type { MemorySearchResult } from "../../memory/types.js";
 import { parseAgentSessionKey } from "../../routing/session-key.js";
-import { resolveSessionAgentId } from "../agent-scope.js";
+import {
+  resolveAgentConfig,
+  resolveAgentWorkspaceDir,
+  resolveSessionAgentId,
+} from "../agent-scope.js";
 import { resolveMemorySearchConfig } from "../memory-search.js";
 import type { AnyAgentTool } from "./common.js";
 import { jsonResult, readNumberParam, readStringParam } from "./common.js";
@@ -17,11 +30,32 @@ const MemorySearchSchema = Type.Object({
 });
 
 const MemoryGetSchema = Type.Object({
-  path: Type.String(),
+  path: Type.Optional(Type.String()),
+  key: Type.Optional(Type.String()),
   from: Type.Optional(Type.Number()),
   lines: Type.Optional(Type.Number()),
 });
 
+const MemorySetSchema = Type.Object({
+  key: Type.String(),
+  value: Type.String(),
+});
+
+const MemoryListSchema = Type.Object({
+  prefix: Type.Optional(Type.String()),
+});
+
+const KbSearchSchema = Type.Object({
+  query: Type.String(),
+  document: Type.Optional(Type.String()),
+  maxResults: Type.Optional(Type.Number()),
+});
+
+const KbIngestSchema = Type.Object({
+  path: Type.String(),
+  title: Type.Optional(Type.String()),
+});
+
 function resolveMemoryToolContext(options: { config?: OpenClawConfig; agentSessionKey?: string }) {
   const cfg = options.config;
   if (!cfg) {
@@ -111,9 +145,20 @@ export function createMemoryGetTool(options: {
     label: "Memory Get",
     name: "memory_get",
     description:
-      "Safe snippet read from MEMORY.md or memory/*.md with optional from/lines; use after memory_search to pull only the needed lines and keep context small.",
+      "Safe snippet read from MEMORY.md or memory/*.md with optional from/lines; use after memory_search to pull only the needed lines and keep context small. Pass key instead of path to read one fact saved with memory_set.",
     parameters: MemoryGetSchema,
     execute: async (_toolCallId, params) => {
+      const key = readStringParam(params, "key");
+      if (key) {
+        const factsPath = resolveMemoryFactsPath(resolveAgentWorkspaceDir(cfg, agentId));
+        const fact = await getMemoryFact(factsPath, key);
+        return jsonResult({
+          key: fact?.key ?? key,
+          value: fact?.value ?? null,
+          found: Boolean(fact),
+          path: MEMORY_FACTS_RELATIVE_PATH,
+        });
+      }
       const relPath = readStringParam(params, "path", { required: true });
       const from = readNumberParam(params, "from", { integer: true });
       const lines = readNumberParam(params, "lines", { integer: true });
@@ -139,6 +184,144 @@ export function createMemoryGetTool(options: {
   };
 }
 
+export function createMemorySetTool(options: {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+}): AnyAgentTool | null {
+  const ctx = resolveMemoryToolContext(options);
+  if (!ctx) {
+    return null;
+  }
+  const { cfg, agentId } = ctx;
+  return {
+    label: "Memory Set",
+    name: "memory_set",
+    description:
+      'Save a durable fact as key/value (e.g. key "pump relay pin", value "GPIO 7") to memory/facts.md so it survives across sessions; setting an existing key replaces it, an empty value forgets it. Use when the user asks you to remember something.',
+    parameters: MemorySetSchema,
+    execute: async (_toolCallId, params) => {
+      const key = readStringParam(params, "key", { required: true });
+      const value = readStringParam(params, "value", { required: true, allowEmpty: true });
+      const factsPath = resolveMemoryFactsPath(resolveAgentWorkspaceDir(cfg, agentId));
+      const { fact, previous, removed } = await setMemoryFact(factsPath, key, value);
+      return jsonResult({
+        key: fact.key,
+        ...(fact.value ? { value: fact.value } : { removed }),
+        ...(previous !== undefined ? { previous } : {}),
+        path: MEMORY_FACTS_RELATIVE_PATH,
+      });
+    },
+  };
+}
+
+export function createMemoryListTool(options: {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+}): AnyAgentTool | null {
+  const ctx = resolveMemoryToolContext(options);
+  if (!ctx) {
+    return null;
+  }
+  const { cfg, agentId } = ctx;
+  return {
+    label: "Memory List",
+    name: "memory_list",
+    description:
+      "List facts saved with memory_set (key and value), optionally only keys starting with prefix.",
+    parameters: MemoryListSchema,
+    execute: async (_toolCallId, params) => {
+      const prefix = readStringParam(params, "prefix")?.toLowerCase();
+      const factsPath = resolveMemoryFactsPath(resolveAgentWorkspaceDir(cfg, agentId));
+      const facts = (await readMemoryFacts(factsPath)).filter(
+        (fact) => !prefix || fact.key.toLowerCase().startsWith(prefix),
+      );
+      return jsonResult({ facts, count: facts.length, path: MEMORY_FACTS_RELATIVE_PATH });
+    },
+  };
+}
+
+export function createKbSearchTool(options: {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+}): AnyAgentTool | null {
+  const ctx = resolveMemoryToolContext(options);
+  if (!ctx) {
+    return null;
+  }
+  const { cfg, agentId } = ctx;
+  return {
+    label: "Knowledge Base Search",
+    name: "kb_search",
+    description:
+      "Search ingested reference documents (datasheets, pinouts, manuals in memory/kb/) before answering wiring, pin, register-map, or spec questions; returns snippets with document path + lines (PDF text is split by '## Page N'). Optional document narrows to one title. Quote the source page; with no results, list documents with an empty query and say so rather than guessing.",
+    parameters: KbSearchSchema,
+    execute: async (_toolCallId, params) => {
+      const query = readStringParam(params, "query", { required: true, allowEmpty: true });
+      const document = readStringParam(params, "document");
+      const maxResults = readNumberParam(params, "maxResults", { integer: true }) ?? 6;
+      const documents = await listKbDocuments(resolveAgentWorkspaceDir(cfg, agentId));
+      if (!query.trim()) {
+        return jsonResult({ results: [], documents });
+      }
+      const { manager, error } = await getMemorySearchManager({ cfg, agentId });
+      if (!manager) {
+        return jsonResult(buildMemorySearchUnavailableResult(error));
+      }
+      try {
+        // Memory files share the index; over-fetch so KB hits survive the path filter.
+        const raw = await manager.search(query, {
+          maxResults: maxResults * 4,
+          sessionKey: options.agentSessionKey,
+        });
+        const results = decorateCitations(filterKbResults(raw, { document, maxResults }), true);
+        return jsonResult({ results, documents: documents.map((entry) => entry.title) });
+      } catch (err) {
+        const message = err instanceof Error ? err.message : String(err);
+        return jsonResult(buildMemorySearchUnavailableResult(message));
+      }
+    },
+  };
+}
+
+export function createKbIngestTool(options: {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+}): AnyAgentTool | null {
+  const ctx = resolveMemoryToolContext(options);
+  if (!ctx) {
+    return null;
+  }
+  const { cfg, agentId } = ctx;
+  return {
+    label: "Knowledge Base Ingest",
+    name: "kb_ingest",
+    description:
+      "Add a PDF, Markdown, or text document (sensor datasheet, board pinout, manual) to the knowledge base so kb_search can find it. path is a local file (relative paths resolve against the workspace); re-ingesting the same title replaces it.",
+    parameters: KbIngestSchema,
+    execute: async (_toolCallId, params) => {
+      const filePath = readStringParam(params, "path", { required: true });
+      const title = readStringParam(params, "title");
+      const workspaceDir = resolveAgentWorkspaceDir(cfg, agentId);
+      const workspaceOnly =
+        resolveAgentConfig(cfg, agentId)?.tools?.fs?.workspaceOnly ??
+        cfg.tools?.fs?.workspaceOnly;
+      const resolved = path.resolve(workspaceDir, filePath);
+      const relative = path.relative(workspaceDir, resolved);
+      if (workspaceOnly && (relative.startsWith("..") || path.isAbsolute(relative))) {
+        throw new Error("path must be inside the workspace (tools.fs.workspaceOnly)");
+      }
+      const result = await ingestKbDocument({ workspaceDir, filePath: resolved, title });
+      const { manager } = await getMemorySearchManager({ cfg, agentId });
+      let indexed = false;
+      if (manager?.sync) {
+        await manager.sync({ reason: "kb-ingest" });
+        indexed = true;
+      }
+      return jsonResult({ ...result, indexed });
+    },
+  };
+}
+
 function resolveMemoryCitationsMode(cfg: OpenClawConfig): MemoryCitationsMode {
   const mode = cfg.memory?.citations;
   if (mode === "on" || mode === "off" || mode === "auto") {
@@ -0,0 +1,70 @@
+import { describe, expect, it, vi } from "vitest";
+import { createRemindMeTool, resolveReminderTimezone } from "./remind-tool.js";
+
+const config = {
+  agents: { defaults: { userTimezone: "UTC" } },
+  session: {
+    userProfiles: { alex: { identities: ["telegram:123"], timezone: "Europe/Berlin" } },
+  },
+};
+// 2026-10-16 12:00 in Berlin.
+const now = () => Date.parse("2026-10-16T10:00:00Z");
+
+describe("remind_me", () => {
+  it("resolves times in the sender's profile timezone and delivers to the chat", async () => {
+    const callGatewayTool = vi.fn().mockResolvedValue({ id: "job-7" });
+    const tool = createRemindMeTool(
+      {
+        config,
+        agentSessionKey: "agent:main:telegram:direct:123",
+        agentChannel: "telegram",
+        agentTo: "123",
+        requesterSenderId: "123",
+      },
+      { callGatewayTool, now },
+    );
+
+    const result = await tool.execute("call", { text: "water the seedlings", when: "6pm" });
+
+    expect(result.details).toMatchObject({
+      id: "job-7",
+      at: "2026-10-16T16:00:00.000Z",
+      timeZone: "Europe/Berlin",
+      deliverTo: "telegram:123",
+    });
+    const [method, , job] = callGatewayTool.mock.calls[0] ?? [];
+    expect(method).toBe("cron.add");
+    expect(job).toMatchObject({
+      name: "Reminder: water the seedlings",
+      deleteAfterRun: true,
+      schedule: { kind: "at", at: "2026-10-16T16:00:00.000Z" },
+      sessionTarget: "isolated",
+      payload: { kind: "agentTurn" },
+      delivery: { mode: "announce", channel: "telegram", to: "123" },
+    });
+  });
+
+  it("wakes the main session when there is no chat to deliver to", async () => {
+    const callGatewayTool = vi.fn().mockResolvedValue({ id: "job-8" });
+    const tool = createRemindMeTool(
+      { config, agentSessionKey: "agent:main:main", agentChannel: "webchat" },
+      { callGatewayTool, now },
+    );
+
+    await tool.execute("call", { text: "stretch", when: "in 20 minutes" });
+
+    expect(callGatewayTool.mock.calls[0]?.[2]).toMatchObject({
+      sessionTarget: "main",
+      payload: { kind: "systemEvent", text: "Reminder the user asked for: stretch" },
+    });
+    await expect(tool.execute("bad", { text: "x", when: "whenever" })).rejects.toThrow(
+      'could not understand time "whenever"',
+    );
+  });
+
+  it("falls back to the default user timezone", () => {
+    expect(
+      resolveReminderTimezone({ config, agentChannel: "telegram", requesterSenderId: "9" }),
+    ).toBe("UTC");
+  });
+});
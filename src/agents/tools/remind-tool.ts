@@ -0,0 +1,122 @@
+import { Type } from "@sinclair/typebox";
+import { resolveUserProfile } from "../../auto-reply/reply/user-profile.js";
+import type { OpenClawConfig } from "../../config/config.js";
+import { parseNaturalTime } from "../../cron/natural-time.js";
+import type { CronDelivery, CronJobCreate } from "../../cron/types.js";
+import { isDeliverableMessageChannel } from "../../utils/message-channel.js";
+import { resolveSessionAgentId } from "../agent-scope.js";
+import { resolveUserTimezone } from "../date-time.js";
+import { type AnyAgentTool, jsonResult, readStringParam, ToolInputError } from "./common.js";
+import { inferDeliveryFromSessionKey } from "./cron-tool.js";
+import { callGatewayTool } from "./gateway.js";
+
+const RemindMeSchema = Type.Object({
+  text: Type.String({ description: "What to remind the user about" }),
+  when: Type.String({
+    description:
+      'When, in the user\'s words: "in 20 minutes", "6pm", "tomorrow 9am", "next friday at 8:30pm", or "2026-03-01 09:00"',
+  }),
+});
+
+type RemindToolOptions = {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+  agentChannel?: string;
+  agentTo?: string;
+  requesterSenderId?: string;
+};
+
+type RemindToolDeps = {
+  callGatewayTool?: typeof callGatewayTool;
+  now?: () => number;
+};
+
+/** Requester's profile timezone, then agents.defaults.userTimezone, then the host zone. */
+export function resolveReminderTimezone(options?: RemindToolOptions): string {
+  const cfg = options?.config ?? {};
+  const profile = options?.requesterSenderId
+    ? resolveUserProfile(cfg, {
+        Surface: options.agentChannel,
+        SenderId: options.requesterSenderId,
+      })
+    : undefined;
+  return resolveUserTimezone(profile?.profile.timezone ?? cfg.agents?.defaults?.userTimezone);
+}
+
+function resolveReminderDelivery(options?: RemindToolOptions): CronDelivery | null {
+  const channel = options?.agentChannel?.trim();
+  const to = options?.agentTo?.trim();
+  if (channel && to && isDeliverableMessageChannel(channel)) {
+    return { mode: "announce", channel, to };
+  }
+  return inferDeliveryFromSessionKey(options?.agentSessionKey);
+}
+
+export function createRemindMeTool(
+  options?: RemindToolOptions,
+  deps?: RemindToolDeps,
+): AnyAgentTool {
+  const callGateway = deps?.callGatewayTool ?? callGatewayTool;
+  return {
+    label: "Remind Me",
+    name: "remind_me",
+    description:
+      "Schedule a one-off reminder for the user. Natural times are resolved in the user's timezone (profile, else agents.defaults.userTimezone) and the reminder is delivered to this chat. Persists across restarts; manage or cancel it with the cron tool.",
+    parameters: RemindMeSchema,
+    execute: async (_toolCallId, params) => {
+      const text = readStringParam(params, "text", { required: true });
+      const when = readStringParam(params, "when", { required: true });
+      const timeZone = resolveReminderTimezone(options);
+      const nowMs = deps?.now?.() ?? Date.now();
+      const at = parseNaturalTime(when, { timeZone, nowMs });
+      if (at === null) {
+        throw new ToolInputError(
+          `could not understand time "${when}"; try "in 20 minutes", "6pm", or "tomorrow 9am"`,
+        );
+      }
+      if (at <= nowMs) {
+        throw new ToolInputError(`time "${when}" is in the past (${timeZone})`);
+      }
+      const agentId = resolveSessionAgentId({
+        sessionKey: options?.agentSessionKey,
+        config: options?.config,
+      });
+      const delivery = resolveReminderDelivery(options);
+      const base = {
+        name: `Reminder: ${text}`,
+        agentId,
+        ...(options?.agentSessionKey ? { sessionKey: options.agentSessionKey } : {}),
+        enabled: true,
+        deleteAfterRun: true,
+        schedule: { kind: "at" as const, at: new Date(at).toISOString() },
+      };
+      // No chat to deliver to (e.g. the shared main session): wake the session instead so the
+      // reply goes out on its last route.
+      const job: CronJobCreate = delivery
+        ? {
+            ...base,
+            sessionTarget: "isolated",
+            wakeMode: "now",
+            payload: {
+              kind: "agentTurn",
+              message: `Send the user this reminder they asked for, briefly and in their language: ${text}`,
+            },
+            delivery,
+          }
+        : {
+            ...base,
+            sessionTarget: "main",
+            wakeMode: "now",
+            payload: { kind: "systemEvent", text: `Reminder the user asked for: ${text}` },
+          };
+      const created = await callGateway<{ id?: string }>("cron.add", {}, job);
+      return jsonResult({
+        id: created?.id,
+        at: new Date(at).toISOString(),
+        local: new Date(at).toLocaleString("en-US", { timeZone, timeZoneName: "short" }),
+        timeZone,
+        deliverTo: delivery ? `${delivery.channel ?? "last"}:${delivery.to}` : "session",
+      });
+    },
+  };
+}
@@ -3,7 +3,7 @@ import type { GatewayMessageChannel } from "../../utils/message-channel.js";
 import { optionalStringEnum } from "../schema/typebox.js";
 import { SUBAGENT_SPAWN_MODES, spawnSubagentDirect } from "../subagent-spawn.js";
 import type { AnyAgentTool } from "./common.js";
-import { jsonResult, readStringParam } from "./common.js";
+import { jsonResult, readStringArrayParam, readStringParam } from "./common.js";
 
 const SessionsSpawnToolSchema = Type.Object({
   task: Type.String(),
@@ -17,6 +17,8 @@ const SessionsSpawnToolSchema = Type.Object({
   thread: Type.Optional(Type.Boolean()),
   mode: optionalStringEnum(SUBAGENT_SPAWN_MODES),
   cleanup: optionalStringEnum(["delete", "keep"] as const),
+  tools: Type.Optional(Type.Array(Type.String())),
+  systemPrompt: Type.Optional(Type.String()),
 });
 
 export function createSessionsSpawnTool(opts?: {
@@ -36,7 +38,7 @@ export function createSessionsSpawnTool(opts?: {
     label: "Sessions",
     name: "sessions_spawn",
     description:
-      'Spawn a sub-agent in an isolated session (mode="run" one-shot or mode="session" persistent) and route results back to the requester chat/thread.',
+      'Spawn a sub-agent in an isolated session (mode="run" one-shot or mode="session" persistent) and route results back to the requester chat/thread. Optional `tools` limits the sub-agent to a tool subset; `systemPrompt` adds role instructions; runTimeoutSeconds bounds the run.',
     parameters: SessionsSpawnToolSchema,
     execute: async (_toolCallId, args) => {
       const params = args as Record<string, unknown>;
@@ -60,6 +62,8 @@ export function createSessionsSpawnTool(opts?: {
           ? Math.max(0, Math.floor(timeoutSecondsCandidate))
           : undefined;
       const thread = params.thread === true;
+      const tools = readStringArrayParam(params, "tools");
+      const systemPrompt = readStringParam(params, "systemPrompt");
 
       const result = await spawnSubagentDirect(
         {
@@ -73,6 +77,8 @@ export function createSessionsSpawnTool(opts?: {
           mode,
           cleanup,
           expectsCompletionMessage: true,
+          tools,
+          systemPrompt,
         },
         {
           agentSessionKey: opts?.agentSessionKey,
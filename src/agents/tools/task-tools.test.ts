@@ -0,0 +1,97 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import {
+  createTaskAddTool,
+  createTaskCompleteTool,
+  createTaskListTool,
+  resolveTaskDueAt,
+} from "./task-tools.js";
+
+describe("task tools", () => {
+  let dir = "";
+  let storePath = "";
+  const callGatewayTool = vi.fn();
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-tasks-"));
+    storePath = path.join(dir, "tasks.json");
+    callGatewayTool.mockReset();
+    callGatewayTool.mockResolvedValue({ id: "job-1" });
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  const options = () => ({ agentSessionKey: "agent:main:main", storePath });
+
+  it("lists open tasks by priority, then due time", async () => {
+    const add = createTaskAddTool(options(), { callGatewayTool });
+    await add.execute("1", { title: "tidy bench", priority: "low" });
+    await add.execute("2", { title: "order flux" });
+    await add.execute("3", { title: "recheck iron temp", priority: "high", due: "10m" });
+    await add.execute("4", { title: "check fume fan", priority: "high", due: "5m" });
+
+    const result = await createTaskListTool(options()).execute("list", {});
+    const titles = (result.details as { tasks: Array<{ title: string }> }).tasks.map(
+      (task) => task.title,
+    );
+    expect(titles).toEqual(["check fume fan", "recheck iron temp", "order flux", "tidy bench"]);
+    expect(
+      await createTaskListTool({ agentSessionKey: "agent:ops:main", storePath }).execute("ops", {}),
+    ).toMatchObject({ details: { count: 0 } });
+  });
+
+  it("schedules a one-shot wake for due tasks", async () => {
+    const before = Date.now();
+    const result = await createTaskAddTool(options(), { callGatewayTool }).execute("add", {
+      title: "recheck the soldering iron temp",
+      due: "10m",
+    });
+
+    expect(result.details).toMatchObject({ reminder: "scheduled" });
+    const [method, , job] = callGatewayTool.mock.calls[0] ?? [];
+    expect(method).toBe("cron.add");
+    expect(job).toMatchObject({
+      agentId: "main",
+      sessionKey: "agent:main:main",
+      deleteAfterRun: true,
+      sessionTarget: "main",
+      wakeMode: "now",
+      payload: { kind: "systemEvent" },
+    });
+    const at = Date.parse((job as { schedule: { at: string } }).schedule.at);
+    expect(at).toBeGreaterThanOrEqual(before + 10 * 60_000);
+    const stored = JSON.parse(fs.readFileSync(storePath, "utf-8"));
+    expect(stored.tasks[0]).toMatchObject({ reminderJobId: "job-1", status: "open" });
+  });
+
+  it("completes tasks and drops pending reminders", async () => {
+    const added = await createTaskAddTool(options(), { callGatewayTool }).execute("add", {
+      title: "recheck iron temp",
+      due: "1h",
+    });
+    const id = (added.details as { task: { id: string } }).task.id;
+
+    const done = await createTaskCompleteTool(options(), { callGatewayTool }).execute("done", {
+      id,
+      result: "holding at 330°C",
+    });
+
+    expect(done.details).toMatchObject({ task: { status: "done", result: "holding at 330°C" } });
+    expect(callGatewayTool).toHaveBeenLastCalledWith("cron.remove", {}, { id: "job-1" });
+    const open = await createTaskListTool(options()).execute("list", {});
+    expect(open.details).toMatchObject({ count: 0 });
+    await expect(
+      createTaskCompleteTool(options(), { callGatewayTool }).execute("again", { id: "nope" }),
+    ).rejects.toThrow("unknown task: nope");
+  });
+
+  it("parses relative and absolute due times", () => {
+    expect(resolveTaskDueAt("90s", 1_000)).toBe(91_000);
+    expect(resolveTaskDueAt("2026-03-01T09:00:00Z")).toBe(Date.parse("2026-03-01T09:00:00Z"));
+    expect(() => resolveTaskDueAt("soon")).toThrow("invalid due time");
+  });
+});
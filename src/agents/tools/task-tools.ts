@@ -0,0 +1,191 @@
+import { Type } from "@sinclair/typebox";
+import { parseDurationMs } from "../../cli/parse-duration.js";
+import type { OpenClawConfig } from "../../config/config.js";
+import { parseAbsoluteTimeMs } from "../../cron/parse.js";
+import type { CronJobCreate } from "../../cron/types.js";
+import {
+  addTask,
+  completeTask,
+  DEFAULT_TASK_STORE_PATH,
+  listTasks,
+  TASK_PRIORITIES,
+  updateTask,
+  type AgentTask,
+  type TaskPriority,
+} from "../../tasks/store.js";
+import { resolveSessionAgentId } from "../agent-scope.js";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { type AnyAgentTool, jsonResult, readStringParam, ToolInputError } from "./common.js";
+import { callGatewayTool } from "./gateway.js";
+
+const TaskAddSchema = Type.Object({
+  title: Type.String(),
+  notes: Type.Optional(Type.String()),
+  priority: optionalStringEnum(TASK_PRIORITIES),
+  due: Type.Optional(
+    Type.String({ description: 'Delay like "10m" / "2h", or an ISO time like 2026-03-01T09:00' }),
+  ),
+});
+
+const TaskListSchema = Type.Object({
+  includeDone: Type.Optional(Type.Boolean()),
+});
+
+const TaskCompleteSchema = Type.Object({
+  id: Type.String(),
+  result: Type.Optional(Type.String()),
+});
+
+type TaskToolOptions = {
+  config?: OpenClawConfig;
+  agentSessionKey?: string;
+  storePath?: string;
+};
+
+type TaskToolDeps = {
+  callGatewayTool?: typeof callGatewayTool;
+};
+
+function resolveTaskToolContext(options?: TaskToolOptions) {
+  return {
+    storePath: options?.storePath ?? DEFAULT_TASK_STORE_PATH,
+    agentId: resolveSessionAgentId({
+      sessionKey: options?.agentSessionKey,
+      config: options?.config,
+    }),
+  };
+}
+
+export function resolveTaskDueAt(raw: string, now = Date.now()): number {
+  const trimmed = raw.trim();
+  if (/^\d+(?:\.\d+)?(ms|s|m|h|d)$/i.test(trimmed)) {
+    return now + parseDurationMs(trimmed);
+  }
+  const absolute = parseAbsoluteTimeMs(trimmed);
+  if (absolute === null) {
+    throw new ToolInputError(`invalid due time: ${raw}`);
+  }
+  return absolute;
+}
+
+function formatTask(task: AgentTask) {
+  return {
+    id: task.id,
+    title: task.title,
+    priority: task.priority,
+    status: task.status,
+    ...(task.notes ? { notes: task.notes } : {}),
+    ...(task.dueAt !== undefined ? { due: new Date(task.dueAt).toISOString() } : {}),
+    ...(task.result ? { result: task.result } : {}),
+  };
+}
+
+export function createTaskAddTool(options?: TaskToolOptions, deps?: TaskToolDeps): AnyAgentTool {
+  const callGateway = deps?.callGatewayTool ?? callGatewayTool;
+  return {
+    label: "Task Add",
+    name: "task_add",
+    description:
+      'Add a follow-up to your persistent task queue (survives restarts). With due ("10m", "2h", or an ISO time) the scheduler wakes this session with a reminder when it is due. Finish tasks with task_complete.',
+    parameters: TaskAddSchema,
+    execute: async (_toolCallId, params) => {
+      const { storePath, agentId } = resolveTaskToolContext(options);
+      const title = readStringParam(params, "title", { required: true });
+      const notes = readStringParam(params, "notes");
+      const dueRaw = readStringParam(params, "due");
+      const priority: TaskPriority =
+        params.priority === "high" || params.priority === "low" ? params.priority : "normal";
+      const dueAt = dueRaw ? resolveTaskDueAt(dueRaw) : undefined;
+      const task = await addTask(storePath, {
+        agentId,
+        title,
+        priority,
+        ...(notes ? { notes } : {}),
+        ...(dueAt !== undefined ? { dueAt } : {}),
+        ...(options?.agentSessionKey ? { sessionKey: options.agentSessionKey } : {}),
+      });
+      if (dueAt === undefined) {
+        return jsonResult({ task: formatTask(task) });
+      }
+      const job: CronJobCreate = {
+        name: `Task: ${title}`,
+        agentId,
+        ...(options?.agentSessionKey ? { sessionKey: options.agentSessionKey } : {}),
+        enabled: true,
+        deleteAfterRun: true,
+        schedule: { kind: "at", at: new Date(dueAt).toISOString() },
+        sessionTarget: "main",
+        wakeMode: "now",
+        payload: {
+          kind: "systemEvent",
+          text: `Task due (${priority}): ${title} [task ${task.id}]. Handle it, then call task_complete.`,
+        },
+      };
+      try {
+        const created = await callGateway<{ id?: string }>("cron.add", {}, job);
+        if (created?.id) {
+          await updateTask(storePath, {
+            agentId,
+            id: task.id,
+            patch: { reminderJobId: created.id },
+          });
+        }
+        return jsonResult({ task: formatTask(task), reminder: "scheduled" });
+      } catch (err) {
+        return jsonResult({
+          task: formatTask(task),
+          reminder: "failed",
+          error: err instanceof Error ? err.message : String(err),
+        });
+      }
+    },
+  };
+}
+
+export function createTaskListTool(options?: TaskToolOptions): AnyAgentTool {
+  return {
+    label: "Task List",
+    name: "task_list",
+    description:
+      "List your open tasks ordered by priority, then due time. Set includeDone to also see completed ones.",
+    parameters: TaskListSchema,
+    execute: async (_toolCallId, params) => {
+      const { storePath, agentId } = resolveTaskToolContext(options);
+      const tasks = await listTasks(storePath, {
+        agentId,
+        includeDone: params.includeDone === true,
+      });
+      return jsonResult({ tasks: tasks.map(formatTask), count: tasks.length });
+    },
+  };
+}
+
+export function createTaskCompleteTool(
+  options?: TaskToolOptions,
+  deps?: TaskToolDeps,
+): AnyAgentTool {
+  const callGateway = deps?.callGatewayTool ?? callGatewayTool;
+  return {
+    label: "Task Complete",
+    name: "task_complete",
+    description: "Mark a task from task_list as done, with an optional short result.",
+    parameters: TaskCompleteSchema,
+    execute: async (_toolCallId, params) => {
+      const { storePath, agentId } = resolveTaskToolContext(options);
+      const id = readStringParam(params, "id", { required: true });
+      const task = await completeTask(storePath, {
+        agentId,
+        id,
+        result: readStringParam(params, "result"),
+      });
+      if (!task) {
+        throw new ToolInputError(`unknown task: ${id}`);
+      }
+      if (task.reminderJobId && (task.dueAt ?? 0) > Date.now()) {
+        // Completed early: drop the pending reminder. Fired one-shot jobs delete themselves.
+        await callGateway("cron.remove", {}, { id: task.reminderJobId }).catch(() => undefined);
+      }
+      return jsonResult({ task: formatTask(task) });
+    },
+  };
+}
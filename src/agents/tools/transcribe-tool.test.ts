@@ -0,0 +1,41 @@
+import path from "node:path";
+import { describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+
+const transcribeAudioFile = vi.fn();
+vi.mock("../../media-understanding/transcribe-file.js", () => ({ transcribeAudioFile }));
+
+const { createTranscribeTool } = await import("./transcribe-tool.js");
+
+describe("createTranscribeTool", () => {
+  it("resolves workspace-relative paths and returns the transcript", async () => {
+    transcribeAudioFile.mockResolvedValueOnce({
+      ok: true,
+      text: "hello board",
+      provider: "openai",
+      model: "whisper-1",
+    });
+    const workspaceDir = path.resolve("/tmp/openclaw-ws");
+    const tool = createTranscribeTool({ config: {} as OpenClawConfig, workspaceDir });
+
+    const result = await tool.execute("call1", { audio: "recordings/clip.wav" });
+
+    expect(transcribeAudioFile).toHaveBeenCalledWith(
+      expect.objectContaining({
+        source: path.join(workspaceDir, "recordings", "clip.wav"),
+        localRoots: [workspaceDir],
+      }),
+    );
+    expect(result.content).toEqual([{ type: "text", text: "hello board" }]);
+    expect(result.details).toEqual({ provider: "openai", model: "whisper-1" });
+  });
+
+  it("surfaces pipeline errors as text", async () => {
+    transcribeAudioFile.mockResolvedValueOnce({ ok: false, error: "No transcription available" });
+    const tool = createTranscribeTool({ config: {} as OpenClawConfig });
+
+    const result = await tool.execute("call2", { audio: "https://example.com/a.mp3" });
+
+    expect(result.details).toEqual({ error: "No transcription available" });
+  });
+});
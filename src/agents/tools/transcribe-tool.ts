@@ -0,0 +1,58 @@
+import path from "node:path";
+import { Type } from "@sinclair/typebox";
+import type { OpenClawConfig } from "../../config/config.js";
+import { loadConfig } from "../../config/config.js";
+import { transcribeAudioFile } from "../../media-understanding/transcribe-file.js";
+import type { AnyAgentTool } from "./common.js";
+import { readStringParam } from "./common.js";
+
+const TranscribeToolSchema = Type.Object({
+  audio: Type.String({ description: "Audio file path (workspace-relative or absolute) or URL." }),
+  mime: Type.Optional(
+    Type.String({ description: "MIME type hint when the file has no audio extension." }),
+  ),
+});
+
+export function createTranscribeTool(opts?: {
+  config?: OpenClawConfig;
+  agentDir?: string;
+  workspaceDir?: string;
+}): AnyAgentTool {
+  return {
+    label: "Transcribe",
+    name: "transcribe",
+    description:
+      "Transcribe an audio recording (voice memo, node audio capture, call recording) to text using the configured speech-to-text provider (tools.media.audio; OpenAI Whisper or local whisper.cpp when available).",
+    parameters: TranscribeToolSchema,
+    execute: async (_toolCallId, args) => {
+      const params = args as Record<string, unknown>;
+      const raw = readStringParam(params, "audio", { required: true });
+      const mime = readStringParam(params, "mime");
+      const cfg = opts?.config ?? loadConfig();
+      const audio = raw.startsWith("@") ? raw.slice(1).trim() : raw;
+      const workspaceDir = opts?.workspaceDir;
+      const source =
+        /^https?:\/\//i.test(audio) || path.isAbsolute(audio) || !workspaceDir
+          ? audio
+          : path.resolve(workspaceDir, audio);
+      const result = await transcribeAudioFile({
+        cfg,
+        source,
+        mime,
+        agentDir: opts?.agentDir,
+        localRoots: workspaceDir ? [workspaceDir] : undefined,
+      });
+
+      if (result.ok) {
+        return {
+          content: [{ type: "text", text: result.text }],
+          details: { provider: result.provider, model: result.model },
+        };
+      }
+      return {
+        content: [{ type: "text", text: result.error }],
+        details: { error: result.error },
+      };
+    },
+  };
+}
@@ -0,0 +1,62 @@
+import { Type } from "@sinclair/typebox";
+import { optionalStringEnum } from "../schema/typebox.js";
+import { describeUndoEntry, listUndoEntries, undoLastAction } from "../undo-journal.js";
+import { type AnyAgentTool, jsonResult, readStringParam } from "./common.js";
+
+const UndoLastSchema = Type.Object({
+  action: optionalStringEnum(["undo", "list"] as const, {
+    description: "undo (default) restores the last change; list shows what can be undone",
+  }),
+});
+
+type UndoToolOptions = {
+  agentSessionKey?: string;
+  /** Looks up the tool an undo entry replays (same tool list as the agent run). */
+  resolveTool: (name: string) => AnyAgentTool | undefined;
+};
+
+export function createUndoLastTool(options: UndoToolOptions): AnyAgentTool {
+  return {
+    label: "Undo Last",
+    name: "undo_last",
+    description:
+      "Restore the state before the most recent reversible hardware action in this session (GPIO level, PWM duty, LED state, or any tool that reported its prior state). Use when the user says you flipped the wrong output or asks to undo; action=list shows the undo history first.",
+    parameters: UndoLastSchema,
+    execute: async (_toolCallId, params, signal) => {
+      const sessionKey = options.agentSessionKey;
+      if (!sessionKey) {
+        return jsonResult({ status: "error", error: "undo requires a session" });
+      }
+      if (readStringParam(params, "action") === "list") {
+        const entries = await listUndoEntries(sessionKey);
+        return jsonResult({
+          entries: entries.map((entry) => ({
+            undo: describeUndoEntry(entry),
+            tool: entry.tool,
+            recordedAt: new Date(entry.recordedAt).toISOString(),
+          })),
+        });
+      }
+      const outcome = await undoLastAction({
+        sessionKey,
+        resolveTool: options.resolveTool,
+        signal,
+      });
+      if (!outcome.ok) {
+        return jsonResult({
+          status: "error",
+          error:
+            outcome.reason === "empty"
+              ? "nothing to undo in this session"
+              : `tool ${outcome.entry?.tool} is not available to replay the undo`,
+        });
+      }
+      return jsonResult({
+        status: "ok",
+        undone: describeUndoEntry(outcome.entry),
+        tool: outcome.entry.tool,
+        result: outcome.result.details ?? outcome.result.content,
+      });
+    },
+  };
+}
@@ -0,0 +1,113 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { createUndoToolResolver } from "./openclaw-tools.js";
+import type { AnyAgentTool } from "./tools/common.js";
+import {
+  listUndoEntries,
+  readToolUndoAction,
+  recordUndoAction,
+  undoLastAction,
+} from "./undo-journal.js";
+
+let tmpDir: string;
+let filePath: string;
+
+beforeEach(async () => {
+  tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-undo-"));
+  filePath = path.join(tmpDir, "undo", "journal.json");
+});
+
+afterEach(async () => {
+  await fs.rm(tmpDir, { recursive: true, force: true });
+});
+
+function fakeTool(name: string, execute: AnyAgentTool["execute"]): AnyAgentTool {
+  return { name, label: name, description: "", parameters: {}, execute } as AnyAgentTool;
+}
+
+describe("undo journal", () => {
+  it("reads the undo action a tool reports in its details", () => {
+    expect(
+      readToolUndoAction({
+        content: [],
+        details: { level: 1, undo: { params: { pin: 17, level: 0 }, description: "GPIO 17 low" } },
+      }),
+    ).toEqual({ params: { pin: 17, level: 0 }, description: "GPIO 17 low" });
+    expect(readToolUndoAction({ content: [], details: { undo: "nope" } })).toBeUndefined();
+    expect(readToolUndoAction({ content: [] })).toBeUndefined();
+  });
+
+  it("replays the newest entry and removes it once the undo succeeds", async () => {
+    const sessionKey = "agent:main:main";
+    await recordUndoAction({
+      sessionKey,
+      sourceTool: "gpio_write",
+      action: { params: { pin: 17, level: 0 }, description: "GPIO 17 back to low" },
+      filePath,
+    });
+    await recordUndoAction({
+      sessionKey,
+      sourceTool: "pwm_set",
+      action: { params: { channel: 0, duty: 0.25 }, description: "PWM 0 back to 25%" },
+      filePath,
+    });
+    expect((await listUndoEntries(sessionKey, { filePath })).map((e) => e.tool)).toEqual([
+      "pwm_set",
+      "gpio_write",
+    ]);
+
+    const pwmSet = vi.fn(async () => ({ content: [], details: { ok: true } }));
+    const gpioWrite = vi
+      .fn<AnyAgentTool["execute"]>()
+      .mockRejectedValueOnce(new Error("bus busy"))
+      .mockResolvedValue({ content: [], details: { ok: true } });
+    const tools = [fakeTool("pwm_set", pwmSet), fakeTool("gpio_write", gpioWrite)];
+    const resolveTool = (name: string) => tools.find((tool) => tool.name === name);
+
+    const first = await undoLastAction({ sessionKey, resolveTool, filePath });
+    expect(first).toMatchObject({ ok: true, entry: { description: "PWM 0 back to 25%" } });
+    expect(pwmSet).toHaveBeenCalledWith(
+      expect.stringMatching(/^undo:/),
+      { channel: 0, duty: 0.25 },
+      undefined,
+    );
+
+    // A failed undo keeps the entry so it can be retried.
+    await expect(undoLastAction({ sessionKey, resolveTool, filePath })).rejects.toThrow("bus busy");
+    expect(await listUndoEntries(sessionKey, { filePath })).toHaveLength(1);
+    await expect(undoLastAction({ sessionKey, resolveTool, filePath })).resolves.toMatchObject({
+      ok: true,
+    });
+    await expect(undoLastAction({ sessionKey, resolveTool, filePath })).resolves.toEqual({
+      ok: false,
+      reason: "empty",
+    });
+  });
+
+  it("runs replays through guardrails so an undo cannot set a forbidden value", async () => {
+    const sessionKey = "agent:main:main";
+    await recordUndoAction({
+      sessionKey,
+      sourceTool: "pwm_set",
+      action: { params: { channel: 0, duty: 90 }, description: "heater back to 90%" },
+      filePath,
+    });
+    const pwmSet = vi.fn(async () => ({ content: [], details: { ok: true } }));
+    const config = {
+      tools: { guardrails: [{ tools: ["pwm_set"], param: "duty", max: 60 }] },
+    } as OpenClawConfig;
+    const resolveTool = createUndoToolResolver(() => [fakeTool("pwm_set", pwmSet)], {
+      config,
+      sessionKey,
+    });
+
+    await expect(undoLastAction({ sessionKey, resolveTool, filePath })).rejects.toThrow(
+      "outside the allowed range",
+    );
+    expect(pwmSet).not.toHaveBeenCalled();
+    expect(await listUndoEntries(sessionKey, { filePath })).toHaveLength(1);
+  });
+});
@@ -0,0 +1,150 @@
+import { randomUUID } from "node:crypto";
+import path from "node:path";
+import { resolveStateDir } from "../config/paths.js";
+import { createAsyncLock, readJsonFile, writeJsonAtomic } from "../infra/json-files.js";
+import { isPlainObject } from "../utils.js";
+import type { AnyAgentTool } from "./tools/common.js";
+
+/**
+ * Returned by a reversible tool in `result.details.undo`: the call that restores the state it
+ * just changed (e.g. `{ params: { pin: 17, level: 0 }, description: "GPIO 17 back to low" }`).
+ * `tool` defaults to the tool that produced the result.
+ */
+export type ToolUndoAction = {
+  tool?: string;
+  params: Record<string, unknown>;
+  description?: string;
+};
+
+export type UndoJournalEntry = {
+  id: string;
+  tool: string;
+  params: Record<string, unknown>;
+  description?: string;
+  /** Tool whose call this entry reverts. */
+  sourceTool: string;
+  recordedAt: number;
+};
+
+type UndoJournalFile = {
+  version: 1;
+  sessions: Record<string, UndoJournalEntry[]>;
+};
+
+const MAX_ENTRIES_PER_SESSION = 20;
+
+/** Tool call ids used when replaying an undo; results of those calls are not journaled again. */
+export const UNDO_TOOL_CALL_PREFIX = "undo:";
+
+const withJournalLock = createAsyncLock();
+
+export function resolveUndoJournalPath(stateDir = resolveStateDir()): string {
+  return path.join(stateDir, "undo", "journal.json");
+}
+
+async function readJournal(filePath: string): Promise<UndoJournalFile> {
+  const raw = await readJsonFile<UndoJournalFile>(filePath);
+  return raw?.version === 1 && isPlainObject(raw.sessions) ? raw : { version: 1, sessions: {} };
+}
+
+export function readToolUndoAction(result: unknown): ToolUndoAction | undefined {
+  const details = (result as { details?: unknown } | undefined)?.details;
+  const undo = isPlainObject(details) ? details.undo : undefined;
+  if (!isPlainObject(undo) || !isPlainObject(undo.params)) {
+    return undefined;
+  }
+  return {
+    ...(typeof undo.tool === "string" && undo.tool.trim() ? { tool: undo.tool.trim() } : {}),
+    params: undo.params,
+    ...(typeof undo.description === "string" ? { description: undo.description } : {}),
+  };
+}
+
+export async function recordUndoAction(params: {
+  sessionKey: string;
+  sourceTool: string;
+  action: ToolUndoAction;
+  now?: number;
+  filePath?: string;
+}): Promise<UndoJournalEntry> {
+  const filePath = params.filePath ?? resolveUndoJournalPath();
+  const entry: UndoJournalEntry = {
+    id: randomUUID(),
+    tool: params.action.tool ?? params.sourceTool,
+    params: params.action.params,
+    ...(params.action.description ? { description: params.action.description } : {}),
+    sourceTool: params.sourceTool,
+    recordedAt: params.now ?? Date.now(),
+  };
+  await withJournalLock(async () => {
+    const journal = await readJournal(filePath);
+    const entries = [...(journal.sessions[params.sessionKey] ?? []), entry];
+    journal.sessions[params.sessionKey] = entries.slice(-MAX_ENTRIES_PER_SESSION);
+    await writeJsonAtomic(filePath, journal);
+  });
+  return entry;
+}
+
+/** Newest first. */
+export async function listUndoEntries(
+  sessionKey: string,
+  opts?: { filePath?: string },
+): Promise<UndoJournalEntry[]> {
+  const journal = await readJournal(opts?.filePath ?? resolveUndoJournalPath());
+  return (journal.sessions[sessionKey] ?? []).toReversed();
+}
+
+async function updateSessionEntries(
+  filePath: string,
+  sessionKey: string,
+  update: (entries: UndoJournalEntry[]) => UndoJournalEntry[],
+) {
+  await withJournalLock(async () => {
+    const journal = await readJournal(filePath);
+    const next = update(journal.sessions[sessionKey] ?? []);
+    if (next.length > 0) {
+      journal.sessions[sessionKey] = next;
+    } else {
+      delete journal.sessions[sessionKey];
+    }
+    await writeJsonAtomic(filePath, journal);
+  });
+}
+
+export type UndoLastResult =
+  | { ok: true; entry: UndoJournalEntry; result: Awaited<ReturnType<AnyAgentTool["execute"]>> }
+  | { ok: false; reason: "empty" | "tool-missing"; entry?: UndoJournalEntry };
+
+/**
+ * Restores the newest journaled state for a session by running its undo call. The entry is only
+ * removed once that call succeeds, so a failed undo can be retried.
+ */
+export async function undoLastAction(params: {
+  sessionKey: string;
+  resolveTool: (name: string) => AnyAgentTool | undefined;
+  signal?: AbortSignal;
+  filePath?: string;
+}): Promise<UndoLastResult> {
+  const filePath = params.filePath ?? resolveUndoJournalPath();
+  const [entry] = await listUndoEntries(params.sessionKey, { filePath });
+  if (!entry) {
+    return { ok: false, reason: "empty" };
+  }
+  const tool = params.resolveTool(entry.tool);
+  if (!tool) {
+    return { ok: false, reason: "tool-missing", entry };
+  }
+  const result = await tool.execute(
+    `${UNDO_TOOL_CALL_PREFIX}${entry.id}`,
+    entry.params,
+    params.signal,
+  );
+  await updateSessionEntries(filePath, params.sessionKey, (entries) =>
+    entries.filter((candidate) => candidate.id !== entry.id),
+  );
+  return { ok: true, entry, result };
+}
+
+export function describeUndoEntry(entry: UndoJournalEntry): string {
+  return entry.description?.trim() || `${entry.tool} ${JSON.stringify(entry.params)}`;
+}
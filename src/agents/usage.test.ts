@@ -1,5 +1,6 @@
 import { describe, expect, it } from "vitest";
 import {
+  createStreamingUsageAccumulator,
   normalizeUsage,
   hasNonzeroUsage,
   derivePromptTokens,
@@ -54,6 +55,41 @@ describe("normalizeUsage", () => {
     });
   });
 
+  it("maps Gemini usageMetadata, splitting cached prompt tokens out of input", () => {
+    const usage = normalizeUsage({
+      promptTokenCount: 1200,
+      cachedContentTokenCount: 1000,
+      candidatesTokenCount: 300,
+      thoughtsTokenCount: 150,
+      totalTokenCount: 1650,
+    });
+    expect(usage).toEqual({
+      input: 200,
+      output: 450,
+      cacheRead: 1000,
+      cacheWrite: undefined,
+      total: 1650,
+    });
+  });
+
+  it("splits OpenAI cached prompt tokens out of input", () => {
+    expect(
+      normalizeUsage({
+        prompt_tokens: 1500,
+        completion_tokens: 40,
+        total_tokens: 1540,
+        prompt_tokens_details: { cached_tokens: 1280 },
+      }),
+    ).toEqual({ input: 220, output: 40, cacheRead: 1280, cacheWrite: undefined, total: 1540 });
+    expect(
+      normalizeUsage({
+        input_tokens: 900,
+        output_tokens: 10,
+        input_tokens_details: { cached_tokens: 0 },
+      }),
+    ).toEqual({ input: 900, output: 10, cacheRead: 0, cacheWrite: undefined, total: undefined });
+  });
+
   it("returns undefined when no valid fields are provided", () => {
     const usage = normalizeUsage(null);
     expect(usage).toBeUndefined();
@@ -142,3 +178,29 @@ describe("deriveSessionTotalTokens", () => {
     expect(totalTokens).toBe(2500);
   });
 });
+
+describe("createStreamingUsageAccumulator", () => {
+  it("keeps the largest cumulative snapshot and prefers the final report", () => {
+    const acc = createStreamingUsageAccumulator();
+    acc.update({ input: 100 });
+    acc.update({ input: 100, output: 5 });
+    acc.update({ input: 100, output: 3 });
+    expect(acc.pending()).toEqual({ input: 100, output: 5 });
+    expect(acc.finalize({ input: 100, output: 42, total: 142 })).toEqual({
+      input: 100,
+      output: 42,
+      cacheRead: undefined,
+      cacheWrite: undefined,
+      total: 142,
+    });
+    expect(acc.pending()).toBeUndefined();
+  });
+
+  it("sums deltas and falls back to partial usage for aborted streams", () => {
+    const acc = createStreamingUsageAccumulator();
+    acc.update({ output_tokens: 4 }, "delta");
+    acc.update({ output_tokens: 6, input_tokens: 50 }, "delta");
+    expect(acc.finalize({ input: 0, output: 0 })).toEqual({ output: 10, input: 50 });
+    expect(acc.finalize()).toBeUndefined();
+  });
+});
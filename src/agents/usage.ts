@@ -20,6 +20,15 @@ export type UsageLike = {
   total_tokens?: number;
   cache_read?: number;
   cache_write?: number;
+  // Gemini `usageMetadata`: the prompt count includes cached tokens, thoughts are billed as output.
+  promptTokenCount?: number;
+  candidatesTokenCount?: number;
+  thoughtsTokenCount?: number;
+  cachedContentTokenCount?: number;
+  totalTokenCount?: number;
+  // OpenAI automatic prompt caching: cached tokens are included in the prompt/input count.
+  prompt_tokens_details?: { cached_tokens?: number };
+  input_tokens_details?: { cached_tokens?: number };
 };
 
 export type NormalizedUsage = {
@@ -49,26 +58,63 @@ export function hasNonzeroUsage(usage?: NormalizedUsage | null): usage is Normal
   );
 }
 
+function normalizeGeminiUsageMetadata(raw: UsageLike): NormalizedUsage {
+  const prompt = asFiniteNumber(raw.promptTokenCount);
+  const cached = asFiniteNumber(raw.cachedContentTokenCount);
+  const candidates = asFiniteNumber(raw.candidatesTokenCount);
+  const thoughts = asFiniteNumber(raw.thoughtsTokenCount);
+  return {
+    input: prompt !== undefined ? Math.max(0, prompt - (cached ?? 0)) : undefined,
+    output:
+      candidates !== undefined || thoughts !== undefined
+        ? (candidates ?? 0) + (thoughts ?? 0)
+        : undefined,
+    cacheRead: cached,
+  };
+}
+
 export function normalizeUsage(raw?: UsageLike | null): NormalizedUsage | undefined {
   if (!raw) {
     return undefined;
   }
 
-  const input = asFiniteNumber(
-    raw.input ?? raw.inputTokens ?? raw.input_tokens ?? raw.promptTokens ?? raw.prompt_tokens,
+  const gemini = normalizeGeminiUsageMetadata(raw);
+  const openaiCached = asFiniteNumber(
+    raw.prompt_tokens_details?.cached_tokens ?? raw.input_tokens_details?.cached_tokens,
   );
+  const rawInput = asFiniteNumber(
+    raw.input ??
+      raw.inputTokens ??
+      raw.input_tokens ??
+      raw.promptTokens ??
+      raw.prompt_tokens ??
+      gemini.input,
+  );
+  const input =
+    rawInput !== undefined && openaiCached !== undefined && raw.input === undefined
+      ? Math.max(0, rawInput - openaiCached)
+      : rawInput;
   const output = asFiniteNumber(
     raw.output ??
       raw.outputTokens ??
       raw.output_tokens ??
       raw.completionTokens ??
-      raw.completion_tokens,
+      raw.completion_tokens ??
+      gemini.output,
+  );
+  const cacheRead = asFiniteNumber(
+    raw.cacheRead ??
+      raw.cache_read ??
+      raw.cache_read_input_tokens ??
+      openaiCached ??
+      gemini.cacheRead,
   );
-  const cacheRead = asFiniteNumber(raw.cacheRead ?? raw.cache_read ?? raw.cache_read_input_tokens);
   const cacheWrite = asFiniteNumber(
     raw.cacheWrite ?? raw.cache_write ?? raw.cache_creation_input_tokens,
   );
-  const total = asFiniteNumber(raw.total ?? raw.totalTokens ?? raw.total_tokens);
+  const total = asFiniteNumber(
+    raw.total ?? raw.totalTokens ?? raw.total_tokens ?? raw.totalTokenCount,
+  );
 
   if (
     input === undefined &&
@@ -141,3 +187,49 @@ export function deriveSessionTotalTokens(params: {
   // (formatTokens in status.ts) already caps the percentage at 999%.
   return total;
 }
+
+const USAGE_FIELDS = ["input", "output", "cacheRead", "cacheWrite", "total"] as const;
+
+export type StreamingUsageAccumulator = {
+  /**
+   * Record usage seen mid-stream for the in-flight message. Providers differ: some send a
+   * cumulative snapshot on every chunk (field-wise max), others send per-chunk deltas (summed).
+   */
+  update: (usage: UsageLike | null | undefined, mode?: "snapshot" | "delta") => void;
+  /** Usage collected for the in-flight message so far, if any. */
+  pending: () => NormalizedUsage | undefined;
+  /**
+   * Close the in-flight message. A nonzero final report wins; otherwise the partial usage is
+   * kept, so streams that only report usage mid-flight (or were aborted) still count.
+   */
+  finalize: (final?: UsageLike | null) => NormalizedUsage | undefined;
+};
+
+export function createStreamingUsageAccumulator(): StreamingUsageAccumulator {
+  let partial: NormalizedUsage | undefined;
+  return {
+    update: (raw, mode = "snapshot") => {
+      const usage = normalizeUsage(raw);
+      if (!hasNonzeroUsage(usage)) {
+        return;
+      }
+      const next: NormalizedUsage = { ...partial };
+      for (const field of USAGE_FIELDS) {
+        const value = usage[field];
+        if (value === undefined) {
+          continue;
+        }
+        const current = next[field] ?? 0;
+        next[field] = mode === "delta" ? current + value : Math.max(current, value);
+      }
+      partial = next;
+    },
+    pending: () => (partial ? { ...partial } : undefined),
+    finalize: (raw) => {
+      const final = normalizeUsage(raw);
+      const result = hasNonzeroUsage(final) ? final : partial;
+      partial = undefined;
+      return result;
+    },
+  };
+}
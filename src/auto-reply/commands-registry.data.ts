@@ -180,6 +180,14 @@ function buildChatCommands(): ChatCommandDefinition[] {
       scope: "text",
       category: "management",
     }),
+    defineChatCommand({
+      key: "pairing",
+      description: "List or approve pending pairing requests.",
+      textAlias: "/pairing",
+      acceptsArgs: true,
+      scope: "text",
+      category: "management",
+    }),
     defineChatCommand({
       key: "approve",
       nativeName: "approve",
@@ -255,6 +263,45 @@ function buildChatCommands(): ChatCommandDefinition[] {
           "• Help – Show usage guide",
       },
     }),
+    defineChatCommand({
+      key: "snap",
+      nativeName: "snap",
+      description: "Capture a photo on a paired node camera.",
+      textAlias: "/snap",
+      category: "media",
+      args: [
+        {
+          name: "facing",
+          description: "Camera",
+          type: "string",
+          choices: [
+            { value: "back", label: "Back" },
+            { value: "front", label: "Front" },
+          ],
+        },
+        {
+          name: "node",
+          description: "Node name or id (default: the only connected node)",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
+    defineChatCommand({
+      key: "undo",
+      nativeName: "undo",
+      description: "Undo the last reversible hardware action.",
+      textAlias: "/undo",
+      category: "tools",
+      args: [
+        {
+          name: "action",
+          description: "list shows the undo history",
+          type: "string",
+          choices: [{ value: "list", label: "List" }],
+        },
+      ],
+    }),
     defineChatCommand({
       key: "whoami",
       nativeName: "whoami",
@@ -493,6 +540,22 @@ function buildChatCommands(): ChatCommandDefinition[] {
       ],
       argsMenu: "auto",
     }),
+    defineChatCommand({
+      key: "autonomy",
+      nativeName: "autonomy",
+      description: "Set autonomy for side-effecting tools.",
+      textAlias: "/autonomy",
+      category: "management",
+      args: [
+        {
+          name: "mode",
+          description: "observe, ask, auto, or inherit",
+          type: "string",
+          choices: ["observe", "ask", "auto", "inherit"],
+        },
+      ],
+      argsMenu: "auto",
+    }),
     defineChatCommand({
       key: "reset",
       nativeName: "reset",
@@ -509,6 +572,35 @@ function buildChatCommands(): ChatCommandDefinition[] {
       acceptsArgs: true,
       category: "session",
     }),
+    defineChatCommand({
+      key: "resume",
+      nativeName: "resume",
+      description: "Switch back to the session replaced by the last /new or reset.",
+      textAlias: "/resume",
+      category: "session",
+    }),
+    defineChatCommand({
+      key: "feedback",
+      nativeName: "feedback",
+      description: "Rate the last reply (saved with its tool trace).",
+      textAlias: "/feedback",
+      category: "session",
+      args: [
+        {
+          name: "rating",
+          description: "up or down",
+          type: "string",
+          choices: ["up", "down"],
+          required: true,
+        },
+        {
+          name: "note",
+          description: "What was good or bad",
+          type: "string",
+          captureRemaining: true,
+        },
+      ],
+    }),
     defineChatCommand({
       key: "compact",
       nativeName: "compact",
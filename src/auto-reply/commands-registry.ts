@@ -105,6 +105,9 @@ export function isCommandEnabled(cfg: OpenClawConfig, commandKey: string): boole
   if (commandKey === "bash") {
     return isCommandFlagEnabled(cfg, "bash");
   }
+  if (commandKey === "snap") {
+    return isCommandFlagEnabled(cfg, "camera");
+  }
   return true;
 }
 
@@ -368,6 +368,15 @@ describe("mention helpers", () => {
     expect(matchesMentionPatterns("workbot: hi", regexes)).toBe(true);
     expect(matchesMentionPatterns("global: hi", regexes)).toBe(false);
   });
+  it("treats trigger prefixes as mentions only at the start of a message", () => {
+    const regexes = buildMentionRegexes({
+      messages: { groupChat: { mentionPatterns: [], triggerPrefixes: ["!bot", " "] } },
+    });
+    expect(regexes).toHaveLength(1);
+    expect(matchesMentionPatterns("!BOT what's the weather?", regexes)).toBe(true);
+    expect(matchesMentionPatterns("  !bot hi", regexes)).toBe(true);
+    expect(matchesMentionPatterns("try !bot later", regexes)).toBe(false);
+  });
 });
 
 describe("resolveGroupRequireMention", () => {
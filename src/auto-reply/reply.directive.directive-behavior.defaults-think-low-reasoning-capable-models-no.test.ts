@@ -55,6 +55,11 @@ async function expectThinkStatusForReasoningModel(params: {
     const text = replyText(res);
     expect(text).toContain(`Current thinking level: ${params.expectedLevel}`);
     expect(text).toContain("Options: off, minimal, low, medium, high.");
+    const buttons = (Array.isArray(res) ? res[0] : res)?.interactive?.rows.flat() ?? [];
+    expect(buttons.map((button) => button.value)).toContain("/think high");
+    expect(buttons.find((button) => button.style === "primary")?.value).toBe(
+      `/think ${params.expectedLevel}`,
+    );
     expect(runEmbeddedPiAgent).not.toHaveBeenCalled();
   });
 }
@@ -5,6 +5,7 @@ import { afterEach, describe, expect, it, vi } from "vitest";
 import type { SubagentRunRecord } from "../../agents/subagent-registry.js";
 import type { OpenClawConfig } from "../../config/config.js";
 import {
+  abortSessionRun,
   getAbortMemory,
   getAbortMemorySizeForTest,
   isAbortRequestText,
@@ -232,6 +233,23 @@ describe("abort detection", () => {
     expect(commandQueueMocks.clearCommandLane).toHaveBeenCalledWith(`session:${sessionKey}`);
   });
 
+  it("abortSessionRun aborts the run by session key and marks the entry", async () => {
+    const sessionKey = "agent:main:api-user:ha";
+    const { storePath, cfg } = await createAbortConfig({
+      sessionIdsByKey: { [sessionKey]: "session-ha" },
+    });
+
+    const result = await abortSessionRun({ cfg, sessionKey });
+
+    expect(result).toEqual({ aborted: true, marked: true, stoppedSubagents: 0 });
+    const store = JSON.parse(await fs.readFile(storePath, "utf8")) as Record<
+      string,
+      { abortedLastRun?: boolean }
+    >;
+    expect(store[sessionKey]?.abortedLastRun).toBe(true);
+    expect(commandQueueMocks.clearCommandLane).toHaveBeenCalledWith(`session:${sessionKey}`);
+  });
+
   it("fast-abort stops active subagent runs for requester session", async () => {
     const sessionKey = "telegram:parent";
     const childKey = "agent:main:subagent:child-1";
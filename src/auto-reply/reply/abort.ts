@@ -199,6 +199,45 @@ export function stopSubagentsForRequester(params: {
   return { stopped };
 }
 
+/**
+ * Abort the active run for a session, drop its queued followups, and mark the entry as aborted so
+ * the next message continues from the partial transcript. Also stops sub-agents it spawned.
+ */
+export async function abortSessionRun(params: {
+  cfg: OpenClawConfig;
+  sessionKey: string;
+}): Promise<{ aborted: boolean; marked: boolean; stoppedSubagents: number }> {
+  const { cfg, sessionKey } = params;
+  const agentId = resolveSessionAgentId({ sessionKey, config: cfg });
+  const storePath = resolveStorePath(cfg.session?.store, { agentId });
+  const store = loadSessionStore(storePath);
+  const { entry, key } = resolveSessionEntryForKey(store, sessionKey);
+  const sessionId = entry?.sessionId;
+  const aborted = sessionId ? abortEmbeddedPiRun(sessionId) : false;
+  const cleared = clearSessionQueues([key ?? sessionKey, sessionId]);
+  if (cleared.followupCleared > 0 || cleared.laneCleared > 0) {
+    logVerbose(
+      `abort: cleared followups=${cleared.followupCleared} lane=${cleared.laneCleared} keys=${cleared.keys.join(",")}`,
+    );
+  }
+  if (entry && key) {
+    entry.abortedLastRun = true;
+    entry.updatedAt = Date.now();
+    store[key] = entry;
+    await updateSessionStore(storePath, (nextStore) => {
+      const nextEntry = nextStore[key] ?? entry;
+      if (!nextEntry) {
+        return;
+      }
+      nextEntry.abortedLastRun = true;
+      nextEntry.updatedAt = Date.now();
+      nextStore[key] = nextEntry;
+    });
+  }
+  const { stopped } = stopSubagentsForRequester({ cfg, requesterSessionKey: sessionKey });
+  return { aborted, marked: Boolean(entry && key), stoppedSubagents: stopped };
+}
+
 export async function tryFastAbortFromMessage(params: {
   ctx: FinalizedMsgContext;
   cfg: OpenClawConfig;
@@ -232,35 +271,11 @@ export async function tryFastAbortFromMessage(params: {
   const requesterSessionKey = targetKey ?? ctx.SessionKey ?? abortKey;
 
   if (targetKey) {
-    const storePath = resolveStorePath(cfg.session?.store, { agentId });
-    const store = loadSessionStore(storePath);
-    const { entry, key } = resolveSessionEntryForKey(store, targetKey);
-    const sessionId = entry?.sessionId;
-    const aborted = sessionId ? abortEmbeddedPiRun(sessionId) : false;
-    const cleared = clearSessionQueues([key ?? targetKey, sessionId]);
-    if (cleared.followupCleared > 0 || cleared.laneCleared > 0) {
-      logVerbose(
-        `abort: cleared followups=${cleared.followupCleared} lane=${cleared.laneCleared} keys=${cleared.keys.join(",")}`,
-      );
-    }
-    if (entry && key) {
-      entry.abortedLastRun = true;
-      entry.updatedAt = Date.now();
-      store[key] = entry;
-      await updateSessionStore(storePath, (nextStore) => {
-        const nextEntry = nextStore[key] ?? entry;
-        if (!nextEntry) {
-          return;
-        }
-        nextEntry.abortedLastRun = true;
-        nextEntry.updatedAt = Date.now();
-        nextStore[key] = nextEntry;
-      });
-    } else if (abortKey) {
+    const result = await abortSessionRun({ cfg, sessionKey: targetKey });
+    if (!result.marked && abortKey) {
       setAbortMemory(abortKey, true);
     }
-    const { stopped } = stopSubagentsForRequester({ cfg, requesterSessionKey });
-    return { handled: true, aborted, stoppedSubagents: stopped };
+    return { handled: true, aborted: result.aborted, stoppedSubagents: result.stoppedSubagents };
   }
 
   if (abortKey) {
@@ -5,13 +5,16 @@ import { getCliSessionId } from "../../agents/cli-session.js";
 import { runWithModelFallback } from "../../agents/model-fallback.js";
 import { isCliProvider } from "../../agents/model-selection.js";
 import {
+  extractRetryAfterMs,
   isCompactionFailureError,
   isContextOverflowError,
   isLikelyContextOverflowError,
+  isRetryableProviderError,
   isTransientHttpError,
   sanitizeUserFacingText,
 } from "../../agents/pi-embedded-helpers.js";
 import { runEmbeddedPiAgent } from "../../agents/pi-embedded.js";
+import type { OpenClawConfig } from "../../config/config.js";
 import {
   resolveGroupSessionKey,
   resolveSessionTranscriptPath,
@@ -20,6 +23,8 @@ import {
 } from "../../config/sessions.js";
 import { logVerbose } from "../../globals.js";
 import { emitAgentEvent, registerAgentRunContext } from "../../infra/agent-events.js";
+import { type BackoffPolicy, computeBackoff } from "../../infra/backoff.js";
+import { logProviderRetry } from "../../logging/diagnostic.js";
 import { defaultRuntime } from "../../runtime.js";
 import {
   isMarkdownCapableMessageChannel,
@@ -34,6 +39,7 @@ import {
   buildEmbeddedRunBaseParams,
   buildEmbeddedRunContexts,
   resolveModelFallbackOptions,
+  resolveToolStepMaxChars,
 } from "./agent-runner-utils.js";
 import { type BlockReplyPipeline } from "./block-reply-pipeline.js";
 import type { FollowupRun } from "./queue.js";
@@ -49,6 +55,11 @@ export type RuntimeFallbackAttempt = {
   code?: string;
 };
 
+function resolveToolProgressIntervalMs(cfg: OpenClawConfig | undefined): number | undefined {
+  const seconds = cfg?.agents?.defaults?.toolProgressIntervalSeconds;
+  return typeof seconds === "number" && Number.isFinite(seconds) ? seconds * 1000 : undefined;
+}
+
 export type AgentRunLoopResult =
   | {
       kind: "success";
@@ -92,7 +103,14 @@ export async function runAgentTurnWithFallback(params: {
   storePath?: string;
   resolvedVerboseLevel: VerboseLevel;
 }): Promise<AgentRunLoopResult> {
-  const TRANSIENT_HTTP_RETRY_DELAY_MS = 2_500;
+  // Retries wrap the full primary→fallback cycle, so keep them few and spaced out.
+  const PROVIDER_RETRY_MAX_ATTEMPTS = 3;
+  const PROVIDER_RETRY_BACKOFF: BackoffPolicy = {
+    initialMs: 2_500,
+    maxMs: 30_000,
+    factor: 2,
+    jitter: 0.2,
+  };
   let didLogHeartbeatStrip = false;
   let autoCompactionCompleted = false;
   // Track payloads sent directly (not via pipeline) during tool flush to avoid duplicates.
@@ -119,7 +137,7 @@ export async function runAgentTurnWithFallback(params: {
   let fallbackModel = params.followupRun.run.model;
   let fallbackAttempts: RuntimeFallbackAttempt[] = [];
   let didResetAfterCompactionFailure = false;
-  let didRetryTransientHttpError = false;
+  let providerRetryAttempt = 0;
 
   while (true) {
     try {
@@ -381,6 +399,11 @@ export async function runAgentTurnWithFallback(params: {
                 : undefined,
             shouldEmitToolResult: params.shouldEmitToolResult,
             shouldEmitToolOutput: params.shouldEmitToolOutput,
+            toolProgressIntervalMs: resolveToolProgressIntervalMs(params.followupRun.run.config),
+            toolStepMaxChars: resolveToolStepMaxChars(
+              params.followupRun.run.config,
+              resolveMessageChannel(params.sessionCtx.Surface, params.sessionCtx.Provider),
+            ),
             onToolResult: onToolResult
               ? (() => {
                   // Serialize tool result delivery to preserve message ordering.
@@ -537,17 +560,40 @@ export async function runAgentTurnWithFallback(params: {
         };
       }
 
-      if (isTransientHttp && !didRetryTransientHttpError) {
-        didRetryTransientHttpError = true;
-        // Retry the full runWithModelFallback() cycle — transient errors
-        // (502/521/etc.) typically affect the whole provider, so falling
+      if (
+        isRetryableProviderError(message) &&
+        providerRetryAttempt < PROVIDER_RETRY_MAX_ATTEMPTS - 1
+      ) {
+        providerRetryAttempt += 1;
+        // Retry the full runWithModelFallback() cycle — retryable errors
+        // (429/502/521/network) typically affect the whole provider, so falling
         // back to an alternate model first would not help. Instead we wait
-        // and retry the complete primary→fallback chain.
+        // (honoring Retry-After when the provider sends one) and retry the
+        // complete primary→fallback chain.
+        const retryAfterMs = extractRetryAfterMs(message);
+        const delayMs =
+          retryAfterMs !== undefined
+            ? Math.min(
+                Math.max(retryAfterMs, PROVIDER_RETRY_BACKOFF.initialMs),
+                PROVIDER_RETRY_BACKOFF.maxMs,
+              )
+            : computeBackoff(PROVIDER_RETRY_BACKOFF, providerRetryAttempt);
         defaultRuntime.error(
-          `Transient HTTP provider error before reply (${message}). Retrying once in ${TRANSIENT_HTTP_RETRY_DELAY_MS}ms.`,
+          `${isTransientHttp ? "Transient HTTP" : "Retryable"} provider error before reply (${message}). Retrying in ${delayMs}ms (attempt ${providerRetryAttempt + 1}/${PROVIDER_RETRY_MAX_ATTEMPTS}).`,
         );
+        logProviderRetry({
+          sessionKey: params.sessionKey,
+          sessionId: params.followupRun.run.sessionId,
+          provider: fallbackProvider,
+          model: fallbackModel,
+          attempt: providerRetryAttempt + 1,
+          maxAttempts: PROVIDER_RETRY_MAX_ATTEMPTS,
+          delayMs,
+          retryAfterMs,
+          error: message,
+        });
         await new Promise<void>((resolve) => {
-          setTimeout(resolve, TRANSIENT_HTTP_RETRY_DELAY_MS);
+          setTimeout(resolve, delayMs);
         });
         continue;
       }
@@ -0,0 +1,186 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { SessionEntry } from "../../config/sessions.js";
+import type { FollowupRun } from "./queue.js";
+
+const requestPlanMock = vi.hoisted(() => vi.fn());
+const checkCostBudgetMock = vi.hoisted(() => vi.fn());
+
+vi.mock("../../agents/planner.js", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("../../agents/planner.js")>()),
+  requestPlan: requestPlanMock,
+}));
+
+vi.mock("../../infra/cost-budget.js", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("../../infra/cost-budget.js")>()),
+  checkCostBudget: checkCostBudgetMock,
+}));
+
+const { checkReflectionBudget, resolveReflectionPrompt, runPlanningPhase } =
+  await import("./agent-runner-planning.js");
+
+const cfg = { agents: { defaults: { planning: { enabled: true } } } };
+
+function createFollowupRun(): FollowupRun {
+  return {
+    run: { agentId: "main", agentDir: "/tmp/agent", provider: "anthropic", model: "claude" },
+  } as unknown as FollowupRun;
+}
+
+describe("runPlanningPhase", () => {
+  beforeEach(() => {
+    requestPlanMock.mockReset();
+  });
+
+  it("runs normally when planning is off or nothing needs a plan", async () => {
+    const off = await runPlanningPhase({
+      cfg: {},
+      followupRun: createFollowupRun(),
+      commandBody: "hi",
+      isHeartbeat: false,
+    });
+    expect(off).toEqual({ kind: "run", commandBody: "hi" });
+    expect(requestPlanMock).not.toHaveBeenCalled();
+
+    requestPlanMock.mockResolvedValueOnce({ steps: [] });
+    const chat = await runPlanningPhase({
+      cfg,
+      followupRun: createFollowupRun(),
+      commandBody: "what is PWM?",
+      isHeartbeat: false,
+    });
+    expect(chat).toEqual({ kind: "run", commandBody: "what is PWM?" });
+  });
+
+  it("shows low-impact plans and hands them to the executor", async () => {
+    requestPlanMock.mockResolvedValueOnce({
+      steps: [{ description: "Read the temperature sensor", tools: ["read"] }],
+    });
+    const followupRun = createFollowupRun();
+
+    const result = await runPlanningPhase({
+      cfg,
+      followupRun,
+      commandBody: "how warm is it?",
+      isHeartbeat: false,
+    });
+
+    expect(result).toMatchObject({
+      kind: "run",
+      commandBody: "how warm is it?",
+      planNotice: "🗒️ Plan:\n1. Read the temperature sensor (read)",
+      reflection: { maxReflections: 1 },
+    });
+    expect(followupRun.run.extraSystemPrompt).toContain("## Execution Plan");
+  });
+
+  it("holds high-impact plans until the user approves", async () => {
+    requestPlanMock.mockResolvedValueOnce({
+      steps: [{ description: "Flash firmware v2.1", tools: ["exec"], highImpact: true }],
+    });
+    const sessionEntry: SessionEntry = { sessionId: "s1", updatedAt: Date.now() };
+
+    const held = await runPlanningPhase({
+      cfg,
+      followupRun: createFollowupRun(),
+      commandBody: "update the firmware",
+      sessionEntry,
+      isHeartbeat: false,
+    });
+    expect(held.kind).toBe("reply");
+    expect(sessionEntry.pendingPlan?.prompt).toBe("update the firmware");
+
+    const followupRun = createFollowupRun();
+    const approved = await runPlanningPhase({
+      cfg,
+      followupRun,
+      commandBody: "approve",
+      sessionEntry,
+      isHeartbeat: false,
+    });
+    expect(approved).toMatchObject({ kind: "run", commandBody: "update the firmware" });
+    expect(followupRun.run.extraSystemPrompt).toContain("The user approved this plan");
+    expect(sessionEntry.pendingPlan).toBeUndefined();
+    expect(requestPlanMock).toHaveBeenCalledTimes(1);
+  });
+
+  it("re-plans with the requested changes, or cancels", async () => {
+    const sessionEntry: SessionEntry = {
+      sessionId: "s1",
+      updatedAt: Date.now(),
+      pendingPlan: {
+        prompt: "update the firmware",
+        steps: [{ description: "Flash v2.1", highImpact: true }],
+        createdAt: Date.now(),
+      },
+    };
+    requestPlanMock.mockResolvedValueOnce({
+      steps: [{ description: "Flash v2.0", highImpact: true }],
+    });
+
+    await runPlanningPhase({
+      cfg,
+      followupRun: createFollowupRun(),
+      commandBody: "use v2.0 instead",
+      sessionEntry,
+      isHeartbeat: false,
+    });
+    expect(requestPlanMock.mock.calls[0]?.[0]?.prompt).toBe(
+      "update the firmware\n\nChanges requested for the previous plan: use v2.0 instead",
+    );
+
+    const cancelled = await runPlanningPhase({
+      cfg,
+      followupRun: createFollowupRun(),
+      commandBody: "cancel",
+      sessionEntry,
+      isHeartbeat: false,
+    });
+    expect(cancelled).toEqual({ kind: "reply", payload: { text: "🗒️ Plan cancelled." } });
+    expect(sessionEntry.pendingPlan).toBeUndefined();
+  });
+
+  it("reflects on tool failures until the retry budget is spent", () => {
+    const reflection = {
+      plan: { steps: [{ description: "Flash v2.1", tools: ["exec"] }] },
+      maxReflections: 1,
+    };
+    const failed = {
+      meta: { durationMs: 1, toolError: { toolName: "exec", error: "port busy" } },
+    };
+
+    expect(resolveReflectionPrompt({ reflection, runResult: failed, attempt: 1 })).toContain(
+      "Error: port busy",
+    );
+    expect(resolveReflectionPrompt({ reflection, runResult: failed, attempt: 2 })).toBeUndefined();
+    expect(
+      resolveReflectionPrompt({ reflection, runResult: { meta: { durationMs: 1 } }, attempt: 1 }),
+    ).toBeUndefined();
+    expect(resolveReflectionPrompt({ runResult: failed, attempt: 1 })).toBeUndefined();
+  });
+
+  it("stops reflection retries once the cost budget is reached", async () => {
+    checkCostBudgetMock.mockResolvedValueOnce({ ok: true, level: "ok" });
+    await expect(
+      checkReflectionBudget({ cfg, followupRun: createFollowupRun(), prompt: "retry" }),
+    ).resolves.toBeUndefined();
+
+    checkCostBudgetMock.mockResolvedValueOnce({
+      ok: false,
+      level: "hard",
+      action: "refuse",
+      period: "day",
+      limitUsd: 5,
+      spentUsd: 5.2,
+      remainingUsd: 0,
+    });
+    const notice = await checkReflectionBudget({
+      cfg,
+      followupRun: createFollowupRun(),
+      prompt: "retry",
+    });
+    expect(notice).toContain("Daily cost budget reached");
+    expect(checkCostBudgetMock).toHaveBeenLastCalledWith(
+      expect.objectContaining({ refresh: true }),
+    );
+  });
+});
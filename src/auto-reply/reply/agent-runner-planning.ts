@@ -0,0 +1,200 @@
+import {
+  buildExecutorPlanPrompt,
+  buildReflectionPrompt,
+  formatPlanMessage,
+  parsePlanDecision,
+  planNeedsApproval,
+  requestPlan,
+  resolvePlanningConfig,
+  type AgentPlan,
+} from "../../agents/planner.js";
+import type { EmbeddedPiRunResult } from "../../agents/pi-embedded-runner/types.js";
+import type { OpenClawConfig } from "../../config/config.js";
+import {
+  resolveFreshSessionTotalTokens,
+  type SessionEntry,
+  updateSessionStoreEntry,
+} from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+import {
+  checkCostBudget,
+  estimateRequestCost,
+  formatBudgetCheckNotice,
+} from "../../infra/cost-budget.js";
+import { defaultRuntime } from "../../runtime.js";
+import { resolveCostDisplayCurrency } from "../../utils/usage-format.js";
+import type { ReplyPayload } from "../types.js";
+import type { FollowupRun } from "./queue.js";
+
+const PENDING_PLAN_TTL_MS = 30 * 60_000;
+
+/** Plan the executor is running, kept so a failed run can be reflected on and retried. */
+export type PlanReflection = { plan: AgentPlan; maxReflections: number };
+
+export type PlanningPhaseResult =
+  | { kind: "reply"; payload: ReplyPayload }
+  | { kind: "run"; commandBody: string; planNotice?: string; reflection?: PlanReflection };
+
+async function savePendingPlan(params: {
+  sessionKey?: string;
+  sessionEntry?: SessionEntry;
+  storePath?: string;
+  pendingPlan: SessionEntry["pendingPlan"];
+}) {
+  if (params.sessionEntry) {
+    params.sessionEntry.pendingPlan = params.pendingPlan;
+  }
+  if (!params.storePath || !params.sessionKey) {
+    return;
+  }
+  await updateSessionStoreEntry({
+    storePath: params.storePath,
+    sessionKey: params.sessionKey,
+    update: async () => ({ pendingPlan: params.pendingPlan }),
+  });
+}
+
+function appendExecutorPlan(followupRun: FollowupRun, plan: AgentPlan, approved: boolean) {
+  followupRun.run.extraSystemPrompt = [
+    followupRun.run.extraSystemPrompt,
+    buildExecutorPlanPrompt(plan, approved),
+  ]
+    .filter(Boolean)
+    .join("\n\n");
+}
+
+/**
+ * Planner/executor mode: plan the turn first, then either hold the plan for approval or let the
+ * executor run with the plan in its system prompt. Planner failures fall back to a normal run.
+ */
+export async function runPlanningPhase(params: {
+  cfg: OpenClawConfig;
+  followupRun: FollowupRun;
+  commandBody: string;
+  sessionKey?: string;
+  sessionEntry?: SessionEntry;
+  storePath?: string;
+  isHeartbeat: boolean;
+}): Promise<PlanningPhaseResult> {
+  const { followupRun, commandBody } = params;
+  const config = resolvePlanningConfig(params.cfg, followupRun.run.agentId);
+  if (!config || params.isHeartbeat) {
+    return { kind: "run", commandBody };
+  }
+
+  let request = commandBody;
+  const pending = params.sessionEntry?.pendingPlan;
+  if (pending && Date.now() - pending.createdAt <= PENDING_PLAN_TTL_MS) {
+    const decision = parsePlanDecision(commandBody);
+    await savePendingPlan({ ...params, pendingPlan: undefined });
+    if (decision === "approve") {
+      const plan = { steps: pending.steps };
+      appendExecutorPlan(followupRun, plan, true);
+      return {
+        kind: "run",
+        commandBody: pending.prompt,
+        reflection: { plan, maxReflections: config.maxReflections },
+      };
+    }
+    if (decision === "cancel") {
+      return { kind: "reply", payload: { text: "🗒️ Plan cancelled." } };
+    }
+    request = `${pending.prompt}\n\nChanges requested for the previous plan: ${commandBody}`;
+  }
+
+  let plan: AgentPlan | null;
+  try {
+    plan = await requestPlan({
+      cfg: params.cfg,
+      config,
+      prompt: request,
+      provider: followupRun.run.provider,
+      model: followupRun.run.model,
+      agentDir: followupRun.run.agentDir,
+    });
+  } catch (err) {
+    defaultRuntime.error(`Planner failed; running without a plan: ${String(err)}`);
+    return { kind: "run", commandBody: request };
+  }
+  if (!plan || plan.steps.length === 0) {
+    logVerbose("planner: no plan needed for this turn");
+    return { kind: "run", commandBody: request };
+  }
+
+  const needsApproval = planNeedsApproval(plan, config);
+  const message = formatPlanMessage(plan, { config, needsApproval });
+  if (needsApproval) {
+    await savePendingPlan({
+      ...params,
+      pendingPlan: { prompt: request, steps: plan.steps, createdAt: Date.now() },
+    });
+    return { kind: "reply", payload: { text: message } };
+  }
+  appendExecutorPlan(followupRun, plan, false);
+  return {
+    kind: "run",
+    commandBody: request,
+    ...(config.showPlan ? { planNotice: message } : {}),
+    reflection: { plan, maxReflections: config.maxReflections },
+  };
+}
+
+/**
+ * Reflection step for planned runs: when the run ended on an unresolved tool failure, returns a
+ * prompt that feeds the failure back to the agent. Undefined once the retry budget is spent.
+ */
+export function resolveReflectionPrompt(params: {
+  reflection?: PlanReflection;
+  runResult: EmbeddedPiRunResult;
+  attempt: number;
+}): string | undefined {
+  const { reflection, runResult, attempt } = params;
+  const failure = runResult.meta.toolError;
+  if (!reflection || !failure || runResult.meta.aborted || attempt > reflection.maxReflections) {
+    return undefined;
+  }
+  logVerbose(
+    `planner: ${failure.toolName} failed; reflection ${attempt}/${reflection.maxReflections}`,
+  );
+  return buildReflectionPrompt({
+    plan: reflection.plan,
+    failure,
+    attempt,
+    maxAttempts: reflection.maxReflections,
+  });
+}
+
+/**
+ * Each reflection retry is another paid request, so it goes through the same budget check as
+ * the first one, with spend re-read to include the failed run. Returns the budget notice when the
+ * retry must not be sent.
+ */
+export async function checkReflectionBudget(params: {
+  cfg: OpenClawConfig;
+  followupRun: FollowupRun;
+  prompt: string;
+  sessionEntry?: SessionEntry;
+}): Promise<string | undefined> {
+  const { cfg, followupRun } = params;
+  const check = await checkCostBudget({
+    config: cfg,
+    estimate: estimateRequestCost({
+      config: cfg,
+      provider: followupRun.run.provider,
+      model: followupRun.run.model,
+      prompt: params.prompt,
+      contextTokens: resolveFreshSessionTotalTokens(params.sessionEntry),
+    }),
+    // The retry continues the conversation that is already running.
+    inFlight: Boolean(cfg.cost?.budget?.gracePercent),
+    refresh: true,
+  }).catch((err) => {
+    defaultRuntime.error(`Cost budget check failed: ${String(err)}`);
+    return undefined;
+  });
+  if (!check || check.ok) {
+    return undefined;
+  }
+  logVerbose("planner: cost budget reached; skipping reflection retry");
+  return formatBudgetCheckNotice(check, resolveCostDisplayCurrency({ config: cfg }));
+}
@@ -22,6 +22,7 @@ const {
   buildEmbeddedRunContexts,
   resolveModelFallbackOptions,
   resolveProviderScopedAuthProfile,
+  resolveToolStepMaxChars,
 } = await import("./agent-runner-utils.js");
 
 function makeRun(overrides: Partial<FollowupRun["run"]> = {}): FollowupRun["run"] {
@@ -150,3 +151,26 @@ describe("agent-runner-utils", () => {
     });
   });
 });
+
+describe("resolveToolStepMaxChars", () => {
+  it("is off unless enabled globally or for the channel", () => {
+    expect(resolveToolStepMaxChars(undefined, "telegram")).toBeUndefined();
+    expect(resolveToolStepMaxChars({ messages: { toolSteps: {} } }, "telegram")).toBeUndefined();
+    expect(
+      resolveToolStepMaxChars({ messages: { toolSteps: { enabled: true } } }, "telegram"),
+    ).toBe(120);
+  });
+
+  it("applies per-channel overrides and the result length", () => {
+    const cfg = {
+      messages: {
+        toolSteps: { enabled: true, byChannel: { discord: false }, maxResultChars: 0 },
+      },
+    };
+    expect(resolveToolStepMaxChars(cfg, "discord")).toBeUndefined();
+    expect(resolveToolStepMaxChars(cfg, "telegram")).toBe(0);
+    expect(
+      resolveToolStepMaxChars({ messages: { toolSteps: { byChannel: { slack: true } } } }, "slack"),
+    ).toBe(120);
+  });
+});
@@ -12,6 +12,7 @@ import type { ReplyPayload } from "../types.js";
 import type { FollowupRun } from "./queue.js";
 
 const BUN_FETCH_SOCKET_ERROR_RE = /socket connection was closed unexpectedly/i;
+const DEFAULT_TOOL_STEP_MAX_CHARS = 120;
 
 /**
  * Build provider-specific threading context for tool auto-injection.
@@ -137,16 +138,28 @@ export const appendUsageLine = (payloads: ReplyPayload[], line: string): ReplyPa
 export const resolveEnforceFinalTag = (run: FollowupRun["run"], provider: string) =>
   Boolean(run.enforceFinalTag || isReasoningTagProvider(provider));
 
+/** Result length for tool step lines on this channel, or undefined when they are off. */
+export function resolveToolStepMaxChars(
+  cfg: OpenClawConfig | undefined,
+  channel: string | undefined,
+): number | undefined {
+  const toolSteps = cfg?.messages?.toolSteps;
+  const channelSetting = channel ? toolSteps?.byChannel?.[channel] : undefined;
+  if (!(channelSetting ?? toolSteps?.enabled)) {
+    return undefined;
+  }
+  return toolSteps?.maxResultChars ?? DEFAULT_TOOL_STEP_MAX_CHARS;
+}
+
 export function resolveModelFallbackOptions(run: FollowupRun["run"]) {
   return {
     cfg: run.config,
     provider: run.provider,
     model: run.model,
     agentDir: run.agentDir,
-    fallbacksOverride: resolveAgentModelFallbacksOverride(
-      run.config,
-      resolveAgentIdFromSessionKey(run.sessionKey),
-    ),
+    fallbacksOverride:
+      run.modelFallbacksOverride ??
+      resolveAgentModelFallbacksOverride(run.config, resolveAgentIdFromSessionKey(run.sessionKey)),
   };
 }
 
@@ -530,6 +530,7 @@ describe("runReplyAgent typing (heartbeat)", () => {
 
   it("retries transient HTTP failures once with timer-driven backoff", async () => {
     vi.useFakeTimers();
+    const randomSpy = vi.spyOn(Math, "random").mockReturnValue(0);
     let calls = 0;
     state.runEmbeddedPiAgentMock.mockImplementation(async () => {
       calls += 1;
@@ -549,9 +550,59 @@ describe("runReplyAgent typing (heartbeat)", () => {
     await vi.advanceTimersByTimeAsync(1);
     await runPromise;
     expect(calls).toBe(2);
+    randomSpy.mockRestore();
     vi.useRealTimers();
   });
 
+  it("backs off exponentially on repeated retryable errors and honors retry-after", async () => {
+    vi.useFakeTimers();
+    const randomSpy = vi.spyOn(Math, "random").mockReturnValue(0);
+    let calls = 0;
+    state.runEmbeddedPiAgentMock.mockImplementation(async () => {
+      calls += 1;
+      if (calls === 1) {
+        throw new Error("fetch failed: ECONNRESET");
+      }
+      if (calls === 2) {
+        throw new Error("429 Too Many Requests: rate limit reached. Please try again in 12s.");
+      }
+      return { payloads: [{ text: "final" }], meta: {} };
+    });
+
+    const { run } = createMinimalRun({
+      typingMode: "message",
+    });
+    const runPromise = run();
+
+    await vi.advanceTimersByTimeAsync(2_500);
+    expect(calls).toBe(2);
+    await vi.advanceTimersByTimeAsync(11_999);
+    expect(calls).toBe(2);
+    await vi.advanceTimersByTimeAsync(1);
+    await runPromise;
+    expect(calls).toBe(3);
+    randomSpy.mockRestore();
+    vi.useRealTimers();
+  });
+
+  it("does not retry fatal provider errors", async () => {
+    let calls = 0;
+    state.runEmbeddedPiAgentMock.mockImplementation(async () => {
+      calls += 1;
+      throw new Error("401 invalid api key");
+    });
+
+    const { run } = createMinimalRun({
+      typingMode: "message",
+    });
+    const result = await run();
+
+    expect(calls).toBe(1);
+    expect(result).toMatchObject({
+      text: expect.stringContaining("Agent failed before reply"),
+    });
+  });
+
   it("delivers tool results in order even when dispatched concurrently", async () => {
     const deliveryOrder: string[] = [];
     const onToolResult = vi.fn(async (payload: { text?: string }) => {
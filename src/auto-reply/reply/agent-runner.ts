@@ -1,6 +1,6 @@
 import fs from "node:fs";
 import { lookupContextTokens } from "../../agents/context.js";
-import { DEFAULT_CONTEXT_TOKENS } from "../../agents/defaults.js";
+import { DEFAULT_CONTEXT_TOKENS, DEFAULT_PROVIDER } from "../../agents/defaults.js";
 import { resolveModelAuthMode } from "../../agents/model-auth.js";
 import { isCliProvider } from "../../agents/model-selection.js";
 import { queueEmbeddedPiMessage } from "../../agents/pi-embedded.js";
@@ -10,17 +10,31 @@ import {
   resolveSessionFilePath,
   resolveSessionFilePathOptions,
   resolveSessionTranscriptPath,
+  resolveFreshSessionTotalTokens,
   type SessionEntry,
   updateSessionStore,
   updateSessionStoreEntry,
 } from "../../config/sessions.js";
 import type { TypingMode } from "../../config/types.js";
 import { emitAgentEvent } from "../../infra/agent-events.js";
+import {
+  checkCostBudget,
+  estimateRequestCost,
+  formatBudgetCheckNotice,
+  formatBudgetFallbackNotice,
+  isConversationInFlight,
+  resolveBudgetFallbackModel,
+} from "../../infra/cost-budget.js";
+import { hasCostNotifications, runCostWebhookChecks } from "../../infra/cost-webhook.js";
 import { emitDiagnosticEvent, isDiagnosticsEnabled } from "../../infra/diagnostic-events.js";
 import { generateSecureUuid } from "../../infra/secure-random.js";
 import { enqueueSystemEvent } from "../../infra/system-events.js";
 import { defaultRuntime } from "../../runtime.js";
-import { estimateUsageCost, resolveModelCostConfig } from "../../utils/usage-format.js";
+import {
+  estimateUsageCost,
+  resolveCostDisplayCurrency,
+  resolveModelCostConfig,
+} from "../../utils/usage-format.js";
 import {
   buildFallbackClearedNotice,
   buildFallbackNotice,
@@ -39,9 +53,19 @@ import {
 } from "./agent-runner-helpers.js";
 import { runMemoryFlushIfNeeded } from "./agent-runner-memory.js";
 import { buildReplyPayloads } from "./agent-runner-payloads.js";
+import {
+  checkReflectionBudget,
+  resolveReflectionPrompt,
+  runPlanningPhase,
+} from "./agent-runner-planning.js";
 import { appendUsageLine, formatResponseUsageLine } from "./agent-runner-utils.js";
 import { createAudioAsVoiceBuffer, createBlockReplyPipeline } from "./block-reply-pipeline.js";
 import { resolveBlockStreamingCoalescing } from "./block-streaming.js";
+import {
+  appendCompactionSummaryToMemory,
+  isCompactionSummaryToMemoryEnabled,
+  readLatestCompactionSummary,
+} from "./compaction-summary-memory.js";
 import { createFollowupRunner } from "./followup-runner.js";
 import {
   auditPostCompactionReads,
@@ -358,30 +382,123 @@ export async function runReplyAgent(params: {
       cleanupTranscripts: true,
     });
   try {
-    const runStartedAt = Date.now();
-    const runOutcome = await runAgentTurnWithFallback({
-      commandBody,
+    let budgetNotice: string | undefined;
+    const budgetCheck = await checkCostBudget({
+      config: cfg,
+      estimate: estimateRequestCost({
+        config: cfg,
+        provider: followupRun.run.provider,
+        model: followupRun.run.model,
+        prompt: commandBody,
+        contextTokens: resolveFreshSessionTotalTokens(activeSessionEntry),
+      }),
+      inFlight:
+        Boolean(cfg.cost?.budget?.gracePercent) &&
+        !activeIsNewSession &&
+        (await isConversationInFlight({ sessionFile: activeSessionEntry?.sessionFile })),
+    }).catch((err) => {
+      defaultRuntime.error(`Cost budget check failed: ${String(err)}`);
+      return undefined;
+    });
+    if (budgetCheck && !budgetCheck.ok) {
+      const currency = resolveCostDisplayCurrency({ config: cfg });
+      const budgetFallback = resolveBudgetFallbackModel({
+        cfg,
+        defaultProvider: DEFAULT_PROVIDER,
+      });
+      if (budgetFallback) {
+        // Degraded mode: pin this run to the fallback model with no further fallbacks,
+        // so a failing local model never escalates back to a paid one.
+        if (budgetFallback.provider !== followupRun.run.provider) {
+          followupRun.run.authProfileId = undefined;
+          followupRun.run.authProfileIdSource = undefined;
+        }
+        followupRun.run.provider = budgetFallback.provider;
+        followupRun.run.model = budgetFallback.model;
+        followupRun.run.modelFallbacksOverride = [];
+        budgetNotice = formatBudgetFallbackNotice(budgetCheck, budgetFallback, currency);
+      } else {
+        budgetNotice = formatBudgetCheckNotice(budgetCheck, currency);
+      }
+      if (!budgetFallback && budgetCheck.action === "refuse") {
+        return finalizeWithFollowup(
+          isHeartbeat ? undefined : { text: budgetNotice },
+          queueKey,
+          runFollowupTurn,
+        );
+      }
+    } else if (budgetCheck?.level === "soft" || budgetCheck?.level === "grace") {
+      budgetNotice = formatBudgetCheckNotice(
+        budgetCheck,
+        resolveCostDisplayCurrency({ config: cfg }),
+      );
+    }
+
+    const planning = await runPlanningPhase({
+      cfg,
       followupRun,
-      sessionCtx,
-      opts,
-      typingSignals,
-      blockReplyPipeline,
-      blockStreamingEnabled,
-      blockReplyChunking,
-      resolvedBlockStreamingBreak,
-      applyReplyToMode,
-      shouldEmitToolResult,
-      shouldEmitToolOutput,
-      pendingToolTasks,
-      resetSessionAfterCompactionFailure,
-      resetSessionAfterRoleOrderingConflict,
-      isHeartbeat,
+      commandBody,
       sessionKey,
-      getActiveSessionEntry: () => activeSessionEntry,
-      activeSessionStore,
+      sessionEntry: activeSessionEntry,
       storePath,
-      resolvedVerboseLevel,
+      isHeartbeat,
     });
+    if (planning.kind === "reply") {
+      return finalizeWithFollowup(planning.payload, queueKey, runFollowupTurn);
+    }
+
+    const runStartedAt = Date.now();
+    const runTurn = (turnBody: string) =>
+      runAgentTurnWithFallback({
+        commandBody: turnBody,
+        followupRun,
+        sessionCtx,
+        opts,
+        typingSignals,
+        blockReplyPipeline,
+        blockStreamingEnabled,
+        blockReplyChunking,
+        resolvedBlockStreamingBreak,
+        applyReplyToMode,
+        shouldEmitToolResult,
+        shouldEmitToolOutput,
+        pendingToolTasks,
+        resetSessionAfterCompactionFailure,
+        resetSessionAfterRoleOrderingConflict,
+        isHeartbeat,
+        sessionKey,
+        getActiveSessionEntry: () => activeSessionEntry,
+        activeSessionStore,
+        storePath,
+        resolvedVerboseLevel,
+      });
+    let runOutcome = await runTurn(planning.commandBody);
+    const reflectionNotices: string[] = [];
+    for (let attempt = 1; runOutcome.kind === "success"; attempt += 1) {
+      const reflectionPrompt = resolveReflectionPrompt({
+        reflection: planning.reflection,
+        runResult: runOutcome.runResult,
+        attempt,
+      });
+      if (!reflectionPrompt) {
+        break;
+      }
+      const reflectionBudgetNotice = await checkReflectionBudget({
+        cfg,
+        followupRun,
+        prompt: reflectionPrompt,
+        sessionEntry: activeSessionEntry,
+      });
+      if (reflectionBudgetNotice) {
+        reflectionNotices.push(reflectionBudgetNotice);
+        break;
+      }
+      const failedTool = runOutcome.runResult.meta.toolError?.toolName ?? "a tool";
+      reflectionNotices.push(
+        `🔁 ${failedTool} failed; reflecting and retrying (${attempt}/${planning.reflection?.maxReflections}).`,
+      );
+      runOutcome = await runTurn(reflectionPrompt);
+    }
 
     if (runOutcome.kind === "final") {
       return finalizeWithFollowup(runOutcome.payload, queueKey, runFollowupTurn);
@@ -397,6 +514,13 @@ export async function runReplyAgent(params: {
     } = runOutcome;
     let { didLogHeartbeatStrip, autoCompactionCompleted } = runOutcome;
 
+    if (hasCostNotifications(cfg)) {
+      // Usage from this run is already in the transcript; report threshold crossings off-path.
+      void runCostWebhookChecks({ config: cfg }).catch((err) => {
+        defaultRuntime.error(`Cost webhook delivery failed: ${String(err)}`);
+      });
+    }
+
     if (
       shouldInjectGroupIntro &&
       activeSessionEntry &&
@@ -692,6 +816,24 @@ export async function runReplyAgent(params: {
         pendingPostCompactionAudits.set(sessionKey, true);
       }
 
+      // Keep the summarized turns searchable once they are gone from context.
+      const compactedSessionFile = activeSessionEntry?.sessionFile;
+      if (compactedSessionFile && isCompactionSummaryToMemoryEnabled(cfg)) {
+        readLatestCompactionSummary(compactedSessionFile)
+          .then((summary) =>
+            summary
+              ? appendCompactionSummaryToMemory({
+                  workspaceDir: followupRun.run.workspaceDir,
+                  summary,
+                  sessionKey,
+                })
+              : null,
+          )
+          .catch((err) => {
+            defaultRuntime.error(`compaction: failed to save summary to memory: ${String(err)}`);
+          });
+      }
+
       if (verboseEnabled) {
         const suffix = typeof count === "number" ? ` (count ${count})` : "";
         verboseNotices.push({ text: `🧹 Auto-compaction complete${suffix}.` });
@@ -700,6 +842,15 @@ export async function runReplyAgent(params: {
     if (verboseNotices.length > 0) {
       finalPayloads = [...verboseNotices, ...finalPayloads];
     }
+    if (reflectionNotices.length > 0 && !isHeartbeat) {
+      finalPayloads = [...reflectionNotices.map((text) => ({ text })), ...finalPayloads];
+    }
+    if (planning.planNotice) {
+      finalPayloads = [{ text: planning.planNotice }, ...finalPayloads];
+    }
+    if (budgetNotice && !isHeartbeat) {
+      finalPayloads = [{ text: budgetNotice }, ...finalPayloads];
+    }
     if (responseUsageLine) {
       finalPayloads = appendUsageLine(finalPayloads, responseUsageLine);
     }
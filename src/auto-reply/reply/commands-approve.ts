@@ -1,11 +1,16 @@
+import {
+  isToolAutonomyActionId,
+  resolveToolAutonomyAction,
+} from "../../agents/tool-autonomy.js";
 import { callGateway } from "../../gateway/call.js";
 import { logVerbose } from "../../globals.js";
+import { enqueueSystemEvent } from "../../infra/system-events.js";
 import {
   GATEWAY_CLIENT_MODES,
   GATEWAY_CLIENT_NAMES,
   isInternalMessageChannel,
 } from "../../utils/message-channel.js";
-import type { CommandHandler } from "./commands-types.js";
+import type { CommandHandler, CommandHandlerResult } from "./commands-types.js";
 
 const COMMAND = "/approve";
 
@@ -60,6 +65,40 @@ function parseApproveCommand(raw: string): ParsedApproveCommand | null {
   return { ok: false, error: "Usage: /approve <id> allow-once|allow-always|deny" };
 }
 
+/** `/approve act-… [allow-once|deny]` answers a call held back by `tools.autonomy: "ask"`. */
+function handleAutonomyApproval(
+  params: Parameters<CommandHandler>[0],
+  normalized: string,
+): CommandHandlerResult | null {
+  const tokens = normalized.trim().slice(COMMAND.length).trim().split(/\s+/).filter(Boolean);
+  const id = tokens.find(isToolAutonomyActionId);
+  if (!id || tokens.length > 2) {
+    return null;
+  }
+  const other = tokens.find((token) => token !== id)?.toLowerCase();
+  const decision = other ? DECISION_ALIASES[other] : "allow-once";
+  if (!decision) {
+    return { shouldContinue: false, reply: { text: `Usage: /approve ${id} [allow-once|deny]` } };
+  }
+  const approve = decision !== "deny";
+  const toolName = resolveToolAutonomyAction({ id, sessionKey: params.sessionKey, approve });
+  if (!toolName) {
+    return {
+      shouldContinue: false,
+      reply: { text: `❌ No pending action ${id} in this session (it may have expired).` },
+    };
+  }
+  if (!approve) {
+    return { shouldContinue: false, reply: { text: `🚫 Declined ${toolName} (${id}).` } };
+  }
+  // Continue into an agent turn so the approved call runs without another message.
+  enqueueSystemEvent(
+    `The user approved ${toolName} (${id}). Call it now with exactly the same parameters; the approval covers that one call only.`,
+    { sessionKey: params.sessionKey },
+  );
+  return { shouldContinue: true };
+}
+
 function buildResolvedByLabel(params: Parameters<CommandHandler>[0]): string {
   const channel = params.command.channel;
   const sender = params.command.senderId ?? "unknown";
@@ -82,6 +121,11 @@ export const handleApproveCommand: CommandHandler = async (params, allowTextComm
     return { shouldContinue: false };
   }
 
+  const autonomyResult = handleAutonomyApproval(params, normalized);
+  if (autonomyResult) {
+    return autonomyResult;
+  }
+
   if (!parsed.ok) {
     return { shouldContinue: false, reply: { text: parsed.error } };
   }
@@ -0,0 +1,81 @@
+import { normalizeToolAutonomyMode, resolveToolAutonomy } from "../../agents/tool-autonomy.js";
+import { updateSessionStore } from "../../config/sessions.js";
+import { logVerbose } from "../../globals.js";
+import { rejectUnauthorizedCommand } from "./command-gates.js";
+import type { CommandHandler } from "./commands-types.js";
+
+const COMMAND = "/autonomy";
+const USAGE = "Usage: /autonomy observe|ask|auto|inherit";
+
+const MODE_LABELS = {
+  observe: "observe-only (side-effecting tools are blocked)",
+  ask: "ask before acting (each action waits for /approve)",
+  auto: "fully autonomous",
+} as const;
+
+export const handleAutonomyCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const body = params.command.commandBodyNormalized.trim();
+  if (body !== COMMAND && !body.startsWith(`${COMMAND} `)) {
+    return null;
+  }
+  const unauthorized = rejectUnauthorizedCommand(params, COMMAND);
+  if (unauthorized) {
+    return unauthorized;
+  }
+  if (!params.command.senderIsOwner) {
+    logVerbose(`Ignoring /autonomy from non-owner: ${params.command.senderId || "<unknown>"}`);
+    return {
+      shouldContinue: false,
+      reply: { text: "⚠️ /autonomy is limited to owners (commands.ownerAllowFrom)." },
+    };
+  }
+  const arg = body.slice(COMMAND.length).trim().toLowerCase();
+  const entry = params.sessionEntry;
+
+  if (!arg) {
+    const current =
+      resolveToolAutonomy({
+        cfg: params.cfg,
+        agentId: params.agentId,
+        sessionKey: params.sessionKey,
+      })?.mode ?? "auto";
+    const source = entry?.autonomy ? "session override" : "config";
+    return {
+      shouldContinue: false,
+      reply: { text: `⚙️ Autonomy: ${MODE_LABELS[current]} (${source}).\n${USAGE}` },
+    };
+  }
+  const mode = arg === "inherit" ? undefined : normalizeToolAutonomyMode(arg);
+  if (arg !== "inherit" && !mode) {
+    return { shouldContinue: false, reply: { text: `⚙️ ${USAGE}` } };
+  }
+  if (!entry || !params.sessionStore || !params.sessionKey) {
+    return {
+      shouldContinue: false,
+      reply: { text: "⚠️ No active session to update." },
+    };
+  }
+  if (mode) {
+    entry.autonomy = mode;
+  } else {
+    delete entry.autonomy;
+  }
+  entry.updatedAt = Date.now();
+  params.sessionStore[params.sessionKey] = entry;
+  if (params.storePath) {
+    await updateSessionStore(params.storePath, (store) => {
+      store[params.sessionKey] = entry;
+    });
+  }
+  return {
+    shouldContinue: false,
+    reply: {
+      text: mode
+        ? `⚙️ Autonomy set to ${MODE_LABELS[mode]} for this session.`
+        : "⚙️ Autonomy reset to the configured default for this session.",
+    },
+  };
+};
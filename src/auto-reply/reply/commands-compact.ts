@@ -14,6 +14,10 @@ import { logVerbose } from "../../globals.js";
 import { enqueueSystemEvent } from "../../infra/system-events.js";
 import { formatContextUsageShort, formatTokenCount } from "../status.js";
 import type { CommandHandler } from "./commands-types.js";
+import {
+  appendCompactionSummaryToMemory,
+  isCompactionSummaryToMemoryEnabled,
+} from "./compaction-summary-memory.js";
 import { stripMentions, stripStructuralPrefixes } from "./mentions.js";
 import { incrementCompactionCount } from "./session-updates.js";
 
@@ -127,6 +131,16 @@ export const handleCompactCommand: CommandHandler = async (params) => {
       // Update token counts after compaction
       tokensAfter: result.result?.tokensAfter,
     });
+    const summary = result.result?.summary;
+    if (summary && isCompactionSummaryToMemoryEnabled(params.cfg)) {
+      await appendCompactionSummaryToMemory({
+        workspaceDir: params.workspaceDir,
+        summary,
+        sessionKey: params.sessionKey,
+      }).catch((err) => {
+        logVerbose(`compaction: failed to save summary to memory: ${String(err)}`);
+      });
+    }
   }
   // Use the post-compaction token count for context summary if available
   const tokensAfterCompaction = result.result?.tokensAfter;
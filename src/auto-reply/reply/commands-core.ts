@@ -6,9 +6,11 @@ import { resolveSendPolicy } from "../../sessions/send-policy.js";
 import { shouldHandleTextCommands } from "../commands-registry.js";
 import { handleAllowlistCommand } from "./commands-allowlist.js";
 import { handleApproveCommand } from "./commands-approve.js";
+import { handleAutonomyCommand } from "./commands-autonomy.js";
 import { handleBashCommand } from "./commands-bash.js";
 import { handleCompactCommand } from "./commands-compact.js";
 import { handleConfigCommand, handleDebugCommand } from "./commands-config.js";
+import { handleFeedbackCommand } from "./commands-feedback.js";
 import {
   handleCommandsListCommand,
   handleContextCommand,
@@ -18,18 +20,22 @@ import {
   handleWhoamiCommand,
 } from "./commands-info.js";
 import { handleModelsCommand } from "./commands-models.js";
+import { handlePairingCommand } from "./commands-pairing.js";
 import { handlePluginCommand } from "./commands-plugin.js";
 import {
   handleAbortTrigger,
   handleActivationCommand,
   handleRestartCommand,
+  handleResumeCommand,
   handleSessionCommand,
   handleSendPolicyCommand,
   handleStopCommand,
   handleUsageCommand,
 } from "./commands-session.js";
+import { handleSnapCommand } from "./commands-snap.js";
 import { handleSubagentsCommand } from "./commands-subagents.js";
 import { handleTtsCommands } from "./commands-tts.js";
+import { handleUndoCommand } from "./commands-undo.js";
 import type {
   CommandHandler,
   CommandHandlerResult,
@@ -47,14 +53,20 @@ export async function handleCommands(params: HandleCommandsParams): Promise<Comm
       handleBashCommand,
       handleActivationCommand,
       handleSendPolicyCommand,
+      handleAutonomyCommand,
       handleUsageCommand,
       handleSessionCommand,
+      handleResumeCommand,
+      handleFeedbackCommand,
       handleRestartCommand,
       handleTtsCommands,
+      handleSnapCommand,
+      handleUndoCommand,
       handleHelpCommand,
       handleCommandsListCommand,
       handleStatusCommand,
       handleAllowlistCommand,
+      handlePairingCommand,
       handleApproveCommand,
       handleContextCommand,
       handleExportSessionCommand,
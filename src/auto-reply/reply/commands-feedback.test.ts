@@ -0,0 +1,54 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import { loadFeedback } from "../../sessions/feedback.js";
+import { handleFeedbackCommand } from "./commands-feedback.js";
+import { buildCommandTestParams } from "./commands.test-harness.js";
+
+const cfg = {
+  commands: { text: true },
+  session: { mainKey: "main", scope: "per-sender" },
+} satisfies OpenClawConfig;
+
+describe("/feedback", () => {
+  let dir = "";
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-feedback-cmd-"));
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  function buildParams(body: string) {
+    const params = buildCommandTestParams(body, cfg);
+    params.storePath = path.join(dir, "sessions.json");
+    params.sessionEntry = { sessionId: "sess-1", updatedAt: Date.now() };
+    return params;
+  }
+
+  it("shows usage for a missing or unknown rating", async () => {
+    const result = await handleFeedbackCommand(buildParams("/feedback meh"), true);
+    expect(result?.reply?.text).toContain("Usage: /feedback up|down");
+  });
+
+  it("saves the rating with its note", async () => {
+    const params = buildParams("/feedback down wrong sensor");
+    const result = await handleFeedbackCommand(params, true);
+
+    expect(result).toEqual({
+      shouldContinue: false,
+      reply: { text: "👎 Feedback saved for the last reply (0 tool calls)." },
+    });
+    expect(loadFeedback(params.storePath ?? "")).toMatchObject([
+      { rating: "down", note: "wrong sensor", source: "command", sessionKey: "agent:main:main" },
+    ]);
+  });
+
+  it("ignores other commands", async () => {
+    expect(await handleFeedbackCommand(buildParams("/feedbacks up"), true)).toBeNull();
+  });
+});
@@ -0,0 +1,44 @@
+import { parseFeedbackRating, recordFeedback } from "../../sessions/feedback.js";
+import { rejectUnauthorizedCommand } from "./command-gates.js";
+import type { CommandHandler } from "./commands-types.js";
+
+const COMMAND = "/feedback";
+const USAGE = "Usage: /feedback up|down [note]";
+
+export const handleFeedbackCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const body = params.command.commandBodyNormalized.trim();
+  if (body !== COMMAND && !body.startsWith(`${COMMAND} `)) {
+    return null;
+  }
+  const unauthorized = rejectUnauthorizedCommand(params, COMMAND);
+  if (unauthorized) {
+    return unauthorized;
+  }
+  const [ratingArg = "", ...noteParts] = body.slice(COMMAND.length).trim().split(/\s+/);
+  const rating = parseFeedbackRating(ratingArg);
+  if (!rating) {
+    return { shouldContinue: false, reply: { text: `⚙️ ${USAGE}` } };
+  }
+  if (!params.sessionEntry?.sessionId || !params.storePath) {
+    return { shouldContinue: false, reply: { text: "⚠️ No active session to rate." } };
+  }
+  const record = await recordFeedback({
+    storePath: params.storePath,
+    sessionKey: params.sessionKey,
+    agentId: params.agentId,
+    rating,
+    source: "command",
+    note: noteParts.join(" "),
+    channel: params.command.channel,
+  });
+  const calls = record.tools?.length ?? 0;
+  return {
+    shouldContinue: false,
+    reply: {
+      text: `${rating === "up" ? "👍" : "👎"} Feedback saved for the last reply (${calls} tool ${calls === 1 ? "call" : "calls"}).`,
+    },
+  };
+};
@@ -0,0 +1,164 @@
+import type { InteractiveButton } from "../../channels/interactive.js";
+import { listPairingChannels, notifyPairingApproved } from "../../channels/plugins/pairing.js";
+import { normalizeChannelId } from "../../channels/registry.js";
+import { logVerbose } from "../../globals.js";
+import {
+  approveChannelPairingCode,
+  listChannelPairingRequests,
+} from "../../pairing/pairing-store.js";
+import { rejectUnauthorizedCommand } from "./command-gates.js";
+import type { CommandHandler } from "./commands-types.js";
+
+const COMMAND = "/pairing";
+const USAGE = "Usage: /pairing [list] [channel] | /pairing approve [channel] <code>";
+// Keep the button grid small; the full list is still in the text.
+const MAX_APPROVE_BUTTONS = 6;
+
+type PairingCommand =
+  | { action: "list"; channel?: string; account?: string }
+  | { action: "approve"; channel?: string; account?: string; code: string }
+  | { action: "error"; message: string };
+
+function parsePairingCommand(raw: string): PairingCommand | null {
+  const trimmed = raw.trim();
+  const lowered = trimmed.toLowerCase();
+  if (lowered !== COMMAND && !lowered.startsWith(`${COMMAND} `)) {
+    return null;
+  }
+  const tokens = trimmed.slice(COMMAND.length).trim().split(/\s+/).filter(Boolean);
+  let account: string | undefined;
+  const positional: string[] = [];
+  for (let i = 0; i < tokens.length; i += 1) {
+    const token = tokens[i];
+    if (token.toLowerCase() === "--account" && tokens[i + 1]) {
+      account = tokens[i + 1];
+      i += 1;
+      continue;
+    }
+    if (token.toLowerCase().startsWith("account=")) {
+      account = token.slice("account=".length).trim() || undefined;
+      continue;
+    }
+    positional.push(token);
+  }
+  const action = positional[0]?.toLowerCase();
+  if (!action || action === "list") {
+    if (positional.length > 2) {
+      return { action: "error", message: USAGE };
+    }
+    return { action: "list", channel: positional[1], account };
+  }
+  if (action === "approve") {
+    const args = positional.slice(1);
+    if (args.length === 1) {
+      return { action: "approve", code: args[0], account };
+    }
+    if (args.length === 2) {
+      return { action: "approve", channel: args[0], code: args[1], account };
+    }
+    return { action: "error", message: USAGE };
+  }
+  if (positional.length === 1) {
+    // `/pairing telegram` lists that channel.
+    return { action: "list", channel: positional[0], account };
+  }
+  return { action: "error", message: USAGE };
+}
+
+export const handlePairingCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parsePairingCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  const unauthorized = rejectUnauthorizedCommand(params, COMMAND);
+  if (unauthorized) {
+    return unauthorized;
+  }
+  if (!params.command.senderIsOwner) {
+    logVerbose(`Ignoring /pairing from non-owner: ${params.command.senderId || "<unknown>"}`);
+    return {
+      shouldContinue: false,
+      reply: { text: "⚠️ /pairing is limited to owners (commands.ownerAllowFrom)." },
+    };
+  }
+  if (parsed.action === "error") {
+    return { shouldContinue: false, reply: { text: `⚠️ ${parsed.message}` } };
+  }
+
+  const channelId = parsed.channel
+    ? normalizeChannelId(parsed.channel)
+    : (params.command.channelId ?? normalizeChannelId(params.command.channel));
+  const pairingChannels = listPairingChannels();
+  if (!channelId || !pairingChannels.includes(channelId)) {
+    const label = parsed.channel ?? params.command.channel;
+    return {
+      shouldContinue: false,
+      reply: {
+        text: `⚠️ ${label || "This channel"} does not support pairing (expected one of: ${pairingChannels.join(", ")}).`,
+      },
+    };
+  }
+  const accountId = parsed.account?.trim() || undefined;
+
+  if (parsed.action === "list") {
+    const requests = await listChannelPairingRequests(channelId, process.env, accountId);
+    if (requests.length === 0) {
+      return {
+        shouldContinue: false,
+        reply: { text: `No pending ${channelId} pairing requests.` },
+      };
+    }
+    const lines = requests.map((request) => {
+      const meta = Object.entries(request.meta ?? {})
+        .map(([key, value]) => `${key}=${value}`)
+        .join(", ");
+      return `- ${request.code} · ${request.id}${meta ? ` (${meta})` : ""}`;
+    });
+    const buttons: InteractiveButton[] = requests
+      .slice(0, MAX_APPROVE_BUTTONS)
+      .map((request) => ({
+        label: `Approve ${request.code}`,
+        value: `${COMMAND} approve ${channelId} ${request.code}`,
+        style: "success",
+      }));
+    return {
+      shouldContinue: false,
+      reply: {
+        text: [`Pending ${channelId} pairing requests (${requests.length}):`, ...lines].join("\n"),
+        interactive: { rows: buttons.map((button) => [button]) },
+      },
+    };
+  }
+
+  const approved = await approveChannelPairingCode({
+    channel: channelId,
+    code: parsed.code,
+    accountId,
+  });
+  if (!approved) {
+    return {
+      shouldContinue: false,
+      reply: { text: `⚠️ No pending ${channelId} pairing request for code ${parsed.code}.` },
+    };
+  }
+  const notified = await notifyPairingApproved({
+    channelId,
+    id: approved.id,
+    cfg: params.cfg,
+  }).then(
+    () => true,
+    (err) => {
+      logVerbose(`pairing: failed to notify ${channelId}:${approved.id}: ${String(err)}`);
+      return false;
+    },
+  );
+  return {
+    shouldContinue: false,
+    reply: {
+      text: `✅ Approved ${channelId} sender ${approved.id}.${notified ? "" : " (Could not notify them.)"}`,
+    },
+  };
+};
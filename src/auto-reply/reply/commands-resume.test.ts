@@ -0,0 +1,90 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import type { SessionEntry } from "../../config/sessions.js";
+import { handleResumeCommand } from "./commands-session.js";
+import { buildCommandTestParams } from "./commands.test-harness.js";
+
+const cfg = {
+  commands: { text: true },
+  session: { mainKey: "main", scope: "per-sender" },
+} satisfies OpenClawConfig;
+
+function buildResumeParams(entry: SessionEntry | undefined) {
+  const params = buildCommandTestParams("/resume", cfg);
+  params.sessionEntry = entry;
+  params.sessionStore = entry ? { [params.sessionKey]: entry } : {};
+  return params;
+}
+
+describe("/resume", () => {
+  let tmpDir: string;
+
+  beforeEach(() => {
+    tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-resume-"));
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it("replies when there is nothing to resume", async () => {
+    const result = await handleResumeCommand(
+      buildResumeParams({ sessionId: "current", updatedAt: Date.now() }),
+      true,
+    );
+    expect(result?.shouldContinue).toBe(false);
+    expect(result?.reply?.text).toContain("No previous session");
+  });
+
+  it("restores the archived transcript and swaps sessions", async () => {
+    const previousFile = path.join(tmpDir, "previous.jsonl");
+    const currentFile = path.join(tmpDir, "current.jsonl");
+    fs.writeFileSync(`${previousFile}.reset.2026-01-01T00-00-00.000Z`, "old\n");
+    fs.writeFileSync(`${previousFile}.reset.2026-02-01T00-00-00.000Z`, "newer\n");
+    fs.writeFileSync(currentFile, "current\n");
+    const entry: SessionEntry = {
+      sessionId: "current-session",
+      sessionFile: currentFile,
+      updatedAt: Date.now(),
+      totalTokens: 1234,
+      previousSession: { sessionId: "previous-session", sessionFile: previousFile },
+    };
+
+    const result = await handleResumeCommand(buildResumeParams(entry), true);
+
+    expect(result?.reply?.text).toContain("Resumed previous session");
+    expect(fs.readFileSync(previousFile, "utf-8")).toBe("newer\n");
+    expect(entry.sessionId).toBe("previous-session");
+    expect(entry.sessionFile).toBe(previousFile);
+    expect(entry.totalTokens).toBeUndefined();
+    expect(entry.previousSession).toEqual({
+      sessionId: "current-session",
+      sessionFile: currentFile,
+    });
+  });
+
+  it("forgets the previous session when its transcript is gone", async () => {
+    const entry: SessionEntry = {
+      sessionId: "current-session",
+      updatedAt: Date.now(),
+      previousSession: {
+        sessionId: "previous-session",
+        sessionFile: path.join(tmpDir, "missing.jsonl"),
+      },
+    };
+
+    const result = await handleResumeCommand(buildResumeParams(entry), true);
+
+    expect(result?.reply?.text).toContain("no longer available");
+    expect(entry.sessionId).toBe("current-session");
+    expect(entry.previousSession).toBeUndefined();
+  });
+
+  it("ignores other commands", async () => {
+    const params = buildCommandTestParams("/status", cfg);
+    expect(await handleResumeCommand(params, true)).toBeNull();
+  });
+});
@@ -8,11 +8,16 @@ import {
   getThreadBindingManager,
   setThreadBindingTtlBySessionKey,
 } from "../../discord/monitor/thread-bindings.js";
+import { restoreResetTranscript } from "../../gateway/session-utils.fs.js";
 import { logVerbose } from "../../globals.js";
 import { createInternalHookEvent, triggerInternalHook } from "../../hooks/internal-hooks.js";
 import { scheduleGatewaySigusr1Restart, triggerOpenClawRestart } from "../../infra/restart.js";
 import { loadCostUsageSummary, loadSessionCostSummary } from "../../infra/session-cost-usage.js";
-import { formatTokenCount, formatUsd } from "../../utils/usage-format.js";
+import {
+  formatCost,
+  formatTokenCount,
+  resolveCostDisplayCurrency,
+} from "../../utils/usage-format.js";
 import { parseActivationCommand } from "../group-activation.js";
 import { parseSendPolicyCommand } from "../send-policy.js";
 import { normalizeUsageDisplay, resolveResponseUsageMode } from "../thinking.js";
@@ -237,8 +242,9 @@ export const handleUsageCommand: CommandHandler = async (params, allowTextComman
       agentId: params.agentId,
     });
     const summary = await loadCostUsageSummary({ days: 30, config: params.cfg });
+    const currency = resolveCostDisplayCurrency({ config: params.cfg });
 
-    const sessionCost = formatUsd(sessionSummary?.totalCost);
+    const sessionCost = formatCost(sessionSummary?.totalCost, currency);
     const sessionTokens = sessionSummary?.totalTokens
       ? formatTokenCount(sessionSummary.totalTokens)
       : undefined;
@@ -251,12 +257,12 @@ export const handleUsageCommand: CommandHandler = async (params, allowTextComman
 
     const todayKey = new Date().toLocaleDateString("en-CA");
     const todayEntry = summary.daily.find((entry) => entry.date === todayKey);
-    const todayCost = formatUsd(todayEntry?.totalCost);
+    const todayCost = formatCost(todayEntry?.totalCost, currency);
     const todayMissing = todayEntry?.missingCostEntries ?? 0;
     const todaySuffix = todayMissing > 0 ? " (partial)" : "";
     const todayLine = `Today ${todayCost ?? "n/a"}${todaySuffix}`;
 
-    const last30Cost = formatUsd(summary.totals.totalCost);
+    const last30Cost = formatCost(summary.totals.totalCost, currency);
     const last30Missing = summary.totals.missingCostEntries;
     const last30Suffix = last30Missing > 0 ? " (partial)" : "";
     const last30Line = `Last 30d ${last30Cost ?? "n/a"}${last30Suffix}`;
@@ -527,6 +533,62 @@ export const handleStopCommand: CommandHandler = async (params, allowTextCommand
   return { shouldContinue: false, reply: { text: formatAbortReplyText(stopped) } };
 };
 
+export const handleResumeCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  if (params.command.commandBodyNormalized !== "/resume") {
+    return null;
+  }
+  if (!params.command.isAuthorizedSender) {
+    logVerbose(
+      `Ignoring /resume from unauthorized sender: ${params.command.senderId || "<unknown>"}`,
+    );
+    return { shouldContinue: false };
+  }
+  const entry = params.sessionEntry;
+  const previous = entry?.previousSession;
+  if (!entry || !previous) {
+    return {
+      shouldContinue: false,
+      reply: { text: "⚙️ No previous session to resume in this chat." },
+    };
+  }
+  const sessionFile = restoreResetTranscript(previous.sessionFile);
+  if (!sessionFile) {
+    delete entry.previousSession;
+    await persistSessionEntry(params);
+    return {
+      shouldContinue: false,
+      reply: { text: "⚠️ The previous session's transcript is no longer available." },
+    };
+  }
+
+  // Stop anything still running on the current conversation before switching away from it.
+  abortEmbeddedPiRun(entry.sessionId);
+  clearSessionQueues([params.sessionKey, entry.sessionId]);
+  // Swap, so a second /resume returns to the conversation we are leaving.
+  entry.previousSession = entry.sessionFile
+    ? { sessionId: entry.sessionId, sessionFile: entry.sessionFile }
+    : undefined;
+  entry.sessionId = previous.sessionId;
+  entry.sessionFile = sessionFile;
+  entry.systemSent = false;
+  entry.abortedLastRun = false;
+  entry.compactionCount = 0;
+  entry.memoryFlushCompactionCount = undefined;
+  entry.memoryFlushAt = undefined;
+  entry.totalTokens = undefined;
+  entry.inputTokens = undefined;
+  entry.outputTokens = undefined;
+  entry.contextTokens = undefined;
+  await persistSessionEntry(params);
+  return {
+    shouldContinue: false,
+    reply: { text: `✅ Resumed previous session (${previous.sessionId.slice(0, 8)}).` },
+  };
+};
+
 export const handleAbortTrigger: CommandHandler = async (params, allowTextCommands) => {
   if (!allowTextCommands) {
     return null;
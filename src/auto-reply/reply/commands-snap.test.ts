@@ -0,0 +1,21 @@
+import { describe, expect, it } from "vitest";
+import { parseSnapCommand } from "./commands-snap.js";
+
+describe("parseSnapCommand", () => {
+  it("ignores other commands", () => {
+    expect(parseSnapCommand("/snapshot")).toBeNull();
+    expect(parseSnapCommand("/status")).toBeNull();
+  });
+
+  it("defaults to the back camera and the default node", () => {
+    expect(parseSnapCommand("/snap")).toEqual({ facing: "back" });
+  });
+
+  it("accepts facing and node in either order", () => {
+    expect(parseSnapCommand("/snap front kitchen pi")).toEqual({
+      facing: "front",
+      node: "kitchen pi",
+    });
+    expect(parseSnapCommand("/snap iphone back")).toEqual({ facing: "back", node: "iphone" });
+  });
+});
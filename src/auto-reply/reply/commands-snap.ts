@@ -0,0 +1,88 @@
+import crypto from "node:crypto";
+import { callGatewayTool } from "../../agents/tools/gateway.js";
+import { resolveNodeId } from "../../agents/tools/nodes-utils.js";
+import {
+  type CameraFacing,
+  cameraTempPath,
+  parseCameraSnapPayload,
+  writeBase64ToFile,
+  writeUrlToFile,
+} from "../../cli/nodes-camera.js";
+import { rejectUnauthorizedCommand, requireCommandFlagEnabled } from "./command-gates.js";
+import type { CommandHandler } from "./commands-types.js";
+
+type ParsedSnapCommand = { node?: string; facing: CameraFacing };
+
+export function parseSnapCommand(normalized: string): ParsedSnapCommand | null {
+  if (normalized !== "/snap" && !normalized.startsWith("/snap ")) {
+    return null;
+  }
+  const tokens = normalized.slice(5).trim().split(/\s+/).filter(Boolean);
+  let facing: CameraFacing = "back";
+  const nodeParts: string[] = [];
+  for (const token of tokens) {
+    const lower = token.toLowerCase();
+    if (lower === "front" || lower === "back") {
+      facing = lower;
+    } else {
+      nodeParts.push(token);
+    }
+  }
+  const node = nodeParts.join(" ").trim();
+  return { ...(node ? { node } : {}), facing };
+}
+
+/** `/snap [node] [front|back]`: capture a photo on a paired node and reply with it. */
+export const handleSnapCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseSnapCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  const unauthorized = rejectUnauthorizedCommand(params, "/snap");
+  if (unauthorized) {
+    return unauthorized;
+  }
+  const disabled = requireCommandFlagEnabled(params.cfg, { label: "/snap", configKey: "camera" });
+  if (disabled) {
+    return disabled;
+  }
+  try {
+    const nodeId = await resolveNodeId({}, parsed.node, true);
+    const raw = await callGatewayTool<{ payload: unknown }>(
+      "node.invoke",
+      {},
+      {
+        nodeId,
+        command: "camera.snap",
+        params: { facing: parsed.facing, format: "jpg" },
+        idempotencyKey: crypto.randomUUID(),
+      },
+    );
+    const payload = parseCameraSnapPayload(raw?.payload);
+    const format = payload.format.toLowerCase();
+    const filePath = cameraTempPath({
+      kind: "snap",
+      facing: parsed.facing,
+      ext: format === "png" ? "png" : "jpg",
+    });
+    if (payload.url) {
+      await writeUrlToFile(filePath, payload.url);
+    } else if (payload.base64) {
+      await writeBase64ToFile(filePath, payload.base64);
+    }
+    return {
+      shouldContinue: false,
+      reply: {
+        text: `📷 ${parsed.facing} camera on ${parsed.node ?? nodeId} (${payload.width}x${payload.height})`,
+        mediaUrl: filePath,
+      },
+    };
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    const hint = message === "node required" ? " Usage: /snap <node> [front|back]" : "";
+    return { shouldContinue: false, reply: { text: `⚠️ /snap failed: ${message}.${hint}` } };
+  }
+};
@@ -8,6 +8,10 @@ import { resolveSandboxRuntimeStatus } from "../../agents/sandbox.js";
 import { buildWorkspaceSkillSnapshot } from "../../agents/skills.js";
 import { getSkillsSnapshotVersion } from "../../agents/skills/refresh.js";
 import { buildSystemPromptParams } from "../../agents/system-prompt-params.js";
+import {
+  buildSystemPromptTemplateVars,
+  renderContextFileTemplates,
+} from "../../agents/system-prompt-template.js";
 import { buildAgentSystemPrompt } from "../../agents/system-prompt.js";
 import { buildToolSummaryMap } from "../../agents/tool-summaries.js";
 import type { WorkspaceBootstrapFile } from "../../agents/workspace.js";
@@ -28,7 +32,7 @@ export async function resolveCommandsSystemPromptBundle(
   params: HandleCommandsParams,
 ): Promise<CommandsSystemPromptBundle> {
   const workspaceDir = params.workspaceDir;
-  const { bootstrapFiles, contextFiles: injectedFiles } = await resolveBootstrapContextForRun({
+  const { bootstrapFiles, contextFiles: rawInjectedFiles } = await resolveBootstrapContextForRun({
     workspaceDir,
     config: params.cfg,
     sessionKey: params.sessionKey,
@@ -108,6 +112,10 @@ export async function resolveCommandsSystemPromptBundle(
       }
     : { enabled: false };
   const ttsHint = params.cfg ? buildTtsSystemPromptHint(params.cfg) : undefined;
+  const injectedFiles = renderContextFileTemplates(
+    rawInjectedFiles,
+    buildSystemPromptTemplateVars({ runtimeInfo, userTimezone, tools }),
+  );
 
   const systemPrompt = buildAgentSystemPrompt({
     workspaceDir,
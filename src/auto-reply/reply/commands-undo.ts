@@ -0,0 +1,70 @@
+import { createOpenClawTools, createUndoToolResolver } from "../../agents/openclaw-tools.js";
+import { describeUndoEntry, listUndoEntries, undoLastAction } from "../../agents/undo-journal.js";
+import { rejectUnauthorizedCommand } from "./command-gates.js";
+import type { CommandHandler } from "./commands-types.js";
+
+export function parseUndoCommand(normalized: string): { action: "undo" | "list" } | null {
+  if (normalized === "/undo") {
+    return { action: "undo" };
+  }
+  if (normalized === "/undo list") {
+    return { action: "list" };
+  }
+  return null;
+}
+
+/** `/undo [list]`: restore the state before the last reversible hardware action in this session. */
+export const handleUndoCommand: CommandHandler = async (params, allowTextCommands) => {
+  if (!allowTextCommands) {
+    return null;
+  }
+  const parsed = parseUndoCommand(params.command.commandBodyNormalized);
+  if (!parsed) {
+    return null;
+  }
+  const unauthorized = rejectUnauthorizedCommand(params, "/undo");
+  if (unauthorized) {
+    return unauthorized;
+  }
+  if (parsed.action === "list") {
+    const entries = await listUndoEntries(params.sessionKey);
+    const text =
+      entries.length === 0
+        ? "Nothing to undo in this session."
+        : [
+            "↩️ Undo history (newest first):",
+            ...entries.map((entry, index) => `${index + 1}. ${describeUndoEntry(entry)}`),
+          ].join("\n");
+    return { shouldContinue: false, reply: { text } };
+  }
+  try {
+    const tools = createOpenClawTools({
+      config: params.cfg,
+      agentSessionKey: params.sessionKey,
+      agentDir: params.agentDir,
+      workspaceDir: params.workspaceDir,
+      senderIsOwner: params.command.senderIsOwner,
+    });
+    const outcome = await undoLastAction({
+      sessionKey: params.sessionKey,
+      resolveTool: createUndoToolResolver(() => tools, {
+        config: params.cfg,
+        sessionKey: params.sessionKey,
+      }),
+    });
+    if (!outcome.ok) {
+      const text =
+        outcome.reason === "empty"
+          ? "Nothing to undo in this session."
+          : `⚠️ /undo failed: tool ${outcome.entry?.tool} is not available.`;
+      return { shouldContinue: false, reply: { text } };
+    }
+    return {
+      shouldContinue: false,
+      reply: { text: `↩️ Undone: ${describeUndoEntry(outcome.entry)}` },
+    };
+  } catch (err) {
+    const message = err instanceof Error ? err.message : String(err);
+    return { shouldContinue: false, reply: { text: `⚠️ /undo failed: ${message}` } };
+  }
+};
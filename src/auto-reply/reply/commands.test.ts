@@ -3,6 +3,7 @@ import os from "node:os";
 import path from "node:path";
 import { afterAll, beforeAll, beforeEach, describe, expect, it, vi } from "vitest";
 import { compactEmbeddedPiSession } from "../../agents/pi-embedded.js";
+import { checkToolAutonomy } from "../../agents/tool-autonomy.js";
 import {
   addSubagentRunForTests,
   listSubagentRunsForRequester,
@@ -11,6 +12,7 @@ import {
 import type { OpenClawConfig } from "../../config/config.js";
 import { updateSessionStore } from "../../config/sessions.js";
 import * as internalHooks from "../../hooks/internal-hooks.js";
+import { enqueueSystemEvent } from "../../infra/system-events.js";
 import { clearPluginCommands, registerPluginCommand } from "../../plugins/commands.js";
 import { typedCases } from "../../test-utils/typed-cases.js";
 import type { MsgContext } from "../templating.js";
@@ -41,6 +43,9 @@ vi.mock("../../config/config.js", async () => {
 const readChannelAllowFromStoreMock = vi.hoisted(() => vi.fn());
 const addChannelAllowFromStoreEntryMock = vi.hoisted(() => vi.fn());
 const removeChannelAllowFromStoreEntryMock = vi.hoisted(() => vi.fn());
+const listChannelPairingRequestsMock = vi.hoisted(() => vi.fn());
+const approveChannelPairingCodeMock = vi.hoisted(() => vi.fn());
+const notifyPairingApprovedMock = vi.hoisted(() => vi.fn());
 
 vi.mock("../../pairing/pairing-store.js", async () => {
   const actual = await vi.importActual<typeof import("../../pairing/pairing-store.js")>(
@@ -51,6 +56,8 @@ vi.mock("../../pairing/pairing-store.js", async () => {
     readChannelAllowFromStore: readChannelAllowFromStoreMock,
     addChannelAllowFromStoreEntry: addChannelAllowFromStoreEntryMock,
     removeChannelAllowFromStoreEntry: removeChannelAllowFromStoreEntryMock,
+    listChannelPairingRequests: listChannelPairingRequestsMock,
+    approveChannelPairingCode: approveChannelPairingCodeMock,
   };
 });
 
@@ -61,6 +68,7 @@ vi.mock("../../channels/plugins/pairing.js", async () => {
   return {
     ...actual,
     listPairingChannels: () => ["telegram"],
+    notifyPairingApproved: notifyPairingApprovedMock,
   };
 });
 
@@ -192,6 +200,16 @@ describe("handleCommands gating", () => {
           }) as OpenClawConfig,
         expectedText: "/debug is disabled",
       },
+      {
+        name: "disabled snap command",
+        commandBody: "/snap front",
+        makeCfg: () =>
+          ({
+            commands: { text: true },
+            channels: { whatsapp: { allowFrom: ["*"] } },
+          }) as OpenClawConfig,
+        expectedText: "/snap is disabled",
+      },
       {
         name: "inherited bash flag does not enable command",
         commandBody: "/bash echo hi",
@@ -333,6 +351,48 @@ describe("/approve command", () => {
   });
 });
 
+describe("/approve for autonomy actions", () => {
+  const cfg = {
+    commands: { text: true },
+    channels: { whatsapp: { allowFrom: ["*"] } },
+  } as OpenClawConfig;
+  const call = {
+    autonomy: { mode: "ask" as const },
+    toolName: "relay_set",
+    toolParams: { relay: 2, on: true },
+    sessionKey: "agent:main:main",
+  };
+  const holdBack = () => checkToolAutonomy(call)?.match(/\/approve (act-[0-9a-f]+)/)?.[1] ?? "";
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("grants the held-back call once and continues into the agent turn", async () => {
+    const id = holdBack();
+    const result = await handleCommands(buildParams(`/approve ${id}`, cfg));
+    expect(result.shouldContinue).toBe(true);
+    expect(callGatewayMock).not.toHaveBeenCalled();
+    expect(enqueueSystemEvent).toHaveBeenCalledWith(
+      expect.stringContaining(`The user approved relay_set (${id})`),
+      { sessionKey: "agent:main:main" },
+    );
+    expect(checkToolAutonomy(call)).toBeNull();
+    expect(checkToolAutonomy(call)).toContain("/approve act-");
+
+    const again = await handleCommands(buildParams(`/approve ${id}`, cfg));
+    expect(again.reply?.text).toContain(`No pending action ${id}`);
+  });
+
+  it("declines without granting", async () => {
+    const id = holdBack();
+    const result = await handleCommands(buildParams(`/approve ${id} deny`, cfg));
+    expect(result.shouldContinue).toBe(false);
+    expect(result.reply?.text).toContain("Declined relay_set");
+    expect(checkToolAutonomy(call)).toContain("/approve act-");
+  });
+});
+
 describe("/compact command", () => {
   beforeEach(() => {
     vi.clearAllMocks();
@@ -588,6 +648,73 @@ function buildPolicyParams(
   return params;
 }
 
+describe("handleCommands /pairing", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  const cfg = { commands: { text: true } } as OpenClawConfig;
+
+  function buildOwnerParams(commandBody: string) {
+    const params = buildPolicyParams(commandBody, cfg);
+    params.command.senderIsOwner = true;
+    return params;
+  }
+
+  it("lists pending requests with approve buttons", async () => {
+    listChannelPairingRequestsMock.mockResolvedValueOnce([
+      {
+        id: "42",
+        code: "QRS678",
+        createdAt: "2026-01-01T00:00:00Z",
+        lastSeenAt: "2026-01-01T00:00:00Z",
+        meta: { username: "alice" },
+      },
+    ]);
+
+    const result = await handleCommands(buildOwnerParams("/pairing"));
+
+    expect(listChannelPairingRequestsMock).toHaveBeenCalledWith("telegram", process.env, undefined);
+    expect(result.reply?.text).toContain("- QRS678 · 42 (username=alice)");
+    expect(result.reply?.interactive?.rows).toEqual([
+      [
+        {
+          label: "Approve QRS678",
+          value: "/pairing approve telegram QRS678",
+          style: "success",
+        },
+      ],
+    ]);
+  });
+
+  it("approves a code and notifies the requester", async () => {
+    approveChannelPairingCodeMock.mockResolvedValueOnce({ id: "42" });
+    notifyPairingApprovedMock.mockResolvedValueOnce(undefined);
+
+    const result = await handleCommands(buildOwnerParams("/pairing approve telegram qrs678"));
+
+    expect(approveChannelPairingCodeMock).toHaveBeenCalledWith({
+      channel: "telegram",
+      code: "qrs678",
+      accountId: undefined,
+    });
+    expect(notifyPairingApprovedMock).toHaveBeenCalledWith(
+      expect.objectContaining({ channelId: "telegram", id: "42" }),
+    );
+    expect(result.reply?.text).toBe("✅ Approved telegram sender 42.");
+  });
+
+  it("rejects non-owners", async () => {
+    const params = buildPolicyParams("/pairing approve ABC123", cfg);
+    params.command.senderIsOwner = false;
+
+    const result = await handleCommands(params);
+
+    expect(result.reply?.text).toContain("limited to owners");
+    expect(approveChannelPairingCodeMock).not.toHaveBeenCalled();
+  });
+});
+
 describe("handleCommands /allowlist", () => {
   beforeEach(() => {
     vi.clearAllMocks();
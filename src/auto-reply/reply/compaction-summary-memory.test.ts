@@ -0,0 +1,83 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import {
+  appendCompactionSummaryToMemory,
+  isCompactionSummaryToMemoryEnabled,
+  readLatestCompactionSummary,
+} from "./compaction-summary-memory.js";
+
+describe("compaction summary memory", () => {
+  let tmpDir: string;
+
+  beforeEach(() => {
+    tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-compaction-memory-"));
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it("is off unless enabled in config", () => {
+    expect(isCompactionSummaryToMemoryEnabled(undefined)).toBe(false);
+    expect(isCompactionSummaryToMemoryEnabled({})).toBe(false);
+    expect(
+      isCompactionSummaryToMemoryEnabled({
+        agents: { defaults: { compaction: { summaryToMemory: true } } },
+      }),
+    ).toBe(true);
+  });
+
+  it("reads the latest compaction summary from a transcript", async () => {
+    const sessionFile = path.join(tmpDir, "session.jsonl");
+    fs.writeFileSync(
+      sessionFile,
+      [
+        JSON.stringify({ type: "session", id: "s1" }),
+        JSON.stringify({ type: "compaction", summary: "first summary" }),
+        JSON.stringify({ type: "message", message: { role: "user", content: "compaction" } }),
+        JSON.stringify({ type: "compaction", summary: "second summary" }),
+        JSON.stringify({ type: "message", message: { role: "assistant", content: "ok" } }),
+        "",
+      ].join("\n"),
+    );
+    expect(await readLatestCompactionSummary(sessionFile)).toBe("second summary");
+    expect(await readLatestCompactionSummary(path.join(tmpDir, "missing.jsonl"))).toBeNull();
+  });
+
+  it("appends summaries to the daily memory file", async () => {
+    const now = new Date("2026-03-04T09:15:00Z");
+    const filePath = await appendCompactionSummaryToMemory({
+      workspaceDir: tmpDir,
+      summary: "Discussed the deploy plan.",
+      sessionKey: "agent:main:main",
+      now,
+    });
+    expect(filePath).toBe(path.join(tmpDir, "memory", "2026-03-04.md"));
+    await appendCompactionSummaryToMemory({
+      workspaceDir: tmpDir,
+      summary: "Rolled back the config change.",
+      now: new Date("2026-03-04T11:40:00Z"),
+    });
+    expect(fs.readFileSync(filePath!, "utf-8")).toBe(
+      [
+        "## Session summary (09:15 UTC, agent:main:main)",
+        "",
+        "Discussed the deploy plan.",
+        "",
+        "## Session summary (11:40 UTC)",
+        "",
+        "Rolled back the config change.",
+        "",
+      ].join("\n"),
+    );
+  });
+
+  it("skips empty summaries", async () => {
+    expect(
+      await appendCompactionSummaryToMemory({ workspaceDir: tmpDir, summary: "  " }),
+    ).toBeNull();
+    expect(fs.existsSync(path.join(tmpDir, "memory"))).toBe(false);
+  });
+});
@@ -0,0 +1,69 @@
+import fs from "node:fs";
+import path from "node:path";
+import type { OpenClawConfig } from "../../config/config.js";
+
+/** Whether compaction summaries should be written to the workspace memory files. */
+export function isCompactionSummaryToMemoryEnabled(cfg: OpenClawConfig | undefined): boolean {
+  return cfg?.agents?.defaults?.compaction?.summaryToMemory === true;
+}
+
+/** Read the summary of the most recent compaction entry from a session transcript. */
+export async function readLatestCompactionSummary(sessionFile: string): Promise<string | null> {
+  let content: string;
+  try {
+    content = await fs.promises.readFile(sessionFile, "utf-8");
+  } catch {
+    return null;
+  }
+  const lines = content.split("\n");
+  for (let i = lines.length - 1; i >= 0; i -= 1) {
+    const line = lines[i]?.trim();
+    if (!line || !line.includes('"compaction"')) {
+      continue;
+    }
+    try {
+      const parsed = JSON.parse(line) as { type?: unknown; summary?: unknown };
+      if (parsed?.type === "compaction") {
+        return typeof parsed.summary === "string" && parsed.summary.trim()
+          ? parsed.summary.trim()
+          : null;
+      }
+    } catch {
+      // ignore bad lines
+    }
+  }
+  return null;
+}
+
+/**
+ * Append a compaction summary to `memory/YYYY-MM-DD.md` so the turns dropped from context stay
+ * reachable through memory_search. Returns the file path written.
+ */
+export async function appendCompactionSummaryToMemory(params: {
+  workspaceDir: string;
+  summary: string;
+  sessionKey?: string;
+  now?: Date;
+}): Promise<string | null> {
+  const summary = params.summary.trim();
+  if (!summary) {
+    return null;
+  }
+  const now = params.now ?? new Date();
+  const [dateStr, timeStr] = now.toISOString().split("T"); // YYYY-MM-DD, HH:MM:SS.sssZ
+  const memoryDir = path.join(params.workspaceDir, "memory");
+  const filePath = path.join(memoryDir, `${dateStr}.md`);
+  const heading = `## Session summary (${timeStr.slice(0, 5)} UTC${params.sessionKey ? `, ${params.sessionKey}` : ""})`;
+  await fs.promises.mkdir(memoryDir, { recursive: true });
+  let prefix = "";
+  try {
+    const existing = await fs.promises.readFile(filePath, "utf-8");
+    if (existing.length > 0) {
+      prefix = existing.endsWith("\n\n") ? "" : existing.endsWith("\n") ? "\n" : "\n\n";
+    }
+  } catch {
+    // new file
+  }
+  await fs.promises.appendFile(filePath, `${prefix}${heading}\n\n${summary}\n`, "utf-8");
+  return filePath;
+}
@@ -10,7 +10,12 @@ import type { ExecAsk, ExecHost, ExecSecurity } from "../../infra/exec-approvals
 import { enqueueSystemEvent } from "../../infra/system-events.js";
 import { applyVerboseOverride } from "../../sessions/level-overrides.js";
 import { applyModelOverrideToSessionEntry } from "../../sessions/model-overrides.js";
-import { formatThinkingLevels, formatXHighModelHint, supportsXHighThinking } from "../thinking.js";
+import {
+  formatThinkingLevels,
+  formatXHighModelHint,
+  listThinkingLevelLabels,
+  supportsXHighThinking,
+} from "../thinking.js";
 import type { ReplyPayload } from "../types.js";
 import {
   maybeHandleModelDirectiveInfo,
@@ -19,6 +24,7 @@ import {
 import type { HandleDirectiveOnlyParams } from "./directive-handling.params.js";
 import { maybeHandleQueueDirective } from "./directive-handling.queue-validation.js";
 import {
+  buildLevelPicker,
   formatDirectiveAck,
   formatElevatedRuntimeHint,
   formatElevatedUnavailableText,
@@ -141,6 +147,11 @@ export async function handleDirectiveOnly(
           `Current thinking level: ${level}.`,
           formatThinkingLevels(resolvedProvider, resolvedModel),
         ),
+        interactive: buildLevelPicker(
+          "think",
+          listThinkingLevelLabels(resolvedProvider, resolvedModel),
+          level,
+        ),
       };
     }
     return {
@@ -152,6 +163,7 @@ export async function handleDirectiveOnly(
       const level = currentVerboseLevel ?? "off";
       return {
         text: withOptions(`Current verbose level: ${level}.`, "on, full, off"),
+        interactive: buildLevelPicker("verbose", ["on", "full", "off"], level),
       };
     }
     return {
@@ -163,6 +175,7 @@ export async function handleDirectiveOnly(
       const level = currentReasoningLevel ?? "off";
       return {
         text: withOptions(`Current reasoning level: ${level}.`, "on, off, stream"),
+        interactive: buildLevelPicker("reasoning", ["on", "off", "stream"], level),
       };
     }
     return {
@@ -1,3 +1,4 @@
+import { optionPicker, type InteractiveReply } from "../../channels/interactive.js";
 import { formatCliCommand } from "../../cli/command-format.js";
 import type { ElevatedLevel, ReasoningLevel } from "./directives.js";
 
@@ -17,6 +18,17 @@ export const formatOptionsLine = (options: string) => `Options: ${options}.`;
 export const withOptions = (line: string, options: string) =>
   `${line}\n${formatOptionsLine(options)}`;
 
+/** Buttons for a level directive (`/think high`); the current level is highlighted. */
+export const buildLevelPicker = (
+  directive: string,
+  levels: readonly string[],
+  current: string,
+): InteractiveReply =>
+  optionPicker(
+    levels.map((level) => ({ label: level, value: `/${directive} ${level}` })),
+    { selected: `/${directive} ${current}` },
+  );
+
 export const formatElevatedRuntimeHint = () =>
   `${SYSTEM_MARK} Runtime is direct; sandboxing does not apply.`;
 
@@ -4,11 +4,14 @@ import {
   resolveSessionAgentId,
   resolveAgentSkillsFilter,
 } from "../../agents/agent-scope.js";
+import { loadModelCatalog } from "../../agents/model-catalog.js";
+import { hasImageAttachments, resolveVisionRoute } from "../../agents/model-routing.js";
 import { resolveModelRefFromString } from "../../agents/model-selection.js";
 import { resolveAgentTimeoutMs } from "../../agents/timeout.js";
 import { DEFAULT_AGENT_WORKSPACE_DIR, ensureAgentWorkspace } from "../../agents/workspace.js";
 import { resolveChannelModelOverride } from "../../channels/model-overrides.js";
 import { type OpenClawConfig, loadConfig } from "../../config/config.js";
+import { logVerbose } from "../../globals.js";
 import { applyLinkUnderstanding } from "../../link-understanding/apply.js";
 import { applyMediaUnderstanding } from "../../media-understanding/apply.js";
 import { defaultRuntime } from "../../runtime.js";
@@ -272,6 +275,24 @@ export async function getReplyFromConfig(
   provider = resolvedProvider;
   model = resolvedModel;
 
+  if (agentCfg?.modelRouting?.vision && hasImageAttachments(finalized)) {
+    const visionRoute = resolveVisionRoute({
+      cfg,
+      catalog: await loadModelCatalog({ config: cfg }),
+      provider,
+      model,
+      defaultProvider,
+      aliasIndex,
+    });
+    if (visionRoute) {
+      logVerbose(
+        `model routing: ${provider}/${model} has no image input; using ${visionRoute.provider}/${visionRoute.model} for this turn`,
+      );
+      provider = visionRoute.provider;
+      model = visionRoute.model;
+    }
+  }
+
   const inlineActionResult = await handleInlineActions({
     ctx,
     sessionCtx,
@@ -35,21 +35,34 @@ function normalizeMentionPatterns(patterns: string[]): string[] {
   return patterns.map(normalizeMentionPattern);
 }
 
+function resolveTriggerPrefixPatterns(cfg: OpenClawConfig, agentId?: string): string[] {
+  const agentGroupChat = agentId ? resolveAgentConfig(cfg, agentId)?.groupChat : undefined;
+  const prefixes =
+    agentGroupChat && Object.hasOwn(agentGroupChat, "triggerPrefixes")
+      ? agentGroupChat.triggerPrefixes
+      : cfg.messages?.groupChat?.triggerPrefixes;
+  // Anchored to the start so a prefix only triggers (and is only stripped) at the beginning.
+  return (prefixes ?? [])
+    .map((prefix) => prefix.trim())
+    .filter(Boolean)
+    .map((prefix) => String.raw`^\s*${escapeRegExp(prefix)}`);
+}
+
 function resolveMentionPatterns(cfg: OpenClawConfig | undefined, agentId?: string): string[] {
   if (!cfg) {
     return [];
   }
+  const prefixPatterns = resolveTriggerPrefixPatterns(cfg, agentId);
   const agentConfig = agentId ? resolveAgentConfig(cfg, agentId) : undefined;
   const agentGroupChat = agentConfig?.groupChat;
   if (agentGroupChat && Object.hasOwn(agentGroupChat, "mentionPatterns")) {
-    return agentGroupChat.mentionPatterns ?? [];
+    return [...(agentGroupChat.mentionPatterns ?? []), ...prefixPatterns];
   }
   const globalGroupChat = cfg.messages?.groupChat;
   if (globalGroupChat && Object.hasOwn(globalGroupChat, "mentionPatterns")) {
-    return globalGroupChat.mentionPatterns ?? [];
+    return [...(globalGroupChat.mentionPatterns ?? []), ...prefixPatterns];
   }
-  const derived = deriveMentionPatterns(agentConfig?.identity);
-  return derived.length > 0 ? derived : [];
+  return [...deriveMentionPatterns(agentConfig?.identity), ...prefixPatterns];
 }
 
 export function buildMentionRegexes(cfg: OpenClawConfig | undefined, agentId?: string): RegExp[] {
@@ -64,6 +64,8 @@ export type FollowupRun = {
     model: string;
     authProfileId?: string;
     authProfileIdSource?: "auto" | "user";
+    /** Replaces the agent fallback chain for this run (budget-degraded runs use `[]`). */
+    modelFallbacksOverride?: string[];
     thinkLevel?: ThinkLevel;
     verboseLevel?: VerboseLevel;
     reasoningLevel?: ReasoningLevel;
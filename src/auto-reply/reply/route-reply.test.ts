@@ -67,6 +67,7 @@ const createRegistry = (channels: PluginRegistry["channels"]): PluginRegistry =>
   hooks: [],
   typedHooks: [],
   commands: [],
+  middlewares: [],
   channels,
   providers: [],
   gatewayHandlers: {},
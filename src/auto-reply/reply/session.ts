@@ -387,6 +387,10 @@ export async function initSessionState(params: {
   if (!sessionEntry.chatType) {
     sessionEntry.chatType = "direct";
   }
+  if (isNewSession && entry?.sessionFile && entry.sessionId !== sessionEntry.sessionId) {
+    // Remember the replaced conversation so /resume can switch back to it.
+    sessionEntry.previousSession = { sessionId: entry.sessionId, sessionFile: entry.sessionFile };
+  }
   const threadLabel = ctx.ThreadLabel?.trim();
   if (threadLabel) {
     sessionEntry.displayName = threadLabel;
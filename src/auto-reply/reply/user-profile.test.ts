@@ -0,0 +1,50 @@
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import { buildUserProfileSystemPrompt, resolveUserProfile } from "./user-profile.js";
+
+const cfg: OpenClawConfig = {
+  session: {
+    identityLinks: { sam: ["discord:987654321"] },
+    userProfiles: {
+      alex: {
+        name: "Alex",
+        identities: ["telegram:123456"],
+        units: "imperial",
+        verbosity: "brief",
+        language: "Spanish",
+        notifications: "only urgent alerts after 22:00",
+      },
+      sam: { units: "metric", notes: "Runs the greenhouse." },
+    },
+  },
+};
+
+describe("user profiles", () => {
+  it("matches senders by profile identities or identityLinks", () => {
+    expect(
+      resolveUserProfile(cfg, { OriginatingChannel: "telegram", SenderId: "123456" })?.id,
+    ).toBe("alex");
+    expect(resolveUserProfile(cfg, { Surface: "discord", SenderId: "987654321" })?.id).toBe("sam");
+    expect(resolveUserProfile(cfg, { Surface: "discord", SenderId: "123456" })).toBeUndefined();
+    expect(resolveUserProfile({}, { Surface: "telegram", SenderId: "123456" })).toBeUndefined();
+  });
+
+  it("renders preferences into the system prompt", () => {
+    expect(
+      buildUserProfileSystemPrompt(cfg, { OriginatingChannel: "telegram", SenderId: "123456" }),
+    ).toBe(
+      [
+        "## Current User",
+        "You are talking with Alex. Tailor the reply to their preferences.",
+        "Units: imperial (°F, miles, pounds, inches).",
+        "Keep replies short: answer first, skip background unless asked.",
+        "Reply in Spanish unless asked otherwise.",
+        "Notification preferences: only urgent alerts after 22:00",
+      ].join("\n"),
+    );
+    const sam = buildUserProfileSystemPrompt(cfg, { Surface: "discord", SenderId: "987654321" });
+    expect(sam).toContain("You are talking with sam.");
+    expect(sam).toContain("Notes: Runs the greenhouse.");
+    expect(buildUserProfileSystemPrompt(cfg, { SenderId: "555" })).toBe("");
+  });
+});
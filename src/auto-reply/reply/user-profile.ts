@@ -0,0 +1,78 @@
+import type { OpenClawConfig } from "../../config/config.js";
+import type { UserProfileConfig } from "../../config/types.base.js";
+import { resolveLinkedPeerId } from "../../routing/session-key.js";
+import type { TemplateContext } from "../templating.js";
+
+const VERBOSITY_GUIDANCE: Record<NonNullable<UserProfileConfig["verbosity"]>, string> = {
+  brief: "Keep replies short: answer first, skip background unless asked.",
+  normal: "Use normal reply length.",
+  detailed: "Give detailed replies with reasoning and next steps.",
+};
+
+/** Matches the sender against `session.userProfiles` (profile identities + identityLinks). */
+export function resolveUserProfile(
+  cfg: OpenClawConfig,
+  ctx: TemplateContext,
+): { id: string; profile: UserProfileConfig } | undefined {
+  const profiles = cfg.session?.userProfiles;
+  if (!profiles || Object.keys(profiles).length === 0) {
+    return undefined;
+  }
+  const channel = ctx.OriginatingChannel ?? ctx.Surface ?? ctx.Provider ?? "";
+  const identityLinks = Object.fromEntries(
+    Object.entries(profiles).map(([id, profile]) => [
+      id,
+      [...(profile.identities ?? []), ...(cfg.session?.identityLinks?.[id] ?? [])],
+    ]),
+  );
+  for (const peerId of [ctx.SenderId, ctx.SenderE164, ctx.SenderUsername]) {
+    if (!peerId?.trim()) {
+      continue;
+    }
+    const id = resolveLinkedPeerId({ identityLinks, channel, peerId });
+    const profile = id ? profiles[id] : undefined;
+    if (id && profile) {
+      return { id, profile };
+    }
+  }
+  return undefined;
+}
+
+/**
+ * System prompt block for the matched profile. Operator-configured, so it is trusted; in group
+ * chats it changes with the sender, which trades some prompt caching for per-person behavior.
+ */
+export function buildUserProfileSystemPrompt(cfg: OpenClawConfig, ctx: TemplateContext): string {
+  const match = resolveUserProfile(cfg, ctx);
+  if (!match) {
+    return "";
+  }
+  const { id, profile } = match;
+  const lines = [
+    "## Current User",
+    `You are talking with ${profile.name?.trim() || id}. Tailor the reply to their preferences.`,
+  ];
+  if (profile.units) {
+    lines.push(
+      profile.units === "imperial"
+        ? "Units: imperial (°F, miles, pounds, inches)."
+        : "Units: metric (°C, kilometres, kilograms, centimetres).",
+    );
+  }
+  if (profile.verbosity) {
+    lines.push(VERBOSITY_GUIDANCE[profile.verbosity]);
+  }
+  if (profile.language?.trim()) {
+    lines.push(`Reply in ${profile.language.trim()} unless asked otherwise.`);
+  }
+  if (profile.timezone?.trim()) {
+    lines.push(`Their timezone: ${profile.timezone.trim()}.`);
+  }
+  if (profile.notifications?.trim()) {
+    lines.push(`Notification preferences: ${profile.notifications.trim()}`);
+  }
+  if (profile.notes?.trim()) {
+    lines.push(`Notes: ${profile.notes.trim()}`);
+  }
+  return lines.join("\n");
+}
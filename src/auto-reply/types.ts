@@ -1,4 +1,5 @@
 import type { ImageContent } from "@mariozechner/pi-ai";
+import type { InteractiveReply } from "../channels/interactive.js";
 import type { TypingController } from "./reply/typing.js";
 
 export type BlockReplyContext = {
@@ -66,6 +67,8 @@ export type ReplyPayload = {
   /** Send audio as voice message (bubble) instead of audio file. Defaults to false. */
   audioAsVoice?: boolean;
   isError?: boolean;
+  /** Buttons rendered natively where the channel supports them (Telegram, Discord, Slack). */
+  interactive?: InteractiveReply;
   /** Channel-specific payload data (per-channel envelope). */
   channelData?: Record<string, unknown>;
 };
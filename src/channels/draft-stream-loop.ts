@@ -4,6 +4,8 @@ export type DraftStreamLoop = {
   stop: () => void;
   resetPending: () => void;
   resetThrottleWindow: () => void;
+  /** Hold sends for `ms` (e.g. a 429 retry-after); a send that returned false is retried after. */
+  backoff: (ms: number) => void;
   waitForInFlight: () => Promise<void>;
 };
 
@@ -13,6 +15,7 @@ export function createDraftStreamLoop(params: {
   sendOrEditStreamMessage: (text: string) => Promise<void | boolean>;
 }): DraftStreamLoop {
   let lastSentAt = 0;
+  let blockedUntil = 0;
   let pendingText = "";
   let inFlightPromise: Promise<void | boolean> | undefined;
   let timer: ReturnType<typeof setTimeout> | undefined;
@@ -42,6 +45,9 @@ export function createDraftStreamLoop(params: {
       const sent = await current;
       if (sent === false) {
         pendingText = text;
+        if (blockedUntil > Date.now()) {
+          schedule();
+        }
         return;
       }
       lastSentAt = Date.now();
@@ -55,7 +61,8 @@ export function createDraftStreamLoop(params: {
     if (timer) {
       return;
     }
-    const delay = Math.max(0, params.throttleMs - (Date.now() - lastSentAt));
+    const now = Date.now();
+    const delay = Math.max(0, params.throttleMs - (now - lastSentAt), blockedUntil - now);
     timer = setTimeout(() => {
       void flush();
     }, delay);
@@ -71,7 +78,8 @@ export function createDraftStreamLoop(params: {
         schedule();
         return;
       }
-      if (!timer && Date.now() - lastSentAt >= params.throttleMs) {
+      const now = Date.now();
+      if (!timer && now - lastSentAt >= params.throttleMs && now >= blockedUntil) {
         void flush();
         return;
       }
@@ -95,6 +103,16 @@ export function createDraftStreamLoop(params: {
         timer = undefined;
       }
     },
+    backoff: (ms: number) => {
+      if (!Number.isFinite(ms) || ms <= 0) {
+        return;
+      }
+      blockedUntil = Math.max(blockedUntil, Date.now() + ms);
+      if (timer) {
+        clearTimeout(timer);
+        timer = undefined;
+      }
+    },
     waitForInFlight: async () => {
       if (inFlightPromise) {
         await inFlightPromise;
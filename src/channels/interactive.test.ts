@@ -0,0 +1,38 @@
+import { describe, expect, it } from "vitest";
+import { confirmButtons, normalizeInteractiveReply, optionPicker } from "./interactive.js";
+
+describe("interactive replies", () => {
+  it("builds a yes/no confirmation row", () => {
+    expect(confirmButtons({ yes: "/reset confirm", no: "/reset cancel" })).toEqual({
+      rows: [
+        [
+          { label: "Yes", value: "/reset confirm", style: "success" },
+          { label: "No", value: "/reset cancel", style: "danger" },
+        ],
+      ],
+    });
+  });
+
+  it("wraps picker options into rows and highlights the selected one", () => {
+    const picker = optionPicker(
+      ["a", "b", "c", "d"].map((id) => ({ label: id.toUpperCase(), value: `/pick ${id}` })),
+      { columns: 3, selected: "/pick d" },
+    );
+    expect(picker.rows.map((row) => row.map((button) => button.label))).toEqual([
+      ["A", "B", "C"],
+      ["D"],
+    ]);
+    expect(picker.rows[1]?.[0]?.style).toBe("primary");
+    expect(picker.rows[0]?.[0]?.style).toBeUndefined();
+  });
+
+  it("drops empty buttons and rows", () => {
+    expect(
+      normalizeInteractiveReply({
+        rows: [[{ label: " ", value: "/x" }], [{ label: "Ok", value: " /ok " }]],
+      }),
+    ).toEqual({ rows: [[{ label: "Ok", value: "/ok" }]] });
+    expect(normalizeInteractiveReply({ rows: [[]] })).toBeUndefined();
+    expect(normalizeInteractiveReply(undefined)).toBeUndefined();
+  });
+});
@@ -0,0 +1,89 @@
+/**
+ * Channel-agnostic interactive replies: rows of buttons whose `value` is sent back as if the
+ * user had typed it (usually a slash command). Channel adapters map these to Telegram inline
+ * keyboards, Discord components, and Slack actions; other channels just send the text.
+ */
+
+export type InteractiveButtonStyle = "primary" | "success" | "danger";
+
+export type InteractiveButton = {
+  label: string;
+  /** Text delivered back to the agent when the button is pressed. */
+  value: string;
+  style?: InteractiveButtonStyle;
+};
+
+export type InteractiveReply = {
+  rows: InteractiveButton[][];
+};
+
+export type InteractiveOption = {
+  label: string;
+  value: string;
+};
+
+const DEFAULT_PICKER_COLUMNS = 3;
+
+function normalizeButton(button: InteractiveButton): InteractiveButton | null {
+  const label = button.label?.trim();
+  const value = button.value?.trim();
+  if (!label || !value) {
+    return null;
+  }
+  return button.style ? { label, value, style: button.style } : { label, value };
+}
+
+/** Drop empty buttons and rows; returns undefined when nothing is left to render. */
+export function normalizeInteractiveReply(
+  reply: InteractiveReply | undefined,
+): InteractiveReply | undefined {
+  if (!reply || !Array.isArray(reply.rows)) {
+    return undefined;
+  }
+  const rows = reply.rows
+    .map((row) => (Array.isArray(row) ? row : []).map(normalizeButton).filter(Boolean))
+    .filter((row) => row.length > 0) as InteractiveButton[][];
+  return rows.length > 0 ? { rows } : undefined;
+}
+
+/** Yes/no confirmation: one row with a success and a danger button. */
+export function confirmButtons(params: {
+  yes: string;
+  no: string;
+  yesLabel?: string;
+  noLabel?: string;
+}): InteractiveReply {
+  return {
+    rows: [
+      [
+        { label: params.yesLabel ?? "Yes", value: params.yes, style: "success" },
+        { label: params.noLabel ?? "No", value: params.no, style: "danger" },
+      ],
+    ],
+  };
+}
+
+/** Option picker: one button per option, wrapped into rows of `columns`. */
+export function optionPicker(
+  options: InteractiveOption[],
+  opts: { columns?: number; selected?: string } = {},
+): InteractiveReply {
+  const columns = Math.max(1, Math.floor(opts.columns ?? DEFAULT_PICKER_COLUMNS));
+  const rows: InteractiveButton[][] = [];
+  for (const option of options) {
+    const button: InteractiveButton = {
+      label: option.label,
+      value: option.value,
+      ...(opts.selected !== undefined && option.value === opts.selected
+        ? { style: "primary" as const }
+        : {}),
+    };
+    const last = rows.at(-1);
+    if (last && last.length < columns) {
+      last.push(button);
+    } else {
+      rows.push([button]);
+    }
+  }
+  return { rows };
+}
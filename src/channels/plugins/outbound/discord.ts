@@ -1,16 +1,22 @@
+import { buildDiscordInteractiveSpec } from "../../../discord/interactive.js";
 import {
   getThreadBindingManager,
   type ThreadBindingRecord,
 } from "../../../discord/monitor/thread-bindings.js";
 import {
+  sendDiscordComponentMessage,
   sendMessageDiscord,
   sendPollDiscord,
   sendWebhookMessageDiscord,
 } from "../../../discord/send.js";
+import type { OutboundDeliveryResult } from "../../../infra/outbound/deliver.js";
 import type { OutboundIdentity } from "../../../infra/outbound/identity.js";
 import { normalizeDiscordOutboundTarget } from "../normalize/discord.js";
 import type { ChannelOutboundAdapter } from "../types.js";
 
+// Component messages carry the text in a single display; longer replies go out as plain text.
+const INTERACTIVE_TEXT_LIMIT = 2000;
+
 function resolveDiscordOutboundTarget(params: {
   to: string;
   threadId?: string | number | null;
@@ -126,6 +132,37 @@ export const discordOutbound: ChannelOutboundAdapter = {
     });
     return { channel: "discord", ...result };
   },
+  sendPayload: async (ctx) => {
+    const { payload } = ctx;
+    const text = payload.text ?? "";
+    const mediaUrls = payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : []);
+    const spec =
+      mediaUrls.length === 0 && text.length <= INTERACTIVE_TEXT_LIMIT
+        ? buildDiscordInteractiveSpec(payload.interactive, text)
+        : undefined;
+    if (spec) {
+      const target = resolveDiscordOutboundTarget({ to: ctx.to, threadId: ctx.threadId });
+      const result = await sendDiscordComponentMessage(target, spec, {
+        accountId: ctx.accountId ?? undefined,
+        replyTo: ctx.replyToId ?? undefined,
+        silent: ctx.silent ?? undefined,
+      });
+      return { channel: "discord", ...result };
+    }
+    if (mediaUrls.length === 0) {
+      return await discordOutbound.sendText!({ ...ctx, text });
+    }
+    // Caption only the first attachment, like the generic delivery loop.
+    let finalResult: OutboundDeliveryResult | undefined;
+    for (let i = 0; i < mediaUrls.length; i += 1) {
+      finalResult = await discordOutbound.sendMedia!({
+        ...ctx,
+        text: i === 0 ? text : "",
+        mediaUrl: mediaUrls[i],
+      });
+    }
+    return finalResult ?? { channel: "discord", messageId: "unknown", channelId: ctx.to };
+  },
   sendPoll: async ({ to, poll, accountId, threadId, silent }) => {
     const target = resolveDiscordOutboundTarget({ to, threadId });
     return await sendPollDiscord(target, poll, {
@@ -1,5 +1,7 @@
+import type { Block, KnownBlock } from "@slack/web-api";
 import type { OutboundIdentity } from "../../../infra/outbound/identity.js";
 import { getGlobalHookRunner } from "../../../plugins/hook-runner-global.js";
+import { buildSlackInteractiveBlocks } from "../../../slack/interactive.js";
 import { sendMessageSlack, type SlackSendIdentity } from "../../../slack/send.js";
 import type { ChannelOutboundAdapter } from "../types.js";
 
@@ -51,6 +53,7 @@ async function sendSlackOutboundMessage(params: {
   text: string;
   mediaUrl?: string;
   mediaLocalRoots?: readonly string[];
+  blocks?: (Block | KnownBlock)[];
   accountId?: string | null;
   deps?: { sendSlack?: typeof sendMessageSlack } | null;
   replyToId?: string | null;
@@ -84,6 +87,7 @@ async function sendSlackOutboundMessage(params: {
     ...(params.mediaUrl
       ? { mediaUrl: params.mediaUrl, mediaLocalRoots: params.mediaLocalRoots }
       : {}),
+    ...(params.blocks ? { blocks: params.blocks } : {}),
     ...(slackIdentity ? { identity: slackIdentity } : {}),
   });
   return { channel: "slack" as const, ...result };
@@ -126,5 +130,26 @@ export const slackOutbound: ChannelOutboundAdapter = {
       threadId,
       identity,
     });
+  },  sendPayload: async (ctx) => {
+    const { payload } = ctx;
+    const text = payload.text ?? "";
+    const mediaUrls = payload.mediaUrls ?? (payload.mediaUrl ? [payload.mediaUrl] : []);
+    if (mediaUrls.length === 0) {
+      return await sendSlackOutboundMessage({
+        ...ctx,
+        text,
+        blocks: buildSlackInteractiveBlocks(payload.interactive, text),
+      });
+    }
+    // Slack cannot attach blocks to uploads; media payloads drop the buttons.
+    let finalResult: Awaited<ReturnType<typeof sendSlackOutboundMessage>> | undefined;
+    for (let i = 0; i < mediaUrls.length; i += 1) {
+      finalResult = await sendSlackOutboundMessage({
+        ...ctx,
+        text: i === 0 ? text : "",
+        mediaUrl: mediaUrls[i],
+      });
+    }
+    return finalResult ?? { channel: "slack", messageId: "unknown", channelId: ctx.to };
   },
 };
@@ -1,6 +1,6 @@
 import type { OutboundSendDeps } from "../../../infra/outbound/deliver.js";
-import type { TelegramInlineButtons } from "../../../telegram/button-types.js";
 import { markdownToTelegramHtmlChunks } from "../../../telegram/format.js";
+import { resolveTelegramReplyButtons } from "../../../telegram/interactive.js";
 import {
   parseTelegramReplyToMessageId,
   parseTelegramThreadId,
@@ -83,11 +83,10 @@ export const telegramOutbound: ChannelOutboundAdapter = {
       replyToId,
       threadId,
     });
-    const telegramData = payload.channelData?.telegram as
-      | { buttons?: TelegramInlineButtons; quoteText?: string }
-      | undefined;
+    const telegramData = payload.channelData?.telegram as { quoteText?: string } | undefined;
     const quoteText =
       typeof telegramData?.quoteText === "string" ? telegramData.quoteText : undefined;
+    const buttons = resolveTelegramReplyButtons(payload);
     const text = payload.text ?? "";
     const mediaUrls = payload.mediaUrls?.length
       ? payload.mediaUrls
@@ -103,7 +102,7 @@ export const telegramOutbound: ChannelOutboundAdapter = {
     if (mediaUrls.length === 0) {
       const result = await send(to, text, {
         ...payloadOpts,
-        buttons: telegramData?.buttons,
+        buttons,
       });
       return { channel: "telegram", ...result };
     }
@@ -116,7 +115,7 @@ export const telegramOutbound: ChannelOutboundAdapter = {
       finalResult = await send(to, isFirst ? text : "", {
         ...payloadOpts,
         mediaUrl,
-        ...(isFirst ? { buttons: telegramData?.buttons } : {}),
+        ...(isFirst ? { buttons } : {}),
       });
     }
     return { channel: "telegram", ...(finalResult ?? { messageId: "unknown", chatId: to }) };
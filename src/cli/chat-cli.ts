@@ -0,0 +1,45 @@
+import type { Command } from "commander";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { runChatRepl } from "../tui/chat-repl.js";
+import { parseTimeoutMs } from "./parse-timeout.js";
+
+export function registerChatCli(program: Command) {
+  program
+    .command("chat")
+    .description("Chat with an agent in a line-mode REPL connected to the Gateway")
+    .option("--url <url>", "Gateway WebSocket URL (defaults to gateway.remote.url when configured)")
+    .option("--token <token>", "Gateway token (if required)")
+    .option("--password <password>", "Gateway password (if required)")
+    .option("--session <key>", 'Session key (default: "main", or "global" when scope is global)')
+    .option("--thinking <level>", "Thinking level override")
+    .option("--timeout-ms <ms>", "Agent timeout in ms (defaults to agents.defaults.timeoutSeconds)")
+    .option("--tool-output", "Print tool results inline (needs /verbose full)", false)
+    .addHelpText(
+      "after",
+      () => `\n${theme.muted("Docs:")} ${formatDocsLink("/cli/chat", "docs.openclaw.ai/cli/chat")}\n`,
+    )
+    .action(async (opts) => {
+      try {
+        const timeoutMs = parseTimeoutMs(opts.timeoutMs);
+        if (opts.timeoutMs !== undefined && timeoutMs === undefined) {
+          defaultRuntime.error(
+            `warning: invalid --timeout-ms "${String(opts.timeoutMs)}"; ignoring`,
+          );
+        }
+        await runChatRepl({
+          url: opts.url as string | undefined,
+          token: opts.token as string | undefined,
+          password: opts.password as string | undefined,
+          session: opts.session as string | undefined,
+          thinking: opts.thinking as string | undefined,
+          timeoutMs,
+          toolOutput: Boolean(opts.toolOutput),
+        });
+      } catch (err) {
+        defaultRuntime.error(String(err));
+        defaultRuntime.exit(1);
+      }
+    });
+}
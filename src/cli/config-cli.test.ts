@@ -270,4 +270,34 @@ describe("config cli", () => {
       });
     });
   });
+  describe("config validate", () => {
+    it("reports a valid config without writing", async () => {
+      setSnapshot({ gateway: { port: 18789 } }, { gateway: { port: 18789 } });
+
+      await runConfigCommand(["config", "validate"]);
+
+      expect(mockLog).toHaveBeenCalledWith(expect.stringContaining("Config valid"));
+      expect(mockExit).not.toHaveBeenCalled();
+      expect(mockWriteConfigFile).not.toHaveBeenCalled();
+    });
+
+    it("lists issues and exits 1 when invalid", async () => {
+      mockReadConfigFileSnapshot.mockResolvedValueOnce({
+        ...buildSnapshot({ resolved: {}, config: {} }),
+        valid: false,
+        issues: [{ path: "gateway.port", message: "Expected number, received string" }],
+      });
+
+      await expect(runConfigCommand(["config", "validate", "--json"])).rejects.toThrow(
+        "__exit__:1",
+      );
+
+      const output = JSON.parse(String(mockLog.mock.calls[0]?.[0]));
+      expect(output).toMatchObject({
+        path: "/tmp/openclaw.json",
+        valid: false,
+        issues: [{ path: "gateway.port", message: "Expected number, received string" }],
+      });
+    });
+  });
 });
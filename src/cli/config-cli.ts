@@ -296,11 +296,43 @@ export async function runConfigUnset(opts: { path: string; runtime?: RuntimeEnv
   }
 }
 
+export async function runConfigValidate(opts: { json?: boolean; runtime?: RuntimeEnv } = {}) {
+  const runtime = opts.runtime ?? defaultRuntime;
+  try {
+    const snapshot = await readConfigFileSnapshot();
+    if (opts.json) {
+      const { path, exists, valid, issues, warnings } = snapshot;
+      runtime.log(JSON.stringify({ path, exists, valid, issues, warnings }, null, 2));
+    } else {
+      const location = shortenHomePath(snapshot.path);
+      if (!snapshot.exists) {
+        runtime.log(theme.muted(`No config file at ${location}; defaults apply.`));
+      } else if (snapshot.valid) {
+        runtime.log(theme.success(`Config valid: ${location}`));
+      } else {
+        runtime.error(`Config invalid at ${location}.`);
+      }
+      for (const issue of snapshot.issues) {
+        runtime.error(`- ${issue.path || "<root>"}: ${issue.message}`);
+      }
+      for (const warning of snapshot.warnings) {
+        runtime.log(theme.warn(`- ${warning.path || "<root>"}: ${warning.message}`));
+      }
+    }
+    if (!snapshot.valid) {
+      runtime.exit(1);
+    }
+  } catch (err) {
+    runtime.error(danger(String(err)));
+    runtime.exit(1);
+  }
+}
+
 export function registerConfigCli(program: Command) {
   const cmd = program
     .command("config")
     .description(
-      "Non-interactive config helpers (get/set/unset). Run without subcommand for the setup wizard.",
+      "Non-interactive config helpers (get/set/unset/validate). Run without subcommand for the setup wizard.",
     )
     .addHelpText(
       "after",
@@ -361,4 +393,12 @@ export function registerConfigCli(program: Command) {
     .action(async (path: string) => {
       await runConfigUnset({ path });
     });
+
+  cmd
+    .command("validate")
+    .description("Check the config file against the schema without starting the gateway")
+    .option("--json", "Output JSON", false)
+    .action(async (opts) => {
+      await runConfigValidate({ json: Boolean(opts.json) });
+    });
 }
@@ -0,0 +1,183 @@
+import { describe, expect, it } from "vitest";
+import type { CostReport } from "../infra/cost-report.js";
+import {
+  renderCostReconcileReport,
+  renderCostReport,
+  resolveCostReportGroups,
+  resolveCostReportRange,
+} from "./cost-cli.js";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+describe("cost cli", () => {
+  it("resolves day windows ending today", () => {
+    const now = new Date(2026, 1, 10, 15, 30);
+    const range = resolveCostReportRange({ days: "7" }, now);
+    expect(range.startMs).toBe(new Date(2026, 1, 4).getTime());
+    expect(range.endMs).toBe(new Date(2026, 1, 11).getTime() - 1);
+  });
+
+  it("steps calendar days across DST changes", () => {
+    const originalTz = process.env.TZ;
+    process.env.TZ = "Europe/Berlin";
+    try {
+      const range = resolveCostReportRange({ days: "7" }, new Date(2026, 2, 30, 12));
+      expect(range.startMs).toBe(new Date(2026, 2, 24).getTime());
+      // March 29 is 23 hours long in Berlin.
+      const dstDay = resolveCostReportRange({ since: "2026-03-29", until: "2026-03-29" });
+      expect(dstDay.endMs - dstDay.startMs).toBe(DAY_MS - 60 * 60 * 1000 - 1);
+    } finally {
+      if (originalTz === undefined) {
+        delete process.env.TZ;
+      } else {
+        process.env.TZ = originalTz;
+      }
+    }
+  });
+
+  it("prefers explicit since/until dates", () => {
+    const range = resolveCostReportRange({ days: "7", since: "2026-01-01", until: "2026-01-31" });
+    expect(range.startMs).toBe(new Date(2026, 0, 1).getTime());
+    expect(range.endMs).toBe(new Date(2026, 0, 31).getTime() + DAY_MS - 1);
+  });
+
+  it("rejects malformed ranges", () => {
+    expect(() => resolveCostReportRange({ since: "01/02/2026" })).toThrow("YYYY-MM-DD");
+    expect(() => resolveCostReportRange({ days: "0" })).toThrow("--days");
+    expect(() => resolveCostReportRange({ since: "2026-02-02", until: "2026-02-01" })).toThrow(
+      "--since",
+    );
+  });
+
+  it("parses group selections", () => {
+    expect(resolveCostReportGroups(undefined)).toEqual(["day", "model", "channel"]);
+    expect(resolveCostReportGroups("model, day,model")).toEqual(["model", "day"]);
+    expect(resolveCostReportGroups("label")).toEqual(["label"]);
+    expect(() => resolveCostReportGroups("agent")).toThrow("--by");
+  });
+
+  it("renders tables for the selected groups", () => {
+    const report: CostReport = {
+      updatedAt: 0,
+      startMs: new Date(2026, 1, 1).getTime(),
+      endMs: new Date(2026, 1, 2).getTime() - 1,
+      sessions: 1,
+      totals: {
+        input: 10,
+        output: 10,
+        cacheRead: 0,
+        cacheWrite: 0,
+        totalTokens: 20,
+        totalCost: 1.5,
+        inputCost: 0,
+        outputCost: 0,
+        cacheReadCost: 0,
+        cacheWriteCost: 0,
+        missingCostEntries: 0,
+      },
+      byDay: [{ key: "2026-02-01", tokens: 20, cost: 1.5, count: 1 }],
+      byModel: [{ key: "openai/gpt-5.2", tokens: 20, cost: 1.5, count: 1 }],
+      byCanonicalModel: [
+        {
+          key: "gpt-5.2",
+          tokens: 20,
+          cost: 1.5,
+          count: 1,
+          details: [
+            { key: "openai/gpt-5.2", tokens: 10, cost: 1, count: 1 },
+            { key: "openrouter/openai/gpt-5.2", tokens: 10, cost: 0.5, count: 0 },
+          ],
+        },
+      ],
+      byChannel: [],
+      byLabel: [{ key: "Cron: Nightly greenhouse report", tokens: 20, cost: 1.5, count: 1 }],
+      byCategory: [{ key: "chat", tokens: 20, cost: 1.5, count: 1 }],
+      zeroCostTokens: 0,
+    };
+    const output = renderCostReport(report, ["model", "channel"], { rich: false, width: 80 }).join(
+      "\n",
+    );
+    expect(output).toContain("Cost report (2026-02-01 → 2026-02-01)");
+    expect(output).toContain("$1.50");
+    expect(output).toContain("openai/gpt-5.2");
+    expect(output).not.toContain("By day");
+    expect(output).toContain("No usage in this period.");
+
+    const canonical = renderCostReport(report, ["canonical"], { rich: false, width: 80 }).join(
+      "\n",
+    );
+    expect(canonical).toContain("By canonical model");
+    expect(canonical).toContain("↳ openrouter/openai/gpt-5.2");
+
+    const labels = renderCostReport(report, ["label"], { rich: false, width: 80 }).join("\n");
+    expect(labels).toContain("By label");
+    expect(labels).toContain("Cron: Nightly greenhouse report");
+
+    const eur = renderCostReport(report, ["day"], {
+      rich: false,
+      width: 80,
+      currency: { code: "EUR", usdRate: 0.5 },
+    }).join("\n");
+    expect(eur).toContain("€0.75");
+    expect(eur).not.toContain("$");
+    expect(output).not.toContain("Prompt cache:");
+
+    const cached = renderCostReport(
+      {
+        ...report,
+        totals: {
+          ...report.totals,
+          input: 1000,
+          cacheRead: 8000,
+          cacheWrite: 1000,
+          cacheReadCost: 0.024,
+          cacheWriteCost: 0.01875,
+        },
+      },
+      ["day"],
+      { rich: false, width: 80 },
+    ).join("\n");
+    expect(cached).toContain(
+      "Prompt cache: 8.0k read · 1.0k written · 80% of prompt tokens · $0.02 read / $0.02 write",
+    );
+  });
+
+  it("renders reconciliation drift", () => {
+    const output = renderCostReconcileReport(
+      {
+        version: 1,
+        generatedAt: 0,
+        startMs: Date.UTC(2026, 1, 1),
+        endMs: Date.UTC(2026, 1, 8),
+        driftThresholdPercent: 10,
+        providers: [
+          {
+            provider: "openai",
+            billedUsd: 10,
+            trackedUsd: 8,
+            driftUsd: -2,
+            driftPercent: -20,
+            flagged: true,
+            billedDays: [],
+          },
+          {
+            provider: "anthropic",
+            billedUsd: 0,
+            trackedUsd: 1,
+            driftUsd: 1,
+            driftPercent: 100,
+            flagged: false,
+            billedDays: [],
+            error: "HTTP 401: invalid x-api-key",
+          },
+        ],
+      },
+      { rich: false, width: 80 },
+    ).join("\n");
+    expect(output).toContain("Billing reconciliation (2026-02-01 → 2026-02-07 UTC)");
+    expect(output).toContain("-$2.00");
+    expect(output).toContain("-20.0%");
+    expect(output).toContain("anthropic: HTTP 401: invalid x-api-key");
+    expect(output).toContain("Drift over 10%: openai");
+  });
+});
@@ -0,0 +1,513 @@
+import { setTimeout as delay } from "node:timers/promises";
+import type { Command } from "commander";
+import { loadConfig } from "../config/config.js";
+import { loadCostBudgetSpend } from "../infra/cost-budget.js";
+import { buildCostDigest, sendCostDigest } from "../infra/cost-digest.js";
+import { runCostReconciliation, type CostReconcileReport } from "../infra/cost-reconcile.js";
+import { compactCostRecords } from "../infra/cost-retention.js";
+import {
+  COST_REPORT_GROUPS,
+  DEFAULT_COST_REPORT_GROUPS,
+  loadCostReport,
+  type CostReport,
+  type CostReportGroup,
+  type CostReportRow,
+} from "../infra/cost-report.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { renderTable } from "../terminal/table.js";
+import { colorize, isRich, theme } from "../terminal/theme.js";
+import {
+  formatCost,
+  formatTokenCount,
+  resolveCostDisplayCurrency,
+  USD_DISPLAY_CURRENCY,
+  type CostDisplayCurrency,
+} from "../utils/usage-format.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { renderCostDashboard } from "./cost-dashboard.js";
+import { formatHelpExamples } from "./help-format.js";
+
+type CostDashboardOptions = {
+  days?: string;
+  agent?: string;
+  watch?: boolean;
+  interval?: string;
+};
+
+type CostCompactOptions = {
+  rawDays?: string;
+  dryRun?: boolean;
+  json?: boolean;
+};
+
+type CostDigestOptions = {
+  weekly?: boolean;
+  send?: boolean;
+};
+
+type CostReconcileOptions = {
+  days?: string;
+  json?: boolean;
+};
+
+type CostReportOptions = {
+  days?: string;
+  since?: string;
+  until?: string;
+  agent?: string;
+  channel?: string;
+  label?: string;
+  by?: string;
+  currency?: string;
+  rate?: string;
+  json?: boolean;
+};
+
+function parseLocalDate(raw: string | undefined, label: string): number | undefined {
+  const trimmed = raw?.trim();
+  if (!trimmed) {
+    return undefined;
+  }
+  const match = /^(\d{4})-(\d{2})-(\d{2})$/.exec(trimmed);
+  if (!match) {
+    throw new Error(`${label} must be YYYY-MM-DD (got "${trimmed}")`);
+  }
+  const ms = new Date(Number(match[1]), Number(match[2]) - 1, Number(match[3])).getTime();
+  if (Number.isNaN(ms)) {
+    throw new Error(`${label} is not a valid date: ${trimmed}`);
+  }
+  return ms;
+}
+
+/** Local midnight `days` calendar days from `ms`; DST days are 23 or 25 hours long. */
+function addLocalDays(ms: number, days: number): number {
+  const date = new Date(ms);
+  return new Date(date.getFullYear(), date.getMonth(), date.getDate() + days).getTime();
+}
+
+export function resolveCostReportRange(
+  opts: Pick<CostReportOptions, "days" | "since" | "until">,
+  now = new Date(),
+): { startMs: number; endMs: number } {
+  const todayStartMs = addLocalDays(now.getTime(), 0);
+  const untilStartMs = parseLocalDate(opts.until, "--until") ?? todayStartMs;
+  const endMs = addLocalDays(untilStartMs, 1) - 1;
+  const sinceMs = parseLocalDate(opts.since, "--since");
+  if (sinceMs !== undefined) {
+    if (sinceMs > endMs) {
+      throw new Error("--since must not be after --until");
+    }
+    return { startMs: sinceMs, endMs };
+  }
+  const rawDays = opts.days?.trim() ? Number(opts.days) : 30;
+  if (!Number.isFinite(rawDays) || rawDays < 1) {
+    throw new Error(`--days must be a positive number (got "${opts.days}")`);
+  }
+  const days = Math.floor(rawDays);
+  return { startMs: addLocalDays(untilStartMs, -(days - 1)), endMs };
+}
+
+function parseRateOption(raw: string | undefined): number | undefined {
+  const trimmed = raw?.trim();
+  if (!trimmed) {
+    return undefined;
+  }
+  const rate = Number(trimmed);
+  if (!Number.isFinite(rate) || rate <= 0) {
+    throw new Error(`--rate must be a positive number (got "${trimmed}")`);
+  }
+  return rate;
+}
+
+export function resolveCostReportGroups(raw: string | undefined): CostReportGroup[] {
+  const trimmed = raw?.trim();
+  if (!trimmed) {
+    return [...DEFAULT_COST_REPORT_GROUPS];
+  }
+  const groups: CostReportGroup[] = [];
+  for (const part of trimmed.split(",")) {
+    const value = part.trim().toLowerCase();
+    if (!value) {
+      continue;
+    }
+    if (!COST_REPORT_GROUPS.includes(value as CostReportGroup)) {
+      throw new Error(`--by must be one of ${COST_REPORT_GROUPS.join(", ")} (got "${value}")`);
+    }
+    if (!groups.includes(value as CostReportGroup)) {
+      groups.push(value as CostReportGroup);
+    }
+  }
+  return groups.length > 0 ? groups : [...DEFAULT_COST_REPORT_GROUPS];
+}
+
+const formatDate = (ms: number) => new Date(ms).toLocaleDateString("en-CA");
+
+const GROUP_TITLES: Record<CostReportGroup, { title: string; keyHeader: string; count: string }> =
+  {
+    day: { title: "By day", keyHeader: "Date", count: "Sessions" },
+    model: { title: "By model", keyHeader: "Model", count: "Messages" },
+    canonical: { title: "By canonical model", keyHeader: "Model", count: "Messages" },
+    channel: { title: "By channel", keyHeader: "Channel", count: "Messages" },
+    label: { title: "By label", keyHeader: "Label", count: "Messages" },
+    category: { title: "By category", keyHeader: "Category", count: "Requests" },
+  };
+
+function rowsForGroup(report: CostReport, group: CostReportGroup): CostReportRow[] {
+  if (group === "day") {
+    return report.byDay;
+  }
+  if (group === "model") {
+    return report.byModel;
+  }
+  if (group === "canonical") {
+    return report.byCanonicalModel;
+  }
+  if (group === "label") {
+    return report.byLabel;
+  }
+  if (group === "category") {
+    return report.byCategory;
+  }
+  return report.byChannel;
+}
+
+/**
+ * Cache reads/writes as reported by providers with prompt caching (Anthropic cache_read /
+ * cache_creation tokens, OpenAI cached input). Hit rate is the share of prompt tokens served
+ * from cache.
+ */
+function formatPromptCacheLine(
+  report: CostReport,
+  money: (value: number) => string,
+): string | undefined {
+  const { input, cacheRead, cacheWrite, cacheReadCost, cacheWriteCost } = report.totals;
+  if (cacheRead <= 0 && cacheWrite <= 0) {
+    return undefined;
+  }
+  const promptTokens = input + cacheRead + cacheWrite;
+  const hitRate = promptTokens > 0 ? Math.round((cacheRead / promptTokens) * 100) : 0;
+  const parts = [
+    `${formatTokenCount(cacheRead)} read`,
+    `${formatTokenCount(cacheWrite)} written`,
+    `${hitRate}% of prompt tokens`,
+  ];
+  if (cacheReadCost > 0 || cacheWriteCost > 0) {
+    parts.push(`${money(cacheReadCost)} read / ${money(cacheWriteCost)} write`);
+  }
+  return parts.join(" · ");
+}
+
+export function renderCostReport(
+  report: CostReport,
+  groups: CostReportGroup[],
+  opts: { rich: boolean; width?: number; currency?: CostDisplayCurrency },
+): string[] {
+  const { rich } = opts;
+  const currency = opts.currency ?? USD_DISPLAY_CURRENCY;
+  const width = opts.width ?? Math.max(60, (process.stdout.columns ?? 120) - 1);
+  const money = (value: number) => formatCost(value, currency) ?? "n/a";
+  const totalCost = money(report.totals.totalCost);
+  const lines = [
+    colorize(
+      rich,
+      theme.heading,
+      `Cost report (${formatDate(report.startMs)} → ${formatDate(report.endMs)})`,
+    ),
+    `${colorize(rich, theme.muted, "Total:")} ${totalCost} · ${formatTokenCount(report.totals.totalTokens)} tokens · ${report.sessions} sessions`,
+  ];
+  const cacheLine = formatPromptCacheLine(report, money);
+  if (cacheLine) {
+    lines.push(`${colorize(rich, theme.muted, "Prompt cache:")} ${cacheLine}`);
+  }
+  if (report.zeroCostTokens > 0) {
+    lines.push(
+      `${colorize(rich, theme.muted, "Zero-cost (local) models:")} ${formatTokenCount(report.zeroCostTokens)} tokens`,
+    );
+  }
+  if (report.totals.missingCostEntries > 0) {
+    lines.push(
+      `${colorize(rich, theme.muted, "Missing cost entries:")} ${report.totals.missingCostEntries}`,
+    );
+  }
+
+  for (const group of groups) {
+    const rows = rowsForGroup(report, group);
+    const labels = GROUP_TITLES[group];
+    lines.push("");
+    lines.push(colorize(rich, theme.heading, labels.title));
+    if (rows.length === 0) {
+      lines.push(colorize(rich, theme.muted, "No usage in this period."));
+      continue;
+    }
+    lines.push(
+      renderTable({
+        width,
+        columns: [
+          { key: "key", header: labels.keyHeader, minWidth: 12, flex: true },
+          { key: "tokens", header: "Tokens", align: "right", minWidth: 8 },
+          { key: "cost", header: "Cost", align: "right", minWidth: 8 },
+          { key: "count", header: labels.count, align: "right", minWidth: 8 },
+        ],
+        rows: rows.flatMap((row) => {
+          const toTableRow = (entry: CostReportRow, label: string) => ({
+            key: label,
+            tokens: formatTokenCount(entry.tokens),
+            cost: entry.zeroCost ? "free" : money(entry.cost),
+            count: String(entry.count),
+          });
+          const details = row.details ?? [];
+          // Only break out providers when more than one route served the model.
+          const detailRows =
+            details.length > 1
+              ? details.map((detail) => toTableRow(detail, `  ↳ ${detail.key}`))
+              : [];
+          return [toTableRow(row, row.key), ...detailRows];
+        }),
+      }).trimEnd(),
+    );
+  }
+  return lines;
+}
+
+export function renderCostReconcileReport(
+  report: CostReconcileReport,
+  opts: { rich: boolean; width?: number },
+): string[] {
+  const { rich } = opts;
+  const width = opts.width ?? Math.max(60, (process.stdout.columns ?? 120) - 1);
+  const money = (value: number) => formatCost(value) ?? "n/a";
+  const lines = [
+    colorize(
+      rich,
+      theme.heading,
+      `Billing reconciliation (${new Date(report.startMs).toISOString().slice(0, 10)} → ${new Date(report.endMs - 1).toISOString().slice(0, 10)} UTC)`,
+    ),
+    renderTable({
+      width,
+      columns: [
+        { key: "provider", header: "Provider", minWidth: 10, flex: true },
+        { key: "billed", header: "Billed", align: "right", minWidth: 9 },
+        { key: "tracked", header: "Tracked", align: "right", minWidth: 9 },
+        { key: "drift", header: "Drift", align: "right", minWidth: 9 },
+        { key: "percent", header: "Drift %", align: "right", minWidth: 8 },
+      ],
+      rows: report.providers.map((row) => {
+        const sign = row.driftUsd < 0 ? "-" : "+";
+        const percent = `${sign}${Math.abs(row.driftPercent).toFixed(1)}%`;
+        return {
+          provider: row.provider,
+          billed: row.error ? "error" : money(row.billedUsd),
+          tracked: money(row.trackedUsd),
+          drift: row.error ? "" : `${sign}${money(Math.abs(row.driftUsd))}`,
+          percent: row.error ? "" : row.flagged ? colorize(rich, theme.warn, percent) : percent,
+        };
+      }),
+    }).trimEnd(),
+  ];
+  for (const row of report.providers) {
+    if (row.error) {
+      lines.push(colorize(rich, theme.error, `${row.provider}: ${row.error}`));
+    }
+  }
+  const flagged = report.providers.filter((row) => row.flagged);
+  lines.push(
+    flagged.length > 0
+      ? colorize(
+          rich,
+          theme.warn,
+          `Drift over ${report.driftThresholdPercent}%: ${flagged.map((row) => row.provider).join(", ")}`,
+        )
+      : colorize(rich, theme.muted, `No drift over ${report.driftThresholdPercent}%.`),
+  );
+  return lines;
+}
+
+export function registerCostCli(program: Command) {
+  const cost = program
+    .command("cost")
+    .description("Inspect model usage cost from local session logs")
+    .addHelpText(
+      "after",
+      () =>
+        `\n${theme.heading("Examples:")}\n${formatHelpExamples([
+          ["openclaw cost report", "Totals by day, model, and channel for the last 30 days."],
+          ["openclaw cost report --days 7 --by model", "Last week, grouped by model only."],
+          ["openclaw cost report --by label", "Spend per cron job and sub-agent task label."],
+          ["openclaw cost dashboard --watch", "Live spend, model, and budget view."],
+          ["openclaw cost reconcile --days 30", "Compare tracked cost with provider billing."],
+          ["openclaw cost digest --weekly", "Preview the weekly digest message."],
+          [
+            "openclaw cost report --since 2026-02-01 --until 2026-02-28 --json",
+            "A fixed period as JSON for scripts.",
+          ],
+        ])}\n\n${theme.muted("Docs:")} ${formatDocsLink("/cli/cost", "docs.openclaw.ai/cli/cost")}\n`,
+    )
+    .action(() => {
+      cost.help({ error: true });
+    });
+
+  cost
+    .command("report")
+    .description("Print usage totals by day, model, and channel")
+    .option("--days <days>", "Number of days to include (ending today or --until)", "30")
+    .option("--since <date>", "Start date (YYYY-MM-DD, local time; overrides --days)")
+    .option("--until <date>", "End date inclusive (YYYY-MM-DD, local time)")
+    .option("--agent <id>", "Only include sessions for this agent")
+    .option("--channel <id>", "Only include sessions from this channel")
+    .option("--label <label>", "Only include sessions with this label (e.g. \"Cron: Nightly\")")
+    .option(
+      "--by <groups>",
+      "Comma-separated groups: day, model, canonical, channel, label, category",
+    )
+    .option("--currency <code>", "Display currency (ISO 4217; default: cost.currency.code)")
+    .option("--rate <rate>", "Units of the display currency per 1 USD")
+    .option("--json", "Output JSON (amounts stay in USD)", false)
+    .action(async (opts: CostReportOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const { startMs, endMs } = resolveCostReportRange(opts);
+        const groups = resolveCostReportGroups(opts.by);
+        const config = loadConfig();
+        const usdRate = parseRateOption(opts.rate);
+        const currency = resolveCostDisplayCurrency({ config, code: opts.currency, usdRate });
+        if (opts.currency?.trim() && currency.code !== opts.currency.trim().toUpperCase()) {
+          throw new Error(
+            `No exchange rate for ${opts.currency.trim().toUpperCase()}; pass --rate or set cost.currency.usdRate`,
+          );
+        }
+        const report = await loadCostReport({
+          config,
+          startMs,
+          endMs,
+          agentId: opts.agent,
+          channel: opts.channel,
+          label: opts.label,
+        });
+        if (opts.json) {
+          defaultRuntime.log(JSON.stringify({ ...report, currency }, null, 2));
+          return;
+        }
+        for (const line of renderCostReport(report, groups, { rich: isRich(), currency })) {
+          defaultRuntime.log(line);
+        }
+      });
+    });
+
+  cost
+    .command("dashboard")
+    .description("Show spend trend, per-model bars, and budget gauges")
+    .option("--days <days>", "Number of days in the spend chart", "14")
+    .option("--agent <id>", "Only include sessions for this agent")
+    .option("--watch", "Refresh until interrupted", false)
+    .option("--interval <seconds>", "Refresh interval for --watch", "10")
+    .action(async (opts: CostDashboardOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const intervalSeconds = Number(opts.interval ?? "10");
+        if (!Number.isFinite(intervalSeconds) || intervalSeconds < 1) {
+          throw new Error(`--interval must be at least 1 second (got "${opts.interval}")`);
+        }
+        const rich = isRich();
+        while (true) {
+          const { startMs, endMs } = resolveCostReportRange({ days: opts.days ?? "14" });
+          const config = loadConfig();
+          const [report, spend] = await Promise.all([
+            loadCostReport({ config, startMs, endMs, agentId: opts.agent }),
+            loadCostBudgetSpend({ config }),
+          ]);
+          const lines = renderCostDashboard({
+            report,
+            budget: config.cost?.budget,
+            spend,
+            rich,
+            currency: resolveCostDisplayCurrency({ config }),
+          });
+          if (opts.watch && process.stdout.isTTY) {
+            // Clear screen + home cursor so each refresh redraws in place.
+            process.stdout.write("\x1b[2J\x1b[H");
+          }
+          for (const line of lines) {
+            defaultRuntime.log(line);
+          }
+          if (!opts.watch) {
+            return;
+          }
+          await delay(intervalSeconds * 1000);
+        }
+      });
+    });
+
+  cost
+    .command("compact")
+    .description("Fold old usage into daily summaries")
+    .option("--raw-days <days>", "Days of usage to leave raw (default: cost.retention.rawDays)")
+    .option("--dry-run", "Show what would be summarized", false)
+    .option("--json", "Output JSON", false)
+    .action(async (opts: CostCompactOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const config = loadConfig();
+        const rawDays = opts.rawDays?.trim()
+          ? Number(opts.rawDays)
+          : config.cost?.retention?.rawDays;
+        if (rawDays === undefined) {
+          throw new Error("Set cost.retention.rawDays or pass --raw-days");
+        }
+        if (!Number.isInteger(rawDays) || rawDays < 1) {
+          throw new Error(`--raw-days must be a positive integer (got "${opts.rawDays}")`);
+        }
+        const result = await compactCostRecords({ config, rawDays, dryRun: opts.dryRun });
+        if (opts.json) {
+          defaultRuntime.log(JSON.stringify({ ...result, dryRun: Boolean(opts.dryRun) }, null, 2));
+          return;
+        }
+        if (result.addedRows === 0) {
+          defaultRuntime.log(colorize(isRich(), theme.muted, "Nothing to compact."));
+          return;
+        }
+        const summarize = opts.dryRun ? "Would summarize" : "Summarized";
+        defaultRuntime.log(
+          `${summarize} usage through ${result.summarizedThrough} (${result.addedRows} rows).`,
+        );
+      });
+    });
+
+  cost
+    .command("digest")
+    .description("Preview the scheduled cost digest, or send it now to cost.digest.channel")
+    .option("--weekly", "Cover the previous seven days instead of yesterday", false)
+    .option("--send", "Deliver to cost.digest.channel / cost.digest.to", false)
+    .action(async (opts: CostDigestOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const config = loadConfig();
+        const schedule = opts.weekly ? "weekly" : (config.cost?.digest?.schedule ?? "daily");
+        const { text } = await buildCostDigest({ config, schedule });
+        if (opts.send) {
+          await sendCostDigest({ config, text });
+          defaultRuntime.log(colorize(isRich(), theme.muted, "Cost digest sent."));
+          return;
+        }
+        defaultRuntime.log(text);
+      });
+    });
+
+  cost
+    .command("reconcile")
+    .description("Compare tracked cost with provider billing APIs (OpenAI, Anthropic)")
+    .option("--days <days>", "Full UTC days to compare (default: cost.reconcile.lookbackDays or 7)")
+    .option("--json", "Output JSON", false)
+    .action(async (opts: CostReconcileOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const days = opts.days?.trim() ? Number(opts.days) : undefined;
+        if (days !== undefined && (!Number.isInteger(days) || days < 1)) {
+          throw new Error(`--days must be a positive integer (got "${opts.days}")`);
+        }
+        const report = await runCostReconciliation({ config: loadConfig(), days });
+        if (opts.json) {
+          defaultRuntime.log(JSON.stringify(report, null, 2));
+          return;
+        }
+        for (const line of renderCostReconcileReport(report, { rich: isRich() })) {
+          defaultRuntime.log(line);
+        }
+      });
+    });
+}
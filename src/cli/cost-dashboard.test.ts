@@ -0,0 +1,78 @@
+import { describe, expect, it } from "vitest";
+import type { CostReport } from "../infra/cost-report.js";
+import {
+  renderBar,
+  renderCostDashboard,
+  renderSparkline,
+  resolveDailySeries,
+} from "./cost-dashboard.js";
+
+const report: CostReport = {
+  updatedAt: 0,
+  startMs: new Date(2026, 1, 1).getTime(),
+  endMs: new Date(2026, 1, 4).getTime() - 1,
+  sessions: 2,
+  totals: {
+    input: 0,
+    output: 0,
+    cacheRead: 0,
+    cacheWrite: 0,
+    totalTokens: 3000,
+    totalCost: 3,
+    inputCost: 0,
+    outputCost: 0,
+    cacheReadCost: 0,
+    cacheWriteCost: 0,
+    missingCostEntries: 0,
+  },
+  byDay: [
+    { key: "2026-02-01", tokens: 1000, cost: 1, count: 1 },
+    { key: "2026-02-03", tokens: 2000, cost: 2, count: 1 },
+  ],
+  byModel: [
+    { key: "anthropic/claude-opus-4-6", tokens: 2000, cost: 2, count: 3 },
+    { key: "openai/gpt-5.2", tokens: 1000, cost: 1, count: 2 },
+  ],
+  byCanonicalModel: [],
+  byChannel: [],
+  byLabel: [],
+  byCategory: [],
+  zeroCostTokens: 0,
+};
+
+describe("cost dashboard", () => {
+  it("renders bars and sparklines", () => {
+    expect(renderBar(0.5, 4)).toBe("██  ");
+    expect(renderBar(1 / 8, 4)).toBe("▌   ");
+    expect(renderBar(2, 3)).toBe("███");
+    expect(renderSparkline([0, 1, 2])).toBe("▁▅█");
+    expect(renderSparkline([0, 0])).toBe("▁▁");
+  });
+
+  it("fills missing days in the spend series", () => {
+    expect(resolveDailySeries(report)).toEqual([
+      { date: "2026-02-01", cost: 1 },
+      { date: "2026-02-02", cost: 0 },
+      { date: "2026-02-03", cost: 2 },
+    ]);
+  });
+
+  it("renders spend, model, and budget sections", () => {
+    const output = renderCostDashboard({
+      report,
+      budget: { dailyUsd: 4 },
+      spend: { dayUsd: 3.5, monthUsd: 10 },
+      rich: false,
+      width: 80,
+      now: new Date(2026, 1, 3, 12, 0),
+    }).join("\n");
+    expect(output).toContain("Cost dashboard · last 3 days");
+    expect(output).toContain("▅▁█  $3.00 total · $1.00/day avg · $2.00 peak");
+    expect(output).toContain("anthropic/claude-opus-4-6");
+    expect(output).toContain(" 88% $3.50 / $4.00");
+    expect(output).not.toContain("Monthly");
+
+    const noBudget = renderCostDashboard({ report, rich: false, width: 80 }).join("\n");
+    expect(noBudget).toContain("No budget configured (cost.budget).");
+  });
+});
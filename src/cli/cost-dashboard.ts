@@ -0,0 +1,136 @@
+import type { CostBudgetConfig } from "../config/types.cost.js";
+import type { CostBudgetSpend } from "../infra/cost-budget.js";
+import type { CostReport } from "../infra/cost-report.js";
+import { colorize, theme } from "../terminal/theme.js";
+import {
+  formatCost,
+  formatTokenCount,
+  USD_DISPLAY_CURRENCY,
+  type CostDisplayCurrency,
+} from "../utils/usage-format.js";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+const SPARK_LEVELS = ["▁", "▂", "▃", "▄", "▅", "▆", "▇", "█"];
+const PARTIAL_BLOCKS = ["", "▏", "▎", "▍", "▌", "▋", "▊", "▉"];
+const MAX_MODEL_ROWS = 8;
+
+/** Horizontal bar with 1/8-cell resolution, padded to `width` cells. */
+export function renderBar(fraction: number, width: number): string {
+  const safe = Number.isFinite(fraction) ? Math.min(1, Math.max(0, fraction)) : 0;
+  const eighths = Math.round(safe * width * 8);
+  const full = Math.floor(eighths / 8);
+  const partial = PARTIAL_BLOCKS[eighths % 8] ?? "";
+  const bar = "█".repeat(full) + partial;
+  return bar + " ".repeat(Math.max(0, width - full - (partial ? 1 : 0)));
+}
+
+export function renderSparkline(values: number[]): string {
+  const max = Math.max(0, ...values);
+  if (max <= 0) {
+    return SPARK_LEVELS[0].repeat(values.length);
+  }
+  return values
+    .map((value) => {
+      const index = Math.round((Math.max(0, value) / max) * (SPARK_LEVELS.length - 1));
+      return SPARK_LEVELS[index] ?? SPARK_LEVELS[0];
+    })
+    .join("");
+}
+
+/** Daily cost series covering every day in the report window (missing days are 0). */
+export function resolveDailySeries(report: CostReport): Array<{ date: string; cost: number }> {
+  const byDate = new Map(report.byDay.map((row) => [row.key, row.cost]));
+  const series: Array<{ date: string; cost: number }> = [];
+  const start = new Date(report.startMs);
+  for (
+    let day = new Date(start.getFullYear(), start.getMonth(), start.getDate());
+    day.getTime() <= report.endMs;
+    day = new Date(day.getFullYear(), day.getMonth(), day.getDate() + 1)
+  ) {
+    const date = day.toLocaleDateString("en-CA");
+    series.push({ date, cost: byDate.get(date) ?? 0 });
+  }
+  return series;
+}
+
+export function renderCostDashboard(params: {
+  report: CostReport;
+  budget?: CostBudgetConfig;
+  spend?: CostBudgetSpend;
+  rich: boolean;
+  width?: number;
+  currency?: CostDisplayCurrency;
+  now?: Date;
+}): string[] {
+  const { report, rich } = params;
+  const currency = params.currency ?? USD_DISPLAY_CURRENCY;
+  const width = params.width ?? Math.max(60, (process.stdout.columns ?? 100) - 1);
+  const money = (value: number) => formatCost(value, currency) ?? "n/a";
+  const days = Math.max(1, Math.round((report.endMs - report.startMs + 1) / DAY_MS));
+  const now = params.now ?? new Date();
+
+  const lines = [
+    colorize(rich, theme.heading, `Cost dashboard · last ${days} days`),
+    colorize(rich, theme.muted, `Updated ${now.toLocaleTimeString("en-GB")}`),
+    "",
+  ];
+
+  const series = resolveDailySeries(report);
+  const peak = series.reduce((max, entry) => Math.max(max, entry.cost), 0);
+  const average = series.length > 0 ? report.totals.totalCost / series.length : 0;
+  lines.push(colorize(rich, theme.heading, "Spend"));
+  lines.push(
+    `${colorize(rich, theme.accent, renderSparkline(series.map((entry) => entry.cost)))}  ${money(report.totals.totalCost)} total · ${money(average)}/day avg · ${money(peak)} peak`,
+  );
+  const last = series.at(-1);
+  if (last) {
+    lines.push(colorize(rich, theme.muted, `${series[0]?.date} → ${last.date}`));
+  }
+
+  lines.push("");
+  lines.push(colorize(rich, theme.heading, "Models"));
+  const models = report.byModel.slice(0, MAX_MODEL_ROWS);
+  if (models.length === 0) {
+    lines.push(colorize(rich, theme.muted, "No usage in this period."));
+  } else {
+    const maxCost = Math.max(...models.map((row) => row.cost));
+    const labelWidth = Math.min(32, Math.max(...models.map((row) => row.key.length)));
+    const barWidth = Math.max(10, width - labelWidth - 24);
+    for (const row of models) {
+      const label = row.key.length > labelWidth ? `${row.key.slice(0, labelWidth - 1)}…` : row.key;
+      const fraction = maxCost > 0 ? row.cost / maxCost : 0;
+      lines.push(
+        `${label.padEnd(labelWidth)} ${colorize(rich, theme.accent, renderBar(fraction, barWidth))} ${(row.zeroCost ? "free" : money(row.cost)).padStart(9)} ${formatTokenCount(row.tokens).padStart(6)}`,
+      );
+    }
+    if (report.byModel.length > models.length) {
+      lines.push(
+        colorize(rich, theme.muted, `+${report.byModel.length - models.length} more models`),
+      );
+    }
+  }
+
+  lines.push("");
+  lines.push(colorize(rich, theme.heading, "Budget"));
+  const gauges: Array<{ label: string; limit?: number; spent: number }> = [
+    { label: "Daily", limit: params.budget?.dailyUsd, spent: params.spend?.dayUsd ?? 0 },
+    { label: "Monthly", limit: params.budget?.monthlyUsd, spent: params.spend?.monthUsd ?? 0 },
+  ].filter((gauge) => gauge.limit !== undefined);
+  if (gauges.length === 0) {
+    lines.push(colorize(rich, theme.muted, "No budget configured (cost.budget)."));
+  } else {
+    const barWidth = Math.max(10, Math.min(40, width - 40));
+    const softFraction = (params.budget?.softLimitPercent ?? 80) / 100;
+    for (const gauge of gauges) {
+      const limit = gauge.limit ?? 0;
+      const fraction = limit > 0 ? gauge.spent / limit : 1;
+      const tone =
+        fraction >= 1 ? theme.error : fraction >= softFraction ? theme.warn : theme.success;
+      const percent = `${Math.round(fraction * 100)}%`.padStart(4);
+      lines.push(
+        `${gauge.label.padEnd(8)} ${colorize(rich, tone, `[${renderBar(fraction, barWidth)}]`)} ${percent} ${money(gauge.spent)} / ${money(limit)}`,
+      );
+    }
+  }
+  return lines;
+}
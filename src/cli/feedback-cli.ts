@@ -0,0 +1,150 @@
+import fs from "node:fs";
+import type { Command } from "commander";
+import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { loadConfig } from "../config/config.js";
+import { resolveStorePath } from "../config/sessions.js";
+import { defaultRuntime } from "../runtime.js";
+import { loadFeedback, parseFeedbackRating, type FeedbackRecord } from "../sessions/feedback.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { renderTable } from "../terminal/table.js";
+import { colorize, isRich, theme } from "../terminal/theme.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+type FeedbackQueryOptions = {
+  agent?: string;
+  rating?: string;
+  session?: string;
+  limit?: string;
+};
+
+type FeedbackListOptions = FeedbackQueryOptions & { json?: boolean };
+
+type FeedbackExportOptions = FeedbackQueryOptions & { output?: string };
+
+function queryFeedback(opts: FeedbackQueryOptions): FeedbackRecord[] {
+  const cfg = loadConfig();
+  const agentId = opts.agent?.trim() || resolveDefaultAgentId(cfg);
+  const rating = opts.rating ? parseFeedbackRating(opts.rating) : undefined;
+  if (opts.rating && !rating) {
+    throw new Error(`Invalid --rating: ${opts.rating} (use up or down)`);
+  }
+  let limit: number | undefined;
+  if (opts.limit !== undefined) {
+    limit = Number.parseInt(opts.limit, 10);
+    if (!Number.isFinite(limit) || limit <= 0) {
+      throw new Error(`Invalid --limit: ${opts.limit}`);
+    }
+  }
+  const storePath = resolveStorePath(cfg.session?.store, { agentId });
+  return loadFeedback(storePath, { rating, sessionKey: opts.session?.trim() || undefined, limit });
+}
+
+function excerpt(text: string | undefined, maxChars = 60): string {
+  const flat = (text ?? "").replace(/\s+/g, " ").trim();
+  return flat.length > maxChars ? `${flat.slice(0, maxChars - 1)}…` : flat;
+}
+
+export function renderFeedbackList(
+  records: FeedbackRecord[],
+  opts: { rich: boolean; width?: number },
+): string[] {
+  if (records.length === 0) {
+    return [colorize(opts.rich, theme.muted, "No feedback recorded yet.")];
+  }
+  const up = records.filter((record) => record.rating === "up").length;
+  const lines = [
+    colorize(
+      opts.rich,
+      theme.heading,
+      `Feedback: ${records.length} (${up} 👍, ${records.length - up} 👎)`,
+    ),
+  ];
+  lines.push(
+    renderTable({
+      width: opts.width ?? Math.max(60, (process.stdout.columns ?? 120) - 1),
+      columns: [
+        { key: "time", header: "Time", minWidth: 16 },
+        { key: "rating", header: "Rating", minWidth: 6 },
+        { key: "session", header: "Session", minWidth: 12, flex: true },
+        { key: "tools", header: "Tools", align: "right", minWidth: 5 },
+        { key: "note", header: "Note", flex: true },
+        { key: "reply", header: "Reply", flex: true },
+      ],
+      rows: records.map((record) => ({
+        time: new Date(record.ts).toISOString().slice(0, 16).replace("T", " "),
+        rating: record.rating === "up" ? "👍 up" : "👎 down",
+        session: record.sessionKey,
+        tools: String(record.tools?.length ?? 0),
+        note: excerpt(record.note),
+        reply: excerpt(record.reply),
+      })),
+    }).trimEnd(),
+  );
+  return lines;
+}
+
+export function registerFeedbackCli(program: Command) {
+  const feedback = program
+    .command("feedback")
+    .description("Review 👍/👎 ratings left on agent replies")
+    .addHelpText(
+      "after",
+      () =>
+        `\n${theme.heading("Examples:")}\n${formatHelpExamples([
+          ["openclaw feedback list", "Latest ratings for the default agent."],
+          ["openclaw feedback list --rating down --limit 20", "The 20 most recent 👎 ratings."],
+          [
+            "openclaw feedback export --rating down --output bad.jsonl",
+            "Full prompt, reply, and tool trace for an eval set.",
+          ],
+        ])}\n\n${theme.muted("Docs:")} ${formatDocsLink("/cli/feedback", "docs.openclaw.ai/cli/feedback")}\n`,
+    )
+    .action(() => {
+      feedback.help({ error: true });
+    });
+
+  feedback
+    .command("list")
+    .description("Print recorded ratings, newest first")
+    .option("--agent <id>", "Agent id (default: the default agent)")
+    .option("--rating <rating>", "Only up or down ratings")
+    .option("--session <key>", "Only ratings for this session key")
+    .option("--limit <n>", "Maximum number of ratings", "50")
+    .option("--json", "Output JSON", false)
+    .action(async (opts: FeedbackListOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const records = queryFeedback(opts);
+        if (opts.json) {
+          defaultRuntime.log(JSON.stringify(records, null, 2));
+          return;
+        }
+        for (const line of renderFeedbackList(records, { rich: isRich() })) {
+          defaultRuntime.log(line);
+        }
+      });
+    });
+
+  feedback
+    .command("export")
+    .description("Write ratings with their prompt, reply, and tool trace as JSONL")
+    .option("--agent <id>", "Agent id (default: the default agent)")
+    .option("--rating <rating>", "Only up or down ratings")
+    .option("--session <key>", "Only ratings for this session key")
+    .option("--limit <n>", "Maximum number of ratings")
+    .option("--output <file>", "Write to a file instead of stdout")
+    .action(async (opts: FeedbackExportOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const records = queryFeedback(opts);
+        const jsonl = records.map((record) => JSON.stringify(record)).join("\n");
+        if (!opts.output) {
+          if (jsonl) {
+            defaultRuntime.log(jsonl);
+          }
+          return;
+        }
+        await fs.promises.writeFile(opts.output, jsonl ? `${jsonl}\n` : "", "utf-8");
+        defaultRuntime.log(`Exported ${records.length} ratings to ${opts.output}`);
+      });
+    });
+}
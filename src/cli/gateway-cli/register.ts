@@ -9,7 +9,12 @@ import { defaultRuntime } from "../../runtime.js";
 import { styleHealthChannelLine } from "../../terminal/health-style.js";
 import { formatDocsLink } from "../../terminal/links.js";
 import { colorize, isRich, theme } from "../../terminal/theme.js";
-import { formatTokenCount, formatUsd } from "../../utils/usage-format.js";
+import {
+  formatCost,
+  formatTokenCount,
+  resolveCostDisplayCurrency,
+  type CostDisplayCurrency,
+} from "../../utils/usage-format.js";
 import { runCommandWithRuntime } from "../cli-utils.js";
 import { inheritOptionFromParent } from "../command-options.js";
 import { addGatewayServiceCommands } from "../daemon-cli.js";
@@ -60,8 +65,13 @@ function resolveGatewayRpcOptions<T extends { token?: string; password?: string
   };
 }
 
-function renderCostUsageSummary(summary: CostUsageSummary, days: number, rich: boolean): string[] {
-  const totalCost = formatUsd(summary.totals.totalCost) ?? "$0.00";
+function renderCostUsageSummary(
+  summary: CostUsageSummary,
+  days: number,
+  rich: boolean,
+  currency: CostDisplayCurrency,
+): string[] {
+  const totalCost = formatCost(summary.totals.totalCost, currency) ?? formatCost(0, currency);
   const totalTokens = formatTokenCount(summary.totals.totalTokens) ?? "0";
   const lines = [
     colorize(rich, theme.heading, `Usage cost (${days} days)`),
@@ -76,7 +86,7 @@ function renderCostUsageSummary(summary: CostUsageSummary, days: number, rich: b
 
   const latest = summary.daily.at(-1);
   if (latest) {
-    const latestCost = formatUsd(latest.totalCost) ?? "$0.00";
+    const latestCost = formatCost(latest.totalCost, currency) ?? formatCost(0, currency);
     const latestTokens = formatTokenCount(latest.totalTokens) ?? "0";
     lines.push(
       `${colorize(rich, theme.muted, "Latest day:")} ${latest.date} · ${latestCost} · ${latestTokens} tokens`,
@@ -151,7 +161,8 @@ export function registerGatewayCli(program: Command) {
           }
           const rich = isRich();
           const summary = result as CostUsageSummary;
-          for (const line of renderCostUsageSummary(summary, days, rich)) {
+          const currency = resolveCostDisplayCurrency({ config: loadConfig() });
+          for (const line of renderCostUsageSummary(summary, days, rich, currency)) {
             defaultRuntime.log(line);
           }
         }, "Gateway usage cost failed");
@@ -3,13 +3,14 @@ import fs from "node:fs/promises";
 import os from "node:os";
 import path from "node:path";
 import type { Command } from "commander";
-import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { resolveAgentWorkspaceDir, resolveDefaultAgentId } from "../agents/agent-scope.js";
 import { loadConfig } from "../config/config.js";
 import { resolveStateDir } from "../config/paths.js";
 import { resolveSessionTranscriptsDirForAgent } from "../config/sessions/paths.js";
 import { setVerbose } from "../globals.js";
 import { getMemorySearchManager, type MemorySearchManagerResult } from "../memory/index.js";
 import { listMemoryFiles, normalizeExtraMemoryPaths } from "../memory/internal.js";
+import { ingestKbDocument } from "../memory/kb.js";
 import { defaultRuntime } from "../runtime.js";
 import { formatDocsLink } from "../terminal/links.js";
 import { colorize, isRich, theme } from "../terminal/theme.js";
@@ -545,6 +546,7 @@ export function registerMemoryCli(program: Command) {
         `\n${theme.heading("Examples:")}\n${formatHelpExamples([
           ["openclaw memory status", "Show index and provider status."],
           ["openclaw memory index --force", "Force a full reindex."],
+          ["openclaw memory ingest ./bme280.pdf", "Add a datasheet to the knowledge base."],
           ['openclaw memory search --query "deployment notes"', "Search indexed memory entries."],
           ["openclaw memory status --json", "Output machine-readable JSON."],
         ])}\n\n${theme.muted("Docs:")} ${formatDocsLink("/cli/memory", "docs.openclaw.ai/cli/memory")}\n`,
@@ -699,6 +701,60 @@ export function registerMemoryCli(program: Command) {
       }
     });
 
+  memory
+    .command("ingest")
+    .description("Add PDFs or Markdown (datasheets, manuals) to the knowledge base for kb_search")
+    .argument("<files...>", "PDF, Markdown, or text files")
+    .option("--agent <id>", "Agent id (default: default agent)")
+    .option("--title <title>", "Document title (single file only; default: file name)")
+    .option("--json", "Print JSON")
+    .action(async (files: string[], opts: MemoryCommandOptions & { title?: string }) => {
+      if (opts.title && files.length > 1) {
+        defaultRuntime.error("--title only applies to a single file.");
+        process.exitCode = 1;
+        return;
+      }
+      const cfg = loadConfig();
+      const agentId = resolveAgent(cfg, opts.agent);
+      const workspaceDir = resolveAgentWorkspaceDir(cfg, agentId);
+      const ingested: Awaited<ReturnType<typeof ingestKbDocument>>[] = [];
+      for (const file of files) {
+        try {
+          ingested.push(
+            await ingestKbDocument({
+              workspaceDir,
+              filePath: path.resolve(file),
+              title: opts.title,
+            }),
+          );
+        } catch (err) {
+          defaultRuntime.error(`Ingest failed (${file}): ${formatErrorMessage(err)}`);
+          process.exitCode = 1;
+        }
+      }
+      if (ingested.length === 0) {
+        return;
+      }
+      await withMemoryManagerForAgent({
+        cfg,
+        agentId,
+        run: async (manager) => {
+          await withProgress({ label: "Indexing knowledge base…" }, async () => {
+            await manager.sync?.({ reason: "kb-ingest" });
+          });
+        },
+      });
+      if (opts.json) {
+        defaultRuntime.log(JSON.stringify({ documents: ingested }, null, 2));
+        return;
+      }
+      for (const entry of ingested) {
+        const pages = entry.pages ? `, ${entry.pages} pages` : "";
+        const verb = entry.replaced ? "Replaced" : "Added";
+        defaultRuntime.log(`${verb} ${entry.title} → ${entry.path}${pages}`);
+      }
+    });
+
   memory
     .command("search")
     .description("Search memory files")
@@ -308,6 +308,9 @@ export function registerPluginsCli(program: Command) {
       if (plugin.services.length > 0) {
         lines.push(`${theme.muted("Services:")} ${plugin.services.join(", ")}`);
       }
+      if (plugin.middlewares.length > 0) {
+        lines.push(`${theme.muted("Middleware:")} ${plugin.middlewares.join(", ")}`);
+      }
       if (plugin.error) {
         lines.push(`${theme.error("Error:")} ${plugin.error}`);
       }
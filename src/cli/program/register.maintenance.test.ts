@@ -2,6 +2,7 @@ import { Command } from "commander";
 import { beforeAll, beforeEach, describe, expect, it, vi } from "vitest";
 
 const doctorCommand = vi.fn();
+const doctorReportCommand = vi.fn();
 const dashboardCommand = vi.fn();
 const resetCommand = vi.fn();
 const uninstallCommand = vi.fn();
@@ -16,6 +17,10 @@ vi.mock("../../commands/doctor.js", () => ({
   doctorCommand,
 }));
 
+vi.mock("../../commands/doctor-report.js", () => ({
+  doctorReportCommand,
+}));
+
 vi.mock("../../commands/dashboard.js", () => ({
   dashboardCommand,
 }));
@@ -74,6 +79,15 @@ describe("registerMaintenanceCommands doctor action", () => {
     expect(runtime.exit).not.toHaveBeenCalledWith(0);
   });
 
+  it("runs the read-only checklist for --report", async () => {
+    doctorReportCommand.mockResolvedValue(undefined);
+
+    await runMaintenanceCli(["doctor", "--report", "--json"]);
+
+    expect(doctorReportCommand).toHaveBeenCalledWith(runtime, { json: true });
+    expect(doctorCommand).not.toHaveBeenCalled();
+  });
+
   it("maps --fix to repair=true", async () => {
     doctorCommand.mockResolvedValue(undefined);
 
@@ -1,5 +1,6 @@
 import type { Command } from "commander";
 import { dashboardCommand } from "../../commands/dashboard.js";
+import { doctorReportCommand } from "../../commands/doctor-report.js";
 import { doctorCommand } from "../../commands/doctor.js";
 import { resetCommand } from "../../commands/reset.js";
 import { uninstallCommand } from "../../commands/uninstall.js";
@@ -25,8 +26,15 @@ export function registerMaintenanceCommands(program: Command) {
     .option("--non-interactive", "Run without prompts (safe migrations only)", false)
     .option("--generate-gateway-token", "Generate and configure a gateway token", false)
     .option("--deep", "Scan system services for extra gateway installs", false)
+    .option("--report", "Print a read-only pass/fail checklist (no prompts or repairs)", false)
+    .option("--json", "Output the --report checklist as JSON", false)
     .action(async (opts) => {
       await runCommandWithRuntime(defaultRuntime, async () => {
+        if (opts.report || opts.json) {
+          await doctorReportCommand(defaultRuntime, { json: Boolean(opts.json) });
+          defaultRuntime.exit(0);
+          return;
+        }
         await doctorCommand(defaultRuntime, {
           workspaceSuggestions: opts.workspaceSuggestions,
           yes: Boolean(opts.yes),
@@ -1,5 +1,10 @@
 import type { Command } from "commander";
 import { healthCommand } from "../../commands/health.js";
+import {
+  sessionsRestoreCommand,
+  sessionsSnapshotCommand,
+} from "../../commands/sessions-snapshot.js";
+import { sessionsExportCommand } from "../../commands/sessions-export.js";
 import { sessionsCommand } from "../../commands/sessions.js";
 import { statusCommand } from "../../commands/status.js";
 import { setVerbose } from "../../globals.js";
@@ -111,7 +116,7 @@ export function registerStatusHealthSessionsCommands(program: Command) {
       });
     });
 
-  program
+  const sessions = program
     .command("sessions")
     .description("List stored conversation sessions")
     .option("--json", "Output as JSON", false)
@@ -126,6 +131,9 @@ export function registerStatusHealthSessionsCommands(program: Command) {
           ["openclaw sessions --active 120", "Only last 2 hours."],
           ["openclaw sessions --json", "Machine-readable output."],
           ["openclaw sessions --store ./tmp/sessions.json", "Use a specific session store."],
+          ["openclaw sessions snapshot agent:main:main", "Save a session to a file."],
+          ["openclaw sessions restore ./session.json", "Restore a saved session."],
+          ["openclaw sessions export agent:main:main -o notes.md", "Export a Markdown transcript."],
         ])}\n\n${theme.muted(
           "Shows token usage per session when the agent reports it; set agents.defaults.contextTokens to cap the window and show %.",
         )}`,
@@ -146,4 +154,68 @@ export function registerStatusHealthSessionsCommands(program: Command) {
         defaultRuntime,
       );
     });
+
+  sessions
+    .command("snapshot")
+    .description("Save a session (history, memory refs, open tasks) to a file")
+    .argument("<sessionKey>", "Session key (see `openclaw sessions`)")
+    .option("-o, --output <path>", "Snapshot file (default: ./session-<id>-<timestamp>.json)")
+    .action(async (sessionKey: string, _opts, command: Command) => {
+      // --json/--store belong to `sessions`; commander hands them to the parent.
+      const opts = command.optsWithGlobals();
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsSnapshotCommand(
+          {
+            sessionKey,
+            output: opts.output as string | undefined,
+            store: opts.store as string | undefined,
+            json: Boolean(opts.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("export")
+    .description("Export a session transcript (tool calls, results, images, per-turn cost)")
+    .argument("<sessionKey>", "Session key (see `openclaw sessions`)")
+    .option("--format <format>", "markdown or json", "markdown")
+    .option("-o, --output <path>", "Write to a file instead of stdout")
+    .action(async (sessionKey: string, _opts, command: Command) => {
+      const opts = command.optsWithGlobals();
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsExportCommand(
+          {
+            sessionKey,
+            format: opts.format as string | undefined,
+            output: opts.output as string | undefined,
+            store: opts.store as string | undefined,
+          },
+          defaultRuntime,
+        );
+      });
+    });
+
+  sessions
+    .command("restore")
+    .description("Restore a session from a snapshot file")
+    .argument("<file>", "Snapshot file from `openclaw sessions snapshot`")
+    .option("--session-key <key>", "Restore under a different session key")
+    .option("--force", "Replace an existing session with the same key", false)
+    .action(async (file: string, _opts, command: Command) => {
+      const opts = command.optsWithGlobals();
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        await sessionsRestoreCommand(
+          {
+            file,
+            sessionKey: opts.sessionKey as string | undefined,
+            store: opts.store as string | undefined,
+            force: Boolean(opts.force),
+            json: Boolean(opts.json),
+          },
+          defaultRuntime,
+        );
+      });
+    });
 }
@@ -91,6 +91,33 @@ const entries: SubCliEntry[] = [
       mod.registerModelsCli(program);
     },
   },
+  {
+    name: "cost",
+    description: "Inspect model usage cost from local session logs",
+    hasSubcommands: true,
+    register: async (program) => {
+      const mod = await import("../cost-cli.js");
+      mod.registerCostCli(program);
+    },
+  },
+  {
+    name: "feedback",
+    description: "Review 👍/👎 ratings left on agent replies",
+    hasSubcommands: true,
+    register: async (program) => {
+      const mod = await import("../feedback-cli.js");
+      mod.registerFeedbackCli(program);
+    },
+  },
+  {
+    name: "secrets",
+    description: "Store secrets referenced from config as ${secret:NAME}",
+    hasSubcommands: true,
+    register: async (program) => {
+      const mod = await import("../secrets-cli.js");
+      mod.registerSecretsCli(program);
+    },
+  },
   {
     name: "approvals",
     description: "Manage exec approvals (gateway or node host)",
@@ -145,6 +172,24 @@ const entries: SubCliEntry[] = [
       mod.registerTuiCli(program);
     },
   },
+  {
+    name: "chat",
+    description: "Chat with an agent in a line-mode REPL connected to the Gateway",
+    hasSubcommands: false,
+    register: async (program) => {
+      const mod = await import("../chat-cli.js");
+      mod.registerChatCli(program);
+    },
+  },
+  {
+    name: "tools",
+    description: "List and call agent tools on the Gateway without an LLM",
+    hasSubcommands: true,
+    register: async (program) => {
+      const mod = await import("../tools-cli.js");
+      mod.registerToolsCli(program);
+    },
+  },
   {
     name: "cron",
     description: "Manage cron jobs via the Gateway scheduler",
@@ -0,0 +1,177 @@
+import { isCancel, password } from "@clack/prompts";
+import type { Command } from "commander";
+import { readConfigFileSnapshot } from "../config/config.js";
+import { resolveConfigIncludes } from "../config/includes.js";
+import type { SecretsConfig } from "../config/types.secrets.js";
+import { defaultRuntime } from "../runtime.js";
+import {
+  createSecretResolver,
+  listFileSecretNames,
+  removeSecret,
+  resolveSecretsFilePath,
+  resolveSecretSources,
+  setSecret,
+} from "../secrets/store.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { stylePromptMessage } from "../terminal/prompt-style.js";
+import { theme } from "../terminal/theme.js";
+import { isPlainObject } from "../utils.js";
+import { runCommandWithRuntime } from "./cli-utils.js";
+import { formatHelpExamples } from "./help-format.js";
+
+type SecretsSetOptions = {
+  keyring?: boolean;
+  value?: string;
+};
+
+const SECRET_REF_RE = /(?<!\$)\$\{secret:([A-Za-z0-9_.-]+)\}/g;
+
+async function loadSecretsConfig(): Promise<{ secrets?: SecretsConfig; resolved: unknown }> {
+  // Resolve includes but not `${...}` references, so this works while a secret is missing.
+  const snapshot = await readConfigFileSnapshot();
+  const resolved = snapshot.exists
+    ? (resolveConfigIncludes(snapshot.parsed, snapshot.path) as { secrets?: SecretsConfig })
+    : undefined;
+  return { secrets: resolved?.secrets, resolved };
+}
+
+function collectSecretRefs(value: unknown, path: string, out: Map<string, string[]>): void {
+  if (typeof value === "string") {
+    for (const match of value.matchAll(SECRET_REF_RE)) {
+      out.set(match[1], [...(out.get(match[1]) ?? []), path]);
+    }
+    return;
+  }
+  if (Array.isArray(value)) {
+    value.forEach((item, index) => collectSecretRefs(item, `${path}[${index}]`, out));
+    return;
+  }
+  if (isPlainObject(value)) {
+    for (const [key, child] of Object.entries(value)) {
+      collectSecretRefs(child, path ? `${path}.${key}` : key, out);
+    }
+  }
+}
+
+async function readSecretValue(name: string, opts: SecretsSetOptions): Promise<string> {
+  if (opts.value !== undefined) {
+    return opts.value;
+  }
+  if (process.stdin.isTTY) {
+    const value = await password({ message: stylePromptMessage(`Value for ${name}`) });
+    if (isCancel(value)) {
+      throw new Error("Cancelled.");
+    }
+    return value;
+  }
+  const chunks: Buffer[] = [];
+  for await (const chunk of process.stdin) {
+    chunks.push(Buffer.isBuffer(chunk) ? chunk : Buffer.from(String(chunk)));
+  }
+  return Buffer.concat(chunks).toString("utf8").replace(/\r?\n$/, "");
+}
+
+export function registerSecretsCli(program: Command) {
+  const secrets = program
+    .command("secrets")
+    .description("Store secrets referenced from config as ${secret:NAME}")
+    .addHelpText(
+      "after",
+      () =>
+        `\n${theme.heading("Examples:")}\n${formatHelpExamples([
+          ["openclaw secrets set TELEGRAM_BOT_TOKEN", "Prompt for a value; store it in the file."],
+          [
+            'printf %s "$TOKEN" | openclaw secrets set HASS_TOKEN --keyring',
+            "Store a piped value in the OS keyring.",
+          ],
+          ["openclaw secrets check", "Show where every ${secret:NAME} in config resolves from."],
+        ])}\n\n${theme.muted("Docs:")} ${formatDocsLink("/cli/secrets", "docs.openclaw.ai/cli/secrets")}\n`,
+    )
+    .action(() => {
+      secrets.help({ error: true });
+    });
+
+  secrets
+    .command("set <name>")
+    .description("Store a secret (reads the value from a prompt or stdin)")
+    .option("--keyring", "Store in the OS keyring instead of the secrets file", false)
+    .option("--value <value>", "Secret value (visible in shell history; prefer stdin)")
+    .action(async (name: string, opts: SecretsSetOptions) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const { secrets: config } = await loadSecretsConfig();
+        const value = await readSecretValue(name, opts);
+        if (!value) {
+          throw new Error("Refusing to store an empty secret.");
+        }
+        const location = setSecret({
+          name,
+          value,
+          config,
+          target: opts.keyring ? "keyring" : "file",
+        });
+        defaultRuntime.log(`Stored ${name} in ${location}`);
+      });
+    });
+
+  secrets
+    .command("unset <name>")
+    .description("Remove a secret from the secrets file and the OS keyring")
+    .action(async (name: string) => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const { secrets: config } = await loadSecretsConfig();
+        const removed = removeSecret({ name, config });
+        defaultRuntime.log(
+          removed.length > 0
+            ? `Removed ${name} from ${removed.join(" and ")}`
+            : theme.muted(`${name} was not stored.`),
+        );
+      });
+    });
+
+  secrets
+    .command("list")
+    .description("List secret names in the secrets file (values are never printed)")
+    .action(async () => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const { secrets: config } = await loadSecretsConfig();
+        const names = listFileSecretNames({ config });
+        if (names.length === 0) {
+          defaultRuntime.log(theme.muted(`No secrets in ${resolveSecretsFilePath(config)}`));
+          return;
+        }
+        defaultRuntime.log(theme.heading(resolveSecretsFilePath(config)));
+        for (const name of names) {
+          defaultRuntime.log(`  ${name}`);
+        }
+      });
+    });
+
+  secrets
+    .command("check")
+    .description("Resolve every ${secret:NAME} in config and report its source")
+    .action(async () => {
+      await runCommandWithRuntime(defaultRuntime, async () => {
+        const { secrets: config, resolved } = await loadSecretsConfig();
+        const refs = new Map<string, string[]>();
+        collectSecretRefs(resolved, "", refs);
+        if (refs.size === 0) {
+          defaultRuntime.log(theme.muted("Config has no ${secret:NAME} references."));
+          return;
+        }
+        defaultRuntime.log(theme.muted(`Sources: ${resolveSecretSources(config).join(" → ")}`));
+        const resolve = createSecretResolver({ config });
+        let missing = 0;
+        for (const [name, paths] of [...refs].toSorted(([a], [b]) => a.localeCompare(b))) {
+          const found = resolve(name);
+          if (!found) {
+            missing += 1;
+          }
+          const status = found ? theme.success(found.source) : theme.error("missing");
+          defaultRuntime.log(`  ${name}  ${status}  ${theme.muted(paths.join(", "))}`);
+        }
+        if (missing > 0) {
+          throw new Error(`${missing} secret(s) missing; store them with: openclaw secrets set`);
+        }
+      });
+    });
+}
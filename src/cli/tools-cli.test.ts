@@ -0,0 +1,87 @@
+import { Command } from "commander";
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { createCliRuntimeCapture } from "./test-runtime-capture.js";
+
+const callGatewayFromCli = vi.fn();
+const addGatewayClientOptions = vi.fn((command: Command) => command);
+
+const { runtimeLogs, runtimeErrors, defaultRuntime, resetRuntimeCapture } =
+  createCliRuntimeCapture();
+
+vi.mock("./gateway-rpc.js", () => ({
+  addGatewayClientOptions,
+  callGatewayFromCli,
+}));
+
+vi.mock("../runtime.js", () => ({
+  defaultRuntime,
+}));
+
+const { registerToolsCli, formatToolCallResult } = await import("./tools-cli.js");
+
+describe("tools-cli", () => {
+  async function runCli(args: string[]) {
+    const program = new Command();
+    registerToolsCli(program);
+    try {
+      await program.parseAsync(args, { from: "user" });
+    } catch (err) {
+      if (!(err instanceof Error && err.message.startsWith("__exit__:"))) {
+        throw err;
+      }
+    }
+  }
+
+  beforeEach(() => {
+    vi.clearAllMocks();
+    resetRuntimeCapture();
+  });
+
+  it("lists the tool catalog", async () => {
+    callGatewayFromCli.mockResolvedValueOnce({
+      agentId: "main",
+      groups: [
+        {
+          label: "Runtime",
+          source: "core",
+          tools: [{ id: "exec", description: "Run shell commands" }],
+        },
+      ],
+    });
+
+    await runCli(["tools", "list", "--no-plugins"]);
+
+    expect(callGatewayFromCli).toHaveBeenCalledWith("tools.catalog", expect.anything(), {
+      includePlugins: false,
+    });
+    expect(runtimeLogs.join("\n")).toContain("Run shell commands");
+  });
+
+  it("calls a tool with JSON5 args and prints its text content", async () => {
+    callGatewayFromCli.mockResolvedValueOnce({
+      ok: true,
+      result: { content: [{ type: "text", text: "pin 7 high" }], details: { pin: 7 } },
+    });
+
+    await runCli(["tools", "call", "gpio_write", "--args", "{pin: 7, level: 1}"]);
+
+    expect(callGatewayFromCli).toHaveBeenCalledWith("tools.invoke", expect.anything(), {
+      name: "gpio_write",
+      args: { pin: 7, level: 1 },
+    });
+    expect(runtimeLogs).toEqual(["pin 7 high"]);
+  });
+
+  it("rejects non-object args before calling the gateway", async () => {
+    await runCli(["tools", "call", "gpio_write", "--args", "[7]"]);
+
+    expect(callGatewayFromCli).not.toHaveBeenCalled();
+    expect(runtimeErrors[0]).toContain("expected a JSON object");
+  });
+
+  it("falls back to the JSON details when a result has no text", () => {
+    expect(formatToolCallResult({ content: [], details: { ok: true } })).toBe(
+      JSON.stringify({ ok: true }, null, 2),
+    );
+  });
+});
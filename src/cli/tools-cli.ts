@@ -0,0 +1,135 @@
+import type { Command } from "commander";
+import JSON5 from "json5";
+import { danger } from "../globals.js";
+import { defaultRuntime } from "../runtime.js";
+import { formatDocsLink } from "../terminal/links.js";
+import { theme } from "../terminal/theme.js";
+import { isPlainObject } from "../utils.js";
+import type { GatewayRpcOpts } from "./gateway-rpc.js";
+import { addGatewayClientOptions, callGatewayFromCli } from "./gateway-rpc.js";
+import { formatHelpExamples } from "./help-format.js";
+
+type ToolsListOpts = GatewayRpcOpts & { agent?: string; plugins?: boolean };
+type ToolsCallOpts = GatewayRpcOpts & { args?: string; action?: string; session?: string };
+
+type ToolsCatalogResult = {
+  agentId: string;
+  groups: Array<{
+    label: string;
+    source: "core" | "plugin";
+    tools: Array<{ id: string; description: string; optional?: boolean }>;
+  }>;
+};
+
+export function parseToolArgs(raw: string | undefined): Record<string, unknown> {
+  if (raw === undefined || !raw.trim()) {
+    return {};
+  }
+  let parsed: unknown;
+  try {
+    parsed = JSON5.parse(raw);
+  } catch (err) {
+    throw new Error(`Invalid --args (expected a JSON object): ${String(err)}`, { cause: err });
+  }
+  if (!isPlainObject(parsed)) {
+    throw new Error('Invalid --args (expected a JSON object, e.g. \'{"pin":7}\')');
+  }
+  return parsed;
+}
+
+/** Text blocks of an agent tool result, or the JSON payload when there are none. */
+export function formatToolCallResult(result: unknown): string {
+  const content = isPlainObject(result) ? result.content : undefined;
+  if (Array.isArray(content)) {
+    const text = content
+      .filter((block) => isPlainObject(block) && block.type === "text")
+      .map((block) => String((block as { text?: unknown }).text ?? ""))
+      .join("\n");
+    if (text) {
+      return text;
+    }
+  }
+  const details = isPlainObject(result) && "details" in result ? result.details : result;
+  return JSON.stringify(details ?? null, null, 2);
+}
+
+async function runToolsCommand(action: () => Promise<void>): Promise<void> {
+  try {
+    await action();
+  } catch (err) {
+    defaultRuntime.error(danger(String(err)));
+    defaultRuntime.exit(1);
+  }
+}
+
+export function registerToolsCli(program: Command) {
+  const tools = program
+    .command("tools")
+    .description("List and call agent tools on the Gateway without an LLM")
+    .addHelpText(
+      "after",
+      () =>
+        `\n${theme.heading("Examples:")}\n${formatHelpExamples([
+          ["openclaw tools list", "Tools available to the default agent."],
+          [
+            `openclaw tools call gpio_write --args '{"pin":7,"level":1}'`,
+            "Run one tool directly and print its result.",
+          ],
+        ])}\n\n${theme.muted("Docs:")} ${formatDocsLink("/cli/tools", "docs.openclaw.ai/cli/tools")}\n`,
+    )
+    .action(() => {
+      tools.help({ error: true });
+    });
+
+  addGatewayClientOptions(
+    tools
+      .command("list")
+      .description("List core and plugin tools")
+      .option("--agent <id>", "Agent id (default: the default agent)")
+      .option("--no-plugins", "Only core tools")
+      .option("--json", "Output JSON", false),
+  ).action(async (opts: ToolsListOpts) => {
+    await runToolsCommand(async () => {
+      const catalog = (await callGatewayFromCli("tools.catalog", opts, {
+        ...(opts.agent ? { agentId: opts.agent } : {}),
+        includePlugins: opts.plugins !== false,
+      })) as ToolsCatalogResult;
+      if (opts.json) {
+        defaultRuntime.log(JSON.stringify(catalog, null, 2));
+        return;
+      }
+      for (const group of catalog.groups) {
+        const source = group.source === "plugin" ? theme.muted(" (plugin)") : "";
+        defaultRuntime.log(theme.heading(`${group.label}${source}`));
+        for (const tool of group.tools) {
+          const optional = tool.optional ? theme.muted(" [optional]") : "";
+          defaultRuntime.log(`  ${theme.command(tool.id)}${optional}  ${tool.description}`);
+        }
+      }
+    });
+  });
+
+  addGatewayClientOptions(
+    tools
+      .command("call <name>")
+      .description("Invoke one tool with JSON arguments (tool policy still applies)")
+      .option("--args <json>", "Tool arguments as a JSON object", "{}")
+      .option("--action <action>", "Shorthand for args.action on tools that take one")
+      .option("--session <key>", 'Session whose tool policy applies (default: "main")')
+      .option("--json", "Print the full tool result as JSON", false),
+  ).action(async (name: string, opts: ToolsCallOpts) => {
+    await runToolsCommand(async () => {
+      const response = (await callGatewayFromCli("tools.invoke", opts, {
+        name,
+        args: parseToolArgs(opts.args),
+        ...(opts.action ? { action: opts.action } : {}),
+        ...(opts.session ? { sessionKey: opts.session } : {}),
+      })) as { result?: unknown };
+      defaultRuntime.log(
+        opts.json
+          ? JSON.stringify(response.result ?? null, null, 2)
+          : formatToolCallResult(response.result),
+      );
+    });
+  });
+}
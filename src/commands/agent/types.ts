@@ -1,4 +1,5 @@
 import type { ClientToolDefinition } from "../../agents/pi-embedded-runner/run/params.js";
+import type { JsonResponseFormat } from "../../agents/structured-output.js";
 import type { ChannelOutboundTargetMode } from "../../channels/plugins/types.js";
 import type { InputProvenance } from "../../sessions/input-provenance.js";
 
@@ -13,6 +14,11 @@ export type AgentStreamParams = {
   /** Provider stream params override (best-effort). */
   temperature?: number;
   maxTokens?: number;
+  /**
+   * Schema-constrained JSON output. Sent natively where supported (OpenAI `response_format` /
+   * `text.format`, Ollama `format`); callers still validate the reply.
+   */
+  responseFormat?: JsonResponseFormat;
 };
 
 export type AgentRunContext = {
@@ -50,6 +50,10 @@ export async function noteMemorySearchHealth(cfg: OpenClawConfig): Promise<void>
       );
       return;
     }
+    // Ollama runs locally and needs no API key.
+    if (resolved.provider === "ollama") {
+      return;
+    }
     // Remote provider — check for API key
     if (hasRemoteApiKey || (await hasApiKeyForProvider(resolved.provider, cfg, agentDir))) {
       return;
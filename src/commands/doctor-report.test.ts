@@ -0,0 +1,132 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import type { ConfigFileSnapshot, OpenClawConfig } from "../config/types.js";
+import type { DoctorReportDeps } from "./doctor-report.js";
+
+const readConfigFileSnapshot = vi.fn<() => Promise<ConfigFileSnapshot>>();
+const resolveApiKeyForProvider = vi.fn(async () => ({ apiKey: "sk-test", source: "env" }));
+
+vi.mock("../config/config.js", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("../config/config.js")>()),
+  readConfigFileSnapshot: () => readConfigFileSnapshot(),
+}));
+
+vi.mock("../agents/model-auth.js", async (importOriginal) => ({
+  ...(await importOriginal<typeof import("../agents/model-auth.js")>()),
+  resolveApiKeyForProvider: () => resolveApiKeyForProvider(),
+}));
+
+const { runDoctorChecks } = await import("./doctor-report.js");
+
+function snapshot(config: OpenClawConfig, valid = true): ConfigFileSnapshot {
+  return {
+    path: "/tmp/openclaw.json",
+    exists: true,
+    raw: JSON.stringify(config),
+    parsed: config,
+    resolved: config,
+    valid,
+    config,
+    issues: valid ? [] : [{ path: "gateway.port", message: "Expected number" }],
+    warnings: [],
+    legacyIssues: [],
+  };
+}
+
+function deps(overrides: DoctorReportDeps = {}): DoctorReportDeps {
+  return {
+    platform: "linux",
+    readDir: async (dirPath) =>
+      dirPath === "/dev" ? ["null", "video0"] : dirPath === "/sys/class/leds" ? ["ACT"] : [],
+    isWritable: async () => true,
+    freeBytes: async () => 8 * 1024 ** 3,
+    callGateway: async (method) =>
+      method === "node.list"
+        ? { nodes: [{ nodeId: "n1", displayName: "garage-pi", connected: true }] }
+        : { ok: true },
+    readSensor: async () => 42_000,
+    probeProvider: async () => ({ ok: true, detail: "answered in 300ms" }),
+    ...overrides,
+  };
+}
+
+const statusOf = (checks: Awaited<ReturnType<typeof runDoctorChecks>>) =>
+  Object.fromEntries(checks.map((check) => [check.id, check.status]));
+
+describe("runDoctorChecks", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("passes on a healthy board", async () => {
+    readConfigFileSnapshot.mockResolvedValueOnce(
+      snapshot({
+        watchdog: {
+          watches: [{ name: "cpu", source: { kind: "hwmon", path: "/x" }, above: 80 }],
+        },
+      }),
+    );
+
+    const checks = await runDoctorChecks({ deps: deps() });
+
+    expect(statusOf(checks)).toEqual({
+      config: "pass",
+      gateway: "pass",
+      nodes: "pass",
+      camera: "pass",
+      leds: "pass",
+      sensors: "pass",
+      provider: "pass",
+      disk: "pass",
+    });
+    expect(checks.find((check) => check.id === "camera")?.detail).toBe("/dev/video0");
+  });
+
+  it("reports failures without stopping the remaining checks", async () => {
+    readConfigFileSnapshot.mockResolvedValueOnce(snapshot({}, false));
+    resolveApiKeyForProvider.mockRejectedValueOnce(new Error('No API key found for "anthropic"'));
+
+    const checks = await runDoctorChecks({
+      deps: deps({
+        readDir: async () => [],
+        callGateway: async () => {
+          throw new Error("gateway closed (1006)");
+        },
+        freeBytes: async () => 50 * 1024 ** 2,
+      }),
+    });
+
+    expect(statusOf(checks)).toEqual({
+      config: "fail",
+      gateway: "fail",
+      nodes: "skip",
+      camera: "skip",
+      leds: "skip",
+      sensors: "skip",
+      provider: "fail",
+      disk: "fail",
+    });
+    expect(checks[0]?.detail).toBe("gateway.port: Expected number");
+  });
+
+  it("warns about LEDs that need root and fails unreadable sensors", async () => {
+    readConfigFileSnapshot.mockResolvedValueOnce(
+      snapshot({
+        watchdog: {
+          watches: [{ name: "psu", source: { kind: "file", path: "/missing" }, below: 4.8 }],
+        },
+      }),
+    );
+
+    const checks = await runDoctorChecks({
+      deps: deps({
+        isWritable: async () => false,
+        readSensor: async () => {
+          throw new Error("ENOENT: /missing");
+        },
+      }),
+    });
+
+    expect(statusOf(checks)).toMatchObject({ leds: "warn", sensors: "fail" });
+    expect(checks.find((check) => check.id === "sensors")?.detail).toBe("psu: ENOENT: /missing");
+  });
+});
@@ -0,0 +1,361 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { DEFAULT_MODEL, DEFAULT_PROVIDER } from "../agents/defaults.js";
+import { resolveApiKeyForProvider } from "../agents/model-auth.js";
+import { resolveConfiguredModelRef } from "../agents/model-selection.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { readConfigFileSnapshot } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { buildGatewayConnectionDetails, callGateway } from "../gateway/call.js";
+import { readWatchSource, resolveWatches } from "../infra/sensor-watchdog.js";
+import type { RuntimeEnv } from "../runtime.js";
+import { defaultRuntime } from "../runtime.js";
+import { parseNodeList } from "../shared/node-list-parse.js";
+import type { NodeListNode } from "../shared/node-list-types.js";
+import { theme } from "../terminal/theme.js";
+import { shortenHomePath } from "../utils.js";
+import { runAuthProbes } from "./models/list.probe.js";
+
+export type DoctorCheckStatus = "pass" | "warn" | "fail" | "skip";
+
+export type DoctorCheck = {
+  id: "config" | "gateway" | "nodes" | "camera" | "leds" | "sensors" | "provider" | "disk";
+  label: string;
+  status: DoctorCheckStatus;
+  detail: string;
+};
+
+export type DoctorReportOptions = {
+  json?: boolean;
+  timeoutMs?: number;
+};
+
+export type DoctorReportDeps = {
+  platform?: NodeJS.Platform;
+  readDir?: (dirPath: string) => Promise<string[]>;
+  isWritable?: (filePath: string) => Promise<boolean>;
+  freeBytes?: (dirPath: string) => Promise<number>;
+  callGateway?: (method: string, cfg: OpenClawConfig, timeoutMs: number) => Promise<unknown>;
+  readSensor?: typeof readWatchSource;
+  probeProvider?: (params: {
+    cfg: OpenClawConfig;
+    provider: string;
+    model: string;
+    timeoutMs: number;
+  }) => Promise<{ ok: boolean; detail: string }>;
+};
+
+const LEDS_ROOT = "/sys/class/leds";
+const DISK_FAIL_BYTES = 200 * 1024 * 1024;
+const DISK_WARN_BYTES = 1024 * 1024 * 1024;
+
+function errorMessage(err: unknown): string {
+  return err instanceof Error ? err.message : String(err);
+}
+
+function formatBytes(bytes: number): string {
+  const gb = bytes / 1024 ** 3;
+  return gb >= 1 ? `${gb.toFixed(1)} GB` : `${Math.round(bytes / 1024 ** 2)} MB`;
+}
+
+async function probeProviderDefault(params: {
+  cfg: OpenClawConfig;
+  provider: string;
+  model: string;
+  timeoutMs: number;
+}): Promise<{ ok: boolean; detail: string }> {
+  const summary = await runAuthProbes({
+    cfg: params.cfg,
+    providers: [params.provider],
+    modelCandidates: [`${params.provider}/${params.model}`],
+    options: {
+      provider: params.provider,
+      timeoutMs: params.timeoutMs,
+      concurrency: 1,
+      maxTokens: 8,
+    },
+  });
+  const ok = summary.results.find((result) => result.status === "ok");
+  if (ok) {
+    return { ok: true, detail: `${ok.label} answered in ${ok.latencyMs ?? "?"}ms` };
+  }
+  const failed = summary.results[0];
+  return {
+    ok: false,
+    detail: failed
+      ? `${failed.label}: ${failed.status}${failed.error ? ` (${failed.error})` : ""}`
+      : "no credentials to probe",
+  };
+}
+
+function resolveDeps(deps: DoctorReportDeps): Required<DoctorReportDeps> {
+  return {
+    platform: deps.platform ?? process.platform,
+    readDir: deps.readDir ?? ((dirPath) => fs.readdir(dirPath)),
+    isWritable:
+      deps.isWritable ??
+      ((filePath) =>
+        fs.access(filePath, fs.constants.W_OK).then(
+          () => true,
+          () => false,
+        )),
+    freeBytes:
+      deps.freeBytes ??
+      (async (dirPath) => {
+        const stats = await fs.statfs(dirPath);
+        return stats.bavail * stats.bsize;
+      }),
+    callGateway:
+      deps.callGateway ??
+      ((method, cfg, timeoutMs) => callGateway({ method, config: cfg, timeoutMs })),
+    readSensor: deps.readSensor ?? readWatchSource,
+    probeProvider: deps.probeProvider ?? probeProviderDefault,
+  };
+}
+
+async function checkConnectivity(
+  cfg: OpenClawConfig,
+  timeoutMs: number,
+  deps: Required<DoctorReportDeps>,
+): Promise<{ checks: DoctorCheck[]; nodes: NodeListNode[] | null }> {
+  const { url } = buildGatewayConnectionDetails({ config: cfg });
+  try {
+    await deps.callGateway("health", cfg, timeoutMs);
+  } catch (err) {
+    return {
+      nodes: null,
+      checks: [
+        { id: "gateway", label: "Gateway", status: "fail", detail: `${url}: ${errorMessage(err)}` },
+        { id: "nodes", label: "Nodes", status: "skip", detail: "Gateway not reachable" },
+      ],
+    };
+  }
+  const gateway: DoctorCheck = { id: "gateway", label: "Gateway", status: "pass", detail: url };
+  try {
+    const nodes = parseNodeList(await deps.callGateway("node.list", cfg, timeoutMs));
+    const connected = nodes.filter((node) => node.connected);
+    const names = connected.map((node) => node.displayName ?? node.nodeId);
+    return {
+      nodes: connected,
+      checks: [
+        gateway,
+        connected.length > 0
+          ? { id: "nodes", label: "Nodes", status: "pass", detail: names.join(", ") }
+          : {
+              id: "nodes",
+              label: "Nodes",
+              status: nodes.length > 0 ? "warn" : "skip",
+              detail:
+                nodes.length > 0 ? `${nodes.length} paired, none connected` : "no paired nodes",
+            },
+      ],
+    };
+  } catch (err) {
+    return {
+      nodes: null,
+      checks: [gateway, { id: "nodes", label: "Nodes", status: "fail", detail: errorMessage(err) }],
+    };
+  }
+}
+
+async function checkCamera(
+  cfg: OpenClawConfig,
+  nodes: NodeListNode[] | null,
+  deps: Required<DoctorReportDeps>,
+): Promise<DoctorCheck> {
+  const found: string[] = [];
+  if (deps.platform === "linux") {
+    const devices = await deps.readDir("/dev").catch(() => [] as string[]);
+    found.push(...devices.filter((name) => /^video\d+$/.test(name)).map((name) => `/dev/${name}`));
+  }
+  for (const node of nodes ?? []) {
+    if (node.commands?.includes("camera.snap") || node.caps?.includes("camera")) {
+      found.push(`node ${node.displayName ?? node.nodeId}`);
+    }
+  }
+  if (found.length > 0) {
+    return { id: "camera", label: "Camera", status: "pass", detail: found.join(", ") };
+  }
+  // Only a failure when something in config expects a camera.
+  const wanted = cfg.commands?.camera === true;
+  return {
+    id: "camera",
+    label: "Camera",
+    status: wanted ? "fail" : "skip",
+    detail: wanted ? "commands.camera is on but no camera was found" : "no camera found",
+  };
+}
+
+async function checkLeds(deps: Required<DoctorReportDeps>): Promise<DoctorCheck> {
+  if (deps.platform !== "linux") {
+    return { id: "leds", label: "LEDs", status: "skip", detail: "Linux only" };
+  }
+  const leds = await deps.readDir(LEDS_ROOT).catch(() => [] as string[]);
+  if (leds.length === 0) {
+    return { id: "leds", label: "LEDs", status: "skip", detail: `nothing in ${LEDS_ROOT}` };
+  }
+  const writable: string[] = [];
+  for (const led of leds) {
+    if (await deps.isWritable(path.join(LEDS_ROOT, led, "brightness"))) {
+      writable.push(led);
+    }
+  }
+  if (writable.length === 0) {
+    return {
+      id: "leds",
+      label: "LEDs",
+      status: "warn",
+      detail: `${leds.join(", ")} (brightness not writable; run as root or add a udev rule)`,
+    };
+  }
+  return { id: "leds", label: "LEDs", status: "pass", detail: writable.join(", ") };
+}
+
+async function checkSensors(
+  cfg: OpenClawConfig,
+  deps: Required<DoctorReportDeps>,
+): Promise<DoctorCheck> {
+  const watches = resolveWatches(cfg.watchdog?.watches);
+  if (watches.length === 0) {
+    return { id: "sensors", label: "Sensors", status: "skip", detail: "no watchdog.watches" };
+  }
+  const failures: string[] = [];
+  for (const watch of watches) {
+    try {
+      await deps.readSensor(watch.source);
+    } catch (err) {
+      failures.push(`${watch.name}: ${errorMessage(err)}`);
+    }
+  }
+  return failures.length > 0
+    ? { id: "sensors", label: "Sensors", status: "fail", detail: failures.join("; ") }
+    : { id: "sensors", label: "Sensors", status: "pass", detail: `${watches.length} readable` };
+}
+
+async function checkProvider(
+  cfg: OpenClawConfig,
+  timeoutMs: number,
+  deps: Required<DoctorReportDeps>,
+): Promise<DoctorCheck> {
+  const ref = resolveConfiguredModelRef({
+    cfg,
+    defaultProvider: DEFAULT_PROVIDER,
+    defaultModel: DEFAULT_MODEL,
+  });
+  const label = "Model provider";
+  try {
+    await resolveApiKeyForProvider({ provider: ref.provider, cfg });
+  } catch (err) {
+    return { id: "provider", label, status: "fail", detail: errorMessage(err) };
+  }
+  try {
+    const probe = await deps.probeProvider({
+      cfg,
+      provider: ref.provider,
+      model: ref.model,
+      timeoutMs,
+    });
+    return {
+      id: "provider",
+      label,
+      status: probe.ok ? "pass" : "fail",
+      detail: `${ref.provider}/${ref.model}: ${probe.detail}`,
+    };
+  } catch (err) {
+    return { id: "provider", label, status: "fail", detail: errorMessage(err) };
+  }
+}
+
+async function checkDisk(deps: Required<DoctorReportDeps>): Promise<DoctorCheck> {
+  const stateDir = resolveStateDir();
+  try {
+    const free = await deps.freeBytes(stateDir);
+    const status = free < DISK_FAIL_BYTES ? "fail" : free < DISK_WARN_BYTES ? "warn" : "pass";
+    return {
+      id: "disk",
+      label: "Disk",
+      status,
+      detail: `${formatBytes(free)} free at ${shortenHomePath(stateDir)}`,
+    };
+  } catch (err) {
+    return { id: "disk", label: "Disk", status: "warn", detail: errorMessage(err) };
+  }
+}
+
+/**
+ * Read-only checklist for first-time setup on a device: config, Gateway and node
+ * connectivity, camera, LEDs, sensors, model provider, and free disk space.
+ */
+export async function runDoctorChecks(
+  params: { timeoutMs?: number; deps?: DoctorReportDeps } = {},
+): Promise<DoctorCheck[]> {
+  const deps = resolveDeps(params.deps ?? {});
+  const timeoutMs = params.timeoutMs ?? 10_000;
+  const snapshot = await readConfigFileSnapshot();
+  const checks: DoctorCheck[] = [];
+  if (!snapshot.exists) {
+    checks.push({ id: "config", label: "Config", status: "warn", detail: "no config file" });
+  } else if (snapshot.valid) {
+    checks.push({
+      id: "config",
+      label: "Config",
+      status: "pass",
+      detail: shortenHomePath(snapshot.path),
+    });
+  } else {
+    const [first] = snapshot.issues;
+    const more = snapshot.issues.length > 1 ? ` (+${snapshot.issues.length - 1} more)` : "";
+    checks.push({
+      id: "config",
+      label: "Config",
+      status: "fail",
+      detail: first ? `${first.path || "<root>"}: ${first.message}${more}` : "invalid",
+    });
+  }
+  const cfg: OpenClawConfig = snapshot.valid ? snapshot.config : {};
+  const connectivity = await checkConnectivity(cfg, timeoutMs, deps);
+  checks.push(...connectivity.checks);
+  checks.push(await checkCamera(cfg, connectivity.nodes, deps));
+  checks.push(await checkLeds(deps));
+  checks.push(await checkSensors(cfg, deps));
+  checks.push(await checkProvider(cfg, timeoutMs, deps));
+  checks.push(await checkDisk(deps));
+  return checks;
+}
+
+const STATUS_STYLE: Record<DoctorCheckStatus, (text: string) => string> = {
+  pass: theme.success,
+  warn: theme.warn,
+  fail: theme.error,
+  skip: theme.muted,
+};
+
+export function formatDoctorReport(checks: DoctorCheck[]): string[] {
+  const width = Math.max(...checks.map((check) => check.label.length));
+  return checks.map(
+    (check) =>
+      `${STATUS_STYLE[check.status](check.status.toUpperCase())}  ${check.label.padEnd(width)}  ${check.detail}`,
+  );
+}
+
+/** `openclaw doctor --report`: print the checklist and exit non-zero when anything fails. */
+export async function doctorReportCommand(
+  runtime: RuntimeEnv = defaultRuntime,
+  opts: DoctorReportOptions = {},
+) {
+  const checks = await runDoctorChecks({ timeoutMs: opts.timeoutMs });
+  const failed = checks.filter((check) => check.status === "fail").length;
+  if (opts.json) {
+    runtime.log(JSON.stringify({ ok: failed === 0, checks }, null, 2));
+  } else {
+    for (const line of formatDoctorReport(checks)) {
+      runtime.log(line);
+    }
+    runtime.log(
+      failed > 0 ? theme.error(`${failed} check(s) failed.`) : theme.success("All checks passed."),
+    );
+  }
+  if (failed > 0) {
+    runtime.exit(1);
+  }
+}
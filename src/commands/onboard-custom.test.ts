@@ -198,6 +198,28 @@ describe("promptCustomApiConfig", () => {
 });
 
 describe("applyCustomApiConfig", () => {
+  it("routes Azure OpenAI resources through the v1 API", () => {
+    const apply = (baseUrl: string) =>
+      applyCustomApiConfig({
+        config: {},
+        baseUrl,
+        modelId: "gpt-5-mini-prod",
+        compatibility: "openai",
+        apiKey: "azure-key",
+        providerId: "azure",
+      }).config.models?.providers?.azure?.baseUrl;
+
+    expect(apply("https://my-resource.openai.azure.com/")).toBe(
+      "https://my-resource.openai.azure.com/openai/v1",
+    );
+    expect(apply("https://my-resource.services.ai.azure.com/openai/v1")).toBe(
+      "https://my-resource.services.ai.azure.com/openai/v1",
+    );
+    expect(apply("https://my-resource.openai.azure.com/openai/deployments/gpt-5-mini-prod")).toBe(
+      "https://my-resource.openai.azure.com/openai/deployments/gpt-5-mini-prod",
+    );
+  });
+
   it.each([
     {
       name: "invalid compatibility values at runtime",
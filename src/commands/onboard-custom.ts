@@ -29,23 +29,28 @@ function isAzureUrl(baseUrl: string): boolean {
   }
 }
 
+const AZURE_LEGACY_DEPLOYMENT_PATH = "/openai/deployments/";
+const AZURE_LEGACY_API_VERSION = "2024-10-21";
+
 /**
- * Transforms an Azure AI Foundry/OpenAI URL to include the deployment path.
- * Azure requires: https://host/openai/deployments/<model-id>/chat/completions?api-version=2024-xx-xx-preview
- * But we can't add query params here, so we just add the path prefix.
- * The api-version will be handled by the Azure OpenAI client or as a query param.
+ * Point a bare Azure AI Foundry/OpenAI resource URL at the Azure OpenAI v1 API.
+ * The v1 API is OpenAI-compatible: the deployment name goes in `model`, no `api-version`
+ * query is needed, and it accepts API keys or Entra ID tokens as bearer credentials.
+ * URLs that already carry a deployment path or `/openai/v1` are kept as-is.
  *
  * Example:
- *   https://my-resource.services.ai.azure.com + gpt-5-nano
- *   => https://my-resource.services.ai.azure.com/openai/deployments/gpt-5-nano
+ *   https://my-resource.services.ai.azure.com
+ *   => https://my-resource.services.ai.azure.com/openai/v1
  */
-function transformAzureUrl(baseUrl: string, modelId: string): string {
-  const normalizedUrl = baseUrl.endsWith("/") ? baseUrl.slice(0, -1) : baseUrl;
-  // Check if the URL already includes the deployment path
-  if (normalizedUrl.includes("/openai/deployments/")) {
+function transformAzureUrl(baseUrl: string): string {
+  const normalizedUrl = baseUrl.replace(/\/+$/, "");
+  if (
+    normalizedUrl.includes(AZURE_LEGACY_DEPLOYMENT_PATH) ||
+    /\/openai\/v1$/i.test(normalizedUrl)
+  ) {
     return normalizedUrl;
   }
-  return `${normalizedUrl}/openai/deployments/${modelId}`;
+  return `${normalizedUrl}/openai/v1`;
 }
 
 export type CustomApiCompatibility = "openai" | "anthropic";
@@ -247,18 +252,17 @@ type VerificationResult = {
 
 function resolveVerificationEndpoint(params: {
   baseUrl: string;
-  modelId: string;
   endpointPath: "chat/completions" | "messages";
 }) {
-  const resolvedUrl = isAzureUrl(params.baseUrl)
-    ? transformAzureUrl(params.baseUrl, params.modelId)
-    : params.baseUrl;
+  const isAzureOpenAi = params.endpointPath === "chat/completions" && isAzureUrl(params.baseUrl);
+  const resolvedUrl = isAzureOpenAi ? transformAzureUrl(params.baseUrl) : params.baseUrl;
   const endpointUrl = new URL(
     params.endpointPath,
     resolvedUrl.endsWith("/") ? resolvedUrl : `${resolvedUrl}/`,
   );
-  if (isAzureUrl(params.baseUrl)) {
-    endpointUrl.searchParams.set("api-version", "2024-10-21");
+  // Only the legacy per-deployment API needs an explicit api-version.
+  if (isAzureOpenAi && resolvedUrl.includes(AZURE_LEGACY_DEPLOYMENT_PATH)) {
+    endpointUrl.searchParams.set("api-version", AZURE_LEGACY_API_VERSION);
   }
   return endpointUrl.href;
 }
@@ -294,7 +298,6 @@ async function requestOpenAiVerification(params: {
 }): Promise<VerificationResult> {
   const endpoint = resolveVerificationEndpoint({
     baseUrl: params.baseUrl,
-    modelId: params.modelId,
     endpointPath: "chat/completions",
   });
   return await requestVerification({
@@ -321,7 +324,6 @@ async function requestAnthropicVerification(params: {
     : params.baseUrl.trim().replace(/\/?$/, "") + "/v1";
   const endpoint = resolveVerificationEndpoint({
     baseUrl: baseUrlForRequest,
-    modelId: params.modelId,
     endpointPath: "messages",
   });
   return await requestVerification({
@@ -504,8 +506,9 @@ export function applyCustomApiConfig(params: ApplyCustomApiConfigParams): Custom
     throw new CustomApiError("invalid_model_id", "Custom provider model ID is required.");
   }
 
-  // Transform Azure URLs to include the deployment path for API calls
-  const resolvedBaseUrl = isAzureUrl(baseUrl) ? transformAzureUrl(baseUrl, modelId) : baseUrl;
+  // Route bare Azure resource URLs through the OpenAI-compatible v1 API.
+  const resolvedBaseUrl =
+    params.compatibility === "openai" && isAzureUrl(baseUrl) ? transformAzureUrl(baseUrl) : baseUrl;
 
   const providerIdResult = resolveCustomProviderId({
     config: params.config,
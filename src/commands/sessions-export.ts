@@ -0,0 +1,69 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { loadConfig } from "../config/config.js";
+import {
+  loadSessionStore,
+  resolveSessionFilePath,
+  resolveSessionFilePathOptions,
+  resolveStorePath,
+} from "../config/sessions.js";
+import { resolveAgentIdFromSessionKey } from "../routing/session-key.js";
+import type { RuntimeEnv } from "../runtime.js";
+import {
+  buildTranscriptExport,
+  renderTranscriptMarkdown,
+  type TranscriptExportFormat,
+} from "../sessions/transcript-export.js";
+import { theme } from "../terminal/theme.js";
+import { shortenHomePath } from "../utils.js";
+
+function parseExportFormat(raw?: string): TranscriptExportFormat {
+  const value = raw?.trim().toLowerCase() || "markdown";
+  if (value === "md" || value === "markdown") {
+    return "markdown";
+  }
+  if (value === "json") {
+    return "json";
+  }
+  throw new Error(`Unknown export format "${raw}" (use markdown or json)`);
+}
+
+export async function sessionsExportCommand(
+  opts: { sessionKey: string; format?: string; output?: string; store?: string },
+  runtime: RuntimeEnv,
+) {
+  const format = parseExportFormat(opts.format);
+  const cfg = loadConfig();
+  const agentId = resolveAgentIdFromSessionKey(opts.sessionKey);
+  const storePath = resolveStorePath(opts.store ?? cfg.session?.store, { agentId });
+  const entry = loadSessionStore(storePath, { skipCache: true })[opts.sessionKey];
+  if (!entry?.sessionId) {
+    throw new Error(`Session not found: ${opts.sessionKey}`);
+  }
+  const transcriptPath = resolveSessionFilePath(
+    entry.sessionId,
+    entry,
+    resolveSessionFilePathOptions({ agentId, storePath }),
+  );
+  const data = buildTranscriptExport({
+    transcript: await fs.readFile(transcriptPath, "utf-8").catch(() => ""),
+    sessionKey: opts.sessionKey,
+    sessionId: entry.sessionId,
+    config: cfg,
+  });
+  const text =
+    format === "json"
+      ? `${JSON.stringify(data, null, 2)}\n`
+      : renderTranscriptMarkdown(data, { config: cfg });
+  if (!opts.output) {
+    runtime.log(text.trimEnd());
+    return;
+  }
+  const output = path.resolve(opts.output);
+  await fs.mkdir(path.dirname(output), { recursive: true });
+  await fs.writeFile(output, text, { mode: 0o600 });
+  runtime.log(
+    `${theme.success("Transcript exported:")} ${shortenHomePath(output)} ` +
+      theme.muted(`(${data.turns.length} turns, ${format})`),
+  );
+}
@@ -0,0 +1,91 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { resolveAgentWorkspaceDir } from "../agents/agent-scope.js";
+import { loadConfig } from "../config/config.js";
+import { resolveStorePath } from "../config/sessions.js";
+import { resolveAgentIdFromSessionKey } from "../routing/session-key.js";
+import type { RuntimeEnv } from "../runtime.js";
+import {
+  createSessionSnapshot,
+  parseSessionSnapshot,
+  restoreSessionSnapshot,
+} from "../sessions/snapshot.js";
+import { DEFAULT_TASK_STORE_PATH } from "../tasks/store.js";
+import { theme } from "../terminal/theme.js";
+import { shortenHomePath } from "../utils.js";
+
+function resolveSessionPaths(sessionKey: string, store?: string) {
+  const cfg = loadConfig();
+  const agentId = resolveAgentIdFromSessionKey(sessionKey);
+  return {
+    agentId,
+    storePath: resolveStorePath(store ?? cfg.session?.store, { agentId }),
+    workspaceDir: resolveAgentWorkspaceDir(cfg, agentId),
+  };
+}
+
+export async function sessionsSnapshotCommand(
+  opts: { sessionKey: string; output?: string; store?: string; json?: boolean },
+  runtime: RuntimeEnv,
+) {
+  const paths = resolveSessionPaths(opts.sessionKey, opts.store);
+  const snapshot = await createSessionSnapshot({
+    ...paths,
+    sessionKey: opts.sessionKey,
+    taskStorePath: DEFAULT_TASK_STORE_PATH,
+  });
+  const stamp = new Date(snapshot.createdAt).toISOString().replace(/[:.]/g, "-");
+  const output = path.resolve(opts.output ?? `session-${snapshot.entry.sessionId}-${stamp}.json`);
+  await fs.mkdir(path.dirname(output), { recursive: true });
+  await fs.writeFile(output, `${JSON.stringify(snapshot, null, 2)}\n`, { mode: 0o600 });
+
+  const summary = {
+    path: output,
+    sessionKey: snapshot.sessionKey,
+    sessionId: snapshot.entry.sessionId,
+    transcriptLines: snapshot.transcript.split("\n").filter(Boolean).length,
+    memoryFiles: snapshot.memory.length,
+    tasks: snapshot.tasks.length,
+  };
+  if (opts.json) {
+    runtime.log(JSON.stringify(summary, null, 2));
+    return;
+  }
+  runtime.log(
+    `${theme.success("Snapshot written:")} ${shortenHomePath(output)} ` +
+      theme.muted(
+        `(${summary.transcriptLines} transcript lines, ${summary.memoryFiles} memory refs, ${summary.tasks} tasks)`,
+      ),
+  );
+}
+
+export async function sessionsRestoreCommand(
+  opts: { file: string; sessionKey?: string; store?: string; force?: boolean; json?: boolean },
+  runtime: RuntimeEnv,
+) {
+  const snapshot = parseSessionSnapshot(await fs.readFile(path.resolve(opts.file), "utf-8"));
+  const sessionKey = opts.sessionKey?.trim() || snapshot.sessionKey;
+  const paths = resolveSessionPaths(sessionKey, opts.store);
+  const result = await restoreSessionSnapshot({
+    ...paths,
+    snapshot,
+    sessionKey,
+    force: opts.force,
+    taskStorePath: DEFAULT_TASK_STORE_PATH,
+  });
+  if (opts.json) {
+    runtime.log(JSON.stringify(result, null, 2));
+    return;
+  }
+  runtime.log(
+    `${theme.success("Session restored:")} ${result.sessionKey} ` +
+      theme.muted(`(${result.sessionId}, ${result.tasksRestored} tasks)`),
+  );
+  const drifted = result.memory.filter((ref) => ref.status !== "ok");
+  for (const ref of drifted) {
+    runtime.log(theme.warn(`Memory ${ref.status}: ${ref.path}`));
+  }
+  if (snapshot.tasks.some((task) => task.reminderJobId)) {
+    runtime.log(theme.muted("Task reminders are not restored; re-add due times if needed."));
+  }
+}
@@ -0,0 +1,90 @@
+import JSON5 from "json5";
+import { isPlainObject } from "../utils.js";
+import { isBlockedObjectKey } from "./prototype-keys.js";
+
+/**
+ * `OPENCLAW_CONFIG__gateway__port=18790` sets `gateway.port`; `__` separates path segments and
+ * numeric segments index into arrays. Values are parsed as JSON5 when possible, else kept as
+ * strings. Overrides layer on top of the config file for this process only and are never written
+ * back to it.
+ */
+export const CONFIG_ENV_OVERRIDE_PREFIX = "OPENCLAW_CONFIG__";
+
+export type ConfigEnvOverride = {
+  envVar: string;
+  path: string[];
+  value: unknown;
+};
+
+function parseOverrideValue(raw: string): unknown {
+  const trimmed = raw.trim();
+  try {
+    return JSON5.parse(trimmed);
+  } catch {
+    return raw;
+  }
+}
+
+/** Sorted by path depth so `a__b` can be refined by a later `a__b__c`. */
+export function collectConfigEnvOverrides(
+  env: NodeJS.ProcessEnv = process.env,
+): ConfigEnvOverride[] {
+  const overrides: ConfigEnvOverride[] = [];
+  for (const [envVar, raw] of Object.entries(env)) {
+    if (!envVar.startsWith(CONFIG_ENV_OVERRIDE_PREFIX) || raw === undefined) {
+      continue;
+    }
+    const path = envVar.slice(CONFIG_ENV_OVERRIDE_PREFIX.length).split("__");
+    if (path.some((segment) => !segment || isBlockedObjectKey(segment))) {
+      continue;
+    }
+    overrides.push({ envVar, path, value: parseOverrideValue(raw) });
+  }
+  return overrides.toSorted(
+    (a, b) => a.path.length - b.path.length || a.envVar.localeCompare(b.envVar),
+  );
+}
+
+function cloneContainer(value: unknown, nextSegment: string): Record<string, unknown> | unknown[] {
+  if (Array.isArray(value)) {
+    return [...value];
+  }
+  if (isPlainObject(value)) {
+    return { ...value };
+  }
+  return /^\d+$/.test(nextSegment) ? [] : {};
+}
+
+/** Returns a copy of `raw` with the overrides applied; the input is left untouched. */
+export function applyConfigEnvOverrides(raw: unknown, overrides: ConfigEnvOverride[]): unknown {
+  if (overrides.length === 0) {
+    return raw;
+  }
+  let root = cloneContainer(raw, "") as Record<string, unknown>;
+  if (Array.isArray(root)) {
+    root = {};
+  }
+  for (const override of overrides) {
+    let cursor: Record<string, unknown> = root;
+    for (let idx = 0; idx < override.path.length - 1; idx += 1) {
+      const key = override.path[idx];
+      const next = cloneContainer(cursor[key], override.path[idx + 1]);
+      cursor[key] = next;
+      cursor = next as Record<string, unknown>;
+    }
+    cursor[override.path[override.path.length - 1]] = override.value;
+  }
+  return root;
+}
+
+/** Points a validation issue at the env var that produced it, if any. */
+export function describeConfigEnvOverrideIssue(
+  issuePath: string,
+  overrides: ConfigEnvOverride[],
+): string {
+  const source = overrides.findLast((override) => {
+    const path = override.path.join(".");
+    return issuePath === path || issuePath.startsWith(`${path}.`);
+  });
+  return source ? ` (set by ${source.envVar})` : "";
+}
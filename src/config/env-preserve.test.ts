@@ -179,4 +179,16 @@ describe("restoreEnvVarRefs", () => {
       resolved: "${MY_TOKEN}", // should restore ref
     });
   });
+
+  it("restores ${secret:NAME} references so secret values are never written to disk", () => {
+    const resolveSecret = (name: string) => (name === "BOT_TOKEN" ? "123:abc" : undefined);
+    const parsed = { token: "${secret:BOT_TOKEN}", other: "${secret:GONE}" };
+    const result = restoreEnvVarRefs(
+      { token: "123:abc", other: "typed-in" },
+      parsed,
+      env,
+      resolveSecret,
+    );
+    expect(result).toEqual({ token: "${secret:BOT_TOKEN}", other: "typed-in" });
+  });
 });
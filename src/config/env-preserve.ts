@@ -16,10 +16,12 @@ import { isPlainObject } from "../infra/plain-object.js";
  * resolves to), the new value is kept as-is.
  */
 
-const ENV_VAR_PATTERN = /\$\{[A-Z_][A-Z0-9_]*\}/;
+const ENV_VAR_PATTERN = /\$\{(?:[A-Z_][A-Z0-9_]*|secret:[A-Za-z0-9_.-]+)\}/;
+
+type SecretLookup = (name: string) => string | undefined;
 
 /**
- * Check if a string contains any `${VAR}` env var references.
+ * Check if a string contains any `${VAR}` env var or `${secret:NAME}` references.
  */
 function hasEnvVarRef(value: string): boolean {
   return ENV_VAR_PATTERN.test(value);
@@ -32,9 +34,15 @@ function hasEnvVarRef(value: string): boolean {
  * Mirrors the substitution semantics of `substituteString` in env-substitution.ts:
  * - `${VAR}` → env value (returns null if missing)
  * - `$${VAR}` → literal `${VAR}` (escape sequence)
+ * - `${secret:NAME}` → secret value (returns null if missing or no lookup is given)
  */
-function tryResolveString(template: string, env: NodeJS.ProcessEnv): string | null {
+function tryResolveString(
+  template: string,
+  env: NodeJS.ProcessEnv,
+  resolveSecret?: SecretLookup,
+): string | null {
   const ENV_VAR_NAME = /^[A-Z_][A-Z0-9_]*$/;
+  const SECRET_REF = /^secret:([A-Za-z0-9_.-]+)$/;
   const chunks: string[] = [];
 
   for (let i = 0; i < template.length; i++) {
@@ -45,7 +53,7 @@ function tryResolveString(template: string, env: NodeJS.ProcessEnv): string | nu
         const end = template.indexOf("}", start);
         if (end !== -1) {
           const name = template.slice(start, end);
-          if (ENV_VAR_NAME.test(name)) {
+          if (ENV_VAR_NAME.test(name) || SECRET_REF.test(name)) {
             chunks.push(`\${${name}}`);
             i = end;
             continue;
@@ -68,6 +76,16 @@ function tryResolveString(template: string, env: NodeJS.ProcessEnv): string | nu
             i = end;
             continue;
           }
+          const secret = SECRET_REF.exec(name);
+          if (secret) {
+            const val = resolveSecret?.(secret[1]);
+            if (!val) {
+              return null;
+            }
+            chunks.push(val);
+            i = end;
+            continue;
+          }
         }
       }
     }
@@ -84,12 +102,14 @@ function tryResolveString(template: string, env: NodeJS.ProcessEnv): string | nu
  * @param incoming - The resolved config about to be written
  * @param parsed - The pre-substitution parsed config (from the current file on disk)
  * @param env - Environment variables for verification
+ * @param resolveSecret - Secret lookup used to verify `${secret:NAME}` references
  * @returns A new config object with env var references restored where appropriate
  */
 export function restoreEnvVarRefs(
   incoming: unknown,
   parsed: unknown,
   env: NodeJS.ProcessEnv = process.env,
+  resolveSecret?: SecretLookup,
 ): unknown {
   // If parsed has no env var refs at this level, return incoming as-is
   if (parsed === null || parsed === undefined) {
@@ -99,7 +119,7 @@ export function restoreEnvVarRefs(
   // String leaf: check if parsed was a ${VAR} template that resolves to incoming
   if (typeof incoming === "string" && typeof parsed === "string") {
     if (hasEnvVarRef(parsed)) {
-      const resolved = tryResolveString(parsed, env);
+      const resolved = tryResolveString(parsed, env, resolveSecret);
       if (resolved === incoming) {
         // The incoming value matches what the env var resolves to — restore the reference
         return parsed;
@@ -111,7 +131,7 @@ export function restoreEnvVarRefs(
   // Arrays: walk element by element
   if (Array.isArray(incoming) && Array.isArray(parsed)) {
     return incoming.map((item, i) =>
-      i < parsed.length ? restoreEnvVarRefs(item, parsed[i], env) : item,
+      i < parsed.length ? restoreEnvVarRefs(item, parsed[i], env, resolveSecret) : item,
     );
   }
 
@@ -120,7 +140,7 @@ export function restoreEnvVarRefs(
     const result: Record<string, unknown> = {};
     for (const [key, value] of Object.entries(incoming)) {
       if (key in parsed) {
-        result[key] = restoreEnvVarRefs(value, parsed[key], env);
+        result[key] = restoreEnvVarRefs(value, parsed[key], env, resolveSecret);
       } else {
         // New key added by caller — keep as-is
         result[key] = value;
@@ -1,5 +1,9 @@
 import { describe, expect, it } from "vitest";
-import { MissingEnvVarError, resolveConfigEnvVars } from "./env-substitution.js";
+import {
+  MissingEnvVarError,
+  MissingSecretError,
+  resolveConfigEnvVars,
+} from "./env-substitution.js";
 
 describe("resolveConfigEnvVars", () => {
   describe("basic substitution", () => {
@@ -117,6 +121,34 @@ describe("resolveConfigEnvVars", () => {
     });
   });
 
+  describe("secret references", () => {
+    const resolveSecret = (name: string) => (name === "hass.token" ? "abc123" : undefined);
+
+    it("resolves ${secret:NAME} through the secret lookup", () => {
+      const result = resolveConfigEnvVars(
+        { auth: "Bearer ${secret:hass.token}", host: "${HASS_HOST}" },
+        { HASS_HOST: "hass.local" },
+        { resolveSecret },
+      );
+      expect(result).toEqual({ auth: "Bearer abc123", host: "hass.local" });
+    });
+
+    it("throws MissingSecretError with a hint when the secret is not stored", () => {
+      expect(() =>
+        resolveConfigEnvVars({ channels: { token: "${secret:BOT}" } }, {}, { resolveSecret }),
+      ).toThrow(MissingSecretError);
+      expect(() => resolveConfigEnvVars({ token: "${secret:BOT}" }, {})).toThrow(
+        "openclaw secrets set BOT",
+      );
+    });
+
+    it("keeps escaped secret references literal", () => {
+      expect(resolveConfigEnvVars({ key: "$${secret:BOT}" }, {})).toEqual({
+        key: "${secret:BOT}",
+      });
+    });
+  });
+
   describe("escape syntax", () => {
     it("outputs literal ${VAR} when escaped with $$", () => {
       const result = resolveConfigEnvVars({ key: "$${VAR}" }, { VAR: "value" });
@@ -5,6 +5,8 @@
  * - Only uppercase env vars are matched: `[A-Z_][A-Z0-9_]*`
  * - Escape with `$${}` to output literal `${}`
  * - Missing env vars throw `MissingEnvVarError` with context
+ * - `${secret:NAME}` resolves through the secrets backend (see `src/secrets/store.ts`);
+ *   unresolved secrets throw `MissingSecretError`
  *
  * @example
  * ```json5
@@ -25,6 +27,9 @@
 import { isPlainObject } from "../utils.js";
 
 const ENV_VAR_NAME_PATTERN = /^[A-Z_][A-Z0-9_]*$/;
+const SECRET_REF_PATTERN = /^secret:([A-Za-z0-9_.-]+)$/;
+
+export type SecretLookup = (name: string) => string | undefined;
 
 export class MissingEnvVarError extends Error {
   constructor(
@@ -36,9 +41,27 @@ export class MissingEnvVarError extends Error {
   }
 }
 
+export class MissingSecretError extends Error {
+  constructor(
+    public readonly secretName: string,
+    public readonly configPath: string,
+  ) {
+    super(
+      `Missing secret "${secretName}" referenced at config path: ${configPath} ` +
+        `(store it with: openclaw secrets set ${secretName})`,
+    );
+    this.name = "MissingSecretError";
+  }
+}
+
 type EnvToken =
   | { kind: "escaped"; name: string; end: number }
-  | { kind: "substitution"; name: string; end: number };
+  | { kind: "substitution"; name: string; end: number }
+  | { kind: "secret"; name: string; end: number };
+
+function isReferenceName(name: string): boolean {
+  return ENV_VAR_NAME_PATTERN.test(name) || SECRET_REF_PATTERN.test(name);
+}
 
 function parseEnvTokenAt(value: string, index: number): EnvToken | null {
   if (value[index] !== "$") {
@@ -48,13 +71,13 @@ function parseEnvTokenAt(value: string, index: number): EnvToken | null {
   const next = value[index + 1];
   const afterNext = value[index + 2];
 
-  // Escaped: $${VAR} -> ${VAR}
+  // Escaped: $${VAR} -> ${VAR}, $${secret:NAME} -> ${secret:NAME}
   if (next === "$" && afterNext === "{") {
     const start = index + 3;
     const end = value.indexOf("}", start);
     if (end !== -1) {
       const name = value.slice(start, end);
-      if (ENV_VAR_NAME_PATTERN.test(name)) {
+      if (isReferenceName(name)) {
         return { kind: "escaped", name, end };
       }
     }
@@ -69,13 +92,22 @@ function parseEnvTokenAt(value: string, index: number): EnvToken | null {
       if (ENV_VAR_NAME_PATTERN.test(name)) {
         return { kind: "substitution", name, end };
       }
+      const secret = SECRET_REF_PATTERN.exec(name);
+      if (secret) {
+        return { kind: "secret", name: secret[1], end };
+      }
     }
   }
 
   return null;
 }
 
-function substituteString(value: string, env: NodeJS.ProcessEnv, configPath: string): string {
+function substituteString(
+  value: string,
+  env: NodeJS.ProcessEnv,
+  configPath: string,
+  resolveSecret?: SecretLookup,
+): string {
   if (!value.includes("$")) {
     return value;
   }
@@ -104,6 +136,15 @@ function substituteString(value: string, env: NodeJS.ProcessEnv, configPath: str
       i = token.end;
       continue;
     }
+    if (token?.kind === "secret") {
+      const secretValue = resolveSecret?.(token.name);
+      if (!secretValue) {
+        throw new MissingSecretError(token.name, configPath);
+      }
+      chunks.push(secretValue);
+      i = token.end;
+      continue;
+    }
 
     // Leave untouched if not a recognized pattern
     chunks.push(char);
@@ -128,7 +169,7 @@ export function containsEnvVarReference(value: string): boolean {
       i = token.end;
       continue;
     }
-    if (token?.kind === "substitution") {
+    if (token?.kind === "substitution" || token?.kind === "secret") {
       return true;
     }
   }
@@ -136,20 +177,25 @@ export function containsEnvVarReference(value: string): boolean {
   return false;
 }
 
-function substituteAny(value: unknown, env: NodeJS.ProcessEnv, path: string): unknown {
+function substituteAny(
+  value: unknown,
+  env: NodeJS.ProcessEnv,
+  path: string,
+  resolveSecret?: SecretLookup,
+): unknown {
   if (typeof value === "string") {
-    return substituteString(value, env, path);
+    return substituteString(value, env, path, resolveSecret);
   }
 
   if (Array.isArray(value)) {
-    return value.map((item, index) => substituteAny(item, env, `${path}[${index}]`));
+    return value.map((item, index) => substituteAny(item, env, `${path}[${index}]`, resolveSecret));
   }
 
   if (isPlainObject(value)) {
     const result: Record<string, unknown> = {};
     for (const [key, val] of Object.entries(value)) {
       const childPath = path ? `${path}.${key}` : key;
-      result[key] = substituteAny(val, env, childPath);
+      result[key] = substituteAny(val, env, childPath, resolveSecret);
     }
     return result;
   }
@@ -159,13 +205,19 @@ function substituteAny(value: unknown, env: NodeJS.ProcessEnv, path: string): un
 }
 
 /**
- * Resolves `${VAR_NAME}` environment variable references in config values.
+ * Resolves `${VAR_NAME}` environment variable and `${secret:NAME}` references in config values.
  *
  * @param obj - The parsed config object (after JSON5 parse and $include resolution)
  * @param env - Environment variables to use for substitution (defaults to process.env)
+ * @param opts.resolveSecret - Secret lookup; without it every `${secret:NAME}` is missing
  * @returns The config object with env vars substituted
  * @throws {MissingEnvVarError} If a referenced env var is not set or empty
+ * @throws {MissingSecretError} If a referenced secret is not found in any source
  */
-export function resolveConfigEnvVars(obj: unknown, env: NodeJS.ProcessEnv = process.env): unknown {
-  return substituteAny(obj, env, "");
+export function resolveConfigEnvVars(
+  obj: unknown,
+  env: NodeJS.ProcessEnv = process.env,
+  opts: { resolveSecret?: SecretLookup } = {},
+): unknown {
+  return substituteAny(obj, env, "", opts.resolveSecret);
 }
@@ -1,7 +1,7 @@
 import * as fs from "node:fs/promises";
 import path from "node:path";
-import JSON5 from "json5";
 import { INCLUDE_KEY, MAX_INCLUDE_DEPTH } from "./includes.js";
+import { parseConfigText } from "./toml.js";
 
 function listDirectIncludes(parsed: unknown): string[] {
   const out: string[] = [];
@@ -70,7 +70,7 @@ export async function collectIncludePathsRecursive(params: {
       }
       const nestedParsed = (() => {
         try {
-          return JSON5.parse(rawText);
+          return parseConfigText(rawText, resolved);
         } catch {
           return null;
         }
@@ -12,10 +12,10 @@
 
 import fs from "node:fs";
 import path from "node:path";
-import JSON5 from "json5";
 import { isPathInside } from "../security/scan-paths.js";
 import { isPlainObject } from "../utils.js";
 import { isBlockedObjectKey } from "./prototype-keys.js";
+import { parseConfigText } from "./toml.js";
 
 export const INCLUDE_KEY = "$include";
 export const MAX_INCLUDE_DEPTH = 10;
@@ -26,7 +26,8 @@ export const MAX_INCLUDE_DEPTH = 10;
 
 export type IncludeResolver = {
   readFile: (path: string) => string;
-  parseJson: (raw: string) => unknown;
+  /** `path` lets resolvers pick a format (`.toml` includes are TOML, everything else JSON5). */
+  parseJson: (raw: string, path: string) => unknown;
 };
 
 // ============================================================================
@@ -239,7 +240,7 @@ class IncludeProcessor {
 
   private parseFile(includePath: string, resolvedPath: string, raw: string): unknown {
     try {
-      return this.resolver.parseJson(raw);
+      return this.resolver.parseJson(raw, resolvedPath);
     } catch (err) {
       throw new ConfigIncludeError(
         `Failed to parse include file: ${includePath} (resolved: ${resolvedPath})`,
@@ -271,7 +272,7 @@ function safeRealpath(target: string): string {
 
 const defaultResolver: IncludeResolver = {
   readFile: (p) => fs.readFileSync(p, "utf-8"),
-  parseJson: (raw) => JSON5.parse(raw),
+  parseJson: (raw, p) => parseConfigText(raw, p),
 };
 
 /**
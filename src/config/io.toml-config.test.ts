@@ -0,0 +1,83 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { createConfigIO } from "./io.js";
+import { parseToml } from "./toml.js";
+
+const TOML_CONFIG = `[gateway]
+port = 19001
+
+[agents.defaults]
+workspace = "~/garden"
+`;
+
+describe("config io (TOML + env overrides)", () => {
+  let home = "";
+  let configPath = "";
+
+  beforeEach(async () => {
+    home = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-toml-"));
+    configPath = path.join(home, ".openclaw", "openclaw.toml");
+    await fs.mkdir(path.dirname(configPath), { recursive: true });
+    await fs.writeFile(configPath, TOML_CONFIG);
+  });
+
+  afterEach(async () => {
+    await fs.rm(home, { recursive: true, force: true });
+  });
+
+  const ioFor = (env: Record<string, string> = {}) =>
+    createConfigIO({
+      env: env as NodeJS.ProcessEnv,
+      homedir: () => home,
+      logger: { warn: vi.fn(), error: vi.fn() },
+    });
+
+  it("finds and loads ~/.openclaw/openclaw.toml", async () => {
+    const io = ioFor();
+    expect(io.configPath).toBe(configPath);
+    expect(io.loadConfig().gateway?.port).toBe(19001);
+    expect((await io.readConfigFileSnapshot()).valid).toBe(true);
+  });
+
+  it("layers OPENCLAW_CONFIG__ env vars over the file", async () => {
+    const io = ioFor({
+      OPENCLAW_CONFIG__gateway__port: "19002",
+      OPENCLAW_CONFIG__gateway__mode: "local",
+    });
+    const cfg = io.loadConfig();
+    expect(cfg.gateway?.port).toBe(19002);
+    expect(cfg.gateway?.mode).toBe("local");
+  });
+
+  it("names the env var behind an invalid value", async () => {
+    const io = ioFor({ OPENCLAW_CONFIG__gateway__port: "not-a-port" });
+    const snapshot = await io.readConfigFileSnapshot();
+    expect(snapshot.valid).toBe(false);
+    expect(snapshot.issues).toEqual([
+      expect.objectContaining({
+        path: "gateway.port",
+        message: expect.stringContaining("(set by OPENCLAW_CONFIG__gateway__port)"),
+      }),
+    ]);
+  });
+
+  it("reports TOML syntax errors with a line number", async () => {
+    await fs.writeFile(configPath, "[gateway]\nmode = local\n");
+    const snapshot = await ioFor().readConfigFileSnapshot();
+    expect(snapshot.valid).toBe(false);
+    expect(snapshot.issues[0]?.message).toContain("TOML parse failed at line 2");
+  });
+
+  it("writes TOML back without persisting env overrides", async () => {
+    const io = ioFor({ OPENCLAW_CONFIG__gateway__port: "19002" });
+    const cfg = io.loadConfig();
+    await io.writeConfigFile({ ...cfg, gateway: { ...cfg.gateway, mode: "local" } });
+
+    const written = parseToml(await fs.readFile(configPath, "utf-8")) as {
+      gateway: { port: number; mode: string };
+    };
+    expect(written.gateway).toEqual({ port: 19001, mode: "local" });
+  });
+});
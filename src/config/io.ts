@@ -13,6 +13,7 @@ import {
   shouldDeferShellEnvFallback,
   shouldEnableShellEnvFallback,
 } from "../infra/shell-env.js";
+import { createSecretResolver, SecretsFileError } from "../secrets/store.js";
 import { VERSION } from "../version.js";
 import { DuplicateAgentDirError, findDuplicateAgentDirs } from "./agent-dirs.js";
 import { rotateConfigBackups } from "./backup-rotation.js";
@@ -26,9 +27,16 @@ import {
   applySessionDefaults,
   applyTalkApiKey,
 } from "./defaults.js";
+import {
+  applyConfigEnvOverrides,
+  collectConfigEnvOverrides,
+  describeConfigEnvOverrideIssue,
+  type ConfigEnvOverride,
+} from "./env-overrides.js";
 import { restoreEnvVarRefs } from "./env-preserve.js";
 import {
   MissingEnvVarError,
+  MissingSecretError,
   containsEnvVarReference,
   resolveConfigEnvVars,
 } from "./env-substitution.js";
@@ -39,8 +47,14 @@ import { applyMergePatch } from "./merge-patch.js";
 import { normalizeExecSafeBinProfilesInConfig } from "./normalize-exec-safe-bin.js";
 import { normalizeConfigPaths } from "./normalize-paths.js";
 import { resolveConfigPath, resolveDefaultConfigCandidates, resolveStateDir } from "./paths.js";
+import {
+  applyConfigProfile,
+  ConfigProfileError,
+  resolveActiveConfigProfile,
+} from "./profiles.js";
 import { isBlockedObjectKey } from "./prototype-keys.js";
 import { applyConfigOverrides } from "./runtime-overrides.js";
+import { isTomlConfigPath, parseConfigText, stringifyToml, TomlParseError } from "./toml.js";
 import type { OpenClawConfig, ConfigFileSnapshot, LegacyConfigIssue } from "./types.js";
 import {
   validateConfigObjectRawWithPlugins,
@@ -50,7 +64,7 @@ import { compareOpenClawVersions } from "./version.js";
 
 // Re-export for backwards compatibility
 export { CircularIncludeError, ConfigIncludeError } from "./includes.js";
-export { MissingEnvVarError } from "./env-substitution.js";
+export { MissingEnvVarError, MissingSecretError } from "./env-substitution.js";
 
 const SHELL_ENV_EXPECTED_KEYS = [
   "OPENAI_API_KEY",
@@ -596,6 +610,43 @@ export function parseConfigJson5(
   }
 }
 
+function parseConfigFileRaw(
+  raw: string,
+  configPath: string,
+  json5: { parse: (value: string) => unknown },
+): ParseConfigJson5Result {
+  if (!isTomlConfigPath(configPath)) {
+    return parseConfigJson5(raw, json5);
+  }
+  try {
+    return { ok: true, parsed: parseConfigText(raw, configPath) };
+  } catch (err) {
+    return { ok: false, error: err instanceof TomlParseError ? err.message : String(err) };
+  }
+}
+
+function annotateEnvOverrideIssues<T extends { path: string; message: string }>(
+  issues: T[],
+  envOverrides: ConfigEnvOverride[],
+): T[] {
+  if (envOverrides.length === 0) {
+    return issues;
+  }
+  return issues.map((iss) => ({
+    ...iss,
+    message: `${iss.message}${describeConfigEnvOverrideIssue(iss.path, envOverrides)}`,
+  }));
+}
+
+function formatConfigIssues(
+  issues: Array<{ path: string; message: string }>,
+  envOverrides: ConfigEnvOverride[],
+): string {
+  return annotateEnvOverrideIssues(issues, envOverrides)
+    .map((iss) => `- ${iss.path || "<root>"}: ${iss.message}`)
+    .join("\n");
+}
+
 type ConfigReadResolution = {
   resolvedConfigRaw: unknown;
   envSnapshotForRestore: Record<string, string | undefined>;
@@ -608,7 +659,7 @@ function resolveConfigIncludesForRead(
 ): unknown {
   return resolveConfigIncludes(parsed, configPath, {
     readFile: (candidate) => deps.fs.readFileSync(candidate, "utf-8"),
-    parseJson: (raw) => deps.json5.parse(raw),
+    parseJson: (raw, candidate) => parseConfigText(raw, candidate, deps.json5),
   });
 }
 
@@ -621,8 +672,14 @@ function resolveConfigForRead(
     applyConfigEnvVars(resolvedIncludes as OpenClawConfig, env);
   }
 
+  const resolveSecret = createSecretResolver({
+    config: (resolvedIncludes as OpenClawConfig | null)?.secrets,
+    env,
+  });
   return {
-    resolvedConfigRaw: resolveConfigEnvVars(resolvedIncludes, env),
+    resolvedConfigRaw: resolveConfigEnvVars(resolvedIncludes, env, {
+      resolveSecret: (name) => resolveSecret(name)?.value,
+    }),
     // Capture env snapshot after substitution for write-time ${VAR} restoration.
     envSnapshotForRestore: { ...env } as Record<string, string | undefined>,
   };
@@ -645,7 +702,9 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
   function loadConfig(): OpenClawConfig {
     try {
       maybeLoadDotEnvForConfig(deps.env);
-      if (!deps.fs.existsSync(configPath)) {
+      const envOverrides = collectConfigEnvOverrides(deps.env);
+      const exists = deps.fs.existsSync(configPath);
+      if (!exists && envOverrides.length === 0) {
         if (shouldEnableShellEnvFallback(deps.env) && !shouldDeferShellEnvFallback(deps.env)) {
           loadShellEnvFallback({
             enabled: true,
@@ -657,12 +716,17 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
         }
         return {};
       }
-      const raw = deps.fs.readFileSync(configPath, "utf-8");
-      const parsed = deps.json5.parse(raw);
-      const { resolvedConfigRaw: resolvedConfig } = resolveConfigForRead(
+      const parsed = exists
+        ? parseConfigText(deps.fs.readFileSync(configPath, "utf-8"), configPath, deps.json5)
+        : {};
+      const { resolvedConfigRaw } = resolveConfigForRead(
         resolveConfigIncludesForRead(parsed, configPath, deps),
         deps.env,
       );
+      const resolvedConfig = applyConfigEnvOverrides(
+        applyConfigProfile(resolvedConfigRaw, resolveActiveConfigProfile(deps.env)),
+        envOverrides,
+      );
       warnOnConfigMiskeys(resolvedConfig, deps.logger);
       if (typeof resolvedConfig !== "object" || resolvedConfig === null) {
         return {};
@@ -676,9 +740,7 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
       }
       const validated = validateConfigObjectWithPlugins(resolvedConfig);
       if (!validated.ok) {
-        const details = validated.issues
-          .map((iss) => `- ${iss.path || "<root>"}: ${iss.message}`)
-          .join("\n");
+        const details = formatConfigIssues(validated.issues, envOverrides);
         if (!loggedInvalidConfigs.has(configPath)) {
           loggedInvalidConfigs.add(configPath);
           deps.logger.error(`Invalid config at ${configPath}:\\n${details}`);
@@ -739,7 +801,8 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
           configPath,
           ownerDisplaySecretResolution.generatedSecret,
         );
-        if (!AUTO_OWNER_DISPLAY_SECRET_PERSIST_IN_FLIGHT.has(configPath)) {
+        // Env-only configs have no file to persist into; keep the secret in memory instead.
+        if (exists && !AUTO_OWNER_DISPLAY_SECRET_PERSIST_IN_FLIGHT.has(configPath)) {
           AUTO_OWNER_DISPLAY_SECRET_PERSIST_IN_FLIGHT.add(configPath);
           void writeConfigFile(cfgWithOwnerDisplaySecret, { expectedConfigPath: configPath })
             .then(() => {
@@ -780,14 +843,37 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
 
   async function readConfigFileSnapshotInternal(): Promise<ReadConfigFileSnapshotInternalResult> {
     maybeLoadDotEnvForConfig(deps.env);
+    const envOverrides = collectConfigEnvOverrides(deps.env);
     const exists = deps.fs.existsSync(configPath);
     if (!exists) {
       const hash = hashConfigRaw(null);
+      const validated =
+        envOverrides.length > 0
+          ? validateConfigObjectWithPlugins(applyConfigEnvOverrides({}, envOverrides))
+          : null;
+      if (validated && !validated.ok) {
+        return {
+          snapshot: {
+            path: configPath,
+            exists: false,
+            raw: null,
+            parsed: {},
+            resolved: {},
+            valid: false,
+            config: {},
+            hash,
+            issues: annotateEnvOverrideIssues(validated.issues, envOverrides),
+            warnings: validated.warnings,
+            legacyIssues: [],
+          },
+        };
+      }
+      const base = validated?.config ?? {};
       const config = applyTalkApiKey(
         applyModelDefaults(
           applyCompactionDefaults(
             applyContextPruningDefaults(
-              applyAgentDefaults(applySessionDefaults(applyMessageDefaults({}))),
+              applyAgentDefaults(applySessionDefaults(applyMessageDefaults(base))),
             ),
           ),
         ),
@@ -804,7 +890,7 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
           config,
           hash,
           issues: [],
-          warnings: [],
+          warnings: validated?.warnings ?? [],
           legacyIssues,
         },
       };
@@ -813,8 +899,11 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
     try {
       const raw = deps.fs.readFileSync(configPath, "utf-8");
       const hash = hashConfigRaw(raw);
-      const parsedRes = parseConfigJson5(raw, deps.json5);
+      const parsedRes = parseConfigFileRaw(raw, configPath, deps.json5);
       if (!parsedRes.ok) {
+        const message = isTomlConfigPath(configPath)
+          ? parsedRes.error
+          : `JSON5 parse failed: ${parsedRes.error}`;
         return {
           snapshot: {
             path: configPath,
@@ -825,7 +914,7 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
             valid: false,
             config: {},
             hash,
-            issues: [{ path: "", message: `JSON5 parse failed: ${parsedRes.error}` }],
+            issues: [{ path: "", message }],
             warnings: [],
             legacyIssues: [],
           },
@@ -863,7 +952,9 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
         readResolution = resolveConfigForRead(resolved, deps.env);
       } catch (err) {
         const message =
-          err instanceof MissingEnvVarError
+          err instanceof MissingEnvVarError ||
+          err instanceof MissingSecretError ||
+          err instanceof SecretsFileError
             ? err.message
             : `Env var substitution failed: ${String(err)}`;
         return {
@@ -886,8 +977,18 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
       const resolvedConfigRaw = readResolution.resolvedConfigRaw;
       const legacyIssues = findLegacyConfigIssues(resolvedConfigRaw);
 
-      const validated = validateConfigObjectWithPlugins(resolvedConfigRaw);
-      if (!validated.ok) {
+      // The active profile and env overrides are validated with the file but kept out of
+      // `resolved`, so writes never persist them.
+      let profiledConfigRaw: unknown;
+      try {
+        profiledConfigRaw = applyConfigProfile(
+          resolvedConfigRaw,
+          resolveActiveConfigProfile(deps.env),
+        );
+      } catch (err) {
+        if (!(err instanceof ConfigProfileError)) {
+          throw err;
+        }
         return {
           snapshot: {
             path: configPath,
@@ -898,7 +999,26 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
             valid: false,
             config: coerceConfig(resolvedConfigRaw),
             hash,
-            issues: validated.issues,
+            issues: [{ path: err.configPath, message: err.message }],
+            warnings: [],
+            legacyIssues,
+          },
+        };
+      }
+      const effectiveConfigRaw = applyConfigEnvOverrides(profiledConfigRaw, envOverrides);
+      const validated = validateConfigObjectWithPlugins(effectiveConfigRaw);
+      if (!validated.ok) {
+        return {
+          snapshot: {
+            path: configPath,
+            exists: true,
+            raw,
+            parsed: parsedRes.parsed,
+            resolved: coerceConfig(resolvedConfigRaw),
+            valid: false,
+            config: coerceConfig(effectiveConfigRaw),
+            hash,
+            issues: annotateEnvOverrideIssues(validated.issues, envOverrides),
             warnings: validated.warnings,
             legacyIssues,
           },
@@ -981,7 +1101,7 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
       try {
         const resolvedIncludes = resolveConfigIncludes(snapshot.parsed, configPath, {
           readFile: (candidate) => deps.fs.readFileSync(candidate, "utf-8"),
-          parseJson: (raw) => deps.json5.parse(raw),
+          parseJson: (raw, candidate) => parseConfigText(raw, candidate, deps.json5),
         });
         const collected = new Map<string, string>();
         collectEnvRefPaths(resolvedIncludes, "", collected);
@@ -1021,16 +1141,21 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
     try {
       if (deps.fs.existsSync(configPath)) {
         const currentRaw = await deps.fs.promises.readFile(configPath, "utf-8");
-        const parsedRes = parseConfigJson5(currentRaw, deps.json5);
+        const parsedRes = parseConfigFileRaw(currentRaw, configPath, deps.json5);
         if (parsedRes.ok) {
           // Use env snapshot from when config was loaded (if available) to avoid
           // TOCTOU issues where env changes between load and write. Falls back to
           // live env if no snapshot exists (e.g., first write before any load).
           const envForRestore = options.envSnapshotForRestore ?? deps.env;
+          const resolveSecret = createSecretResolver({
+            config: validated.config.secrets,
+            env: envForRestore,
+          });
           cfgToWrite = restoreEnvVarRefs(
             cfgToWrite,
             parsedRes.parsed,
             envForRestore,
+            (name) => resolveSecret(name)?.value,
           ) as OpenClawConfig;
         }
       }
@@ -1059,7 +1184,9 @@ export function createConfigIO(overrides: ConfigIoDeps = {}) {
     // Do NOT apply runtime defaults when writing — user config should only contain
     // explicitly set values. Runtime defaults are applied when loading (issue #6070).
     const stampedOutputConfig = stampConfigVersion(outputConfig);
-    const json = JSON.stringify(stampedOutputConfig, null, 2).trimEnd().concat("\n");
+    const json = isTomlConfigPath(configPath)
+      ? stringifyToml(stampedOutputConfig as Record<string, unknown>)
+      : JSON.stringify(stampedOutputConfig, null, 2).trimEnd().concat("\n");
     const nextHash = hashConfigRaw(json);
     const previousHash = resolveConfigSnapshotHash(snapshot);
     const changedPathCount = changedPaths?.size;
@@ -155,13 +155,16 @@ function isSignalConfigured(cfg: OpenClawConfig): boolean {
   if (
     hasNonEmptyString(entry.account) ||
     hasNonEmptyString(entry.httpUrl) ||
+    hasNonEmptyString(entry.socketPath) ||
     hasNonEmptyString(entry.httpHost) ||
     typeof entry.httpPort === "number" ||
     hasNonEmptyString(entry.cliPath)
   ) {
     return true;
   }
-  if (accountsHaveKeys(entry.accounts, ["account", "httpUrl", "httpHost", "cliPath"])) {
+  if (
+    accountsHaveKeys(entry.accounts, ["account", "httpUrl", "socketPath", "httpHost", "cliPath"])
+  ) {
     return true;
   }
   return recordHasKeys(entry);
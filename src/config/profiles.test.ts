@@ -0,0 +1,92 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { createConfigIO } from "./io.js";
+import { applyConfigProfile, ConfigProfileError } from "./profiles.js";
+
+const BASE = {
+  gateway: { port: 18789, mode: "local" },
+  tools: { deny: ["exec", "browser"] },
+  profiles: {
+    raspi: { tools: { deny: ["browser"] } },
+    "raspi-garage": { extends: "raspi", gateway: { port: 19001 }, tools: { deny: null } },
+    loop: { extends: "loop-b" },
+    "loop-b": { extends: "loop" },
+    orphan: { extends: "missing" },
+  },
+};
+
+describe("config profiles", () => {
+  it("layers a profile and its parents over the base config", () => {
+    expect(applyConfigProfile(BASE, "raspi")).toMatchObject({
+      gateway: { port: 18789, mode: "local" },
+      tools: { deny: ["browser"] },
+    });
+    const garage = applyConfigProfile(BASE, "raspi-garage") as typeof BASE;
+    expect(garage.gateway).toEqual({ port: 19001, mode: "local" });
+    expect(garage.tools).toEqual({});
+    expect(garage).not.toHaveProperty("extends");
+  });
+
+  it("leaves the config alone without a matching profile", () => {
+    expect(applyConfigProfile(BASE, null)).toBe(BASE);
+    expect(applyConfigProfile(BASE, "rescue")).toBe(BASE);
+  });
+
+  it("rejects unknown parents and inheritance cycles", () => {
+    expect(() => applyConfigProfile(BASE, "orphan")).toThrow(
+      'Profile "orphan" extends unknown profile "missing"',
+    );
+    expect(() => applyConfigProfile(BASE, "loop")).toThrow(ConfigProfileError);
+    expect(() => applyConfigProfile(BASE, "loop")).toThrow("loop -> loop-b -> loop");
+  });
+});
+
+describe("config io (profiles)", () => {
+  let home = "";
+  let configPath = "";
+
+  beforeEach(async () => {
+    home = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-profiles-"));
+    configPath = path.join(home, "configs", "openclaw.json");
+    await fs.mkdir(path.dirname(configPath), { recursive: true });
+    const { loop: _loop, "loop-b": _loopB, orphan: _orphan, ...profiles } = BASE.profiles;
+    await fs.writeFile(configPath, JSON.stringify({ ...BASE, profiles }, null, 2));
+  });
+
+  afterEach(async () => {
+    await fs.rm(home, { recursive: true, force: true });
+  });
+
+  const ioFor = (profile?: string) =>
+    createConfigIO({
+      env: { OPENCLAW_CONFIG_PATH: configPath, OPENCLAW_PROFILE: profile } as NodeJS.ProcessEnv,
+      homedir: () => home,
+      logger: { warn: vi.fn(), error: vi.fn() },
+    });
+
+  it("applies the active profile and keeps it out of writes", async () => {
+    const io = ioFor("raspi-garage");
+    const cfg = io.loadConfig();
+    expect(cfg.gateway?.port).toBe(19001);
+    expect((await io.readConfigFileSnapshot()).config.gateway?.port).toBe(19001);
+
+    await io.writeConfigFile({ ...cfg, gateway: { ...cfg.gateway, bind: "lan" } });
+    const written = JSON.parse(await fs.readFile(configPath, "utf-8"));
+    expect(written.gateway).toEqual({ port: 18789, mode: "local", bind: "lan" });
+    expect(written.tools).toEqual({ deny: ["exec", "browser"] });
+    expect(ioFor().loadConfig().gateway?.port).toBe(18789);
+  });
+
+  it("reports a broken extends chain as a config issue", async () => {
+    const raw = JSON.parse(await fs.readFile(configPath, "utf-8"));
+    raw.profiles.raspi.extends = "pi-zero";
+    await fs.writeFile(configPath, JSON.stringify(raw));
+    const snapshot = await ioFor("raspi-garage").readConfigFileSnapshot();
+    expect(snapshot.valid).toBe(false);
+    expect(snapshot.issues).toEqual([
+      { path: "profiles.raspi.extends", message: expect.stringContaining('"pi-zero"') },
+    ]);
+  });
+});
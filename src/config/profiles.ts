@@ -0,0 +1,70 @@
+import { isPlainObject } from "../utils.js";
+import { applyMergePatch } from "./merge-patch.js";
+import type { ConfigProfile } from "./types.profiles.js";
+
+/**
+ * `profiles.<name>` overlays the base config when the process runs with `--profile <name>`
+ * (`OPENCLAW_PROFILE`). Overlays merge like `config.patch` and are applied before
+ * `OPENCLAW_CONFIG__*` env overrides. Like those, they are never written back to the file.
+ */
+export class ConfigProfileError extends Error {
+  constructor(
+    message: string,
+    public readonly configPath: string,
+  ) {
+    super(message);
+    this.name = "ConfigProfileError";
+  }
+}
+
+export function resolveActiveConfigProfile(env: NodeJS.ProcessEnv = process.env): string | null {
+  return env.OPENCLAW_PROFILE?.trim() || null;
+}
+
+/** Base-first list of profile names to apply, following `extends`. */
+export function resolveConfigProfileChain(
+  profiles: Record<string, ConfigProfile>,
+  name: string,
+): string[] {
+  const chain: string[] = [];
+  let current: string | undefined = name;
+  while (current !== undefined) {
+    if (chain.includes(current)) {
+      throw new ConfigProfileError(
+        `Profile inheritance cycle: ${[...chain, current].join(" -> ")}`,
+        `profiles.${chain[chain.length - 1]}.extends`,
+      );
+    }
+    const profile: ConfigProfile | undefined = profiles[current];
+    if (!isPlainObject(profile)) {
+      const from = chain[chain.length - 1];
+      throw new ConfigProfileError(
+        `Profile "${from}" extends unknown profile "${current}"`,
+        `profiles.${from}.extends`,
+      );
+    }
+    chain.push(current);
+    current = profile.extends?.trim() || undefined;
+  }
+  return chain.toReversed();
+}
+
+/**
+ * Returns `raw` with the active profile's overlays applied. Running with a profile the config
+ * doesn't define is fine (`--profile` also just isolates the state dir).
+ */
+export function applyConfigProfile(raw: unknown, profile: string | null): unknown {
+  if (!profile || !isPlainObject(raw) || !isPlainObject(raw.profiles)) {
+    return raw;
+  }
+  const profiles = raw.profiles as Record<string, ConfigProfile>;
+  if (!(profile in profiles)) {
+    return raw;
+  }
+  let result: unknown = raw;
+  for (const name of resolveConfigProfileChain(profiles, profile)) {
+    const { extends: _extends, profiles: _nested, ...overlay } = profiles[name];
+    result = applyMergePatch(result, overlay);
+  }
+  return result;
+}
@@ -25,6 +25,10 @@ const ROOT_SECTIONS = [
   "approvals",
   "session",
   "cron",
+  "cost",
+  "watchdog",
+  "secrets",
+  "profiles",
   "hooks",
   "web",
   "channels",
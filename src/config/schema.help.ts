@@ -141,6 +141,10 @@ export const FIELD_HELP: Record<string, string> = {
     "Explicit list of configured agents with IDs and optional overrides for model, tools, identity, and workspace. Keep IDs stable over time so bindings, approvals, and session routing remain deterministic.",
   "agents.list[].identity.avatar":
     "Avatar image path (relative to the agent workspace only) or a remote URL/data URL.",
+  "agents.defaults.heartbeat.checklist":
+    "Checklist items appended to every heartbeat prompt (for example sensor checks, pending tasks, unread events). With a checklist set, heartbeats run even when HEARTBEAT.md is missing or empty; the agent still replies HEARTBEAT_OK when nothing is noteworthy.",
+  "agents.list[].heartbeat.checklist":
+    "Per-agent heartbeat checklist; replaces agents.defaults.heartbeat.checklist for this agent.",
   "agents.defaults.heartbeat.suppressToolErrorWarnings":
     "Suppress tool error warning payloads during heartbeat runs.",
   "agents.list[].heartbeat.suppressToolErrorWarnings":
@@ -301,6 +305,8 @@ export const FIELD_HELP: Record<string, string> = {
     "Disables Control UI device identity checks and relies on token/password only. Use only for short-lived debugging on trusted networks, then turn it off immediately.",
   "gateway.http.endpoints.chatCompletions.enabled":
     "Enable the OpenAI-compatible `POST /v1/chat/completions` endpoint (default: false).",
+  "gateway.http.endpoints.chat.enabled":
+    "Enable the plain `POST /v1/chat` endpoint: send one message, get the agent reply as JSON (default: false).",
   "gateway.reload.mode":
     'Controls how config edits are applied: "off" ignores live edits, "restart" always restarts, "hot" applies in-process, and "hybrid" tries hot then restarts if required. Keep "hybrid" for safest routine updates.',
   "gateway.reload.debounceMs": "Debounce window (ms) before applying config changes.",
@@ -397,6 +403,52 @@ export const FIELD_HELP: Record<string, string> = {
     "Restrict apply_patch paths to the workspace directory (default: true). Set false to allow writing outside the workspace (dangerous).",
   "tools.exec.applyPatch.allowModels":
     'Optional allowlist of model ids (e.g. "gpt-5.2" or "openai/gpt-5.2").',
+  "tools.maxCallsPerRun":
+    "Maximum tool calls in one agent run. Once reached, further calls are refused and the model is told to stop and reply with what it has, so a confused model cannot loop forever. Unset means no cap.",
+  "agents.list[].tools.maxCallsPerRun":
+    "Per-agent override for tools.maxCallsPerRun (max tool calls in one agent run).",
+  "tools.autonomy":
+    "Autonomy level for side-effecting tool calls (writes, exec, sending messages, actuator tools, and any plugin tool not listed in readOnlyTools). Read-only calls are never gated.",
+  "tools.autonomy.mode":
+    'How side-effecting tool calls are handled: "observe" blocks them, "ask" makes the agent ask and act only after the user replies, "auto" runs them (default). Owners can switch a session at runtime with /autonomy.',
+  "tools.autonomy.actionTools":
+    'Extra tool names or groups treated as side-effecting (for example ["mqtt_*"]). Built-in tools are already classified.',
+  "tools.autonomy.readOnlyTools":
+    'Plugin or skill tools that only read (for example ["adc_read", "bme280_*"]). Any tool that is neither built-in nor listed here is treated as side-effecting.',
+  "agents.list[].tools.autonomy":
+    "Per-agent override for tools.autonomy (autonomy level for side-effecting tool calls).",
+  "agents.list[].tools.autonomy.mode": "Per-agent override for tools.autonomy.mode.",
+  "agents.list[].tools.autonomy.actionTools": "Per-agent override for tools.autonomy.actionTools.",
+  "agents.list[].tools.autonomy.readOnlyTools":
+    "Per-agent override for tools.autonomy.readOnlyTools.",
+  "tools.parallel":
+    "Concurrent execution of independent tool calls the model returns in one turn. Read-only calls before the first side-effecting call run together; side-effecting calls still run one at a time, in order.",
+  "tools.parallel.enabled":
+    "Run independent read-only tool calls from one turn concurrently (default: false).",
+  "tools.parallel.maxConcurrency": "Maximum tool calls running at once (default: 4).",
+  "tools.parallel.tools":
+    'Tool names or groups allowed to run in parallel even though they are side-effecting (for example ["nodes"] for camera snaps alongside sensor reads).',
+  "tools.parallel.exclusive":
+    'Tool names or groups that hold a resource lock: at most one call of each runs at a time (for example ["i2c_read"] for a shared bus).',
+  "tools.guardrails":
+    "Rules checked against every tool call before it runs, after plugin before_tool_call hooks. A rule matches tools by name/glob/group, reads one argument (param), and rejects the call on match/oneOf, or rejects (or clamps) numbers outside min/max. The rule message is returned to the model.",
+  "tools.guardrails[].name": "Rule label shown in logs and in the explanation sent to the model.",
+  "tools.guardrails[].tools":
+    'Tool names, globs, or groups the rule applies to (for example ["exec"] or ["gpio_*"]).',
+  "tools.guardrails[].param":
+    'Dotted path of the argument to check (for example "pin" or "options.duty"). Omit to match the regex against all arguments as JSON.',
+  "tools.guardrails[].match":
+    "Case-insensitive regular expression; the call is rejected when the argument matches (for example a banned shell command).",
+  "tools.guardrails[].oneOf":
+    "Forbidden values; the call is rejected when the argument equals one of them.",
+  "tools.guardrails[].min": "Numeric lower bound for the argument.",
+  "tools.guardrails[].max": "Numeric upper bound for the argument.",
+  "tools.guardrails[].clamp":
+    "When true, numbers outside min/max are rewritten to the nearest bound and the call runs; the model is told about the change.",
+  "tools.guardrails[].message":
+    "Explanation returned to the model when the rule rejects or rewrites a call.",
+  "agents.list[].tools.guardrails":
+    "Per-agent override for tools.guardrails (replaces the global rules).",
   "tools.loopDetection.enabled":
     "Enable repetitive tool-call loop detection and backoff safety checks (default: false).",
   "tools.loopDetection.historySize": "Tool history window size for loop detection (default: 30).",
@@ -649,6 +701,8 @@ export const FIELD_HELP: Record<string, string> = {
   "agents.defaults.envelopeTimestamp":
     'Include absolute timestamps in message envelopes ("on" or "off").',
   "agents.defaults.envelopeElapsed": 'Include elapsed time in message envelopes ("on" or "off").',
+  "agents.defaults.toolProgressIntervalSeconds":
+    "Min seconds between progress messages that long-running tools report to chat channels; the first waits this long after the tool starts (0 disables, default: 10).",
   "agents.defaults.models": "Configured model catalog (keys are full provider/model IDs).",
   "agents.defaults.memorySearch":
     "Vector search over MEMORY.md and memory/*.md (per-agent overrides supported).",
@@ -661,7 +715,7 @@ export const FIELD_HELP: Record<string, string> = {
   "agents.defaults.memorySearch.experimental.sessionMemory":
     "Indexes session transcripts into memory search so responses can reference prior chat turns. Keep this off unless transcript recall is needed, because indexing cost and storage usage both increase.",
   "agents.defaults.memorySearch.provider":
-    'Selects the embedding backend used to build/query memory vectors: "openai", "gemini", "voyage", "mistral", or "local". Keep your most reliable provider here and configure fallback for resilience.',
+    'Selects the embedding backend used to build/query memory vectors: "openai", "gemini", "voyage", "mistral", "ollama", or "local". Keep your most reliable provider here and configure fallback for resilience.',
   "agents.defaults.memorySearch.model":
     "Embedding model override used by the selected memory provider when a non-default model is required. Set this only when you need explicit recall quality/cost tuning beyond provider defaults.",
   "agents.defaults.memorySearch.remote.baseUrl":
@@ -683,7 +737,7 @@ export const FIELD_HELP: Record<string, string> = {
   "agents.defaults.memorySearch.local.modelPath":
     "Specifies the local embedding model source for local memory search, such as a GGUF file path or `hf:` URI. Use this only when provider is `local`, and verify model compatibility before large index rebuilds.",
   "agents.defaults.memorySearch.fallback":
-    'Backup provider used when primary embeddings fail: "openai", "gemini", "voyage", "mistral", "local", or "none". Set a real fallback for production reliability; use "none" only if you prefer explicit failures.',
+    'Backup provider used when primary embeddings fail: "openai", "gemini", "voyage", "mistral", "ollama", "local", or "none". Set a real fallback for production reliability; use "none" only if you prefer explicit failures.',
   "agents.defaults.memorySearch.store.path":
     "Sets where the SQLite memory index is stored on disk for each agent. Keep the default `~/.openclaw/memory/{agentId}.sqlite` unless you need custom storage placement or backup policy alignment.",
   "agents.defaults.memorySearch.store.vector.enabled":
@@ -852,6 +906,17 @@ export const FIELD_HELP: Record<string, string> = {
   "agents.defaults.imageModel.primary":
     "Optional image model (provider/model) used when the primary model lacks image input.",
   "agents.defaults.imageModel.fallbacks": "Ordered fallback image models (provider/model).",
+  "agents.defaults.modelRouting":
+    "Per-task model routing: pick a different model for vision turns or cron runs without changing the session model.",
+  "agents.defaults.modelRouting.vision":
+    "Model (provider/model or alias) used for a turn with image attachments when the selected model has no image input.",
+  "agents.defaults.modelRouting.cron":
+    "Default model (provider/model or alias) for isolated cron runs that do not set a job-level model.",
+  "agents.defaults.taskParams":
+    "Generation params (temperature, maxTokens, topP, stop) per kind of run, layered over the model's params.",
+  "agents.defaults.taskParams.chat": "Generation params for interactive chat turns.",
+  "agents.defaults.taskParams.cron": "Generation params for isolated cron runs.",
+  "agents.defaults.taskParams.subagent": "Generation params for sub-agent runs.",
   "agents.defaults.imageMaxDimensionPx":
     "Max image side length in pixels when sanitizing transcript/tool-result image payloads (default: 1200).",
   "agents.defaults.cliBackends": "Optional CLI backends for text-only fallback (claude-cli, etc.).",
@@ -877,9 +942,26 @@ export const FIELD_HELP: Record<string, string> = {
     "User-prompt template used for the pre-compaction memory flush turn when generating memory candidates. Use this only when you need custom extraction instructions beyond the default memory flush behavior.",
   "agents.defaults.compaction.memoryFlush.systemPrompt":
     "System-prompt override for the pre-compaction memory flush turn to control extraction style and safety constraints. Use carefully so custom instructions do not reduce memory quality or leak sensitive context.",
+  "agents.defaults.compaction.summaryToMemory":
+    "When true, each compaction summary is appended to the workspace memory/YYYY-MM-DD.md file so older turns dropped from context stay reachable through memory_search. Leave off if you do not want transcript summaries persisted to disk.",
   "agents.defaults.humanDelay.mode": 'Delay style for block replies ("off", "natural", "custom").',
   "agents.defaults.humanDelay.minMs": "Minimum delay in ms for custom humanDelay (default: 800).",
   "agents.defaults.humanDelay.maxMs": "Maximum delay in ms for custom humanDelay (default: 2500).",
+  "agents.defaults.planning":
+    "Planner/executor mode: a planner model writes a step plan for each user turn, the plan is shown, and the agent carries it out with tools. High-impact plans (firmware flashing, shell commands) wait for the user to approve.",
+  "agents.defaults.planning.enabled": "Plan each user turn before running tools (default: false).",
+  "agents.defaults.planning.model":
+    "Planner model as provider/model or alias (default: the agent's primary model). A stronger model can plan while a cheaper one executes.",
+  "agents.defaults.planning.approval":
+    'When a plan needs approval: "high-impact" (default), "always", or "off" (plans run right away).',
+  "agents.defaults.planning.highImpactTools":
+    "Tool names or groups that make a plan high-impact (default: exec, process, gateway, nodes).",
+  "agents.defaults.planning.showPlan":
+    "Show plans that run without approval above the reply (default: true).",
+  "agents.defaults.planning.timeoutSeconds":
+    "Planner request timeout; on timeout the turn runs without a plan (default: 60).",
+  "agents.defaults.planning.maxReflections":
+    "Retries after a planned run ends with a failed tool call. The failure is fed back to the agent to reflect and try again (default: 1, 0 disables, max 5).",
   commands:
     "Controls chat command surfaces, owner gating, and elevated command access behavior across providers. Keep defaults unless you need stricter operator controls or broader command availability.",
   "commands.native":
@@ -894,6 +976,8 @@ export const FIELD_HELP: Record<string, string> = {
     "How long bash waits before backgrounding (default: 2000; 0 backgrounds immediately).",
   "commands.config": "Allow /config chat command to read/write config on disk (default: false).",
   "commands.debug": "Allow /debug chat command for runtime-only overrides (default: false).",
+  "commands.camera":
+    "Allow /snap chat command to capture a photo on a paired node camera and send it back (default: false).",
   "commands.restart": "Allow /restart and gateway restart tool actions (default: true).",
   "commands.useAccessGroups": "Enforce access-group allowlists/policies for commands.",
   "commands.ownerAllowFrom":
@@ -912,6 +996,19 @@ export const FIELD_HELP: Record<string, string> = {
     'DM session scoping: "main" keeps continuity, while "per-peer", "per-channel-peer", and "per-account-channel-peer" increase isolation. Use isolated modes for shared inboxes or multi-account deployments.',
   "session.identityLinks":
     "Maps canonical identities to provider-prefixed peer IDs so equivalent users resolve to one DM thread (example: telegram:123456). Use this when the same human appears across multiple channels or accounts.",
+  "session.userProfiles":
+    "Per-person personalization keyed by canonical user id. When the sender matches a profile, its name and preferences are added to the system prompt for that turn, so each household member gets tailored replies.",
+  "session.userProfiles.*.name": "Name the agent uses for this person.",
+  "session.userProfiles.*.identities":
+    "Provider-prefixed sender ids for this person (example: telegram:123456). Entries in session.identityLinks under the same key match too.",
+  "session.userProfiles.*.units": 'Preferred measurement units: "metric" or "imperial".',
+  "session.userProfiles.*.verbosity": 'Reply length: "brief", "normal", or "detailed".',
+  "session.userProfiles.*.language": "Language to reply in (example: de or Spanish).",
+  "session.userProfiles.*.timezone":
+    "IANA timezone for this person (example: Europe/Berlin). remind_me resolves times like 9am against it; default: agents.defaults.userTimezone.",
+  "session.userProfiles.*.notifications":
+    "Free-form notification preferences (example: only urgent alerts after 22:00, prefer Telegram).",
+  "session.userProfiles.*.notes": "Other context the agent should keep in mind for this person.",
   "session.resetTriggers":
     "Lists message triggers that force a session reset when matched in inbound content. Use sparingly for explicit reset phrases so context is not dropped unexpectedly during normal conversation.",
   "session.idleMinutes":
@@ -997,6 +1094,117 @@ export const FIELD_HELP: Record<string, string> = {
     "Bearer token attached to cron webhook POST deliveries when webhook mode is used. Prefer secret/env substitution and rotate this token regularly if shared webhook endpoints are internet-reachable.",
   "cron.sessionRetention":
     "Controls how long completed cron run sessions are kept before pruning (`24h`, `7d`, `1h30m`, or `false` to disable pruning; default: `24h`). Use shorter retention to reduce storage growth on high-frequency schedules.",
+  "cron.jobs":
+    "Jobs declared in config, using the same shape as `cron.add` params (`name`, `schedule`, `payload`, `sessionTarget`, `delivery`). They are synced into the store on gateway start: config wins over runtime edits, and jobs removed from this list are deleted.",
+  cost: "Cost reporting settings for usage summaries, reports, and budget tooling. Stored costs always stay in USD; these settings only change how totals are presented and enforced.",
+  "cost.currency":
+    "Display currency used when formatting cost summaries (`/usage cost`, `openclaw cost report`, `gateway usage-cost`). Keep unset to show USD.",
+  "cost.currency.code":
+    "ISO 4217 currency code to display (for example `EUR` or `GBP`). Default: `USD`; non-USD codes require `cost.currency.usdRate`.",
+  "cost.currency.usdRate":
+    "Static exchange rate in units of the display currency per 1 USD (for example `0.92` for EUR). Update it periodically; stored records are never rewritten.",
+  "cost.budget":
+    "Spend limits checked before each agent run using a local token estimate and the model pricing table. Leave unset to disable budget checks.",
+  "cost.budget.dailyUsd":
+    "Maximum USD spend per local calendar day across all agents. Requests whose estimated cost would exceed the remainder trigger `cost.budget.action`.",
+  "cost.budget.monthlyUsd":
+    "Maximum USD spend per local calendar month across all agents. Combine with `dailyUsd` to cap both bursts and monthly totals.",
+  "cost.budget.action":
+    'Budget enforcement mode: "warn" (default) still sends the request and prepends a notice, while "refuse" skips the model call and replies with the budget status.',
+  "cost.budget.softLimitPercent":
+    "Soft limit as a percent of each budget limit (for example 80). Past it, requests still run but replies are marked with a budget notice; leave unset to only react at the hard limit.",
+  "cost.budget.gracePercent":
+    "Grace margin over the hard limit, in percent, for conversations active in the last 30 minutes (default: 0). Lets an ongoing exchange finish instead of being cut off at exactly 100%; new conversations still hit `cost.budget.action` at the limit.",
+  "cost.budget.estimateOutputTokens":
+    "Output tokens assumed when estimating a pending request before it is sent (default: 1024). Raise it for agents that routinely produce long replies.",
+  "cost.budget.fallbackModel":
+    'Zero/low-cost model used instead of the selected model when the budget check fails (provider/model or alias, for example "ollama/llama3.2"). Overrides `cost.budget.action`; replies are prefixed with a degraded-mode notice.',
+  "cost.retention":
+    "Summary policy for old usage records. Compaction only adds daily summary rows; it does not delete transcripts or bound disk use (use session.maintenance for that). Leave unset to read raw per-message usage for every day.",
+  "cost.retention.rawDays":
+    "Days of per-message usage read raw before older days are folded into daily summaries. Transcripts are never deleted by cost compaction (see session.maintenance). Reports, budgets, and dashboards read summaries for older days automatically.",
+  "cost.modelAliases":
+    'Canonical model names for cost stats, keyed by "provider/model" or model id (for example `{ "azure/prod-gpt": "gpt-5.2" }`). Use this when the same logical model is reached through OpenRouter, Azure, and the native API so `openclaw cost report --by canonical` aggregates it under one name.',
+  "cost.webhook":
+    "Outbound webhook for cost events so ntfy, Slack incoming webhooks, or billing tools can react without polling. Fires `budget.threshold` when spend crosses a configured percent of `cost.budget` and `cost.daily_summary` once per day.",
+  "cost.webhook.url":
+    "http(s) endpoint that receives cost events as JSON POSTs. Private and loopback hosts are blocked by the SSRF guard, the same as cron webhooks.",
+  "cost.webhook.secret":
+    "Shared secret used to sign payloads: `X-OpenClaw-Signature: sha256=<hex>` is HMAC-SHA256 over `<X-OpenClaw-Timestamp>.<body>`. Use env substitution and verify the signature and timestamp on the receiver.",
+  "cost.webhook.thresholds":
+    "Percent-of-budget thresholds that fire `budget.threshold` events, each at most once per day or month (default: [50, 80, 100]). Values above 100 report overspend.",
+  "cost.webhook.dailySummary":
+    "Send the previous day's totals and top models/channels once per day, on gateway start or the first agent run after midnight (default: true).",
+  "cost.webhook.timeoutSeconds":
+    "Request timeout in seconds for cost webhook deliveries (default: 10).",
+  "cost.reconcile":
+    "Scheduled comparison of the tracker's own cost estimates against provider billing APIs (OpenAI organization Costs API, Anthropic Admin API cost report). The Gateway stores the latest report in `cost/reconciliation.json` and logs providers whose drift exceeds `driftPercent`.",
+  "cost.reconcile.openaiAdminKey":
+    "OpenAI admin API key used to read organization costs (falls back to OPENAI_ADMIN_KEY). Regular project keys cannot read billing; keep this in env substitution.",
+  "cost.reconcile.anthropicAdminKey":
+    "Anthropic Admin API key (sk-ant-admin...) used to read the organization cost report (falls back to ANTHROPIC_ADMIN_KEY). Keep this secret in env substitution and rotate it like other credentials.",
+  "cost.reconcile.intervalHours":
+    "Hours between scheduled reconciliations while the Gateway runs (default: 24). Billing APIs lag by hours, so shorter intervals rarely add information.",
+  "cost.reconcile.lookbackDays":
+    "Full UTC days compared on each run, ending at the last UTC midnight (default: 7).",
+  "cost.reconcile.driftPercent":
+    "Drift between tracked and billed cost, as a percent of billed cost, that flags a provider in the report and Gateway log (default: 10).",
+  "cost.digest":
+    "Scheduled cost digest the Gateway delivers to a chat channel: totals, tokens, top models, and budget status. Delivered periods are tracked in `cost/digest-state.json`, so restarts do not resend.",
+  "cost.digest.schedule":
+    'Digest cadence: "daily" covers yesterday, "weekly" covers the previous seven days and is sent on Mondays (default: "daily").',
+  "cost.digest.time":
+    'Local send time in "HH:MM" 24h format (default: "09:00"). A Gateway started after this time sends the pending digest right away.',
+  "cost.digest.channel":
+    'Channel used to deliver the digest (for example "telegram", "slack", or "discord").',
+  "cost.digest.to":
+    "Recipient on the digest channel, such as a chat id, channel id, or phone number.",
+  "cost.digest.accountId":
+    "Channel account id to send from when the channel has multiple accounts.",
+  watchdog:
+    "Sensor threshold watchdog run by the Gateway. Each watch polls a reading (hwmon, IIO ADC channel, I2C register, or numeric file) and, when it crosses a threshold, wakes the agent with the reading so it can act (for example switch off a relay) and/or alert you.",
+  "watchdog.enabled":
+    "Runs the watchdog when true (default: true whenever `watchdog.watches` is non-empty). Set false to pause all watches without deleting them.",
+  "watchdog.intervalSeconds":
+    "Seconds between readings for every watch (default: 30). Shorter intervals react faster but spend more I2C/sysfs reads on small boards.",
+  "watchdog.agentId":
+    "Agent that handles breach events (default: the default agent). Use a dedicated ops agent when the main agent should not control hardware.",
+  "watchdog.sessionKey":
+    "Session that receives breach events (default: the agent's main session). The agent replies on that session's last route.",
+  "watchdog.watches":
+    "Watched readings with thresholds. Breaches are edge-triggered: a watch fires once when it crosses `above`/`below` and re-arms only after moving back past the threshold by `hysteresis`.",
+  "watchdog.watches[].name":
+    "Unique watch name shown in logs and in the event the agent receives (for example `cpu-temp`).",
+  "watchdog.watches[].source":
+    'Where to read the value: `{ kind: "hwmon", chip, input }` or `{ kind: "hwmon", path }`, `{ kind: "adc", device, channel }` (Linux IIO), `{ kind: "i2c", bus, address, register, mode }` (needs `i2cget` from i2c-tools), or `{ kind: "file", path }`.',
+  "watchdog.watches[].scale":
+    "Multiplier applied to the raw reading. hwmon defaults convert to base units (temp/in/curr 0.001, power 0.000001); other sources default to 1.",
+  "watchdog.watches[].offset":
+    "Value added after scaling, for calibration offsets (default: 0).",
+  "watchdog.watches[].unit":
+    'Unit shown to the agent next to the value (for example "°C" or "V").',
+  "watchdog.watches[].above":
+    "Breach when the scaled value rises above this threshold.",
+  "watchdog.watches[].below":
+    "Breach when the scaled value drops below this threshold.",
+  "watchdog.watches[].hysteresis":
+    "How far back inside the threshold the value must move before the breach clears (default: 0). Prevents a reading hovering at the limit from waking the agent repeatedly.",
+  "watchdog.watches[].cooldownSeconds":
+    "Minimum seconds between escalations for this watch, even if it clears and breaches again (default: 300).",
+  "watchdog.watches[].prompt":
+    'Instructions included with the breach, for example "Turn off the heater relay on GPIO 17, then tell me." Without it the agent decides whether to act or alert.',
+  secrets:
+    "Named secrets that config strings reference as `${secret:NAME}` instead of storing API keys and tokens inline. Values come from env vars, a permissions-checked secrets file, or the OS keyring; manage them with `openclaw secrets`.",
+  "secrets.sources":
+    'Sources searched for each `${secret:NAME}`, in order (default: ["env", "file", "keyring"]). `env` reads the env var named NAME; drop a source to stop it from supplying secrets.',
+  "secrets.file":
+    "Secrets file path (default: ~/.openclaw/secrets.json), a JSON object of name → value. Config load fails if the file is readable by group or others, so keep it at mode 600.",
+  "secrets.keyringService":
+    'OS keyring service the secrets are stored under (default: "openclaw"). Uses the macOS login Keychain or the Linux Secret Service (`secret-tool`, from libsecret).',
+  profiles:
+    "Named config overlays (for example `dev`, `uno-q`, `raspi-garage`) applied on top of this base config when OpenClaw runs with `--profile <name>`. Objects merge, arrays and scalars replace, and `null` removes a key, so one config repo can drive several differently equipped boards.",
+  "profiles.*.extends":
+    "Another profile to apply first, so related boards can share an intermediate layer (for example `raspi-garage` extends `raspi`). Unknown names and cycles fail config load.",
   hooks:
     "Inbound webhook automation surface for mapping external events into wake or agent actions in OpenClaw. Keep this locked down with explicit token/session/agent controls before exposing it beyond trusted networks.",
   "hooks.enabled":
@@ -1135,6 +1343,8 @@ export const FIELD_HELP: Record<string, string> = {
     "Group-message handling controls including mention triggers and history window sizing. Keep mention patterns narrow so group channels do not trigger on every message.",
   "messages.groupChat.mentionPatterns":
     "Regex-like patterns used to detect explicit mentions/trigger phrases in group chats. Use precise patterns to reduce false positives in high-volume channels.",
+  "messages.groupChat.triggerPrefixes":
+    "Literal prefixes (for example `!bot`) that trigger a reply in group chats when a message starts with them, like a mention. The prefix is stripped before the agent sees the message.",
   "messages.groupChat.historyLimit":
     "Maximum number of prior group messages loaded as context per turn for group sessions. Use higher values for richer continuity, or lower values for faster and cheaper responses.",
   "messages.queue":
@@ -1155,6 +1365,13 @@ export const FIELD_HELP: Record<string, string> = {
     "Direct inbound debounce settings used before queue/turn processing starts. Configure this for provider-specific rapid message bursts from the same sender.",
   "messages.inbound.byChannel":
     "Per-channel inbound debounce overrides keyed by provider id in milliseconds. Use this where some providers send message fragments more aggressively than others.",
+  "messages.toolSteps":
+    'Posts one line per finished tool call to the chat while the agent works, for example "→ adc_read(ch=2) = 512". Skipped when /verbose already shows tool activity.',
+  "messages.toolSteps.enabled": "Enable tool step lines on all channels (default: false).",
+  "messages.toolSteps.byChannel":
+    "Per-channel on/off overrides keyed by provider id, for example turn steps on for a debug Telegram bot but off for Discord.",
+  "messages.toolSteps.maxResultChars":
+    "Maximum characters of each tool result shown on its step line; 0 shows only the call (default: 120).",
   "messages.removeAckAfterReply":
     "Removes the acknowledgment reaction after final reply delivery when enabled. Keep enabled for cleaner UX in channels where persistent ack reactions create clutter.",
   "messages.tts":
@@ -1223,6 +1440,8 @@ export const FIELD_HELP: Record<string, string> = {
     "Allow Signal to write config in response to channel events/commands (default: true).",
   "channels.signal.account":
     "Signal account identifier (phone/number handle) used to bind this channel config to a specific Signal identity. Keep this aligned with your linked device/session state.",
+  "channels.signal.socketPath":
+    "Unix socket path for the signal-cli JSON-RPC daemon (signal-cli daemon --socket). When set, OpenClaw talks JSON-RPC over the socket instead of HTTP, and an auto-started daemon binds this socket.",
   "channels.imessage.configWrites":
     "Allow iMessage to write config in response to channel events/commands (default: true).",
   "channels.imessage.cliPath":
@@ -1247,6 +1466,8 @@ export const FIELD_HELP: Record<string, string> = {
     "Enable native Slack text streaming (chat.startStream/chat.appendStream/chat.stopStream) when channels.slack.streaming is partial (default: true).",
   "channels.slack.streamMode":
     "Legacy Slack preview mode alias (replace | status_final | append); auto-migrated to channels.slack.streaming.",
+  "channels.slack.tableBlocks":
+    "Send replies containing two-column Markdown tables (status or sensor readouts) as Block Kit field grids instead of code-block tables (default: false).",
   "channels.telegram.customCommands":
     "Additional Telegram bot menu commands (merged with native; conflicts ignored).",
   "messages.suppressToolErrors":
@@ -1262,6 +1483,45 @@ export const FIELD_HELP: Record<string, string> = {
     "Override default status reaction emojis. Keys: thinking, tool, coding, web, done, error, stallSoft, stallHard. Must be valid Telegram reaction emojis.",
   "messages.statusReactions.timing":
     "Override default timing. Keys: debounceMs (700), stallSoftMs (25000), stallHardMs (60000), doneHoldMs (1500), errorHoldMs (2500).",
+  "messages.alerts":
+    "Broadcast routing for alert-class messages such as budget threshold crossings and billing drift. Conversation replies still return to the originating channel; alerts go to every configured target.",
+  "messages.alerts.targets":
+    "Channels that receive alerts, each with channel, destination, and optional kinds. Keep the list to channels operators actually watch so alerts are not missed or duplicated into noisy rooms.",
+  "messages.alerts.targets[].channel":
+    "Channel/provider ID used for alert delivery, such as telegram, slack, or a plugin channel id. Unknown or non-deliverable channels are skipped.",
+  "messages.alerts.targets[].to":
+    "Destination identifier inside the target channel (chat ID, channel ID, phone number, depending on provider).",
+  "messages.alerts.targets[].accountId":
+    "Optional account selector for multi-account channel setups when alerts must be sent from a specific account.",
+  "messages.alerts.targets[].threadId":
+    "Optional thread/topic target for channels that support threaded delivery, to keep alerts in an operations thread.",
+  "messages.alerts.targets[].kinds":
+    'Alert kinds sent to this target: "budget" (cost.budget threshold crossings) and "reconcile" (cost.reconcile billing drift). Omit to receive all kinds.',
+  "messages.notifications":
+    "Delivery policy for proactive messages (heartbeat and cron output, alerts, digests): quiet hours, a severity floor, and digest batching. Replies to a message the user just sent are never held.",
+  "messages.notifications.quietHours":
+    'Local-time window (start/end "HH:MM", optional timezone "user", "local", or IANA id) in which proactive messages below quietHoursBypass are held.',
+  "messages.notifications.quietHours.start": 'Quiet hours start (24h "HH:MM", inclusive).',
+  "messages.notifications.quietHours.end":
+    'Quiet hours end (24h "HH:MM", exclusive; "24:00" for end of day). Windows may wrap midnight, e.g. 22:00 to 07:00.',
+  "messages.notifications.quietHours.timezone":
+    'Timezone for the quiet hours window: "user" (agents.defaults.userTimezone, default), "local" (gateway host), or an IANA id.',
+  "messages.notifications.minSeverity":
+    'Proactive messages below this severity are dropped at any hour: "info" (default, send everything), "warning", or "critical".',
+  "messages.notifications.quietHoursBypass":
+    'Lowest severity that is still delivered during quiet hours (default: "critical"). Set "warning" to also let warnings through.',
+  "messages.notifications.held":
+    'What happens to messages held by quiet hours: "digest" (default) batches them into one message once quiet hours end; "drop" discards them.',
+  "messages.notifications.byChannel":
+    "Per-channel policy overrides keyed by channel id (for example a stricter policy for a phone channel than for an ops Slack room).",
+  "messages.notifications.recipients":
+    "Per-recipient policy overrides matched by channel and destination (and accountId when set). The most specific matching entry wins over byChannel and the defaults.",
+  "messages.notifications.recipients[].channel":
+    "Channel/provider ID of the recipient, such as telegram, whatsapp, or a plugin channel id.",
+  "messages.notifications.recipients[].to":
+    "Destination identifier inside the channel (chat ID, channel ID, phone number, depending on provider).",
+  "messages.notifications.recipients[].accountId":
+    "Optional account selector; when set, the entry only matches deliveries from that account.",
   "messages.inbound.debounceMs":
     "Debounce window (ms) for batching rapid inbound messages from the same sender (0 to disable).",
   "channels.telegram.dmPolicy":
@@ -7,6 +7,8 @@ export const IRC_FIELD_LABELS: Record<string, string> = {
   "channels.irc.nickserv.passwordFile": "IRC NickServ Password File",
   "channels.irc.nickserv.register": "IRC NickServ Register",
   "channels.irc.nickserv.registerEmail": "IRC NickServ Register Email",
+  "channels.irc.nickserv.sasl": "IRC SASL Login",
+  "channels.irc.nickserv.account": "IRC SASL Account",
 };
 
 export const IRC_FIELD_HELP: Record<string, string> = {
@@ -23,4 +25,7 @@ export const IRC_FIELD_HELP: Record<string, string> = {
     "If true, send NickServ REGISTER on every connect. Use once for initial registration, then disable.",
   "channels.irc.nickserv.registerEmail":
     "Email used with NickServ REGISTER (required when register=true).",
+  "channels.irc.nickserv.sasl":
+    "Log in with SASL PLAIN during connect, using the NickServ password, instead of IDENTIFY after connect. The connection fails if the server rejects SASL.",
+  "channels.irc.nickserv.account": "Services account name for SASL login (default: nick).",
 };
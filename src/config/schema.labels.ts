@@ -154,6 +154,32 @@ export const FIELD_LABELS: Record<string, string> = {
   "tools.exec.applyPatch.enabled": "Enable apply_patch",
   "tools.exec.applyPatch.workspaceOnly": "apply_patch Workspace-Only",
   "tools.exec.applyPatch.allowModels": "apply_patch Model Allowlist",
+  "tools.maxCallsPerRun": "Max Tool Calls per Run",
+  "agents.list[].tools.maxCallsPerRun": "Agent Max Tool Calls per Run",
+  "tools.guardrails": "Tool Guardrails",
+  "tools.guardrails[].name": "Tool Guardrail Name",
+  "tools.guardrails[].tools": "Tool Guardrail Tools",
+  "tools.guardrails[].param": "Tool Guardrail Param",
+  "tools.guardrails[].match": "Tool Guardrail Match",
+  "tools.guardrails[].oneOf": "Tool Guardrail Forbidden Values",
+  "tools.guardrails[].min": "Tool Guardrail Min",
+  "tools.guardrails[].max": "Tool Guardrail Max",
+  "tools.guardrails[].clamp": "Tool Guardrail Clamp",
+  "tools.guardrails[].message": "Tool Guardrail Message",
+  "agents.list[].tools.guardrails": "Agent Tool Guardrails",
+  "tools.autonomy": "Tool Autonomy",
+  "tools.autonomy.mode": "Tool Autonomy Mode",
+  "tools.autonomy.actionTools": "Tool Autonomy Action Tools",
+  "tools.autonomy.readOnlyTools": "Tool Autonomy Read-Only Tools",
+  "tools.parallel": "Parallel Tool Calls",
+  "tools.parallel.enabled": "Parallel Tool Calls Enabled",
+  "tools.parallel.maxConcurrency": "Parallel Tool Calls Max Concurrency",
+  "tools.parallel.tools": "Parallel-Safe Tools",
+  "tools.parallel.exclusive": "Exclusive Tools",
+  "agents.list[].tools.autonomy": "Agent Tool Autonomy",
+  "agents.list[].tools.autonomy.mode": "Agent Tool Autonomy Mode",
+  "agents.list[].tools.autonomy.actionTools": "Agent Tool Autonomy Action Tools",
+  "agents.list[].tools.autonomy.readOnlyTools": "Agent Tool Autonomy Read-Only Tools",
   "tools.loopDetection.enabled": "Tool-loop Detection",
   "tools.loopDetection.historySize": "Tool-loop History Size",
   "tools.loopDetection.warningThreshold": "Tool-loop Warning Threshold",
@@ -232,6 +258,7 @@ export const FIELD_LABELS: Record<string, string> = {
   "gateway.controlUi.allowInsecureAuth": "Insecure Control UI Auth Toggle",
   "gateway.controlUi.dangerouslyDisableDeviceAuth": "Dangerously Disable Control UI Device Auth",
   "gateway.http.endpoints.chatCompletions.enabled": "OpenAI Chat Completions Endpoint",
+  "gateway.http.endpoints.chat.enabled": "Chat API Endpoint",
   "gateway.reload.mode": "Config Reload Mode",
   "gateway.reload.debounceMs": "Config Reload Debounce (ms)",
   "gateway.nodes.browser.mode": "Gateway Node Browser Mode",
@@ -271,6 +298,7 @@ export const FIELD_LABELS: Record<string, string> = {
   "agents.defaults.envelopeTimezone": "Envelope Timezone",
   "agents.defaults.envelopeTimestamp": "Envelope Timestamp",
   "agents.defaults.envelopeElapsed": "Envelope Elapsed",
+  "agents.defaults.toolProgressIntervalSeconds": "Tool Progress Interval (seconds)",
   "agents.defaults.memorySearch": "Memory Search",
   "agents.defaults.memorySearch.enabled": "Enable Memory Search",
   "agents.defaults.memorySearch.sources": "Memory Search Sources",
@@ -374,10 +402,25 @@ export const FIELD_LABELS: Record<string, string> = {
   "agents.defaults.model.fallbacks": "Model Fallbacks",
   "agents.defaults.imageModel.primary": "Image Model",
   "agents.defaults.imageModel.fallbacks": "Image Model Fallbacks",
+  "agents.defaults.modelRouting": "Model Routing",
+  "agents.defaults.modelRouting.vision": "Vision Turn Model",
+  "agents.defaults.modelRouting.cron": "Cron Run Model",
+  "agents.defaults.taskParams": "Task Generation Params",
+  "agents.defaults.taskParams.chat": "Chat Generation Params",
+  "agents.defaults.taskParams.cron": "Cron Generation Params",
+  "agents.defaults.taskParams.subagent": "Sub-agent Generation Params",
   "agents.defaults.imageMaxDimensionPx": "Image Max Dimension (px)",
   "agents.defaults.humanDelay.mode": "Human Delay Mode",
   "agents.defaults.humanDelay.minMs": "Human Delay Min (ms)",
   "agents.defaults.humanDelay.maxMs": "Human Delay Max (ms)",
+  "agents.defaults.planning": "Planning",
+  "agents.defaults.planning.enabled": "Planning Enabled",
+  "agents.defaults.planning.model": "Planner Model",
+  "agents.defaults.planning.approval": "Plan Approval",
+  "agents.defaults.planning.highImpactTools": "High-Impact Tools",
+  "agents.defaults.planning.showPlan": "Show Plan",
+  "agents.defaults.planning.timeoutSeconds": "Planner Timeout (s)",
+  "agents.defaults.planning.maxReflections": "Max Reflection Retries",
   "agents.defaults.cliBackends": "CLI Backends",
   "agents.defaults.compaction": "Compaction",
   "agents.defaults.compaction.mode": "Compaction Mode",
@@ -391,6 +434,8 @@ export const FIELD_LABELS: Record<string, string> = {
     "Compaction Memory Flush Soft Threshold",
   "agents.defaults.compaction.memoryFlush.prompt": "Compaction Memory Flush Prompt",
   "agents.defaults.compaction.memoryFlush.systemPrompt": "Compaction Memory Flush System Prompt",
+  "agents.defaults.compaction.summaryToMemory": "Compaction Summary to Memory",
+  "agents.defaults.heartbeat.checklist": "Heartbeat Checklist",
   "agents.defaults.heartbeat.suppressToolErrorWarnings": "Heartbeat Suppress Tool Error Warnings",
   "agents.defaults.sandbox.browser.network": "Sandbox Browser Network",
   "agents.defaults.sandbox.browser.cdpSourceRange": "Sandbox Browser CDP Source Port Range",
@@ -402,6 +447,7 @@ export const FIELD_LABELS: Record<string, string> = {
   "commands.bashForegroundMs": "Bash Foreground Window (ms)",
   "commands.config": "Allow /config",
   "commands.debug": "Allow /debug",
+  "commands.camera": "Allow /snap",
   "commands.restart": "Allow Restart",
   "commands.useAccessGroups": "Use Access Groups",
   "commands.ownerAllowFrom": "Command Owners",
@@ -426,6 +472,15 @@ export const FIELD_LABELS: Record<string, string> = {
   "session.scope": "Session Scope",
   "session.dmScope": "DM Session Scope",
   "session.identityLinks": "Session Identity Links",
+  "session.userProfiles": "User Profiles",
+  "session.userProfiles.*.name": "User Name",
+  "session.userProfiles.*.identities": "User Identities",
+  "session.userProfiles.*.units": "Preferred Units",
+  "session.userProfiles.*.verbosity": "Reply Verbosity",
+  "session.userProfiles.*.language": "Preferred Language",
+  "session.userProfiles.*.timezone": "User Timezone",
+  "session.userProfiles.*.notifications": "Notification Preferences",
+  "session.userProfiles.*.notes": "User Notes",
   "session.resetTriggers": "Session Reset Triggers",
   "session.idleMinutes": "Session Idle Minutes",
   "session.reset": "Session Reset Policy",
@@ -469,6 +524,62 @@ export const FIELD_LABELS: Record<string, string> = {
   "cron.webhook": "Cron Legacy Webhook (Deprecated)",
   "cron.webhookToken": "Cron Webhook Bearer Token",
   "cron.sessionRetention": "Cron Session Retention",
+  "cron.jobs": "Cron Config Jobs",
+  cost: "Cost",
+  "cost.currency": "Cost Display Currency",
+  "cost.currency.code": "Cost Currency Code",
+  "cost.currency.usdRate": "Cost Currency Rate (per USD)",
+  "cost.budget": "Cost Budget",
+  "cost.budget.dailyUsd": "Daily Budget (USD)",
+  "cost.budget.monthlyUsd": "Monthly Budget (USD)",
+  "cost.budget.action": "Budget Action",
+  "cost.budget.softLimitPercent": "Budget Soft Limit (%)",
+  "cost.budget.gracePercent": "Budget Grace Margin (%)",
+  "cost.budget.estimateOutputTokens": "Budget Estimate Output Tokens",
+  "cost.budget.fallbackModel": "Budget Fallback Model",
+  "cost.retention": "Cost Retention",
+  "cost.retention.rawDays": "Raw Usage Window (days)",
+  "cost.modelAliases": "Cost Model Aliases",
+  "cost.webhook": "Cost Webhook",
+  "cost.webhook.url": "Cost Webhook URL",
+  "cost.webhook.secret": "Cost Webhook Signing Secret",
+  "cost.webhook.thresholds": "Cost Webhook Budget Thresholds (%)",
+  "cost.webhook.dailySummary": "Cost Webhook Daily Summary",
+  "cost.webhook.timeoutSeconds": "Cost Webhook Timeout (sec)",
+  "cost.reconcile": "Cost Reconciliation",
+  "cost.reconcile.openaiAdminKey": "OpenAI Admin Key (Billing)",
+  "cost.reconcile.anthropicAdminKey": "Anthropic Admin Key (Billing)",
+  "cost.reconcile.intervalHours": "Reconciliation Interval (hours)",
+  "cost.reconcile.lookbackDays": "Reconciliation Lookback (days)",
+  "cost.reconcile.driftPercent": "Reconciliation Drift Threshold (%)",
+  "cost.digest": "Cost Digest",
+  "cost.digest.schedule": "Cost Digest Schedule",
+  "cost.digest.time": "Cost Digest Time",
+  "cost.digest.channel": "Cost Digest Channel",
+  "cost.digest.to": "Cost Digest Recipient",
+  "cost.digest.accountId": "Cost Digest Account ID",
+  watchdog: "Watchdog",
+  "watchdog.enabled": "Watchdog Enabled",
+  "watchdog.intervalSeconds": "Watchdog Interval (sec)",
+  "watchdog.agentId": "Watchdog Agent ID",
+  "watchdog.sessionKey": "Watchdog Session Key",
+  "watchdog.watches": "Watchdog Watches",
+  "watchdog.watches[].name": "Watch Name",
+  "watchdog.watches[].source": "Watch Source",
+  "watchdog.watches[].scale": "Watch Scale",
+  "watchdog.watches[].offset": "Watch Offset",
+  "watchdog.watches[].unit": "Watch Unit",
+  "watchdog.watches[].above": "Watch Above Threshold",
+  "watchdog.watches[].below": "Watch Below Threshold",
+  "watchdog.watches[].hysteresis": "Watch Hysteresis",
+  "watchdog.watches[].cooldownSeconds": "Watch Cooldown (sec)",
+  "watchdog.watches[].prompt": "Watch Prompt",
+  secrets: "Secrets",
+  "secrets.sources": "Secret Sources",
+  "secrets.file": "Secrets File",
+  "secrets.keyringService": "Secrets Keyring Service",
+  profiles: "Config Profiles",
+  "profiles.*.extends": "Profile Extends",
   hooks: "Hooks",
   "hooks.enabled": "Hooks Enabled",
   "hooks.path": "Hooks Endpoint Path",
@@ -562,6 +673,7 @@ export const FIELD_LABELS: Record<string, string> = {
   "messages.responsePrefix": "Outbound Response Prefix",
   "messages.groupChat": "Group Chat Rules",
   "messages.groupChat.mentionPatterns": "Group Mention Patterns",
+  "messages.groupChat.triggerPrefixes": "Group Trigger Prefixes",
   "messages.groupChat.historyLimit": "Group History Limit",
   "messages.queue": "Inbound Queue",
   "messages.queue.mode": "Queue Mode",
@@ -572,6 +684,10 @@ export const FIELD_LABELS: Record<string, string> = {
   "messages.queue.drop": "Queue Drop Strategy",
   "messages.inbound": "Inbound Debounce",
   "messages.suppressToolErrors": "Suppress Tool Error Warnings",
+  "messages.toolSteps": "Tool Step Lines",
+  "messages.toolSteps.enabled": "Enable Tool Step Lines",
+  "messages.toolSteps.byChannel": "Tool Step Lines by Channel",
+  "messages.toolSteps.maxResultChars": "Tool Step Result Length",
   "messages.ackReaction": "Ack Reaction Emoji",
   "messages.ackReactionScope": "Ack Reaction Scope",
   "messages.removeAckAfterReply": "Remove Ack Reaction After Reply",
@@ -582,6 +698,26 @@ export const FIELD_LABELS: Record<string, string> = {
   "messages.inbound.debounceMs": "Inbound Message Debounce (ms)",
   "messages.inbound.byChannel": "Inbound Debounce by Channel (ms)",
   "messages.tts": "Message Text-to-Speech",
+  "messages.alerts": "Alert Broadcast",
+  "messages.alerts.targets": "Alert Targets",
+  "messages.alerts.targets[].channel": "Alert Target Channel",
+  "messages.alerts.targets[].to": "Alert Target Destination",
+  "messages.alerts.targets[].accountId": "Alert Target Account ID",
+  "messages.alerts.targets[].threadId": "Alert Target Thread ID",
+  "messages.alerts.targets[].kinds": "Alert Target Kinds",
+  "messages.notifications": "Notification Policy",
+  "messages.notifications.quietHours": "Quiet Hours",
+  "messages.notifications.quietHours.start": "Quiet Hours Start",
+  "messages.notifications.quietHours.end": "Quiet Hours End",
+  "messages.notifications.quietHours.timezone": "Quiet Hours Timezone",
+  "messages.notifications.minSeverity": "Minimum Notification Severity",
+  "messages.notifications.quietHoursBypass": "Quiet Hours Bypass Severity",
+  "messages.notifications.held": "Held Notification Handling",
+  "messages.notifications.byChannel": "Notification Policy by Channel",
+  "messages.notifications.recipients": "Notification Policy Recipients",
+  "messages.notifications.recipients[].channel": "Notification Recipient Channel",
+  "messages.notifications.recipients[].to": "Notification Recipient Destination",
+  "messages.notifications.recipients[].accountId": "Notification Recipient Account ID",
   "talk.apiKey": "Talk API Key",
   channels: "Channels",
   "channels.defaults": "Channel Defaults",
@@ -671,6 +807,7 @@ export const FIELD_LABELS: Record<string, string> = {
   "channels.slack.streaming": "Slack Streaming Mode",
   "channels.slack.nativeStreaming": "Slack Native Streaming",
   "channels.slack.streamMode": "Slack Stream Mode (Legacy)",
+  "channels.slack.tableBlocks": "Slack Table Blocks",
   "channels.slack.thread.historyScope": "Slack Thread History Scope",
   "channels.slack.thread.inheritParent": "Slack Thread Parent Inheritance",
   "channels.slack.thread.initialHistoryLimit": "Slack Thread Initial History Limit",
@@ -681,9 +818,11 @@ export const FIELD_LABELS: Record<string, string> = {
   "channels.mattermost.oncharPrefixes": "Mattermost Onchar Prefixes",
   "channels.mattermost.requireMention": "Mattermost Require Mention",
   "channels.signal.account": "Signal Account",
+  "channels.signal.socketPath": "Signal Socket Path",
   "channels.imessage.cliPath": "iMessage CLI Path",
   "agents.list[].skills": "Agent Skill Filter",
   "agents.list[].identity.avatar": "Agent Avatar",
+  "agents.list[].heartbeat.checklist": "Agent Heartbeat Checklist",
   "agents.list[].heartbeat.suppressToolErrorWarnings":
     "Agent Heartbeat Suppress Tool Error Warnings",
   "agents.list[].sandbox.browser.network": "Agent Sandbox Browser Network",
@@ -58,6 +58,7 @@ const PREFIX_RULES: Array<{ prefix: string; tags: ConfigTag[] }> = [
   { prefix: "diagnostics.", tags: ["observability"] },
   { prefix: "logging.", tags: ["observability"] },
   { prefix: "cron.", tags: ["automation"] },
+  { prefix: "cost.", tags: ["observability"] },
   { prefix: "talk.", tags: ["media"] },
   { prefix: "audio.", tags: ["media"] },
 ];
@@ -2,7 +2,7 @@ import fs from "node:fs";
 import fsPromises from "node:fs/promises";
 import os from "node:os";
 import path from "node:path";
-import { afterAll, afterEach, beforeAll, beforeEach, describe, expect, it } from "vitest";
+import { afterAll, afterEach, beforeAll, beforeEach, describe, expect, it, vi } from "vitest";
 import {
   clearSessionStoreCacheForTest,
   loadSessionStore,
@@ -121,6 +121,37 @@ describe("session store lock (Promise chain mutex)", () => {
     lockTmpDirs = [];
   });
 
+  it.runIf(process.platform !== "win32")(
+    "fsyncs the store and its directory before a save resolves",
+    async () => {
+      const { dir, storePath } = await makeTmpStore();
+      const synced: string[] = [];
+      const realOpen = fsPromises.open;
+      const openSpy = vi.spyOn(fsPromises, "open").mockImplementation(async (...args) => {
+        const handle = await realOpen(...args);
+        const realSync = handle.sync.bind(handle);
+        handle.sync = async () => {
+          synced.push(String(args[0]));
+          await realSync();
+        };
+        return handle;
+      });
+      try {
+        await updateSessionStore(storePath, (store) => {
+          store["agent:main:durable"] = { sessionId: "s1", updatedAt: 1 };
+        });
+      } finally {
+        openSpy.mockRestore();
+      }
+
+      expect(synced.some((file) => file.startsWith(`${storePath}.`) && file.endsWith(".tmp"))).toBe(
+        true,
+      );
+      expect(synced).toContain(dir);
+      expect(loadSessionStore(storePath, { skipCache: true })["agent:main:durable"]).toBeTruthy();
+    },
+  );
+
   it("serializes concurrent updateSessionStore calls without data loss", async () => {
     const key = "agent:main:test";
     const { storePath } = await makeTmpStore({
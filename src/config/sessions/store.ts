@@ -190,6 +190,33 @@ type LoadSessionStoreOptions = {
   skipCache?: boolean;
 };
 
+/**
+ * Write and fsync before the rename: without it, a power cut shortly after a save can leave
+ * a renamed but empty store, which loads as "no sessions" and drops every conversation.
+ */
+async function writeFileSynced(filePath: string, data: string): Promise<void> {
+  const handle = await fs.promises.open(filePath, "w", 0o600);
+  try {
+    await handle.writeFile(data, "utf-8");
+    await handle.sync();
+  } finally {
+    await handle.close();
+  }
+}
+
+/** Persist the rename itself; best-effort because some filesystems refuse directory fsync. */
+async function syncDirectory(dir: string): Promise<void> {
+  let handle: fs.promises.FileHandle | undefined;
+  try {
+    handle = await fs.promises.open(dir, "r");
+    await handle.sync();
+  } catch {
+    // ignore
+  } finally {
+    await handle?.close().catch(() => undefined);
+  }
+}
+
 export function loadSessionStore(
   storePath: string,
   opts: LoadSessionStoreOptions = {},
@@ -662,8 +689,9 @@ async function saveSessionStoreUnlocked(
 
   const tmp = `${storePath}.${process.pid}.${crypto.randomUUID()}.tmp`;
   try {
-    await fs.promises.writeFile(tmp, json, { mode: 0o600, encoding: "utf-8" });
+    await writeFileSynced(tmp, json);
     await fs.promises.rename(tmp, storePath);
+    await syncDirectory(path.dirname(storePath));
     // Ensure permissions are set even if rename loses them
     await fs.promises.chmod(storePath, 0o600);
   } catch (err) {
@@ -4,6 +4,7 @@ import type { ChatType } from "../../channels/chat-type.js";
 import type { ChannelId } from "../../channels/plugins/types.js";
 import type { DeliveryContext } from "../../utils/delivery-context.js";
 import type { TtsAutoMode } from "../types.tts.js";
+import type { ToolAutonomyMode } from "../types.tools.js";
 
 export type SessionScope = "per-sender" | "global";
 
@@ -33,12 +34,16 @@ export type SessionEntry = {
   sessionId: string;
   updatedAt: number;
   sessionFile?: string;
+  /** Session replaced by the last /new, /reset, or idle expiry (restored by /resume). */
+  previousSession?: { sessionId: string; sessionFile: string };
   /** Parent session key that spawned this session (used for sandbox session-tool scoping). */
   spawnedBy?: string;
   /** True after a thread/topic session has been forked from its parent transcript once. */
   forkedFromParent?: boolean;
   /** Subagent spawn depth (0 = main, 1 = sub-agent, 2 = sub-sub-agent). */
   spawnDepth?: number;
+  /** Tool allowlist set by the parent at spawn time (subagent sessions only). */
+  spawnTools?: string[];
   systemSent?: boolean;
   abortedLastRun?: boolean;
   chatType?: SessionChatType;
@@ -60,6 +65,14 @@ export type SessionEntry = {
   groupActivation?: "mention" | "always";
   groupActivationNeedsSystemIntro?: boolean;
   sendPolicy?: "allow" | "deny";
+  /** Session autonomy override for side-effecting tool calls (set with /autonomy). */
+  autonomy?: ToolAutonomyMode;
+  /** Plan waiting for the user's approval (planner/executor mode). */
+  pendingPlan?: {
+    prompt: string;
+    steps: Array<{ description: string; tools?: string[]; highImpact?: boolean }>;
+    createdAt: number;
+  };
   queueMode?:
     | "steer"
     | "followup"
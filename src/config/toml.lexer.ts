@@ -0,0 +1,234 @@
+import { isBlockedObjectKey } from "./prototype-keys.js";
+
+export type TomlTable = Record<string, unknown>;
+
+export class TomlParseError extends Error {
+  constructor(
+    message: string,
+    public readonly line: number,
+  ) {
+    super(`TOML parse failed at line ${line}: ${message}`);
+    this.name = "TomlParseError";
+  }
+}
+
+// Control characters other than tab must be escaped in strings (built from a string to
+// satisfy the no-control-regex lint rule).
+const CONTROL_CHAR_RE = new RegExp("[\\u0000-\\u0008\\u000a-\\u001f\\u007f]");
+const ESCAPES: Record<string, string> = {
+  b: "\b",
+  t: "\t",
+  n: "\n",
+  f: "\f",
+  r: "\r",
+  '"': '"',
+  "\\": "\\",
+};
+
+/** Cursor over the source: whitespace, comments, keys, and the four string forms. */
+export abstract class TomlLexer {
+  protected pos = 0;
+
+  constructor(protected readonly src: string) {}
+
+  protected fail(message: string): never {
+    const line = this.src.slice(0, this.pos).split("\n").length;
+    throw new TomlParseError(message, line);
+  }
+
+  protected peek(offset = 0): string {
+    return this.src[this.pos + offset] ?? "";
+  }
+
+  protected startsWith(token: string): boolean {
+    return this.src.startsWith(token, this.pos);
+  }
+
+  protected skipSpaces(): void {
+    while (this.peek() === " " || this.peek() === "\t") {
+      this.pos += 1;
+    }
+  }
+
+  protected skipComment(): void {
+    if (this.peek() === "#") {
+      while (this.pos < this.src.length && this.peek() !== "\n") {
+        this.pos += 1;
+      }
+    }
+  }
+
+  /** Skips whitespace, comments, and newlines. Returns false at end of input. */
+  protected skipBlankLines(): boolean {
+    for (;;) {
+      this.skipSpaces();
+      this.skipComment();
+      if (this.peek() === "\n" || this.startsWith("\r\n")) {
+        this.pos += this.peek() === "\n" ? 1 : 2;
+        continue;
+      }
+      return this.pos < this.src.length;
+    }
+  }
+
+  protected expectLineEnd(): void {
+    this.skipSpaces();
+    this.skipComment();
+    if (this.pos >= this.src.length || this.peek() === "\n" || this.startsWith("\r\n")) {
+      return;
+    }
+    this.fail(`unexpected "${this.peek()}"`);
+  }
+
+  protected readKey(): string[] {
+    const keys: string[] = [];
+    for (;;) {
+      this.skipSpaces();
+      let key: string;
+      if (this.peek() === '"') {
+        key = this.readBasicString();
+      } else if (this.peek() === "'") {
+        key = this.readLiteralString();
+      } else {
+        const start = this.pos;
+        while (/[A-Za-z0-9_-]/.test(this.peek())) {
+          this.pos += 1;
+        }
+        key = this.src.slice(start, this.pos);
+        if (!key) {
+          this.fail("expected a key");
+        }
+      }
+      if (isBlockedObjectKey(key)) {
+        this.fail(`key "${key}" is not allowed`);
+      }
+      keys.push(key);
+      this.skipSpaces();
+      if (this.peek() !== ".") {
+        return keys;
+      }
+      this.pos += 1;
+    }
+  }
+
+  protected readEscape(): string {
+    const code = this.peek();
+    this.pos += 1;
+    if (code in ESCAPES) {
+      return ESCAPES[code];
+    }
+    if (code === "u" || code === "U") {
+      const length = code === "u" ? 4 : 8;
+      const hex = this.src.slice(this.pos, this.pos + length);
+      if (!new RegExp(`^[0-9a-fA-F]{${length}}$`).test(hex)) {
+        this.fail("invalid unicode escape");
+      }
+      const codePoint = Number.parseInt(hex, 16);
+      if (codePoint > 0x10ffff || (codePoint >= 0xd800 && codePoint <= 0xdfff)) {
+        this.fail("invalid unicode escape");
+      }
+      this.pos += length;
+      return String.fromCodePoint(codePoint);
+    }
+    this.fail(`invalid escape "\\${code}"`);
+  }
+
+  protected readBasicString(): string {
+    this.pos += 1;
+    let out = "";
+    for (;;) {
+      const ch = this.peek();
+      if (ch === "" || ch === "\n") {
+        this.fail("unterminated string");
+      }
+      if (CONTROL_CHAR_RE.test(ch)) {
+        this.fail("control characters must be escaped in strings");
+      }
+      this.pos += 1;
+      if (ch === '"') {
+        return out;
+      }
+      out += ch === "\\" ? this.readEscape() : ch;
+    }
+  }
+
+  protected readLiteralString(): string {
+    const end = this.src.indexOf("'", this.pos + 1);
+    const newline = this.src.indexOf("\n", this.pos + 1);
+    if (end === -1 || (newline !== -1 && newline < end)) {
+      this.fail("unterminated string");
+    }
+    const value = this.src.slice(this.pos + 1, end);
+    if (CONTROL_CHAR_RE.test(value)) {
+      this.fail("control characters must be escaped in strings");
+    }
+    this.pos = end + 1;
+    return value;
+  }
+
+  protected skipLeadingNewline(): void {
+    if (this.peek() === "\n") {
+      this.pos += 1;
+    } else if (this.startsWith("\r\n")) {
+      this.pos += 2;
+    }
+  }
+
+  protected readMultilineBasicString(): string {
+    this.pos += 3;
+    this.skipLeadingNewline();
+    let out = "";
+    for (;;) {
+      if (this.pos >= this.src.length) {
+        this.fail("unterminated string");
+      }
+      if (this.startsWith('"""')) {
+        // Up to two quotes may directly precede the closing delimiter.
+        let extra = 0;
+        while (extra < 2 && this.peek(3 + extra) === '"') {
+          extra += 1;
+        }
+        this.pos += 3 + extra;
+        return out + '"'.repeat(extra);
+      }
+      const ch = this.peek();
+      if (CONTROL_CHAR_RE.test(ch) && ch !== "\n" && !this.startsWith("\r\n")) {
+        this.fail("control characters must be escaped in strings");
+      }
+      this.pos += 1;
+      if (ch !== "\\") {
+        out += ch;
+        continue;
+      }
+      // Line-ending backslash trims the newline and leading whitespace that follows.
+      const rest = /^[ \t]*\r?\n/.exec(this.src.slice(this.pos));
+      if (rest) {
+        this.pos += rest[0].length;
+        while (/[ \t\r\n]/.test(this.peek())) {
+          this.pos += 1;
+        }
+        continue;
+      }
+      out += this.readEscape();
+    }
+  }
+
+  protected readMultilineLiteralString(): string {
+    this.pos += 3;
+    this.skipLeadingNewline();
+    const end = this.src.indexOf("'''", this.pos);
+    if (end === -1) {
+      this.fail("unterminated string");
+    }
+    let close = end;
+    while (close - end < 2 && this.src[close + 3] === "'") {
+      close += 1;
+    }
+    const value = this.src.slice(this.pos, close);
+    if (CONTROL_CHAR_RE.test(value.replace(/\r?\n/g, ""))) {
+      this.fail("control characters must be escaped in strings");
+    }
+    this.pos = close + 3;
+    return value;
+  }
+}
@@ -0,0 +1,209 @@
+import { describe, expect, it } from "vitest";
+import { parseToml, stringifyToml } from "./toml.js";
+
+const SAMPLE = `
+# Greenhouse gateway
+[gateway]
+port = 18_789 # inline comment
+bind = 'loopback'
+
+[gateway.auth]
+token = "\${GATEWAY_TOKEN}"
+
+[models.providers.ollama]
+baseUrl = "http://127.0.0.1:11434"
+models = [
+  { id = "llama3", name = "Llama 3" },
+]
+
+[[agents.list]]
+id = "main"
+default = true
+
+[[agents.list]]
+id = "sensors"
+tools.deny = ["exec"]
+
+[agents.list.identity]
+name = "Sensor Bot"
+
+[messages]
+responsePrefix = """
+[bot]"""
+`;
+
+describe("toml", () => {
+  it("parses tables, arrays of tables, dotted keys, and inline values", () => {
+    expect(parseToml(SAMPLE)).toEqual({
+      gateway: { port: 18789, bind: "loopback", auth: { token: "${GATEWAY_TOKEN}" } },
+      models: {
+        providers: {
+          ollama: {
+            baseUrl: "http://127.0.0.1:11434",
+            models: [{ id: "llama3", name: "Llama 3" }],
+          },
+        },
+      },
+      agents: {
+        list: [
+          { id: "main", default: true },
+          { id: "sensors", tools: { deny: ["exec"] }, identity: { name: "Sensor Bot" } },
+        ],
+      },
+      messages: { responsePrefix: "[bot]" },
+    });
+  });
+
+  it("round-trips through stringifyToml", () => {
+    const parsed = parseToml(SAMPLE);
+    expect(parseToml(stringifyToml(parsed))).toEqual(parsed);
+  });
+
+  it("reports the failing line", () => {
+    expect(() => parseToml("[gateway]\nport = 1\nport = 2\n")).toThrow(
+      'TOML parse failed at line 3: duplicate key "port"',
+    );
+    expect(() => parseToml("[gateway]\nmode = local\n")).toThrow(
+      'TOML parse failed at line 2: invalid value "local" (strings must be quoted)',
+    );
+    expect(() => parseToml("[a]\n[a]\n")).toThrow('table "a" is defined twice');
+    expect(() => parseToml("__proto__ = 1\n")).toThrow('key "__proto__" is not allowed');
+  });
+
+  it("treats Object.prototype member names as ordinary keys", () => {
+    const raw = "toString = 1\nhasOwnProperty.valueOf = 2\n[isPrototypeOf]\na = 3\n";
+    expect(parseToml(raw)).toEqual({
+      toString: 1,
+      hasOwnProperty: { valueOf: 2 },
+      isPrototypeOf: { a: 3 },
+    });
+  });
+
+  it("refuses to drop null array entries when writing", () => {
+    expect(() => stringifyToml({ list: [1, null, 3] })).toThrow(
+      "cannot encode null array entries as TOML",
+    );
+    expect(stringifyToml({ skipped: null, kept: 1 })).toBe("kept = 1\n");
+  });
+
+  it("round-trips quoted keys, escapes, nested arrays, and special floats", () => {
+    const value = {
+      "a.b": { toString: 'line\n"quoted"\ttab', "": true },
+      nested: [[1, 2], ["x"], []],
+      limits: { max: Infinity, min: -Infinity, ratio: 0.25 },
+    };
+    expect(parseToml(stringifyToml(value))).toEqual(value);
+  });
+});
+
+describe("toml spec edge cases", () => {
+  const value = (raw: string) => parseToml(`v = ${raw}\n`).v;
+
+  it("parses decimal, hex, octal, and binary integers with underscores", () => {
+    expect(value("0")).toBe(0);
+    expect(value("+99")).toBe(99);
+    expect(value("-17")).toBe(-17);
+    expect(value("1_000_000")).toBe(1_000_000);
+    expect(value("0xdead_BEEF")).toBe(0xdeadbeef);
+    expect(value("0o755")).toBe(0o755);
+    expect(value("0b1101_0110")).toBe(0b11010110);
+    expect(value("9007199254740991")).toBe(Number.MAX_SAFE_INTEGER);
+  });
+
+  it("rejects leading zeros, stray underscores, and malformed prefixes", () => {
+    for (const raw of [
+      "01",
+      "-01",
+      "+00",
+      "0_1",
+      "01.5",
+      "1__0",
+      "_1",
+      "1_",
+      "1_.5",
+      "1e_5",
+      "0x_1",
+      "0X1F",
+      "+0x1",
+      "0o8",
+      "0b2",
+      "3.",
+      ".7",
+      "1.e5",
+    ]) {
+      expect(() => value(raw), raw).toThrow(`invalid value "${raw}"`);
+    }
+  });
+
+  it("rejects integers that cannot be represented losslessly", () => {
+    expect(() => value("9007199254740993")).toThrow('integer "9007199254740993" is out of range');
+    expect(() => value("0x7FFFFFFFFFFFFFFF")).toThrow("is out of range");
+  });
+
+  it("parses floats, exponents, inf, and nan", () => {
+    expect(value("+1.5")).toBe(1.5);
+    expect(value("3.141_592")).toBe(3.141592);
+    expect(value("6.626e-34")).toBe(6.626e-34);
+    expect(value("5E+22")).toBe(5e22);
+    expect(value("1e06")).toBe(1e6);
+    expect(value("-inf")).toBe(-Infinity);
+    expect(value("+inf")).toBe(Infinity);
+    expect(value("nan")).toBeNaN();
+  });
+
+  it("reads dates and times as strings", () => {
+    expect(value("1979-05-27T07:32:00Z")).toBe("1979-05-27T07:32:00Z");
+    expect(value("1979-05-27 07:32:00.999-07:00")).toBe("1979-05-27 07:32:00.999-07:00");
+    expect(value("1979-05-27")).toBe("1979-05-27");
+    expect(value("07:32:00")).toBe("07:32:00");
+  });
+
+  it("decodes escapes and multi-line strings", () => {
+    expect(value(String.raw`"tab\there \u00e9 \U0001F600 \\ \""`)).toBe('tab\there é 😀 \\ "');
+    expect(value(String.raw`'C:\Users\node'`)).toBe(String.raw`C:\Users\node`);
+    expect(value('"""\nThe quick \\\n    brown fox"""')).toBe("The quick brown fox");
+    expect(value('"""He said "hi""""')).toBe('He said "hi"');
+    expect(value("'''\nfirst\r\n  second'''")).toBe("first\r\n  second");
+    expect(value("''''quoted'''''")).toBe("'quoted''");
+  });
+
+  it("rejects invalid escapes and raw control characters", () => {
+    expect(() => value(String.raw`"\x41"`)).toThrow('invalid escape "\\x"');
+    expect(() => value(String.raw`"\uD800"`)).toThrow("invalid unicode escape");
+    expect(() => value(String.raw`"\U00110000"`)).toThrow("invalid unicode escape");
+    expect(() => value('"bell\u0007"')).toThrow("control characters must be escaped");
+    expect(() => value("'nul\u0000'")).toThrow("control characters must be escaped");
+    expect(() => value('"""del\u007f"""')).toThrow("control characters must be escaped");
+    expect(() => parseToml('v = "open\nw = 1\n')).toThrow("line 1: unterminated string");
+  });
+
+  it("handles quoted and dotted keys", () => {
+    expect(parseToml('"a.b" = 1\nsite."google.com" = true\n"" = "empty"\n')).toEqual({
+      "a.b": 1,
+      site: { "google.com": true },
+      "": "empty",
+    });
+  });
+
+  it("allows sub-tables under dotted keys but not reopening them", () => {
+    const fruit = '[fruit]\napple.color = "red"\napple.taste.sweet = true\n';
+    expect(parseToml(`${fruit}[fruit.apple.texture]\nsmooth = true\n`)).toEqual({
+      fruit: { apple: { color: "red", taste: { sweet: true }, texture: { smooth: true } } },
+    });
+    expect(() => parseToml(`${fruit}[fruit.apple]\n`)).toThrow(
+      'table "fruit.apple" is defined twice',
+    );
+    expect(() => parseToml(`${fruit}[fruit.apple.taste]\n`)).toThrow("is defined twice");
+  });
+
+  it("keeps inline tables and static arrays closed", () => {
+    expect(() => parseToml("a = { b = 1 }\n[a]\n")).toThrow('table "a" is defined twice');
+    expect(() => parseToml("a = { b = 1 }\na.c = 2\n")).toThrow('"a" is not a table');
+    expect(() => parseToml("a = [1]\n[[a]]\n")).toThrow('cannot redefine "a"');
+    expect(() => parseToml("[a.b]\n[a]\nb.c = 1\n")).toThrow('cannot add keys to "b"');
+  });
+
+  it("accepts CRLF line endings and a byte-order mark", () => {
+    expect(parseToml("\uFEFFa = 1\r\n[t]\r\nb = 'x' # note\r\n")).toEqual({ a: 1, t: { b: "x" } });
+  });
+});
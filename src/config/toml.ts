@@ -0,0 +1,240 @@
+/**
+ * Minimal TOML 1.0 reader/writer for config files (`openclaw.toml` and `$include`d `.toml`).
+ *
+ * Covers what config files use: tables, arrays of tables, dotted and quoted keys, basic/literal
+ * (multi-line) strings, integers, floats, booleans, arrays, and inline tables. Date-time values
+ * are read as strings. Parsing is layered: `toml.lexer.ts` (whitespace, keys, strings),
+ * `toml.values.ts` (values), and table assembly here.
+ */
+
+import JSON5 from "json5";
+import { isPlainObject } from "../utils.js";
+import type { TomlTable } from "./toml.lexer.js";
+import { TomlValueReader } from "./toml.values.js";
+
+export { TomlParseError } from "./toml.lexer.js";
+
+/** Own-property lookup, so keys like "toString" aren't mistaken for inherited members. */
+function ownValue(table: TomlTable, key: string): unknown {
+  return Object.hasOwn(table, key) ? table[key] : undefined;
+}
+
+export function isTomlConfigPath(filePath: string): boolean {
+  return filePath.toLowerCase().endsWith(".toml");
+}
+
+/** Parses a config file (or `$include`) as TOML or JSON5, based on its extension. */
+export function parseConfigText(
+  raw: string,
+  filePath: string,
+  json5: { parse: (value: string) => unknown } = JSON5,
+): unknown {
+  return isTomlConfigPath(filePath) ? parseToml(raw) : json5.parse(raw);
+}
+
+const BARE_KEY_RE = /^[A-Za-z0-9_-]+$/;
+
+/** Table assembly: `[headers]`, `[[arrays of tables]]`, and dotted keys. */
+class TomlReader extends TomlValueReader {
+  private readonly root: TomlTable = {};
+  /** Tables created by a `[header]` or dotted key; TOML forbids defining them twice. */
+  private readonly defined = new WeakSet<object>();
+  private readonly tableArrays = new WeakSet<object>();
+  /** Tables created by dotted keys; a `[header]` can add sub-tables but not reopen them. */
+  private readonly dotted = new WeakSet<object>();
+
+  parse(): TomlTable {
+    let current = this.root;
+    while (this.skipBlankLines()) {
+      if (this.peek() === "[") {
+        current = this.readHeader();
+      } else {
+        this.readKeyValue(current);
+      }
+      this.expectLineEnd();
+    }
+    return this.root;
+  }
+
+  private readHeader(): TomlTable {
+    const isArray = this.startsWith("[[");
+    this.pos += isArray ? 2 : 1;
+    const keys = this.readKey();
+    if (!this.startsWith(isArray ? "]]" : "]")) {
+      this.fail(`expected "${isArray ? "]]" : "]"}" after table name`);
+    }
+    this.pos += isArray ? 2 : 1;
+
+    let table = this.root;
+    for (const key of keys.slice(0, -1)) {
+      table = this.descend(table, key);
+    }
+    const last = keys[keys.length - 1];
+    const existing = ownValue(table, last);
+    if (isArray) {
+      if (existing === undefined) {
+        const list: TomlTable[] = [];
+        this.tableArrays.add(list);
+        table[last] = list;
+      } else if (!Array.isArray(existing) || !this.tableArrays.has(existing)) {
+        this.fail(`cannot redefine "${keys.join(".")}" as an array of tables`);
+      }
+      const entry: TomlTable = {};
+      (table[last] as TomlTable[]).push(entry);
+      return entry;
+    }
+    if (existing === undefined) {
+      const created: TomlTable = {};
+      table[last] = created;
+      this.defined.add(created);
+      return created;
+    }
+    if (
+      !isPlainObject(existing) ||
+      this.defined.has(existing) ||
+      this.frozen.has(existing) ||
+      this.dotted.has(existing)
+    ) {
+      this.fail(`table "${keys.join(".")}" is defined twice`);
+    }
+    this.defined.add(existing);
+    return existing;
+  }
+
+  /** Walks into a parent table, following the latest entry of arrays of tables. */
+  private descend(table: TomlTable, key: string): TomlTable {
+    const existing = ownValue(table, key);
+    if (existing === undefined) {
+      const created: TomlTable = {};
+      table[key] = created;
+      return created;
+    }
+    if (Array.isArray(existing) && this.tableArrays.has(existing)) {
+      return existing[existing.length - 1] as TomlTable;
+    }
+    if (!isPlainObject(existing) || this.frozen.has(existing)) {
+      this.fail(`"${key}" is not a table`);
+    }
+    return existing;
+  }
+
+  protected readKeyValue(table: TomlTable): void {
+    const keys = this.readKey();
+    if (this.peek() !== "=") {
+      this.fail('expected "=" after key');
+    }
+    this.pos += 1;
+    this.skipSpaces();
+    let target = table;
+    for (const key of keys.slice(0, -1)) {
+      const existing = ownValue(target, key);
+      if (existing !== undefined && (!isPlainObject(existing) || this.defined.has(existing))) {
+        this.fail(`cannot add keys to "${key}"`);
+      }
+      target = this.descend(target, key);
+      this.dotted.add(target);
+    }
+    const last = keys[keys.length - 1];
+    if (Object.hasOwn(target, last)) {
+      this.fail(`duplicate key "${keys.join(".")}"`);
+    }
+    target[last] = this.readValue();
+  }
+}
+
+export function parseToml(raw: string): TomlTable {
+  return new TomlReader(raw.replace(/^\uFEFF/, "")).parse();
+}
+
+function formatKey(key: string): string {
+  return BARE_KEY_RE.test(key) ? key : JSON.stringify(key);
+}
+
+function isTableArray(value: unknown): value is TomlTable[] {
+  return Array.isArray(value) && value.length > 0 && value.every((entry) => isPlainObject(entry));
+}
+
+function formatValue(value: unknown): string {
+  if (typeof value === "string") {
+    return JSON.stringify(value);
+  }
+  if (typeof value === "number") {
+    if (Number.isNaN(value)) {
+      return "nan";
+    }
+    if (!Number.isFinite(value)) {
+      return value > 0 ? "inf" : "-inf";
+    }
+    return String(value);
+  }
+  if (typeof value === "boolean") {
+    return String(value);
+  }
+  if (Array.isArray(value)) {
+    // Dropping a null entry would shift the indices after it; refuse instead.
+    if (value.some((entry) => entry == null)) {
+      throw new Error("cannot encode null array entries as TOML");
+    }
+    return `[${value.map(formatValue).join(", ")}]`;
+  }
+  if (isPlainObject(value)) {
+    const entries = Object.entries(value).filter(([, entry]) => entry != null);
+    if (entries.length === 0) {
+      return "{}";
+    }
+    const body = entries.map(([key, entry]) => `${formatKey(key)} = ${formatValue(entry)}`);
+    return `{ ${body.join(", ")} }`;
+  }
+  throw new Error(`cannot encode ${typeof value} as TOML`);
+}
+
+function writeTable(table: TomlTable, prefix: string[], out: string[]): void {
+  const nested: Array<[string, unknown]> = [];
+  const lines: string[] = [];
+  for (const [key, value] of Object.entries(table)) {
+    if (value == null) {
+      // TOML has no null; dropping the key keeps the same meaning for config.
+      continue;
+    }
+    if (isPlainObject(value) || isTableArray(value)) {
+      nested.push([key, value]);
+    } else {
+      lines.push(`${formatKey(key)} = ${formatValue(value)}`);
+    }
+  }
+  const header = prefix.map(formatKey).join(".");
+  if (prefix.length > 0 && (lines.length > 0 || nested.length === 0)) {
+    out.push(`[${header}]`);
+  }
+  if (lines.length > 0) {
+    out.push(...lines, "");
+  } else if (prefix.length > 0 && nested.length === 0) {
+    out.push("");
+  }
+  for (const [key, value] of nested) {
+    const path = [...prefix, key];
+    if (Array.isArray(value)) {
+      for (const entry of value) {
+        out.push(`[[${path.map(formatKey).join(".")}]]`);
+        const inner: string[] = [];
+        writeTable(entry as TomlTable, path, inner);
+        // Sub-tables of an array entry keep their own headers; the entry's header is ours.
+        out.push(...(inner[0] === `[${path.map(formatKey).join(".")}]` ? inner.slice(1) : inner));
+        if (out[out.length - 1] !== "") {
+          out.push("");
+        }
+      }
+    } else {
+      writeTable(value as TomlTable, path, out);
+    }
+  }
+}
+
+export function stringifyToml(value: TomlTable): string {
+  const out: string[] = [];
+  writeTable(value, [], out);
+  while (out.length > 0 && out[out.length - 1] === "") {
+    out.pop();
+  }
+  return `${out.join("\n")}\n`;
+}
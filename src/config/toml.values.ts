@@ -0,0 +1,143 @@
+import { type TomlTable, TomlLexer } from "./toml.lexer.js";
+
+// No leading zeros; underscores only between digits; a fraction needs digits on both sides.
+const DECIMAL_RE = /^[+-]?(?:0|[1-9](?:_?\d)*)(?:\.\d(?:_?\d)*)?(?:[eE][+-]?\d(?:_?\d)*)?$/;
+const DATE_RE = /^\d{4}-\d{2}-\d{2}(?:[Tt ][0-9:.]+(?:[Zz]|[+-]\d{2}:\d{2})?)?/;
+const TIME_RE = /^\d{2}:\d{2}:\d{2}(?:\.\d+)?/;
+const RADIX_INTEGERS: Record<string, { base: number; digits: RegExp }> = {
+  x: { base: 16, digits: /^[0-9A-Fa-f](?:_?[0-9A-Fa-f])*$/ },
+  o: { base: 8, digits: /^[0-7](?:_?[0-7])*$/ },
+  b: { base: 2, digits: /^[01](?:_?[01])*$/ },
+};
+
+/** Values: strings, numbers, booleans, date-times (as strings), arrays, and inline tables. */
+export abstract class TomlValueReader extends TomlLexer {
+  /** Inline tables and static arrays can't be extended later. */
+  protected readonly frozen = new WeakSet<object>();
+
+  /** Inline tables hold key/value pairs, which the table assembler owns. */
+  protected abstract readKeyValue(table: TomlTable): void;
+
+  protected readValue(): unknown {
+    const ch = this.peek();
+    if (this.startsWith('"""')) {
+      return this.readMultilineBasicString();
+    }
+    if (this.startsWith("'''")) {
+      return this.readMultilineLiteralString();
+    }
+    if (ch === '"') {
+      return this.readBasicString();
+    }
+    if (ch === "'") {
+      return this.readLiteralString();
+    }
+    if (ch === "[") {
+      return this.readArray();
+    }
+    if (ch === "{") {
+      return this.readInlineTable();
+    }
+    if (this.startsWith("true")) {
+      this.pos += 4;
+      return true;
+    }
+    if (this.startsWith("false")) {
+      this.pos += 5;
+      return false;
+    }
+    return this.readScalar();
+  }
+
+  protected readScalar(): unknown {
+    const rest = this.src.slice(this.pos);
+    const dateTime = DATE_RE.exec(rest) ?? TIME_RE.exec(rest);
+    if (dateTime) {
+      this.pos += dateTime[0].length;
+      return dateTime[0];
+    }
+    const start = this.pos;
+    while (/[0-9A-Za-z_+.-]/.test(this.peek())) {
+      this.pos += 1;
+    }
+    const token = this.src.slice(start, this.pos);
+    if (!token) {
+      this.fail(this.pos >= this.src.length ? "expected a value" : `unexpected "${this.peek()}"`);
+    }
+    const special = /^([+-])?(inf|nan)$/.exec(token);
+    if (special) {
+      if (special[2] === "nan") {
+        return Number.NaN;
+      }
+      return special[1] === "-" ? -Infinity : Infinity;
+    }
+    const radix = /^0([xob])(.*)$/.exec(token);
+    if (radix) {
+      const [, prefix, body] = radix;
+      const { base, digits } = RADIX_INTEGERS[prefix];
+      if (digits.test(body)) {
+        return this.toInteger(Number.parseInt(body.replace(/_/g, ""), base), start, token);
+      }
+    } else if (DECIMAL_RE.test(token)) {
+      const digits = token.replace(/_/g, "");
+      const value = Number(digits);
+      return /[.eE]/.test(digits) ? value : this.toInteger(value, start, token);
+    }
+    this.pos = start;
+    this.fail(`invalid value "${token}" (strings must be quoted)`);
+  }
+
+  /** Integers beyond 2^53 can't be represented losslessly, which TOML requires to be an error. */
+  protected toInteger(value: number, start: number, token: string): number {
+    if (!Number.isSafeInteger(value)) {
+      this.pos = start;
+      this.fail(`integer "${token}" is out of range`);
+    }
+    return value;
+  }
+
+  protected readArray(): unknown[] {
+    this.pos += 1;
+    const list: unknown[] = [];
+    for (;;) {
+      this.skipBlankLines();
+      if (this.peek() === "]") {
+        this.pos += 1;
+        this.frozen.add(list);
+        return list;
+      }
+      list.push(this.readValue());
+      this.skipBlankLines();
+      if (this.peek() === ",") {
+        this.pos += 1;
+      } else if (this.peek() !== "]") {
+        this.fail('expected "," or "]" in array');
+      }
+    }
+  }
+
+  protected readInlineTable(): TomlTable {
+    this.pos += 1;
+    const table: TomlTable = {};
+    this.skipSpaces();
+    if (this.peek() === "}") {
+      this.pos += 1;
+      this.frozen.add(table);
+      return table;
+    }
+    for (;;) {
+      this.readKeyValue(table);
+      this.skipSpaces();
+      if (this.peek() === "}") {
+        this.pos += 1;
+        this.frozen.add(table);
+        return table;
+      }
+      if (this.peek() !== ",") {
+        this.fail('expected "," or "}" in inline table');
+      }
+      this.pos += 1;
+    }
+  }
+}
+}
@@ -1,5 +1,9 @@
 import type { ChannelId } from "../channels/plugins/types.js";
-import type { AgentModelConfig, AgentSandboxConfig } from "./types.agents-shared.js";
+import type {
+  AgentModelConfig,
+  AgentPlanningConfig,
+  AgentSandboxConfig,
+} from "./types.agents-shared.js";
 import type {
   BlockStreamingChunkConfig,
   BlockStreamingCoalesceConfig,
@@ -117,11 +121,38 @@ export type CliBackendConfig = {
   };
 };
 
+export type AgentModelRoutingConfig = {
+  /**
+   * Model for turns that carry image attachments when the selected model has no image input.
+   * Applies to that turn only; the session keeps its model.
+   */
+  vision?: string;
+  /** Default model for isolated cron runs without a job-level model. */
+  cron?: string;
+};
+
+/**
+ * Generation params per kind of run, layered over the model's `params`
+ * (e.g. `{ temperature: 0.2, maxTokens: 2048, topP: 0.9, stop: ["END"] }`).
+ */
+export type AgentTaskParamsConfig = {
+  /** Interactive chat turns. */
+  chat?: Record<string, unknown>;
+  /** Isolated cron runs. */
+  cron?: Record<string, unknown>;
+  /** Sub-agent runs. */
+  subagent?: Record<string, unknown>;
+};
+
 export type AgentDefaultsConfig = {
   /** Primary model and fallbacks (provider/model). Accepts string or {primary,fallbacks}. */
   model?: AgentModelConfig;
   /** Optional image-capable model and fallbacks (provider/model). Accepts string or {primary,fallbacks}. */
   imageModel?: AgentModelConfig;
+  /** Per-task model routing (provider/model or alias). */
+  modelRouting?: AgentModelRoutingConfig;
+  /** Per-task generation params (temperature, maxTokens, topP, stop). */
+  taskParams?: AgentTaskParamsConfig;
   /** Model catalog with optional aliases (full provider/model keys). */
   models?: Record<string, AgentModelEntryConfig>;
   /** Agent working directory (preferred). Used as the default cwd for agent runs. */
@@ -194,6 +225,11 @@ export type AgentDefaultsConfig = {
   typingIntervalSeconds?: number;
   /** Typing indicator start mode (never|instant|thinking|message). */
   typingMode?: TypingMode;
+  /**
+   * Min seconds between tool progress messages ("⏳ flashing firmware (40%)"); the first one
+   * waits this long after the tool starts. 0 disables. Default: 10.
+   */
+  toolProgressIntervalSeconds?: number;
   /** Periodic background heartbeat runs. */
   heartbeat?: {
     /** Heartbeat interval (duration string, default unit: minutes; default: 30m). */
@@ -219,6 +255,8 @@ export type AgentDefaultsConfig = {
     accountId?: string;
     /** Override the heartbeat prompt body (default: "Read HEARTBEAT.md if it exists (workspace context). Follow it strictly. Do not infer or repeat old tasks from prior chats. If nothing needs attention, reply HEARTBEAT_OK."). */
     prompt?: string;
+    /** Checklist items appended to the heartbeat prompt; runs even when HEARTBEAT.md is empty. */
+    checklist?: string[];
     /** Max chars allowed after HEARTBEAT_OK before delivery (default: 30). */
     ackMaxChars?: number;
     /** Suppress tool error warning payloads during heartbeat runs. */
@@ -252,6 +290,8 @@ export type AgentDefaultsConfig = {
   };
   /** Optional sandbox settings for non-main sessions. */
   sandbox?: AgentSandboxConfig;
+  /** Optional planner/executor mode: plan first, then run the plan (with approval). */
+  planning?: AgentPlanningConfig;
 };
 
 export type AgentCompactionMode = "default" | "safeguard";
@@ -269,6 +309,8 @@ export type AgentCompactionConfig = {
   maxHistoryShare?: number;
   /** Pre-compaction memory flush (agentic turn). Default: enabled. */
   memoryFlush?: AgentCompactionMemoryFlushConfig;
+  /** Append each compaction summary to memory/YYYY-MM-DD.md (default: false). */
+  summaryToMemory?: boolean;
 };
 
 export type AgentCompactionMemoryFlushConfig = {
@@ -13,6 +13,30 @@ export type AgentModelConfig =
       fallbacks?: string[];
     };
 
+export type AgentPlanningConfig = {
+  /** Plan each user turn with a planner model before the agent runs tools (default: false). */
+  enabled?: boolean;
+  /** Planner model (provider/model or alias; default: the agent's primary model). */
+  model?: string;
+  /**
+   * When the plan must be approved before execution:
+   * "high-impact" (default) when a step is flagged high-impact or uses highImpactTools,
+   * "always" for every plan, "off" never (the plan is still shown).
+   */
+  approval?: "off" | "high-impact" | "always";
+  /** Tool names/groups that make a plan high-impact (default: exec, process, gateway, nodes). */
+  highImpactTools?: string[];
+  /** Show plans that do not need approval before the reply (default: true). */
+  showPlan?: boolean;
+  /** Planner request timeout in seconds (default: 60). */
+  timeoutSeconds?: number;
+  /**
+   * Reflection retries when a planned run ends with an unresolved tool failure (default: 1).
+   * The failure trace is fed back to the agent so it can adjust and retry; 0 disables.
+   */
+  maxReflections?: number;
+};
+
 export type AgentSandboxConfig = {
   mode?: "off" | "non-main" | "all";
   /** Agent workspace access inside the sandbox. */
@@ -97,12 +97,32 @@ export type SessionThreadBindingsConfig = {
   ttlHours?: number;
 };
 
+/** Per-person preferences injected into the system prompt when that person is the sender. */
+export type UserProfileConfig = {
+  /** Display name the agent should use. */
+  name?: string;
+  /** Provider-prefixed sender ids (e.g. "telegram:123456"); same-key identityLinks also match. */
+  identities?: string[];
+  units?: "metric" | "imperial";
+  verbosity?: "brief" | "normal" | "detailed";
+  /** Preferred reply language (e.g. "de", "Spanish"). */
+  language?: string;
+  /** IANA timezone (e.g. "Europe/Berlin"); default: agents.defaults.userTimezone. */
+  timezone?: string;
+  /** Free-form notification preferences (e.g. "only urgent alerts after 22:00"). */
+  notifications?: string;
+  /** Anything else the agent should know about this person. */
+  notes?: string;
+};
+
 export type SessionConfig = {
   scope?: SessionScope;
   /** DM session scoping (default: "main"). */
   dmScope?: DmScope;
   /** Map platform-prefixed identities (e.g. "telegram:123") to canonical DM peers. */
   identityLinks?: Record<string, string[]>;
+  /** Personalization profiles keyed by canonical user id (same keys as identityLinks). */
+  userProfiles?: Record<string, UserProfileConfig>;
   resetTriggers?: string[];
   idleMinutes?: number;
   reset?: SessionResetConfig;
@@ -0,0 +1,114 @@
+export type CostCurrencyConfig = {
+  /** ISO 4217 currency code used when displaying costs. Default: "USD". */
+  code?: string;
+  /**
+   * Units of `code` per 1 USD (e.g. 0.92 for EUR). Required for non-USD codes.
+   * Costs are always stored in USD; this rate is applied only when formatting.
+   */
+  usdRate?: number;
+};
+
+export type CostBudgetAction = "warn" | "refuse";
+
+export type CostBudgetConfig = {
+  /** Maximum USD spend per local calendar day, summed across agents. */
+  dailyUsd?: number;
+  /** Maximum USD spend per local calendar month, summed across agents. */
+  monthlyUsd?: number;
+  /**
+   * What to do when a request's estimated cost would exceed the remaining budget.
+   * "warn" sends the request and prepends a notice; "refuse" skips the model call. Default: "warn".
+   */
+  action?: CostBudgetAction;
+  /**
+   * Soft limit as a percent of each limit (e.g. 80). Past it, requests still run but replies
+   * carry a budget notice. Unset disables the soft stage.
+   */
+  softLimitPercent?: number;
+  /**
+   * Grace margin over the hard limit, in percent (e.g. 10), for conversations that were active
+   * in the last 30 minutes, so they can finish instead of stopping at exactly 100%. Default: 0.
+   */
+  gracePercent?: number;
+  /** Output tokens assumed when estimating a pending request. Default: 1024. */
+  estimateOutputTokens?: number;
+  /**
+   * Zero/low-cost model (provider/model or alias) to switch to when the budget check fails,
+   * e.g. "ollama/llama3.2". Takes precedence over `action`; replies note the degraded mode.
+   */
+  fallbackModel?: string;
+};
+
+export type CostRetentionConfig = {
+  /**
+   * Days of per-message usage that reports read raw. Older usage is folded into daily summary
+   * rows (state dir `cost/daily-summaries.json`) so pruning transcripts with session maintenance
+   * keeps their cost history. Compaction itself deletes nothing.
+   */
+  rawDays?: number;
+};
+
+export type CostWebhookConfig = {
+  /** http(s) endpoint that receives cost events as JSON POSTs. */
+  url?: string;
+  /**
+   * Shared secret for the `X-OpenClaw-Signature: sha256=<hex>` header
+   * (HMAC-SHA256 over `<X-OpenClaw-Timestamp>.<body>`). Unsigned when unset.
+   */
+  secret?: string;
+  /** Percent-of-budget thresholds that fire `budget.threshold` events. Default: [50, 80, 100]. */
+  thresholds?: number[];
+  /** Send yesterday's `cost.daily_summary` once per day. Default: true. */
+  dailySummary?: boolean;
+  /** Request timeout in seconds. Default: 10. */
+  timeoutSeconds?: number;
+};
+
+export type CostReconcileConfig = {
+  /** OpenAI admin key for the organization Costs API. Falls back to OPENAI_ADMIN_KEY. */
+  openaiAdminKey?: string;
+  /** Anthropic Admin API key for the cost report. Falls back to ANTHROPIC_ADMIN_KEY. */
+  anthropicAdminKey?: string;
+  /** Hours between scheduled reconciliations on the Gateway. Default: 24. */
+  intervalHours?: number;
+  /** Full UTC days compared per run. Default: 7. */
+  lookbackDays?: number;
+  /** Drift (percent of billed cost) that flags a provider. Default: 10. */
+  driftPercent?: number;
+};
+
+export type CostDigestSchedule = "daily" | "weekly";
+
+export type CostDigestConfig = {
+  /** "daily" covers yesterday; "weekly" covers the previous seven days and sends on Mondays. */
+  schedule?: CostDigestSchedule;
+  /** Local send time (HH:MM, 24h). Default: "09:00". */
+  time?: string;
+  /** Delivery channel (e.g. "telegram", "slack"). */
+  channel?: string;
+  /** Channel-specific recipient (chat id, channel id, phone number). */
+  to?: string;
+  /** Channel account id for multi-account setups. */
+  accountId?: string;
+};
+
+export type CostConfig = {
+  /** Display currency for cost summaries and reports. */
+  currency?: CostCurrencyConfig;
+  /** Spend limits checked before each agent run. */
+  budget?: CostBudgetConfig;
+  /** Compaction of old usage records into daily summaries. */
+  retention?: CostRetentionConfig;
+  /** Outbound webhook for budget threshold crossings and daily summaries. */
+  webhook?: CostWebhookConfig;
+  /** Scheduled comparison of tracked cost against provider billing APIs. */
+  reconcile?: CostReconcileConfig;
+  /** Scheduled daily/weekly cost digest delivered to a channel by the Gateway. */
+  digest?: CostDigestConfig;
+  /**
+   * Canonical model names for cost stats, keyed by "provider/model" or model id
+   * (e.g. { "azure/prod-gpt": "gpt-5.2" }). Aggregator prefixes like "anthropic/" are stripped
+   * automatically.
+   */
+  modelAliases?: Record<string, string>;
+};
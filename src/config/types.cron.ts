@@ -15,4 +15,9 @@ export type CronConfig = {
    * Default: "24h".
    */
   sessionRetention?: string | false;
+  /**
+   * Jobs declared in config (same shape as `cron.add` params; `name` is required and unique).
+   * Reconciled into the store on gateway start; config wins over edits made at runtime.
+   */
+  jobs?: Array<Record<string, unknown>>;
 };
@@ -179,6 +179,14 @@ export type GatewayHttpChatCompletionsConfig = {
   enabled?: boolean;
 };
 
+export type GatewayHttpChatConfig = {
+  /**
+   * If false, the Gateway will not serve `POST /v1/chat`.
+   * Default: false when absent.
+   */
+  enabled?: boolean;
+};
+
 export type GatewayHttpResponsesConfig = {
   /**
    * If false, the Gateway will not serve `POST /v1/responses` (OpenResponses API).
@@ -252,6 +260,7 @@ export type GatewayHttpResponsesImagesConfig = {
 
 export type GatewayHttpEndpointsConfig = {
   chatCompletions?: GatewayHttpChatCompletionsConfig;
+  chat?: GatewayHttpChatConfig;
   responses?: GatewayHttpResponsesConfig;
 };
 
@@ -32,6 +32,10 @@ export type IrcAccountConfig = CommonChannelMessagingConfig & {
     register?: boolean;
     /** Email used with NickServ REGISTER. */
     registerEmail?: string;
+    /** Authenticate with SASL PLAIN while connecting instead of IDENTIFY after connect. */
+    sasl?: boolean;
+    /** Services account name for SASL (default: nick). */
+    account?: string;
   };
   /** Auto-join channel list at connect (example: ["#openclaw"]). */
   channels?: string[];
@@ -3,6 +3,8 @@ import type { TtsConfig } from "./types.tts.js";
 
 export type GroupChatConfig = {
   mentionPatterns?: string[];
+  /** Literal prefixes (e.g. "!bot") that count as a mention when a message starts with them. */
+  triggerPrefixes?: string[];
   historyLimit?: number;
 };
 
@@ -82,6 +84,82 @@ export type StatusReactionsConfig = {
   timing?: StatusReactionsTimingConfig;
 };
 
+export type AlertKind = "budget" | "reconcile";
+
+export type AlertTarget = {
+  /** Channel id (e.g. "telegram", "slack", or plugin channel id). */
+  channel: string;
+  /** Destination id (chat id, channel id, phone number, depending on channel). */
+  to: string;
+  /** Optional account id for multi-account channels. */
+  accountId?: string;
+  /** Optional thread id to post inside a thread. */
+  threadId?: string | number;
+  /** Alert kinds sent to this target. Omit = all kinds. */
+  kinds?: AlertKind[];
+};
+
+export type AlertsConfig = {
+  /**
+   * Channels that receive alert-class messages (budget thresholds, billing drift).
+   * Alerts go to every matching target at once; conversation replies still go to their origin.
+   */
+  targets?: AlertTarget[];
+};
+
+export type NotificationSeverity = "info" | "warning" | "critical";
+
+export type NotificationPolicyConfig = {
+  /** Local-time window in which non-critical proactive messages are held. */
+  quietHours?: {
+    /** Start time (24h, HH:MM). Inclusive. */
+    start: string;
+    /** End time (24h, HH:MM). Exclusive. Use "24:00" for end-of-day. */
+    end: string;
+    /** Timezone for the window ("user", "local", or IANA TZ id). Default: "user". */
+    timezone?: string;
+  };
+  /** Proactive messages below this severity are dropped (default: "info", i.e. all are sent). */
+  minSeverity?: NotificationSeverity;
+  /** Lowest severity still delivered during quiet hours (default: "critical"). */
+  quietHoursBypass?: NotificationSeverity;
+  /**
+   * Messages held by quiet hours: "digest" sends them as one message once quiet hours end
+   * (default), "drop" discards them.
+   */
+  held?: "digest" | "drop";
+};
+
+export type NotificationRecipientConfig = NotificationPolicyConfig & {
+  /** Channel id (e.g. "telegram", "slack", or plugin channel id). */
+  channel: string;
+  /** Destination id (chat id, channel id, phone number, depending on channel). */
+  to: string;
+  /** Optional account id for multi-account channels. */
+  accountId?: string;
+};
+
+/**
+ * Policy for proactive messages (heartbeat and cron output, alerts, digests). Replies to a
+ * message the user just sent are never held. Recipient entries override `byChannel`, which
+ * overrides the top-level defaults.
+ */
+export type NotificationsConfig = NotificationPolicyConfig & {
+  /** Per-channel overrides keyed by channel id. */
+  byChannel?: Record<string, NotificationPolicyConfig>;
+  /** Per-recipient overrides (a person's DM, an ops room). */
+  recipients?: NotificationRecipientConfig[];
+};
+
+export type ToolStepsConfig = {
+  /** Post one line per finished tool call, with a short result, to the chat (default: false). */
+  enabled?: boolean;
+  /** Per-channel on/off overrides keyed by provider id (e.g. { discord: false }). */
+  byChannel?: Record<string, boolean>;
+  /** Max result characters per line; 0 shows the call only (default: 120). */
+  maxResultChars?: number;
+};
+
 export type MessagesConfig = {
   /** @deprecated Use `whatsapp.messagePrefix` (WhatsApp-only inbound prefix). */
   messagePrefix?: string;
@@ -119,8 +197,14 @@ export type MessagesConfig = {
   statusReactions?: StatusReactionsConfig;
   /** When true, suppress ⚠️ tool-error warnings from being shown to the user. Default: false. */
   suppressToolErrors?: boolean;
+  /** Live "→ tool(args) = result" lines while the agent works. */
+  toolSteps?: ToolStepsConfig;
   /** Text-to-speech settings for outbound replies. */
   tts?: TtsConfig;
+  /** Broadcast targets for alert-class messages. */
+  alerts?: AlertsConfig;
+  /** Quiet hours, severity thresholds, and digest batching for proactive messages. */
+  notifications?: NotificationsConfig;
 };
 
 export type NativeCommandsSetting = boolean | "auto";
@@ -149,6 +233,8 @@ export type CommandsConfig = {
   config?: boolean;
   /** Allow /debug command (default: false). */
   debug?: boolean;
+  /** Allow /snap (paired-node camera capture) (default: false). */
+  camera?: boolean;
   /** Allow restart commands/tools (default: true). */
   restart?: boolean;
   /** Enforce access-group allowlists/policies for commands (default: true). */
@@ -5,7 +5,8 @@ export type ModelApi =
   | "google-generative-ai"
   | "github-copilot"
   | "bedrock-converse-stream"
-  | "ollama";
+  | "ollama"
+  | "llama-cpp";
 
 export type ModelCompatConfig = {
   supportsStore?: boolean;
@@ -19,6 +20,8 @@ export type ModelCompatConfig = {
   requiresAssistantAfterToolResult?: boolean;
   requiresThinkingAsText?: boolean;
   requiresMistralToolIds?: boolean;
+  /** Set false for models without tool calling; runs then go out without tools. */
+  supportsTools?: boolean;
 };
 
 export type ModelProviderAuthMode = "api-key" | "aws-sdk" | "oauth" | "token";
@@ -4,6 +4,7 @@ import type { AuthConfig } from "./types.auth.js";
 import type { DiagnosticsConfig, LoggingConfig, SessionConfig, WebConfig } from "./types.base.js";
 import type { BrowserConfig } from "./types.browser.js";
 import type { ChannelsConfig } from "./types.channels.js";
+import type { CostConfig } from "./types.cost.js";
 import type { CronConfig } from "./types.cron.js";
 import type {
   CanvasHostConfig,
@@ -22,8 +23,11 @@ import type {
 import type { ModelsConfig } from "./types.models.js";
 import type { NodeHostConfig } from "./types.node-host.js";
 import type { PluginsConfig } from "./types.plugins.js";
+import type { ConfigProfilesConfig } from "./types.profiles.js";
+import type { SecretsConfig } from "./types.secrets.js";
 import type { SkillsConfig } from "./types.skills.js";
 import type { ToolsConfig } from "./types.tools.js";
+import type { WatchdogConfig } from "./types.watchdog.js";
 
 export type OpenClawConfig = {
   meta?: {
@@ -108,6 +112,11 @@ export type OpenClawConfig = {
   talk?: TalkConfig;
   gateway?: GatewayConfig;
   memory?: MemoryConfig;
+  cost?: CostConfig;
+  watchdog?: WatchdogConfig;
+  secrets?: SecretsConfig;
+  /** Named overlays selected with `--profile <name>`. */
+  profiles?: ConfigProfilesConfig;
 };
 
 export type ConfigValidationIssue = {
@@ -0,0 +1,11 @@
+/**
+ * Overlay applied on top of the base config when the process runs with `--profile <name>`.
+ * Takes any top-level config keys: objects merge, arrays and scalars replace, `null` removes a key.
+ */
+export type ConfigProfile = {
+  /** Profile applied first, before this one (the base config is always the root). */
+  extends?: string;
+  [key: string]: unknown;
+};
+
+export type ConfigProfilesConfig = Record<string, ConfigProfile>;
@@ -0,0 +1,19 @@
+export type SecretSource = "env" | "file" | "keyring";
+
+export type SecretsConfig = {
+  /**
+   * Where `${secret:NAME}` references are looked up, in order (default: ["env", "file", "keyring"]).
+   * The first source that has the name wins.
+   */
+  sources?: SecretSource[];
+  /**
+   * JSON file mapping secret names to values (default: ~/.openclaw/secrets.json). It must not be
+   * readable by group or others; looser permissions fail config load.
+   */
+  file?: string;
+  /**
+   * OS keyring service name (default: "openclaw"). macOS uses the login Keychain (`security`),
+   * Linux the Secret Service (`secret-tool`); other platforms have no keyring source.
+   */
+  keyringService?: string;
+};
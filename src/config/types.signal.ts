@@ -8,6 +8,8 @@ export type SignalAccountConfig = CommonChannelMessagingConfig & {
   account?: string;
   /** Optional full base URL for signal-cli HTTP daemon. */
   httpUrl?: string;
+  /** Unix socket path for the signal-cli JSON-RPC daemon (`daemon --socket`); overrides HTTP. */
+  socketPath?: string;
   /** HTTP host for signal-cli daemon (default 127.0.0.1). */
   httpHost?: string;
   /** HTTP port for signal-cli daemon (default 8080). */
@@ -144,6 +144,8 @@ export type SlackAccountConfig = {
   /** @deprecated Legacy preview mode key; migrated automatically to `streaming`. */
   streamMode?: SlackLegacyStreamMode;
   mediaMaxMb?: number;
+  /** Send two-column Markdown tables (status/sensor readouts) as Block Kit fields. Default: false. */
+  tableBlocks?: boolean;
   /** Reaction notification mode (off|own|all|allowlist). Default: own. */
   reactionNotifications?: SlackReactionNotificationMode;
   /** Allowlist for reaction notifications when mode is allowlist. */
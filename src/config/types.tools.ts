@@ -139,6 +139,52 @@ export type ToolLoopDetectionDetectorConfig = {
   pingPong?: boolean;
 };
 
+export type ToolAutonomyMode = "observe" | "ask" | "auto";
+
+export type ToolAutonomyConfig = {
+  /**
+   * What the agent may do with side-effecting tool calls (read-only calls always run):
+   * "observe" blocks them, "ask" requires the user to confirm first, "auto" runs them (default).
+   */
+  mode?: ToolAutonomyMode;
+  /** Extra tool names/groups treated as side-effecting (for example plugin actuator tools). */
+  actionTools?: string[];
+  /** Plugin/skill tools known to be read-only; every other unclassified tool is side-effecting. */
+  readOnlyTools?: string[];
+};
+
+export type ToolParallelConfig = {
+  /** Run independent read-only tool calls from one model turn concurrently (default: false). */
+  enabled?: boolean;
+  /** Max tool calls running at once (default: 4). */
+  maxConcurrency?: number;
+  /** Tool names/groups that may run in parallel even though they are side-effecting. */
+  tools?: string[];
+  /** Tool names/groups that hold a lock: at most one call of each runs at a time. */
+  exclusive?: string[];
+};
+
+export type ToolGuardrailRule = {
+  /** Label used in logs and the explanation sent to the model. */
+  name?: string;
+  /** Tool names, globs, or groups this rule applies to. */
+  tools: string[];
+  /** Dotted path of the argument to check (e.g. "pin", "options.duty"); omit to match all args. */
+  param?: string;
+  /** Reject when the argument (or all args as JSON) matches this case-insensitive regex. */
+  match?: string;
+  /** Reject when the argument equals one of these values. */
+  oneOf?: Array<string | number | boolean>;
+  /** Numeric lower bound for the argument. */
+  min?: number;
+  /** Numeric upper bound for the argument. */
+  max?: number;
+  /** Rewrite out-of-range numbers to the nearest bound instead of rejecting. */
+  clamp?: boolean;
+  /** Explanation fed back to the model when the rule fires. */
+  message?: string;
+};
+
 export type ToolLoopDetectionConfig = {
   /** Enable tool-loop protection (default: false). */
   enabled?: boolean;
@@ -293,6 +339,12 @@ export type AgentToolsConfig = {
   fs?: FsToolsConfig;
   /** Runtime loop detection for repetitive/ stuck tool-call patterns. */
   loopDetection?: ToolLoopDetectionConfig;
+  /** Max tool calls per agent run; further calls are refused so the model wraps up. */
+  maxCallsPerRun?: number;
+  /** Autonomy level for side-effecting tool calls. */
+  autonomy?: ToolAutonomyConfig;
+  /** Rules checked against every tool call before it runs (reject or clamp). */
+  guardrails?: ToolGuardrailRule[];
   sandbox?: {
     tools?: {
       allow?: string[];
@@ -314,7 +366,7 @@ export type MemorySearchConfig = {
     sessionMemory?: boolean;
   };
   /** Embedding provider mode. */
-  provider?: "openai" | "gemini" | "local" | "voyage" | "mistral";
+  provider?: "openai" | "gemini" | "local" | "voyage" | "mistral" | "ollama";
   remote?: {
     baseUrl?: string;
     apiKey?: string;
@@ -333,7 +385,7 @@ export type MemorySearchConfig = {
     };
   };
   /** Fallback behavior when embeddings fail. */
-  fallback?: "openai" | "gemini" | "local" | "voyage" | "mistral" | "none";
+  fallback?: "openai" | "gemini" | "local" | "voyage" | "mistral" | "ollama" | "none";
   /** Embedding model id (remote) or alias (local). */
   model?: string;
   /** Local embedding settings (node-llama-cpp). */
@@ -555,6 +607,14 @@ export type ToolsConfig = {
   fs?: FsToolsConfig;
   /** Runtime loop detection for repetitive/ stuck tool-call patterns. */
   loopDetection?: ToolLoopDetectionConfig;
+  /** Max tool calls per agent run; further calls are refused so the model wraps up. */
+  maxCallsPerRun?: number;
+  /** Autonomy level for side-effecting tool calls. */
+  autonomy?: ToolAutonomyConfig;
+  /** Rules checked against every tool call before it runs (reject or clamp). */
+  guardrails?: ToolGuardrailRule[];
+  /** Concurrent execution of independent tool calls returned in one model turn. */
+  parallel?: ToolParallelConfig;
   /** Sub-agent tool policy defaults (deny wins). */
   subagents?: {
     /** Default model selection for spawned sub-agents (string or {primary,fallbacks}). */
@@ -7,6 +7,7 @@ export * from "./types.auth.js";
 export * from "./types.base.js";
 export * from "./types.browser.js";
 export * from "./types.channels.js";
+export * from "./types.cost.js";
 export * from "./types.openclaw.js";
 export * from "./types.cron.js";
 export * from "./types.discord.js";
@@ -20,13 +21,16 @@ export * from "./types.models.js";
 export * from "./types.node-host.js";
 export * from "./types.msteams.js";
 export * from "./types.plugins.js";
+export * from "./types.profiles.js";
 export * from "./types.queue.js";
 export * from "./types.sandbox.js";
 export * from "./types.signal.js";
 export * from "./types.skills.js";
+export * from "./types.secrets.js";
 export * from "./types.slack.js";
 export * from "./types.telegram.js";
 export * from "./types.tts.js";
 export * from "./types.tools.js";
+export * from "./types.watchdog.js";
 export * from "./types.whatsapp.js";
 export * from "./types.memory.js";
@@ -1,4 +1,4 @@
-export type TtsProvider = "elevenlabs" | "openai" | "edge";
+export type TtsProvider = "elevenlabs" | "openai" | "edge" | "piper";
 
 export type TtsMode = "final" | "all";
 
@@ -73,6 +73,26 @@ export type TtsConfig = {
     proxy?: string;
     timeoutMs?: number;
   };
+  /** Local Piper (https://github.com/rhasspy/piper) configuration. */
+  piper?: {
+    /** Piper executable (default: "piper" on PATH). */
+    command?: string;
+    /** Path to the voice model (.onnx). Required to enable Piper. */
+    model?: string;
+    /** Optional voice config path (defaults to `<model>.json`). */
+    config?: string;
+    /** Speaker id for multi-speaker models. */
+    speaker?: number;
+    /** Phoneme length scale (>1 slower, <1 faster). */
+    lengthScale?: number;
+    /** Generator noise. */
+    noiseScale?: number;
+    /** Phoneme width noise. */
+    noiseW?: number;
+    /** Seconds of silence after each sentence. */
+    sentenceSilence?: number;
+    timeoutMs?: number;
+  };
   /** Optional path for local TTS user preferences JSON. */
   prefsPath?: string;
   /** Hard cap for text sent to TTS (chars). */
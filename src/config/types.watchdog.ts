@@ -0,0 +1,71 @@
+export type WatchdogSourceConfig =
+  | {
+      /**
+       * hwmon reading, either an explicit sysfs file (e.g. "/sys/class/hwmon/hwmon0/temp1_input")
+       * or a chip name (the hwmon device's `name` file) plus an input (e.g. "temp1_input").
+       */
+      kind: "hwmon";
+      path?: string;
+      chip?: string;
+      input?: string;
+    }
+  | {
+      /** Linux IIO ADC channel: reads `<channel>_raw` (times `<channel>_scale` when present). */
+      kind: "adc";
+      /** IIO device under /sys/bus/iio/devices (default: "iio:device0"). */
+      device?: string;
+      /** Channel prefix, e.g. "in_voltage0". */
+      channel: string;
+    }
+  | {
+      /** I2C register read via `i2cget -y <bus> <address> <register> <mode>`. */
+      kind: "i2c";
+      bus: number;
+      /** 7-bit device address, e.g. "0x48". */
+      address: string;
+      register: string;
+      /** "byte" (default) or "word". */
+      mode?: "byte" | "word";
+    }
+  | {
+      /** Any file whose trimmed contents are a number. */
+      kind: "file";
+      path: string;
+    };
+
+export type WatchdogWatchConfig = {
+  /** Unique name used in logs and the agent prompt. */
+  name: string;
+  source: WatchdogSourceConfig;
+  /** Multiplier applied to the raw reading (hwmon defaults: temp/in/curr 0.001, power 1e-6). */
+  scale?: number;
+  /** Added after scaling. Default: 0. */
+  offset?: number;
+  /** Display unit for the prompt, e.g. "°C" or "V". */
+  unit?: string;
+  /** Breach when the value rises above this. */
+  above?: number;
+  /** Breach when the value drops below this. */
+  below?: number;
+  /**
+   * Distance back inside the threshold before a breach clears and can fire again
+   * (e.g. above 70 with hysteresis 5 clears under 65). Default: 0.
+   */
+  hysteresis?: number;
+  /** Minimum seconds between escalations for this watch. Default: 300. */
+  cooldownSeconds?: number;
+  /** Instructions for the agent, e.g. "Turn off the heater relay (GPIO 17) and tell me." */
+  prompt?: string;
+};
+
+export type WatchdogConfig = {
+  /** Run the watchdog on the Gateway. Default: true when `watches` is non-empty. */
+  enabled?: boolean;
+  /** Seconds between readings. Default: 30. */
+  intervalSeconds?: number;
+  /** Agent that handles breaches. Default: the default agent. */
+  agentId?: string;
+  /** Session that receives breach events. Default: the agent's main session. */
+  sessionKey?: string;
+  watches?: WatchdogWatchConfig[];
+};
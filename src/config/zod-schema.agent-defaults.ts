@@ -1,6 +1,7 @@
 import { z } from "zod";
 import {
   HeartbeatSchema,
+  AgentPlanningSchema,
   AgentSandboxSchema,
   AgentModelSchema,
   MemorySearchSchema,
@@ -17,6 +18,21 @@ export const AgentDefaultsSchema = z
   .object({
     model: AgentModelSchema.optional(),
     imageModel: AgentModelSchema.optional(),
+    modelRouting: z
+      .object({
+        vision: z.string().optional(),
+        cron: z.string().optional(),
+      })
+      .strict()
+      .optional(),
+    taskParams: z
+      .object({
+        chat: z.record(z.string(), z.unknown()).optional(),
+        cron: z.record(z.string(), z.unknown()).optional(),
+        subagent: z.record(z.string(), z.unknown()).optional(),
+      })
+      .strict()
+      .optional(),
     models: z
       .record(
         z.string(),
@@ -93,6 +109,7 @@ export const AgentDefaultsSchema = z
           })
           .strict()
           .optional(),
+        summaryToMemory: z.boolean().optional(),
       })
       .strict()
       .optional(),
@@ -120,6 +137,7 @@ export const AgentDefaultsSchema = z
     imageMaxDimensionPx: z.number().int().positive().optional(),
     typingIntervalSeconds: z.number().int().positive().optional(),
     typingMode: TypingModeSchema.optional(),
+    toolProgressIntervalSeconds: z.number().int().nonnegative().optional(),
     heartbeat: HeartbeatSchema,
     maxConcurrent: z.number().int().positive().optional(),
     subagents: z
@@ -151,6 +169,7 @@ export const AgentDefaultsSchema = z
       .strict()
       .optional(),
     sandbox: AgentSandboxSchema,
+    planning: AgentPlanningSchema,
   })
   .strict()
   .optional();
@@ -28,6 +28,7 @@ export const HeartbeatSchema = z
     to: z.string().optional(),
     accountId: z.string().optional(),
     prompt: z.string().optional(),
+    checklist: z.array(z.string()).optional(),
     ackMaxChars: z.number().int().nonnegative().optional(),
     suppressToolErrorWarnings: z.boolean().optional(),
   })
@@ -389,6 +390,72 @@ const ToolLoopDetectionDetectorSchema = z
   .strict()
   .optional();
 
+const ToolAutonomySchema = z
+  .object({
+    mode: z.union([z.literal("observe"), z.literal("ask"), z.literal("auto")]).optional(),
+    actionTools: z.array(z.string()).optional(),
+    readOnlyTools: z.array(z.string()).optional(),
+  })
+  .strict()
+  .optional();
+
+const ToolParallelSchema = z
+  .object({
+    enabled: z.boolean().optional(),
+    maxConcurrency: z.number().int().positive().optional(),
+    tools: z.array(z.string()).optional(),
+    exclusive: z.array(z.string()).optional(),
+  })
+  .strict()
+  .optional();
+
+const ToolGuardrailRuleSchema = z
+  .object({
+    name: z.string().optional(),
+    tools: z.array(z.string()).min(1),
+    param: z.string().optional(),
+    match: z.string().optional(),
+    oneOf: z.array(z.union([z.string(), z.number(), z.boolean()])).optional(),
+    min: z.number().optional(),
+    max: z.number().optional(),
+    clamp: z.boolean().optional(),
+    message: z.string().optional(),
+  })
+  .strict()
+  .superRefine((rule, ctx) => {
+    if (
+      rule.match === undefined &&
+      rule.oneOf === undefined &&
+      rule.min === undefined &&
+      rule.max === undefined
+    ) {
+      ctx.addIssue({
+        code: z.ZodIssueCode.custom,
+        message: "guardrail needs at least one of match, oneOf, min, max",
+      });
+    }
+    if (rule.match !== undefined) {
+      try {
+        new RegExp(rule.match, "i");
+      } catch {
+        ctx.addIssue({
+          code: z.ZodIssueCode.custom,
+          path: ["match"],
+          message: "invalid regular expression",
+        });
+      }
+    }
+    if ((rule.min !== undefined || rule.max !== undefined || rule.clamp) && !rule.param) {
+      ctx.addIssue({
+        code: z.ZodIssueCode.custom,
+        path: ["param"],
+        message: "min/max/clamp require param",
+      });
+    }
+  });
+
+const ToolGuardrailsSchema = z.array(ToolGuardrailRuleSchema).optional();
+
 const ToolLoopDetectionSchema = z
   .object({
     enabled: z.boolean().optional(),
@@ -426,6 +493,19 @@ const ToolLoopDetectionSchema = z
   })
   .optional();
 
+export const AgentPlanningSchema = z
+  .object({
+    enabled: z.boolean().optional(),
+    model: z.string().optional(),
+    approval: z.union([z.literal("off"), z.literal("high-impact"), z.literal("always")]).optional(),
+    highImpactTools: z.array(z.string()).optional(),
+    showPlan: z.boolean().optional(),
+    timeoutSeconds: z.number().int().positive().optional(),
+    maxReflections: z.number().int().min(0).max(5).optional(),
+  })
+  .strict()
+  .optional();
+
 export const AgentSandboxSchema = z
   .object({
     mode: z.union([z.literal("off"), z.literal("non-main"), z.literal("all")]).optional(),
@@ -462,6 +542,9 @@ export const AgentToolsSchema = z
     exec: AgentToolExecSchema,
     fs: ToolFsSchema,
     loopDetection: ToolLoopDetectionSchema,
+    maxCallsPerRun: z.number().int().positive().optional(),
+    autonomy: ToolAutonomySchema,
+    guardrails: ToolGuardrailsSchema,
     sandbox: z
       .object({
         tools: ToolPolicySchema,
@@ -497,6 +580,7 @@ export const MemorySearchSchema = z
         z.literal("gemini"),
         z.literal("voyage"),
         z.literal("mistral"),
+        z.literal("ollama"),
       ])
       .optional(),
     remote: z
@@ -524,6 +608,7 @@ export const MemorySearchSchema = z
         z.literal("local"),
         z.literal("voyage"),
         z.literal("mistral"),
+        z.literal("ollama"),
         z.literal("none"),
       ])
       .optional(),
@@ -647,6 +732,7 @@ export const AgentEntrySchema = z
       .strict()
       .optional(),
     sandbox: AgentSandboxSchema,
+    planning: AgentPlanningSchema,
     tools: AgentToolsSchema,
   })
   .strict();
@@ -664,6 +750,10 @@ export const ToolsSchema = z
       .strict()
       .optional(),
     loopDetection: ToolLoopDetectionSchema,
+    maxCallsPerRun: z.number().int().positive().optional(),
+    autonomy: ToolAutonomySchema,
+    guardrails: ToolGuardrailsSchema,
+    parallel: ToolParallelSchema,
     message: z
       .object({
         allowCrossContextSend: z.boolean().optional(),
@@ -11,6 +11,7 @@ export const ModelApiSchema = z.union([
   z.literal("github-copilot"),
   z.literal("bedrock-converse-stream"),
   z.literal("ollama"),
+  z.literal("llama-cpp"),
 ]);
 
 export const ModelCompatSchema = z
@@ -28,6 +29,7 @@ export const ModelCompatSchema = z
     requiresAssistantAfterToolResult: z.boolean().optional(),
     requiresThinkingAsText: z.boolean().optional(),
     requiresMistralToolIds: z.boolean().optional(),
+    supportsTools: z.boolean().optional(),
   })
   .strict()
   .optional();
@@ -93,6 +95,7 @@ export const ModelsConfigSchema = z
 export const GroupChatSchema = z
   .object({
     mentionPatterns: z.array(z.string()).optional(),
+    triggerPrefixes: z.array(z.string()).optional(),
     historyLimit: z.number().int().positive().optional(),
   })
   .strict()
@@ -171,7 +174,7 @@ export const MarkdownConfigSchema = z
   .strict()
   .optional();
 
-export const TtsProviderSchema = z.enum(["elevenlabs", "openai", "edge"]);
+export const TtsProviderSchema = z.enum(["elevenlabs", "openai", "edge", "piper"]);
 export const TtsModeSchema = z.enum(["final", "all"]);
 export const TtsAutoSchema = z.enum(["off", "always", "inbound", "tagged"]);
 export const TtsConfigSchema = z
@@ -239,6 +242,20 @@ export const TtsConfigSchema = z
       })
       .strict()
       .optional(),
+    piper: z
+      .object({
+        command: z.string().optional(),
+        model: z.string().optional(),
+        config: z.string().optional(),
+        speaker: z.number().int().min(0).optional(),
+        lengthScale: z.number().positive().optional(),
+        noiseScale: z.number().min(0).optional(),
+        noiseW: z.number().min(0).optional(),
+        sentenceSilence: z.number().min(0).optional(),
+        timeoutMs: z.number().int().min(1000).max(300000).optional(),
+      })
+      .strict()
+      .optional(),
     prefsPath: z.string().optional(),
     maxTextLength: z.number().int().min(1).optional(),
     timeoutMs: z.number().int().min(1000).max(120000).optional(),
@@ -0,0 +1,86 @@
+import { z } from "zod";
+import { sensitive } from "./zod-schema.sensitive.js";
+
+const CostCurrencySchema = z
+  .object({
+    code: z
+      .string()
+      .regex(/^[A-Za-z]{3}$/, "Expected a 3-letter ISO 4217 currency code")
+      .optional(),
+    usdRate: z.number().positive().optional(),
+  })
+  .strict()
+  .superRefine((value, ctx) => {
+    const code = value.code?.toUpperCase();
+    if (code && code !== "USD" && value.usdRate === undefined) {
+      ctx.addIssue({
+        code: z.ZodIssueCode.custom,
+        path: ["usdRate"],
+        message: `usdRate is required when currency code is ${code}`,
+      });
+    }
+  });
+
+const CostBudgetSchema = z
+  .object({
+    dailyUsd: z.number().positive().optional(),
+    monthlyUsd: z.number().positive().optional(),
+    action: z.union([z.literal("warn"), z.literal("refuse")]).optional(),
+    softLimitPercent: z.number().positive().max(100).optional(),
+    gracePercent: z.number().nonnegative().max(100).optional(),
+    estimateOutputTokens: z.number().int().nonnegative().optional(),
+    fallbackModel: z.string().optional(),
+  })
+  .strict();
+
+const CostRetentionSchema = z
+  .object({
+    rawDays: z.number().int().positive().optional(),
+  })
+  .strict();
+
+const CostWebhookSchema = z
+  .object({
+    url: z.string().optional(),
+    secret: z.string().optional().register(sensitive),
+    thresholds: z.array(z.number().positive()).optional(),
+    dailySummary: z.boolean().optional(),
+    timeoutSeconds: z.number().int().positive().optional(),
+  })
+  .strict();
+
+const CostReconcileSchema = z
+  .object({
+    openaiAdminKey: z.string().optional().register(sensitive),
+    anthropicAdminKey: z.string().optional().register(sensitive),
+    intervalHours: z.number().positive().max(168).optional(),
+    lookbackDays: z.number().int().positive().max(90).optional(),
+    driftPercent: z.number().nonnegative().optional(),
+  })
+  .strict();
+
+const CostDigestSchema = z
+  .object({
+    schedule: z.union([z.literal("daily"), z.literal("weekly")]).optional(),
+    time: z
+      .string()
+      .regex(/^([01]\d|2[0-3]):([0-5]\d)$/, 'invalid time (use "HH:MM" 24h format)')
+      .optional(),
+    channel: z.string().optional(),
+    to: z.string().optional(),
+    accountId: z.string().optional(),
+  })
+  .strict();
+
+export const CostSchema = z
+  .object({
+    currency: CostCurrencySchema.optional(),
+    budget: CostBudgetSchema.optional(),
+    retention: CostRetentionSchema.optional(),
+    webhook: CostWebhookSchema.optional(),
+    reconcile: CostReconcileSchema.optional(),
+    digest: CostDigestSchema.optional(),
+    modelAliases: z.record(z.string(), z.string().min(1)).optional(),
+  })
+  .strict()
+  .optional();
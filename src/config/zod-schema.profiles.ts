@@ -0,0 +1,9 @@
+import { z } from "zod";
+
+// Overlay contents are validated after they are merged into the base config.
+export const ProfilesSchema = z
+  .record(
+    z.string().regex(/^[a-z0-9][a-z0-9_-]{0,63}$/i, "Use letters, numbers, '_' or '-'"),
+    z.object({ extends: z.string().optional() }).catchall(z.unknown()),
+  )
+  .optional();
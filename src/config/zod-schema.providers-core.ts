@@ -625,6 +625,7 @@ export const SlackAccountSchema = z
     nativeStreaming: z.boolean().optional(),
     streamMode: z.enum(["replace", "status_final", "append"]).optional(),
     mediaMaxMb: z.number().positive().optional(),
+    tableBlocks: z.boolean().optional(),
     reactionNotifications: z.enum(["off", "own", "all", "allowlist"]).optional(),
     reactionAllowlist: z.array(z.union([z.string(), z.number()])).optional(),
     replyToMode: ReplyToModeSchema.optional(),
@@ -730,6 +731,7 @@ export const SignalAccountSchemaBase = z
     configWrites: z.boolean().optional(),
     account: z.string().optional(),
     httpUrl: z.string().optional(),
+    socketPath: z.string().optional(),
     httpHost: z.string().optional(),
     httpPort: z.number().int().positive().optional(),
     cliPath: ExecutableTokenSchema.optional(),
@@ -808,6 +810,8 @@ export const IrcNickServSchema = z
     passwordFile: z.string().optional(),
     register: z.boolean().optional(),
     registerEmail: z.string().optional(),
+    sasl: z.boolean().optional(),
+    account: z.string().optional(),
   })
   .strict();
 
@@ -0,0 +1,13 @@
+import { z } from "zod";
+
+export const SecretsSchema = z
+  .object({
+    sources: z
+      .array(z.union([z.literal("env"), z.literal("file"), z.literal("keyring")]))
+      .min(1)
+      .optional(),
+    file: z.string().optional(),
+    keyringService: z.string().min(1).optional(),
+  })
+  .strict()
+  .optional();
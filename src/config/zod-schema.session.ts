@@ -35,6 +35,25 @@ export const SessionSchema = z
       ])
       .optional(),
     identityLinks: z.record(z.string(), z.array(z.string())).optional(),
+    userProfiles: z
+      .record(
+        z.string(),
+        z
+          .object({
+            name: z.string().optional(),
+            identities: z.array(z.string()).optional(),
+            units: z.union([z.literal("metric"), z.literal("imperial")]).optional(),
+            verbosity: z
+              .union([z.literal("brief"), z.literal("normal"), z.literal("detailed")])
+              .optional(),
+            language: z.string().optional(),
+            timezone: z.string().optional(),
+            notifications: z.string().optional(),
+            notes: z.string().optional(),
+          })
+          .strict(),
+      )
+      .optional(),
     resetTriggers: z.array(z.string()).optional(),
     idleMinutes: z.number().int().positive().optional(),
     reset: SessionResetConfigSchema.optional(),
@@ -106,6 +125,36 @@ export const SessionSchema = z
   .strict()
   .optional();
 
+const AlertTargetSchema = z
+  .object({
+    channel: z.string().min(1),
+    to: z.string().min(1),
+    accountId: z.string().optional(),
+    threadId: z.union([z.string(), z.number()]).optional(),
+    kinds: z.array(z.enum(["budget", "reconcile"])).optional(),
+  })
+  .strict();
+
+const NotificationSeveritySchema = z.enum(["info", "warning", "critical"]);
+
+const NotificationPolicyShape = {
+  quietHours: z
+    .object({
+      start: z.string().regex(/^([01]\d|2[0-3]):[0-5]\d$/, 'invalid time (use "HH:MM" 24h format)'),
+      end: z
+        .string()
+        .regex(/^(([01]\d|2[0-3]):[0-5]\d|24:00)$/, 'invalid time (use "HH:MM" 24h format)'),
+      timezone: z.string().optional(),
+    })
+    .strict()
+    .optional(),
+  minSeverity: NotificationSeveritySchema.optional(),
+  quietHoursBypass: NotificationSeveritySchema.optional(),
+  held: z.enum(["digest", "drop"]).optional(),
+};
+
+const NotificationPolicySchema = z.object(NotificationPolicyShape).strict();
+
 export const MessagesSchema = z
   .object({
     messagePrefix: z.string().optional(),
@@ -146,7 +195,40 @@ export const MessagesSchema = z
       .strict()
       .optional(),
     suppressToolErrors: z.boolean().optional(),
+    toolSteps: z
+      .object({
+        enabled: z.boolean().optional(),
+        byChannel: z.record(z.string(), z.boolean()).optional(),
+        maxResultChars: z.number().int().nonnegative().optional(),
+      })
+      .strict()
+      .optional(),
     tts: TtsConfigSchema,
+    alerts: z
+      .object({
+        targets: z.array(AlertTargetSchema).optional(),
+      })
+      .strict()
+      .optional(),
+    notifications: z
+      .object({
+        ...NotificationPolicyShape,
+        byChannel: z.record(z.string(), NotificationPolicySchema).optional(),
+        recipients: z
+          .array(
+            z
+              .object({
+                ...NotificationPolicyShape,
+                channel: z.string().min(1),
+                to: z.string().min(1),
+                accountId: z.string().optional(),
+              })
+              .strict(),
+          )
+          .optional(),
+      })
+      .strict()
+      .optional(),
   })
   .strict()
   .optional();
@@ -160,6 +242,7 @@ export const CommandsSchema = z
     bashForegroundMs: z.number().int().min(0).max(30_000).optional(),
     config: z.boolean().optional(),
     debug: z.boolean().optional(),
+    camera: z.boolean().optional(),
     restart: z.boolean().optional().default(true),
     useAccessGroups: z.boolean().optional(),
     ownerAllowFrom: z.array(z.union([z.string(), z.number()])).optional(),
@@ -3,9 +3,12 @@ import { ToolsSchema } from "./zod-schema.agent-runtime.js";
 import { AgentsSchema, AudioSchema, BindingsSchema, BroadcastSchema } from "./zod-schema.agents.js";
 import { ApprovalsSchema } from "./zod-schema.approvals.js";
 import { HexColorSchema, ModelsConfigSchema } from "./zod-schema.core.js";
+import { CostSchema } from "./zod-schema.cost.js";
 import { HookMappingSchema, HooksGmailSchema, InternalHooksSchema } from "./zod-schema.hooks.js";
 import { InstallRecordShape } from "./zod-schema.installs.js";
 import { ChannelsSchema } from "./zod-schema.providers.js";
+import { ProfilesSchema } from "./zod-schema.profiles.js";
+import { SecretsSchema } from "./zod-schema.secrets.js";
 import { sensitive } from "./zod-schema.sensitive.js";
 import {
   CommandsSchema,
@@ -13,6 +16,7 @@ import {
   SessionSchema,
   SessionSendPolicySchema,
 } from "./zod-schema.session.js";
+import { WatchdogSchema } from "./zod-schema.watchdog.js";
 
 const BrowserSnapshotDefaultsSchema = z
   .object({
@@ -324,6 +328,7 @@ export const OpenClawSchema = z
         webhook: HttpUrlSchema.optional(),
         webhookToken: z.string().optional().register(sensitive),
         sessionRetention: z.union([z.string(), z.literal(false)]).optional(),
+        jobs: z.array(z.record(z.string(), z.unknown())).optional(),
       })
       .strict()
       .optional(),
@@ -520,6 +525,12 @@ export const OpenClawSchema = z
                   })
                   .strict()
                   .optional(),
+                chat: z
+                  .object({
+                    enabled: z.boolean().optional(),
+                  })
+                  .strict()
+                  .optional(),
                 responses: z
                   .object({
                     enabled: z.boolean().optional(),
@@ -585,6 +596,10 @@ export const OpenClawSchema = z
       .strict()
       .optional(),
     memory: MemorySchema,
+    cost: CostSchema,
+    watchdog: WatchdogSchema,
+    secrets: SecretsSchema,
+    profiles: ProfilesSchema,
     skills: z
       .object({
         allowBundled: z.array(z.string()).optional(),
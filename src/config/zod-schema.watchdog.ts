@@ -0,0 +1,79 @@
+import { z } from "zod";
+
+const WatchdogSourceSchema = z.union([
+  z
+    .object({
+      kind: z.literal("hwmon"),
+      path: z.string().optional(),
+      chip: z.string().optional(),
+      input: z.string().optional(),
+    })
+    .strict()
+    .refine((value) => Boolean(value.path?.trim() || (value.chip?.trim() && value.input?.trim())), {
+      message: "hwmon source requires path, or chip and input",
+    }),
+  z
+    .object({
+      kind: z.literal("adc"),
+      device: z.string().optional(),
+      channel: z.string().min(1),
+    })
+    .strict(),
+  z
+    .object({
+      kind: z.literal("i2c"),
+      bus: z.number().int().nonnegative(),
+      address: z.string().regex(/^0x[0-7][0-9a-fA-F]$/, 'expected a 7-bit address like "0x48"'),
+      register: z.string().regex(/^0x[0-9a-fA-F]{1,2}$/, 'expected a register like "0x00"'),
+      mode: z.union([z.literal("byte"), z.literal("word")]).optional(),
+    })
+    .strict(),
+  z
+    .object({
+      kind: z.literal("file"),
+      path: z.string().min(1),
+    })
+    .strict(),
+]);
+
+const WatchdogWatchSchema = z
+  .object({
+    name: z.string().min(1),
+    source: WatchdogSourceSchema,
+    scale: z.number().optional(),
+    offset: z.number().optional(),
+    unit: z.string().optional(),
+    above: z.number().optional(),
+    below: z.number().optional(),
+    hysteresis: z.number().nonnegative().optional(),
+    cooldownSeconds: z.number().nonnegative().optional(),
+    prompt: z.string().optional(),
+  })
+  .strict()
+  .refine((value) => value.above !== undefined || value.below !== undefined, {
+    message: "watch requires above and/or below",
+  });
+
+export const WatchdogSchema = z
+  .object({
+    enabled: z.boolean().optional(),
+    intervalSeconds: z.number().int().min(1).optional(),
+    agentId: z.string().optional(),
+    sessionKey: z.string().optional(),
+    watches: z.array(WatchdogWatchSchema).optional(),
+  })
+  .strict()
+  .superRefine((value, ctx) => {
+    const seen = new Set<string>();
+    for (const [index, watch] of (value.watches ?? []).entries()) {
+      if (seen.has(watch.name)) {
+        ctx.addIssue({
+          code: z.ZodIssueCode.custom,
+          path: ["watches", index, "name"],
+          message: `duplicate watch name "${watch.name}"`,
+        });
+      }
+      seen.add(watch.name);
+    }
+  })
+  .optional();
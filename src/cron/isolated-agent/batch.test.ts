@@ -0,0 +1,137 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import { resolvePromptBatchClient } from "../../agents/prompt-batch.js";
+import { applyJobResult } from "../service/timer.js";
+import type { CronServiceState } from "../service/state.js";
+import type { CronJob } from "../types.js";
+import { CRON_BATCH_POLL_INTERVAL_MS, runCronBatchTurn } from "./batch.js";
+
+describe("cron batch turns", () => {
+  let dir: string;
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cron-batch-"));
+  });
+
+  afterEach(async () => {
+    vi.unstubAllGlobals();
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("submits once, polls, then returns the reply as a run result", async () => {
+    const responses: unknown[] = [
+      { id: "msgbatch_1" },
+      { processing_status: "in_progress" },
+      { processing_status: "ended", results_url: "https://api.anthropic.com/results/1" },
+      JSON.stringify({
+        custom_id: "nightly",
+        result: {
+          type: "succeeded",
+          message: {
+            content: [{ type: "text", text: "Humidity peaked at 71%." }],
+            usage: { input_tokens: 5000, output_tokens: 40 },
+          },
+        },
+      }),
+    ];
+    const fetchMock = vi.fn(async () => {
+      const next = responses.shift();
+      return new Response(typeof next === "string" ? next : JSON.stringify(next));
+    });
+    vi.stubGlobal("fetch", fetchMock);
+
+    let now = 1_000;
+    const run = () =>
+      runCronBatchTurn({
+        cfg: {},
+        agentDir: dir,
+        jobId: "nightly",
+        provider: "anthropic",
+        model: "claude-sonnet-4-5",
+        prompt: "Summarize today's sensor log",
+        sessionId: "sess-1",
+        storePath: path.join(dir, "batches.json"),
+        nowMs: () => now,
+        client: resolvePromptBatchClient({ provider: "anthropic", apiKey: "sk-ant" }),
+      });
+
+    await expect(run()).resolves.toEqual({
+      state: "pending",
+      batchId: "msgbatch_1",
+      retryAtMs: 1_000 + CRON_BATCH_POLL_INTERVAL_MS,
+    });
+    now = 2_000;
+    await expect(run()).resolves.toMatchObject({ state: "pending", batchId: "msgbatch_1" });
+    now = 61_000;
+    const done = await run();
+    expect(done).toMatchObject({
+      state: "completed",
+      result: {
+        payloads: [{ text: "Humidity peaked at 71%." }],
+        meta: {
+          durationMs: 60_000,
+          agentMeta: { provider: "anthropic", usage: { input: 5000, output: 40, total: 5040 } },
+        },
+      },
+    });
+    const store = JSON.parse(await fs.readFile(path.join(dir, "batches.json"), "utf-8"));
+    expect(store.batches).toEqual({});
+    expect(fetchMock).toHaveBeenCalledTimes(4);
+  });
+
+  it("rejects providers without a batch API", async () => {
+    await expect(
+      runCronBatchTurn({
+        cfg: {},
+        agentDir: dir,
+        jobId: "nightly",
+        provider: "ollama",
+        model: "llama3.3",
+        prompt: "hi",
+        sessionId: "sess-1",
+        storePath: path.join(dir, "batches.json"),
+      }),
+    ).rejects.toThrow(/openai and anthropic/);
+  });
+
+  it("re-runs a pending job at retryAtMs instead of the next scheduled run", () => {
+    const log = { info: vi.fn(), warn: vi.fn() };
+    const state = { deps: { log } } as unknown as CronServiceState;
+    const job: CronJob = {
+      id: "nightly",
+      name: "nightly",
+      enabled: true,
+      createdAtMs: 0,
+      updatedAtMs: 0,
+      schedule: { kind: "every", everyMs: 24 * 60 * 60_000, anchorMs: 0 },
+      sessionTarget: "isolated",
+      wakeMode: "now",
+      payload: { kind: "agentTurn", message: "summarize", batch: true },
+      state: {},
+    };
+    applyJobResult(state, job, {
+      status: "skipped",
+      error: "provider batch msgbatch_1 pending",
+      startedAt: 10_000,
+      endedAt: 11_000,
+      retryAtMs: 11_000 + CRON_BATCH_POLL_INTERVAL_MS,
+    });
+    expect(job.state.nextRunAtMs).toBe(11_000 + CRON_BATCH_POLL_INTERVAL_MS);
+
+    const oneShot: CronJob = {
+      ...job,
+      schedule: { kind: "at", at: "2026-01-01T00:00:00Z" },
+      state: {},
+    };
+    applyJobResult(state, oneShot, {
+      status: "skipped",
+      startedAt: 10_000,
+      endedAt: 11_000,
+      retryAtMs: 50_000,
+    });
+    expect(oneShot.enabled).toBe(true);
+    expect(oneShot.state.nextRunAtMs).toBe(50_000);
+  });
+});
@@ -0,0 +1,158 @@
+import path from "node:path";
+import { requireApiKey, resolveApiKeyForProvider } from "../../agents/model-auth.js";
+import type { EmbeddedPiRunResult } from "../../agents/pi-embedded-runner/types.js";
+import {
+  pollPromptBatch,
+  resolvePromptBatchClient,
+  submitPromptBatch,
+  supportsPromptBatch,
+  type PromptBatchClient,
+} from "../../agents/prompt-batch.js";
+import type { OpenClawConfig } from "../../config/config.js";
+import { resolveStateDir } from "../../config/paths.js";
+import { createAsyncLock, readJsonFile, writeJsonAtomic } from "../../infra/json-files.js";
+
+/** How long to wait before checking a submitted batch again. */
+export const CRON_BATCH_POLL_INTERVAL_MS = 5 * 60_000;
+
+type PendingCronBatch = {
+  provider: string;
+  model: string;
+  batchId: string;
+  submittedAtMs: number;
+};
+
+type CronBatchStoreFile = {
+  version: 1;
+  batches: Record<string, PendingCronBatch>;
+};
+
+export type CronBatchTurnResult =
+  | { state: "pending"; batchId: string; retryAtMs: number }
+  | { state: "completed"; batchId: string; result: EmbeddedPiRunResult };
+
+const withStoreLock = createAsyncLock();
+
+export function resolveCronBatchStorePath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cron", "batches.json");
+}
+
+async function loadStore(storePath: string): Promise<CronBatchStoreFile> {
+  const raw = await readJsonFile<CronBatchStoreFile>(storePath);
+  return raw?.version === 1 && raw.batches ? raw : { version: 1, batches: {} };
+}
+
+async function updateStore(
+  storePath: string,
+  jobId: string,
+  entry: PendingCronBatch | undefined,
+): Promise<void> {
+  await withStoreLock(async () => {
+    const store = await loadStore(storePath);
+    if (entry) {
+      store.batches[jobId] = entry;
+    } else {
+      delete store.batches[jobId];
+    }
+    await writeJsonAtomic(storePath, store);
+  });
+}
+
+/**
+ * Run a cron job's prompt through the provider batch API instead of a live agent turn.
+ * The first run submits the batch and records it; later runs poll it and, once finished,
+ * return the reply as a regular run result so the usual delivery path applies.
+ * Batch turns are single prompts: no tools, no transcript history.
+ */
+export async function runCronBatchTurn(params: {
+  cfg: OpenClawConfig;
+  agentDir: string;
+  jobId: string;
+  provider: string;
+  model: string;
+  prompt: string;
+  sessionId: string;
+  storePath?: string;
+  nowMs?: () => number;
+  client?: PromptBatchClient;
+}): Promise<CronBatchTurnResult> {
+  const storePath = params.storePath ?? resolveCronBatchStorePath();
+  const now = params.nowMs ?? Date.now;
+  const pending = (await loadStore(storePath)).batches[params.jobId];
+  const provider = pending?.provider ?? params.provider;
+  const model = pending?.model ?? params.model;
+  if (!supportsPromptBatch(provider)) {
+    throw new Error(`cron batch mode supports openai and anthropic models (got ${provider})`);
+  }
+  const client =
+    params.client ??
+    resolvePromptBatchClient({
+      provider,
+      cfg: params.cfg,
+      apiKey: requireApiKey(
+        await resolveApiKeyForProvider({ provider, cfg: params.cfg, agentDir: params.agentDir }),
+        provider,
+      ),
+    });
+
+  if (!pending) {
+    const batchId = await submitPromptBatch(
+      client,
+      [{ customId: params.jobId, model, prompt: params.prompt }],
+      { source: "openclaw-cron", job: params.jobId },
+    );
+    await updateStore(storePath, params.jobId, {
+      provider,
+      model,
+      batchId,
+      submittedAtMs: now(),
+    });
+    return { state: "pending", batchId, retryAtMs: now() + CRON_BATCH_POLL_INTERVAL_MS };
+  }
+
+  const status = await pollPromptBatch(client, pending.batchId);
+  if (status.state === "pending") {
+    return {
+      state: "pending",
+      batchId: pending.batchId,
+      retryAtMs: now() + CRON_BATCH_POLL_INTERVAL_MS,
+    };
+  }
+  // Finished either way: the next scheduled run submits a fresh batch.
+  await updateStore(storePath, params.jobId, undefined);
+  if (status.state === "failed") {
+    throw new Error(status.error);
+  }
+  const entry =
+    status.results.find((result) => result.customId === params.jobId) ?? status.results[0];
+  if (!entry || entry.error) {
+    throw new Error(
+      `${provider} batch ${pending.batchId} failed: ${entry?.error ?? "no result for job"}`,
+    );
+  }
+  const usage = entry.usage;
+  return {
+    state: "completed",
+    batchId: pending.batchId,
+    result: {
+      payloads: entry.text ? [{ text: entry.text }] : [],
+      meta: {
+        durationMs: Math.max(0, now() - pending.submittedAtMs),
+        agentMeta: {
+          sessionId: params.sessionId,
+          provider,
+          model,
+          ...(usage
+            ? {
+                usage: {
+                  input: usage.input,
+                  output: usage.output,
+                  total: usage.input + usage.output,
+                },
+              }
+            : {}),
+        },
+      },
+    },
+  };
+}
@@ -56,6 +56,7 @@ import {
 } from "../../security/external-content.js";
 import { resolveCronDeliveryPlan } from "../delivery.js";
 import type { CronJob, CronRunOutcome, CronRunTelemetry } from "../types.js";
+import { runCronBatchTurn } from "./batch.js";
 import { resolveDeliveryTarget } from "./delivery-target.js";
 import {
   isHeartbeatOnlyResponse,
@@ -256,6 +257,22 @@ export async function runCronIsolatedAgentTurn(params: {
       hooksGmailModelApplied = true;
     }
   }
+  const cronRouteRaw = cfgWithAgentDefaults.agents?.defaults?.modelRouting?.cron?.trim();
+  if (!hooksGmailModelApplied && cronRouteRaw) {
+    const resolvedRoute = resolveAllowedModelRef({
+      cfg: cfgWithAgentDefaults,
+      catalog: await loadCatalog(),
+      raw: cronRouteRaw,
+      defaultProvider: resolvedDefault.provider,
+      defaultModel: resolvedDefault.model,
+    });
+    if ("error" in resolvedRoute) {
+      logWarn(`[cron:${params.job.id}] ignoring modelRouting.cron: ${resolvedRoute.error}`);
+    } else {
+      provider = resolvedRoute.ref.provider;
+      model = resolvedRoute.ref.model;
+    }
+  }
   const modelOverrideRaw =
     params.job.payload.kind === "agentTurn" ? params.job.payload.model : undefined;
   const modelOverride = typeof modelOverrideRaw === "string" ? modelOverrideRaw.trim() : undefined;
@@ -459,6 +476,7 @@ export async function runCronIsolatedAgentTurn(params: {
   });
   const authProfileIdSource = cronSession.sessionEntry.authProfileOverrideSource;
 
+  const batchMode = params.job.payload.kind === "agentTurn" && params.job.payload.batch === true;
   let runResult: Awaited<ReturnType<typeof runEmbeddedPiAgent>>;
   let fallbackProvider = provider;
   let fallbackModel = model;
@@ -474,66 +492,87 @@ export async function runCronIsolatedAgentTurn(params: {
       sessionKey: agentSessionKey,
       verboseLevel: resolvedVerboseLevel,
     });
-    const messageChannel = resolvedDelivery.channel;
-    const fallbackResult = await runWithModelFallback({
-      cfg: cfgWithAgentDefaults,
-      provider,
-      model,
-      agentDir,
-      fallbacksOverride: resolveAgentModelFallbacksOverride(params.cfg, agentId),
-      run: (providerOverride, modelOverride) => {
-        if (abortSignal?.aborted) {
-          throw new Error(abortReason());
-        }
-        if (isCliProvider(providerOverride, cfgWithAgentDefaults)) {
-          const cliSessionId = getCliSessionId(cronSession.sessionEntry, providerOverride);
-          return runCliAgent({
+    if (batchMode) {
+      const batch = await runCronBatchTurn({
+        cfg: cfgWithAgentDefaults,
+        agentDir,
+        jobId: params.job.id,
+        provider,
+        model,
+        prompt: commandBody,
+        sessionId: cronSession.sessionEntry.sessionId,
+      });
+      if (batch.state === "pending") {
+        return withRunSession({
+          status: "skipped",
+          error: `provider batch ${batch.batchId} pending`,
+          retryAtMs: batch.retryAtMs,
+        });
+      }
+      runResult = batch.result;
+      runEndedAt = Date.now();
+    } else {
+      const messageChannel = resolvedDelivery.channel;
+      const fallbackResult = await runWithModelFallback({
+        cfg: cfgWithAgentDefaults,
+        provider,
+        model,
+        agentDir,
+        fallbacksOverride: resolveAgentModelFallbacksOverride(params.cfg, agentId),
+        run: (providerOverride, modelOverride) => {
+          if (abortSignal?.aborted) {
+            throw new Error(abortReason());
+          }
+          if (isCliProvider(providerOverride, cfgWithAgentDefaults)) {
+            const cliSessionId = getCliSessionId(cronSession.sessionEntry, providerOverride);
+            return runCliAgent({
+              sessionId: cronSession.sessionEntry.sessionId,
+              sessionKey: agentSessionKey,
+              agentId,
+              sessionFile,
+              workspaceDir,
+              config: cfgWithAgentDefaults,
+              prompt: commandBody,
+              provider: providerOverride,
+              model: modelOverride,
+              thinkLevel,
+              timeoutMs,
+              runId: cronSession.sessionEntry.sessionId,
+              cliSessionId,
+            });
+          }
+          return runEmbeddedPiAgent({
             sessionId: cronSession.sessionEntry.sessionId,
             sessionKey: agentSessionKey,
             agentId,
+            messageChannel,
+            agentAccountId: resolvedDelivery.accountId,
             sessionFile,
+            agentDir,
             workspaceDir,
             config: cfgWithAgentDefaults,
+            skillsSnapshot,
             prompt: commandBody,
+            lane: params.lane ?? "cron",
             provider: providerOverride,
             model: modelOverride,
+            authProfileId,
+            authProfileIdSource,
             thinkLevel,
+            verboseLevel: resolvedVerboseLevel,
             timeoutMs,
             runId: cronSession.sessionEntry.sessionId,
-            cliSessionId,
+            requireExplicitMessageTarget: true,
+            disableMessageTool: deliveryRequested,
+            abortSignal,
           });
-        }
-        return runEmbeddedPiAgent({
-          sessionId: cronSession.sessionEntry.sessionId,
-          sessionKey: agentSessionKey,
-          agentId,
-          messageChannel,
-          agentAccountId: resolvedDelivery.accountId,
-          sessionFile,
-          agentDir,
-          workspaceDir,
-          config: cfgWithAgentDefaults,
-          skillsSnapshot,
-          prompt: commandBody,
-          lane: params.lane ?? "cron",
-          provider: providerOverride,
-          model: modelOverride,
-          authProfileId,
-          authProfileIdSource,
-          thinkLevel,
-          verboseLevel: resolvedVerboseLevel,
-          timeoutMs,
-          runId: cronSession.sessionEntry.sessionId,
-          requireExplicitMessageTarget: true,
-          disableMessageTool: deliveryRequested,
-          abortSignal,
-        });
-      },
-    });
-    runResult = fallbackResult.result;
-    fallbackProvider = fallbackResult.provider;
-    fallbackModel = fallbackResult.model;
-    runEndedAt = Date.now();
+        },
+      });
+      runResult = fallbackResult.result;
+      fallbackProvider = fallbackResult.provider;
+      fallbackModel = fallbackResult.model;
+      runEndedAt = Date.now();
+    }
   } catch (err) {
     return withRunSession({ status: "error", error: String(err) });
   }
@@ -699,6 +738,7 @@ export async function runCronIsolatedAgentTurn(params: {
             agentId,
             identity,
             bestEffort: deliveryBestEffort,
+            notification: { kind: "cron" },
             deps: createOutboundSendDeps(params.deps),
             abortSignal,
           });
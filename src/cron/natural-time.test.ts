@@ -0,0 +1,44 @@
+import { describe, expect, it } from "vitest";
+import { parseNaturalTime } from "./natural-time.js";
+
+// Friday 2026-10-16, 12:00 in Berlin (CEST, UTC+2).
+const nowMs = Date.parse("2026-10-16T10:00:00Z");
+const at = (raw: string, timeZone = "Europe/Berlin") => {
+  const ms = parseNaturalTime(raw, { timeZone, nowMs });
+  return ms === null ? null : new Date(ms).toISOString();
+};
+
+describe("parseNaturalTime", () => {
+  it("handles relative delays", () => {
+    expect(at("in 20 minutes")).toBe("2026-10-16T10:20:00.000Z");
+    expect(at("2h")).toBe("2026-10-16T12:00:00.000Z");
+    expect(at("in an hour")).toBe("2026-10-16T11:00:00.000Z");
+    expect(at("half an hour")).toBe("2026-10-16T10:30:00.000Z");
+  });
+
+  it("resolves clock times and days in the given timezone", () => {
+    expect(at("6pm")).toBe("2026-10-16T16:00:00.000Z");
+    expect(at("9am")).toBe("2026-10-17T07:00:00.000Z");
+    expect(at("9am", "America/New_York")).toBe("2026-10-16T13:00:00.000Z");
+    expect(at("tomorrow 9am")).toBe("2026-10-17T07:00:00.000Z");
+    expect(at("9am tomorrow")).toBe("2026-10-17T07:00:00.000Z");
+    expect(at("tomorrow morning")).toBe("2026-10-17T07:00:00.000Z");
+    expect(at("tonight")).toBe("2026-10-16T18:00:00.000Z");
+    expect(at("on monday at 7:15")).toBe("2026-10-19T05:15:00.000Z");
+    expect(at("friday 8pm")).toBe("2026-10-16T18:00:00.000Z");
+    expect(at("friday 11am")).toBe("2026-10-23T09:00:00.000Z");
+    expect(at("next friday at 8:30pm")).toBe("2026-10-23T18:30:00.000Z");
+  });
+
+  it("reads local dates across DST changes and absolute ISO times", () => {
+    expect(at("2026-10-25 09:00")).toBe("2026-10-25T08:00:00.000Z");
+    expect(at("2026-10-24")).toBe("2026-10-24T07:00:00.000Z");
+    expect(at("2026-03-01T09:00:00Z")).toBe("2026-03-01T09:00:00.000Z");
+  });
+
+  it("rejects ambiguous or unknown input", () => {
+    expect(at("at 5")).toBeNull();
+    expect(at("soon")).toBeNull();
+    expect(at("in 2 months")).toBeNull();
+  });
+});
@@ -0,0 +1,198 @@
+import { parseAbsoluteTimeMs } from "./parse.js";
+
+type WallClock = { year: number; month: number; day: number; hour: number; minute: number };
+
+const UNIT_MS: Record<string, number> = {
+  s: 1000,
+  m: 60_000,
+  h: 3_600_000,
+  d: 86_400_000,
+  w: 604_800_000,
+};
+
+const WEEKDAYS = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+const DAY_PART_TIMES: Record<string, { hour: number; minute: number }> = {
+  morning: { hour: 9, minute: 0 },
+  noon: { hour: 12, minute: 0 },
+  afternoon: { hour: 15, minute: 0 },
+  evening: { hour: 18, minute: 0 },
+  tonight: { hour: 20, minute: 0 },
+  night: { hour: 21, minute: 0 },
+  midnight: { hour: 0, minute: 0 },
+};
+
+const DEFAULT_DAY_TIME = { hour: 9, minute: 0 };
+
+const RELATIVE_RE =
+  /^(?:in\s+)?(\d+(?:\.\d+)?|an?|half an?)\s*(s|secs?|seconds?|m|mins?|minutes?|h|hrs?|hours?|d|days?|w|weeks?)(?:\s+from\s+now)?$/;
+const DAY_RE =
+  /\b(today|tomorrow|tonight|(?:next\s+)?(?:sun|mon|tues?|wed(?:nes)?|thu(?:rs?)?|fri|sat(?:ur)?)(?:day)?)\b/;
+const CLOCK_RE = /^(\d{1,2})(?::(\d{2}))?\s*(am|pm|a\.m\.|p\.m\.)?$/;
+const LOCAL_ISO_RE = /^(\d{4})-(\d{2})-(\d{2})(?:[t\s](\d{2}):(\d{2}))?$/;
+
+function wallClockAt(ms: number, timeZone: string): WallClock & { weekday: number } {
+  const parts = new Intl.DateTimeFormat("en-US", {
+    timeZone,
+    year: "numeric",
+    month: "2-digit",
+    day: "2-digit",
+    hour: "2-digit",
+    minute: "2-digit",
+    weekday: "short",
+    hourCycle: "h23",
+  }).formatToParts(new Date(ms));
+  const map: Record<string, string> = {};
+  for (const part of parts) {
+    if (part.type !== "literal") {
+      map[part.type] = part.value;
+    }
+  }
+  return {
+    year: Number(map.year),
+    month: Number(map.month),
+    day: Number(map.day),
+    hour: Number(map.hour),
+    minute: Number(map.minute),
+    weekday: WEEKDAYS.indexOf((map.weekday ?? "").slice(0, 3).toLowerCase()),
+  };
+}
+
+/** Wall-clock time in `timeZone` to epoch ms; corrects twice so DST transitions settle. */
+export function zonedWallTimeToMs(wall: WallClock, timeZone: string): number {
+  const target = Date.UTC(wall.year, wall.month - 1, wall.day, wall.hour, wall.minute);
+  let guess = target;
+  for (let i = 0; i < 2; i += 1) {
+    const { year, month, day, hour, minute } = wallClockAt(guess, timeZone);
+    guess -= Date.UTC(year, month - 1, day, hour, minute) - target;
+  }
+  return guess;
+}
+
+function addDays(wall: WallClock, days: number): WallClock {
+  const date = new Date(Date.UTC(wall.year, wall.month - 1, wall.day + days));
+  return {
+    ...wall,
+    year: date.getUTCFullYear(),
+    month: date.getUTCMonth() + 1,
+    day: date.getUTCDate(),
+  };
+}
+
+function parseClock(raw: string): { hour: number; minute: number } | null {
+  const text = raw.replace(/^(?:at|@)\s*/, "").trim();
+  if (!text) {
+    return null;
+  }
+  const named = DAY_PART_TIMES[text.replace(/^(?:the|this)\s+/, "")];
+  if (named) {
+    return named;
+  }
+  const match = CLOCK_RE.exec(text);
+  if (!match) {
+    return null;
+  }
+  let hour = Number(match[1]);
+  const minute = Number(match[2] ?? 0);
+  const meridiem = match[3]?.replaceAll(".", "");
+  if (meridiem) {
+    if (hour < 1 || hour > 12) {
+      return null;
+    }
+    hour = (hour % 12) + (meridiem === "pm" ? 12 : 0);
+  } else if (!match[2]) {
+    // A bare number like "at 5" is ambiguous; require am/pm or hh:mm.
+    return null;
+  }
+  return hour <= 23 && minute <= 59 ? { hour, minute } : null;
+}
+
+function parseRelative(text: string, nowMs: number): number | null {
+  const match = RELATIVE_RE.exec(text);
+  if (!match) {
+    return null;
+  }
+  const amountRaw = match[1];
+  let amount = Number(amountRaw);
+  if (amountRaw.startsWith("half")) {
+    amount = 0.5;
+  } else if (/^an?$/.test(amountRaw)) {
+    amount = 1;
+  }
+  const unit = match[2].startsWith("mi") || match[2] === "m" ? "m" : match[2].charAt(0);
+  const unitMs = UNIT_MS[unit];
+  return unitMs && amount > 0 ? nowMs + Math.round(amount * unitMs) : null;
+}
+
+/**
+ * Resolves a natural time ("in 20 minutes", "6pm", "tomorrow 9am", "next friday at 8:30pm",
+ * "2026-03-01 09:00") against `timeZone`. Times without a day roll over to tomorrow once past.
+ * Returns null when the text is not understood.
+ */
+export function parseNaturalTime(
+  raw: string,
+  opts: { timeZone: string; nowMs?: number },
+): number | null {
+  const nowMs = opts.nowMs ?? Date.now();
+  const text = raw.trim().toLowerCase().replace(/\s+/g, " ");
+  if (!text) {
+    return null;
+  }
+  const relative = parseRelative(text, nowMs);
+  if (relative !== null) {
+    return relative;
+  }
+  const localIso = LOCAL_ISO_RE.exec(text);
+  if (localIso) {
+    return zonedWallTimeToMs(
+      {
+        year: Number(localIso[1]),
+        month: Number(localIso[2]),
+        day: Number(localIso[3]),
+        hour: Number(localIso[4] ?? DEFAULT_DAY_TIME.hour),
+        minute: Number(localIso[5] ?? DEFAULT_DAY_TIME.minute),
+      },
+      opts.timeZone,
+    );
+  }
+  if (/^\d{4}-\d{2}-\d{2}t/.test(text)) {
+    return parseAbsoluteTimeMs(raw);
+  }
+
+  const now = wallClockAt(nowMs, opts.timeZone);
+  const dayMatch = DAY_RE.exec(text);
+  const dayToken = dayMatch?.[1];
+  const rest = (dayMatch ? text.replace(dayMatch[0], " ") : text)
+    .replace(/\b(?:on|at)\s*$/, "")
+    .replace(/^\s*on\b/, "")
+    .replace(/\s+/g, " ")
+    .trim();
+  const clock = rest ? parseClock(rest) : null;
+  if (rest && !clock) {
+    return null;
+  }
+  if (!dayToken && !clock) {
+    return null;
+  }
+
+  let days = 0;
+  if (dayToken === "tomorrow") {
+    days = 1;
+  } else if (dayToken && dayToken !== "today" && dayToken !== "tonight") {
+    const isNext = dayToken.startsWith("next ");
+    const weekday = WEEKDAYS.indexOf(dayToken.replace(/^next /, "").slice(0, 3));
+    days = (weekday - now.weekday + 7) % 7;
+    if (days === 0 && isNext) {
+      days = 7;
+    }
+  }
+  const time = clock ?? (dayToken === "tonight" ? DAY_PART_TIMES.tonight : DEFAULT_DAY_TIME);
+  let resolved = zonedWallTimeToMs({ ...addDays(now, days), ...time }, opts.timeZone);
+  if (resolved <= nowMs && (!dayToken || /^(sun|mon|tue|wed|thu|fri|sat)/.test(dayToken))) {
+    resolved = zonedWallTimeToMs(
+      { ...addDays(now, days + (dayToken ? 7 : 1)), ...time },
+      opts.timeZone,
+    );
+  }
+  return resolved;
+}
@@ -150,6 +150,9 @@ function coercePayload(payload: UnknownRecord) {
   ) {
     delete next.allowUnsafeExternalContent;
   }
+  if ("batch" in next && typeof next.batch !== "boolean") {
+    delete next.batch;
+  }
   return next;
 }
 
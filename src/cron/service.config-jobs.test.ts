@@ -0,0 +1,96 @@
+import { describe, expect, it, vi } from "vitest";
+import type { CronConfig } from "../config/types.cron.js";
+import { CronService } from "./service.js";
+import {
+  createCronStoreHarness,
+  createNoopLogger,
+  installCronTestHooks,
+} from "./service.test-harness.js";
+
+const logger = createNoopLogger();
+const { makeStorePath } = createCronStoreHarness({ prefix: "openclaw-cron-config-jobs-" });
+installCronTestHooks({ logger });
+
+function createCronService(storePath: string, cronConfig: CronConfig) {
+  return new CronService({
+    storePath,
+    cronEnabled: true,
+    cronConfig,
+    log: logger,
+    enqueueSystemEvent: vi.fn(),
+    requestHeartbeatNow: vi.fn(),
+    runIsolatedAgentJob: vi.fn(async () => ({ status: "ok" as const })),
+  });
+}
+
+const gardenJob = {
+  name: "Garden report",
+  schedule: { kind: "cron", expr: "0 7 * * *", tz: "Europe/Berlin" },
+  sessionTarget: "isolated",
+  payload: { kind: "agentTurn", message: "Photograph the garden and report soil moisture." },
+  delivery: { mode: "announce", channel: "telegram", to: "123" },
+};
+
+describe("CronService config jobs", () => {
+  it("adds config jobs with stable ids and keeps them across restarts", async () => {
+    const { storePath } = await makeStorePath();
+    const first = createCronService(storePath, { jobs: [gardenJob] });
+    await first.start();
+    const [job] = await first.list({ includeDisabled: true });
+    first.stop();
+
+    expect(job.id).toBe("config:garden-report");
+    expect(job.payload).toMatchObject({ kind: "agentTurn" });
+    expect(job.state.nextRunAtMs).toBeTypeOf("number");
+
+    const second = createCronService(storePath, { jobs: [gardenJob] });
+    await second.start();
+    const jobs = await second.list({ includeDisabled: true });
+    second.stop();
+
+    expect(jobs).toHaveLength(1);
+    expect(jobs[0].updatedAtMs).toBe(job.updatedAtMs);
+  });
+
+  it("applies config edits and removes jobs dropped from config", async () => {
+    const { storePath } = await makeStorePath();
+    const first = createCronService(storePath, {
+      jobs: [gardenJob, { ...gardenJob, name: "Old job" }],
+    });
+    await first.start();
+    const manual = await first.add({
+      name: "manual",
+      enabled: true,
+      schedule: { kind: "every", everyMs: 60_000 },
+      sessionTarget: "main",
+      wakeMode: "next-heartbeat",
+      payload: { kind: "systemEvent", text: "ping" },
+    });
+    first.stop();
+
+    const second = createCronService(storePath, {
+      jobs: [{ ...gardenJob, payload: { kind: "agentTurn", message: "Water check." } }],
+    });
+    await second.start();
+    const jobs = await second.list({ includeDisabled: true });
+    second.stop();
+
+    expect(jobs.map((job) => job.id).toSorted()).toEqual(["config:garden-report", manual.id]);
+    expect(jobs.find((job) => job.id === "config:garden-report")?.payload).toMatchObject({
+      message: "Water check.",
+    });
+  });
+
+  it("skips invalid and duplicate entries", async () => {
+    const { storePath } = await makeStorePath();
+    const cron = createCronService(storePath, {
+      jobs: [gardenJob, gardenJob, { name: "broken" }],
+    });
+    await cron.start();
+    const jobs = await cron.list({ includeDisabled: true });
+    cron.stop();
+
+    expect(jobs.map((job) => job.id)).toEqual(["config:garden-report"]);
+    expect(logger.warn).toHaveBeenCalledTimes(2);
+  });
+});
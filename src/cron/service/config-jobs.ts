@@ -0,0 +1,117 @@
+import { normalizeCronJobCreate } from "../normalize.js";
+import type { CronJob } from "../types.js";
+import { computeJobNextRunAtMs, createJob } from "./jobs.js";
+import type { CronServiceState } from "./state.js";
+
+/** Jobs declared under `cron.jobs` are stored with this id prefix so they can be reconciled. */
+export const CONFIG_JOB_ID_PREFIX = "config:";
+
+const SPEC_FIELDS = [
+  "agentId",
+  "sessionKey",
+  "name",
+  "description",
+  "enabled",
+  "deleteAfterRun",
+  "schedule",
+  "sessionTarget",
+  "wakeMode",
+  "payload",
+  "delivery",
+] as const satisfies ReadonlyArray<keyof CronJob>;
+
+export function resolveConfigJobId(name: string): string {
+  const slug = name
+    .trim()
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, "-")
+    .replace(/^-+|-+$/g, "");
+  return `${CONFIG_JOB_ID_PREFIX}${slug || "job"}`;
+}
+
+function specOf(job: CronJob) {
+  return JSON.stringify(SPEC_FIELDS.map((field) => job[field] ?? null));
+}
+
+/**
+ * Reconcile `cron.jobs` from config into the store: config is the source of truth for these
+ * jobs, so edits made through the tool/CLI are replaced on the next start and jobs removed from
+ * config are deleted. Run state (last run, errors) is kept when the spec is unchanged.
+ */
+export function syncConfigJobs(state: CronServiceState): boolean {
+  const store = state.store;
+  const entries = state.deps.cronConfig?.jobs;
+  if (!store || (!entries && !store.jobs.some((j) => j.id.startsWith(CONFIG_JOB_ID_PREFIX)))) {
+    return false;
+  }
+  const now = state.deps.nowMs();
+  const desired = new Map<string, { job: CronJob; explicitAnchor: boolean }>();
+  for (const [index, entry] of (entries ?? []).entries()) {
+    try {
+      const input = normalizeCronJobCreate(entry);
+      if (!input) {
+        throw new Error("invalid job");
+      }
+      const job = createJob(state, input);
+      job.id = resolveConfigJobId(job.name);
+      if (desired.has(job.id)) {
+        throw new Error(`duplicate job name "${job.name}"`);
+      }
+      desired.set(job.id, {
+        job,
+        explicitAnchor: input.schedule.kind === "every" && input.schedule.anchorMs !== undefined,
+      });
+    } catch (err) {
+      state.deps.log.warn(
+        { index, err: String(err) },
+        "cron: skipping invalid cron.jobs entry from config",
+      );
+    }
+  }
+
+  let changed = false;
+  const kept: CronJob[] = [];
+  for (const job of store.jobs) {
+    if (!job.id.startsWith(CONFIG_JOB_ID_PREFIX)) {
+      kept.push(job);
+      continue;
+    }
+    const entry = desired.get(job.id);
+    desired.delete(job.id);
+    if (!entry) {
+      state.deps.log.info({ jobId: job.id }, "cron: removing job no longer in config");
+      changed = true;
+      continue;
+    }
+    const next = entry.job;
+    if (
+      !entry.explicitAnchor &&
+      next.schedule.kind === "every" &&
+      job.schedule.kind === "every" &&
+      next.schedule.everyMs === job.schedule.everyMs
+    ) {
+      // Keep the stored anchor so restarts do not shift "every" jobs.
+      next.schedule = { ...next.schedule, anchorMs: job.schedule.anchorMs };
+    }
+    if (specOf(job) === specOf(next)) {
+      kept.push(job);
+      continue;
+    }
+    for (const field of SPEC_FIELDS) {
+      (job as unknown as Record<string, unknown>)[field] = next[field];
+    }
+    job.updatedAtMs = now;
+    job.state.scheduleErrorCount = undefined;
+    job.state.nextRunAtMs = job.enabled ? computeJobNextRunAtMs(job, now) : undefined;
+    state.deps.log.info({ jobId: job.id }, "cron: updated job from config");
+    kept.push(job);
+    changed = true;
+  }
+  for (const { job } of desired.values()) {
+    state.deps.log.info({ jobId: job.id }, "cron: added job from config");
+    kept.push(job);
+    changed = true;
+  }
+  store.jobs = kept;
+  return changed;
+}
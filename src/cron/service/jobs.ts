@@ -501,6 +501,9 @@ function mergeCronPayload(existing: CronPayload, patch: CronPayloadPatch): CronP
   if (typeof patch.allowUnsafeExternalContent === "boolean") {
     next.allowUnsafeExternalContent = patch.allowUnsafeExternalContent;
   }
+  if (typeof patch.batch === "boolean") {
+    next.batch = patch.batch;
+  }
   if (typeof patch.deliver === "boolean") {
     next.deliver = patch.deliver;
   }
@@ -576,6 +579,7 @@ function buildPayloadFromPatch(patch: CronPayloadPatch): CronPayload {
     thinking: patch.thinking,
     timeoutSeconds: patch.timeoutSeconds,
     allowUnsafeExternalContent: patch.allowUnsafeExternalContent,
+    batch: patch.batch,
     deliver: patch.deliver,
     channel: patch.channel,
     to: patch.to,
@@ -1,4 +1,5 @@
 import type { CronJob, CronJobCreate, CronJobPatch } from "../types.js";
+import { syncConfigJobs } from "./config-jobs.js";
 import {
   applyJobPatch,
   computeJobNextRunAtMs,
@@ -106,6 +107,7 @@ export async function start(state: CronServiceState) {
         startupInterruptedJobIds.add(job.id);
       }
     }
+    syncConfigJobs(state);
     await persist(state);
   });
 
@@ -142,6 +142,7 @@ export function applyJobResult(
     delivered?: boolean;
     startedAt: number;
     endedAt: number;
+    retryAtMs?: number;
   },
 ): boolean {
   job.state.runningAtMs = undefined;
@@ -167,8 +168,20 @@ export function applyJobResult(
   const shouldDelete =
     job.schedule.kind === "at" && job.deleteAfterRun === true && result.status === "ok";
 
+  const retryAtMs =
+    result.status === "skipped" && typeof result.retryAtMs === "number"
+      ? result.retryAtMs
+      : undefined;
+
   if (!shouldDelete) {
-    if (job.schedule.kind === "at") {
+    if (retryAtMs !== undefined && job.enabled) {
+      // Deferred work (e.g. a provider batch still processing): come back at retryAtMs, or
+      // earlier if the schedule fires first. One-shot jobs stay enabled until it completes.
+      const naturalNext =
+        job.schedule.kind === "at" ? undefined : computeJobNextRunAtMs(job, result.endedAt);
+      job.state.nextRunAtMs =
+        naturalNext !== undefined ? Math.min(naturalNext, retryAtMs) : retryAtMs;
+    } else if (job.schedule.kind === "at") {
       // One-shot jobs are always disabled after ANY terminal status
       // (ok, error, or skipped). This prevents tight-loop rescheduling
       // when computeJobNextRunAtMs returns the past atMs value (#11452).
@@ -240,6 +253,7 @@ function applyOutcomeToStoredJob(state: CronServiceState, result: TimedCronRunOu
     delivered: result.delivered,
     startedAt: result.startedAt,
     endedAt: result.endedAt,
+    retryAtMs: result.retryAtMs,
   });
 
   emitJobFinished(state, job, result, result.startedAt);
@@ -764,6 +778,7 @@ export async function executeJobCore(
     delivered: res.delivered,
     sessionId: res.sessionId,
     sessionKey: res.sessionKey,
+    retryAtMs: res.retryAtMs,
     model: res.model,
     provider: res.provider,
     usage: res.usage,
@@ -806,6 +821,7 @@ export async function executeJob(
     delivered: coreResult.delivered,
     startedAt,
     endedAt,
+    retryAtMs: coreResult.retryAtMs,
   });
 
   emitJobFinished(state, job, coreResult, startedAt);
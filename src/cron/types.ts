@@ -52,6 +52,11 @@ export type CronRunOutcome = {
   summary?: string;
   sessionId?: string;
   sessionKey?: string;
+  /**
+   * Deferred work (provider batch still processing): run the job again at this time instead
+   * of waiting for the next scheduled run. Only honored with status "skipped".
+   */
+  retryAtMs?: number;
 };
 
 export type CronPayload =
@@ -64,6 +69,8 @@ export type CronPayload =
       thinking?: string;
       timeoutSeconds?: number;
       allowUnsafeExternalContent?: boolean;
+      /** Run the prompt through the provider batch API (no tools; lower cost, delayed result). */
+      batch?: boolean;
       deliver?: boolean;
       channel?: CronMessageChannel;
       to?: string;
@@ -79,6 +86,7 @@ export type CronPayloadPatch =
       thinking?: string;
       timeoutSeconds?: number;
       allowUnsafeExternalContent?: boolean;
+      batch?: boolean;
       deliver?: boolean;
       channel?: CronMessageChannel;
       to?: string;
@@ -54,6 +54,8 @@ export type DiscordComponentButtonSpec = {
   disabled?: boolean;
   /** Optional allowlist of users who can interact with this button (ids or names). */
   allowedUsers?: string[];
+  /** Text sent to the agent on click instead of the generic "Clicked" event (quick replies). */
+  value?: string;
 };
 
 export type DiscordComponentSelectOption = {
@@ -164,6 +166,7 @@ export type DiscordComponentEntry = {
   accountId?: string;
   reusable?: boolean;
   allowedUsers?: string[];
+  value?: string;
   messageId?: string;
   createdAt?: number;
   expiresAt?: number;
@@ -716,6 +719,7 @@ function createButtonComponent(params: {
       label: params.spec.label,
       modalId: params.modalId,
       allowedUsers: params.spec.allowedUsers,
+      value: params.spec.value,
     },
   };
 }
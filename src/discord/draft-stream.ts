@@ -1,4 +1,4 @@
-import type { RequestClient } from "@buape/carbon";
+import { RateLimitError, type RequestClient } from "@buape/carbon";
 import { Routes } from "discord-api-types/v10";
 import { createFinalizableDraftLifecycle } from "../channels/draft-stream-controls.js";
 
@@ -68,6 +68,7 @@ export function createDiscordDraftStream(params: {
       }
     }
 
+    const previousText = lastSentText;
     lastSentText = trimmed;
     try {
       if (streamMessageId !== undefined) {
@@ -97,6 +98,14 @@ export function createDiscordDraftStream(params: {
       streamMessageId = sentMessageId;
       return true;
     } catch (err) {
+      // Rate limited: keep the preview and retry once Discord's retry_after has passed.
+      if (err instanceof RateLimitError && !streamState.final) {
+        const retryAfterMs = Math.max(0, err.retryAfter * 1000);
+        lastSentText = previousText;
+        loop.backoff(retryAfterMs);
+        params.log?.(`discord stream preview rate limited; retrying in ${retryAfterMs}ms`);
+        return false;
+      }
       streamState.stopped = true;
       params.warn?.(
         `discord stream preview failed: ${err instanceof Error ? err.message : String(err)}`,
@@ -0,0 +1,35 @@
+import { normalizeInteractiveReply, type InteractiveReply } from "../channels/interactive.js";
+import type { DiscordComponentBlock, DiscordComponentMessageSpec } from "./components.js";
+
+// Discord allows 5 buttons per action row; labels are capped at 80 characters.
+const MAX_BUTTONS_PER_ROW = 5;
+const MAX_ACTION_ROWS = 5;
+const MAX_BUTTON_LABEL = 80;
+
+/** Map interactive buttons to a component message; each click sends the button value back. */
+export function buildDiscordInteractiveSpec(
+  reply: InteractiveReply | undefined,
+  text?: string,
+): DiscordComponentMessageSpec | undefined {
+  const normalized = normalizeInteractiveReply(reply);
+  if (!normalized) {
+    return undefined;
+  }
+  const blocks: DiscordComponentBlock[] = [];
+  for (const row of normalized.rows) {
+    for (let i = 0; i < row.length; i += MAX_BUTTONS_PER_ROW) {
+      blocks.push({
+        type: "actions",
+        buttons: row.slice(i, i + MAX_BUTTONS_PER_ROW).map((button) => ({
+          label: button.label.slice(0, MAX_BUTTON_LABEL),
+          style: button.style ?? "secondary",
+          value: button.value,
+        })),
+      });
+    }
+  }
+  return {
+    ...(text?.trim() ? { text } : {}),
+    blocks: blocks.slice(0, MAX_ACTION_ROWS),
+  };
+}
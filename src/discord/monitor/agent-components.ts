@@ -1021,11 +1021,15 @@ async function handleDiscordComponentEvent(params: {
   }
 
   const values = params.values ? mapSelectValues(consumed, params.values) : undefined;
-  const eventText = formatDiscordComponentEventText({
-    kind: consumed.kind === "select" ? "select" : "button",
-    label: consumed.label,
-    values,
-  });
+  // Quick-reply buttons carry the text to send, e.g. `/approve <id> allow-once`.
+  const eventText =
+    consumed.kind === "button" && consumed.value
+      ? consumed.value
+      : formatDiscordComponentEventText({
+          kind: consumed.kind === "select" ? "select" : "button",
+          label: consumed.label,
+          values,
+        });
 
   try {
     await params.interaction.reply({ content: "✓", ...replyOpts });
@@ -9,8 +9,10 @@ import {
 const sendMessageDiscordMock = vi.hoisted(() => vi.fn());
 const sendVoiceMessageDiscordMock = vi.hoisted(() => vi.fn());
 const sendWebhookMessageDiscordMock = vi.hoisted(() => vi.fn());
+const sendDiscordComponentMessageMock = vi.hoisted(() => vi.fn());
 
 vi.mock("../send.js", () => ({
+  sendDiscordComponentMessage: (...args: unknown[]) => sendDiscordComponentMessageMock(...args),
   sendMessageDiscord: (...args: unknown[]) => sendMessageDiscordMock(...args),
   sendVoiceMessageDiscord: (...args: unknown[]) => sendVoiceMessageDiscordMock(...args),
   sendWebhookMessageDiscord: (...args: unknown[]) => sendWebhookMessageDiscordMock(...args),
@@ -62,6 +64,10 @@ describe("deliverDiscordReply", () => {
       messageId: "webhook-1",
       channelId: "thread-1",
     });
+    sendDiscordComponentMessageMock.mockClear().mockResolvedValue({
+      messageId: "components-1",
+      channelId: "channel-1",
+    });
     threadBindingTesting.resetThreadBindingsForTests();
   });
 
@@ -239,4 +245,38 @@ describe("deliverDiscordReply", () => {
       expect.objectContaining({ token: "token", accountId: "default" }),
     );
   });
+
+  it("sends interactive replies as a component message with value buttons", async () => {
+    await deliverDiscordReply({
+      replies: [
+        {
+          text: "Approve?",
+          interactive: {
+            rows: [[{ label: "Allow", value: "/approve abc allow-once", style: "success" }]],
+          },
+        },
+      ],
+      target: "channel:123",
+      token: "token",
+      accountId: "default",
+      runtime,
+      textLimit: 2000,
+      replyToId: "reply-1",
+    });
+
+    expect(sendMessageDiscordMock).not.toHaveBeenCalled();
+    expect(sendDiscordComponentMessageMock).toHaveBeenCalledWith(
+      "channel:123",
+      {
+        text: "Approve?",
+        blocks: [
+          {
+            type: "actions",
+            buttons: [{ label: "Allow", style: "success", value: "/approve abc allow-once" }],
+          },
+        ],
+      },
+      expect.objectContaining({ token: "token", accountId: "default", replyTo: "reply-1" }),
+    );
+  });
 });
@@ -7,7 +7,13 @@ import type { MarkdownTableMode, ReplyToMode } from "../../config/types.base.js"
 import { convertMarkdownTables } from "../../markdown/tables.js";
 import type { RuntimeEnv } from "../../runtime.js";
 import { chunkDiscordTextWithMode } from "../chunk.js";
-import { sendMessageDiscord, sendVoiceMessageDiscord, sendWebhookMessageDiscord } from "../send.js";
+import { buildDiscordInteractiveSpec } from "../interactive.js";
+import {
+  sendDiscordComponentMessage,
+  sendMessageDiscord,
+  sendVoiceMessageDiscord,
+  sendWebhookMessageDiscord,
+} from "../send.js";
 import type { ThreadBindingManager, ThreadBindingRecord } from "./thread-bindings.js";
 
 function resolveTargetChannelId(target: string): string | undefined {
@@ -169,6 +175,20 @@ export async function deliverDiscordReply(params: {
     if (!text && mediaList.length === 0) {
       continue;
     }
+    const interactiveSpec =
+      mediaList.length === 0 && text.length <= chunkLimit
+        ? buildDiscordInteractiveSpec(payload.interactive, text)
+        : undefined;
+    if (interactiveSpec) {
+      await sendDiscordComponentMessage(params.target, interactiveSpec, {
+        token: params.token,
+        rest: params.rest,
+        accountId: params.accountId,
+        replyTo: resolveReplyTo(),
+        sessionKey: params.sessionKey,
+      });
+      continue;
+    }
     if (mediaList.length === 0) {
       const mode = params.chunkMode ?? "length";
       const chunks = chunkDiscordTextWithMode(text, {
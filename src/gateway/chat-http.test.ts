@@ -0,0 +1,151 @@
+import { afterAll, beforeAll, describe, expect, it } from "vitest";
+import {
+  agentCommand,
+  getFreePort,
+  installGatewayTestHooks,
+  withGatewayServer,
+} from "./test-helpers.js";
+
+installGatewayTestHooks({ scope: "suite" });
+
+let startGatewayServer: typeof import("./server.js").startGatewayServer;
+let enabledServer: Awaited<ReturnType<typeof startServer>>;
+let enabledPort: number;
+
+beforeAll(async () => {
+  ({ startGatewayServer } = await import("./server.js"));
+  enabledPort = await getFreePort();
+  enabledServer = await startServer(enabledPort);
+});
+
+afterAll(async () => {
+  await enabledServer.close({ reason: "chat http enabled suite done" });
+});
+
+async function startServer(port: number, opts?: { chatApiEnabled?: boolean }) {
+  return await startGatewayServer(port, {
+    host: "127.0.0.1",
+    auth: { mode: "token", token: "secret" },
+    controlUiEnabled: false,
+    chatApiEnabled: opts?.chatApiEnabled ?? true,
+  });
+}
+
+async function postChat(port: number, body: unknown, headers?: Record<string, string>) {
+  return await fetch(`http://127.0.0.1:${port}/v1/chat`, {
+    method: "POST",
+    headers: {
+      "content-type": "application/json",
+      authorization: "Bearer secret",
+      ...headers,
+    },
+    body: JSON.stringify(body),
+  });
+}
+
+const getFirstAgentCall = () =>
+  (agentCommand.mock.calls[0] as unknown[] | undefined)?.[0] as
+    | { sessionKey?: string; message?: string; deliver?: boolean }
+    | undefined;
+
+describe("chat HTTP API (e2e)", () => {
+  it("rejects when disabled", async () => {
+    await withGatewayServer(
+      async ({ port }) => {
+        const res = await postChat(port, { message: "hi" });
+        expect(res.status).toBe(404);
+        await res.text();
+      },
+      { serverOptions: { chatApiEnabled: false } },
+    );
+  });
+
+  it("validates method, auth, and body", async () => {
+    const get = await fetch(`http://127.0.0.1:${enabledPort}/v1/chat`, {
+      headers: { authorization: "Bearer secret" },
+    });
+    expect(get.status).toBe(405);
+    await get.text();
+
+    const unauthorized = await postChat(enabledPort, { message: "hi" }, { authorization: "" });
+    expect(unauthorized.status).toBe(401);
+    await unauthorized.text();
+
+    const missing = await postChat(enabledPort, { message: "  " });
+    expect(missing.status).toBe(400);
+    await missing.text();
+  });
+
+  it("runs the agent and returns the reply", async () => {
+    agentCommand.mockClear();
+    agentCommand.mockResolvedValueOnce({
+      payloads: [{ text: "Lights are off." }, { mediaUrl: "https://example.com/cam.jpg" }],
+    } as never);
+
+    const res = await postChat(enabledPort, { message: "turn off the lights", user: "ha" });
+    expect(res.status).toBe(200);
+    const body = (await res.json()) as Record<string, unknown>;
+    expect(body).toMatchObject({
+      ok: true,
+      reply: "Lights are off.",
+      payloads: [{ text: "Lights are off." }, { mediaUrls: ["https://example.com/cam.jpg"] }],
+    });
+    expect(body.sessionKey).toBe("agent:main:api-user:ha");
+
+    const call = getFirstAgentCall();
+    expect(call?.message).toBe("turn off the lights");
+    expect(call?.sessionKey).toBe("agent:main:api-user:ha");
+    expect(call?.deliver).toBe(false);
+  });
+
+  it("routes by agentId and explicit sessionKey", async () => {
+    agentCommand.mockClear();
+    agentCommand.mockResolvedValueOnce({ payloads: [{ text: "ok" }] } as never);
+    const routed = await postChat(enabledPort, { message: "hi", agentId: "beta" });
+    expect(routed.status).toBe(200);
+    await routed.text();
+    expect(getFirstAgentCall()?.sessionKey).toMatch(/^agent:beta:api:/);
+
+    agentCommand.mockClear();
+    agentCommand.mockResolvedValueOnce({ payloads: [{ text: "ok" }] } as never);
+    const explicit = await postChat(enabledPort, {
+      message: "hi",
+      agentId: "beta",
+      sessionKey: "main",
+    });
+    expect(explicit.status).toBe(200);
+    await explicit.text();
+    expect(getFirstAgentCall()?.sessionKey).toBe("agent:beta:main");
+  });
+
+  it("stops a named session and rejects stops without one", async () => {
+    const post = (body: unknown) =>
+      fetch(`http://127.0.0.1:${enabledPort}/v1/chat/stop`, {
+        method: "POST",
+        headers: { "content-type": "application/json", authorization: "Bearer secret" },
+        body: JSON.stringify(body),
+      });
+
+    const anonymous = await post({});
+    expect(anonymous.status).toBe(400);
+    await anonymous.text();
+
+    const res = await post({ user: "ha" });
+    expect(res.status).toBe(200);
+    expect(await res.json()).toEqual({
+      ok: true,
+      sessionKey: "agent:main:api-user:ha",
+      aborted: false,
+      stoppedSubagents: 0,
+    });
+  });
+
+  it("returns 500 when the agent run fails", async () => {
+    agentCommand.mockClear();
+    agentCommand.mockRejectedValueOnce(new Error("boom"));
+    const res = await postChat(enabledPort, { message: "hi" });
+    expect(res.status).toBe(500);
+    const body = (await res.json()) as { ok?: boolean };
+    expect(body.ok).toBe(false);
+  });
+});
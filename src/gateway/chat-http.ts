@@ -0,0 +1,219 @@
+import { randomUUID } from "node:crypto";
+import type { IncomingMessage, ServerResponse } from "node:http";
+import { abortSessionRun } from "../auto-reply/reply/abort.js";
+import { createDefaultDeps } from "../cli/deps.js";
+import { agentCommand } from "../commands/agent.js";
+import { loadConfig } from "../config/config.js";
+import { logWarn } from "../logger.js";
+import { buildAgentMainSessionKey, normalizeAgentId } from "../routing/session-key.js";
+import { defaultRuntime } from "../runtime.js";
+import type { AuthRateLimiter } from "./auth-rate-limit.js";
+import type { ResolvedGatewayAuth } from "./auth.js";
+import { sendInvalidRequest, sendJson } from "./http-common.js";
+import { handleGatewayPostJsonEndpoint } from "./http-endpoint-helpers.js";
+import { getHeader, resolveAgentIdFromHeader, resolveSessionKey } from "./http-utils.js";
+
+type ChatApiHttpOptions = {
+  auth: ResolvedGatewayAuth;
+  maxBodyBytes?: number;
+  trustedProxies?: string[];
+  allowRealIpFallback?: boolean;
+  rateLimiter?: AuthRateLimiter;
+};
+
+export type ChatApiBody = {
+  message?: unknown;
+  agentId?: unknown;
+  sessionKey?: unknown;
+  user?: unknown;
+};
+
+export type ChatApiPayload = {
+  text?: string;
+  mediaUrls?: string[];
+};
+
+export function readChatApiString(value: unknown): string | undefined {
+  return typeof value === "string" && value.trim() ? value.trim() : undefined;
+}
+
+export function resolveChatApiAgentId(req: IncomingMessage, body: ChatApiBody): string {
+  const agentId = readChatApiString(body.agentId);
+  return agentId ? normalizeAgentId(agentId) : (resolveAgentIdFromHeader(req) ?? "main");
+}
+
+/**
+ * Explicit `sessionKey` wins, then the `x-openclaw-session-key` header, then `user`. Without
+ * any of those, HTTP requests get a fresh session and WebSocket connections (`connectionId`)
+ * keep one session for their lifetime.
+ */
+export function resolveChatApiSessionKey(params: {
+  req: IncomingMessage;
+  body: ChatApiBody;
+  agentId: string;
+  connectionId?: string;
+}): string {
+  const explicit = readChatApiString(params.body.sessionKey);
+  if (explicit) {
+    return explicit === "main" ? buildAgentMainSessionKey({ agentId: params.agentId }) : explicit;
+  }
+  const user = readChatApiString(params.body.user);
+  if (!user && params.connectionId && !getHeader(params.req, "x-openclaw-session-key")?.trim()) {
+    return buildAgentMainSessionKey({
+      agentId: params.agentId,
+      mainKey: `api:${params.connectionId}`,
+    });
+  }
+  return resolveSessionKey({ req: params.req, agentId: params.agentId, user, prefix: "api" });
+}
+
+function collectPayloads(result: unknown): ChatApiPayload[] {
+  const payloads = (result as { payloads?: unknown } | null)?.payloads;
+  if (!Array.isArray(payloads)) {
+    return [];
+  }
+  return payloads.flatMap((raw) => {
+    const payload = raw as { text?: unknown; mediaUrl?: unknown; mediaUrls?: unknown };
+    const text = typeof payload.text === "string" && payload.text ? payload.text : undefined;
+    const mediaUrls = [
+      ...(Array.isArray(payload.mediaUrls) ? payload.mediaUrls : []),
+      ...(typeof payload.mediaUrl === "string" ? [payload.mediaUrl] : []),
+    ].filter((url): url is string => typeof url === "string" && url.length > 0);
+    if (!text && mediaUrls.length === 0) {
+      return [];
+    }
+    return [{ ...(text ? { text } : {}), ...(mediaUrls.length ? { mediaUrls } : {}) }];
+  });
+}
+
+/**
+ * Stop whatever the agent is doing in this session: aborts the run (in-flight tool calls see the
+ * abort signal), drops queued followups, and stops spawned sub-agents. The transcript up to the
+ * abort is kept, so the next message continues the same session.
+ */
+export async function stopChatApiSession(
+  sessionKey: string,
+): Promise<{ aborted: boolean; stoppedSubagents: number }> {
+  const { aborted, stoppedSubagents } = await abortSessionRun({ cfg: loadConfig(), sessionKey });
+  return { aborted, stoppedSubagents };
+}
+
+/** Run one agent turn for the chat API. Replies go back to the caller, never to channels. */
+export async function runChatApiTurn(params: {
+  message: string;
+  sessionKey: string;
+  runId: string;
+}): Promise<{ reply: string; payloads: ChatApiPayload[] }> {
+  const result = await agentCommand(
+    {
+      message: params.message,
+      sessionKey: params.sessionKey,
+      runId: params.runId,
+      deliver: false,
+      messageChannel: "webchat",
+      bestEffortDeliver: false,
+    },
+    defaultRuntime,
+    createDefaultDeps(),
+  );
+  const payloads = collectPayloads(result);
+  const reply = payloads
+    .map((payload) => payload.text ?? "")
+    .filter(Boolean)
+    .join("\n\n");
+  return { reply, payloads };
+}
+
+/**
+ * `POST /v1/chat`: one message in, the agent's reply out. A plain JSON shape for scripts and
+ * home automation that do not want the OpenAI Chat Completions envelope.
+ */
+export async function handleChatApiHttpRequest(
+  req: IncomingMessage,
+  res: ServerResponse,
+  opts: ChatApiHttpOptions,
+): Promise<boolean> {
+  if (await handleChatApiStopHttpRequest(req, res, opts)) {
+    return true;
+  }
+  const handled = await handleGatewayPostJsonEndpoint(req, res, {
+    pathname: "/v1/chat",
+    auth: opts.auth,
+    trustedProxies: opts.trustedProxies,
+    allowRealIpFallback: opts.allowRealIpFallback,
+    rateLimiter: opts.rateLimiter,
+    maxBodyBytes: opts.maxBodyBytes ?? 1024 * 1024,
+  });
+  if (handled === false) {
+    return false;
+  }
+  if (!handled) {
+    return true;
+  }
+
+  const body = (handled.body ?? {}) as ChatApiBody;
+  const message = readChatApiString(body.message);
+  if (!message) {
+    sendInvalidRequest(res, "chat requires body.message");
+    return true;
+  }
+
+  const agentId = resolveChatApiAgentId(req, body);
+  const sessionKey = resolveChatApiSessionKey({ req, body, agentId });
+  const runId = `chat_${randomUUID()}`;
+
+  try {
+    const { reply, payloads } = await runChatApiTurn({ message, sessionKey, runId });
+    sendJson(res, 200, { ok: true, runId, sessionKey, reply, payloads });
+  } catch (err) {
+    logWarn(`chat-api: agent run failed: ${String(err)}`);
+    sendJson(res, 500, { ok: false, error: { type: "api_error", message: "internal error" } });
+  }
+  return true;
+}
+
+/**
+ * `POST /v1/chat/stop`: cancel the in-progress turn for a session. The session is picked the same
+ * way as for `/v1/chat`, but one must be named; a stop without it would target a fresh session.
+ */
+async function handleChatApiStopHttpRequest(
+  req: IncomingMessage,
+  res: ServerResponse,
+  opts: ChatApiHttpOptions,
+): Promise<boolean> {
+  const handled = await handleGatewayPostJsonEndpoint(req, res, {
+    pathname: "/v1/chat/stop",
+    auth: opts.auth,
+    trustedProxies: opts.trustedProxies,
+    allowRealIpFallback: opts.allowRealIpFallback,
+    rateLimiter: opts.rateLimiter,
+    maxBodyBytes: 64 * 1024,
+  });
+  if (handled === false) {
+    return false;
+  }
+  if (!handled) {
+    return true;
+  }
+
+  const body = (handled.body ?? {}) as ChatApiBody;
+  if (
+    !readChatApiString(body.sessionKey) &&
+    !readChatApiString(body.user) &&
+    !getHeader(req, "x-openclaw-session-key")?.trim()
+  ) {
+    sendInvalidRequest(res, "stop requires body.sessionKey or body.user");
+    return true;
+  }
+
+  const agentId = resolveChatApiAgentId(req, body);
+  const sessionKey = resolveChatApiSessionKey({ req, body, agentId });
+  try {
+    const { aborted, stoppedSubagents } = await stopChatApiSession(sessionKey);
+    sendJson(res, 200, { ok: true, sessionKey, aborted, stoppedSubagents });
+  } catch (err) {
+    logWarn(`chat-api: stop failed: ${String(err)}`);
+    sendJson(res, 500, { ok: false, error: { type: "api_error", message: "internal error" } });
+  }
+  return true;
+}
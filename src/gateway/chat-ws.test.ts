@@ -0,0 +1,128 @@
+import { afterAll, beforeAll, describe, expect, it } from "vitest";
+import { WebSocket } from "ws";
+import { emitAgentEvent } from "../infra/agent-events.js";
+import { rawDataToString } from "../infra/ws.js";
+import { agentCommand, getFreePort, installGatewayTestHooks } from "./test-helpers.js";
+
+installGatewayTestHooks({ scope: "suite" });
+
+let startGatewayServer: typeof import("./server.js").startGatewayServer;
+let server: Awaited<ReturnType<typeof startGatewayServer>>;
+let port: number;
+
+beforeAll(async () => {
+  ({ startGatewayServer } = await import("./server.js"));
+  port = await getFreePort();
+  server = await startGatewayServer(port, {
+    host: "127.0.0.1",
+    auth: { mode: "token", token: "secret" },
+    controlUiEnabled: false,
+    chatApiEnabled: true,
+  });
+});
+
+afterAll(async () => {
+  await server.close({ reason: "chat ws suite done" });
+});
+
+async function connect(query = "?token=secret"): Promise<WebSocket> {
+  const ws = new WebSocket(`ws://127.0.0.1:${port}/v1/chat/ws${query}`);
+  await new Promise<void>((resolve, reject) => {
+    ws.once("open", () => resolve());
+    ws.once("error", reject);
+  });
+  return ws;
+}
+
+function collectUntilDone(ws: WebSocket): Promise<Array<Record<string, unknown>>> {
+  const frames: Array<Record<string, unknown>> = [];
+  return new Promise((resolve) => {
+    ws.on("message", (data) => {
+      const frame = JSON.parse(rawDataToString(data)) as Record<string, unknown>;
+      frames.push(frame);
+      if (frame.type === "done" || (frame.type === "error" && frame.runId)) {
+        resolve(frames);
+      }
+    });
+  });
+}
+
+describe("chat WebSocket API (e2e)", () => {
+  it("rejects upgrades without a valid token", async () => {
+    const ws = new WebSocket(`ws://127.0.0.1:${port}/v1/chat/ws?token=wrong`);
+    const status = await new Promise<number | undefined>((resolve) => {
+      ws.once("unexpected-response", (_req, res) => resolve(res.statusCode));
+      ws.once("open", () => resolve(undefined));
+      ws.once("error", () => resolve(undefined));
+    });
+    expect(status).toBe(401);
+    ws.terminate();
+  });
+
+  it("streams deltas and tool events, then the final reply", async () => {
+    agentCommand.mockClear();
+    agentCommand.mockImplementationOnce((async (opts: unknown) => {
+      const runId = (opts as { runId: string }).runId;
+      emitAgentEvent({
+        runId,
+        stream: "tool",
+        data: { phase: "start", name: "web_search", toolCallId: "t1", args: { q: "x" } },
+      });
+      emitAgentEvent({
+        runId,
+        stream: "tool",
+        data: { phase: "result", name: "web_search", toolCallId: "t1", isError: false },
+      });
+      emitAgentEvent({ runId, stream: "assistant", data: { delta: "hel" } });
+      emitAgentEvent({ runId, stream: "assistant", data: { delta: "lo" } });
+      return { payloads: [{ text: "hello" }] };
+    }) as never);
+
+    const ws = await connect();
+    try {
+      const done = collectUntilDone(ws);
+      ws.send(JSON.stringify({ type: "message", id: "m1", message: "hi" }));
+      const frames = await done;
+
+      expect(frames.map((frame) => frame.type)).toEqual([
+        "start",
+        "tool",
+        "tool",
+        "delta",
+        "delta",
+        "done",
+      ]);
+      expect(frames[0]).toMatchObject({ id: "m1" });
+      expect(frames[1]).toMatchObject({ phase: "start", name: "web_search", args: { q: "x" } });
+      expect(frames[2]).toMatchObject({ phase: "result", isError: false });
+      expect(frames.slice(3, 5).map((frame) => frame.text)).toEqual(["hel", "lo"]);
+      expect(frames[5]).toMatchObject({ id: "m1", reply: "hello" });
+      expect(String(frames[5]?.sessionKey)).toMatch(/^agent:main:api:/);
+    } finally {
+      ws.close();
+    }
+  });
+
+  it("keeps one session per connection", async () => {
+    agentCommand.mockClear();
+    agentCommand
+      .mockResolvedValueOnce({ payloads: [{ text: "ok" }] } as never)
+      .mockResolvedValueOnce({ payloads: [{ text: "ok" }] } as never);
+    const ws = await connect();
+    try {
+      for (const message of ["one", "two"]) {
+        const done = collectUntilDone(ws);
+        ws.send(JSON.stringify({ type: "message", message }));
+        await done;
+        ws.removeAllListeners("message");
+      }
+      const keys = agentCommand.mock.calls.map(
+        (call) => (call[0] as { sessionKey?: string }).sessionKey,
+      );
+      expect(keys).toHaveLength(2);
+      expect(keys[0]).toBe(keys[1]);
+    } finally {
+      ws.close();
+    }
+  });
+});
@@ -0,0 +1,165 @@
+import { randomUUID } from "node:crypto";
+import type { IncomingMessage } from "node:http";
+import type { Duplex } from "node:stream";
+import { WebSocketServer, type WebSocket } from "ws";
+import { onAgentEvent, type AgentEventPayload } from "../infra/agent-events.js";
+import { rawDataToString } from "../infra/ws.js";
+import { logWarn } from "../logger.js";
+import { resolveAssistantStreamDeltaText } from "./agent-event-assistant-text.js";
+import {
+  readChatApiString,
+  resolveChatApiAgentId,
+  resolveChatApiSessionKey,
+  runChatApiTurn,
+  stopChatApiSession,
+  type ChatApiBody,
+} from "./chat-http.js";
+
+export const CHAT_API_WS_PATH = "/v1/chat/ws";
+
+const MAX_FRAME_BYTES = 1024 * 1024;
+
+type ChatApiFrame = ChatApiBody & { type?: unknown; id?: unknown };
+
+function sendFrame(ws: WebSocket, frame: Record<string, unknown>) {
+  if (ws.readyState === ws.OPEN) {
+    ws.send(JSON.stringify(frame));
+  }
+}
+
+function toToolFrame(runId: string, evt: AgentEventPayload): Record<string, unknown> | null {
+  const phase = evt.data.phase;
+  const name = evt.data.name;
+  if (typeof phase !== "string" || typeof name !== "string") {
+    return null;
+  }
+  return {
+    type: "tool",
+    runId,
+    phase,
+    name,
+    toolCallId: evt.data.toolCallId,
+    ...(phase === "start" && evt.data.args ? { args: evt.data.args } : {}),
+    ...(phase === "result" ? { meta: evt.data.meta, isError: evt.data.isError === true } : {}),
+  };
+}
+
+function handleConnection(ws: WebSocket, req: IncomingMessage) {
+  const connectionId = randomUUID();
+  let activeRunId: string | undefined;
+  let activeSessionKey: string | undefined;
+
+  const runTurn = async (frame: ChatApiFrame, message: string) => {
+    const id = readChatApiString(frame.id);
+    const agentId = resolveChatApiAgentId(req, frame);
+    const sessionKey = resolveChatApiSessionKey({ req, body: frame, agentId, connectionId });
+    const runId = `chat_${randomUUID()}`;
+    activeRunId = runId;
+    activeSessionKey = sessionKey;
+    sendFrame(ws, { type: "start", id, runId, sessionKey });
+
+    const unsubscribe = onAgentEvent((evt) => {
+      if (evt.runId !== runId) {
+        return;
+      }
+      if (evt.stream === "assistant") {
+        const text = resolveAssistantStreamDeltaText(evt);
+        if (text) {
+          sendFrame(ws, { type: "delta", runId, text });
+        }
+        return;
+      }
+      if (evt.stream === "tool") {
+        const toolFrame = toToolFrame(runId, evt);
+        if (toolFrame) {
+          sendFrame(ws, toolFrame);
+        }
+      }
+    });
+    try {
+      const { reply, payloads } = await runChatApiTurn({ message, sessionKey, runId });
+      sendFrame(ws, { type: "done", id, runId, sessionKey, reply, payloads });
+    } catch (err) {
+      logWarn(`chat-api: websocket agent run failed: ${String(err)}`);
+      sendFrame(ws, { type: "error", id, runId, message: "internal error" });
+    } finally {
+      unsubscribe();
+      activeRunId = undefined;
+      activeSessionKey = undefined;
+    }
+  };
+
+  const stopTurn = async (frame: ChatApiFrame) => {
+    const id = readChatApiString(frame.id);
+    const runId = activeRunId;
+    const sessionKey = activeSessionKey;
+    if (!runId || !sessionKey) {
+      sendFrame(ws, { type: "stopped", id, aborted: false });
+      return;
+    }
+    try {
+      const { aborted, stoppedSubagents } = await stopChatApiSession(sessionKey);
+      sendFrame(ws, { type: "stopped", id, runId, sessionKey, aborted, stoppedSubagents });
+    } catch (err) {
+      logWarn(`chat-api: websocket stop failed: ${String(err)}`);
+      sendFrame(ws, { type: "error", id, runId, message: "internal error" });
+    }
+  };
+
+  ws.on("message", (data) => {
+    let frame: ChatApiFrame;
+    try {
+      frame = JSON.parse(rawDataToString(data)) as ChatApiFrame;
+    } catch {
+      sendFrame(ws, { type: "error", message: "invalid JSON" });
+      return;
+    }
+    if (frame && typeof frame === "object" && frame.type === "stop") {
+      void stopTurn(frame);
+      return;
+    }
+    if (!frame || typeof frame !== "object" || frame.type !== "message") {
+      sendFrame(ws, { type: "error", message: 'expected {"type":"message"} or {"type":"stop"}' });
+      return;
+    }
+    const message = readChatApiString(frame.message);
+    if (!message) {
+      sendFrame(ws, { type: "error", id: frame.id, message: "message is required" });
+      return;
+    }
+    if (activeRunId) {
+      sendFrame(ws, {
+        type: "error",
+        id: frame.id,
+        runId: activeRunId,
+        message: "a run is already in progress on this connection",
+      });
+      return;
+    }
+    void runTurn(frame, message);
+  });
+}
+
+/**
+ * WebSocket flavor of `POST /v1/chat`: each `message` frame runs one agent turn and streams
+ * `delta` (assistant text) and `tool` (tool start/update/result) frames before `done`. A `stop`
+ * frame cancels the running turn.
+ * Callers authenticate the upgrade request; this only speaks the frame protocol.
+ */
+export function createChatApiWsServer() {
+  const wss = new WebSocketServer({ noServer: true, maxPayload: MAX_FRAME_BYTES });
+  wss.on("connection", handleConnection);
+  return {
+    handleUpgrade(req: IncomingMessage, socket: Duplex, head: Buffer) {
+      wss.handleUpgrade(req, socket, head, (ws) => {
+        wss.emit("connection", ws, req);
+      });
+    },
+    close() {
+      for (const client of wss.clients) {
+        client.close(1012, "service restart");
+      }
+      wss.close();
+    },
+  };
+}
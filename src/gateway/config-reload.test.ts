@@ -2,7 +2,7 @@ import chokidar from "chokidar";
 import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
 import { listChannelPlugins } from "../channels/plugins/index.js";
 import type { ChannelPlugin } from "../channels/plugins/types.js";
-import type { ConfigFileSnapshot } from "../config/config.js";
+import type { ConfigFileSnapshot, OpenClawConfig } from "../config/config.js";
 import { setActivePluginRegistry } from "../plugins/runtime.js";
 import { createTestRegistry } from "../test-utils/channel-plugins.js";
 import {
@@ -153,6 +153,17 @@ describe("buildGatewayReloadPlan", () => {
     expect(plan.noopPaths).toContain("gateway.remote.url");
   });
 
+  it("restarts gateway timers for digest, reconcile, and watchdog changes", () => {
+    const plan = buildGatewayReloadPlan([
+      "cost.digest.schedule",
+      "messages.notifications.quietHours.start",
+      "cost.budget.daily",
+    ]);
+    expect(plan.restartGateway).toBe(false);
+    expect(plan.restartSchedules).toBe(true);
+    expect(plan.noopPaths).toEqual(["cost.budget.daily"]);
+  });
+
   it("defaults unknown paths to restart", () => {
     const plan = buildGatewayReloadPlan(["unknownField"]);
     expect(plan.restartGateway).toBe(true);
@@ -204,7 +215,10 @@ function makeSnapshot(partial: Partial<ConfigFileSnapshot> = {}): ConfigFileSnap
   };
 }
 
-function createReloaderHarness(readSnapshot: () => Promise<ConfigFileSnapshot>) {
+function createReloaderHarness(
+  readSnapshot: () => Promise<ConfigFileSnapshot>,
+  initialConfig: OpenClawConfig = { gateway: { reload: { debounceMs: 0 } } },
+) {
   const watcher = createWatcherMock();
   vi.spyOn(chokidar, "watch").mockReturnValue(watcher as unknown as never);
   const onHotReload = vi.fn(async () => {});
@@ -215,7 +229,7 @@ function createReloaderHarness(readSnapshot: () => Promise<ConfigFileSnapshot>)
     error: vi.fn(),
   };
   const reloader = startGatewayConfigReloader({
-    initialConfig: { gateway: { reload: { debounceMs: 0 } } },
+    initialConfig,
     readSnapshot,
     onHotReload,
     onRestart,
@@ -279,4 +293,45 @@ describe("startGatewayConfigReloader", () => {
 
     await reloader.stop();
   });
+
+  it("applies safe changes in hot mode and reports the ones that need a restart", async () => {
+    const reload = { mode: "hot" as const, debounceMs: 0 };
+    const next: OpenClawConfig = {
+      gateway: { reload, port: 19001 },
+      agents: { defaults: { model: { primary: "openai/gpt-5" } } },
+    };
+    const readSnapshot = vi.fn(async () => makeSnapshot({ config: next }));
+    const { watcher, onHotReload, onRestart, log, reloader } = createReloaderHarness(readSnapshot, {
+      gateway: { reload },
+    });
+
+    watcher.emit("change");
+    await vi.runOnlyPendingTimersAsync();
+
+    expect(onRestart).not.toHaveBeenCalled();
+    expect(onHotReload).toHaveBeenCalledTimes(1);
+    expect(log.warn).toHaveBeenCalledWith(
+      "config changes need a gateway restart to take effect (gateway.port); hot mode applies the rest now",
+    );
+
+    await reloader.stop();
+  });
+
+  it.runIf(process.platform !== "win32")("reloads on SIGHUP until stopped", async () => {
+    const next: OpenClawConfig = {
+      gateway: { reload: { debounceMs: 0 } },
+      hooks: { enabled: true },
+    };
+    const readSnapshot = vi.fn(async () => makeSnapshot({ config: next }));
+    const { onHotReload, reloader } = createReloaderHarness(readSnapshot);
+
+    process.emit("SIGHUP", "SIGHUP");
+    await vi.runOnlyPendingTimersAsync();
+    expect(onHotReload).toHaveBeenCalledTimes(1);
+
+    await reloader.stop();
+    process.emit("SIGHUP", "SIGHUP");
+    await vi.runOnlyPendingTimersAsync();
+    expect(readSnapshot).toHaveBeenCalledTimes(1);
+  });
 });
@@ -3,6 +3,7 @@ import chokidar from "chokidar";
 import { type ChannelId, listChannelPlugins } from "../channels/plugins/index.js";
 import type { OpenClawConfig, ConfigFileSnapshot, GatewayReloadMode } from "../config/config.js";
 import { getActivePluginRegistry } from "../plugins/runtime.js";
+import { clearKeyringSecretCache } from "../secrets/store.js";
 import { isPlainObject } from "../utils.js";
 
 export type GatewayReloadSettings = {
@@ -22,6 +23,7 @@ export type GatewayReloadPlan = {
   restartBrowserControl: boolean;
   restartCron: boolean;
   restartHeartbeat: boolean;
+  restartSchedules: boolean;
   restartChannels: Set<ChannelKind>;
   noopPaths: string[];
 };
@@ -38,6 +40,7 @@ type ReloadAction =
   | "restart-browser-control"
   | "restart-cron"
   | "restart-heartbeat"
+  | "restart-schedules"
   | `restart-channel:${ChannelId}`;
 
 const DEFAULT_RELOAD_SETTINGS: GatewayReloadSettings = {
@@ -59,6 +62,11 @@ const BASE_RELOAD_RULES: ReloadRule[] = [
   },
   { prefix: "agent.heartbeat", kind: "hot", actions: ["restart-heartbeat"] },
   { prefix: "cron", kind: "hot", actions: ["restart-cron"] },
+  // Gateway-owned timers (digests, reconciliation, watchdog) read config once when started.
+  { prefix: "cost.digest", kind: "hot", actions: ["restart-schedules"] },
+  { prefix: "cost.reconcile", kind: "hot", actions: ["restart-schedules"] },
+  { prefix: "messages.notifications", kind: "hot", actions: ["restart-schedules"] },
+  { prefix: "watchdog", kind: "hot", actions: ["restart-schedules"] },
   {
     prefix: "browser",
     kind: "hot",
@@ -74,6 +82,7 @@ const BASE_RELOAD_RULES_TAIL: ReloadRule[] = [
   { prefix: "models", kind: "none" },
   { prefix: "agents", kind: "none" },
   { prefix: "tools", kind: "none" },
+  { prefix: "cost", kind: "none" },
   { prefix: "bindings", kind: "none" },
   { prefix: "audio", kind: "none" },
   { prefix: "agent", kind: "none" },
@@ -187,6 +196,7 @@ export function buildGatewayReloadPlan(changedPaths: string[]): GatewayReloadPla
     restartBrowserControl: false,
     restartCron: false,
     restartHeartbeat: false,
+    restartSchedules: false,
     restartChannels: new Set(),
     noopPaths: [],
   };
@@ -213,6 +223,9 @@ export function buildGatewayReloadPlan(changedPaths: string[]): GatewayReloadPla
       case "restart-heartbeat":
         plan.restartHeartbeat = true;
         break;
+      case "restart-schedules":
+        plan.restartSchedules = true;
+        break;
       default:
         break;
     }
@@ -271,6 +284,8 @@ export function startGatewayConfigReloader(opts: {
   let stopped = false;
   let restartQueued = false;
   let missingConfigRetries = 0;
+  // Restart-only changes seen in hot mode; they stay pending until the gateway restarts.
+  const pendingRestartPaths = new Set<string>();
 
   const scheduleAfter = (wait: number) => {
     if (stopped) {
@@ -339,16 +354,21 @@ export function startGatewayConfigReloader(opts: {
       return;
     }
     if (plan.restartGateway) {
-      if (settings.mode === "hot") {
-        opts.log.warn(
-          `config reload requires gateway restart; hot mode ignoring (${plan.restartReasons.join(
-            ", ",
-          )})`,
-        );
+      if (settings.mode !== "hot") {
+        queueRestart(plan, nextConfig);
+        return;
+      }
+      for (const path of plan.restartReasons) {
+        pendingRestartPaths.add(path);
+      }
+      opts.log.warn(
+        `config changes need a gateway restart to take effect (${[...pendingRestartPaths].join(
+          ", ",
+        )}); hot mode applies the rest now`,
+      );
+      if (plan.hotReasons.length === 0 && plan.noopPaths.length === 0) {
         return;
       }
-      queueRestart(plan, nextConfig);
-      return;
     }
 
     await opts.onHotReload(plan, nextConfig);
@@ -396,6 +416,18 @@ export function startGatewayConfigReloader(opts: {
   watcher.on("add", schedule);
   watcher.on("change", schedule);
   watcher.on("unlink", schedule);
+
+  // `kill -HUP <pid>` re-reads the config right away (same rules as a file change), asking the
+  // OS keyring again for secrets stored since startup.
+  const onSighup = () => {
+    opts.log.info("signal SIGHUP received; reloading config");
+    clearKeyringSecretCache();
+    void runReload();
+  };
+  const watchSighup = process.platform !== "win32";
+  if (watchSighup) {
+    process.on("SIGHUP", onSighup);
+  }
   let watcherClosed = false;
   watcher.on("error", (err) => {
     if (watcherClosed) {
@@ -409,6 +441,9 @@ export function startGatewayConfigReloader(opts: {
   return {
     stop: async () => {
       stopped = true;
+      if (watchSighup) {
+        process.removeListener("SIGHUP", onSighup);
+      }
       if (debounceTimer) {
         clearTimeout(debounceTimer);
       }
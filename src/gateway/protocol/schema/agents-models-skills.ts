@@ -268,3 +268,13 @@ export const ToolsCatalogResultSchema = Type.Object(
   },
   { additionalProperties: false },
 );
+
+export const ToolsInvokeParamsSchema = Type.Object(
+  {
+    name: NonEmptyString,
+    args: Type.Optional(Type.Record(Type.String(), Type.Unknown())),
+    action: Type.Optional(NonEmptyString),
+    sessionKey: Type.Optional(NonEmptyString),
+  },
+  { additionalProperties: false },
+);
@@ -39,6 +39,7 @@ import {
   ToolCatalogProfileSchema,
   ToolsCatalogParamsSchema,
   ToolsCatalogResultSchema,
+  ToolsInvokeParamsSchema,
 } from "./agents-models-skills.js";
 import {
   ChannelsLogoutParamsSchema,
@@ -234,6 +235,7 @@ export const ProtocolSchemas: Record<string, TSchema> = {
   ToolCatalogEntry: ToolCatalogEntrySchema,
   ToolCatalogGroup: ToolCatalogGroupSchema,
   ToolsCatalogResult: ToolsCatalogResultSchema,
+  ToolsInvokeParams: ToolsInvokeParamsSchema,
   SkillsBinsParams: SkillsBinsParamsSchema,
   SkillsBinsResult: SkillsBinsResultSchema,
   SkillsInstallParams: SkillsInstallParamsSchema,
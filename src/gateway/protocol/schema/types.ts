@@ -37,6 +37,7 @@ import type {
   ToolCatalogProfileSchema,
   ToolsCatalogParamsSchema,
   ToolsCatalogResultSchema,
+  ToolsInvokeParamsSchema,
 } from "./agents-models-skills.js";
 import type {
   ChannelsLogoutParamsSchema,
@@ -223,6 +224,7 @@ export type ToolCatalogProfile = Static<typeof ToolCatalogProfileSchema>;
 export type ToolCatalogEntry = Static<typeof ToolCatalogEntrySchema>;
 export type ToolCatalogGroup = Static<typeof ToolCatalogGroupSchema>;
 export type ToolsCatalogResult = Static<typeof ToolsCatalogResultSchema>;
+export type ToolsInvokeParams = Static<typeof ToolsInvokeParamsSchema>;
 export type SkillsBinsParams = Static<typeof SkillsBinsParamsSchema>;
 export type SkillsBinsResult = Static<typeof SkillsBinsResultSchema>;
 export type SkillsInstallParams = Static<typeof SkillsInstallParamsSchema>;
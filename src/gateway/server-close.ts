@@ -16,6 +16,7 @@ export function createGatewayCloseHandler(params: {
   cron: { stop: () => void };
   heartbeatRunner: HeartbeatRunner;
   updateCheckStop?: (() => void) | null;
+  schedulesStop?: (() => void) | null;
   nodePresenceTimers: Map<string, ReturnType<typeof setInterval>>;
   broadcast: (event: string, payload: unknown, opts?: { dropIfSlow?: boolean }) => void;
   tickInterval: ReturnType<typeof setInterval>;
@@ -73,6 +74,7 @@ export function createGatewayCloseHandler(params: {
     params.heartbeatRunner.stop();
     try {
       params.updateCheckStop?.();
+      params.schedulesStop?.();
     } catch {
       /* ignore */
     }
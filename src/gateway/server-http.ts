@@ -32,6 +32,8 @@ import {
   type ResolvedGatewayAuth,
 } from "./auth.js";
 import { CANVAS_CAPABILITY_TTL_MS, normalizeCanvasScopedUrl } from "./canvas-capability.js";
+import { handleChatApiHttpRequest } from "./chat-http.js";
+import { CHAT_API_WS_PATH, createChatApiWsServer } from "./chat-ws.js";
 import {
   handleControlUiAvatarRequest,
   handleControlUiHttpRequest,
@@ -415,6 +417,7 @@ export function createGatewayHttpServer(opts: {
   controlUiBasePath: string;
   controlUiRoot?: ControlUiRootState;
   openAiChatCompletionsEnabled: boolean;
+  chatApiEnabled: boolean;
   openResponsesEnabled: boolean;
   openResponsesConfig?: import("../config/types.gateway.js").GatewayHttpResponsesConfig;
   handleHooksRequest: HooksRequestHandler;
@@ -431,6 +434,7 @@ export function createGatewayHttpServer(opts: {
     controlUiBasePath,
     controlUiRoot,
     openAiChatCompletionsEnabled,
+    chatApiEnabled,
     openResponsesEnabled,
     openResponsesConfig,
     handleHooksRequest,
@@ -531,6 +535,18 @@ export function createGatewayHttpServer(opts: {
           return;
         }
       }
+      if (chatApiEnabled) {
+        if (
+          await handleChatApiHttpRequest(req, res, {
+            auth: resolvedAuth,
+            trustedProxies,
+            allowRealIpFallback,
+            rateLimiter,
+          })
+        ) {
+          return;
+        }
+      }
       if (canvasHost) {
         if (isCanvasPath(requestPath)) {
           const ok = await authorizeCanvasRequest({
@@ -594,12 +610,40 @@ export function attachGatewayUpgradeHandler(opts: {
   canvasHost: CanvasHostHandler | null;
   clients: Set<GatewayWsClient>;
   resolvedAuth: ResolvedGatewayAuth;
+  /** Serve the streaming chat API at `/v1/chat/ws`. */
+  chatApiEnabled?: boolean;
   /** Optional rate limiter for auth brute-force protection. */
   rateLimiter?: AuthRateLimiter;
 }) {
   const { httpServer, wss, canvasHost, clients, resolvedAuth, rateLimiter } = opts;
+  const chatApiWs = opts.chatApiEnabled ? createChatApiWsServer() : null;
+  if (chatApiWs) {
+    httpServer.once("close", () => chatApiWs.close());
+  }
   httpServer.on("upgrade", (req, socket, head) => {
     void (async () => {
+      const upgradeUrl = new URL(req.url ?? "/", "http://localhost");
+      if (chatApiWs && upgradeUrl.pathname === CHAT_API_WS_PATH) {
+        const configSnapshot = loadConfig();
+        // Browsers cannot set headers on WebSocket upgrades, so also accept `?token=`.
+        const token =
+          getBearerToken(req) ?? (upgradeUrl.searchParams.get("token")?.trim() || undefined);
+        const authResult = await authorizeHttpGatewayConnect({
+          auth: resolvedAuth,
+          connectAuth: token ? { token, password: token } : null,
+          req,
+          trustedProxies: configSnapshot.gateway?.trustedProxies ?? [],
+          allowRealIpFallback: configSnapshot.gateway?.allowRealIpFallback === true,
+          rateLimiter,
+        });
+        if (!authResult.ok) {
+          writeUpgradeAuthFailure(socket, authResult);
+          socket.destroy();
+          return;
+        }
+        chatApiWs.handleUpgrade(req, socket, head);
+        return;
+      }
       const scopedCanvas = normalizeCanvasScopedUrl(req.url ?? "/");
       if (scopedCanvas.malformedScopedPath) {
         writeUpgradeAuthFailure(socket, { ok: false, reason: "unauthorized" });
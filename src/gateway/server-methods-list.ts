@@ -35,6 +35,7 @@ const BASE_METHODS = [
   "talk.mode",
   "models.list",
   "tools.catalog",
+  "tools.invoke",
   "agents.list",
   "agents.create",
   "agents.update",
@@ -0,0 +1,76 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { ErrorCodes } from "../protocol/index.js";
+import type { GatewayToolInvokeOutcome } from "../tools-invoke-http.js";
+import { toolsInvokeHandlers } from "./tools-invoke.js";
+
+vi.mock("../../config/config.js", () => ({
+  loadConfig: vi.fn(() => ({})),
+}));
+
+const invokeGatewayTool = vi.fn(
+  async (_params: unknown): Promise<GatewayToolInvokeOutcome> => ({ ok: true, result: null }),
+);
+
+vi.mock("../tools-invoke-http.js", () => ({
+  invokeGatewayTool: (params: unknown) => invokeGatewayTool(params),
+}));
+
+type RespondCall = [boolean, unknown?, { code: string; message: string; details?: unknown }?];
+
+async function invoke(params: Record<string, unknown>) {
+  const respond = vi.fn();
+  await toolsInvokeHandlers["tools.invoke"]({
+    params,
+    respond: respond as never,
+    context: {} as never,
+    client: null,
+    req: { type: "req", id: "req-1", method: "tools.invoke" },
+    isWebchatConnect: () => false,
+  });
+  return respond.mock.calls[0] as RespondCall;
+}
+
+describe("tools.invoke handler", () => {
+  beforeEach(() => {
+    invokeGatewayTool.mockClear();
+  });
+
+  it("rejects invalid params", async () => {
+    const call = await invoke({ name: "gpio_write", args: [7] });
+    expect(call[0]).toBe(false);
+    expect(call[2]?.code).toBe(ErrorCodes.INVALID_REQUEST);
+    expect(invokeGatewayTool).not.toHaveBeenCalled();
+  });
+
+  it("runs the tool and returns its result", async () => {
+    invokeGatewayTool.mockResolvedValueOnce({ ok: true, result: { content: [] } });
+    const call = await invoke({ name: "gpio_write", args: { pin: 7, level: 1 } });
+    expect(invokeGatewayTool).toHaveBeenCalledWith(
+      expect.objectContaining({ toolName: "gpio_write", args: { pin: 7, level: 1 } }),
+    );
+    expect(call).toEqual([true, { ok: true, result: { content: [] } }, undefined]);
+  });
+
+  it("maps missing tools and failures to protocol errors", async () => {
+    invokeGatewayTool.mockResolvedValueOnce({
+      ok: false,
+      status: 404,
+      type: "not_found",
+      message: "Tool not available: nope",
+    });
+    const missing = await invoke({ name: "nope" });
+    expect(missing[2]).toMatchObject({
+      code: ErrorCodes.INVALID_REQUEST,
+      message: "Tool not available: nope",
+    });
+
+    invokeGatewayTool.mockResolvedValueOnce({
+      ok: false,
+      status: 500,
+      type: "tool_error",
+      message: "tool execution failed",
+    });
+    const failed = await invoke({ name: "gpio_write" });
+    expect(failed[2]?.code).toBe(ErrorCodes.UNAVAILABLE);
+  });
+});
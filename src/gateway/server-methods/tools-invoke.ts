@@ -0,0 +1,46 @@
+import { loadConfig } from "../../config/config.js";
+import {
+  ErrorCodes,
+  errorShape,
+  formatValidationErrors,
+  validateToolsInvokeParams,
+} from "../protocol/index.js";
+import { invokeGatewayTool } from "../tools-invoke-http.js";
+import type { GatewayRequestHandlers } from "./types.js";
+
+export const toolsInvokeHandlers: GatewayRequestHandlers = {
+  "tools.invoke": async ({ params, respond }) => {
+    if (!validateToolsInvokeParams(params)) {
+      respond(
+        false,
+        undefined,
+        errorShape(
+          ErrorCodes.INVALID_REQUEST,
+          `invalid tools.invoke params: ${formatValidationErrors(validateToolsInvokeParams.errors)}`,
+        ),
+      );
+      return;
+    }
+    const outcome = await invokeGatewayTool({
+      cfg: loadConfig(),
+      toolName: params.name.trim(),
+      args: params.args ?? {},
+      action: params.action?.trim(),
+      sessionKey: params.sessionKey,
+      callId: `rpc-${Date.now()}`,
+    });
+    if (outcome.ok) {
+      respond(true, { ok: true, result: outcome.result }, undefined);
+      return;
+    }
+    respond(
+      false,
+      undefined,
+      errorShape(
+        outcome.status >= 500 ? ErrorCodes.UNAVAILABLE : ErrorCodes.INVALID_REQUEST,
+        outcome.message,
+        { details: { type: outcome.type, status: outcome.status } },
+      ),
+    );
+  },
+};
@@ -39,6 +39,7 @@ export const ttsHandlers: GatewayRequestHandlers = {
         hasOpenAIKey: Boolean(resolveTtsApiKey(config, "openai")),
         hasElevenLabsKey: Boolean(resolveTtsApiKey(config, "elevenlabs")),
         edgeEnabled: isTtsProviderConfigured(config, "edge"),
+        piperConfigured: isTtsProviderConfigured(config, "piper"),
       });
     } catch (err) {
       respond(false, undefined, errorShape(ErrorCodes.UNAVAILABLE, formatForLog(err)));
@@ -100,13 +101,18 @@ export const ttsHandlers: GatewayRequestHandlers = {
   },
   "tts.setProvider": async ({ params, respond }) => {
     const provider = typeof params.provider === "string" ? params.provider.trim() : "";
-    if (provider !== "openai" && provider !== "elevenlabs" && provider !== "edge") {
+    if (
+      provider !== "openai" &&
+      provider !== "elevenlabs" &&
+      provider !== "edge" &&
+      provider !== "piper"
+    ) {
       respond(
         false,
         undefined,
         errorShape(
           ErrorCodes.INVALID_REQUEST,
-          "Invalid provider. Use openai, elevenlabs, or edge.",
+          "Invalid provider. Use openai, elevenlabs, edge, or piper.",
         ),
       );
       return;
@@ -147,6 +153,12 @@ export const ttsHandlers: GatewayRequestHandlers = {
             configured: isTtsProviderConfigured(config, "edge"),
             models: [],
           },
+          {
+            id: "piper",
+            name: "Piper (local)",
+            configured: isTtsProviderConfigured(config, "piper"),
+            models: config.piper.model ? [config.piper.model] : [],
+          },
         ],
         active: getTtsProvider(config, prefsPath),
       });
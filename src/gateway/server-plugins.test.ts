@@ -16,6 +16,7 @@ const createRegistry = (diagnostics: PluginDiagnostic[]): PluginRegistry => ({
   typedHooks: [],
   channels: [],
   commands: [],
+  middlewares: [],
   providers: [],
   gatewayHandlers: {},
   httpHandlers: [],
@@ -35,6 +35,7 @@ export function createGatewayReloadHandlers(params: {
   setState: (state: GatewayHotReloadState) => void;
   startChannel: (name: ChannelKind) => Promise<void>;
   stopChannel: (name: ChannelKind) => Promise<void>;
+  restartSchedules: (nextConfig: ReturnType<typeof loadConfig>) => void;
   logHooks: {
     info: (msg: string) => void;
     warn: (msg: string) => void;
@@ -67,6 +68,10 @@ export function createGatewayReloadHandlers(params: {
 
     resetDirectoryCache();
 
+    if (plan.restartSchedules) {
+      params.restartSchedules(nextConfig);
+    }
+
     if (plan.restartCron) {
       state.cronState.cron.stop();
       nextState.cronState = buildGatewayCronService({
@@ -0,0 +1,31 @@
+import type { OpenClawConfig } from "../config/config.js";
+import { scheduleCostDigest } from "../infra/cost-digest.js";
+import { scheduleCostReconciliation } from "../infra/cost-reconcile.js";
+import { scheduleNotificationDigest } from "../infra/outbound/notification-policy.js";
+import { startSensorWatchdog } from "../infra/sensor-watchdog.js";
+
+/**
+ * Starts the config-driven Gateway timers (cost reconciliation and digest, quiet-hours digest,
+ * sensor watchdog). Each reads its config once, so hot reload stops and restarts them together.
+ */
+export function startGatewaySchedules(params: {
+  cfg: OpenClawConfig;
+  log: { info: (msg: string) => void; warn: (msg: string) => void };
+}): () => void {
+  const { cfg, log } = params;
+  const stops = [
+    cfg.cost?.reconcile ? scheduleCostReconciliation({ cfg, log }) : null,
+    cfg.cost?.digest ? scheduleCostDigest({ cfg, log }) : null,
+    cfg.messages?.notifications ? scheduleNotificationDigest({ cfg, log }) : null,
+    startSensorWatchdog({ cfg, log }),
+  ];
+  return () => {
+    for (const stop of stops) {
+      try {
+        stop?.();
+      } catch {
+        /* ignore */
+      }
+    }
+  };
+}
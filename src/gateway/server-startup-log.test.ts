@@ -38,11 +38,30 @@ describe("gateway startup log", () => {
       port: 18789,
       log: { info, warn },
       isNixMode: false,
+      env: {},
     });
 
     expect(warn).not.toHaveBeenCalled();
   });
 
+  it("names the host that OPENAI_BASE_URL redirects openai requests to", () => {
+    const info = vi.fn();
+    const warn = vi.fn();
+
+    logGatewayStartup({
+      cfg: {},
+      bindHost: "127.0.0.1",
+      port: 18789,
+      log: { info, warn },
+      isNixMode: false,
+      env: { OPENAI_BASE_URL: "https://llm-gateway.example.com/v1/" },
+    });
+
+    expect(warn).toHaveBeenCalledWith(
+      "OPENAI_BASE_URL is set: built-in openai/* requests and the OpenAI API key go to llm-gateway.example.com.",
+    );
+  });
+
   it("logs all listen endpoints on a single line", () => {
     const info = vi.fn();
     const warn = vi.fn();
@@ -1,6 +1,7 @@
 import chalk from "chalk";
 import { DEFAULT_MODEL, DEFAULT_PROVIDER } from "../agents/defaults.js";
 import { resolveConfiguredModelRef } from "../agents/model-selection.js";
+import { resolveOpenAiBaseUrlOverride } from "../agents/pi-embedded-runner/model.js";
 import type { loadConfig } from "../config/config.js";
 import { getResolvedLoggerSettings } from "../logging.js";
 import { collectEnabledInsecureOrDangerousFlags } from "../security/dangerous-config-flags.js";
@@ -13,6 +14,7 @@ export function logGatewayStartup(params: {
   tlsEnabled?: boolean;
   log: { info: (msg: string, meta?: Record<string, unknown>) => void; warn: (msg: string) => void };
   isNixMode: boolean;
+  env?: NodeJS.ProcessEnv;
 }) {
   const { provider: agentProvider, model: agentModel } = resolveConfiguredModelRef({
     cfg: params.cfg,
@@ -41,4 +43,18 @@ export function logGatewayStartup(params: {
       "Run `openclaw security audit`.";
     params.log.warn(warning);
   }
+
+  // A stray OPENAI_BASE_URL silently sends every openai/* request (and its API key) elsewhere.
+  const openAiBaseUrl = resolveOpenAiBaseUrlOverride(params.env);
+  if (openAiBaseUrl) {
+    let host = openAiBaseUrl;
+    try {
+      host = new URL(openAiBaseUrl).host;
+    } catch {
+      // Show the raw value when it is not a URL.
+    }
+    params.log.warn(
+      `OPENAI_BASE_URL is set: built-in openai/* requests and the OpenAI API key go to ${host}.`,
+    );
+  }
 }
@@ -17,6 +17,8 @@ import {
   triggerInternalHook,
 } from "../hooks/internal-hooks.js";
 import { loadInternalHooks } from "../hooks/loader.js";
+import { compactCostRecords } from "../infra/cost-retention.js";
+import { hasCostNotifications, runCostWebhookChecks } from "../infra/cost-webhook.js";
 import { isTruthyEnvValue } from "../infra/env.js";
 import type { loadOpenClawPlugins } from "../plugins/loader.js";
 import { type PluginServicesHandle, startPluginServices } from "../plugins/services.js";
@@ -59,6 +61,20 @@ export async function startGatewaySidecars(params: {
     params.log.warn(`session lock cleanup failed on startup: ${String(err)}`);
   }
 
+  // Fold usage older than cost.retention.rawDays into daily summaries (best-effort, background).
+  if (params.cfg.cost?.retention?.rawDays) {
+    void compactCostRecords({ config: params.cfg }).catch((err) => {
+      params.log.warn(`cost record compaction failed on startup: ${String(err)}`);
+    });
+  }
+
+  // Catch up on missed cost notifications (webhook, budget alerts); later checks run after turns.
+  if (hasCostNotifications(params.cfg)) {
+    void runCostWebhookChecks({ config: params.cfg }).catch((err) => {
+      params.log.warn(`cost webhook delivery failed on startup: ${String(err)}`);
+    });
+  }
+
   // Start OpenClaw browser control server (unless disabled via config).
   let browserControl: Awaited<ReturnType<typeof startBrowserControlServerIfEnabled>> = null;
   try {
@@ -146,6 +146,7 @@ async function withCanvasGatewayHarness(params: {
     controlUiEnabled: false,
     controlUiBasePath: "/__control__",
     openAiChatCompletionsEnabled: false,
+    chatApiEnabled: false,
     openResponsesEnabled: false,
     handleHooksRequest: async () => false,
     resolvedAuth: params.resolvedAuth,
@@ -1,5 +1,6 @@
 import path from "node:path";
 import { resolveAgentWorkspaceDir, resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { isOpenRouterInUse, loadOpenRouterCatalog } from "../agents/openrouter-catalog.js";
 import { getActiveEmbeddedRunCount } from "../agents/pi-embedded-runner/runs.js";
 import { registerSkillsChangeListener } from "../agents/skills/refresh.js";
 import { initSubagentRegistry } from "../agents/subagent-registry.js";
@@ -75,6 +76,7 @@ import { loadGatewayPlugins } from "./server-plugins.js";
 import { createGatewayReloadHandlers } from "./server-reload-handlers.js";
 import { resolveGatewayRuntimeConfig } from "./server-runtime-config.js";
 import { createGatewayRuntimeState } from "./server-runtime-state.js";
+import { startGatewaySchedules } from "./server-schedules.js";
 import { resolveSessionKeyForRun } from "./server-session-key.js";
 import { logGatewayStartup } from "./server-startup-log.js";
 import { startGatewaySidecars } from "./server-startup.js";
@@ -138,6 +140,11 @@ export type GatewayServerOptions = {
    * Default: config `gateway.http.endpoints.chatCompletions.enabled` (or false when absent).
    */
   openAiChatCompletionsEnabled?: boolean;
+  /**
+   * If false, do not serve `POST /v1/chat` (plain chat API for scripts and home automation).
+   * Default: config `gateway.http.endpoints.chat.enabled` (or false when absent).
+   */
+  chatApiEnabled?: boolean;
   /**
    * If false, do not serve `POST /v1/responses` (OpenResponses API).
    * Default: config `gateway.http.endpoints.responses.enabled` (or false when absent).
@@ -291,6 +298,7 @@ export async function startGatewayServer(
     host: opts.host,
     controlUiEnabled: opts.controlUiEnabled,
     openAiChatCompletionsEnabled: opts.openAiChatCompletionsEnabled,
+    chatApiEnabled: opts.chatApiEnabled,
     openResponsesEnabled: opts.openResponsesEnabled,
     auth: opts.auth,
     tailscale: opts.tailscale,
@@ -299,6 +307,7 @@ export async function startGatewayServer(
     bindHost,
     controlUiEnabled,
     openAiChatCompletionsEnabled,
+    chatApiEnabled,
     openResponsesEnabled,
     openResponsesConfig,
     controlUiBasePath,
@@ -383,6 +392,7 @@ export async function startGatewayServer(
     controlUiBasePath,
     controlUiRoot: controlUiRootState,
     openAiChatCompletionsEnabled,
+    chatApiEnabled,
     openResponsesEnabled,
     openResponsesConfig,
     resolvedAuth,
@@ -654,6 +664,14 @@ export async function startGatewayServer(
           broadcast(GATEWAY_EVENT_UPDATE_AVAILABLE, payload, { dropIfSlow: true });
         },
       });
+  let stopGatewaySchedules = minimalTestGateway
+    ? () => {}
+    : startGatewaySchedules({ cfg: cfgAtStart, log });
+  if (!minimalTestGateway && isOpenRouterInUse(cfgAtStart)) {
+    void loadOpenRouterCatalog()
+      .then((count) => log.info(`openrouter catalog: loaded pricing for ${count} models`))
+      .catch((err) => log.warn(`openrouter catalog fetch failed: ${String(err)}`));
+  }
   const tailscaleCleanup = minimalTestGateway
     ? null
     : await startGatewayTailscaleExposure({
@@ -711,6 +729,10 @@ export async function startGatewayServer(
           },
           startChannel,
           stopChannel,
+          restartSchedules: (nextConfig) => {
+            stopGatewaySchedules();
+            stopGatewaySchedules = startGatewaySchedules({ cfg: nextConfig, log });
+          },
           logHooks,
           logBrowser,
           logChannels,
@@ -742,6 +764,7 @@ export async function startGatewayServer(
     cron,
     heartbeatRunner,
     updateCheckStop: stopGatewayUpdateCheck,
+    schedulesStop: () => stopGatewaySchedules(),
     nodePresenceTimers,
     broadcast,
     tickInterval,
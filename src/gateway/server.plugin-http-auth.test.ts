@@ -101,6 +101,7 @@ describe("gateway plugin HTTP auth boundary", () => {
           controlUiEnabled: false,
           controlUiBasePath: "/__control__",
           openAiChatCompletionsEnabled: false,
+          chatApiEnabled: false,
           openResponsesEnabled: false,
           handleHooksRequest: async () => false,
           handlePluginRequest,
@@ -220,6 +220,38 @@ function parseArchivedTimestamp(fileName: string, reason: ArchiveFileReason): nu
   return Number.isNaN(timestamp) ? null : timestamp;
 }
 
+/**
+ * Bring back a transcript archived by a reset. Returns the live path when the file is already
+ * there, the restored path when the newest `.reset.` archive was renamed back, or null.
+ */
+export function restoreResetTranscript(filePath: string): string | null {
+  if (fs.existsSync(filePath)) {
+    return filePath;
+  }
+  const dir = path.dirname(filePath);
+  const prefix = `${path.basename(filePath)}.reset.`;
+  let entries: string[];
+  try {
+    entries = fs.readdirSync(dir);
+  } catch {
+    return null;
+  }
+  const latest = entries
+    .filter((entry) => entry.startsWith(prefix))
+    .map((entry) => ({ entry, timestamp: parseArchivedTimestamp(entry, "reset") }))
+    .filter((candidate) => candidate.timestamp != null)
+    .toSorted((a, b) => (b.timestamp ?? 0) - (a.timestamp ?? 0))[0];
+  if (!latest) {
+    return null;
+  }
+  try {
+    fs.renameSync(path.join(dir, latest.entry), filePath);
+  } catch {
+    return null;
+  }
+  return filePath;
+}
+
 export async function cleanupArchivedSessionTranscripts(opts: {
   directories: string[];
   olderThanMs: number;
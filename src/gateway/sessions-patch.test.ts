@@ -209,6 +209,33 @@ describe("gateway sessions patch", () => {
     expect(res.error.message).toContain("spawnDepth is only supported");
   });
 
+  test("sets spawnTools once for subagent sessions", async () => {
+    const store: Record<string, SessionEntry> = {};
+    const res = await applySessionsPatchToStore({
+      cfg: {} as OpenClawConfig,
+      store,
+      storeKey: "agent:main:subagent:child",
+      patch: { key: "agent:main:subagent:child", spawnTools: [" read ", "exec", "read"] },
+    });
+    expect(res.ok).toBe(true);
+    if (!res.ok) {
+      return;
+    }
+    expect(res.entry.spawnTools).toEqual(["read", "exec"]);
+
+    const widened = await applySessionsPatchToStore({
+      cfg: {} as OpenClawConfig,
+      store: { "agent:main:subagent:child": res.entry },
+      storeKey: "agent:main:subagent:child",
+      patch: { key: "agent:main:subagent:child", spawnTools: ["read", "exec", "browser"] },
+    });
+    expect(widened.ok).toBe(false);
+    if (widened.ok) {
+      return;
+    }
+    expect(widened.error.message).toContain("spawnTools cannot be changed");
+  });
+
   test("normalizes exec/send/group patches", async () => {
     const store: Record<string, SessionEntry> = {};
     const res = await applySessionsPatchToStore({
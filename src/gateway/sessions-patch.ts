@@ -129,6 +129,29 @@ export async function applySessionsPatchToStore(params: {
     }
   }
 
+  if ("spawnTools" in patch) {
+    const raw = patch.spawnTools;
+    if (raw === null) {
+      if (existing?.spawnTools) {
+        return invalid("spawnTools cannot be cleared once set");
+      }
+    } else if (raw !== undefined) {
+      if (!isSubagentSessionKey(storeKey)) {
+        return invalid("spawnTools is only supported for subagent:* sessions");
+      }
+      if (!Array.isArray(raw)) {
+        return invalid("invalid spawnTools (use an array of tool names)");
+      }
+      const normalized = Array.from(
+        new Set(raw.map((entry) => String(entry).trim()).filter(Boolean)),
+      );
+      if (existing?.spawnTools && existing.spawnTools.join(",") !== normalized.join(",")) {
+        return invalid("spawnTools cannot be changed once set");
+      }
+      next.spawnTools = normalized;
+    }
+  }
+
   if ("label" in patch) {
     const raw = patch.label;
     if (raw === null) {
@@ -272,6 +272,27 @@ describe("POST /tools/invoke", () => {
     expect(body).toHaveProperty("result");
   });
 
+  it("accepts the tool name in the path at /v1/tools/:name", async () => {
+    allowAgentsListForMain();
+    const res = await fetch(`http://127.0.0.1:${sharedPort}/v1/tools/agents_list`, {
+      method: "POST",
+      headers: { "content-type": "application/json", ...gatewayAuthHeaders() },
+      body: JSON.stringify({ tool: "session_status", action: "json", args: {} }),
+    });
+
+    expect(res.status).toBe(200);
+    const body = await res.json();
+    expect(body.ok).toBe(true);
+    expect(body.result).toEqual({ ok: true, result: [] });
+
+    const nested = await fetch(`http://127.0.0.1:${sharedPort}/v1/tools/agents_list/extra`, {
+      method: "POST",
+      headers: { "content-type": "application/json", ...gatewayAuthHeaders() },
+      body: JSON.stringify({}),
+    });
+    expect(nested.status).toBe(404);
+  });
+
   it("supports tools.alsoAllow in profile and implicit modes", async () => {
     cfg = {
       ...cfg,
@@ -35,7 +35,15 @@ import {
 import { getBearerToken, getHeader } from "./http-utils.js";
 
 const DEFAULT_BODY_BYTES = 2 * 1024 * 1024;
-const MEMORY_TOOL_NAMES = new Set(["memory_search", "memory_get"]);
+const MEMORY_TOOL_NAMES = new Set([
+  "memory_search",
+  "memory_get",
+  "memory_set",
+  "memory_list",
+  "kb_search",
+  "kb_ingest",
+]);
+const TOOLS_PATH_PREFIX = "/v1/tools/";
 
 type ToolsInvokeBody = {
   tool?: unknown;
@@ -45,6 +53,28 @@ type ToolsInvokeBody = {
   dryRun?: unknown;
 };
 
+/**
+ * `/tools/invoke` names the tool in the body; `/v1/tools/<name>` names it in the path.
+ * Returns null when the path is neither.
+ */
+function resolveToolNameFromPath(pathname: string): { fromPath?: string } | null {
+  if (pathname === "/tools/invoke") {
+    return {};
+  }
+  if (!pathname.startsWith(TOOLS_PATH_PREFIX)) {
+    return null;
+  }
+  const rest = pathname.slice(TOOLS_PATH_PREFIX.length);
+  if (!rest || rest.includes("/")) {
+    return null;
+  }
+  try {
+    return { fromPath: decodeURIComponent(rest).trim() };
+  } catch {
+    return { fromPath: "" };
+  }
+}
+
 function resolveSessionKeyFromBody(body: ToolsInvokeBody): string | undefined {
   if (typeof body.sessionKey === "string" && body.sessionKey.trim()) {
     return body.sessionKey.trim();
@@ -131,89 +161,29 @@ function resolveToolInputErrorStatus(err: unknown): number | null {
   return name === "ToolAuthorizationError" ? 403 : 400;
 }
 
-export async function handleToolsInvokeHttpRequest(
-  req: IncomingMessage,
-  res: ServerResponse,
-  opts: {
-    auth: ResolvedGatewayAuth;
-    maxBodyBytes?: number;
-    trustedProxies?: string[];
-    allowRealIpFallback?: boolean;
-    rateLimiter?: AuthRateLimiter;
-  },
-): Promise<boolean> {
-  const url = new URL(req.url ?? "/", `http://${req.headers.host ?? "localhost"}`);
-  if (url.pathname !== "/tools/invoke") {
-    return false;
-  }
-
-  if (req.method !== "POST") {
-    sendMethodNotAllowed(res, "POST");
-    return true;
-  }
-
-  const cfg = loadConfig();
-  const token = getBearerToken(req);
-  const authResult = await authorizeHttpGatewayConnect({
-    auth: opts.auth,
-    connectAuth: token ? { token, password: token } : null,
-    req,
-    trustedProxies: opts.trustedProxies ?? cfg.gateway?.trustedProxies,
-    allowRealIpFallback: opts.allowRealIpFallback ?? cfg.gateway?.allowRealIpFallback,
-    rateLimiter: opts.rateLimiter,
-  });
-  if (!authResult.ok) {
-    sendGatewayAuthFailure(res, authResult);
-    return true;
-  }
-
-  const bodyUnknown = await readJsonBodyOrError(req, res, opts.maxBodyBytes ?? DEFAULT_BODY_BYTES);
-  if (bodyUnknown === undefined) {
-    return true;
-  }
-  const body = (bodyUnknown ?? {}) as ToolsInvokeBody;
-
-  const toolName = typeof body.tool === "string" ? body.tool.trim() : "";
-  if (!toolName) {
-    sendInvalidRequest(res, "tools.invoke requires body.tool");
-    return true;
-  }
-
-  if (process.env.VITEST && MEMORY_TOOL_NAMES.has(toolName)) {
-    const reasons = resolveMemoryToolDisableReasons(cfg);
-    if (reasons.length > 0) {
-      const suffix = reasons.length > 0 ? ` (${reasons.join(", ")})` : "";
-      sendJson(res, 400, {
-        ok: false,
-        error: {
-          type: "invalid_request",
-          message:
-            `memory tools are disabled in tests${suffix}. ` +
-            'Enable by setting plugins.slots.memory="memory-core" (and ensure plugins.enabled is not false).',
-        },
-      });
-      return true;
-    }
-  }
-
-  const action = typeof body.action === "string" ? body.action.trim() : undefined;
+export type GatewayToolInvokeOutcome =
+  | { ok: true; result: unknown }
+  | { ok: false; status: number; type: "not_found" | "tool_error"; message: string };
 
-  const argsRaw = body.args;
-  const args =
-    argsRaw && typeof argsRaw === "object" && !Array.isArray(argsRaw)
-      ? (argsRaw as Record<string, unknown>)
-      : {};
-
-  const rawSessionKey = resolveSessionKeyFromBody(body);
+/**
+ * Runs one tool outside an agent turn, filtered through the same policy chain as Gateway agents
+ * plus the `gateway.tools` deny list. Shared by `/tools/invoke` and the `tools.invoke` method.
+ */
+export async function invokeGatewayTool(params: {
+  cfg: ReturnType<typeof loadConfig>;
+  toolName: string;
+  args: Record<string, unknown>;
+  action?: string;
+  sessionKey?: string;
+  messageChannel?: string;
+  accountId?: string;
+  callId: string;
+}): Promise<GatewayToolInvokeOutcome> {
+  const { cfg, toolName, messageChannel, accountId } = params;
+  const rawSessionKey = params.sessionKey?.trim();
   const sessionKey =
     !rawSessionKey || rawSessionKey === "main" ? resolveMainSessionKey(cfg) : rawSessionKey;
 
-  // Resolve message channel/account hints (optional headers) for policy inheritance.
-  const messageChannel = normalizeMessageChannel(
-    getHeader(req, "x-openclaw-message-channel") ?? "",
-  );
-  const accountId = getHeader(req, "x-openclaw-account-id")?.trim() || undefined;
-
   const {
     agentId,
     globalPolicy,
@@ -236,7 +206,7 @@ export async function handleToolsInvokeHttpRequest(
   const groupPolicy = resolveGroupToolPolicy({
     config: cfg,
     sessionKey,
-    messageProvider: messageChannel ?? undefined,
+    messageProvider: messageChannel,
     accountId: accountId ?? null,
   });
   const subagentPolicy = isSubagentSessionKey(sessionKey)
@@ -246,7 +216,7 @@ export async function handleToolsInvokeHttpRequest(
   // Build tool list (core + plugin tools).
   const allTools = createOpenClawTools({
     agentSessionKey: sessionKey,
-    agentChannel: messageChannel ?? undefined,
+    agentChannel: messageChannel,
     agentAccountId: accountId,
     config: cfg,
     pluginToolAllowlist: collectExplicitAllowlist([
@@ -284,7 +254,7 @@ export async function handleToolsInvokeHttpRequest(
     ],
   });
 
-  // Gateway HTTP-specific deny list — applies to ALL sessions via HTTP.
+  // Gateway deny list — applies to ALL sessions invoked over HTTP or `tools.invoke`.
   const gatewayToolsCfg = cfg.gateway?.tools;
   const defaultGatewayDeny: string[] = DEFAULT_GATEWAY_HTTP_TOOL_DENY.filter(
     (name) => !gatewayToolsCfg?.allow?.includes(name),
@@ -297,36 +267,138 @@ export async function handleToolsInvokeHttpRequest(
 
   const tool = gatewayFiltered.find((t) => t.name === toolName);
   if (!tool) {
-    sendJson(res, 404, {
+    return {
       ok: false,
-      error: { type: "not_found", message: `Tool not available: ${toolName}` },
-    });
-    return true;
+      status: 404,
+      type: "not_found",
+      message: `Tool not available: ${toolName}`,
+    };
   }
 
   try {
     const toolArgs = mergeActionIntoArgsIfSupported({
       // oxlint-disable-next-line typescript/no-explicit-any
       toolSchema: (tool as any).parameters,
-      action,
-      args,
+      action: params.action,
+      args: params.args,
     });
     // oxlint-disable-next-line typescript/no-explicit-any
-    const result = await (tool as any).execute?.(`http-${Date.now()}`, toolArgs);
-    sendJson(res, 200, { ok: true, result });
+    const result = await (tool as any).execute?.(params.callId, toolArgs);
+    return { ok: true, result };
   } catch (err) {
     const inputStatus = resolveToolInputErrorStatus(err);
     if (inputStatus !== null) {
-      sendJson(res, inputStatus, {
+      return {
         ok: false,
-        error: { type: "tool_error", message: getErrorMessage(err) || "invalid tool arguments" },
+        status: inputStatus,
+        type: "tool_error",
+        message: getErrorMessage(err) || "invalid tool arguments",
+      };
+    }
+    logWarn(`tools-invoke: tool execution failed: ${String(err)}`);
+    return { ok: false, status: 500, type: "tool_error", message: "tool execution failed" };
+  }
+}
+
+export async function handleToolsInvokeHttpRequest(
+  req: IncomingMessage,
+  res: ServerResponse,
+  opts: {
+    auth: ResolvedGatewayAuth;
+    maxBodyBytes?: number;
+    trustedProxies?: string[];
+    allowRealIpFallback?: boolean;
+    rateLimiter?: AuthRateLimiter;
+  },
+): Promise<boolean> {
+  const url = new URL(req.url ?? "/", `http://${req.headers.host ?? "localhost"}`);
+  const route = resolveToolNameFromPath(url.pathname);
+  if (!route) {
+    return false;
+  }
+
+  if (req.method !== "POST") {
+    sendMethodNotAllowed(res, "POST");
+    return true;
+  }
+
+  const cfg = loadConfig();
+  const token = getBearerToken(req);
+  const authResult = await authorizeHttpGatewayConnect({
+    auth: opts.auth,
+    connectAuth: token ? { token, password: token } : null,
+    req,
+    trustedProxies: opts.trustedProxies ?? cfg.gateway?.trustedProxies,
+    allowRealIpFallback: opts.allowRealIpFallback ?? cfg.gateway?.allowRealIpFallback,
+    rateLimiter: opts.rateLimiter,
+  });
+  if (!authResult.ok) {
+    sendGatewayAuthFailure(res, authResult);
+    return true;
+  }
+
+  const bodyUnknown = await readJsonBodyOrError(req, res, opts.maxBodyBytes ?? DEFAULT_BODY_BYTES);
+  if (bodyUnknown === undefined) {
+    return true;
+  }
+  const body = (bodyUnknown ?? {}) as ToolsInvokeBody;
+
+  const toolName = route.fromPath ?? (typeof body.tool === "string" ? body.tool.trim() : "");
+  if (!toolName) {
+    sendInvalidRequest(
+      res,
+      route.fromPath === undefined ? "tools.invoke requires body.tool" : "invalid tool name",
+    );
+    return true;
+  }
+
+  if (process.env.VITEST && MEMORY_TOOL_NAMES.has(toolName)) {
+    const reasons = resolveMemoryToolDisableReasons(cfg);
+    if (reasons.length > 0) {
+      const suffix = reasons.length > 0 ? ` (${reasons.join(", ")})` : "";
+      sendJson(res, 400, {
+        ok: false,
+        error: {
+          type: "invalid_request",
+          message:
+            `memory tools are disabled in tests${suffix}. ` +
+            'Enable by setting plugins.slots.memory="memory-core" (and ensure plugins.enabled is not false).',
+        },
       });
       return true;
     }
-    logWarn(`tools-invoke: tool execution failed: ${String(err)}`);
-    sendJson(res, 500, {
+  }
+
+  const action = typeof body.action === "string" ? body.action.trim() : undefined;
+
+  const argsRaw = body.args;
+  const args =
+    argsRaw && typeof argsRaw === "object" && !Array.isArray(argsRaw)
+      ? (argsRaw as Record<string, unknown>)
+      : {};
+
+  // Resolve message channel/account hints (optional headers) for policy inheritance.
+  const messageChannel = normalizeMessageChannel(
+    getHeader(req, "x-openclaw-message-channel") ?? "",
+  );
+  const accountId = getHeader(req, "x-openclaw-account-id")?.trim() || undefined;
+
+  const outcome = await invokeGatewayTool({
+    cfg,
+    toolName,
+    action,
+    args,
+    sessionKey: resolveSessionKeyFromBody(body),
+    messageChannel: messageChannel ?? undefined,
+    accountId,
+    callId: `http-${Date.now()}`,
+  });
+  if (outcome.ok) {
+    sendJson(res, 200, { ok: true, result: outcome.result });
+  } else {
+    sendJson(res, outcome.status, {
       ok: false,
-      error: { type: "tool_error", message: "tool execution failed" },
+      error: { type: outcome.type, message: outcome.message },
     });
   }
 
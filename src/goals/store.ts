@@ -0,0 +1,168 @@
+import { randomUUID } from "node:crypto";
+import path from "node:path";
+import { createAsyncLock, readJsonFile, writeJsonAtomic } from "../infra/json-files.js";
+import { CONFIG_DIR } from "../utils.js";
+
+export const DEFAULT_GOAL_STORE_PATH = path.join(CONFIG_DIR, "goals", "goals.json");
+
+export const GOAL_STATUSES = ["active", "paused", "achieved", "abandoned"] as const;
+export type GoalStatus = (typeof GOAL_STATUSES)[number];
+
+export type GoalProgressEntry = {
+  at: number;
+  note: string;
+};
+
+/** A standing objective ("keep the greenhouse between 18–24°C") that outlives a session. */
+export type AgentGoal = {
+  id: string;
+  agentId: string;
+  objective: string;
+  status: GoalStatus;
+  /** Session the goal was set from; progress reports go there by default. */
+  sessionKey?: string;
+  createdAt: number;
+  updatedAt: number;
+  /** Newest last, capped at MAX_PROGRESS_ENTRIES. */
+  progress: GoalProgressEntry[];
+};
+
+type GoalStoreFile = { version: 1; goals: AgentGoal[] };
+
+const MAX_PROGRESS_ENTRIES = 20;
+const MAX_PROMPT_GOALS = 10;
+
+const withGoalStoreLock = createAsyncLock();
+
+async function loadGoalStore(storePath: string): Promise<GoalStoreFile> {
+  const parsed = await readJsonFile<Partial<GoalStoreFile>>(storePath);
+  const goals = Array.isArray(parsed?.goals) ? parsed.goals : [];
+  return {
+    version: 1,
+    goals: goals
+      .filter((goal) => goal && typeof goal.id === "string")
+      .map((goal) => ({ ...goal, progress: Array.isArray(goal.progress) ? goal.progress : [] })),
+  };
+}
+
+async function updateGoalStore<T>(
+  storePath: string,
+  mutate: (store: GoalStoreFile) => T,
+): Promise<T> {
+  return await withGoalStoreLock(async () => {
+    const store = await loadGoalStore(storePath);
+    const result = mutate(store);
+    await writeJsonAtomic(storePath, store);
+    return result;
+  });
+}
+
+export async function addGoal(
+  storePath: string,
+  input: { agentId: string; objective: string; sessionKey?: string; now?: number },
+): Promise<AgentGoal> {
+  const now = input.now ?? Date.now();
+  const goal: AgentGoal = {
+    id: randomUUID().slice(0, 8),
+    agentId: input.agentId,
+    objective: input.objective.trim(),
+    status: "active",
+    ...(input.sessionKey ? { sessionKey: input.sessionKey } : {}),
+    createdAt: now,
+    updatedAt: now,
+    progress: [],
+  };
+  return await updateGoalStore(storePath, (store) => {
+    store.goals.push(goal);
+    return goal;
+  });
+}
+
+/** Changes status and/or objective and appends a progress note; null when the id is unknown. */
+export async function updateGoal(
+  storePath: string,
+  params: {
+    agentId: string;
+    id: string;
+    status?: GoalStatus;
+    objective?: string;
+    progress?: string;
+    now?: number;
+  },
+): Promise<AgentGoal | null> {
+  const now = params.now ?? Date.now();
+  const note = params.progress?.trim();
+  return await updateGoalStore(storePath, (store) => {
+    const goal = store.goals.find(
+      (entry) => entry.id === params.id && entry.agentId === params.agentId,
+    );
+    if (!goal) {
+      return null;
+    }
+    if (params.status) {
+      goal.status = params.status;
+    }
+    if (params.objective?.trim()) {
+      goal.objective = params.objective.trim();
+    }
+    if (note) {
+      goal.progress = [...goal.progress, { at: now, note }].slice(-MAX_PROGRESS_ENTRIES);
+    }
+    goal.updatedAt = now;
+    return { ...goal };
+  });
+}
+
+/** Active goals first, then paused, then finished; oldest first within each status. */
+export async function listGoals(
+  storePath: string,
+  opts: { agentId: string; includeInactive?: boolean },
+): Promise<AgentGoal[]> {
+  const store = await loadGoalStore(storePath);
+  return store.goals
+    .filter(
+      (goal) => goal.agentId === opts.agentId && (opts.includeInactive || goal.status === "active"),
+    )
+    .toSorted(
+      (a, b) =>
+        GOAL_STATUSES.indexOf(a.status) - GOAL_STATUSES.indexOf(b.status) ||
+        a.createdAt - b.createdAt,
+    );
+}
+
+export async function getGoal(
+  storePath: string,
+  params: { agentId: string; id: string },
+): Promise<AgentGoal | null> {
+  const store = await loadGoalStore(storePath);
+  return (
+    store.goals.find((goal) => goal.id === params.id && goal.agentId === params.agentId) ?? null
+  );
+}
+
+/**
+ * Prompt section listing active goals with their latest progress, appended to heartbeat and
+ * trigger prompts so every proactive run checks them. Empty when there is nothing to pursue.
+ */
+export function buildGoalsPrompt(goals: AgentGoal[]): string {
+  const active = goals.filter((goal) => goal.status === "active").slice(0, MAX_PROMPT_GOALS);
+  if (active.length === 0) {
+    return "";
+  }
+  const lines = active.map((goal) => {
+    const last = goal.progress.at(-1);
+    const latest = last ? ` (last progress ${new Date(last.at).toISOString()}: ${last.note})` : "";
+    return `- [${goal.id}] ${goal.objective}${latest}`;
+  });
+  return [
+    "Active goals (standing objectives; check each one, act if it is off track, and record progress with goal_set):",
+    ...lines,
+  ].join("\n");
+}
+
+export async function resolveActiveGoalsPrompt(
+  agentId: string,
+  storePath = DEFAULT_GOAL_STORE_PATH,
+): Promise<string> {
+  return buildGoalsPrompt(await listGoals(storePath, { agentId }));
+}
@@ -0,0 +1,76 @@
+import { describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { broadcastAlert, hasAlertTargets, resolveAlertTargets } from "./alerts.js";
+
+type DeliverFn = NonNullable<Parameters<typeof broadcastAlert>[0]["deliver"]>;
+
+const config = {
+  messages: {
+    alerts: {
+      targets: [
+        { channel: "telegram", to: "123" },
+        { channel: "Slack", to: "C0ALERTS", accountId: "ops", kinds: ["budget"] },
+        { channel: "discord", to: "456", kinds: ["reconcile"] },
+        { channel: "not-a-channel", to: "789" },
+        { channel: "signal", to: "  " },
+      ],
+    },
+  },
+} as OpenClawConfig;
+
+describe("alerts", () => {
+  it("filters targets by kind and drops undeliverable entries", () => {
+    expect(resolveAlertTargets(config, "budget").map((t) => `${t.channel}:${t.to}`)).toEqual([
+      "telegram:123",
+      "slack:C0ALERTS",
+    ]);
+    expect(resolveAlertTargets(config, "reconcile").map((t) => t.channel)).toEqual([
+      "telegram",
+      "discord",
+    ]);
+    expect(hasAlertTargets({} as OpenClawConfig, "budget")).toBe(false);
+  });
+
+  it("delivers to every target and keeps going when one channel fails", async () => {
+    const deliver = vi.fn(async (params: { channel: string }) => {
+      if (params.channel === "telegram") {
+        throw new Error("bot blocked");
+      }
+      return [];
+    });
+
+    const results = await broadcastAlert({
+      config,
+      kind: "budget",
+      text: "budget at 80%",
+      deliver: deliver as unknown as DeliverFn,
+    });
+
+    expect(deliver).toHaveBeenCalledTimes(2);
+    expect(deliver).toHaveBeenCalledWith(
+      expect.objectContaining({
+        channel: "slack",
+        to: "C0ALERTS",
+        accountId: "ops",
+        payloads: [{ text: "budget at 80%" }],
+      }),
+    );
+    expect(results.map((result) => [result.target.channel, result.ok])).toEqual([
+      ["telegram", false],
+      ["slack", true],
+    ]);
+    expect(results[0]?.error).toContain("bot blocked");
+  });
+
+  it("does nothing without matching targets", async () => {
+    const deliver = vi.fn();
+    const results = await broadcastAlert({
+      config: {} as OpenClawConfig,
+      kind: "budget",
+      text: "x",
+      deliver: deliver as unknown as DeliverFn,
+    });
+    expect(results).toEqual([]);
+    expect(deliver).not.toHaveBeenCalled();
+  });
+});
@@ -0,0 +1,83 @@
+import type { OpenClawConfig } from "../config/config.js";
+import type { AlertKind, AlertTarget, NotificationSeverity } from "../config/types.messages.js";
+import { createSubsystemLogger } from "../logging/subsystem.js";
+import {
+  isDeliverableMessageChannel,
+  normalizeMessageChannel,
+  type DeliverableMessageChannel,
+} from "../utils/message-channel.js";
+import type { deliverOutboundPayloads } from "./outbound/deliver.js";
+
+const log = createSubsystemLogger("gateway/alerts");
+
+export type ResolvedAlertTarget = AlertTarget & { channel: DeliverableMessageChannel };
+
+export type AlertDeliveryResult = {
+  target: ResolvedAlertTarget;
+  ok: boolean;
+  error?: string;
+};
+
+/** `messages.alerts.targets` that take `kind`, limited to deliverable channels. */
+export function resolveAlertTargets(
+  config: OpenClawConfig,
+  kind: AlertKind,
+): ResolvedAlertTarget[] {
+  const targets: ResolvedAlertTarget[] = [];
+  for (const target of config.messages?.alerts?.targets ?? []) {
+    const channel = normalizeMessageChannel(target.channel);
+    const to = target.to?.trim();
+    if (!channel || !isDeliverableMessageChannel(channel) || !to) {
+      continue;
+    }
+    if (target.kinds && !target.kinds.includes(kind)) {
+      continue;
+    }
+    targets.push({ ...target, channel, to });
+  }
+  return targets;
+}
+
+export function hasAlertTargets(config: OpenClawConfig, kind: AlertKind): boolean {
+  return resolveAlertTargets(config, kind).length > 0;
+}
+
+/**
+ * Send an alert to every matching `messages.alerts` target concurrently. One failing
+ * channel does not stop the others; failures are logged and returned, never thrown.
+ */
+export async function broadcastAlert(params: {
+  config: OpenClawConfig;
+  kind: AlertKind;
+  text: string;
+  /** Severity for `messages.notifications` (default: "warning"). */
+  severity?: NotificationSeverity;
+  deliver?: typeof deliverOutboundPayloads;
+}): Promise<AlertDeliveryResult[]> {
+  const targets = resolveAlertTargets(params.config, params.kind);
+  if (targets.length === 0) {
+    return [];
+  }
+  const deliver = params.deliver ?? (await import("./outbound/deliver.js")).deliverOutboundPayloads;
+  return await Promise.all(
+    targets.map(async (target): Promise<AlertDeliveryResult> => {
+      try {
+        await deliver({
+          cfg: params.config,
+          channel: target.channel,
+          to: target.to,
+          accountId: target.accountId,
+          threadId: target.threadId,
+          payloads: [{ text: params.text }],
+          notification: { severity: params.severity ?? "warning", kind: params.kind },
+        });
+        return { target, ok: true };
+      } catch (err) {
+        log.error(
+          `alerts: failed to deliver ${params.kind} alert to ${target.channel}:${target.to}: ${String(err)}`,
+        );
+        return { target, ok: false, error: String(err) };
+      }
+    }),
+  );
+}
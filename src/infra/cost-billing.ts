@@ -0,0 +1,161 @@
+import { fetchJson, parseFiniteNumber } from "./provider-usage.fetch.shared.js";
+
+export type BillingProviderId = "openai" | "anthropic";
+
+export const BILLING_PROVIDERS: readonly BillingProviderId[] = ["openai", "anthropic"];
+
+/** One UTC day of billed cost as reported by the provider. */
+export type BillingDailyCost = {
+  date: string; // YYYY-MM-DD (UTC bucket start)
+  costUsd: number;
+};
+
+export type BillingFetchResult = {
+  provider: BillingProviderId;
+  days: BillingDailyCost[];
+  error?: string;
+};
+
+type OpenAiCostsResponse = {
+  data?: Array<{
+    start_time?: number;
+    results?: Array<{ amount?: { value?: number | string; currency?: string } }>;
+  }>;
+  has_more?: boolean;
+  next_page?: string | null;
+};
+
+type AnthropicCostReportResponse = {
+  data?: Array<{
+    starting_at?: string;
+    results?: Array<{ amount?: number | string; currency?: string }>;
+  }>;
+  has_more?: boolean;
+  next_page?: string | null;
+};
+
+const OPENAI_COSTS_URL = "https://api.openai.com/v1/organization/costs";
+const ANTHROPIC_COST_REPORT_URL = "https://api.anthropic.com/v1/organizations/cost_report";
+const MAX_BILLING_PAGES = 20;
+
+const formatUtcDate = (ms: number) => new Date(ms).toISOString().slice(0, 10);
+
+function addDailyCost(days: Map<string, number>, date: string, costUsd: number) {
+  days.set(date, (days.get(date) ?? 0) + costUsd);
+}
+
+function toDailyCosts(days: Map<string, number>): BillingDailyCost[] {
+  return Array.from(days, ([date, costUsd]) => ({ date, costUsd })).toSorted((a, b) =>
+    a.date.localeCompare(b.date),
+  );
+}
+
+async function readHttpError(res: Response): Promise<string> {
+  const body = await res.text().catch(() => "");
+  const message = (() => {
+    try {
+      const parsed = JSON.parse(body) as { error?: { message?: string } };
+      return parsed.error?.message?.trim();
+    } catch {
+      return body.trim().slice(0, 200);
+    }
+  })();
+  return message ? `HTTP ${res.status}: ${message}` : `HTTP ${res.status}`;
+}
+
+/** OpenAI organization Costs API (admin key); amounts are in dollars. */
+export async function fetchOpenAiBillingCosts(params: {
+  adminKey: string;
+  startMs: number;
+  endMs: number;
+  timeoutMs: number;
+  fetchFn?: typeof fetch;
+}): Promise<BillingFetchResult> {
+  const days = new Map<string, number>();
+  let page: string | undefined;
+  for (let i = 0; i < MAX_BILLING_PAGES; i += 1) {
+    const url = new URL(OPENAI_COSTS_URL);
+    url.searchParams.set("start_time", String(Math.floor(params.startMs / 1000)));
+    url.searchParams.set("end_time", String(Math.ceil(params.endMs / 1000)));
+    url.searchParams.set("bucket_width", "1d");
+    url.searchParams.set("limit", "31");
+    if (page) {
+      url.searchParams.set("page", page);
+    }
+    const res = await fetchJson(
+      url.toString(),
+      { headers: { Authorization: `Bearer ${params.adminKey}` } },
+      params.timeoutMs,
+      params.fetchFn ?? fetch,
+    );
+    if (!res.ok) {
+      return { provider: "openai", days: toDailyCosts(days), error: await readHttpError(res) };
+    }
+    const data = (await res.json()) as OpenAiCostsResponse;
+    for (const bucket of data.data ?? []) {
+      if (typeof bucket.start_time !== "number") {
+        continue;
+      }
+      const date = formatUtcDate(bucket.start_time * 1000);
+      for (const result of bucket.results ?? []) {
+        addDailyCost(days, date, parseFiniteNumber(result.amount?.value) ?? 0);
+      }
+    }
+    if (!data.has_more || !data.next_page) {
+      break;
+    }
+    page = data.next_page;
+  }
+  return { provider: "openai", days: toDailyCosts(days) };
+}
+
+/** Anthropic Admin API cost report; amounts are decimal strings in cents. */
+export async function fetchAnthropicBillingCosts(params: {
+  adminKey: string;
+  startMs: number;
+  endMs: number;
+  timeoutMs: number;
+  fetchFn?: typeof fetch;
+}): Promise<BillingFetchResult> {
+  const days = new Map<string, number>();
+  let page: string | undefined;
+  for (let i = 0; i < MAX_BILLING_PAGES; i += 1) {
+    const url = new URL(ANTHROPIC_COST_REPORT_URL);
+    url.searchParams.set("starting_at", new Date(params.startMs).toISOString());
+    url.searchParams.set("ending_at", new Date(params.endMs).toISOString());
+    url.searchParams.set("bucket_width", "1d");
+    if (page) {
+      url.searchParams.set("page", page);
+    }
+    const res = await fetchJson(
+      url.toString(),
+      {
+        headers: {
+          "x-api-key": params.adminKey,
+          "anthropic-version": "2023-06-01",
+        },
+      },
+      params.timeoutMs,
+      params.fetchFn ?? fetch,
+    );
+    if (!res.ok) {
+      return { provider: "anthropic", days: toDailyCosts(days), error: await readHttpError(res) };
+    }
+    const data = (await res.json()) as AnthropicCostReportResponse;
+    for (const bucket of data.data ?? []) {
+      const startMs = bucket.starting_at ? Date.parse(bucket.starting_at) : Number.NaN;
+      if (!Number.isFinite(startMs)) {
+        continue;
+      }
+      const date = formatUtcDate(startMs);
+      for (const result of bucket.results ?? []) {
+        addDailyCost(days, date, (parseFiniteNumber(result.amount) ?? 0) / 100);
+      }
+    }
+    if (!data.has_more || !data.next_page) {
+      break;
+    }
+    page = data.next_page;
+  }
+  return { provider: "anthropic", days: toDailyCosts(days) };
+}
@@ -0,0 +1,180 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import {
+  checkCostBudget,
+  estimateRequestCost,
+  evaluateCostBudget,
+  formatBudgetCheckNotice,
+  formatBudgetFallbackNotice,
+  loadCostBudgetSpend,
+  resetCostBudgetSpendCacheForTest,
+  resolveBudgetFallbackModel,
+} from "./cost-budget.js";
+
+const pricedConfig = {
+  models: {
+    providers: {
+      openai: {
+        models: [{ id: "gpt-5.2", cost: { input: 2, output: 10, cacheRead: 0, cacheWrite: 0 } }],
+      },
+    },
+  },
+} as unknown as OpenClawConfig;
+
+describe("cost budget", () => {
+  afterEach(() => {
+    resetCostBudgetSpendCacheForTest();
+  });
+
+  it("estimates request cost from local token counts", () => {
+    const estimate = estimateRequestCost({
+      config: pricedConfig,
+      provider: "openai",
+      model: "gpt-5.2",
+      contextTokens: 10_000,
+      outputTokens: 1000,
+    });
+    expect(estimate.inputTokens).toBe(10_000);
+    expect(estimate.costUsd).toBeCloseTo(0.03, 6);
+
+    const unpriced = estimateRequestCost({ provider: "ollama", model: "llama3", prompt: "hi" });
+    expect(unpriced.outputTokens).toBe(1024);
+    expect(unpriced.costUsd).toBeUndefined();
+    expect(unpriced.zeroCost).toBe(false);
+  });
+
+  it("exempts zero-priced local models from budget checks", async () => {
+    const config = {
+      cost: { budget: { dailyUsd: 1, action: "refuse" } },
+      models: {
+        providers: {
+          ollama: {
+            models: [
+              { id: "llama3.3", cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0 } },
+            ],
+          },
+        },
+      },
+    } as unknown as OpenClawConfig;
+    const estimate = estimateRequestCost({ config, provider: "ollama", model: "llama3.3" });
+    expect(estimate).toMatchObject({ costUsd: 0, zeroCost: true });
+    expect(await checkCostBudget({ config, estimate })).toBeUndefined();
+  });
+
+  it("checks the most constrained limit", () => {
+    expect(evaluateCostBudget({ budget: {}, spend: { dayUsd: 1, monthUsd: 1 } })).toBeUndefined();
+
+    const check = evaluateCostBudget({
+      budget: { dailyUsd: 5, monthlyUsd: 20 },
+      spend: { dayUsd: 1, monthUsd: 19.5 },
+      estimateUsd: 0.75,
+    });
+    expect(check).toMatchObject({
+      ok: false,
+      level: "hard",
+      action: "warn",
+      period: "month",
+      remainingUsd: 0.5,
+    });
+
+    const exhausted = evaluateCostBudget({
+      budget: { dailyUsd: 5, action: "refuse" },
+      spend: { dayUsd: 5, monthUsd: 5 },
+    });
+    expect(exhausted?.ok).toBe(false);
+    expect(formatBudgetCheckNotice(exhausted!)).toBe(
+      "⛔ Daily cost budget reached ($5.00 of $5.00 spent). Request not sent.",
+    );
+
+    const fine = evaluateCostBudget({
+      budget: { dailyUsd: 5 },
+      spend: { dayUsd: 1, monthUsd: 1 },
+      estimateUsd: 0.1,
+    });
+    expect(fine?.ok).toBe(true);
+  });
+
+  it("applies soft limits and the in-flight grace margin", () => {
+    const budget = {
+      dailyUsd: 10,
+      softLimitPercent: 80,
+      gracePercent: 10,
+      action: "refuse" as const,
+    };
+    const soft = evaluateCostBudget({ budget, spend: { dayUsd: 8.5, monthUsd: 8.5 } });
+    expect(soft).toMatchObject({ ok: true, level: "soft" });
+    expect(formatBudgetCheckNotice(soft!)).toBe(
+      "⚠️ Daily cost budget at 85% ($8.50 of $10.00 spent).",
+    );
+
+    const overLimit = { budget, spend: { dayUsd: 10.4, monthUsd: 10.4 } };
+    expect(evaluateCostBudget(overLimit)).toMatchObject({ ok: false, level: "hard" });
+    const grace = evaluateCostBudget({ ...overLimit, inFlight: true });
+    expect(grace).toMatchObject({ ok: true, level: "grace" });
+    expect(grace?.graceLimitUsd).toBeCloseTo(11, 6);
+    expect(formatBudgetCheckNotice(grace!)).toBe(
+      "⏳ Daily cost budget reached ($10.40 of $10.00 spent); finishing this conversation within the grace margin (up to $11.00).",
+    );
+
+    const pastGrace = evaluateCostBudget({
+      budget,
+      spend: { dayUsd: 11, monthUsd: 11 },
+      inFlight: true,
+    });
+    expect(pastGrace).toMatchObject({ ok: false, level: "hard" });
+  });
+
+  it("resolves the degraded-mode fallback model", () => {
+    expect(
+      resolveBudgetFallbackModel({ cfg: {} as OpenClawConfig, defaultProvider: "anthropic" }),
+    ).toBeNull();
+    const cfg = {
+      cost: { budget: { dailyUsd: 1, fallbackModel: "ollama/llama3.2" } },
+    } as OpenClawConfig;
+    const fallback = resolveBudgetFallbackModel({ cfg, defaultProvider: "anthropic" });
+    expect(fallback).toEqual({ provider: "ollama", model: "llama3.2" });
+
+    const check = evaluateCostBudget({
+      budget: cfg.cost?.budget,
+      spend: { dayUsd: 1.2, monthUsd: 1.2 },
+    });
+    expect(formatBudgetFallbackNotice(check!, fallback!)).toBe(
+      "🪫 Running in degraded mode on ollama/llama3.2: daily cost budget exhausted ($1.20 of $1.00 spent).",
+    );
+  });
+
+  it("sums today's and this month's spend across transcripts", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-budget-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    const earlier = new Date(now.getFullYear(), now.getMonth(), 1);
+    const entry = (timestamp: Date, cost: number) =>
+      JSON.stringify({
+        type: "message",
+        timestamp: timestamp.toISOString(),
+        message: {
+          role: "assistant",
+          provider: "openai",
+          model: "gpt-5.2",
+          usage: { input: 10, output: 10, totalTokens: 20, cost: { total: cost } },
+        },
+      });
+    await fs.writeFile(
+      path.join(sessionsDir, "sess-1.jsonl"),
+      [entry(now, 0.25), entry(earlier, 1)].join("\n"),
+      "utf-8",
+    );
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const spend = await loadCostBudgetSpend({ config: {} as OpenClawConfig, now });
+      const sameDay = earlier.toDateString() === now.toDateString();
+      expect(spend.monthUsd).toBeCloseTo(1.25, 5);
+      expect(spend.dayUsd).toBeCloseTo(sameDay ? 1.25 : 0.25, 5);
+    });
+  });
+});
@@ -0,0 +1,366 @@
+import fs from "node:fs";
+import { listAgentIds } from "../agents/agent-scope.js";
+import {
+  buildModelAliasIndex,
+  resolveModelRefFromString,
+  type ModelRef,
+} from "../agents/model-selection.js";
+import { countTokens } from "../agents/tokenizer.js";
+import type { OpenClawConfig } from "../config/config.js";
+import type { CostBudgetAction, CostBudgetConfig } from "../config/types.cost.js";
+import {
+  estimateUsageCost,
+  formatCost,
+  isZeroCostModel,
+  resolveModelCostConfig,
+  type CostDisplayCurrency,
+} from "../utils/usage-format.js";
+import {
+  loadCostSummaryStore,
+  resolveRawRecordsStartMs,
+  selectCostSummaryRows,
+} from "./cost-summary-store.js";
+import { loadCostUsageSummary } from "./session-cost-usage.js";
+
+export type CostBudgetPeriod = "day" | "month";
+
+export const DEFAULT_BUDGET_ESTIMATE_OUTPUT_TOKENS = 1024;
+
+const BUDGET_SPEND_CACHE_TTL_MS = 30_000;
+
+export type CostEstimate = {
+  provider: string;
+  model: string;
+  inputTokens: number;
+  outputTokens: number;
+  /** Undefined when the model has no pricing in `models.providers.*.models[].cost`. */
+  costUsd?: number;
+  /** The model is priced at zero (local runtime); budgets do not apply to it. */
+  zeroCost?: boolean;
+};
+
+export type CostBudgetSpend = {
+  dayUsd: number;
+  monthUsd: number;
+};
+
+/**
+ * "soft": past `softLimitPercent` but within the limit (run, mark the reply).
+ * "grace": past the limit but within `gracePercent` for an in-flight conversation (run, mark).
+ * "hard": over the limit; `action` / `fallbackModel` apply.
+ */
+export type BudgetCheckLevel = "ok" | "soft" | "grace" | "hard";
+
+export type BudgetCheck = {
+  ok: boolean;
+  level: BudgetCheckLevel;
+  action: CostBudgetAction;
+  /** Most constrained period (worst level, then smallest remaining amount). */
+  period: CostBudgetPeriod;
+  limitUsd: number;
+  spentUsd: number;
+  remainingUsd: number;
+  estimateUsd?: number;
+  /** Hard limit including the grace margin; set when `gracePercent` is configured. */
+  graceLimitUsd?: number;
+};
+
+/** Conversations with transcript activity this recent count as in flight for the grace margin. */
+export const BUDGET_GRACE_IDLE_MS = 30 * 60 * 1000;
+
+const LEVEL_SEVERITY: Record<BudgetCheckLevel, number> = { ok: 0, soft: 1, grace: 2, hard: 3 };
+
+/** Local token count for the target model (BPE when loaded, heuristic otherwise). */
+export function estimateTokensFromText(
+  text: string | undefined,
+  model?: { provider?: string; model?: string },
+): number {
+  return text ? countTokens(text, model) : 0;
+}
+
+/**
+ * Estimate the cost of a pending request from a local token count and the pricing table.
+ * `contextTokens` is the prompt size already in the session (history, system prompt).
+ */
+export function estimateRequestCost(params: {
+  config?: OpenClawConfig;
+  provider: string;
+  model: string;
+  prompt?: string;
+  contextTokens?: number;
+  outputTokens?: number;
+}): CostEstimate {
+  const contextTokens =
+    typeof params.contextTokens === "number" && Number.isFinite(params.contextTokens)
+      ? Math.max(0, params.contextTokens)
+      : 0;
+  const inputTokens =
+    contextTokens +
+    estimateTokensFromText(params.prompt, { provider: params.provider, model: params.model });
+  const outputTokens =
+    params.outputTokens ??
+    params.config?.cost?.budget?.estimateOutputTokens ??
+    DEFAULT_BUDGET_ESTIMATE_OUTPUT_TOKENS;
+  const cost = resolveModelCostConfig({
+    provider: params.provider,
+    model: params.model,
+    config: params.config,
+  });
+  return {
+    provider: params.provider,
+    model: params.model,
+    inputTokens,
+    outputTokens,
+    costUsd: estimateUsageCost({ usage: { input: inputTokens, output: outputTokens }, cost }),
+    zeroCost: isZeroCostModel(cost),
+  };
+}
+
+export function resolveBudgetPeriodStart(period: CostBudgetPeriod, now: Date): number {
+  if (period === "day") {
+    return new Date(now.getFullYear(), now.getMonth(), now.getDate()).getTime();
+  }
+  return new Date(now.getFullYear(), now.getMonth(), 1).getTime();
+}
+
+export function hasCostBudget(budget?: CostBudgetConfig): boolean {
+  return Boolean(budget && (budget.dailyUsd !== undefined || budget.monthlyUsd !== undefined));
+}
+
+/**
+ * Compare spend plus the pending estimate against the configured limits.
+ * Returns undefined when no limit is configured.
+ */
+export function evaluateCostBudget(params: {
+  budget?: CostBudgetConfig;
+  spend: CostBudgetSpend;
+  estimateUsd?: number;
+  /** The session had recent activity, so `gracePercent` may extend the hard limit. */
+  inFlight?: boolean;
+}): BudgetCheck | undefined {
+  const budget = params.budget;
+  if (!budget || !hasCostBudget(budget)) {
+    return undefined;
+  }
+  const limits: Array<{ period: CostBudgetPeriod; limitUsd: number; spentUsd: number }> = [];
+  if (budget.dailyUsd !== undefined) {
+    limits.push({ period: "day", limitUsd: budget.dailyUsd, spentUsd: params.spend.dayUsd });
+  }
+  if (budget.monthlyUsd !== undefined) {
+    limits.push({ period: "month", limitUsd: budget.monthlyUsd, spentUsd: params.spend.monthUsd });
+  }
+  const estimateUsd = params.estimateUsd;
+  const gracePercent = budget.gracePercent ?? 0;
+  const softLimitPercent = budget.softLimitPercent;
+  const evaluated = limits.map((limit) => {
+    const remainingUsd = Math.max(0, limit.limitUsd - limit.spentUsd);
+    const graceLimitUsd = limit.limitUsd * (1 + gracePercent / 100);
+    let level: BudgetCheckLevel;
+    if (
+      limit.spentUsd < limit.limitUsd &&
+      (estimateUsd === undefined || estimateUsd <= remainingUsd)
+    ) {
+      const projectedUsd = limit.spentUsd + (estimateUsd ?? 0);
+      level =
+        softLimitPercent !== undefined && projectedUsd >= (limit.limitUsd * softLimitPercent) / 100
+          ? "soft"
+          : "ok";
+    } else if (params.inFlight && gracePercent > 0 && limit.spentUsd < graceLimitUsd) {
+      level = "grace";
+    } else {
+      level = "hard";
+    }
+    return {
+      ...limit,
+      level,
+      remainingUsd,
+      graceLimitUsd: gracePercent > 0 ? graceLimitUsd : undefined,
+    };
+  });
+  const worst = evaluated.toSorted(
+    (a, b) =>
+      LEVEL_SEVERITY[b.level] - LEVEL_SEVERITY[a.level] || a.remainingUsd - b.remainingUsd,
+  )[0];
+  if (!worst) {
+    return undefined;
+  }
+  return {
+    ok: worst.level !== "hard",
+    level: worst.level,
+    action: budget.action ?? "warn",
+    period: worst.period,
+    limitUsd: worst.limitUsd,
+    spentUsd: worst.spentUsd,
+    remainingUsd: worst.remainingUsd,
+    estimateUsd,
+    graceLimitUsd: worst.graceLimitUsd,
+  };
+}
+
+/** True when the session transcript was written within `BUDGET_GRACE_IDLE_MS`. */
+export async function isConversationInFlight(params: {
+  sessionFile?: string;
+  now?: number;
+}): Promise<boolean> {
+  if (!params.sessionFile) {
+    return false;
+  }
+  try {
+    const stat = await fs.promises.stat(params.sessionFile);
+    return (params.now ?? Date.now()) - stat.mtimeMs < BUDGET_GRACE_IDLE_MS;
+  } catch {
+    return false;
+  }
+}
+
+type SpendCacheEntry = { key: string; spend: CostBudgetSpend; updatedAt: number };
+let spendCache: SpendCacheEntry | undefined;
+
+/**
+ * Sum today's and this month's spend across agents (cached briefly; transcripts are scanned).
+ * `refresh` bypasses the cache, e.g. right after a run wrote new usage.
+ */
+export async function loadCostBudgetSpend(params: {
+  config: OpenClawConfig;
+  now?: Date;
+  refresh?: boolean;
+}): Promise<CostBudgetSpend> {
+  const now = params.now ?? new Date();
+  const dayStartMs = resolveBudgetPeriodStart("day", now);
+  const monthStartMs = resolveBudgetPeriodStart("month", now);
+  const todayKey = now.toLocaleDateString("en-CA");
+  const key = `${dayStartMs}:${listAgentIds(params.config).join(",")}`;
+  if (
+    !params.refresh &&
+    spendCache?.key === key &&
+    now.getTime() - spendCache.updatedAt < BUDGET_SPEND_CACHE_TTL_MS
+  ) {
+    return spendCache.spend;
+  }
+  const spend: CostBudgetSpend = { dayUsd: 0, monthUsd: 0 };
+  // Days compacted by cost.retention only survive as summary rows.
+  const summaryStore = await loadCostSummaryStore();
+  for (const row of selectCostSummaryRows(summaryStore, {
+    startMs: monthStartMs,
+    endMs: now.getTime(),
+  })) {
+    spend.monthUsd += row.cost;
+    if (row.date === todayKey) {
+      spend.dayUsd += row.cost;
+    }
+  }
+  const rawStartMs = resolveRawRecordsStartMs(summaryStore, monthStartMs);
+  for (const agentId of rawStartMs <= now.getTime() ? listAgentIds(params.config) : []) {
+    const summary = await loadCostUsageSummary({
+      startMs: rawStartMs,
+      endMs: now.getTime(),
+      config: params.config,
+      agentId,
+    });
+    spend.monthUsd += summary.totals.totalCost;
+    for (const day of summary.daily) {
+      if (day.date === todayKey) {
+        spend.dayUsd += day.totalCost;
+      }
+    }
+  }
+  spendCache = { key, spend, updatedAt: now.getTime() };
+  return spend;
+}
+
+export function resetCostBudgetSpendCacheForTest() {
+  spendCache = undefined;
+}
+
+/**
+ * Estimate-then-check entry point used before provider calls.
+ * Zero-priced models are exempt: they cannot add spend, so an exhausted budget never blocks them.
+ * `refresh` re-reads spend instead of using the short-lived cache.
+ */
+export async function checkCostBudget(params: {
+  config: OpenClawConfig;
+  estimate?: CostEstimate;
+  now?: Date;
+  inFlight?: boolean;
+  refresh?: boolean;
+}): Promise<BudgetCheck | undefined> {
+  const budget = params.config.cost?.budget;
+  if (!hasCostBudget(budget) || params.estimate?.zeroCost) {
+    return undefined;
+  }
+  const spend = await loadCostBudgetSpend({
+    config: params.config,
+    now: params.now,
+    refresh: params.refresh,
+  });
+  return evaluateCostBudget({
+    budget,
+    spend,
+    estimateUsd: params.estimate?.costUsd,
+    inFlight: params.inFlight,
+  });
+}
+
+export function formatBudgetCheckNotice(
+  check: BudgetCheck,
+  currency?: CostDisplayCurrency,
+): string {
+  const money = (value: number) => formatCost(value, currency) ?? "n/a";
+  const label = check.period === "day" ? "daily" : "monthly";
+  const status = `${money(check.spentUsd)} of ${money(check.limitUsd)} spent`;
+  if (check.level === "soft") {
+    const percent = Math.round((check.spentUsd / check.limitUsd) * 100);
+    return `⚠️ ${capitalize(label)} cost budget at ${percent}% (${status}).`;
+  }
+  if (check.level === "grace") {
+    const grace = check.graceLimitUsd !== undefined ? ` (up to ${money(check.graceLimitUsd)})` : "";
+    return `⏳ ${capitalize(label)} cost budget reached (${status}); finishing this conversation within the grace margin${grace}.`;
+  }
+  if (check.spentUsd >= check.limitUsd) {
+    return check.action === "refuse"
+      ? `⛔ ${capitalize(label)} cost budget reached (${status}). Request not sent.`
+      : `⚠️ ${capitalize(label)} cost budget reached (${status}).`;
+  }
+  const estimate = check.estimateUsd !== undefined ? ` (~${money(check.estimateUsd)})` : "";
+  return check.action === "refuse"
+    ? `⛔ This request${estimate} would exceed the remaining ${label} cost budget (${status}). Request not sent.`
+    : `⚠️ This request${estimate} may exceed the remaining ${label} cost budget (${status}).`;
+}
+
+/**
+ * Resolve `cost.budget.fallbackModel` (provider/model or alias).
+ * Returns null when unset or unparseable.
+ */
+export function resolveBudgetFallbackModel(params: {
+  cfg: OpenClawConfig;
+  defaultProvider: string;
+}): ModelRef | null {
+  const raw = params.cfg.cost?.budget?.fallbackModel;
+  if (!raw?.trim()) {
+    return null;
+  }
+  const aliasIndex = buildModelAliasIndex({
+    cfg: params.cfg,
+    defaultProvider: params.defaultProvider,
+  });
+  const resolved = resolveModelRefFromString({
+    raw,
+    defaultProvider: params.defaultProvider,
+    aliasIndex,
+  });
+  return resolved?.ref ?? null;
+}
+
+export function formatBudgetFallbackNotice(
+  check: BudgetCheck,
+  fallback: ModelRef,
+  currency?: CostDisplayCurrency,
+): string {
+  const money = (value: number) => formatCost(value, currency) ?? "n/a";
+  const label = check.period === "day" ? "daily" : "monthly";
+  return `🪫 Running in degraded mode on ${fallback.provider}/${fallback.model}: ${label} cost budget exhausted (${money(check.spentUsd)} of ${money(check.limitUsd)} spent).`;
+}
+
+function capitalize(value: string): string {
+  return value.charAt(0).toUpperCase() + value.slice(1);
+}
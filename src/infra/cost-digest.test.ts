@@ -0,0 +1,122 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import { resetCostBudgetSpendCacheForTest } from "./cost-budget.js";
+import {
+  buildCostDigest,
+  formatCostDigest,
+  resolveCostDigestPeriod,
+  resolveNextCostDigestAt,
+} from "./cost-digest.js";
+import type { CostReport } from "./cost-report.js";
+
+const emptyReport = (startMs: number, endMs: number): CostReport => ({
+  updatedAt: endMs,
+  startMs,
+  endMs,
+  sessions: 0,
+  totals: {
+    input: 0,
+    output: 0,
+    cacheRead: 0,
+    cacheWrite: 0,
+    totalTokens: 0,
+    totalCost: 0,
+    inputCost: 0,
+    outputCost: 0,
+    cacheReadCost: 0,
+    cacheWriteCost: 0,
+    missingCostEntries: 0,
+  },
+  byDay: [],
+  byModel: [],
+  byCanonicalModel: [],
+  byChannel: [],
+  byLabel: [],
+  byCategory: [],
+  zeroCostTokens: 0,
+});
+
+describe("cost digest", () => {
+  afterEach(() => {
+    resetCostBudgetSpendCacheForTest();
+  });
+
+  it("covers yesterday or the previous seven days", () => {
+    // Wednesday 2026-02-11, 10:30 local.
+    const now = new Date(2026, 1, 11, 10, 30);
+    expect(resolveCostDigestPeriod("daily", now)).toEqual({
+      startMs: new Date(2026, 1, 10).getTime(),
+      endMs: new Date(2026, 1, 11).getTime() - 1,
+    });
+    expect(resolveCostDigestPeriod("weekly", now).startMs).toBe(new Date(2026, 1, 4).getTime());
+  });
+
+  it("schedules the next send at the local time, on Mondays for weekly", () => {
+    const now = new Date(2026, 1, 11, 10, 30);
+    expect(resolveNextCostDigestAt({ schedule: "daily", time: "09:00", now })).toBe(
+      new Date(2026, 1, 12, 9, 0).getTime(),
+    );
+    expect(resolveNextCostDigestAt({ schedule: "daily", time: "18:15", now })).toBe(
+      new Date(2026, 1, 11, 18, 15).getTime(),
+    );
+    expect(resolveNextCostDigestAt({ schedule: "weekly", now })).toBe(
+      new Date(2026, 1, 16, 9, 0).getTime(),
+    );
+  });
+
+  it("formats totals, top models, and budget status", () => {
+    const startMs = new Date(2026, 1, 10).getTime();
+    const report = emptyReport(startMs, new Date(2026, 1, 11).getTime() - 1);
+    report.sessions = 3;
+    report.totals.totalCost = 4.5;
+    report.totals.totalTokens = 12_000;
+    report.byModel = [
+      { key: "anthropic/claude-opus-4-6", tokens: 8000, cost: 4, count: 5 },
+      { key: "openai/gpt-5.2", tokens: 4000, cost: 0.5, count: 2 },
+    ];
+    const text = formatCostDigest({
+      schedule: "daily",
+      report,
+      budget: { dailyUsd: 10, monthlyUsd: 100 },
+      spend: { dayUsd: 1.25, monthUsd: 42 },
+    });
+    expect(text).toContain("Daily cost digest (2026-02-10)");
+    expect(text).toContain("Total: $4.50 · 12k tokens · 3 sessions");
+    expect(text).toContain("• anthropic/claude-opus-4-6: $4.00");
+    expect(text).toContain("Budget: today $1.25 of $10.00 · this month $42.00 of $100.00");
+  });
+
+  it("builds the digest from transcript usage", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-digest-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    const { endMs } = resolveCostDigestPeriod("daily", now);
+    await fs.writeFile(
+      path.join(sessionsDir, "sess-1.jsonl"),
+      JSON.stringify({
+        type: "message",
+        timestamp: new Date(endMs - 60 * 60 * 1000).toISOString(),
+        message: {
+          role: "assistant",
+          provider: "openai",
+          model: "gpt-5.2",
+          usage: { input: 10, output: 10, totalTokens: 20, cost: { total: 0.75 } },
+        },
+      }),
+      "utf-8",
+    );
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const digest = await buildCostDigest({ config: {} as OpenClawConfig, now });
+      expect(digest.periodEnd).toBe(new Date(endMs).toLocaleDateString("en-CA"));
+      expect(digest.text).toContain("Total: $0.75");
+      expect(digest.text).toContain("• openai/gpt-5.2: $0.75");
+      expect(digest.text).not.toContain("Budget:");
+    });
+  });
+});
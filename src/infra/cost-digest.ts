@@ -0,0 +1,235 @@
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import type { CostBudgetConfig, CostDigestSchedule } from "../config/types.cost.js";
+import { isDeliverableMessageChannel, normalizeMessageChannel } from "../utils/message-channel.js";
+import {
+  formatCost,
+  formatTokenCount,
+  resolveCostDisplayCurrency,
+  type CostDisplayCurrency,
+} from "../utils/usage-format.js";
+import { loadCostBudgetSpend, type CostBudgetSpend } from "./cost-budget.js";
+import { loadCostReport, type CostReport } from "./cost-report.js";
+import { formatLocalDate } from "./cost-summary-store.js";
+import { readJsonFile, writeJsonAtomic } from "./json-files.js";
+
+const DEFAULT_COST_DIGEST_TIME = "09:00";
+const DIGEST_TOP_MODELS = 3;
+/** Re-check at least this often so clock changes (DST, sleep) cannot delay a digest for long. */
+const MAX_DIGEST_TIMER_MS = 60 * 60 * 1000;
+
+type CostDigestState = {
+  version: 1;
+  /** Last local date (YYYY-MM-DD) covered by a delivered digest. */
+  lastPeriodEnd?: string;
+};
+
+export function resolveCostDigestStatePath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cost", "digest-state.json");
+}
+
+function parseDigestTime(raw: string | undefined): { hours: number; minutes: number } {
+  const [hours, minutes] = (raw?.trim() || DEFAULT_COST_DIGEST_TIME).split(":").map(Number);
+  return { hours: hours ?? 9, minutes: minutes ?? 0 };
+}
+
+/**
+ * Period covered by a digest sent at `now`: yesterday for "daily", the seven days ending
+ * yesterday for "weekly".
+ */
+export function resolveCostDigestPeriod(
+  schedule: CostDigestSchedule,
+  now: Date,
+): { startMs: number; endMs: number } {
+  const todayStart = new Date(now.getFullYear(), now.getMonth(), now.getDate());
+  const days = schedule === "weekly" ? 7 : 1;
+  const startMs = new Date(now.getFullYear(), now.getMonth(), now.getDate() - days).getTime();
+  return { startMs, endMs: todayStart.getTime() - 1 };
+}
+
+/** Next local send time: today/tomorrow at `time` (daily) or the next Monday at `time`. */
+export function resolveNextCostDigestAt(params: {
+  schedule: CostDigestSchedule;
+  time?: string;
+  now: Date;
+}): number {
+  const { hours, minutes } = parseDigestTime(params.time);
+  const { now } = params;
+  for (let offset = 0; offset <= 7; offset += 1) {
+    const candidate = new Date(
+      now.getFullYear(),
+      now.getMonth(),
+      now.getDate() + offset,
+      hours,
+      minutes,
+    );
+    if (candidate.getTime() <= now.getTime()) {
+      continue;
+    }
+    if (params.schedule === "weekly" && candidate.getDay() !== 1) {
+      continue;
+    }
+    return candidate.getTime();
+  }
+  return now.getTime() + MAX_DIGEST_TIMER_MS;
+}
+
+/** True when today's send time has passed (and, for weekly digests, today is Monday). */
+function isCostDigestDue(params: { schedule: CostDigestSchedule; time?: string; now: Date }) {
+  const { hours, minutes } = parseDigestTime(params.time);
+  const { now } = params;
+  if (params.schedule === "weekly" && now.getDay() !== 1) {
+    return false;
+  }
+  return now.getHours() * 60 + now.getMinutes() >= hours * 60 + minutes;
+}
+
+export function formatCostDigest(params: {
+  schedule: CostDigestSchedule;
+  report: CostReport;
+  budget?: CostBudgetConfig;
+  spend?: CostBudgetSpend;
+  currency?: CostDisplayCurrency;
+}): string {
+  const { report } = params;
+  const money = (value: number) => formatCost(value, params.currency) ?? "n/a";
+  const startDate = formatLocalDate(report.startMs);
+  const endDate = formatLocalDate(report.endMs);
+  const title =
+    params.schedule === "weekly"
+      ? `💸 Weekly cost digest (${startDate} → ${endDate})`
+      : `💸 Daily cost digest (${endDate})`;
+  const lines = [
+    title,
+    `Total: ${money(report.totals.totalCost)} · ${formatTokenCount(report.totals.totalTokens)} tokens · ${report.sessions} sessions`,
+  ];
+  if (report.zeroCostTokens > 0) {
+    lines.push(`Local models: ${formatTokenCount(report.zeroCostTokens)} tokens (no cost)`);
+  }
+  const models = report.byModel.slice(0, DIGEST_TOP_MODELS);
+  if (models.length > 0) {
+    lines.push("Top models:");
+    for (const row of models) {
+      const cost = row.zeroCost ? "free" : money(row.cost);
+      lines.push(`• ${row.key}: ${cost} (${formatTokenCount(row.tokens)} tokens)`);
+    }
+  }
+  const budget = params.budget;
+  if (params.spend && (budget?.dailyUsd !== undefined || budget?.monthlyUsd !== undefined)) {
+    const parts: string[] = [];
+    if (budget?.dailyUsd !== undefined) {
+      parts.push(`today ${money(params.spend.dayUsd)} of ${money(budget.dailyUsd)}`);
+    }
+    if (budget?.monthlyUsd !== undefined) {
+      parts.push(`this month ${money(params.spend.monthUsd)} of ${money(budget.monthlyUsd)}`);
+    }
+    lines.push(`Budget: ${parts.join(" · ")}`);
+  }
+  return lines.join("\n");
+}
+
+export async function buildCostDigest(params: {
+  config: OpenClawConfig;
+  schedule?: CostDigestSchedule;
+  now?: Date;
+}): Promise<{ text: string; periodEnd: string }> {
+  const { config } = params;
+  const schedule = params.schedule ?? config.cost?.digest?.schedule ?? "daily";
+  const now = params.now ?? new Date();
+  const { startMs, endMs } = resolveCostDigestPeriod(schedule, now);
+  const budget = config.cost?.budget;
+  const [report, spend] = await Promise.all([
+    loadCostReport({ config, startMs, endMs }),
+    budget ? loadCostBudgetSpend({ config, now }) : Promise.resolve(undefined),
+  ]);
+  return {
+    text: formatCostDigest({
+      schedule,
+      report,
+      budget,
+      spend,
+      currency: resolveCostDisplayCurrency({ config }),
+    }),
+    periodEnd: formatLocalDate(endMs),
+  };
+}
+
+/** Deliver the digest to `cost.digest.channel` / `cost.digest.to`. */
+export async function sendCostDigest(params: {
+  config: OpenClawConfig;
+  text: string;
+}): Promise<void> {
+  const digest = params.config.cost?.digest;
+  const channel = normalizeMessageChannel(digest?.channel);
+  const to = digest?.to?.trim();
+  if (!channel || !isDeliverableMessageChannel(channel) || !to) {
+    throw new Error("cost.digest needs a deliverable channel and a `to` target");
+  }
+  const { deliverOutboundPayloads } = await import("./outbound/deliver.js");
+  await deliverOutboundPayloads({
+    cfg: params.config,
+    channel,
+    to,
+    accountId: digest?.accountId,
+    payloads: [{ text: params.text }],
+    notification: { kind: "cost digest" },
+  });
+}
+
+/**
+ * Gateway schedule for `cost.digest`. Sends once per period at the configured local time,
+ * catching up after restarts; delivered periods are tracked in `cost/digest-state.json`.
+ * Returns a stop function.
+ */
+export function scheduleCostDigest(params: {
+  cfg: OpenClawConfig;
+  log: { info: (msg: string) => void; warn: (msg: string) => void };
+  statePath?: string;
+}): () => void {
+  const digest = params.cfg.cost?.digest;
+  const schedule = digest?.schedule ?? "daily";
+  const statePath = params.statePath ?? resolveCostDigestStatePath();
+  let stopped = false;
+  let timer: ReturnType<typeof setTimeout> | null = null;
+
+  const tick = async () => {
+    if (stopped) {
+      return;
+    }
+    const now = new Date();
+    try {
+      if (isCostDigestDue({ schedule, time: digest?.time, now })) {
+        const state = await readJsonFile<CostDigestState>(statePath);
+        const built = await buildCostDigest({ config: params.cfg, schedule, now });
+        if (state?.lastPeriodEnd !== built.periodEnd) {
+          await sendCostDigest({ config: params.cfg, text: built.text });
+          await writeJsonAtomic(statePath, { version: 1, lastPeriodEnd: built.periodEnd });
+          params.log.info(`cost digest: sent ${schedule} digest for ${built.periodEnd}`);
+        }
+      }
+    } catch (err) {
+      params.log.warn(`cost digest delivery failed: ${String(err)}`);
+    }
+    if (stopped) {
+      return;
+    }
+    const nextAt = resolveNextCostDigestAt({ schedule, time: digest?.time, now: new Date() });
+    timer = setTimeout(
+      () => {
+        void tick();
+      },
+      Math.min(MAX_DIGEST_TIMER_MS, Math.max(1000, nextAt - Date.now())),
+    );
+    timer.unref?.();
+  };
+
+  void tick();
+  return () => {
+    stopped = true;
+    if (timer) {
+      clearTimeout(timer);
+      timer = null;
+    }
+  };
+}
@@ -0,0 +1,140 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import { createProviderUsageFetch, makeResponse } from "../test-utils/provider-usage-fetch.js";
+import {
+  buildReconcileRow,
+  formatCostReconcileAlert,
+  loadCostReconcileReport,
+  resolveCostReconcileWindow,
+  runCostReconciliation,
+} from "./cost-reconcile.js";
+
+const usageLine = (timestamp: Date, provider: string, cost: number) =>
+  JSON.stringify({
+    type: "message",
+    timestamp: timestamp.toISOString(),
+    message: {
+      role: "assistant",
+      provider,
+      model: provider === "openai" ? "gpt-5.2" : "claude-opus-4-6",
+      usage: { input: 10, output: 10, totalTokens: 20, cost: { total: cost } },
+    },
+  });
+
+describe("cost reconciliation", () => {
+  it("uses full UTC days ending at the last UTC midnight", () => {
+    const window = resolveCostReconcileWindow(2, new Date(Date.UTC(2026, 1, 10, 15)));
+    expect(window).toEqual({
+      startMs: Date.UTC(2026, 1, 8),
+      endMs: Date.UTC(2026, 1, 10),
+    });
+  });
+
+  it("flags drift over the threshold", () => {
+    const billing = { provider: "openai" as const, days: [{ date: "2026-02-01", costUsd: 10 }] };
+    const under = buildReconcileRow({ billing, trackedUsd: 8.5, driftThresholdPercent: 10 });
+    expect(under).toMatchObject({ billedUsd: 10, driftUsd: -1.5, flagged: true });
+    expect(under.driftPercent).toBeCloseTo(-15, 6);
+    const close = buildReconcileRow({ billing, trackedUsd: 9.5, driftThresholdPercent: 10 });
+    expect(close.flagged).toBe(false);
+    expect(
+      buildReconcileRow({
+        billing: { provider: "anthropic", days: [], error: "HTTP 401" },
+        trackedUsd: 3,
+        driftThresholdPercent: 10,
+      }).flagged,
+    ).toBe(false);
+  });
+
+  it("formats an alert only for flagged providers", () => {
+    const billing = { provider: "openai" as const, days: [{ date: "2026-02-01", costUsd: 10 }] };
+    const report = {
+      version: 1 as const,
+      generatedAt: 0,
+      startMs: 0,
+      endMs: 0,
+      driftThresholdPercent: 10,
+      providers: [
+        buildReconcileRow({ billing, trackedUsd: 8.5, driftThresholdPercent: 10 }),
+        buildReconcileRow({
+          billing: { ...billing, provider: "anthropic" },
+          trackedUsd: 9.5,
+          driftThresholdPercent: 10,
+        }),
+      ],
+    };
+    expect(formatCostReconcileAlert(report)).toContain(
+      "over 10% from provider billing: openai -15%.",
+    );
+    expect(
+      formatCostReconcileAlert({ ...report, providers: report.providers.slice(1) }),
+    ).toBeUndefined();
+  });
+
+  it("compares provider billing with tracked usage and stores the report", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-reconcile-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    const { endMs } = resolveCostReconcileWindow(7, now);
+    const inWindow = new Date(endMs - 60 * 60 * 1000);
+    await fs.writeFile(
+      path.join(sessionsDir, "sess-1.jsonl"),
+      [
+        usageLine(inWindow, "openai", 2),
+        usageLine(inWindow, "anthropic", 1),
+        usageLine(new Date(endMs + 1000), "openai", 50),
+      ].join("\n"),
+      "utf-8",
+    );
+    const bucketStart = Math.floor((endMs - 24 * 60 * 60 * 1000) / 1000);
+    const fetchFn = createProviderUsageFetch(async (url, init) => {
+      if (url.startsWith("https://api.openai.com/v1/organization/costs")) {
+        expect(new Headers(init?.headers).get("authorization")).toBe("Bearer sk-admin-openai");
+        return makeResponse(200, {
+          data: [
+            { start_time: bucketStart, results: [{ amount: { value: 2.5, currency: "usd" } }] },
+          ],
+          has_more: false,
+        });
+      }
+      if (url.startsWith("https://api.anthropic.com/v1/organizations/cost_report")) {
+        return makeResponse(200, {
+          data: [
+            {
+              starting_at: new Date(bucketStart * 1000).toISOString(),
+              results: [{ amount: "100", currency: "USD" }],
+            },
+          ],
+          has_more: false,
+        });
+      }
+      return makeResponse(404, "not found");
+    });
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const report = await runCostReconciliation({
+        config: {
+          cost: { reconcile: { openaiAdminKey: "sk-admin-openai", anthropicAdminKey: "sk-ant" } },
+        } as OpenClawConfig,
+        now,
+        fetchFn,
+      });
+      const byProvider = Object.fromEntries(report.providers.map((row) => [row.provider, row]));
+      expect(byProvider.openai).toMatchObject({ billedUsd: 2.5, trackedUsd: 2, flagged: true });
+      expect(byProvider.openai?.driftPercent).toBeCloseTo(-20, 6);
+      expect(byProvider.anthropic).toMatchObject({ billedUsd: 1, trackedUsd: 1, flagged: false });
+      expect((await loadCostReconcileReport())?.generatedAt).toBe(now.getTime());
+    });
+  });
+
+  it("requires at least one admin key", async () => {
+    await expect(runCostReconciliation({ config: {} as OpenClawConfig, env: {} })).rejects.toThrow(
+      "No billing admin key",
+    );
+  });
+});
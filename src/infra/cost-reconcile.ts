@@ -0,0 +1,291 @@
+import path from "node:path";
+import { listAgentIds } from "../agents/agent-scope.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { broadcastAlert } from "./alerts.js";
+import {
+  BILLING_PROVIDERS,
+  fetchAnthropicBillingCosts,
+  fetchOpenAiBillingCosts,
+  type BillingDailyCost,
+  type BillingFetchResult,
+  type BillingProviderId,
+} from "./cost-billing.js";
+import {
+  loadCostSummaryStore,
+  resolveRawRecordsStartMs,
+  selectCostSummaryRows,
+} from "./cost-summary-store.js";
+import { readJsonFile, writeJsonAtomic } from "./json-files.js";
+import { discoverAllSessions, loadSessionCostSummary } from "./session-cost-usage.js";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+export const DEFAULT_COST_RECONCILE_LOOKBACK_DAYS = 7;
+export const DEFAULT_COST_RECONCILE_INTERVAL_HOURS = 24;
+export const DEFAULT_COST_RECONCILE_DRIFT_PERCENT = 10;
+
+const BILLING_FETCH_TIMEOUT_MS = 30_000;
+/** Ignore drift below one cent so idle providers never get flagged. */
+const MIN_FLAGGED_DRIFT_USD = 0.01;
+
+export type CostReconcileProviderRow = {
+  provider: BillingProviderId;
+  billedUsd: number;
+  trackedUsd: number;
+  /** tracked - billed; negative means the tracker under-counted. */
+  driftUsd: number;
+  /** Drift relative to the billed amount (100 when nothing was billed but usage was tracked). */
+  driftPercent: number;
+  flagged: boolean;
+  billedDays: BillingDailyCost[];
+  error?: string;
+};
+
+export type CostReconcileReport = {
+  version: 1;
+  generatedAt: number;
+  /** UTC-aligned window; provider billing buckets are UTC days. */
+  startMs: number;
+  endMs: number;
+  driftThresholdPercent: number;
+  providers: CostReconcileProviderRow[];
+};
+
+/** Alert text for providers whose tracked cost drifted past the threshold. */
+export function formatCostReconcileAlert(report: CostReconcileReport): string | undefined {
+  const flagged = report.providers.filter((row) => row.flagged);
+  if (flagged.length === 0) {
+    return undefined;
+  }
+  const rows = flagged.map((row) => {
+    const sign = row.driftPercent > 0 ? "+" : "";
+    return `${row.provider} ${sign}${Math.round(row.driftPercent * 10) / 10}%`;
+  });
+  return `⚠️ Tracked cost drifted over ${report.driftThresholdPercent}% from provider billing: ${rows.join(", ")}. Run \`openclaw cost reconcile\` for details.`;
+}
+
+export function resolveCostReconcileReportPath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cost", "reconciliation.json");
+}
+
+export async function loadCostReconcileReport(
+  filePath = resolveCostReconcileReportPath(),
+): Promise<CostReconcileReport | null> {
+  const parsed = await readJsonFile<CostReconcileReport>(filePath);
+  return parsed?.version === 1 && Array.isArray(parsed.providers) ? parsed : null;
+}
+
+/** Admin keys from `cost.reconcile`, falling back to OPENAI_ADMIN_KEY / ANTHROPIC_ADMIN_KEY. */
+export function resolveBillingAdminKeys(
+  config: OpenClawConfig,
+  env: NodeJS.ProcessEnv = process.env,
+): Partial<Record<BillingProviderId, string>> {
+  const reconcile = config.cost?.reconcile;
+  const keys: Partial<Record<BillingProviderId, string>> = {};
+  const openai = reconcile?.openaiAdminKey?.trim() || env.OPENAI_ADMIN_KEY?.trim();
+  const anthropic = reconcile?.anthropicAdminKey?.trim() || env.ANTHROPIC_ADMIN_KEY?.trim();
+  if (openai) {
+    keys.openai = openai;
+  }
+  if (anthropic) {
+    keys.anthropic = anthropic;
+  }
+  return keys;
+}
+
+/** Last `days` full UTC days, ending at the most recent UTC midnight. */
+export function resolveCostReconcileWindow(
+  days: number,
+  now = new Date(),
+): { startMs: number; endMs: number } {
+  const endMs = Date.UTC(now.getUTCFullYear(), now.getUTCMonth(), now.getUTCDate());
+  return { startMs: endMs - Math.max(1, Math.floor(days)) * DAY_MS, endMs };
+}
+
+/** Tracker spend per provider inside [startMs, endMs), from transcripts and daily summaries. */
+export async function loadTrackedProviderCosts(params: {
+  config: OpenClawConfig;
+  startMs: number;
+  endMs: number;
+}): Promise<Map<string, number>> {
+  const totals = new Map<string, number>();
+  const add = (provider: string | undefined, cost: number) => {
+    const key = provider?.trim().toLowerCase();
+    if (key) {
+      totals.set(key, (totals.get(key) ?? 0) + cost);
+    }
+  };
+  const endMs = params.endMs - 1;
+  const summaryStore = await loadCostSummaryStore();
+  for (const row of selectCostSummaryRows(summaryStore, { startMs: params.startMs, endMs })) {
+    add(row.provider, row.cost);
+  }
+  const rawStartMs = resolveRawRecordsStartMs(summaryStore, params.startMs);
+  if (rawStartMs > endMs) {
+    return totals;
+  }
+  for (const agentId of listAgentIds(params.config)) {
+    const discovered = await discoverAllSessions({ agentId, startMs: rawStartMs, endMs });
+    for (const session of discovered) {
+      const usage = await loadSessionCostSummary({
+        sessionId: session.sessionId,
+        sessionFile: session.sessionFile,
+        config: params.config,
+        agentId,
+        startMs: rawStartMs,
+        endMs,
+      });
+      for (const model of usage?.modelUsage ?? []) {
+        add(model.provider, model.totals.totalCost);
+      }
+    }
+  }
+  return totals;
+}
+
+export function buildReconcileRow(params: {
+  billing: BillingFetchResult;
+  trackedUsd: number;
+  driftThresholdPercent: number;
+}): CostReconcileProviderRow {
+  const billedUsd = params.billing.days.reduce((sum, day) => sum + day.costUsd, 0);
+  const driftUsd = params.trackedUsd - billedUsd;
+  const driftPercent =
+    billedUsd > 0 ? (driftUsd / billedUsd) * 100 : params.trackedUsd > 0 ? 100 : 0;
+  return {
+    provider: params.billing.provider,
+    billedUsd,
+    trackedUsd: params.trackedUsd,
+    driftUsd,
+    driftPercent,
+    flagged:
+      !params.billing.error &&
+      Math.abs(driftUsd) >= MIN_FLAGGED_DRIFT_USD &&
+      Math.abs(driftPercent) >= params.driftThresholdPercent,
+    billedDays: params.billing.days,
+    error: params.billing.error,
+  };
+}
+
+/**
+ * Pull billed cost from provider admin APIs for the window, compare it with the tracker's
+ * own estimates, and store the result in `cost/reconciliation.json`.
+ */
+export async function runCostReconciliation(params: {
+  config: OpenClawConfig;
+  days?: number;
+  now?: Date;
+  env?: NodeJS.ProcessEnv;
+  fetchFn?: typeof fetch;
+  reportPath?: string;
+}): Promise<CostReconcileReport> {
+  const reconcile = params.config.cost?.reconcile;
+  const days = params.days ?? reconcile?.lookbackDays ?? DEFAULT_COST_RECONCILE_LOOKBACK_DAYS;
+  const driftThresholdPercent = reconcile?.driftPercent ?? DEFAULT_COST_RECONCILE_DRIFT_PERCENT;
+  const now = params.now ?? new Date();
+  const { startMs, endMs } = resolveCostReconcileWindow(days, now);
+  const keys = resolveBillingAdminKeys(params.config, params.env);
+  const providers = BILLING_PROVIDERS.filter((provider) => keys[provider]);
+  if (providers.length === 0) {
+    throw new Error(
+      "No billing admin key configured (cost.reconcile.openaiAdminKey / anthropicAdminKey, or OPENAI_ADMIN_KEY / ANTHROPIC_ADMIN_KEY)",
+    );
+  }
+
+  const tracked = await loadTrackedProviderCosts({ config: params.config, startMs, endMs });
+  const rows = await Promise.all(
+    providers.map(async (provider) => {
+      const fetchParams = {
+        adminKey: keys[provider] ?? "",
+        startMs,
+        endMs,
+        timeoutMs: BILLING_FETCH_TIMEOUT_MS,
+        fetchFn: params.fetchFn,
+      };
+      const billing = await (
+        provider === "openai"
+          ? fetchOpenAiBillingCosts(fetchParams)
+          : fetchAnthropicBillingCosts(fetchParams)
+      ).catch((err): BillingFetchResult => ({ provider, days: [], error: String(err) }));
+      return buildReconcileRow({
+        billing,
+        trackedUsd: tracked.get(provider) ?? 0,
+        driftThresholdPercent,
+      });
+    }),
+  );
+
+  const report: CostReconcileReport = {
+    version: 1,
+    generatedAt: now.getTime(),
+    startMs,
+    endMs,
+    driftThresholdPercent,
+    providers: rows,
+  };
+  await writeJsonAtomic(params.reportPath ?? resolveCostReconcileReportPath(), report);
+  return report;
+}
+
+/**
+ * Gateway schedule for `cost.reconcile`: runs when the stored report is older than
+ * `intervalHours`, then re-checks every interval. Returns a stop function.
+ */
+export function scheduleCostReconciliation(params: {
+  cfg: OpenClawConfig;
+  log: { info: (msg: string) => void; warn: (msg: string) => void };
+}): () => void {
+  let stopped = false;
+  let timer: ReturnType<typeof setTimeout> | null = null;
+  const intervalHours =
+    params.cfg.cost?.reconcile?.intervalHours ?? DEFAULT_COST_RECONCILE_INTERVAL_HOURS;
+  const intervalMs = intervalHours * 60 * 60 * 1000;
+
+  const tick = async () => {
+    if (stopped) {
+      return;
+    }
+    try {
+      const previous = await loadCostReconcileReport();
+      if (!previous || Date.now() - previous.generatedAt >= intervalMs) {
+        const report = await runCostReconciliation({ config: params.cfg });
+        const flagged = report.providers.filter((row) => row.flagged);
+        for (const row of report.providers) {
+          if (row.error) {
+            params.log.warn(
+              `cost reconciliation: ${row.provider} billing fetch failed: ${row.error}`,
+            );
+          }
+        }
+        const alert = formatCostReconcileAlert(report);
+        if (alert) {
+          params.log.warn(
+            `cost reconciliation: drift over ${report.driftThresholdPercent}% for ${flagged.map((row) => row.provider).join(", ")}`,
+          );
+          await broadcastAlert({ config: params.cfg, kind: "reconcile", text: alert });
+        } else {
+          params.log.info("cost reconciliation: tracker matches provider billing");
+        }
+      }
+    } catch (err) {
+      params.log.warn(`cost reconciliation failed: ${String(err)}`);
+    }
+    if (stopped) {
+      return;
+    }
+    timer = setTimeout(() => {
+      void tick();
+    }, intervalMs);
+    timer.unref?.();
+  };
+
+  void tick();
+  return () => {
+    stopped = true;
+    if (timer) {
+      clearTimeout(timer);
+      timer = null;
+    }
+  };
+}
@@ -0,0 +1,250 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import {
+  loadCostReport,
+  resolveCanonicalModel,
+  resolveCostReportChannel,
+  resolveCostReportLabel,
+} from "./cost-report.js";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+async function writeSession(params: {
+  sessionsDir: string;
+  sessionId: string;
+  entries: Array<{ timestamp: Date; model: string; provider: string; cost: number }>;
+}) {
+  const lines = params.entries.map((entry) =>
+    JSON.stringify({
+      type: "message",
+      timestamp: entry.timestamp.toISOString(),
+      message: {
+        role: "assistant",
+        provider: entry.provider,
+        model: entry.model,
+        usage: { input: 10, output: 10, totalTokens: 20, cost: { total: entry.cost } },
+      },
+    }),
+  );
+  await fs.writeFile(
+    path.join(params.sessionsDir, `${params.sessionId}.jsonl`),
+    lines.join("\n"),
+    "utf-8",
+  );
+}
+
+describe("loadCostReport", () => {
+  it("groups usage by day, model, and channel", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-report-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+
+    await writeSession({
+      sessionsDir,
+      sessionId: "sess-tg",
+      entries: [
+        { timestamp: now, provider: "openai", model: "gpt-5.2", cost: 0.02 },
+        { timestamp: now, provider: "anthropic", model: "claude-opus-4-6", cost: 0.05 },
+      ],
+    });
+    await writeSession({
+      sessionsDir,
+      sessionId: "sess-unknown",
+      entries: [{ timestamp: now, provider: "openai", model: "gpt-5.2", cost: 0.01 }],
+    });
+    await fs.writeFile(
+      path.join(sessionsDir, "sessions.json"),
+      JSON.stringify({
+        "agent:main:telegram:dm:1": {
+          sessionId: "sess-tg",
+          updatedAt: now.getTime(),
+          channel: "telegram",
+        },
+        "agent:main:cron:nightly:run:1": {
+          sessionId: "sess-unknown",
+          updatedAt: now.getTime(),
+          label: "Cron: Nightly greenhouse report",
+        },
+      }),
+      "utf-8",
+    );
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const report = await loadCostReport({
+        config: {} as OpenClawConfig,
+        startMs: now.getTime() - DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+      });
+      expect(report.sessions).toBe(2);
+      expect(report.totals.totalCost).toBeCloseTo(0.08, 5);
+      expect(report.byDay).toHaveLength(1);
+      expect(report.byModel.map((row) => row.key)).toEqual([
+        "anthropic/claude-opus-4-6",
+        "openai/gpt-5.2",
+      ]);
+      expect(report.byModel[1]?.count).toBe(2);
+      expect(report.byCanonicalModel.map((row) => row.key)).toEqual(["claude-opus-4-6", "gpt-5.2"]);
+      expect(report.byChannel.map((row) => [row.key, row.count])).toEqual([
+        ["telegram", 2],
+        ["unknown", 1],
+      ]);
+
+      const filtered = await loadCostReport({
+        config: {} as OpenClawConfig,
+        startMs: now.getTime() - DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+        channel: "Telegram",
+      });
+      expect(filtered.sessions).toBe(1);
+      expect(filtered.totals.totalCost).toBeCloseTo(0.07, 5);
+
+      expect(report.byLabel.map((row) => row.key)).toEqual([
+        "unlabeled",
+        "Cron: Nightly greenhouse report",
+      ]);
+      expect(report.byLabel[1]?.cost).toBeCloseTo(0.01, 5);
+      const byLabel = await loadCostReport({
+        config: {} as OpenClawConfig,
+        startMs: now.getTime() - DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+        label: "cron: nightly greenhouse report",
+      });
+      expect(byLabel.sessions).toBe(1);
+      expect(byLabel.totals.totalCost).toBeCloseTo(0.01, 5);
+    });
+  });
+
+  it("adds embeddings usage under its own category", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-report-embeddings-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    await fs.mkdir(path.join(root, "cost"), { recursive: true });
+    const now = new Date();
+    await writeSession({
+      sessionsDir,
+      sessionId: "sess-chat",
+      entries: [{ timestamp: now, provider: "openai", model: "gpt-5.2", cost: 0.02 }],
+    });
+    await fs.writeFile(
+      path.join(root, "cost", "embeddings.jsonl"),
+      `${JSON.stringify({
+        ts: now.getTime(),
+        provider: "openai",
+        model: "text-embedding-3-small",
+        tokens: 500_000,
+        inputs: 40,
+        agentId: "main",
+      })}\n`,
+      "utf-8",
+    );
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const report = await loadCostReport({
+        config: {} as OpenClawConfig,
+        startMs: now.getTime() - DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+      });
+      expect(report.byCategory.map((row) => [row.key, row.count])).toEqual([
+        ["chat", 1],
+        ["embeddings", 1],
+      ]);
+      expect(report.byCategory[1]?.cost).toBeCloseTo(0.01, 6);
+      expect(report.totals.totalCost).toBeCloseTo(0.03, 6);
+      expect(report.totals.totalTokens).toBe(500_020);
+
+      const byChannel = await loadCostReport({
+        config: {} as OpenClawConfig,
+        startMs: now.getTime() - DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+        channel: "unknown",
+      });
+      expect(byChannel.byCategory.map((row) => row.key)).toEqual(["chat"]);
+    });
+  });
+
+  it("reports token usage for zero-priced local models", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-report-local-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    await writeSession({
+      sessionsDir,
+      sessionId: "sess-local",
+      entries: [
+        { timestamp: now, provider: "ollama", model: "llama3.3", cost: 0 },
+        { timestamp: now, provider: "ollama", model: "llama3.3", cost: 0 },
+        { timestamp: now, provider: "openai", model: "gpt-5.2", cost: 0.02 },
+      ],
+    });
+    const config = {
+      models: {
+        providers: {
+          ollama: {
+            models: [
+              { id: "llama3.3", cost: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0 } },
+            ],
+          },
+        },
+      },
+    } as unknown as OpenClawConfig;
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const report = await loadCostReport({
+        config,
+        startMs: now.getTime() - DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+      });
+      expect(report.totals.totalTokens).toBe(60);
+      expect(report.zeroCostTokens).toBe(40);
+      const local = report.byModel.find((row) => row.key === "ollama/llama3.3");
+      expect(local).toMatchObject({ tokens: 40, cost: 0, zeroCost: true });
+      expect(report.byModel.find((row) => row.key === "openai/gpt-5.2")?.zeroCost).toBeUndefined();
+    });
+  });
+
+  it("normalizes models across providers", () => {
+    const aliases = { "azure/prod-gpt": "gpt-5.2", "claude-opus-4-6-20260101": "claude-opus-4-6" };
+    expect(resolveCanonicalModel({ provider: "openai", model: "gpt-5.2" })).toBe("gpt-5.2");
+    expect(resolveCanonicalModel({ provider: "openrouter", model: "openai/gpt-5.2" })).toBe(
+      "gpt-5.2",
+    );
+    expect(resolveCanonicalModel({ provider: "azure", model: "prod-gpt", aliases })).toBe(
+      "gpt-5.2",
+    );
+    expect(
+      resolveCanonicalModel({
+        provider: "openrouter",
+        model: "anthropic/claude-opus-4-6-20260101",
+        aliases,
+      }),
+    ).toBe("claude-opus-4-6");
+    expect(resolveCanonicalModel({ provider: "ollama" })).toBe("ollama");
+  });
+
+  it("labels sessions by their session label", () => {
+    expect(resolveCostReportLabel(undefined)).toBe("unlabeled");
+    expect(resolveCostReportLabel({ sessionId: "s", updatedAt: 0, label: "  digest " })).toBe(
+      "digest",
+    );
+  });
+
+  it("falls back through origin and last channel", () => {
+    expect(resolveCostReportChannel(undefined)).toBe("unknown");
+    expect(
+      resolveCostReportChannel({
+        sessionId: "s",
+        updatedAt: 0,
+        origin: { provider: "slack" },
+        lastChannel: "discord",
+      }),
+    ).toBe("slack");
+    expect(resolveCostReportChannel({ sessionId: "s", updatedAt: 0, lastChannel: "discord" })).toBe(
+      "discord",
+    );
+  });
+});
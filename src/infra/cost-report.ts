@@ -0,0 +1,350 @@
+import { listAgentIds } from "../agents/agent-scope.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStorePath } from "../config/sessions/paths.js";
+import { loadSessionStore } from "../config/sessions/store.js";
+import type { SessionEntry } from "../config/sessions/types.js";
+import { normalizeAgentId } from "../routing/session-key.js";
+import { isZeroCostModel, resolveModelCostConfig } from "../utils/usage-format.js";
+import { estimateEmbeddingCost, loadEmbeddingUsage } from "./embedding-usage.js";
+import {
+  formatLocalDate,
+  loadCostSummaryStore,
+  resolveRawRecordsStartMs,
+  selectCostSummaryRows,
+  UNLABELED_COST_LABEL,
+} from "./cost-summary-store.js";
+import { discoverAllSessions, loadSessionCostSummary } from "./session-cost-usage.js";
+import type { CostUsageTotals } from "./session-cost-usage.types.js";
+
+export type CostReportGroup = "day" | "model" | "canonical" | "channel" | "label" | "category";
+
+export const COST_REPORT_GROUPS: readonly CostReportGroup[] = [
+  "day",
+  "model",
+  "canonical",
+  "channel",
+  "label",
+  "category",
+];
+
+export const DEFAULT_COST_REPORT_GROUPS: readonly CostReportGroup[] = ["day", "model", "channel"];
+
+export type CostReportRow = {
+  key: string;
+  tokens: number;
+  cost: number;
+  /** Assistant messages (model/channel/label rows) or sessions with activity (day rows). */
+  count: number;
+  /** Per provider/model breakdown (canonical model rows only). */
+  details?: CostReportRow[];
+  /** Model is priced at zero (local runtime); only its token count is meaningful. */
+  zeroCost?: boolean;
+};
+
+export type CostReport = {
+  updatedAt: number;
+  startMs: number;
+  endMs: number;
+  sessions: number;
+  totals: CostUsageTotals;
+  byDay: CostReportRow[];
+  byModel: CostReportRow[];
+  /** Models grouped by canonical name across providers (see `cost.modelAliases`). */
+  byCanonicalModel: CostReportRow[];
+  byChannel: CostReportRow[];
+  /** Usage by session label (cron jobs, sub-agent tasks); unlabeled sessions share one row. */
+  byLabel: CostReportRow[];
+  /**
+   * "chat" (model turns) vs "embeddings" (memory indexing and search). Embeddings have no
+   * channel or label, so they are left out when either filter is set.
+   */
+  byCategory: CostReportRow[];
+  /** Tokens served by zero-priced (local) models; included in `totals.totalTokens`. */
+  zeroCostTokens: number;
+};
+
+const UNKNOWN_CHANNEL = "unknown";
+
+const emptyTotals = (): CostUsageTotals => ({
+  input: 0,
+  output: 0,
+  cacheRead: 0,
+  cacheWrite: 0,
+  totalTokens: 0,
+  totalCost: 0,
+  inputCost: 0,
+  outputCost: 0,
+  cacheReadCost: 0,
+  cacheWriteCost: 0,
+  missingCostEntries: 0,
+});
+
+const mergeTotals = (target: CostUsageTotals, source: CostUsageTotals) => {
+  target.input += source.input;
+  target.output += source.output;
+  target.cacheRead += source.cacheRead;
+  target.cacheWrite += source.cacheWrite;
+  target.totalTokens += source.totalTokens;
+  target.totalCost += source.totalCost;
+  target.inputCost += source.inputCost;
+  target.outputCost += source.outputCost;
+  target.cacheReadCost += source.cacheReadCost;
+  target.cacheWriteCost += source.cacheWriteCost;
+  target.missingCostEntries += source.missingCostEntries;
+};
+
+const addRow = (
+  map: Map<string, CostReportRow>,
+  key: string,
+  delta: { tokens: number; cost: number; count: number },
+) => {
+  const row = map.get(key) ?? { key, tokens: 0, cost: 0, count: 0 };
+  row.tokens += delta.tokens;
+  row.cost += delta.cost;
+  row.count += delta.count;
+  map.set(key, row);
+};
+
+const addCanonicalRow = (
+  map: Map<string, CostReportRow>,
+  canonical: string,
+  detailKey: string,
+  delta: { tokens: number; cost: number; count: number },
+) => {
+  const row = map.get(canonical) ?? { key: canonical, tokens: 0, cost: 0, count: 0, details: [] };
+  row.tokens += delta.tokens;
+  row.cost += delta.cost;
+  row.count += delta.count;
+  const details = row.details ?? [];
+  const detail = details.find((entry) => entry.key === detailKey);
+  if (detail) {
+    detail.tokens += delta.tokens;
+    detail.cost += delta.cost;
+    detail.count += delta.count;
+  } else {
+    details.push({ key: detailKey, ...delta });
+  }
+  row.details = details;
+  map.set(canonical, row);
+};
+
+const sortByCost = (rows: Iterable<CostReportRow>): CostReportRow[] =>
+  Array.from(rows).toSorted((a, b) => b.cost - a.cost || b.tokens - a.tokens);
+
+export function formatCostReportModelKey(provider?: string, model?: string): string {
+  if (provider && model) {
+    return `${provider}/${model}`;
+  }
+  return model ?? provider ?? "unknown";
+}
+
+/**
+ * Map a provider-specific model id to the logical model it serves, so usage through
+ * OpenRouter, Azure, and the native API aggregates under one name.
+ * Checks `cost.modelAliases` for "provider/model", then the model id; otherwise strips
+ * aggregator vendor prefixes ("anthropic/claude-opus-4-6" -> "claude-opus-4-6").
+ */
+export function resolveCanonicalModel(params: {
+  provider?: string;
+  model?: string;
+  aliases?: Record<string, string>;
+}): string {
+  const provider = params.provider?.trim();
+  const model = params.model?.trim();
+  if (!model) {
+    return provider || "unknown";
+  }
+  const aliases = params.aliases ?? {};
+  const lookup = (key: string) => (aliases[key] ?? aliases[key.toLowerCase()])?.trim();
+  const explicit = (provider ? lookup(`${provider}/${model}`) : undefined) || lookup(model);
+  if (explicit) {
+    return explicit;
+  }
+  const base = model.slice(model.lastIndexOf("/") + 1);
+  return lookup(base) || base;
+}
+
+export function resolveCostReportChannel(entry?: SessionEntry): string {
+  return (
+    entry?.channel?.trim() ||
+    entry?.origin?.provider?.trim() ||
+    entry?.lastChannel?.trim() ||
+    UNKNOWN_CHANNEL
+  );
+}
+
+/** Cron runs are labelled "Cron: <job name>"; sub-agents use their `sessions_spawn` label. */
+export function resolveCostReportLabel(entry?: SessionEntry): string {
+  return entry?.label?.trim() || UNLABELED_COST_LABEL;
+}
+
+function buildSessionIndex(storePath: string): Map<string, SessionEntry> {
+  const index = new Map<string, SessionEntry>();
+  for (const entry of Object.values(loadSessionStore(storePath))) {
+    if (entry?.sessionId) {
+      index.set(entry.sessionId, entry);
+    }
+  }
+  return index;
+}
+
+/**
+ * Aggregate transcript usage across agents into day/model/channel rollups.
+ * Reads session files directly so it works without a running Gateway; days already
+ * compacted by `cost.retention` come from the daily summary store instead.
+ */
+export async function loadCostReport(params: {
+  config: OpenClawConfig;
+  startMs: number;
+  endMs: number;
+  agentId?: string;
+  channel?: string;
+  label?: string;
+}): Promise<CostReport> {
+  const agentIds = params.agentId
+    ? [normalizeAgentId(params.agentId)]
+    : listAgentIds(params.config);
+  const channelFilter = params.channel?.trim().toLowerCase();
+  const labelFilter = params.label?.trim().toLowerCase();
+
+  const totals = emptyTotals();
+  const byDay = new Map<string, CostReportRow>();
+  const byModel = new Map<string, CostReportRow>();
+  const byCanonicalModel = new Map<string, CostReportRow>();
+  const aliases = params.config.cost?.modelAliases;
+  let zeroCostTokens = 0;
+  const addModelRows = (
+    provider: string | undefined,
+    model: string | undefined,
+    delta: { tokens: number; cost: number; count: number },
+  ) => {
+    const key = formatCostReportModelKey(provider, model);
+    addRow(byModel, key, delta);
+    if (isZeroCostModel(resolveModelCostConfig({ provider, model, config: params.config }))) {
+      zeroCostTokens += delta.tokens;
+      const row = byModel.get(key);
+      if (row) {
+        row.zeroCost = true;
+      }
+    }
+    addCanonicalRow(
+      byCanonicalModel,
+      resolveCanonicalModel({ provider, model, aliases }),
+      key,
+      delta,
+    );
+  };
+  const byChannel = new Map<string, CostReportRow>();
+  const byLabel = new Map<string, CostReportRow>();
+  const byCategory = new Map<string, CostReportRow>();
+  let sessions = 0;
+
+  const summaryStore = await loadCostSummaryStore();
+  for (const row of selectCostSummaryRows(summaryStore, {
+    startMs: params.startMs,
+    endMs: params.endMs,
+    agentIds,
+    channel: channelFilter,
+    label: labelFilter,
+  })) {
+    totals.totalTokens += row.tokens;
+    totals.totalCost += row.cost;
+    const delta = { tokens: row.tokens, cost: row.cost, count: row.count };
+    addRow(byDay, row.date, { ...delta, count: 0 });
+    addModelRows(row.provider, row.model, delta);
+    addRow(byChannel, row.channel, delta);
+    addRow(byLabel, row.label ?? UNLABELED_COST_LABEL, delta);
+    addRow(byCategory, "chat", delta);
+  }
+  const rawStartMs = resolveRawRecordsStartMs(summaryStore, params.startMs);
+
+  for (const agentId of agentIds) {
+    const storePath = resolveStorePath(params.config.session?.store, { agentId });
+    const index = buildSessionIndex(storePath);
+    const discovered =
+      rawStartMs > params.endMs
+        ? []
+        : await discoverAllSessions({ agentId, startMs: rawStartMs, endMs: params.endMs });
+
+    for (const session of discovered) {
+      const entry = index.get(session.sessionId);
+      const channel = resolveCostReportChannel(entry);
+      const label = resolveCostReportLabel(entry);
+      if (channelFilter && channel.toLowerCase() !== channelFilter) {
+        continue;
+      }
+      if (labelFilter && label.toLowerCase() !== labelFilter) {
+        continue;
+      }
+      const usage = await loadSessionCostSummary({
+        sessionId: session.sessionId,
+        sessionEntry: entry,
+        sessionFile: session.sessionFile,
+        config: params.config,
+        agentId,
+        startMs: rawStartMs,
+        endMs: params.endMs,
+      });
+      if (!usage || usage.totalTokens <= 0) {
+        continue;
+      }
+
+      sessions += 1;
+      mergeTotals(totals, usage);
+      const sessionDelta = {
+        tokens: usage.totalTokens,
+        cost: usage.totalCost,
+        count: usage.messageCounts?.assistant ?? 0,
+      };
+      addRow(byChannel, channel, sessionDelta);
+      addRow(byLabel, label, sessionDelta);
+      addRow(byCategory, "chat", sessionDelta);
+      for (const day of usage.dailyBreakdown ?? []) {
+        addRow(byDay, day.date, { tokens: day.tokens, cost: day.cost, count: 1 });
+      }
+      for (const model of usage.modelUsage ?? []) {
+        addModelRows(model.provider, model.model, {
+          tokens: model.totals.totalTokens,
+          cost: model.totals.totalCost,
+          count: model.count,
+        });
+      }
+    }
+  }
+
+  if (!channelFilter && !labelFilter) {
+    const embeddings = await loadEmbeddingUsage({
+      startMs: params.startMs,
+      endMs: params.endMs,
+      agentIds: params.agentId ? agentIds : undefined,
+    });
+    for (const record of embeddings) {
+      const cost = estimateEmbeddingCost(record, params.config);
+      totals.input += record.tokens;
+      totals.totalTokens += record.tokens;
+      totals.inputCost += cost;
+      totals.totalCost += cost;
+      const delta = { tokens: record.tokens, cost, count: 1 };
+      addRow(byDay, formatLocalDate(record.ts), { ...delta, count: 0 });
+      addRow(byCategory, "embeddings", delta);
+    }
+  }
+
+  return {
+    updatedAt: Date.now(),
+    startMs: params.startMs,
+    endMs: params.endMs,
+    sessions,
+    totals,
+    byDay: Array.from(byDay.values()).toSorted((a, b) => a.key.localeCompare(b.key)),
+    byModel: sortByCost(byModel.values()),
+    byCanonicalModel: sortByCost(byCanonicalModel.values()).map((row) => ({
+      ...row,
+      details: sortByCost(row.details ?? []),
+    })),
+    byChannel: sortByCost(byChannel.values()),
+    byLabel: sortByCost(byLabel.values()),
+    byCategory: sortByCost(byCategory.values()),
+    zeroCostTokens,
+  };
+}
@@ -0,0 +1,91 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import { loadCostReport } from "./cost-report.js";
+import { compactCostRecords } from "./cost-retention.js";
+import { loadCostSummaryStore, resolveRawRecordsStartMs } from "./cost-summary-store.js";
+
+const DAY_MS = 24 * 60 * 60 * 1000;
+
+const usageLine = (timestamp: Date, cost: number) =>
+  JSON.stringify({
+    type: "message",
+    timestamp: timestamp.toISOString(),
+    message: {
+      role: "assistant",
+      provider: "openai",
+      model: "gpt-5.2",
+      usage: { input: 10, output: 10, totalTokens: 20, cost: { total: cost } },
+    },
+  });
+
+describe("compactCostRecords", () => {
+  it("summarizes old usage, keeps every transcript, and keeps report totals", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-retention-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    const old = new Date(now.getTime() - 20 * DAY_MS);
+
+    await fs.writeFile(
+      path.join(sessionsDir, "sess-live.jsonl"),
+      [usageLine(old, 0.5), usageLine(now, 0.25)].join("\n"),
+      "utf-8",
+    );
+    const orphan = path.join(sessionsDir, "sess-orphan.jsonl");
+    await fs.writeFile(orphan, usageLine(old, 1), "utf-8");
+    await fs.utimes(orphan, old, old);
+    await fs.writeFile(
+      path.join(sessionsDir, "sessions.json"),
+      JSON.stringify({
+        "agent:main:main": { sessionId: "sess-live", updatedAt: now.getTime(), channel: "slack" },
+      }),
+      "utf-8",
+    );
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const config = {} as OpenClawConfig;
+      const range = {
+        config,
+        startMs: now.getTime() - 30 * DAY_MS,
+        endMs: now.getTime() + DAY_MS,
+      };
+      const before = await loadCostReport(range);
+      expect(before.totals.totalCost).toBeCloseTo(1.75, 5);
+
+      const dryRun = await compactCostRecords({ config, now, rawDays: 7, dryRun: true });
+      expect(dryRun.addedRows).toBe(2);
+      expect((await loadCostSummaryStore()).rows).toEqual([]);
+
+      const result = await compactCostRecords({ config, now, rawDays: 7 });
+      expect(result.addedRows).toBe(2);
+      // Unreferenced transcripts are still conversation history; compaction never deletes them.
+      await expect(fs.stat(orphan)).resolves.toBeTruthy();
+
+      const store = await loadCostSummaryStore();
+      expect(store.summarizedThrough).toBe(result.summarizedThrough);
+      expect(store.rows.map((row) => [row.channel, row.cost])).toEqual(
+        expect.arrayContaining([
+          ["slack", 0.5],
+          ["unknown", 1],
+        ]),
+      );
+      expect(resolveRawRecordsStartMs(store, 0)).toBeGreaterThan(old.getTime());
+
+      const after = await loadCostReport(range);
+      expect(after.totals.totalCost).toBeCloseTo(1.75, 5);
+      expect(after.byChannel.map((row) => row.key)).toEqual(["unknown", "slack"]);
+
+      const again = await compactCostRecords({ config, now, rawDays: 7 });
+      expect(again.addedRows).toBe(0);
+    });
+  });
+
+  it("does nothing without a retention window", async () => {
+    const result = await compactCostRecords({ config: {} as OpenClawConfig });
+    expect(result).toEqual({ addedRows: 0 });
+  });
+});
@@ -0,0 +1,118 @@
+import { listAgentIds } from "../agents/agent-scope.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStorePath } from "../config/sessions/paths.js";
+import { loadSessionStore } from "../config/sessions/store.js";
+import type { SessionEntry } from "../config/sessions/types.js";
+import { resolveCostReportChannel, resolveCostReportLabel } from "./cost-report.js";
+import {
+  formatLocalDate,
+  loadCostSummaryStore,
+  resolveCostSummaryStorePath,
+  resolveDayAfterMs,
+  type CostSummaryRow,
+  type CostSummaryStore,
+} from "./cost-summary-store.js";
+import { createAsyncLock, writeJsonAtomic } from "./json-files.js";
+import { discoverAllSessions, loadSessionCostSummary } from "./session-cost-usage.js";
+
+export type CostCompactionResult = {
+  summarizedThrough?: string;
+  addedRows: number;
+};
+
+const withSummaryLock = createAsyncLock();
+
+/**
+ * Fold raw usage older than `cost.retention.rawDays` into daily summary rows. Transcripts are
+ * conversation history and are never touched here (session maintenance owns them); reports read
+ * their usage only after `summarizedThrough`.
+ */
+export async function compactCostRecords(params: {
+  config: OpenClawConfig;
+  now?: Date;
+  rawDays?: number;
+  dryRun?: boolean;
+  storePath?: string;
+}): Promise<CostCompactionResult> {
+  const rawDays = params.rawDays ?? params.config.cost?.retention?.rawDays;
+  if (rawDays === undefined || !Number.isFinite(rawDays) || rawDays < 1) {
+    return { addedRows: 0 };
+  }
+  const now = params.now ?? new Date();
+  const summaryPath = params.storePath ?? resolveCostSummaryStorePath();
+
+  return await withSummaryLock(async () => {
+    const store = await loadCostSummaryStore(summaryPath);
+    const cutoffMs = new Date(
+      now.getFullYear(),
+      now.getMonth(),
+      now.getDate() - rawDays,
+    ).getTime();
+    const startMs = store.summarizedThrough ? resolveDayAfterMs(store.summarizedThrough) : 0;
+    if (startMs >= cutoffMs) {
+      return { summarizedThrough: store.summarizedThrough, addedRows: 0 };
+    }
+    const endMs = cutoffMs - 1;
+
+    const rows = new Map<string, CostSummaryRow>();
+    for (const agentId of listAgentIds(params.config)) {
+      const sessionStore = loadSessionStore(
+        resolveStorePath(params.config.session?.store, { agentId }),
+      );
+      const bySessionId = new Map<string, SessionEntry>();
+      for (const entry of Object.values(sessionStore)) {
+        if (entry?.sessionId) {
+          bySessionId.set(entry.sessionId, entry);
+        }
+      }
+      const discovered = await discoverAllSessions({ agentId });
+      for (const session of discovered) {
+        if (session.mtime < startMs) {
+          continue;
+        }
+        const entry = bySessionId.get(session.sessionId);
+        const usage = await loadSessionCostSummary({
+          sessionId: session.sessionId,
+          sessionEntry: entry,
+          sessionFile: session.sessionFile,
+          config: params.config,
+          agentId,
+          startMs,
+          endMs,
+        });
+        const channel = resolveCostReportChannel(entry);
+        const label = resolveCostReportLabel(entry);
+        for (const day of usage?.dailyModelUsage ?? []) {
+          const key = [day.date, agentId, channel, label, day.provider, day.model].join("\u0000");
+          const row = rows.get(key) ?? {
+            date: day.date,
+            agentId,
+            channel,
+            label,
+            provider: day.provider,
+            model: day.model,
+            tokens: 0,
+            cost: 0,
+            count: 0,
+          };
+          row.tokens += day.tokens;
+          row.cost += day.cost;
+          row.count += day.count;
+          rows.set(key, row);
+        }
+      }
+    }
+
+    const summarizedThrough = formatLocalDate(endMs);
+    if (params.dryRun) {
+      return { summarizedThrough, addedRows: rows.size };
+    }
+    const next: CostSummaryStore = {
+      version: 1,
+      summarizedThrough,
+      rows: [...store.rows, ...rows.values()].toSorted((a, b) => a.date.localeCompare(b.date)),
+    };
+    await writeJsonAtomic(summaryPath, next);
+    return { summarizedThrough, addedRows: rows.size };
+  });
+}
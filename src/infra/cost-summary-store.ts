@@ -0,0 +1,90 @@
+import path from "node:path";
+import { resolveStateDir } from "../config/paths.js";
+import { readJsonFile } from "./json-files.js";
+
+/** One aggregated day of usage for an agent/channel/model, kept after raw records are pruned. */
+export type CostSummaryRow = {
+  date: string; // YYYY-MM-DD (local)
+  agentId: string;
+  channel: string;
+  /** Session label (cron job, sub-agent task); rows written before labels existed omit it. */
+  label?: string;
+  provider?: string;
+  model?: string;
+  tokens: number;
+  cost: number;
+  /** Assistant messages. */
+  count: number;
+};
+
+/** Report key for usage from sessions without a label. */
+export const UNLABELED_COST_LABEL = "unlabeled";
+
+export type CostSummaryStore = {
+  version: 1;
+  /** Last local date (inclusive) folded into `rows`; raw records up to here may be gone. */
+  summarizedThrough?: string;
+  rows: CostSummaryRow[];
+};
+
+export function resolveCostSummaryStorePath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cost", "daily-summaries.json");
+}
+
+export async function loadCostSummaryStore(
+  filePath = resolveCostSummaryStorePath(),
+): Promise<CostSummaryStore> {
+  const parsed = await readJsonFile<CostSummaryStore>(filePath);
+  if (!parsed || parsed.version !== 1 || !Array.isArray(parsed.rows)) {
+    return { version: 1, rows: [] };
+  }
+  return parsed;
+}
+
+export const formatLocalDate = (ms: number) => new Date(ms).toLocaleDateString("en-CA");
+
+/** Start of the local day after `date` (YYYY-MM-DD). */
+export function resolveDayAfterMs(date: string): number {
+  const [year, month, day] = date.split("-").map(Number);
+  return new Date(year, month - 1, day + 1).getTime();
+}
+
+/**
+ * Where raw transcript records should take over from summaries for a range starting at `startMs`.
+ * Returns `startMs` unchanged when nothing has been summarized yet.
+ */
+export function resolveRawRecordsStartMs(store: CostSummaryStore, startMs: number): number {
+  if (!store.summarizedThrough) {
+    return startMs;
+  }
+  return Math.max(startMs, resolveDayAfterMs(store.summarizedThrough));
+}
+
+/** Summary rows inside [startMs, endMs] that are no longer backed by raw records. */
+export function selectCostSummaryRows(
+  store: CostSummaryStore,
+  params: {
+    startMs: number;
+    endMs: number;
+    agentIds?: string[];
+    channel?: string;
+    label?: string;
+  },
+): CostSummaryRow[] {
+  if (!store.summarizedThrough) {
+    return [];
+  }
+  const startDate = formatLocalDate(params.startMs);
+  const endDate = formatLocalDate(params.endMs);
+  const channel = params.channel?.trim().toLowerCase();
+  const label = params.label?.trim().toLowerCase();
+  return store.rows.filter(
+    (row) =>
+      row.date >= startDate &&
+      row.date <= endDate &&
+      row.date <= store.summarizedThrough! &&
+      (!params.agentIds || params.agentIds.includes(row.agentId)) &&
+      (!channel || row.channel.toLowerCase() === channel) &&
+      (!label || (row.label ?? UNLABELED_COST_LABEL).toLowerCase() === label),
+  );
+}
@@ -0,0 +1,172 @@
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import { resetCostBudgetSpendCacheForTest } from "./cost-budget.js";
+import {
+  buildCostWebhookHeaders,
+  formatBudgetThresholdAlert,
+  resetCostWebhookThrottleForTest,
+  resolveCrossedBudgetThreshold,
+  runCostWebhookChecks,
+  type CostBudgetThresholdEvent,
+  type CostWebhookEvent,
+} from "./cost-webhook.js";
+
+const MINUTE_MS = 60_000;
+
+describe("cost webhook", () => {
+  afterEach(() => {
+    resetCostBudgetSpendCacheForTest();
+    resetCostWebhookThrottleForTest();
+  });
+
+  it("signs the timestamped body with the shared secret", () => {
+    const event: CostWebhookEvent = {
+      type: "budget.threshold",
+      ts: 0,
+      period: "day",
+      thresholdPercent: 80,
+      percentUsed: 84,
+      limitUsd: 5,
+      spentUsd: 4.2,
+    };
+    const body = JSON.stringify(event);
+    const headers = buildCostWebhookHeaders({ body, event, secret: "s3cret", timestamp: 1700 });
+    const expected = crypto.createHmac("sha256", "s3cret").update(`1700.${body}`).digest("hex");
+    expect(headers).toMatchObject({
+      "X-OpenClaw-Event": "budget.threshold",
+      "X-OpenClaw-Timestamp": "1700",
+      "X-OpenClaw-Signature": `sha256=${expected}`,
+    });
+    expect(buildCostWebhookHeaders({ body, event })).not.toHaveProperty("X-OpenClaw-Signature");
+  });
+
+  it("reports the highest new threshold once per jump", () => {
+    expect(
+      resolveCrossedBudgetThreshold({
+        limitUsd: 10,
+        spentUsd: 8.5,
+        thresholds: [50, 80, 100],
+        notified: [],
+      }),
+    ).toEqual({ thresholdPercent: 80, percentUsed: 85, crossed: [50, 80] });
+    expect(
+      resolveCrossedBudgetThreshold({
+        limitUsd: 10,
+        spentUsd: 8.5,
+        thresholds: [50, 80, 100],
+        notified: [50, 80],
+      }),
+    ).toBeUndefined();
+  });
+
+  it("sends threshold crossings and the daily summary without repeats", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-webhook-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    await fs.writeFile(
+      path.join(sessionsDir, "sess-1.jsonl"),
+      JSON.stringify({
+        type: "message",
+        timestamp: now.toISOString(),
+        message: {
+          role: "assistant",
+          provider: "openai",
+          model: "gpt-5.2",
+          usage: { input: 10, output: 10, totalTokens: 20, cost: { total: 0.6 } },
+        },
+      }),
+      "utf-8",
+    );
+    const config = {
+      cost: {
+        budget: { dailyUsd: 1 },
+        webhook: { url: "https://hooks.example.com/cost", secret: "s3cret" },
+      },
+    } as OpenClawConfig;
+    const send = vi.fn(async () => {});
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const first = await runCostWebhookChecks({ config, now, send });
+      expect(first.map((event) => event.type)).toEqual(["budget.threshold", "cost.daily_summary"]);
+      expect(first[0]).toMatchObject({ period: "day", thresholdPercent: 50, spentUsd: 0.6 });
+
+      // Throttled within the same minute.
+      expect(await runCostWebhookChecks({ config, now, send })).toEqual([]);
+
+      const later = new Date(now.getTime() + 2 * MINUTE_MS);
+      expect(await runCostWebhookChecks({ config, now: later, send })).toEqual([]);
+      expect(send).toHaveBeenCalledTimes(2);
+    });
+  });
+
+  it("broadcasts threshold crossings to alert targets without a webhook", async () => {
+    const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-cost-alerts-"));
+    const sessionsDir = path.join(root, "agents", "main", "sessions");
+    await fs.mkdir(sessionsDir, { recursive: true });
+    const now = new Date();
+    await fs.writeFile(
+      path.join(sessionsDir, "sess-1.jsonl"),
+      JSON.stringify({
+        type: "message",
+        timestamp: now.toISOString(),
+        message: {
+          role: "assistant",
+          provider: "openai",
+          model: "gpt-5.2",
+          usage: { input: 10, output: 10, totalTokens: 20, cost: { total: 0.9 } },
+        },
+      }),
+      "utf-8",
+    );
+    const config = {
+      cost: { budget: { dailyUsd: 1 } },
+      messages: { alerts: { targets: [{ channel: "telegram", to: "123" }] } },
+    } as OpenClawConfig;
+    const send = vi.fn(async () => {});
+    const alert = vi.fn(async () => {});
+
+    await withEnvAsync({ OPENCLAW_STATE_DIR: root }, async () => {
+      const events = await runCostWebhookChecks({ config, now, send, alert });
+      expect(events.map((event) => event.type)).toEqual(["budget.threshold"]);
+      expect(send).not.toHaveBeenCalled();
+      expect(alert).toHaveBeenCalledWith({
+        config,
+        event: expect.objectContaining({ period: "day", thresholdPercent: 80 }),
+      });
+    });
+  });
+
+  it("formats budget alerts per period", () => {
+    const event: CostBudgetThresholdEvent = {
+      type: "budget.threshold",
+      ts: 0,
+      period: "month",
+      thresholdPercent: 80,
+      percentUsed: 84.4,
+      limitUsd: 50,
+      spentUsd: 42.2,
+    };
+    expect(formatBudgetThresholdAlert(event)).toBe(
+      "⚠️ Monthly cost budget at 84% ($42.20 of $50.00 spent).",
+    );
+    expect(
+      formatBudgetThresholdAlert({ ...event, period: "day", percentUsed: 100, spentUsd: 50 }),
+    ).toBe("⛔ Daily cost budget reached ($50.00 of $50.00 spent).");
+  });
+
+  it("skips everything without a webhook url", async () => {
+    const send = vi.fn(async () => {});
+    const events = await runCostWebhookChecks({
+      config: { cost: { budget: { dailyUsd: 1 } } } as OpenClawConfig,
+      send,
+    });
+    expect(events).toEqual([]);
+    expect(send).not.toHaveBeenCalled();
+  });
+});
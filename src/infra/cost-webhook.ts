@@ -0,0 +1,354 @@
+import crypto from "node:crypto";
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import type { CostBudgetConfig } from "../config/types.cost.js";
+import { normalizeHttpWebhookUrl } from "../cron/webhook-url.js";
+import {
+  formatCost,
+  resolveCostDisplayCurrency,
+  type CostDisplayCurrency,
+} from "../utils/usage-format.js";
+import { broadcastAlert, hasAlertTargets } from "./alerts.js";
+import {
+  loadCostBudgetSpend,
+  resolveBudgetPeriodStart,
+  type CostBudgetPeriod,
+  type CostBudgetSpend,
+} from "./cost-budget.js";
+import { loadCostReport, type CostReportRow } from "./cost-report.js";
+import { formatLocalDate } from "./cost-summary-store.js";
+import { createAsyncLock, readJsonFile, writeJsonAtomic } from "./json-files.js";
+import { fetchWithSsrFGuard } from "./net/fetch-guard.js";
+
+export const DEFAULT_COST_WEBHOOK_THRESHOLDS = [50, 80, 100];
+
+const DEFAULT_COST_WEBHOOK_TIMEOUT_SECONDS = 10;
+const COST_WEBHOOK_MIN_CHECK_INTERVAL_MS = 60_000;
+const DAILY_SUMMARY_TOP_ROWS = 10;
+
+export const COST_WEBHOOK_SIGNATURE_HEADER = "X-OpenClaw-Signature";
+export const COST_WEBHOOK_TIMESTAMP_HEADER = "X-OpenClaw-Timestamp";
+export const COST_WEBHOOK_EVENT_HEADER = "X-OpenClaw-Event";
+
+export type CostBudgetThresholdEvent = {
+  type: "budget.threshold";
+  ts: number;
+  period: CostBudgetPeriod;
+  /** Highest configured threshold (percent of the limit) crossed by this check. */
+  thresholdPercent: number;
+  percentUsed: number;
+  limitUsd: number;
+  spentUsd: number;
+};
+
+export type CostWebhookEvent =
+  | CostBudgetThresholdEvent
+  | {
+      type: "cost.daily_summary";
+      ts: number;
+      date: string;
+      totalCost: number;
+      totalTokens: number;
+      sessions: number;
+      byModel: CostReportRow[];
+      byChannel: CostReportRow[];
+    };
+
+type ThresholdState = { key: string; percents: number[] };
+
+type PendingThresholdEvent = {
+  event: CostBudgetThresholdEvent;
+  period: CostBudgetPeriod;
+  next: ThresholdState;
+};
+
+type CostWebhookState = {
+  version: 1;
+  thresholds?: Partial<Record<CostBudgetPeriod, ThresholdState>>;
+  /** Last local date (YYYY-MM-DD) a daily summary was delivered for. */
+  lastDailySummary?: string;
+};
+
+export type CostWebhookSender = (params: {
+  config: OpenClawConfig;
+  event: CostWebhookEvent;
+}) => Promise<void>;
+
+export type CostBudgetAlertSender = (params: {
+  config: OpenClawConfig;
+  event: CostBudgetThresholdEvent;
+}) => Promise<void>;
+
+export function resolveCostWebhookStatePath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cost", "webhook-state.json");
+}
+
+export function hasCostWebhook(config: OpenClawConfig): boolean {
+  return Boolean(normalizeHttpWebhookUrl(config.cost?.webhook?.url));
+}
+
+/** Whether threshold checks have anywhere to report: `cost.webhook` or budget alert targets. */
+export function hasCostNotifications(config: OpenClawConfig): boolean {
+  return hasCostWebhook(config) || hasAlertTargets(config, "budget");
+}
+
+export function formatBudgetThresholdAlert(
+  event: CostBudgetThresholdEvent,
+  currency?: CostDisplayCurrency,
+): string {
+  const money = (value: number) => formatCost(value, currency) ?? "n/a";
+  const label = event.period === "day" ? "Daily" : "Monthly";
+  const status = `${money(event.spentUsd)} of ${money(event.limitUsd)} spent`;
+  return event.percentUsed >= 100
+    ? `⛔ ${label} cost budget reached (${status}).`
+    : `⚠️ ${label} cost budget at ${Math.round(event.percentUsed)}% (${status}).`;
+}
+
+/** Broadcast a threshold crossing to `messages.alerts` targets that take budget alerts. */
+export const sendCostBudgetAlert: CostBudgetAlertSender = async ({ config, event }) => {
+  const text = formatBudgetThresholdAlert(event, resolveCostDisplayCurrency({ config }));
+  // A reached budget is worth waking someone for; earlier thresholds can wait for the digest.
+  const severity = event.percentUsed >= 100 ? "critical" : "warning";
+  await broadcastAlert({ config, kind: "budget", text, severity });
+};
+
+/** Hex HMAC-SHA256 over `${timestamp}.${body}`; receivers should reject stale timestamps. */
+export function signCostWebhookPayload(params: {
+  body: string;
+  secret: string;
+  timestamp: number;
+}): string {
+  return crypto
+    .createHmac("sha256", params.secret)
+    .update(`${params.timestamp}.${params.body}`)
+    .digest("hex");
+}
+
+export function buildCostWebhookHeaders(params: {
+  body: string;
+  event: CostWebhookEvent;
+  secret?: string;
+  timestamp?: number;
+}): Record<string, string> {
+  const timestamp = params.timestamp ?? Math.floor(Date.now() / 1000);
+  const headers: Record<string, string> = {
+    "Content-Type": "application/json",
+    [COST_WEBHOOK_EVENT_HEADER]: params.event.type,
+    [COST_WEBHOOK_TIMESTAMP_HEADER]: String(timestamp),
+  };
+  const secret = params.secret?.trim();
+  if (secret) {
+    const signature = signCostWebhookPayload({ body: params.body, secret, timestamp });
+    headers[COST_WEBHOOK_SIGNATURE_HEADER] = `sha256=${signature}`;
+  }
+  return headers;
+}
+
+export const sendCostWebhook: CostWebhookSender = async ({ config, event }) => {
+  const webhook = config.cost?.webhook;
+  const url = normalizeHttpWebhookUrl(webhook?.url);
+  if (!url) {
+    return;
+  }
+  const body = JSON.stringify(event);
+  const result = await fetchWithSsrFGuard({
+    url,
+    init: {
+      method: "POST",
+      headers: buildCostWebhookHeaders({ body, event, secret: webhook?.secret }),
+      body,
+    },
+    timeoutMs: (webhook?.timeoutSeconds ?? DEFAULT_COST_WEBHOOK_TIMEOUT_SECONDS) * 1000,
+    auditContext: "cost-webhook",
+  });
+  try {
+    if (!result.response.ok) {
+      throw new Error(`cost webhook responded ${result.response.status}`);
+    }
+  } finally {
+    await result.release();
+  }
+};
+
+/**
+ * Highest threshold crossed for a period that has not been reported yet.
+ * Lower thresholds crossed in the same jump are folded into that one event.
+ */
+export function resolveCrossedBudgetThreshold(params: {
+  limitUsd: number;
+  spentUsd: number;
+  thresholds: number[];
+  notified: number[];
+}): { thresholdPercent: number; percentUsed: number; crossed: number[] } | undefined {
+  if (!(params.limitUsd > 0)) {
+    return undefined;
+  }
+  const percentUsed = (params.spentUsd / params.limitUsd) * 100;
+  const crossed = params.thresholds
+    .filter((threshold) => percentUsed >= threshold && !params.notified.includes(threshold))
+    .toSorted((a, b) => a - b);
+  const thresholdPercent = crossed.at(-1);
+  if (thresholdPercent === undefined) {
+    return undefined;
+  }
+  return { thresholdPercent, percentUsed, crossed };
+}
+
+function resolvePeriodKey(period: CostBudgetPeriod, now: Date): string {
+  const date = formatLocalDate(now.getTime());
+  return period === "day" ? date : date.slice(0, 7);
+}
+
+function collectThresholdEvents(params: {
+  budget?: CostBudgetConfig;
+  spend: CostBudgetSpend;
+  thresholds: number[];
+  state: CostWebhookState;
+  now: Date;
+}): PendingThresholdEvent[] {
+  const limits: Array<{ period: CostBudgetPeriod; limitUsd?: number; spentUsd: number }> = [
+    { period: "day", limitUsd: params.budget?.dailyUsd, spentUsd: params.spend.dayUsd },
+    { period: "month", limitUsd: params.budget?.monthlyUsd, spentUsd: params.spend.monthUsd },
+  ];
+  const events: PendingThresholdEvent[] = [];
+  for (const { period, limitUsd, spentUsd } of limits) {
+    if (limitUsd === undefined) {
+      continue;
+    }
+    const key = resolvePeriodKey(period, params.now);
+    const previous = params.state.thresholds?.[period];
+    const notified = previous?.key === key ? previous.percents : [];
+    const crossing = resolveCrossedBudgetThreshold({
+      limitUsd,
+      spentUsd,
+      thresholds: params.thresholds,
+      notified,
+    });
+    if (!crossing) {
+      continue;
+    }
+    events.push({
+      period,
+      next: { key, percents: [...notified, ...crossing.crossed] },
+      event: {
+        type: "budget.threshold",
+        ts: params.now.getTime(),
+        period,
+        thresholdPercent: crossing.thresholdPercent,
+        percentUsed: Math.round(crossing.percentUsed * 10) / 10,
+        limitUsd,
+        spentUsd,
+      },
+    });
+  }
+  return events;
+}
+
+async function buildDailySummaryEvent(params: {
+  config: OpenClawConfig;
+  now: Date;
+}): Promise<CostWebhookEvent> {
+  const endMs = resolveBudgetPeriodStart("day", params.now) - 1;
+  const start = new Date(endMs);
+  const startMs = new Date(start.getFullYear(), start.getMonth(), start.getDate()).getTime();
+  const report = await loadCostReport({ config: params.config, startMs, endMs });
+  return {
+    type: "cost.daily_summary",
+    ts: params.now.getTime(),
+    date: formatLocalDate(startMs),
+    totalCost: report.totals.totalCost,
+    totalTokens: report.totals.totalTokens,
+    sessions: report.sessions,
+    byModel: report.byModel.slice(0, DAILY_SUMMARY_TOP_ROWS),
+    byChannel: report.byChannel.slice(0, DAILY_SUMMARY_TOP_ROWS),
+  };
+}
+
+const withWebhookLock = createAsyncLock();
+let lastCheckAt: number | undefined;
+
+export function resetCostWebhookThrottleForTest() {
+  lastCheckAt = undefined;
+}
+
+/**
+ * Fire `cost.webhook` events that are due: budget threshold crossings for today/this month
+ * and yesterday's summary (once per day, on the first check after midnight). Threshold
+ * crossings are also broadcast to `messages.alerts` targets; the daily summary is webhook-only.
+ * Checks are throttled to one per minute; delivery failures are retried on the next check.
+ */
+export async function runCostWebhookChecks(params: {
+  config: OpenClawConfig;
+  now?: Date;
+  send?: CostWebhookSender;
+  alert?: CostBudgetAlertSender;
+  statePath?: string;
+}): Promise<CostWebhookEvent[]> {
+  const { config } = params;
+  const webhookEnabled = hasCostWebhook(config);
+  const alertsEnabled = hasAlertTargets(config, "budget");
+  if (!webhookEnabled && !alertsEnabled) {
+    return [];
+  }
+  const now = params.now ?? new Date();
+  if (
+    lastCheckAt !== undefined &&
+    now.getTime() - lastCheckAt < COST_WEBHOOK_MIN_CHECK_INTERVAL_MS
+  ) {
+    return [];
+  }
+  lastCheckAt = now.getTime();
+  const send = params.send ?? sendCostWebhook;
+  const alert = params.alert ?? sendCostBudgetAlert;
+  const statePath = params.statePath ?? resolveCostWebhookStatePath();
+  const webhook = config.cost?.webhook;
+
+  return await withWebhookLock(async () => {
+    const loaded = await readJsonFile<CostWebhookState>(statePath);
+    const state: CostWebhookState = loaded?.version === 1 ? loaded : { version: 1 };
+    const sent: CostWebhookEvent[] = [];
+    const budget = config.cost?.budget;
+    try {
+      if (budget?.dailyUsd !== undefined || budget?.monthlyUsd !== undefined) {
+        const spend = await loadCostBudgetSpend({ config, now, refresh: true });
+        const pending = collectThresholdEvents({
+          budget,
+          spend,
+          thresholds: webhook?.thresholds ?? DEFAULT_COST_WEBHOOK_THRESHOLDS,
+          state,
+          now,
+        });
+        for (const { event, period, next } of pending) {
+          if (webhookEnabled) {
+            await send({ config, event });
+          }
+          if (alertsEnabled) {
+            // Never throws: per-channel failures are logged, not retried.
+            await alert({ config, event });
+          }
+          state.thresholds = { ...state.thresholds, [period]: next };
+          sent.push(event);
+        }
+      }
+
+      const yesterday = formatLocalDate(resolveBudgetPeriodStart("day", now) - 1);
+      if (
+        webhookEnabled &&
+        webhook?.dailySummary !== false &&
+        (state.lastDailySummary ?? "") < yesterday
+      ) {
+        const event = await buildDailySummaryEvent({ config, now });
+        await send({ config, event });
+        state.lastDailySummary = yesterday;
+        sent.push(event);
+      }
+    } finally {
+      // Persist whatever was delivered so a later failure does not cause duplicates.
+      if (sent.length > 0) {
+        await writeJsonAtomic(statePath, state);
+      }
+    }
+    return sent;
+  });
+}
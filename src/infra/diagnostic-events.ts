@@ -122,6 +122,19 @@ export type DiagnosticRunAttemptEvent = DiagnosticBaseEvent & {
   attempt: number;
 };
 
+export type DiagnosticProviderRetryEvent = DiagnosticBaseEvent & {
+  type: "provider.retry";
+  sessionKey?: string;
+  sessionId?: string;
+  provider?: string;
+  model?: string;
+  attempt: number;
+  maxAttempts: number;
+  delayMs: number;
+  retryAfterMs?: number;
+  error: string;
+};
+
 export type DiagnosticHeartbeatEvent = DiagnosticBaseEvent & {
   type: "diagnostic.heartbeat";
   webhooks: {
@@ -159,6 +172,7 @@ export type DiagnosticEventPayload =
   | DiagnosticLaneEnqueueEvent
   | DiagnosticLaneDequeueEvent
   | DiagnosticRunAttemptEvent
+  | DiagnosticProviderRetryEvent
   | DiagnosticHeartbeatEvent
   | DiagnosticToolLoopEvent;
 
@@ -0,0 +1,63 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import {
+  estimateEmbeddingCost,
+  loadEmbeddingUsage,
+  recordEmbeddingUsage,
+} from "./embedding-usage.js";
+
+describe("embedding usage ledger", () => {
+  it("appends records and filters them by time and agent", async () => {
+    const dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-embedding-usage-"));
+    const filePath = path.join(dir, "cost", "embeddings.jsonl");
+    const base = { provider: "openai", model: "text-embedding-3-small", inputs: 4 };
+    await recordEmbeddingUsage({ ...base, tokens: 100, ts: 1_000, agentId: "main" }, filePath);
+    await recordEmbeddingUsage({ ...base, tokens: 200, ts: 2_000, agentId: "work" }, filePath);
+    await recordEmbeddingUsage({ ...base, tokens: 0, ts: 2_500 }, filePath);
+    await recordEmbeddingUsage({ ...base, tokens: 300, ts: 9_000 }, filePath);
+
+    const all = await loadEmbeddingUsage({ startMs: 0, endMs: 5_000, filePath });
+    expect(all.map((record) => record.tokens)).toEqual([100, 200]);
+    const main = await loadEmbeddingUsage({
+      startMs: 0,
+      endMs: 5_000,
+      agentIds: ["main"],
+      filePath,
+    });
+    expect(main.map((record) => record.tokens)).toEqual([100]);
+  });
+
+  it("prices embeddings from config, then list prices; local providers are free", () => {
+    expect(
+      estimateEmbeddingCost({ provider: "openai", model: "text-embedding-3-small", tokens: 1e6 }),
+    ).toBeCloseTo(0.02, 6);
+    expect(
+      estimateEmbeddingCost({ provider: "ollama", model: "nomic-embed-text", tokens: 1e6 }),
+    ).toBe(0);
+
+    const config = {
+      models: {
+        providers: {
+          openai: {
+            baseUrl: "https://api.openai.com/v1",
+            models: [
+              {
+                id: "text-embedding-3-large",
+                cost: { input: 0.1, output: 0, cacheRead: 0, cacheWrite: 0 },
+              },
+            ],
+          },
+        },
+      },
+    } as unknown as OpenClawConfig;
+    expect(
+      estimateEmbeddingCost(
+        { provider: "openai", model: "text-embedding-3-large", tokens: 1e6 },
+        config,
+      ),
+    ).toBeCloseTo(0.1, 6);
+  });
+});
@@ -0,0 +1,119 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { resolveModelCostConfig } from "../utils/usage-format.js";
+
+/** One embeddings request (memory indexing or search), as appended to the usage ledger. */
+export type EmbeddingUsageRecord = {
+  ts: number;
+  provider: string;
+  model: string;
+  /** Input tokens reported by the provider. */
+  tokens: number;
+  /** Texts embedded by the request. */
+  inputs: number;
+  agentId?: string;
+};
+
+export type EmbeddingUsageTotals = {
+  tokens: number;
+  cost: number;
+  /** Embedding requests. */
+  count: number;
+};
+
+/** USD per 1M input tokens for embedding models without a configured `cost.input`. */
+const EMBEDDING_PRICES_PER_MILLION: Record<string, number> = {
+  "openai/text-embedding-3-small": 0.02,
+  "openai/text-embedding-3-large": 0.13,
+  "openai/text-embedding-ada-002": 0.1,
+  "mistral/mistral-embed": 0.1,
+};
+
+/** Providers that run on local hardware; their embeddings are always free. */
+const LOCAL_EMBEDDING_PROVIDERS = new Set(["ollama", "local"]);
+
+export function resolveEmbeddingUsageLogPath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "cost", "embeddings.jsonl");
+}
+
+/**
+ * Append an embeddings usage record. Best-effort: a failed write never fails the embedding
+ * request it describes.
+ */
+export async function recordEmbeddingUsage(
+  record: Omit<EmbeddingUsageRecord, "ts"> & { ts?: number },
+  filePath = resolveEmbeddingUsageLogPath(),
+): Promise<void> {
+  if (!(record.tokens > 0)) {
+    return;
+  }
+  const line = JSON.stringify({ ...record, ts: record.ts ?? Date.now() });
+  try {
+    await fs.mkdir(path.dirname(filePath), { recursive: true });
+    await fs.appendFile(filePath, `${line}\n`, "utf-8");
+  } catch {
+    // Usage accounting must not break memory indexing.
+  }
+}
+
+export async function loadEmbeddingUsage(params: {
+  startMs: number;
+  endMs: number;
+  agentIds?: string[];
+  filePath?: string;
+}): Promise<EmbeddingUsageRecord[]> {
+  let raw: string;
+  try {
+    raw = await fs.readFile(params.filePath ?? resolveEmbeddingUsageLogPath(), "utf-8");
+  } catch {
+    return [];
+  }
+  const records: EmbeddingUsageRecord[] = [];
+  for (const line of raw.split("\n")) {
+    if (!line.trim()) {
+      continue;
+    }
+    let parsed: EmbeddingUsageRecord;
+    try {
+      parsed = JSON.parse(line) as EmbeddingUsageRecord;
+    } catch {
+      continue;
+    }
+    if (
+      typeof parsed?.ts !== "number" ||
+      parsed.ts < params.startMs ||
+      parsed.ts > params.endMs ||
+      typeof parsed.tokens !== "number"
+    ) {
+      continue;
+    }
+    if (params.agentIds && parsed.agentId && !params.agentIds.includes(parsed.agentId)) {
+      continue;
+    }
+    records.push(parsed);
+  }
+  return records;
+}
+
+/**
+ * USD cost of an embeddings record: configured `models.providers.<id>.models[].cost.input`
+ * first, then built-in list prices; local providers are free. Unknown models cost 0.
+ */
+export function estimateEmbeddingCost(
+  record: Pick<EmbeddingUsageRecord, "provider" | "model" | "tokens">,
+  config?: OpenClawConfig,
+): number {
+  const provider = record.provider.trim().toLowerCase();
+  if (LOCAL_EMBEDDING_PROVIDERS.has(provider)) {
+    return 0;
+  }
+  const configured = resolveModelCostConfig({
+    provider: record.provider,
+    model: record.model,
+    config,
+  })?.input;
+  const perMillion = configured ?? EMBEDDING_PRICES_PER_MILLION[`${provider}/${record.model}`];
+  return perMillion ? (record.tokens * perMillion) / 1_000_000 : 0;
+}
@@ -135,6 +135,25 @@ describe("exec approval forwarder", () => {
     expect(getFirstDeliveryText(deliver)).toContain("Command: `echo hello`");
   });
 
+  it("attaches approve/deny buttons to the request only", async () => {
+    vi.useFakeTimers();
+    const { deliver, forwarder } = createForwarder({ cfg: TARGETS_CFG });
+
+    await expect(forwarder.handleRequested(baseRequest)).resolves.toBe(true);
+    const request = deliver.mock.calls[0]?.[0] as {
+      payloads: Array<{ interactive?: { rows: Array<Array<{ value: string }>> } }>;
+    };
+    expect(request.payloads[0]?.interactive?.rows[0]?.map((button) => button.value)).toEqual([
+      "/approve req-1 allow-once",
+      "/approve req-1 allow-always",
+      "/approve req-1 deny",
+    ]);
+
+    await vi.runAllTimersAsync();
+    const expired = deliver.mock.calls[1]?.[0] as { payloads: Array<{ interactive?: unknown }> };
+    expect(expired.payloads[0]?.interactive).toBeUndefined();
+  });
+
   it("formats complex commands as fenced code blocks", async () => {
     vi.useFakeTimers();
     const { deliver, forwarder } = createForwarder({ cfg: TARGETS_CFG });
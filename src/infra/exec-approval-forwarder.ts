@@ -1,3 +1,5 @@
+import type { ReplyPayload } from "../auto-reply/types.js";
+import type { InteractiveReply } from "../channels/interactive.js";
 import type { OpenClawConfig } from "../config/config.js";
 import { loadConfig } from "../config/config.js";
 import { loadSessionStore, resolveStorePath } from "../config/sessions.js";
@@ -185,6 +187,19 @@ function buildRequestMessage(request: ExecApprovalRequest, nowMs: number) {
   return lines.join("\n");
 }
 
+function buildRequestButtons(request: ExecApprovalRequest): InteractiveReply {
+  const command = `/approve ${request.id}`;
+  return {
+    rows: [
+      [
+        { label: "Allow once", value: `${command} allow-once`, style: "success" },
+        { label: "Always allow", value: `${command} allow-always`, style: "primary" },
+        { label: "Deny", value: `${command} deny`, style: "danger" },
+      ],
+    ],
+  };
+}
+
 function decisionLabel(decision: ExecApprovalDecision): string {
   if (decision === "allow-once") {
     return "allowed once";
@@ -239,7 +254,7 @@ function defaultResolveSessionTarget(params: {
 async function deliverToTargets(params: {
   cfg: OpenClawConfig;
   targets: ForwardTarget[];
-  text: string;
+  payload: ReplyPayload;
   deliver: typeof deliverOutboundPayloads;
   shouldSend?: () => boolean;
 }) {
@@ -258,7 +273,7 @@ async function deliverToTargets(params: {
         to: target.to,
         accountId: target.accountId,
         threadId: target.threadId,
-        payloads: [{ text: params.text }],
+        payloads: [params.payload],
       });
     } catch (err) {
       log.error(`exec approvals: failed to deliver to ${channel}:${target.to}: ${String(err)}`);
@@ -344,7 +359,12 @@ export function createExecApprovalForwarder(
         }
         pending.delete(request.id);
         const expiredText = buildExpiredMessage(request);
-        await deliverToTargets({ cfg, targets: entry.targets, text: expiredText, deliver });
+        await deliverToTargets({
+          cfg,
+          targets: entry.targets,
+          payload: { text: expiredText },
+          deliver,
+        });
       })();
     }, expiresInMs);
     timeoutId.unref?.();
@@ -356,11 +376,14 @@ export function createExecApprovalForwarder(
       return false;
     }
 
-    const text = buildRequestMessage(request, nowMs());
+    const payload: ReplyPayload = {
+      text: buildRequestMessage(request, nowMs()),
+      interactive: buildRequestButtons(request),
+    };
     void deliverToTargets({
       cfg,
       targets: filteredTargets,
-      text,
+      payload,
       deliver,
       shouldSend: () => pending.get(request.id) === pendingEntry,
     }).catch((err) => {
@@ -401,7 +424,7 @@ export function createExecApprovalForwarder(
       return;
     }
     const text = buildResolvedMessage(resolved);
-    await deliverToTargets({ cfg, targets, text, deliver });
+    await deliverToTargets({ cfg, targets, payload: { text }, deliver });
   };
 
   const stop = () => {
@@ -6,7 +6,7 @@ type HeartbeatConfig = AgentDefaultsConfig["heartbeat"];
 
 const ACTIVE_HOURS_TIME_PATTERN = /^(?:([01]\d|2[0-3]):([0-5]\d)|24:00)$/;
 
-function resolveActiveHoursTimezone(cfg: OpenClawConfig, raw?: string): string {
+export function resolveActiveHoursTimezone(cfg: OpenClawConfig, raw?: string): string {
   const trimmed = raw?.trim();
   if (!trimmed || trimmed === "user") {
     return resolveUserTimezone(cfg.agents?.defaults?.userTimezone);
@@ -67,29 +67,33 @@ function resolveMinutesInTimeZone(nowMs: number, timeZone: string): number | nul
   }
 }
 
-export function isWithinActiveHours(
+/**
+ * Whether `nowMs` falls inside a `start`–`end` local-time window ("HH:MM", end may be "24:00";
+ * windows may wrap midnight). Undefined when the window is missing or malformed, so callers pick
+ * their own fallback.
+ */
+export function isWithinTimeWindow(
   cfg: OpenClawConfig,
-  heartbeat?: HeartbeatConfig,
+  window: { start?: string; end?: string; timezone?: string } | undefined,
   nowMs?: number,
-): boolean {
-  const active = heartbeat?.activeHours;
-  if (!active) {
-    return true;
+): boolean | undefined {
+  if (!window) {
+    return undefined;
   }
 
-  const startMin = parseActiveHoursTime({ allow24: false }, active.start);
-  const endMin = parseActiveHoursTime({ allow24: true }, active.end);
+  const startMin = parseActiveHoursTime({ allow24: false }, window.start);
+  const endMin = parseActiveHoursTime({ allow24: true }, window.end);
   if (startMin === null || endMin === null) {
-    return true;
+    return undefined;
   }
   if (startMin === endMin) {
     return false;
   }
 
-  const timeZone = resolveActiveHoursTimezone(cfg, active.timezone);
+  const timeZone = resolveActiveHoursTimezone(cfg, window.timezone);
   const currentMin = resolveMinutesInTimeZone(nowMs ?? Date.now(), timeZone);
   if (currentMin === null) {
-    return true;
+    return undefined;
   }
 
   if (endMin > startMin) {
@@ -97,3 +101,11 @@ export function isWithinActiveHours(
   }
   return currentMin >= startMin || currentMin < endMin;
 }
+
+export function isWithinActiveHours(
+  cfg: OpenClawConfig,
+  heartbeat?: HeartbeatConfig,
+  nowMs?: number,
+): boolean {
+  return isWithinTimeWindow(cfg, heartbeat?.activeHours, nowMs) ?? true;
+}
@@ -1,6 +1,7 @@
 import { describe, expect, it } from "vitest";
 import {
   isHeartbeatActionWakeReason,
+  isHeartbeatActiveHoursExemptReason,
   isHeartbeatEventDrivenReason,
   normalizeHeartbeatWakeReason,
   resolveHeartbeatReasonKind,
@@ -49,4 +50,11 @@ describe("heartbeat-reason", () => {
     expect(isHeartbeatActionWakeReason("cron:job-1")).toBe(false);
     expect(isHeartbeatActionWakeReason("retry")).toBe(false);
   });
+
+  it("exempts only watchdog wakes from active hours", () => {
+    expect(isHeartbeatActiveHoursExemptReason("hook:watchdog:heater")).toBe(true);
+    expect(isHeartbeatActiveHoursExemptReason("hook:wake")).toBe(false);
+    expect(isHeartbeatActiveHoursExemptReason("interval")).toBe(false);
+    expect(isHeartbeatActiveHoursExemptReason(undefined)).toBe(false);
+  });
 });
@@ -48,6 +48,11 @@ export function isHeartbeatEventDrivenReason(reason?: string): boolean {
   return kind === "exec-event" || kind === "cron" || kind === "wake" || kind === "hook";
 }
 
+/** Sensor watchdog breaches are safety wakes: they run even outside `heartbeat.activeHours`. */
+export function isHeartbeatActiveHoursExemptReason(reason?: string): boolean {
+  return trimReason(reason).startsWith("hook:watchdog:");
+}
+
 export function isHeartbeatActionWakeReason(reason?: string): boolean {
   const kind = resolveHeartbeatReasonKind(reason);
   return kind === "manual" || kind === "exec-event" || kind === "hook";
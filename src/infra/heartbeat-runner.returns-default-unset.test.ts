@@ -12,6 +12,7 @@ import {
   resolveMainSessionKey,
   resolveStorePath,
 } from "../config/sessions.js";
+import { addGoal } from "../goals/store.js";
 import { getActivePluginRegistry, setActivePluginRegistry } from "../plugins/runtime.js";
 import { buildAgentPeerSessionKey } from "../routing/session-key.js";
 import { createOutboundTestPlugin, createTestRegistry } from "../test-utils/channel-plugins.js";
@@ -184,6 +185,17 @@ describe("resolveHeartbeatPrompt", () => {
       expect(resolveHeartbeatPrompt(testCase.cfg)).toBe(testCase.expected);
     }
   });
+
+  it("appends checklist items, preferring the per-agent list", () => {
+    const cfg = {
+      agents: { defaults: { heartbeat: { prompt: "ping", checklist: ["a", "  ", " b "] } } },
+    } as OpenClawConfig;
+    expect(resolveHeartbeatPrompt(cfg)).toBe("ping\n\nChecklist:\n- a\n- b");
+    expect(resolveHeartbeatPrompt(cfg, { checklist: ["sensors"] })).toBe(
+      "ping\n\nChecklist:\n- sensors",
+    );
+    expect(resolveHeartbeatPrompt(cfg, { checklist: [] })).toBe("ping");
+  });
 });
 
 describe("isHeartbeatEnabledForAgent", () => {
@@ -489,6 +501,13 @@ describe("runHeartbeatOnce", () => {
     if (res.status === "skipped") {
       expect(res.reason).toBe("quiet-hours");
     }
+
+    const watchdog = await runHeartbeatOnce({
+      cfg,
+      reason: "hook:watchdog:heater",
+      deps: { nowMs: () => Date.UTC(2025, 0, 1, 7, 0, 0) },
+    });
+    expect(watchdog.status === "skipped" ? watchdog.reason : undefined).not.toBe("quiet-hours");
   });
 
   it("uses the last non-empty payload for delivery", async () => {
@@ -978,11 +997,17 @@ describe("runHeartbeatOnce", () => {
     reason?: "interval" | "wake";
     queueCronEvent?: boolean;
     replyText?: string;
+    checklist?: string[];
+    goals?: string[];
   }) {
     const tmpDir = await createCaseDir("openclaw-hb");
     const storePath = path.join(tmpDir, "sessions.json");
+    const goalStorePath = path.join(tmpDir, "goals.json");
     const workspaceDir = path.join(tmpDir, "workspace");
     await fs.mkdir(workspaceDir, { recursive: true });
+    for (const objective of params.goals ?? []) {
+      await addGoal(goalStorePath, { agentId: "main", objective });
+    }
 
     if (params.fileState === "empty") {
       await fs.writeFile(
@@ -1005,7 +1030,7 @@ describe("runHeartbeatOnce", () => {
       agents: {
         defaults: {
           workspace: workspaceDir,
-          heartbeat: { every: "5m", target: "whatsapp" },
+          heartbeat: { every: "5m", target: "whatsapp", checklist: params.checklist },
         },
       },
       channels: { whatsapp: { allowFrom: ["*"] } },
@@ -1038,7 +1063,7 @@ describe("runHeartbeatOnce", () => {
     const res = await runHeartbeatOnce({
       cfg,
       reason: params.reason,
-      deps: createHeartbeatDeps(sendWhatsApp),
+      deps: { ...createHeartbeatDeps(sendWhatsApp), goalStorePath },
     });
     return { res, replySpy, sendWhatsApp };
   }
@@ -1055,6 +1080,9 @@ describe("runHeartbeatOnce", () => {
       expectedReplyCalls: number;
       expectCronContext?: boolean;
       replyText?: string;
+      checklist?: string[];
+      goals?: string[];
+      expectBodyContains?: string;
     }> = [
       {
         name: "empty file + interval skips",
@@ -1084,6 +1112,23 @@ describe("runHeartbeatOnce", () => {
         expectCronContext: true,
         replyText: "Relay this cron update now",
       },
+      {
+        name: "empty file + config checklist runs",
+        fileState: "empty",
+        checklist: ["Check sensor readings"],
+        expectedStatus: "ran",
+        expectedSendCalls: 1,
+        expectedReplyCalls: 1,
+      },
+      {
+        name: "empty file + active goal runs",
+        fileState: "empty",
+        goals: ["Keep the greenhouse between 18-24C"],
+        expectedStatus: "ran",
+        expectedSendCalls: 1,
+        expectedReplyCalls: 1,
+        expectBodyContains: "Keep the greenhouse between 18-24C",
+      },
       {
         name: "actionable file runs",
         fileState: "actionable",
@@ -1141,6 +1186,10 @@ describe("runHeartbeatOnce", () => {
           expect(calledCtx.Provider, testCase.name).toBe("cron-event");
           expect(calledCtx.Body, testCase.name).toContain("scheduled reminder has been triggered");
         }
+        if (testCase.expectBodyContains) {
+          const calledCtx = replySpy.mock.calls[0]?.[0] as { Body?: string };
+          expect(calledCtx.Body, testCase.name).toContain(testCase.expectBodyContains);
+        }
       } finally {
         replySpy.mockRestore();
       }
@@ -35,6 +35,7 @@ import {
   updateSessionStore,
 } from "../config/sessions.js";
 import type { AgentDefaultsConfig } from "../config/types.agent-defaults.js";
+import { resolveActiveGoalsPrompt } from "../goals/store.js";
 import { createSubsystemLogger } from "../logging/subsystem.js";
 import { getQueueSize } from "../process/command-queue.js";
 import { CommandLane } from "../process/lanes.js";
@@ -49,7 +50,10 @@ import {
   isExecCompletionEvent,
 } from "./heartbeat-events-filter.js";
 import { emitHeartbeatEvent, resolveIndicatorType } from "./heartbeat-events.js";
-import { resolveHeartbeatReasonKind } from "./heartbeat-reason.js";
+import {
+  isHeartbeatActiveHoursExemptReason,
+  resolveHeartbeatReasonKind,
+} from "./heartbeat-reason.js";
 import { resolveHeartbeatVisibility } from "./heartbeat-visibility.js";
 import {
   type HeartbeatRunResult,
@@ -59,6 +63,11 @@ import {
 } from "./heartbeat-wake.js";
 import type { OutboundSendDeps } from "./outbound/deliver.js";
 import { deliverOutboundPayloads } from "./outbound/deliver.js";
+import {
+  extractNotificationSeverity,
+  isInQuietHours,
+  NOTIFICATION_SEVERITY_HINT,
+} from "./outbound/notification-policy.js";
 import {
   resolveHeartbeatDeliveryTarget,
   resolveHeartbeatSenderContext,
@@ -70,6 +79,7 @@ export type HeartbeatDeps = OutboundSendDeps &
     runtime?: RuntimeEnv;
     getQueueSize?: (lane?: string) => number;
     nowMs?: () => number;
+    goalStorePath?: string;
   };
 
 const log = createSubsystemLogger("gateway/heartbeat");
@@ -243,8 +253,20 @@ export function resolveHeartbeatIntervalMs(
   return ms;
 }
 
+function resolveHeartbeatChecklist(cfg: OpenClawConfig, heartbeat?: HeartbeatConfig): string[] {
+  const raw = heartbeat?.checklist ?? cfg.agents?.defaults?.heartbeat?.checklist ?? [];
+  return raw.map((item) => item.trim()).filter(Boolean);
+}
+
 export function resolveHeartbeatPrompt(cfg: OpenClawConfig, heartbeat?: HeartbeatConfig) {
-  return resolveHeartbeatPromptText(heartbeat?.prompt ?? cfg.agents?.defaults?.heartbeat?.prompt);
+  const prompt = resolveHeartbeatPromptText(
+    heartbeat?.prompt ?? cfg.agents?.defaults?.heartbeat?.prompt,
+  );
+  const checklist = resolveHeartbeatChecklist(cfg, heartbeat);
+  if (checklist.length === 0) {
+    return prompt;
+  }
+  return `${prompt}\n\nChecklist:\n${checklist.map((item) => `- ${item}`).join("\n")}`;
 }
 
 function resolveHeartbeatAckMaxChars(cfg: OpenClawConfig, heartbeat?: HeartbeatConfig) {
@@ -488,6 +510,8 @@ type HeartbeatPreflight = HeartbeatReasonFlags & {
   pendingEventEntries: ReturnType<typeof peekSystemEventEntries>;
   hasTaggedCronEvents: boolean;
   shouldInspectPendingEvents: boolean;
+  /** Active standing goals rendered for the prompt; empty when none. */
+  goalsPrompt: string;
   skipReason?: HeartbeatSkipReason;
 };
 
@@ -506,6 +530,7 @@ async function resolveHeartbeatPreflight(params: {
   heartbeat?: HeartbeatConfig;
   forcedSessionKey?: string;
   reason?: string;
+  goalStorePath?: string;
 }): Promise<HeartbeatPreflight> {
   const reasonFlags = resolveHeartbeatReasonFlags(params.reason);
   const session = resolveHeartbeatSession(
@@ -525,15 +550,25 @@ async function resolveHeartbeatPreflight(params: {
     reasonFlags.isCronEventReason ||
     reasonFlags.isWakeReason ||
     hasTaggedCronEvents;
+  const goalsPrompt = await resolveActiveGoalsPrompt(params.agentId, params.goalStorePath).catch(
+    () => "",
+  );
   const basePreflight = {
     ...reasonFlags,
     session,
     pendingEventEntries,
     hasTaggedCronEvents,
     shouldInspectPendingEvents,
+    goalsPrompt,
   } satisfies Omit<HeartbeatPreflight, "skipReason">;
 
-  if (shouldBypassFileGates) {
+  // A configured checklist or an active goal is enough to act on, so HEARTBEAT.md gating does
+  // not apply.
+  if (
+    shouldBypassFileGates ||
+    goalsPrompt ||
+    resolveHeartbeatChecklist(params.cfg, params.heartbeat).length > 0
+  ) {
     return basePreflight;
   }
 
@@ -582,7 +617,10 @@ export async function runHeartbeatOnce(opts: {
   }
 
   const startedAt = opts.deps?.nowMs?.() ?? Date.now();
-  if (!isWithinActiveHours(cfg, heartbeat, startedAt)) {
+  if (
+    !isHeartbeatActiveHoursExemptReason(opts.reason) &&
+    !isWithinActiveHours(cfg, heartbeat, startedAt)
+  ) {
     return { status: "skipped", reason: "quiet-hours" };
   }
 
@@ -598,6 +636,7 @@ export async function runHeartbeatOnce(opts: {
     heartbeat,
     forcedSessionKey: opts.sessionKey,
     reason: opts.reason,
+    goalStorePath: opts.deps?.goalStorePath,
   });
   if (preflight.skipReason) {
     emitHeartbeatEvent({
@@ -658,7 +697,13 @@ export async function runHeartbeatOnce(opts: {
     ? EXEC_EVENT_PROMPT
     : hasCronEvents
       ? buildCronEventPrompt(cronEvents)
-      : resolveHeartbeatPrompt(cfg, heartbeat);
+      : [
+          resolveHeartbeatPrompt(cfg, heartbeat),
+          preflight.goalsPrompt,
+          cfg.messages?.notifications ? NOTIFICATION_SEVERITY_HINT : "",
+        ]
+          .filter(Boolean)
+          .join("\n\n");
   const ctx = {
     Body: appendCronStyleCurrentTimeLine(prompt, cfg, startedAt),
     From: sender,
@@ -685,6 +730,11 @@ export async function runHeartbeatOnce(opts: {
     if (!canAttemptHeartbeatOk || delivery.channel === "none" || !delivery.to) {
       return false;
     }
+    // Acks carry no news, so quiet hours drop them instead of adding them to the digest.
+    const ackTarget = { channel: delivery.channel, to: delivery.to, accountId: delivery.accountId };
+    if (isInQuietHours(cfg, ackTarget)) {
+      return false;
+    }
     const heartbeatPlugin = getChannelPlugin(delivery.channel);
     if (heartbeatPlugin?.heartbeat?.checkReady) {
       const readiness = await heartbeatPlugin.heartbeat.checkReady({
@@ -767,6 +817,8 @@ export async function runHeartbeatOnce(opts: {
       normalized.text = execFallbackText;
       normalized.shouldSkip = false;
     }
+    const tagged = extractNotificationSeverity(normalized.text);
+    normalized.text = tagged.text;
     const shouldSkipMain = normalized.shouldSkip && !normalized.hasMedia && !hasExecCompletion;
     if (shouldSkipMain && reasoningPayloads.length === 0) {
       await restoreHeartbeatUpdatedAt({
@@ -909,6 +961,7 @@ export async function runHeartbeatOnce(opts: {
               },
             ]),
       ],
+      notification: { severity: tagged.severity, kind: hasCronEvents ? "reminder" : "heartbeat" },
       deps: opts.deps,
     });
 
@@ -30,6 +30,11 @@ import type { sendMessageWhatsApp } from "../../web/outbound.js";
 import { throwIfAborted } from "./abort.js";
 import { ackDelivery, enqueueDelivery, failDelivery } from "./delivery-queue.js";
 import type { OutboundIdentity } from "./identity.js";
+import {
+  evaluateNotification,
+  holdNotification,
+  type OutboundNotification,
+} from "./notification-policy.js";
 import type { NormalizedOutboundPayload } from "./payloads.js";
 import { normalizeReplyPayloadsForDelivery } from "./payloads.js";
 import type { OutboundChannel } from "./targets.js";
@@ -221,6 +226,8 @@ type DeliverOutboundPayloadsCoreParams = {
 type DeliverOutboundPayloadsParams = DeliverOutboundPayloadsCoreParams & {
   /** @internal Skip write-ahead queue (used by crash-recovery to avoid re-enqueueing). */
   skipQueue?: boolean;
+  /** Proactive message (not a reply): subject to `messages.notifications` quiet hours. */
+  notification?: OutboundNotification;
 };
 
 export async function deliverOutboundPayloads(
@@ -228,6 +235,21 @@ export async function deliverOutboundPayloads(
 ): Promise<OutboundDeliveryResult[]> {
   const { channel, to, payloads } = params;
 
+  if (params.notification) {
+    const target = { channel, to, accountId: params.accountId, threadId: params.threadId };
+    const decision = evaluateNotification({
+      cfg: params.cfg,
+      target,
+      severity: params.notification.severity,
+    });
+    if (decision === "hold") {
+      await holdNotification({ target, payloads, notification: params.notification });
+    }
+    if (decision !== "send") {
+      return [];
+    }
+  }
+
   // Write-ahead delivery queue: persist before sending, remove after success.
   const queueId = params.skipQueue
     ? null
@@ -525,7 +547,7 @@ async function deliverOutboundPayloadsCore(
         replyToId: effectivePayload.replyToId ?? params.replyToId ?? undefined,
         threadId: params.threadId ?? undefined,
       };
-      if (handler.sendPayload && effectivePayload.channelData) {
+      if (handler.sendPayload && (effectivePayload.channelData || effectivePayload.interactive)) {
         const delivery = await handler.sendPayload(effectivePayload, sendOverrides);
         results.push(delivery);
         emitMessageSent({
@@ -0,0 +1,122 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import type { OpenClawConfig } from "../../config/config.js";
+import {
+  evaluateNotification,
+  extractNotificationSeverity,
+  flushHeldNotifications,
+  holdNotification,
+} from "./notification-policy.js";
+
+const NIGHT = Date.parse("2026-03-01T03:00:00Z");
+const MORNING = Date.parse("2026-03-01T08:30:00Z");
+
+const cfg = {
+  messages: {
+    notifications: {
+      quietHours: { start: "22:00", end: "07:00", timezone: "UTC" },
+      byChannel: { slack: { minSeverity: "warning" } },
+      recipients: [
+        { channel: "telegram", to: "42", quietHoursBypass: "warning" },
+        { channel: "whatsapp", to: "+1555", held: "drop" },
+      ],
+    },
+  },
+} as OpenClawConfig;
+
+describe("notification policy", () => {
+  it("holds non-critical messages during quiet hours and lets critical ones through", () => {
+    const target = { channel: "telegram", to: "7" };
+    expect(evaluateNotification({ cfg, target, nowMs: NIGHT })).toBe("hold");
+    expect(evaluateNotification({ cfg, target, severity: "warning", nowMs: NIGHT })).toBe("hold");
+    expect(evaluateNotification({ cfg, target, severity: "critical", nowMs: NIGHT })).toBe("send");
+    expect(evaluateNotification({ cfg, target, nowMs: MORNING })).toBe("send");
+  });
+
+  it("applies channel and recipient overrides", () => {
+    const slack = { channel: "slack", to: "C0OPS" };
+    expect(evaluateNotification({ cfg, target: slack, nowMs: MORNING })).toBe("drop");
+    expect(evaluateNotification({ cfg, target: slack, severity: "warning", nowMs: MORNING })).toBe(
+      "send",
+    );
+    const phone = { channel: "telegram", to: "42" };
+    expect(evaluateNotification({ cfg, target: phone, severity: "warning", nowMs: NIGHT })).toBe(
+      "send",
+    );
+    const quietDrop = { channel: "whatsapp", to: "+1555" };
+    expect(evaluateNotification({ cfg, target: quietDrop, nowMs: NIGHT })).toBe("drop");
+  });
+
+  it("sends everything when no policy is configured", () => {
+    expect(
+      evaluateNotification({ cfg: {}, target: { channel: "telegram", to: "7" }, nowMs: NIGHT }),
+    ).toBe("send");
+  });
+
+  it("reads severity tags from agent replies", () => {
+    expect(extractNotificationSeverity("[critical] Smoke detected in the garage")).toEqual({
+      severity: "critical",
+      text: "Smoke detected in the garage",
+    });
+    expect(extractNotificationSeverity("Soil is dry [critical]")).toEqual({
+      severity: "info",
+      text: "Soil is dry [critical]",
+    });
+  });
+});
+
+describe("quiet-hours digest", () => {
+  let tmpDir: string;
+  let filePath: string;
+
+  beforeEach(async () => {
+    tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-notify-"));
+    filePath = path.join(tmpDir, "held.json");
+  });
+
+  afterEach(async () => {
+    await fs.rm(tmpDir, { recursive: true, force: true });
+  });
+
+  it("batches held messages into one digest once quiet hours end", async () => {
+    const target = { channel: "telegram", to: "7" };
+    await holdNotification({
+      target,
+      payloads: [{ text: "Bed 2 soil moisture is low" }],
+      notification: { kind: "heartbeat" },
+      nowMs: Date.parse("2026-03-01T02:10:00Z"),
+      filePath,
+    });
+    await holdNotification({
+      target,
+      payloads: [{ text: "Nightly backup finished" }],
+      notification: { kind: "cron" },
+      nowMs: Date.parse("2026-03-01T04:05:00Z"),
+      filePath,
+    });
+
+    const deliver = vi.fn(async () => []);
+    expect(await flushHeldNotifications({ cfg, deliver, nowMs: NIGHT, filePath })).toBe(0);
+    expect(deliver).not.toHaveBeenCalled();
+
+    expect(await flushHeldNotifications({ cfg, deliver, nowMs: MORNING, filePath })).toBe(1);
+    expect(deliver).toHaveBeenCalledWith(
+      expect.objectContaining({
+        channel: "telegram",
+        to: "7",
+        payloads: [
+          {
+            text: [
+              "🔕 Held during quiet hours (2):",
+              "• 02:10 [heartbeat] Bed 2 soil moisture is low",
+              "• 04:05 [cron] Nightly backup finished",
+            ].join("\n"),
+          },
+        ],
+      }),
+    );
+    expect(await flushHeldNotifications({ cfg, deliver, nowMs: MORNING, filePath })).toBe(0);
+  });
+});
@@ -0,0 +1,317 @@
+import { randomUUID } from "node:crypto";
+import path from "node:path";
+import type { ReplyPayload } from "../../auto-reply/types.js";
+import type { OpenClawConfig } from "../../config/config.js";
+import { resolveStateDir } from "../../config/paths.js";
+import type {
+  NotificationPolicyConfig,
+  NotificationSeverity,
+} from "../../config/types.messages.js";
+import {
+  isDeliverableMessageChannel,
+  normalizeMessageChannel,
+} from "../../utils/message-channel.js";
+import { isWithinTimeWindow, resolveActiveHoursTimezone } from "../heartbeat-active-hours.js";
+import { createAsyncLock, readJsonFile, writeJsonAtomic } from "../json-files.js";
+import type { deliverOutboundPayloads } from "./deliver.js";
+
+/** Marks a delivery as proactive (not a reply), so `messages.notifications` applies to it. */
+export type OutboundNotification = {
+  severity?: NotificationSeverity;
+  /** Short source label shown in digests (e.g. "heartbeat", "cron", "alert"). */
+  kind?: string;
+};
+
+export type NotificationTarget = {
+  channel: string;
+  to: string;
+  accountId?: string;
+  threadId?: string | number | null;
+};
+
+export type NotificationDecision = "send" | "hold" | "drop";
+
+type ResolvedNotificationPolicy = Pick<NotificationPolicyConfig, "quietHours"> & {
+  minSeverity: NotificationSeverity;
+  quietHoursBypass: NotificationSeverity;
+  held: "digest" | "drop";
+};
+
+type HeldNotification = {
+  id: string;
+  channel: string;
+  to: string;
+  accountId?: string;
+  threadId?: string | number;
+  severity: NotificationSeverity;
+  kind?: string;
+  text: string;
+  heldAt: number;
+};
+
+type HeldNotificationsFile = { version: 1; entries: HeldNotification[] };
+
+const SEVERITY_RANK: Record<NotificationSeverity, number> = { info: 0, warning: 1, critical: 2 };
+const MAX_HELD_PER_RECIPIENT = 50;
+const MAX_DIGEST_LINE_CHARS = 300;
+const DIGEST_CHECK_INTERVAL_MS = 60_000;
+
+const SEVERITY_TAG_RE = /^\s*\[(critical|warning)\]\s*/i;
+
+/** Appended to proactive prompts when a notification policy is configured. */
+export const NOTIFICATION_SEVERITY_HINT =
+  "Quiet hours may hold this message until morning. Start your reply with [critical] only if it cannot wait (safety, security, or damage), or [warning] if it is urgent but not critical.";
+
+const withHeldLock = createAsyncLock();
+
+export function resolveHeldNotificationsPath(stateDir = resolveStateDir()): string {
+  return path.join(stateDir, "notifications", "held.json");
+}
+
+function pickPolicy(entry: NotificationPolicyConfig | undefined): NotificationPolicyConfig {
+  if (!entry) {
+    return {};
+  }
+  const { quietHours, minSeverity, quietHoursBypass, held } = entry;
+  return {
+    ...(quietHours ? { quietHours } : {}),
+    ...(minSeverity ? { minSeverity } : {}),
+    ...(quietHoursBypass ? { quietHoursBypass } : {}),
+    ...(held ? { held } : {}),
+  };
+}
+
+/** Defaults, then `byChannel`, then the matching recipient entry. Null when no policy is set. */
+export function resolveNotificationPolicy(
+  cfg: OpenClawConfig,
+  target: NotificationTarget,
+): ResolvedNotificationPolicy | null {
+  const config = cfg.messages?.notifications;
+  if (!config) {
+    return null;
+  }
+  const channel = normalizeMessageChannel(target.channel);
+  const byChannel = Object.entries(config.byChannel ?? {}).find(
+    ([key]) => normalizeMessageChannel(key) === channel,
+  )?.[1];
+  const recipient = config.recipients?.find(
+    (entry) =>
+      normalizeMessageChannel(entry.channel) === channel &&
+      entry.to.trim() === target.to.trim() &&
+      (!entry.accountId || entry.accountId === target.accountId),
+  );
+  const merged = { ...pickPolicy(config), ...pickPolicy(byChannel), ...pickPolicy(recipient) };
+  return {
+    ...merged,
+    minSeverity: merged.minSeverity ?? "info",
+    quietHoursBypass: merged.quietHoursBypass ?? "critical",
+    held: merged.held ?? "digest",
+  };
+}
+
+export function isInQuietHours(
+  cfg: OpenClawConfig,
+  target: NotificationTarget,
+  nowMs = Date.now(),
+): boolean {
+  const policy = resolveNotificationPolicy(cfg, target);
+  return Boolean(policy?.quietHours && isWithinTimeWindow(cfg, policy.quietHours, nowMs));
+}
+
+export function evaluateNotification(params: {
+  cfg: OpenClawConfig;
+  target: NotificationTarget;
+  severity?: NotificationSeverity;
+  nowMs?: number;
+}): NotificationDecision {
+  const policy = resolveNotificationPolicy(params.cfg, params.target);
+  if (!policy) {
+    return "send";
+  }
+  const rank = SEVERITY_RANK[params.severity ?? "info"];
+  if (rank < SEVERITY_RANK[policy.minSeverity]) {
+    return "drop";
+  }
+  if (
+    rank < SEVERITY_RANK[policy.quietHoursBypass] &&
+    isWithinTimeWindow(params.cfg, policy.quietHours, params.nowMs)
+  ) {
+    return policy.held === "drop" ? "drop" : "hold";
+  }
+  return "send";
+}
+
+/** Reads and strips a leading `[critical]` / `[warning]` tag from an agent's proactive reply. */
+export function extractNotificationSeverity(text: string): {
+  severity: NotificationSeverity;
+  text: string;
+} {
+  const match = SEVERITY_TAG_RE.exec(text);
+  if (!match) {
+    return { severity: "info", text };
+  }
+  return {
+    severity: match[1].toLowerCase() as NotificationSeverity,
+    text: text.slice(match[0].length),
+  };
+}
+
+async function readHeld(filePath: string): Promise<HeldNotificationsFile> {
+  const raw = await readJsonFile<Partial<HeldNotificationsFile>>(filePath);
+  return { version: 1, entries: Array.isArray(raw?.entries) ? raw.entries : [] };
+}
+
+function recipientKey(entry: NotificationTarget): string {
+  return [entry.channel, entry.to, entry.accountId ?? "", entry.threadId ?? ""].join("\u0000");
+}
+
+/** Keeps a message for the next digest to this recipient. */
+export async function holdNotification(params: {
+  target: NotificationTarget;
+  payloads: ReplyPayload[];
+  notification: OutboundNotification;
+  nowMs?: number;
+  filePath?: string;
+}): Promise<void> {
+  const filePath = params.filePath ?? resolveHeldNotificationsPath();
+  const text =
+    params.payloads
+      .map((payload) => payload.text?.trim())
+      .filter(Boolean)
+      .join("\n") || "(media message)";
+  const { channel, to, accountId, threadId } = params.target;
+  const entry: HeldNotification = {
+    id: randomUUID(),
+    channel,
+    to,
+    ...(accountId ? { accountId } : {}),
+    ...(threadId != null ? { threadId } : {}),
+    severity: params.notification.severity ?? "info",
+    ...(params.notification.kind ? { kind: params.notification.kind } : {}),
+    text,
+    heldAt: params.nowMs ?? Date.now(),
+  };
+  await withHeldLock(async () => {
+    const held = await readHeld(filePath);
+    const key = recipientKey(entry);
+    const sameRecipient = held.entries.filter((candidate) => recipientKey(candidate) === key);
+    const overflow = new Set(
+      sameRecipient.slice(0, sameRecipient.length + 1 - MAX_HELD_PER_RECIPIENT).map((e) => e.id),
+    );
+    held.entries = [...held.entries.filter((candidate) => !overflow.has(candidate.id)), entry];
+    await writeJsonAtomic(filePath, held);
+  });
+}
+
+function formatDigestTime(cfg: OpenClawConfig, timezone: string | undefined, atMs: number) {
+  return new Intl.DateTimeFormat("en-US", {
+    timeZone: resolveActiveHoursTimezone(cfg, timezone),
+    hour: "2-digit",
+    minute: "2-digit",
+    hourCycle: "h23",
+  }).format(new Date(atMs));
+}
+
+export function formatNotificationDigest(
+  cfg: OpenClawConfig,
+  entries: HeldNotification[],
+  timezone?: string,
+): string {
+  const lines = entries.map((entry) => {
+    const text = entry.text.replace(/\s+/g, " ");
+    const clipped =
+      text.length > MAX_DIGEST_LINE_CHARS ? `${text.slice(0, MAX_DIGEST_LINE_CHARS)}…` : text;
+    const kind = entry.kind ? ` [${entry.kind}]` : "";
+    return `• ${formatDigestTime(cfg, timezone, entry.heldAt)}${kind} ${clipped}`;
+  });
+  return [`🔕 Held during quiet hours (${entries.length}):`, ...lines].join("\n");
+}
+
+/**
+ * Sends one digest per recipient whose quiet hours are over and forgets the delivered entries.
+ * A failed delivery keeps its entries for the next check. Returns the number of digests sent.
+ */
+export async function flushHeldNotifications(params: {
+  cfg: OpenClawConfig;
+  deliver?: typeof deliverOutboundPayloads;
+  nowMs?: number;
+  filePath?: string;
+}): Promise<number> {
+  const filePath = params.filePath ?? resolveHeldNotificationsPath();
+  const held = await readHeld(filePath);
+  if (held.entries.length === 0) {
+    return 0;
+  }
+  const groups = new Map<string, HeldNotification[]>();
+  for (const entry of held.entries) {
+    const key = recipientKey(entry);
+    groups.set(key, [...(groups.get(key) ?? []), entry]);
+  }
+  const deliver = params.deliver ?? (await import("./deliver.js")).deliverOutboundPayloads;
+  const delivered = new Set<string>();
+  let sent = 0;
+  for (const entries of groups.values()) {
+    const [first] = entries;
+    const channel = normalizeMessageChannel(first.channel);
+    if (!channel || !isDeliverableMessageChannel(channel)) {
+      continue;
+    }
+    if (isInQuietHours(params.cfg, first, params.nowMs)) {
+      continue;
+    }
+    const timezone = resolveNotificationPolicy(params.cfg, first)?.quietHours?.timezone;
+    try {
+      await deliver({
+        cfg: params.cfg,
+        channel,
+        to: first.to,
+        accountId: first.accountId,
+        threadId: first.threadId,
+        payloads: [{ text: formatNotificationDigest(params.cfg, entries, timezone) }],
+      });
+      for (const entry of entries) {
+        delivered.add(entry.id);
+      }
+      sent += 1;
+    } catch {
+      // Kept for the next check.
+    }
+  }
+  if (delivered.size > 0) {
+    await withHeldLock(async () => {
+      const current = await readHeld(filePath);
+      current.entries = current.entries.filter((entry) => !delivered.has(entry.id));
+      await writeJsonAtomic(filePath, current);
+    });
+  }
+  return sent;
+}
+
+/** Gateway loop that sends quiet-hours digests once each recipient's window ends. */
+export function scheduleNotificationDigest(params: {
+  cfg: OpenClawConfig;
+  log: { info: (msg: string) => void; warn: (msg: string) => void };
+  filePath?: string;
+}): () => void {
+  let running = false;
+  const tick = async () => {
+    if (running) {
+      return;
+    }
+    running = true;
+    try {
+      const sent = await flushHeldNotifications({ cfg: params.cfg, filePath: params.filePath });
+      if (sent > 0) {
+        params.log.info(`notifications: sent ${sent} quiet-hours digest(s)`);
+      }
+    } catch (err) {
+      params.log.warn(`notifications: digest delivery failed: ${String(err)}`);
+    } finally {
+      running = false;
+    }
+  };
+  void tick();
+  const timer = setInterval(() => void tick(), DIGEST_CHECK_INTERVAL_MS);
+  timer.unref?.();
+  return () => clearInterval(timer);
+}
@@ -0,0 +1,127 @@
+import { describe, expect, it } from "vitest";
+import {
+  buildWatchdogPrompt,
+  createWatchdogEvaluator,
+  readWatchSource,
+  resolveWatches,
+} from "./sensor-watchdog.js";
+
+describe("sensor watchdog", () => {
+  it("fires once per breach and re-arms past the hysteresis band", () => {
+    let now = 0;
+    const evaluate = createWatchdogEvaluator(() => now);
+    const [watch] = resolveWatches([
+      {
+        name: "cpu",
+        source: { kind: "hwmon", chip: "cpu_thermal", input: "temp1_input" },
+        above: 70,
+        hysteresis: 5,
+        cooldownSeconds: 0,
+      },
+    ]);
+    expect(watch.scale).toBe(0.001);
+
+    const fired = [68, 71, 72, 67, 71, 64, 71].map((value) => {
+      now += 30_000;
+      return evaluate(watch, value)?.condition ?? null;
+    });
+    expect(fired).toEqual([null, "above 70", null, null, null, null, "above 70"]);
+  });
+
+  it("respects the cooldown between escalations", () => {
+    let now = 0;
+    const evaluate = createWatchdogEvaluator(() => now);
+    const [watch] = resolveWatches([
+      { name: "battery", source: { kind: "adc", channel: "in_voltage0" }, below: 11.5 },
+    ]);
+
+    expect(evaluate(watch, 11.2)?.condition).toBe("below 11.5");
+    now += 60_000;
+    expect(evaluate(watch, 12)).toBeNull();
+    expect(evaluate(watch, 11.1)).toBeNull();
+    now += 300_000;
+    expect(evaluate(watch, 12)).toBeNull();
+    expect(evaluate(watch, 11)?.value).toBe(11);
+  });
+
+  it("escalates a re-breach held back by the cooldown once the cooldown ends", () => {
+    let now = 0;
+    const evaluate = createWatchdogEvaluator(() => now);
+    const [watch] = resolveWatches([
+      {
+        name: "heater",
+        source: { kind: "file", path: "/run/heater-temp" },
+        above: 80,
+        hysteresis: 2,
+        cooldownSeconds: 300,
+      },
+    ]);
+
+    // Breach, brief dip below the band, then a sustained 90 °C reading.
+    const fired = [90, 75, 90, 90, 90, 90, 90].map((value, index) => {
+      now = index * 60_000;
+      return evaluate(watch, value)?.condition ?? null;
+    });
+    expect(fired).toEqual(["above 80", null, null, null, null, "above 80", null]);
+  });
+
+  it("reads hwmon by chip name, IIO ADC with scale, and I2C registers", async () => {
+    const files: Record<string, string> = {
+      "/sys/class/hwmon/hwmon0/name": "nvme\n",
+      "/sys/class/hwmon/hwmon1/name": "cpu_thermal\n",
+      "/sys/class/hwmon/hwmon1/temp1_input": "71250\n",
+      "/sys/bus/iio/devices/iio:device0/in_voltage0_raw": "2048\n",
+      "/sys/bus/iio/devices/iio:device0/in_voltage0_scale": "0.5\n",
+    };
+    const i2cCalls: string[][] = [];
+    const deps = {
+      readFile: async (filePath: string) => {
+        if (!(filePath in files)) {
+          throw new Error(`ENOENT: ${filePath}`);
+        }
+        return files[filePath];
+      },
+      readDir: async () => ["hwmon0", "hwmon1"],
+      i2cget: async (args: string[]) => {
+        i2cCalls.push(args);
+        return "0x1a\n";
+      },
+    };
+
+    await expect(
+      readWatchSource({ kind: "hwmon", chip: "cpu_thermal", input: "temp1_input" }, deps),
+    ).resolves.toBe(71250);
+    await expect(
+      readWatchSource({ kind: "adc", channel: "in_voltage0" }, deps),
+    ).resolves.toBe(1024);
+    await expect(
+      readWatchSource({ kind: "i2c", bus: 1, address: "0x48", register: "0x00" }, deps),
+    ).resolves.toBe(26);
+    expect(i2cCalls).toEqual([["-y", "1", "0x48", "0x00", "b"]]);
+    await expect(
+      readWatchSource({ kind: "hwmon", chip: "acpitz", input: "t" }, deps),
+    ).rejects.toThrow('hwmon chip "acpitz" not found');
+  });
+
+  it("builds the agent prompt with the reading and instructions", () => {
+    const [watch] = resolveWatches([
+      {
+        name: "greenhouse",
+        source: { kind: "file", path: "/run/sensors/greenhouse" },
+        unit: "°C",
+        above: 35,
+        hysteresis: 2,
+        prompt: "Turn off the heater relay (GPIO 17), then tell me.",
+      },
+    ]);
+    expect(buildWatchdogPrompt({ watch, value: 36.4, condition: "above 35" })).toBe(
+      [
+        '[Watchdog "greenhouse"]',
+        "Reading: 36.4 °C from /run/sensors/greenhouse",
+        "Condition: above 35 °C (clears 2 °C back inside the threshold)",
+        "",
+        "Turn off the heater relay (GPIO 17), then tell me.",
+      ].join("\n"),
+    );
+  });
+});
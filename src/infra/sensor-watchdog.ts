@@ -0,0 +1,305 @@
+/**
+ * Sensor threshold watchdog: polls configured readings and wakes the agent when one crosses a
+ * threshold, so it can act (switch off a relay) and/or alert the user.
+ *
+ * Breaches are edge-triggered with hysteresis: an `above` watch fires once when the value rises
+ * past the threshold and re-arms only after it drops below `above - hysteresis` (mirrored for
+ * `below`), so a reading hovering at the limit does not wake the agent on every poll.
+ */
+
+import { execFile } from "node:child_process";
+import fs from "node:fs/promises";
+import path from "node:path";
+import { promisify } from "node:util";
+import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { resolveAgentMainSessionKey } from "../config/sessions.js";
+import type { WatchdogSourceConfig, WatchdogWatchConfig } from "../config/types.watchdog.js";
+import { requestHeartbeatNow } from "./heartbeat-wake.js";
+import { enqueueSystemEvent } from "./system-events.js";
+
+const execFileAsync = promisify(execFile);
+
+const DEFAULT_INTERVAL_SECONDS = 30;
+const DEFAULT_COOLDOWN_SECONDS = 300;
+const DEFAULT_IIO_DEVICE = "iio:device0";
+const HWMON_ROOT = "/sys/class/hwmon";
+const IIO_ROOT = "/sys/bus/iio/devices";
+
+export type ResolvedWatch = {
+  name: string;
+  source: WatchdogSourceConfig;
+  scale: number;
+  offset: number;
+  unit?: string;
+  above?: number;
+  below?: number;
+  hysteresis: number;
+  cooldownMs: number;
+  prompt?: string;
+};
+
+export type WatchdogBreach = {
+  watch: ResolvedWatch;
+  value: number;
+  /** Human-readable condition, e.g. `above 70`. */
+  condition: string;
+};
+
+type WatchState = {
+  /** Direction of the active breach; cleared once the value is back inside the hysteresis band. */
+  breached?: "above" | "below";
+  lastFiredAtMs?: number;
+};
+
+export type WatchdogReadDeps = {
+  readFile?: (filePath: string) => Promise<string>;
+  readDir?: (dirPath: string) => Promise<string[]>;
+  i2cget?: (args: string[]) => Promise<string>;
+};
+
+/** hwmon sysfs files are integers in milli-units (micro-watts for power). */
+function defaultHwmonScale(input: string): number {
+  const base = path.basename(input);
+  if (base.startsWith("fan") || base.startsWith("pwm")) {
+    return 1;
+  }
+  if (base.startsWith("power") || base.startsWith("energy")) {
+    return 1e-6;
+  }
+  return 0.001;
+}
+
+function defaultScale(source: WatchdogSourceConfig): number {
+  if (source.kind !== "hwmon") {
+    return 1;
+  }
+  return defaultHwmonScale(source.path?.trim() || source.input?.trim() || "");
+}
+
+export function resolveWatches(watches: WatchdogWatchConfig[] | undefined): ResolvedWatch[] {
+  return (watches ?? [])
+    .filter(
+      (watch) => watch.name?.trim() && (watch.above !== undefined || watch.below !== undefined),
+    )
+    .map((watch) => ({
+      name: watch.name.trim(),
+      source: watch.source,
+      scale: watch.scale ?? defaultScale(watch.source),
+      offset: watch.offset ?? 0,
+      unit: watch.unit?.trim() || undefined,
+      above: watch.above,
+      below: watch.below,
+      hysteresis: Math.max(0, watch.hysteresis ?? 0),
+      cooldownMs: Math.max(0, watch.cooldownSeconds ?? DEFAULT_COOLDOWN_SECONDS) * 1000,
+      prompt: watch.prompt?.trim() || undefined,
+    }));
+}
+
+function parseReading(raw: string, label: string): number {
+  const text = raw.trim();
+  const value = /^0x[0-9a-f]+$/i.test(text) ? Number.parseInt(text, 16) : Number(text);
+  if (!text || !Number.isFinite(value)) {
+    throw new Error(`${label}: not a number (${JSON.stringify(text.slice(0, 40))})`);
+  }
+  return value;
+}
+
+async function resolveHwmonPath(
+  source: Extract<WatchdogSourceConfig, { kind: "hwmon" }>,
+  deps: Required<WatchdogReadDeps>,
+): Promise<string> {
+  const explicit = source.path?.trim();
+  if (explicit) {
+    return explicit;
+  }
+  const chip = source.chip?.trim() ?? "";
+  for (const entry of await deps.readDir(HWMON_ROOT)) {
+    const dir = path.join(HWMON_ROOT, entry);
+    const name = await deps.readFile(path.join(dir, "name")).catch(() => "");
+    if (name.trim() === chip) {
+      return path.join(dir, source.input?.trim() ?? "");
+    }
+  }
+  throw new Error(`hwmon chip "${chip}" not found under ${HWMON_ROOT}`);
+}
+
+/** Raw reading for a source, before `scale`/`offset`. IIO ADC values include `_scale`. */
+export async function readWatchSource(
+  source: WatchdogSourceConfig,
+  deps: WatchdogReadDeps = {},
+): Promise<number> {
+  const resolved: Required<WatchdogReadDeps> = {
+    readFile: deps.readFile ?? ((filePath) => fs.readFile(filePath, "utf-8")),
+    readDir: deps.readDir ?? ((dirPath) => fs.readdir(dirPath)),
+    i2cget:
+      deps.i2cget ??
+      (async (args) => (await execFileAsync("i2cget", args, { timeout: 5_000 })).stdout),
+  };
+  switch (source.kind) {
+    case "hwmon": {
+      const filePath = await resolveHwmonPath(source, resolved);
+      return parseReading(await resolved.readFile(filePath), filePath);
+    }
+    case "adc": {
+      const device = source.device?.trim() || DEFAULT_IIO_DEVICE;
+      const base = path.join(IIO_ROOT, device, source.channel);
+      const raw = parseReading(await resolved.readFile(`${base}_raw`), `${base}_raw`);
+      const scale = await resolved
+        .readFile(`${base}_scale`)
+        .then((text) => parseReading(text, `${base}_scale`))
+        .catch(() => 1);
+      return raw * scale;
+    }
+    case "i2c": {
+      const mode = source.mode === "word" ? "w" : "b";
+      const args = ["-y", String(source.bus), source.address, source.register, mode];
+      return parseReading(await resolved.i2cget(args), `i2c ${source.bus}:${source.address}`);
+    }
+    case "file":
+      return parseReading(await resolved.readFile(source.path), source.path);
+  }
+}
+
+export function createWatchdogEvaluator(nowMs: () => number = Date.now) {
+  const states = new Map<string, WatchState>();
+
+  /** Feeds one scaled reading; returns a breach when the watch should escalate. */
+  return (watch: ResolvedWatch, value: number): WatchdogBreach | null => {
+    const state = states.get(watch.name) ?? {};
+    states.set(watch.name, state);
+    if (state.breached === "above" && watch.above !== undefined) {
+      if (value < watch.above - watch.hysteresis) {
+        state.breached = undefined;
+      }
+    } else if (state.breached === "below" && watch.below !== undefined) {
+      if (value > watch.below + watch.hysteresis) {
+        state.breached = undefined;
+      }
+    }
+    if (state.breached) {
+      return null;
+    }
+    const direction =
+      watch.above !== undefined && value > watch.above
+        ? "above"
+        : watch.below !== undefined && value < watch.below
+          ? "below"
+          : undefined;
+    if (!direction) {
+      return null;
+    }
+    // A breach inside the cooldown stays un-fired (not latched), so a reading that is still out
+    // of range when the cooldown ends escalates then instead of being ignored until it clears.
+    const now = nowMs();
+    if (state.lastFiredAtMs !== undefined && now - state.lastFiredAtMs < watch.cooldownMs) {
+      return null;
+    }
+    state.breached = direction;
+    state.lastFiredAtMs = now;
+    return { watch, value, condition: `${direction} ${watch[direction]}` };
+  };
+}
+
+function formatValue(value: number, unit?: string): string {
+  const rounded = Number.isInteger(value) ? String(value) : String(Number(value.toFixed(3)));
+  return unit ? `${rounded} ${unit}` : rounded;
+}
+
+function describeSource(source: WatchdogSourceConfig): string {
+  switch (source.kind) {
+    case "hwmon":
+      return source.path?.trim() || `hwmon ${source.chip}/${source.input}`;
+    case "adc":
+      return `adc ${source.device?.trim() || DEFAULT_IIO_DEVICE}/${source.channel}`;
+    case "i2c":
+      return `i2c bus ${source.bus} ${source.address} reg ${source.register}`;
+    case "file":
+      return source.path;
+  }
+}
+
+export function buildWatchdogPrompt(breach: WatchdogBreach): string {
+  const { watch } = breach;
+  const unit = watch.unit ? ` ${watch.unit}` : "";
+  const band =
+    watch.hysteresis > 0 ? ` (clears ${watch.hysteresis}${unit} back inside the threshold)` : "";
+  return [
+    `[Watchdog "${watch.name}"]`,
+    `Reading: ${formatValue(breach.value, watch.unit)} from ${describeSource(watch.source)}`,
+    `Condition: ${breach.condition}${unit}${band}`,
+    "",
+    watch.prompt ??
+      "A watched sensor crossed its threshold. Decide whether to act (for example switch off the device it protects) and whether to alert the user.",
+  ].join("\n");
+}
+
+/** Starts polling `watchdog.watches` on the Gateway; returns a stop function. */
+export function startSensorWatchdog(params: {
+  cfg: OpenClawConfig;
+  log: { info: (msg: string) => void; warn: (msg: string) => void };
+  deps?: WatchdogReadDeps;
+}): () => void {
+  const config = params.cfg.watchdog;
+  const watches = resolveWatches(config?.watches);
+  if (config?.enabled === false || watches.length === 0) {
+    return () => {};
+  }
+  const agentId = config?.agentId?.trim() || resolveDefaultAgentId(params.cfg);
+  const sessionKey =
+    config?.sessionKey?.trim() || resolveAgentMainSessionKey({ cfg: params.cfg, agentId });
+  const evaluate = createWatchdogEvaluator();
+  const failing = new Set<string>();
+  let stopped = false;
+  let running = false;
+
+  const poll = async () => {
+    if (stopped || running) {
+      return;
+    }
+    running = true;
+    try {
+      for (const watch of watches) {
+        let value: number;
+        try {
+          value = (await readWatchSource(watch.source, params.deps)) * watch.scale + watch.offset;
+          failing.delete(watch.name);
+        } catch (err) {
+          // Log once per failure streak; a missing sensor should not flood the log every poll.
+          if (!failing.has(watch.name)) {
+            failing.add(watch.name);
+            params.log.warn(`watchdog: ${watch.name} read failed: ${String(err)}`);
+          }
+          continue;
+        }
+        const breach = evaluate(watch, value);
+        if (!breach || stopped) {
+          continue;
+        }
+        params.log.info(
+          `watchdog: ${watch.name} ${breach.condition} (${formatValue(value, watch.unit)})`,
+        );
+        enqueueSystemEvent(buildWatchdogPrompt(breach), {
+          sessionKey,
+          contextKey: `watchdog:${watch.name}`,
+        });
+        requestHeartbeatNow({ reason: `hook:watchdog:${watch.name}`, agentId, sessionKey });
+      }
+    } finally {
+      running = false;
+    }
+  };
+
+  const intervalMs = (config?.intervalSeconds ?? DEFAULT_INTERVAL_SECONDS) * 1000;
+  const timer = setInterval(() => {
+    void poll();
+  }, intervalMs);
+  timer.unref?.();
+  void poll();
+  params.log.info(`watchdog: watching ${watches.length} reading(s) every ${intervalMs / 1000}s`);
+
+  return () => {
+    stopped = true;
+    clearInterval(timer);
+  };
+}
@@ -256,6 +256,39 @@ export function logRunAttempt(params: SessionRef & { runId: string; attempt: num
   markActivity();
 }
 
+export function logProviderRetry(
+  params: SessionRef & {
+    provider?: string;
+    model?: string;
+    attempt: number;
+    maxAttempts: number;
+    delayMs: number;
+    retryAfterMs?: number;
+    error: string;
+  },
+) {
+  diag.warn(
+    `provider retry: sessionKey=${params.sessionKey ?? "unknown"} model=${params.provider ?? "?"}/${
+      params.model ?? "?"
+    } attempt=${params.attempt}/${params.maxAttempts} delayMs=${params.delayMs}${
+      params.retryAfterMs !== undefined ? ` retryAfterMs=${params.retryAfterMs}` : ""
+    } error="${params.error}"`,
+  );
+  emitDiagnosticEvent({
+    type: "provider.retry",
+    sessionId: params.sessionId,
+    sessionKey: params.sessionKey,
+    provider: params.provider,
+    model: params.model,
+    attempt: params.attempt,
+    maxAttempts: params.maxAttempts,
+    delayMs: params.delayMs,
+    retryAfterMs: params.retryAfterMs,
+    error: params.error,
+  });
+  markActivity();
+}
+
 export function logToolLoopAction(
   params: SessionRef & {
     toolName: string;
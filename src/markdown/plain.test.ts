@@ -0,0 +1,26 @@
+import { describe, expect, it } from "vitest";
+import { markdownToPlainText } from "./plain.js";
+
+describe("markdownToPlainText", () => {
+  it("drops styling and keeps link targets", () => {
+    expect(
+      markdownToPlainText("## Alert\n\n**Freezer** is `warm`, see [dashboard](https://x.test)"),
+    ).toBe("Alert\n\nFreezer is warm, see dashboard (https://x.test)");
+  });
+
+  it("does not repeat bare URLs", () => {
+    expect(markdownToPlainText("[https://x.test](https://x.test)")).toBe("https://x.test");
+  });
+
+  it("renders tables as bullets and honors a custom bullet", () => {
+    const table = "| Sensor | Temp |\n| --- | --- |\n| Freezer | -18 |";
+    const text = markdownToPlainText(table, { bullet: "-" });
+    expect(text).toContain("- Temp: -18");
+    expect(text).not.toContain("|");
+    expect(text).not.toContain("•");
+  });
+
+  it("keeps code block contents without fences", () => {
+    expect(markdownToPlainText("```sh\nmake flash\n```")).toBe("make flash");
+  });
+});
@@ -0,0 +1,44 @@
+import type { MarkdownTableMode } from "../config/types.base.js";
+import { markdownToIR, type MarkdownLinkSpan } from "./ir.js";
+import { renderMarkdownWithMarkers } from "./render.js";
+
+export type PlainTextOptions = {
+  /** How to render tables. Default: bullets (plain text has no grid). */
+  tableMode?: MarkdownTableMode;
+  /** List bullet to use instead of "•" (e.g. "-" for GSM-7 SMS). */
+  bullet?: string;
+};
+
+function buildPlainLink(link: MarkdownLinkSpan, text: string) {
+  const href = link.href.trim();
+  if (!href) {
+    return null;
+  }
+  const label = text.slice(link.start, link.end).trim();
+  const comparableHref = href.startsWith("mailto:") ? href.slice("mailto:".length) : href;
+  if (!label || label === href || label === comparableHref) {
+    return null;
+  }
+  return { start: link.start, end: link.end, open: "", close: ` (${href})` };
+}
+
+/**
+ * Render Markdown for channels that show markup literally (SMS, plain-text bridges): styles
+ * are dropped, headings become plain lines, and links keep their target as "label (url)".
+ */
+export function markdownToPlainText(markdown: string, options: PlainTextOptions = {}): string {
+  const ir = markdownToIR(markdown ?? "", {
+    linkify: false,
+    autolink: false,
+    headingStyle: "none",
+    blockquotePrefix: "",
+    tableMode: options.tableMode ?? "bullets",
+  });
+  const text = renderMarkdownWithMarkers(ir, {
+    styleMarkers: {},
+    escapeText: (value) => value,
+    buildLink: buildPlainLink,
+  });
+  const bullet = options.bullet;
+  return (bullet ? text.replace(/^(\s*)• /gm, `$1${bullet} `) : text).trim();
+}
@@ -17,6 +17,21 @@ describe("formatMediaUnderstandingBody", () => {
     expect(body).toBe("[Audio]\nTranscript:\nhello world");
   });
 
+  it("labels voice note transcripts", () => {
+    const body = formatMediaUnderstandingBody({
+      body: "<media:audio> (voice note)",
+      outputs: [
+        {
+          kind: "audio.transcription",
+          attachmentIndex: 0,
+          text: "turn on the lights",
+          provider: "openai",
+        },
+      ],
+    });
+    expect(body).toBe("[Voice note]\nTranscript:\nturn on the lights");
+  });
+
   it("includes user text when body is meaningful", () => {
     const body = formatMediaUnderstandingBody({
       body: "caption here",
@@ -2,6 +2,8 @@ import type { MediaUnderstandingOutput } from "./types.js";
 
 const MEDIA_PLACEHOLDER_RE = /^<media:[^>]+>(\s*\([^)]*\))?$/i;
 const MEDIA_PLACEHOLDER_TOKEN_RE = /^<media:[^>]+>(\s*\([^)]*\))?\s*/i;
+// Channels tag recorded voice notes (Telegram voice, WhatsApp PTT) as `<media:audio> (voice note)`.
+const VOICE_NOTE_PLACEHOLDER_RE = /<media:audio>\s*\(voice note\)/i;
 
 export function extractMediaUserText(body?: string): string | undefined {
   const trimmed = body?.trim() ?? "";
@@ -49,6 +51,7 @@ export function formatMediaUnderstandingBody(params: {
     counts.set(output.kind, (counts.get(output.kind) ?? 0) + 1);
   }
   const seen = new Map<MediaUnderstandingOutput["kind"], number>();
+  const audioTitle = VOICE_NOTE_PLACEHOLDER_RE.test(params.body ?? "") ? "Voice note" : "Audio";
 
   for (const output of outputs) {
     const count = counts.get(output.kind) ?? 1;
@@ -58,7 +61,7 @@ export function formatMediaUnderstandingBody(params: {
     if (output.kind === "audio.transcription") {
       sections.push(
         formatSection(
-          `Audio${suffix}`,
+          `${audioTitle}${suffix}`,
           "Transcript",
           output.text,
           outputs.length === 1 ? userText : undefined,
@@ -0,0 +1,63 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { withEnvAsync } from "../test-utils/env.js";
+import { transcribeAudioFile } from "./transcribe-file.js";
+
+const cfg = {
+  models: { providers: { openai: { apiKey: "test-key", models: [] } } },
+} as unknown as OpenClawConfig;
+
+async function withRecording(run: (filePath: string, root: string) => Promise<void>) {
+  const root = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-transcribe-"));
+  const filePath = path.join(root, "capture.wav");
+  await fs.writeFile(filePath, Buffer.from("RIFF"));
+  try {
+    await withEnvAsync({ PATH: "" }, async () => {
+      await run(filePath, root);
+    });
+  } finally {
+    await fs.rm(root, { recursive: true, force: true });
+  }
+}
+
+describe("transcribeAudioFile", () => {
+  it("transcribes a local recording with the audio pipeline", async () => {
+    await withRecording(async (filePath, root) => {
+      const result = await transcribeAudioFile({
+        cfg,
+        source: filePath,
+        localRoots: [root],
+        providers: {
+          openai: {
+            id: "openai",
+            capabilities: ["audio"],
+            transcribeAudio: async (req) => ({ text: "turn on the fan", model: req.model }),
+          },
+        },
+      });
+      expect(result).toEqual({
+        ok: true,
+        text: "turn on the fan",
+        provider: "openai",
+        model: "gpt-4o-mini-transcribe",
+      });
+    });
+  });
+
+  it("reports when transcription is disabled", async () => {
+    await withRecording(async (filePath, root) => {
+      const result = await transcribeAudioFile({
+        cfg: { ...cfg, tools: { media: { audio: { enabled: false } } } },
+        source: filePath,
+        localRoots: [root],
+      });
+      expect(result).toEqual({
+        ok: false,
+        error: "Audio transcription is disabled (tools.media.audio.enabled=false).",
+      });
+    });
+  });
+});
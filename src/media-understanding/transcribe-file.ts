@@ -0,0 +1,74 @@
+import type { MsgContext } from "../auto-reply/templating.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { getDefaultMediaLocalRoots } from "../media/local-roots.js";
+import {
+  type ActiveMediaModel,
+  buildProviderRegistry,
+  createMediaAttachmentCache,
+  normalizeMediaAttachments,
+  runCapability,
+} from "./runner.js";
+import type { MediaUnderstandingProvider } from "./types.js";
+
+export type AudioFileTranscription =
+  | { ok: true; text: string; provider: string; model?: string }
+  | { ok: false; error: string };
+
+/**
+ * Transcribe a single audio file or URL with the `tools.media.audio` pipeline: configured
+ * providers/CLIs first, then auto-detected local whisper.cpp / whisper, then provider APIs
+ * (OpenAI Whisper, Groq, Deepgram, ...) with available auth.
+ */
+export async function transcribeAudioFile(params: {
+  cfg: OpenClawConfig;
+  /** Local path or http(s) URL. */
+  source: string;
+  /** MIME type hint when the file name has no audio extension. */
+  mime?: string;
+  agentDir?: string;
+  /** Extra directories local paths may be read from (e.g. the agent workspace). */
+  localRoots?: readonly string[];
+  providers?: Record<string, MediaUnderstandingProvider>;
+  activeModel?: ActiveMediaModel;
+}): Promise<AudioFileTranscription> {
+  const source = params.source.trim();
+  const isUrl = /^https?:\/\//i.test(source);
+  const ctx: MsgContext = {
+    MediaPath: isUrl ? undefined : source,
+    MediaUrl: isUrl ? source : undefined,
+    MediaType: params.mime,
+  };
+  const attachments = normalizeMediaAttachments(ctx);
+  const cache = createMediaAttachmentCache(attachments, {
+    localPathRoots: [...getDefaultMediaLocalRoots(), ...(params.localRoots ?? [])],
+  });
+  try {
+    const result = await runCapability({
+      capability: "audio",
+      cfg: params.cfg,
+      ctx,
+      attachments: cache,
+      media: attachments,
+      agentDir: params.agentDir,
+      providerRegistry: buildProviderRegistry(params.providers),
+      activeModel: params.activeModel,
+    });
+    const output = result.outputs.find((entry) => entry.kind === "audio.transcription");
+    if (output?.text) {
+      return { ok: true, text: output.text, provider: output.provider, model: output.model };
+    }
+    const reason = result.decision.attachments
+      .flatMap((attachment) => attachment.attempts)
+      .map((attempt) => attempt.reason)
+      .find(Boolean);
+    return {
+      ok: false,
+      error:
+        result.decision.outcome === "disabled"
+          ? "Audio transcription is disabled (tools.media.audio.enabled=false)."
+          : `No transcription available (${reason ?? result.decision.outcome}).`,
+    };
+  } finally {
+    await cache.cleanup();
+  }
+}
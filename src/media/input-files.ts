@@ -241,6 +241,33 @@ function clampText(text: string, maxChars: number): string {
   return text.slice(0, maxChars);
 }
 
+/** Text of each PDF page (no OCR fallback); used for knowledge-base ingestion. */
+export async function extractPdfPageTexts(
+  buffer: Buffer,
+  opts?: { maxPages?: number },
+): Promise<{ pages: string[]; totalPages: number }> {
+  const { getDocument } = await loadPdfJsModule();
+  const pdf = await getDocument({
+    data: new Uint8Array(buffer),
+    disableWorker: true,
+  }).promise;
+  const maxPages = Math.min(pdf.numPages, opts?.maxPages ?? pdf.numPages);
+  const pages: string[] = [];
+  for (let pageNum = 1; pageNum <= maxPages; pageNum += 1) {
+    const page = await pdf.getPage(pageNum);
+    const textContent = await page.getTextContent();
+    pages.push(
+      textContent.items
+        .map((item) => ("str" in item ? String(item.str) + (item.hasEOL ? "\n" : "") : ""))
+        .join(" ")
+        .replace(/[ \t]+\n[ \t]*/g, "\n")
+        .replace(/[ \t]{2,}/g, " ")
+        .trim(),
+    );
+  }
+  return { pages, totalPages: pdf.numPages };
+}
+
 async function extractPdfContent(params: {
   buffer: Buffer;
   limits: InputFileLimits;
@@ -50,4 +50,20 @@ describe("splitMediaFromOutput", () => {
     const result = splitMediaFromOutput("MEDIA:screenshot");
     expect(result.mediaUrls).toBeUndefined();
   });
+
+  it("extracts inline [IMAGE:...] markers", () => {
+    const result = splitMediaFromOutput(
+      "Here is the plot [IMAGE:/tmp/plot.png] and [IMAGE: https://example.com/a.jpg]\nDone.",
+    );
+    expect(result.mediaUrls).toEqual(["/tmp/plot.png", "https://example.com/a.jpg"]);
+    expect(result.text).toBe("Here is the plot and\nDone.");
+  });
+
+  it("keeps [IMAGE:...] markers inside code fences and without a valid source", () => {
+    const fenced = "```\n[IMAGE:/tmp/plot.png]\n```";
+    expect(splitMediaFromOutput(fenced).mediaUrls).toBeUndefined();
+    const invalid = splitMediaFromOutput("see [IMAGE:not an image]");
+    expect(invalid.mediaUrls).toBeUndefined();
+    expect(invalid.text).toBe("see [IMAGE:not an image]");
+  });
 });
@@ -6,6 +6,10 @@ import { parseAudioTag } from "./audio-tags.js";
 // Allow optional wrapping backticks and punctuation after the token; capture the core token.
 export const MEDIA_TOKEN_RE = /\bMEDIA:\s*`?([^\n]+)`?/gi;
 
+// Inline `[IMAGE:<path or url>]` markers from skills and model text. Legacy convention: tools
+// should declare `details.attachments` (withToolAttachments) instead.
+const IMAGE_MARKER_RE = /\[IMAGE:\s*([^\]\n]+)\]/gi;
+
 export function normalizeMediaSource(src: string) {
   return src.startsWith("file://") ? src.replace("file://", "") : src;
 }
@@ -63,6 +67,17 @@ function isValidMedia(
   return false;
 }
 
+function extractImageMarkers(line: string, media: string[]): string {
+  return line.replace(IMAGE_MARKER_RE, (marker, payload: string) => {
+    const candidate = normalizeMediaSource(cleanCandidate(unwrapQuoted(payload) ?? payload.trim()));
+    if (!isValidMedia(candidate, { allowSpaces: true, allowBareFilename: true })) {
+      return marker;
+    }
+    media.push(candidate);
+    return "";
+  });
+}
+
 function unwrapQuoted(value: string): string | undefined {
   const trimmed = value.trim();
   if (trimmed.length < 2) {
@@ -108,25 +123,39 @@ export function splitMediaFromOutput(raw: string): {
   const keptLines: string[] = [];
 
   let lineOffset = 0; // Track character offset for fence checking
-  for (const line of lines) {
+  for (const rawLine of lines) {
     // Skip MEDIA extraction if this line is inside a fenced code block
     if (isInsideFence(fenceSpans, lineOffset)) {
-      keptLines.push(line);
-      lineOffset += line.length + 1; // +1 for newline
+      keptLines.push(rawLine);
+      lineOffset += rawLine.length + 1; // +1 for newline
       continue;
     }
 
+    let line = rawLine;
+    if (/\[IMAGE:/i.test(line)) {
+      const before = media.length;
+      line = extractImageMarkers(line, media);
+      if (media.length > before) {
+        foundMediaToken = true;
+        line = line.replace(/[ \t]{2,}/g, " ").trimEnd();
+        if (!line.trim()) {
+          lineOffset += rawLine.length + 1; // +1 for newline
+          continue;
+        }
+      }
+    }
+
     const trimmedStart = line.trimStart();
     if (!trimmedStart.startsWith("MEDIA:")) {
       keptLines.push(line);
-      lineOffset += line.length + 1; // +1 for newline
+      lineOffset += rawLine.length + 1; // +1 for newline
       continue;
     }
 
     const matches = Array.from(line.matchAll(MEDIA_TOKEN_RE));
     if (matches.length === 0) {
       keptLines.push(line);
-      lineOffset += line.length + 1; // +1 for newline
+      lineOffset += rawLine.length + 1; // +1 for newline
       continue;
     }
 
@@ -214,7 +243,7 @@ export function splitMediaFromOutput(raw: string): {
     if (cleanedLine) {
       keptLines.push(cleanedLine);
     }
-    lineOffset += line.length + 1; // +1 for newline
+    lineOffset += rawLine.length + 1; // +1 for newline
   }
 
   let cleanedText = keptLines
@@ -36,6 +36,7 @@ export async function createMistralEmbeddingProvider(
       id: "mistral",
       client,
       errorPrefix: "mistral embeddings failed",
+      agentId: options.agentId,
     }),
     client,
   };
@@ -0,0 +1,81 @@
+import { afterEach, describe, expect, it, vi } from "vitest";
+import { recordEmbeddingUsage } from "../infra/embedding-usage.js";
+import { type FetchMock, withFetchPreconnect } from "../test-utils/fetch-mock.js";
+import {
+  createOllamaEmbeddingProvider,
+  DEFAULT_OLLAMA_EMBEDDING_MODEL,
+  normalizeOllamaModel,
+} from "./embeddings-ollama.js";
+
+vi.mock("../infra/embedding-usage.js", () => ({
+  recordEmbeddingUsage: vi.fn(async () => {}),
+}));
+
+const createFetchMock = () =>
+  withFetchPreconnect(
+    vi.fn<FetchMock>(
+      async (_input: RequestInfo | URL, _init?: RequestInit) =>
+        new Response(
+          JSON.stringify({
+            model: "nomic-embed-text",
+            embeddings: [
+              [0.1, 0.2],
+              [0.3, 0.4],
+            ],
+            prompt_eval_count: 12,
+          }),
+          { status: 200, headers: { "Content-Type": "application/json" } },
+        ),
+    ),
+  );
+
+describe("ollama embedding provider", () => {
+  afterEach(() => {
+    vi.resetAllMocks();
+    vi.unstubAllGlobals();
+  });
+
+  it("normalizes model ids", () => {
+    expect(normalizeOllamaModel("")).toBe(DEFAULT_OLLAMA_EMBEDDING_MODEL);
+    expect(normalizeOllamaModel("ollama/mxbai-embed-large")).toBe("mxbai-embed-large");
+  });
+
+  it("batches inputs through /api/embed and records usage", async () => {
+    const fetchMock = createFetchMock();
+    vi.stubGlobal("fetch", fetchMock);
+    const { provider, client } = await createOllamaEmbeddingProvider({
+      config: {
+        models: {
+          providers: {
+            ollama: { baseUrl: "http://127.0.0.1:11434/v1", models: [] },
+          },
+        },
+      } as never,
+      agentId: "main",
+      provider: "ollama",
+      model: "",
+      fallback: "none",
+    });
+
+    expect(client.baseUrl).toBe("http://127.0.0.1:11434");
+    expect(client.headers.Authorization).toBeUndefined();
+    await expect(provider.embedBatch(["alpha", "beta"])).resolves.toEqual([
+      [0.1, 0.2],
+      [0.3, 0.4],
+    ]);
+
+    const [url, init] = fetchMock.mock.calls[0] ?? [];
+    expect(url).toBe("http://127.0.0.1:11434/api/embed");
+    expect(JSON.parse(String(init?.body))).toEqual({
+      model: "nomic-embed-text",
+      input: ["alpha", "beta"],
+    });
+    expect(recordEmbeddingUsage).toHaveBeenCalledWith({
+      provider: "ollama",
+      model: "nomic-embed-text",
+      tokens: 12,
+      inputs: 2,
+      agentId: "main",
+    });
+  });
+});
@@ -0,0 +1,99 @@
+import { resolveOllamaApiBase } from "../agents/models-config.providers.js";
+import { recordEmbeddingUsage } from "../infra/embedding-usage.js";
+import type { SsrFPolicy } from "../infra/net/ssrf.js";
+import type { EmbeddingProvider, EmbeddingProviderOptions } from "./embeddings.js";
+import { postJson } from "./post-json.js";
+import { buildRemoteBaseUrlPolicy } from "./remote-http.js";
+
+export type OllamaEmbeddingClient = {
+  baseUrl: string;
+  headers: Record<string, string>;
+  ssrfPolicy?: SsrFPolicy;
+  model: string;
+};
+
+export const DEFAULT_OLLAMA_EMBEDDING_MODEL = "nomic-embed-text";
+
+export function normalizeOllamaModel(model: string): string {
+  const trimmed = model.trim();
+  if (!trimmed) {
+    return DEFAULT_OLLAMA_EMBEDDING_MODEL;
+  }
+  if (trimmed.startsWith("ollama/")) {
+    return trimmed.slice("ollama/".length);
+  }
+  return trimmed;
+}
+
+/**
+ * Embeddings via Ollama's native `/api/embed`, which takes a batch of inputs per request.
+ * No API key is required; `memorySearch.remote.apiKey` or `models.providers.ollama.apiKey`
+ * is sent as a bearer token for proxied setups.
+ */
+export async function createOllamaEmbeddingProvider(
+  options: EmbeddingProviderOptions,
+): Promise<{ provider: EmbeddingProvider; client: OllamaEmbeddingClient }> {
+  const client = resolveOllamaEmbeddingClient(options);
+  const url = `${client.baseUrl}/api/embed`;
+
+  const embed = async (input: string[]): Promise<number[][]> => {
+    if (input.length === 0) {
+      return [];
+    }
+    return await postJson({
+      url,
+      headers: client.headers,
+      ssrfPolicy: client.ssrfPolicy,
+      body: { model: client.model, input },
+      errorPrefix: "ollama embeddings failed",
+      parse: (payload) => {
+        const typed = payload as { embeddings?: number[][]; prompt_eval_count?: number };
+        if (typeof typed.prompt_eval_count === "number") {
+          void recordEmbeddingUsage({
+            provider: "ollama",
+            model: client.model,
+            tokens: typed.prompt_eval_count,
+            inputs: input.length,
+            agentId: options.agentId,
+          });
+        }
+        return typed.embeddings ?? [];
+      },
+    });
+  };
+
+  return {
+    provider: {
+      id: "ollama",
+      model: client.model,
+      embedQuery: async (text) => {
+        const [vec] = await embed([text]);
+        return vec ?? [];
+      },
+      embedBatch: embed,
+    },
+    client,
+  };
+}
+
+export function resolveOllamaEmbeddingClient(
+  options: EmbeddingProviderOptions,
+): OllamaEmbeddingClient {
+  const remote = options.remote;
+  const providerConfig = options.config.models?.providers?.ollama;
+  const baseUrl = resolveOllamaApiBase(
+    remote?.baseUrl?.trim() || providerConfig?.baseUrl?.trim() || undefined,
+  );
+  const apiKey = remote?.apiKey?.trim() || providerConfig?.apiKey?.trim();
+  const headers: Record<string, string> = {
+    "Content-Type": "application/json",
+    ...(apiKey ? { Authorization: `Bearer ${apiKey}` } : {}),
+    ...Object.assign({}, providerConfig?.headers, remote?.headers),
+  };
+  return {
+    baseUrl,
+    headers,
+    ssrfPolicy: buildRemoteBaseUrlPolicy(baseUrl),
+    model: normalizeOllamaModel(options.model),
+  };
+}
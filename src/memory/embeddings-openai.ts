@@ -41,6 +41,7 @@ export async function createOpenAiEmbeddingProvider(
       id: "openai",
       client,
       errorPrefix: "openai embeddings failed",
+      agentId: options.agentId,
       maxInputTokens: OPENAI_MAX_INPUT_TOKENS[client.model],
     }),
     client,
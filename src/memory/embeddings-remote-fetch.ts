@@ -7,6 +7,8 @@ export async function fetchRemoteEmbeddingVectors(params: {
   ssrfPolicy?: SsrFPolicy;
   body: unknown;
   errorPrefix: string;
+  /** Called with the input tokens the provider reports for the request. */
+  onUsage?: (tokens: number) => void;
 }): Promise<number[][]> {
   return await postJson({
     url: params.url,
@@ -17,7 +19,12 @@ export async function fetchRemoteEmbeddingVectors(params: {
     parse: (payload) => {
       const typedPayload = payload as {
         data?: Array<{ embedding?: number[] }>;
+        usage?: { prompt_tokens?: number; total_tokens?: number };
       };
+      const tokens = typedPayload.usage?.prompt_tokens ?? typedPayload.usage?.total_tokens;
+      if (typeof tokens === "number" && tokens > 0) {
+        params.onUsage?.(tokens);
+      }
       const data = typedPayload.data ?? [];
       return data.map((entry) => entry.embedding ?? []);
     },
@@ -1,3 +1,4 @@
+import { recordEmbeddingUsage } from "../infra/embedding-usage.js";
 import type { SsrFPolicy } from "../infra/net/ssrf.js";
 import {
   resolveRemoteEmbeddingBearerClient,
@@ -18,6 +19,8 @@ export function createRemoteEmbeddingProvider(params: {
   client: RemoteEmbeddingClient;
   errorPrefix: string;
   maxInputTokens?: number;
+  /** Agent the embeddings are billed to in the usage ledger. */
+  agentId?: string;
 }): EmbeddingProvider {
   const { client } = params;
   const url = `${client.baseUrl.replace(/\/$/, "")}/embeddings`;
@@ -32,6 +35,14 @@ export function createRemoteEmbeddingProvider(params: {
       ssrfPolicy: client.ssrfPolicy,
       body: { model: client.model, input },
       errorPrefix: params.errorPrefix,
+      onUsage: (tokens) =>
+        void recordEmbeddingUsage({
+          provider: params.id,
+          model: client.model,
+          tokens,
+          inputs: input.length,
+          agentId: params.agentId,
+        }),
     });
   };
 
@@ -0,0 +1,109 @@
+import { randomUUID } from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import { createAsyncLock } from "../infra/json-files.js";
+
+export type MemoryFact = { key: string; value: string };
+
+export const MEMORY_FACTS_RELATIVE_PATH = "memory/facts.md";
+const MAX_KEY_CHARS = 120;
+const MAX_VALUE_CHARS = 2000;
+const FACT_LINE_RE = /^- `([^`]+)`: (.*)$/;
+const HEADER = [
+  "# Facts",
+  "",
+  "Saved by the agent with memory_set. One fact per line; edits here are picked up on the next read.",
+  "",
+].join("\n");
+
+const withFactsLock = createAsyncLock();
+
+export function resolveMemoryFactsPath(workspaceDir: string): string {
+  return path.join(workspaceDir, MEMORY_FACTS_RELATIVE_PATH);
+}
+
+/** Collapse whitespace so every fact stays on one line of the markdown file. */
+export function normalizeMemoryFactKey(raw: string): string {
+  const key = raw.replace(/[`\s]+/g, " ").trim();
+  if (!key) {
+    throw new Error("key required");
+  }
+  if (key.length > MAX_KEY_CHARS) {
+    throw new Error(`key must be at most ${MAX_KEY_CHARS} characters`);
+  }
+  return key;
+}
+
+function parseFacts(content: string): MemoryFact[] {
+  const facts: MemoryFact[] = [];
+  for (const line of content.split("\n")) {
+    const match = FACT_LINE_RE.exec(line.trimEnd());
+    if (match) {
+      facts.push({ key: match[1], value: match[2] });
+    }
+  }
+  return facts;
+}
+
+function formatFacts(facts: MemoryFact[]): string {
+  return `${HEADER}\n${facts.map((fact) => `- \`${fact.key}\`: ${fact.value}`).join("\n")}\n`;
+}
+
+export async function readMemoryFacts(filePath: string): Promise<MemoryFact[]> {
+  try {
+    return parseFacts(await fs.readFile(filePath, "utf-8"));
+  } catch {
+    return [];
+  }
+}
+
+function sameKey(a: string, b: string): boolean {
+  return a.toLowerCase() === b.toLowerCase();
+}
+
+export async function getMemoryFact(filePath: string, key: string): Promise<MemoryFact | null> {
+  const normalized = normalizeMemoryFactKey(key);
+  const facts = await readMemoryFacts(filePath);
+  return facts.find((fact) => sameKey(fact.key, normalized)) ?? null;
+}
+
+/**
+ * Save (or, with an empty value, forget) one fact. Keys match case-insensitively; an existing
+ * fact keeps its position in the file.
+ */
+export async function setMemoryFact(
+  filePath: string,
+  key: string,
+  value: string,
+): Promise<{ fact: MemoryFact; previous?: string; removed: boolean }> {
+  const normalizedKey = normalizeMemoryFactKey(key);
+  const normalizedValue = value.replace(/\s+/g, " ").trim();
+  if (normalizedValue.length > MAX_VALUE_CHARS) {
+    throw new Error(`value must be at most ${MAX_VALUE_CHARS} characters`);
+  }
+  return await withFactsLock(async () => {
+    const facts = await readMemoryFacts(filePath);
+    const index = facts.findIndex((fact) => sameKey(fact.key, normalizedKey));
+    const previous = index >= 0 ? facts[index]?.value : undefined;
+    const fact = { key: normalizedKey, value: normalizedValue };
+    if (!normalizedValue) {
+      if (index < 0) {
+        return { fact, removed: false };
+      }
+      facts.splice(index, 1);
+    } else if (index >= 0) {
+      facts[index] = fact;
+    } else {
+      facts.push(fact);
+    }
+    await fs.mkdir(path.dirname(filePath), { recursive: true });
+    const tmp = `${filePath}.${randomUUID()}.tmp`;
+    await fs.writeFile(tmp, formatFacts(facts), "utf-8");
+    await fs.rename(tmp, filePath);
+    return {
+      fact,
+      ...(previous !== undefined ? { previous } : {}),
+      removed: !normalizedValue && index >= 0,
+    };
+  });
+}
@@ -0,0 +1,100 @@
+import fs from "node:fs/promises";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, describe, expect, it } from "vitest";
+import { filterKbResults, ingestKbDocument, listKbDocuments, renderKbDocument } from "./kb.js";
+import type { MemorySearchResult } from "./types.js";
+
+let tmpDir: string | undefined;
+
+afterEach(async () => {
+  if (tmpDir) {
+    await fs.rm(tmpDir, { recursive: true, force: true });
+    tmpDir = undefined;
+  }
+});
+
+describe("knowledge base", () => {
+  it("renders PDF pages as page sections", () => {
+    expect(
+      renderKbDocument({
+        title: "BME280",
+        source: "/docs/bme280.pdf",
+        ingestedAt: "2026-10-16T00:00:00.000Z",
+        pages: ["Overview", "", "Register 0xF7 press_msb"],
+      }),
+    ).toBe(
+      [
+        "# BME280",
+        "",
+        "Source: /docs/bme280.pdf",
+        "Ingested: 2026-10-16T00:00:00.000Z",
+        "Pages: 3",
+        "",
+        "## Page 1",
+        "",
+        "Overview",
+        "",
+        "## Page 3",
+        "",
+        "Register 0xF7 press_msb",
+        "",
+      ].join("\n"),
+    );
+  });
+
+  it("ingests Markdown into memory/kb and lists it", async () => {
+    tmpDir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-kb-"));
+    const source = path.join(tmpDir, "pinout.md");
+    await fs.writeFile(source, "# Header J8\n\n## Power\n\nPin 1: 3V3\n");
+
+    const first = await ingestKbDocument({
+      workspaceDir: tmpDir,
+      filePath: source,
+      title: "Pi 5 Pinout",
+      now: 0,
+    });
+    expect(first).toMatchObject({ path: "memory/kb/pi-5-pinout.md", replaced: false });
+    const written = await fs.readFile(path.join(tmpDir, first.path), "utf-8");
+    expect(written).toContain("# Pi 5 Pinout\n");
+    expect(written).toContain("## Header J8\n\n### Power\n\nPin 1: 3V3");
+
+    const again = await ingestKbDocument({
+      workspaceDir: tmpDir,
+      filePath: source,
+      title: "Pi 5 Pinout",
+    });
+    expect(again.replaced).toBe(true);
+    expect(await listKbDocuments(tmpDir)).toEqual([
+      {
+        path: "memory/kb/pi-5-pinout.md",
+        title: "Pi 5 Pinout",
+        source,
+        ingestedAt: again.ingestedAt,
+      },
+    ]);
+    const unsupported = ingestKbDocument({ workspaceDir: tmpDir, filePath: "board.docx" });
+    await expect(unsupported).rejects.toThrow('unsupported file type ".docx"');
+  });
+
+  it("keeps only knowledge-base hits, optionally for one document", () => {
+    const hit = (hitPath: string): MemorySearchResult => ({
+      path: hitPath,
+      startLine: 1,
+      endLine: 2,
+      score: 0.9,
+      snippet: "",
+      source: "memory",
+    });
+    const results = [
+      hit("memory/2026-10-01.md"),
+      hit("memory/kb/bme280.md"),
+      hit("memory/kb/pi-5-pinout.md"),
+    ];
+    expect(filterKbResults(results).map((entry) => entry.path)).toEqual([
+      "memory/kb/bme280.md",
+      "memory/kb/pi-5-pinout.md",
+    ]);
+    expect(filterKbResults(results, { document: "Pi 5 Pinout" })).toHaveLength(1);
+  });
+});
@@ -0,0 +1,156 @@
+import fs from "node:fs/promises";
+import path from "node:path";
+import { extractPdfPageTexts } from "../media/input-files.js";
+import type { MemorySearchResult } from "./types.js";
+
+/** Ingested documents live inside the memory tree so the memory index chunks and embeds them. */
+export const KB_RELATIVE_DIR = "memory/kb";
+
+const SUPPORTED_EXTENSIONS = new Set([".pdf", ".md", ".markdown", ".txt"]);
+const DEFAULT_MAX_PAGES = 500;
+
+export type KbDocument = {
+  /** Path relative to the workspace (memory/kb/<slug>.md). */
+  path: string;
+  title: string;
+  source: string;
+  pages?: number;
+  ingestedAt?: string;
+};
+
+export type KbIngestResult = KbDocument & { chars: number; replaced: boolean };
+
+export function isKbPath(relPath: string): boolean {
+  return relPath.replace(/\\/g, "/").startsWith(`${KB_RELATIVE_DIR}/`);
+}
+
+export function slugifyKbTitle(value: string): string {
+  const slug = value
+    .toLowerCase()
+    .replace(/[^a-z0-9]+/g, "-")
+    .replace(/^-+|-+$/g, "")
+    .slice(0, 80);
+  return slug || "document";
+}
+
+/**
+ * Markdown written for one source document. PDF pages get their own heading so search hits and
+ * citations point at the page ("## Page 12"), which is how datasheets are usually referenced.
+ */
+export function renderKbDocument(params: {
+  title: string;
+  source: string;
+  ingestedAt: string;
+  pages?: string[];
+  text?: string;
+}): string {
+  const lines = [
+    `# ${params.title}`,
+    "",
+    `Source: ${params.source}`,
+    `Ingested: ${params.ingestedAt}`,
+    ...(params.pages ? [`Pages: ${params.pages.length}`] : []),
+    "",
+  ];
+  if (params.pages) {
+    params.pages.forEach((page, index) => {
+      if (page.trim()) {
+        lines.push(`## Page ${index + 1}`, "", page.trim(), "");
+      }
+    });
+  } else {
+    // Demote the source's own headings one level so the document title stays the only h1.
+    lines.push((params.text ?? "").trim().replace(/^(#{1,5}) /gm, "#$1 "), "");
+  }
+  return `${lines.join("\n").trimEnd()}\n`;
+}
+
+function parseKbHeader(relPath: string, content: string): KbDocument {
+  const field = (name: string) => new RegExp(`^${name}: (.+)$`, "m").exec(content)?.[1]?.trim();
+  const pages = Number(field("Pages"));
+  return {
+    path: relPath,
+    title: /^# (.+)$/m.exec(content)?.[1]?.trim() ?? path.basename(relPath, ".md"),
+    source: field("Source") ?? "",
+    ...(Number.isFinite(pages) && pages > 0 ? { pages } : {}),
+    ingestedAt: field("Ingested"),
+  };
+}
+
+/** Converts a PDF, Markdown, or text file into memory/kb/<slug>.md. Re-ingesting replaces it. */
+export async function ingestKbDocument(params: {
+  workspaceDir: string;
+  filePath: string;
+  title?: string;
+  maxPages?: number;
+  now?: number;
+}): Promise<KbIngestResult> {
+  const filePath = path.resolve(params.workspaceDir, params.filePath);
+  const ext = path.extname(filePath).toLowerCase();
+  if (!SUPPORTED_EXTENSIONS.has(ext)) {
+    throw new Error(`unsupported file type "${ext || "none"}" (use .pdf, .md, or .txt)`);
+  }
+  const title = params.title?.trim() || path.basename(filePath, ext);
+  const ingestedAt = new Date(params.now ?? Date.now()).toISOString();
+  let content: string;
+  let pageCount: number | undefined;
+  if (ext === ".pdf") {
+    const { pages } = await extractPdfPageTexts(await fs.readFile(filePath), {
+      maxPages: params.maxPages ?? DEFAULT_MAX_PAGES,
+    });
+    if (!pages.some((page) => page.trim())) {
+      throw new Error("no extractable text in PDF (scanned documents need OCR first)");
+    }
+    pageCount = pages.length;
+    content = renderKbDocument({ title, source: filePath, ingestedAt, pages });
+  } else {
+    const text = await fs.readFile(filePath, "utf-8");
+    content = renderKbDocument({ title, source: filePath, ingestedAt, text });
+  }
+
+  const relPath = `${KB_RELATIVE_DIR}/${slugifyKbTitle(title)}.md`;
+  const target = path.join(params.workspaceDir, relPath);
+  const replaced = await fs
+    .stat(target)
+    .then(() => true)
+    .catch(() => false);
+  await fs.mkdir(path.dirname(target), { recursive: true });
+  await fs.writeFile(target, content, "utf-8");
+  return {
+    path: relPath,
+    title,
+    source: filePath,
+    ...(pageCount !== undefined ? { pages: pageCount } : {}),
+    ingestedAt,
+    chars: content.length,
+    replaced,
+  };
+}
+
+export async function listKbDocuments(workspaceDir: string): Promise<KbDocument[]> {
+  const dir = path.join(workspaceDir, KB_RELATIVE_DIR);
+  let names: string[];
+  try {
+    names = (await fs.readdir(dir)).filter((name) => name.endsWith(".md")).toSorted();
+  } catch {
+    return [];
+  }
+  return Promise.all(
+    names.map(async (name) => {
+      const relPath = `${KB_RELATIVE_DIR}/${name}`;
+      return parseKbHeader(relPath, await fs.readFile(path.join(dir, name), "utf-8"));
+    }),
+  );
+}
+
+/** Keeps knowledge-base hits (optionally one document) from a memory search. */
+export function filterKbResults(
+  results: MemorySearchResult[],
+  opts?: { document?: string; maxResults?: number },
+): MemorySearchResult[] {
+  const document = opts?.document ? slugifyKbTitle(opts.document) : undefined;
+  const filtered = results.filter(
+    (entry) => isKbPath(entry.path) && (!document || path.basename(entry.path, ".md") === document),
+  );
+  return opts?.maxResults ? filtered.slice(0, opts.maxResults) : filtered;
+}
@@ -57,6 +57,7 @@ describe("buildPairingReply", () => {
         `(?:openclaw|openclaw) --profile isolated pairing approve ${testCase.channel} ${testCase.code}`,
       );
       expect(text).toMatch(commandRe);
+      expect(text).toContain(`/pairing approve ${testCase.channel} ${testCase.code}`);
     });
   }
 });
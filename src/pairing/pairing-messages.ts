@@ -16,5 +16,6 @@ export function buildPairingReply(params: {
     "",
     "Ask the bot owner to approve with:",
     formatCliCommand(`openclaw pairing approve ${channel} ${code}`),
+    `(or /pairing approve ${channel} ${code} from an owner chat)`,
   ].join("\n");
 }
@@ -186,6 +186,12 @@ export type { ChatType } from "../channels/chat-type.js";
 export type { RoutePeerKind } from "../routing/resolve-route.js";
 export { resolveAckReaction } from "../agents/identity.js";
 export type { ReplyPayload } from "../auto-reply/types.js";
+export { confirmButtons, optionPicker } from "../channels/interactive.js";
+export type {
+  InteractiveButton,
+  InteractiveButtonStyle,
+  InteractiveReply,
+} from "../channels/interactive.js";
 export type { ChunkMode } from "../auto-reply/chunk.js";
 export { SILENT_REPLY_TOKEN, isSilentReplyText } from "../auto-reply/tokens.js";
 export {
@@ -251,6 +257,10 @@ export {
   shouldAckReactionForWhatsApp,
 } from "../channels/ack-reactions.js";
 export { createTypingCallbacks } from "../channels/typing.js";
+export {
+  createFinalizableDraftLifecycle,
+  type FinalizableDraftStreamState,
+} from "../channels/draft-stream-controls.js";
 export { createReplyPrefixContext, createReplyPrefixOptions } from "../channels/reply-prefix.js";
 export { logAckFailure, logInboundDrop, logTypingFailure } from "../channels/logging.js";
 export { resolveChannelMediaMaxBytes } from "../channels/plugins/media-limits.js";
@@ -328,6 +338,13 @@ export {
   readNumberParam,
   readReactionParams,
   readStringParam,
+  toolProgressResult,
+  withToolAttachments,
+} from "../agents/tools/common.js";
+export type {
+  ToolProgress,
+  ToolResultAttachment,
+  ToolResultAttachmentKind,
 } from "../agents/tools/common.js";
 export { formatDocsLink } from "../terminal/links.js";
 export {
@@ -498,6 +515,7 @@ export {
   stripMarkdown,
 } from "../line/markdown-to-line.js";
 export type { ProcessedLineMessage } from "../line/markdown-to-line.js";
+export { markdownToPlainText, type PlainTextOptions } from "../markdown/plain.js";
 
 // Media utilities
 export { loadWebMedia, type WebMediaResult } from "../web/media.js";
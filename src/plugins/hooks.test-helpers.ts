@@ -21,5 +21,6 @@ export function createMockPluginRegistry(
     services: [],
     providers: [],
     commands: [],
+    middlewares: [],
   } as unknown as PluginRegistry;
 }
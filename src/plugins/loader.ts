@@ -167,6 +167,7 @@ function createPluginRecord(params: {
     cliCommands: [],
     services: [],
     commands: [],
+    middlewares: [],
     httpHandlers: 0,
     hookCount: 0,
     configSchema: params.configSchema,
@@ -13,6 +13,7 @@ import { registerPluginCommand } from "./commands.js";
 import { normalizePluginHttpPath } from "./http-path.js";
 import type { PluginRuntime } from "./runtime/types.js";
 import type {
+  OpenClawAgentMiddleware,
   OpenClawPluginApi,
   OpenClawPluginChannelRegistration,
   OpenClawPluginCliRegistrar,
@@ -88,6 +89,12 @@ export type PluginServiceRegistration = {
   source: string;
 };
 
+export type PluginMiddlewareRegistration = {
+  pluginId: string;
+  middleware: OpenClawAgentMiddleware;
+  source: string;
+};
+
 export type PluginCommandRegistration = {
   pluginId: string;
   command: OpenClawPluginCommandDefinition;
@@ -114,6 +121,7 @@ export type PluginRecord = {
   cliCommands: string[];
   services: string[];
   commands: string[];
+  middlewares: string[];
   httpHandlers: number;
   hookCount: number;
   configSchema: boolean;
@@ -134,6 +142,7 @@ export type PluginRegistry = {
   cliRegistrars: PluginCliRegistration[];
   services: PluginServiceRegistration[];
   commands: PluginCommandRegistration[];
+  middlewares: PluginMiddlewareRegistration[];
   diagnostics: PluginDiagnostic[];
 };
 
@@ -157,6 +166,7 @@ export function createEmptyPluginRegistry(): PluginRegistry {
     cliRegistrars: [],
     services: [],
     commands: [],
+    middlewares: [],
     diagnostics: [],
   };
 }
@@ -414,6 +424,25 @@ export function createPluginRegistry(registryParams: PluginRegistryParams) {
     });
   };
 
+  const registerMiddleware = (record: PluginRecord, middleware: OpenClawAgentMiddleware) => {
+    const id = middleware.id?.trim();
+    if (!id) {
+      pushDiagnostic({
+        level: "error",
+        pluginId: record.id,
+        source: record.source,
+        message: "middleware registration missing id",
+      });
+      return;
+    }
+    record.middlewares.push(id);
+    registry.middlewares.push({
+      pluginId: record.id,
+      middleware,
+      source: record.source,
+    });
+  };
+
   const registerCommand = (record: PluginRecord, command: OpenClawPluginCommandDefinition) => {
     const name = command.name.trim();
     if (!name) {
@@ -497,6 +526,7 @@ export function createPluginRegistry(registryParams: PluginRegistryParams) {
       registerCli: (registrar, opts) => registerCli(record, registrar, opts),
       registerService: (service) => registerService(record, service),
       registerCommand: (command) => registerCommand(record, command),
+      registerMiddleware: (middleware) => registerMiddleware(record, middleware),
       resolvePath: (input: string) => resolveUserPath(input),
       on: (hookName, handler, opts) => registerTypedHook(record, hookName, handler, opts),
     };
@@ -513,6 +543,7 @@ export function createPluginRegistry(registryParams: PluginRegistryParams) {
     registerCli,
     registerService,
     registerCommand,
+    registerMiddleware,
     registerHook,
     registerTypedHook,
   };
@@ -1,6 +1,13 @@
 import { createRequire } from "node:module";
 import { resolveEffectiveMessagesConfig, resolveHumanDelayConfig } from "../../agents/identity.js";
-import { createMemoryGetTool, createMemorySearchTool } from "../../agents/tools/memory-tool.js";
+import {
+  createKbIngestTool,
+  createKbSearchTool,
+  createMemoryGetTool,
+  createMemoryListTool,
+  createMemorySearchTool,
+  createMemorySetTool,
+} from "../../agents/tools/memory-tool.js";
 import { handleSlackAction } from "../../agents/tools/slack-actions.js";
 import {
   chunkByNewline,
@@ -67,6 +74,7 @@ import { resolveDiscordChannelAllowlist } from "../../discord/resolve-channels.j
 import { resolveDiscordUserAllowlist } from "../../discord/resolve-users.js";
 import { sendMessageDiscord, sendPollDiscord } from "../../discord/send.js";
 import { shouldLogVerbose } from "../../globals.js";
+import { resolveActiveGoalsPrompt } from "../../goals/store.js";
 import { monitorIMessageProvider } from "../../imessage/monitor.js";
 import { probeIMessage } from "../../imessage/probe.js";
 import { sendMessageIMessage } from "../../imessage/send.js";
@@ -100,6 +108,7 @@ import { fetchRemoteMedia } from "../../media/fetch.js";
 import { getImageMetadata, resizeToJpeg } from "../../media/image-ops.js";
 import { detectMime } from "../../media/mime.js";
 import { saveMediaBuffer } from "../../media/store.js";
+import { transcribeAudioFile } from "../../media-understanding/transcribe-file.js";
 import { buildPairingReply } from "../../pairing/pairing-messages.js";
 import {
   readChannelAllowFromStore,
@@ -127,7 +136,7 @@ import { monitorTelegramProvider } from "../../telegram/monitor.js";
 import { probeTelegram } from "../../telegram/probe.js";
 import { sendMessageTelegram, sendPollTelegram } from "../../telegram/send.js";
 import { resolveTelegramToken } from "../../telegram/token.js";
-import { textToSpeechTelephony } from "../../tts/tts.js";
+import { textToSpeech, textToSpeechTelephony } from "../../tts/tts.js";
 import { getActiveWebListener } from "../../web/active-listener.js";
 import {
   getWebAuthAgeMs,
@@ -242,7 +251,7 @@ export function createPluginRuntime(): PluginRuntime {
     config: createRuntimeConfig(),
     system: createRuntimeSystem(),
     media: createRuntimeMedia(),
-    tts: { textToSpeechTelephony },
+    tts: { textToSpeech, textToSpeechTelephony },
     tools: createRuntimeTools(),
     channel: createRuntimeChannel(),
     logging: createRuntimeLogging(),
@@ -262,6 +271,7 @@ function createRuntimeSystem(): PluginRuntime["system"] {
     enqueueSystemEvent,
     runCommandWithTimeout,
     formatNativeDependencyHint,
+    resolveActiveGoalsPrompt,
   };
 }
 
@@ -273,13 +283,18 @@ function createRuntimeMedia(): PluginRuntime["media"] {
     isVoiceCompatibleAudio,
     getImageMetadata,
     resizeToJpeg,
+    transcribeAudioFile,
   };
 }
 
 function createRuntimeTools(): PluginRuntime["tools"] {
   return {
+    createKbIngestTool,
+    createKbSearchTool,
     createMemoryGetTool,
+    createMemoryListTool,
     createMemorySearchTool,
+    createMemorySetTool,
     registerMemoryCli,
   };
 }
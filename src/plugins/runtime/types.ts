@@ -16,7 +16,10 @@ type UpsertChannelPairingRequest =
   typeof import("../../pairing/pairing-store.js").upsertChannelPairingRequest;
 type FetchRemoteMedia = typeof import("../../media/fetch.js").fetchRemoteMedia;
 type SaveMediaBuffer = typeof import("../../media/store.js").saveMediaBuffer;
+type TextToSpeech = typeof import("../../tts/tts.js").textToSpeech;
 type TextToSpeechTelephony = typeof import("../../tts/tts.js").textToSpeechTelephony;
+type TranscribeAudioFile =
+  typeof import("../../media-understanding/transcribe-file.js").transcribeAudioFile;
 type BuildMentionRegexes = typeof import("../../auto-reply/reply/mentions.js").buildMentionRegexes;
 type MatchesMentionPatterns =
   typeof import("../../auto-reply/reply/mentions.js").matchesMentionPatterns;
@@ -75,15 +78,20 @@ type GetChannelActivity = typeof import("../../infra/channel-activity.js").getCh
 type EnqueueSystemEvent = typeof import("../../infra/system-events.js").enqueueSystemEvent;
 type RunCommandWithTimeout = typeof import("../../process/exec.js").runCommandWithTimeout;
 type FormatNativeDependencyHint = typeof import("./native-deps.js").formatNativeDependencyHint;
+type ResolveActiveGoalsPrompt = typeof import("../../goals/store.js").resolveActiveGoalsPrompt;
 type LoadWebMedia = typeof import("../../web/media.js").loadWebMedia;
 type DetectMime = typeof import("../../media/mime.js").detectMime;
 type MediaKindFromMime = typeof import("../../media/constants.js").mediaKindFromMime;
 type IsVoiceCompatibleAudio = typeof import("../../media/audio.js").isVoiceCompatibleAudio;
 type GetImageMetadata = typeof import("../../media/image-ops.js").getImageMetadata;
 type ResizeToJpeg = typeof import("../../media/image-ops.js").resizeToJpeg;
+type CreateKbIngestTool = typeof import("../../agents/tools/memory-tool.js").createKbIngestTool;
+type CreateKbSearchTool = typeof import("../../agents/tools/memory-tool.js").createKbSearchTool;
 type CreateMemoryGetTool = typeof import("../../agents/tools/memory-tool.js").createMemoryGetTool;
+type CreateMemoryListTool = typeof import("../../agents/tools/memory-tool.js").createMemoryListTool;
 type CreateMemorySearchTool =
   typeof import("../../agents/tools/memory-tool.js").createMemorySearchTool;
+type CreateMemorySetTool = typeof import("../../agents/tools/memory-tool.js").createMemorySetTool;
 type RegisterMemoryCli = typeof import("../../cli/memory-cli.js").registerMemoryCli;
 type DiscordMessageActions =
   typeof import("../../channels/plugins/actions/discord.js").discordMessageActions;
@@ -186,6 +194,7 @@ export type PluginRuntime = {
     enqueueSystemEvent: EnqueueSystemEvent;
     runCommandWithTimeout: RunCommandWithTimeout;
     formatNativeDependencyHint: FormatNativeDependencyHint;
+    resolveActiveGoalsPrompt: ResolveActiveGoalsPrompt;
   };
   media: {
     loadWebMedia: LoadWebMedia;
@@ -194,13 +203,19 @@ export type PluginRuntime = {
     isVoiceCompatibleAudio: IsVoiceCompatibleAudio;
     getImageMetadata: GetImageMetadata;
     resizeToJpeg: ResizeToJpeg;
+    transcribeAudioFile: TranscribeAudioFile;
   };
   tts: {
+    textToSpeech: TextToSpeech;
     textToSpeechTelephony: TextToSpeechTelephony;
   };
   tools: {
+    createKbIngestTool: CreateKbIngestTool;
+    createKbSearchTool: CreateKbSearchTool;
     createMemoryGetTool: CreateMemoryGetTool;
+    createMemoryListTool: CreateMemoryListTool;
     createMemorySearchTool: CreateMemorySearchTool;
+    createMemorySetTool: CreateMemorySetTool;
     registerMemoryCli: RegisterMemoryCli;
   };
   channel: {
@@ -1,5 +1,6 @@
 import type { IncomingMessage, ServerResponse } from "node:http";
-import type { AgentMessage } from "@mariozechner/pi-agent-core";
+import type { AgentMessage, AgentToolResult } from "@mariozechner/pi-agent-core";
+import type { AssistantMessage, Message } from "@mariozechner/pi-ai";
 import type { Command } from "commander";
 import type { AuthProfileCredential, OAuthCredential } from "../agents/auth-profiles/types.js";
 import type { AnyAgentTool } from "../agents/tools/common.js";
@@ -222,6 +223,65 @@ export type OpenClawPluginService = {
   stop?: (ctx: OpenClawPluginServiceContext) => void | Promise<void>;
 };
 
+// Agent middleware: runs inside the agent loop, unlike hooks that observe it from outside.
+export type AgentMiddlewareModelCallEvent = {
+  provider: string;
+  model: string;
+  systemPrompt?: string;
+  /** Messages sent on this provider call: history, the prompt, and tool results so far. */
+  messages: Message[];
+};
+
+export type AgentMiddlewareModelCallResult = {
+  systemPrompt?: string;
+  messages?: Message[];
+};
+
+export type AgentMiddlewareModelResponseEvent = {
+  provider: string;
+  model: string;
+  message: AssistantMessage;
+};
+
+export type AgentMiddlewareModelResponseResult = {
+  message?: AssistantMessage;
+};
+
+export type AgentMiddlewareToolCallEvent = {
+  toolName: string;
+  toolCallId: string;
+  params: unknown;
+};
+
+export type OpenClawAgentMiddleware = {
+  id: string;
+  /** Before every provider call, including tool-loop continuations. Changes are not persisted. */
+  beforeModelCall?: (
+    event: AgentMiddlewareModelCallEvent,
+    ctx: PluginHookAgentContext,
+  ) =>
+    | AgentMiddlewareModelCallResult
+    | void
+    | Promise<AgentMiddlewareModelCallResult | void>;
+  /**
+   * After each provider response, before the agent loop, transcript, and channels see it.
+   * While registered, replies are delivered whole instead of streamed.
+   */
+  afterModelResponse?: (
+    event: AgentMiddlewareModelResponseEvent,
+    ctx: PluginHookAgentContext,
+  ) =>
+    | AgentMiddlewareModelResponseResult
+    | void
+    | Promise<AgentMiddlewareModelResponseResult | void>;
+  /** Around each tool execution: call `next` (optionally with new params) or return a result. */
+  wrapToolCall?: (
+    event: AgentMiddlewareToolCallEvent,
+    next: (params: unknown) => Promise<AgentToolResult<unknown>>,
+    ctx: PluginHookToolContext,
+  ) => Promise<AgentToolResult<unknown>>;
+};
+
 export type OpenClawPluginChannelRegistration = {
   plugin: ChannelPlugin;
   dock?: ChannelDock;
@@ -274,6 +334,11 @@ export type OpenClawPluginApi = {
    * Use this for simple state-toggling or status commands that don't need AI reasoning.
    */
   registerCommand: (command: OpenClawPluginCommandDefinition) => void;
+  /**
+   * Register agent middleware that can rewrite provider requests and responses and wrap tool
+   * execution. Middleware runs in registration order; the first registered is outermost.
+   */
+  registerMiddleware: (middleware: OpenClawAgentMiddleware) => void;
   resolvePath: (input: string) => string;
   /** Register a lifecycle hook handler */
   on: <K extends PluginHookName>(
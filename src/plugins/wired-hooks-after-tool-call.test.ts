@@ -38,6 +38,7 @@ function createToolHandlerCtx(params: {
       toolMetaById: new Map<string, string | undefined>(),
       toolMetas: [] as Array<{ toolName?: string; meta?: string }>,
       toolSummaryById: new Set<string>(),
+      toolProgressById: new Map(),
       lastToolError: undefined,
       pendingMessagingTexts: new Map<string, string>(),
       pendingMessagingTargets: new Map<string, unknown>(),
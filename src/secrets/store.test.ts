@@ -0,0 +1,158 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it, vi } from "vitest";
+import {
+  clearKeyringSecretCache,
+  createSecretResolver,
+  listFileSecretNames,
+  readSecretsFile,
+  removeSecret,
+  SecretsFileError,
+  setSecret,
+  type KeyringExec,
+} from "./store.js";
+
+describe("secrets store", () => {
+  let tmpDir: string;
+  let file: string;
+
+  beforeEach(() => {
+    tmpDir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-secrets-"));
+    file = path.join(tmpDir, "secrets.json");
+  });
+
+  afterEach(() => {
+    fs.rmSync(tmpDir, { recursive: true, force: true });
+  });
+
+  it("writes the secrets file owner-only and lists names without values", () => {
+    setSecret({ name: "HASS_TOKEN", value: "abc", target: "file", config: { file } });
+    setSecret({ name: "BOT", value: "123:xyz", target: "file", config: { file } });
+    if (process.platform !== "win32") {
+      expect(fs.statSync(file).mode & 0o777).toBe(0o600);
+    }
+    expect(listFileSecretNames({ config: { file } })).toEqual(["BOT", "HASS_TOKEN"]);
+    expect(removeSecret({ name: "BOT", config: { file }, platform: "win32" })).toEqual([file]);
+    expect(readSecretsFile(file)).toEqual({ HASS_TOKEN: "abc" });
+  });
+
+  it.runIf(process.platform !== "win32")("refuses a secrets file other users can read", () => {
+    fs.writeFileSync(file, JSON.stringify({ BOT: "123" }), { mode: 0o644 });
+    fs.chmodSync(file, 0o644);
+    expect(() => readSecretsFile(file)).toThrow(SecretsFileError);
+    expect(() => readSecretsFile(file)).toThrow(`chmod 600 ${file}`);
+  });
+
+  it("tries sources in configured order", () => {
+    fs.writeFileSync(file, JSON.stringify({ BOT: "from-file", ONLY_FILE: "f" }), { mode: 0o600 });
+    const exec = vi.fn<KeyringExec>((_command, args) => {
+      if (args.includes("KEYRING_ONLY")) {
+        return "from-keyring\n";
+      }
+      throw new Error("not found");
+    });
+    const env = { BOT: "from-env" };
+
+    const defaults = createSecretResolver({ config: { file }, env, exec, platform: "linux" });
+    expect(defaults("BOT")).toEqual({ value: "from-env", source: "env" });
+    expect(defaults("ONLY_FILE")).toEqual({ value: "f", source: "file" });
+    expect(defaults("KEYRING_ONLY")).toEqual({ value: "from-keyring", source: "keyring" });
+    expect(defaults("NOWHERE")).toBeUndefined();
+    expect(exec).toHaveBeenCalledWith("secret-tool", [
+      "lookup",
+      "service",
+      "openclaw",
+      "account",
+      "KEYRING_ONLY",
+    ]);
+
+    const fileFirst = createSecretResolver({
+      config: { file, sources: ["file", "env"] },
+      env,
+      exec,
+      platform: "linux",
+    });
+    expect(fileFirst("BOT")).toEqual({ value: "from-file", source: "file" });
+    expect(fileFirst("KEYRING_ONLY")).toBeUndefined();
+  });
+
+  it("asks the keyring once per name for the process lifetime", () => {
+    const exec = vi.fn<KeyringExec>(() => "token\n");
+    const config = { sources: ["keyring" as const] };
+    expect(createSecretResolver({ config, exec, platform: "linux" })("BOT")?.value).toBe("token");
+    // A new resolver per config read must not exec again.
+    expect(createSecretResolver({ config, exec, platform: "linux" })("BOT")?.value).toBe("token");
+    expect(exec).toHaveBeenCalledTimes(1);
+
+    setSecret({ name: "BOT", value: "next", target: "keyring", exec, platform: "linux" });
+    createSecretResolver({ config, exec, platform: "linux" })("BOT");
+    expect(exec).toHaveBeenCalledTimes(3);
+
+    clearKeyringSecretCache(exec);
+    createSecretResolver({ config, exec, platform: "linux" })("BOT");
+    expect(exec).toHaveBeenCalledTimes(4);
+  });
+
+  it("retries a keyring that failed (for example locked at boot) after a short delay", () => {
+    vi.useFakeTimers();
+    try {
+      const exec = vi.fn<KeyringExec>(() => {
+        throw new Error("User interaction is not allowed.");
+      });
+      const config = { sources: ["keyring" as const] };
+      const resolve = () => createSecretResolver({ config, exec, platform: "darwin" })("BOT");
+      expect(resolve()).toBeUndefined();
+      expect(resolve()).toBeUndefined();
+      expect(exec).toHaveBeenCalledTimes(1);
+
+      exec.mockReturnValue("unlocked\n");
+      vi.advanceTimersByTime(31_000);
+      expect(resolve()?.value).toBe("unlocked");
+      vi.advanceTimersByTime(60 * 60_000);
+      expect(resolve()?.value).toBe("unlocked");
+      expect(exec).toHaveBeenCalledTimes(2);
+    } finally {
+      vi.useRealTimers();
+    }
+  });
+
+  it("stores keyring secrets without putting the value in argv on linux", () => {
+    const exec = vi.fn<KeyringExec>(() => "");
+    setSecret({
+      name: "BOT",
+      value: "s3cret",
+      target: "keyring",
+      config: { keyringService: "home" },
+      exec,
+      platform: "linux",
+    });
+    expect(exec).toHaveBeenCalledWith(
+      "secret-tool",
+      ["store", "--label", "OpenClaw BOT", "service", "home", "account", "BOT"],
+      "s3cret",
+    );
+    expect(() =>
+      setSecret({ name: "BOT", value: "x", target: "keyring", exec, platform: "win32" }),
+    ).toThrow("No OS keyring support");
+  });
+
+  it("sends the keychain value to security on stdin on macOS", () => {
+    const exec = vi.fn<KeyringExec>(() => "");
+    setSecret({ name: "BOT", value: 'pa ss"\\', target: "keyring", exec, platform: "darwin" });
+    expect(exec).toHaveBeenCalledWith(
+      "security",
+      ["-i"],
+      '"add-generic-password" "-U" "-s" "openclaw" "-a" "BOT" "-w" "pa ss\\"\\\\"\n',
+    );
+    expect(() =>
+      setSecret({ name: "BOT", value: "a\nb", target: "keyring", exec, platform: "darwin" }),
+    ).toThrow("cannot contain line breaks");
+  });
+
+  it("rejects names that can't be referenced from config", () => {
+    expect(() =>
+      setSecret({ name: "has space", value: "x", target: "file", config: { file } }),
+    ).toThrow("Invalid secret name");
+  });
+});
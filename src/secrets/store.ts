@@ -0,0 +1,295 @@
+import { execFileSync } from "node:child_process";
+import fs from "node:fs";
+import path from "node:path";
+import { resolveStateDir } from "../config/paths.js";
+import type { SecretSource, SecretsConfig } from "../config/types.secrets.js";
+import { resolveUserPath } from "../utils.js";
+
+export const DEFAULT_SECRET_SOURCES: readonly SecretSource[] = ["env", "file", "keyring"];
+export const DEFAULT_KEYRING_SERVICE = "openclaw";
+export const SECRET_NAME_PATTERN = /^[A-Za-z0-9_.-]+$/;
+
+const KEYRING_TIMEOUT_MS = 5_000;
+// A locked keyring (common right after boot) fails like a missing entry; retry soon after.
+const KEYRING_MISS_TTL_MS = 30_000;
+
+/** Runs a keyring CLI (`security` / `secret-tool`) and returns stdout; throws on failure. */
+export type KeyringExec = (command: string, args: string[], input?: string) => string;
+
+export type SecretStoreOptions = {
+  config?: SecretsConfig;
+  env?: NodeJS.ProcessEnv;
+  exec?: KeyringExec;
+  platform?: NodeJS.Platform;
+};
+
+export class SecretsFileError extends Error {
+  constructor(message: string) {
+    super(message);
+    this.name = "SecretsFileError";
+  }
+}
+
+// Keyring CLIs are synchronous and can take seconds, and config is re-read often (short cache,
+// every write), so found values are cached per exec for the process lifetime (SIGHUP clears it).
+// Misses, failures, and timeouts are only cached for KEYRING_MISS_TTL_MS.
+const keyringCache = new WeakMap<
+  KeyringExec,
+  Map<string, { value: string } | { missUntilMs: number }>
+>();
+
+const defaultExec: KeyringExec = (command, args, input) =>
+  execFileSync(command, args, {
+    encoding: "utf8",
+    input,
+    stdio: ["pipe", "pipe", "pipe"],
+    timeout: KEYRING_TIMEOUT_MS,
+  });
+
+/** Drops cached keyring lookups so the next config read asks the keyring again. */
+export function clearKeyringSecretCache(exec: KeyringExec = defaultExec): void {
+  keyringCache.delete(exec);
+}
+
+export function resolveSecretsFilePath(
+  config?: SecretsConfig,
+  env: NodeJS.ProcessEnv = process.env,
+): string {
+  const configured = config?.file?.trim();
+  return configured
+    ? resolveUserPath(configured)
+    : path.join(resolveStateDir(env), "secrets.json");
+}
+
+export function resolveSecretSources(config?: SecretsConfig): readonly SecretSource[] {
+  return config?.sources?.length ? config.sources : DEFAULT_SECRET_SOURCES;
+}
+
+export function isKeyringSupported(platform: NodeJS.Platform = process.platform): boolean {
+  return platform === "darwin" || platform === "linux";
+}
+
+/** Reads the secrets file; refuses files that group or others can read. Missing file → `{}`. */
+export function readSecretsFile(
+  filePath: string,
+  platform: NodeJS.Platform = process.platform,
+): Record<string, string> {
+  let stat: fs.Stats;
+  try {
+    stat = fs.statSync(filePath);
+  } catch (err) {
+    if ((err as NodeJS.ErrnoException).code === "ENOENT") {
+      return {};
+    }
+    throw err;
+  }
+  if (platform !== "win32" && (stat.mode & 0o077) !== 0) {
+    const mode = (stat.mode & 0o777).toString(8);
+    throw new SecretsFileError(
+      `Secrets file ${filePath} is accessible by other users (mode ${mode}); run: chmod 600 ${filePath}`,
+    );
+  }
+  let parsed: unknown;
+  try {
+    parsed = JSON.parse(fs.readFileSync(filePath, "utf-8"));
+  } catch (err) {
+    throw new SecretsFileError(`Secrets file ${filePath} is not valid JSON: ${String(err)}`);
+  }
+  if (!parsed || typeof parsed !== "object" || Array.isArray(parsed)) {
+    throw new SecretsFileError(`Secrets file ${filePath} must be a JSON object of name → value`);
+  }
+  const secrets: Record<string, string> = {};
+  for (const [name, value] of Object.entries(parsed)) {
+    if (typeof value === "string") {
+      secrets[name] = value;
+    }
+  }
+  return secrets;
+}
+
+function writeSecretsFile(filePath: string, secrets: Record<string, string>): void {
+  fs.mkdirSync(path.dirname(filePath), { recursive: true, mode: 0o700 });
+  const tmp = `${filePath}.${process.pid}.tmp`;
+  fs.writeFileSync(tmp, `${JSON.stringify(secrets, null, 2)}\n`, { mode: 0o600 });
+  fs.renameSync(tmp, filePath);
+  fs.chmodSync(filePath, 0o600);
+}
+
+function keyringCommand(
+  platform: NodeJS.Platform,
+  action: "get" | "set" | "delete",
+  service: string,
+  name: string,
+): { command: string; args: string[] } {
+  if (platform === "darwin") {
+    const base = ["-s", service, "-a", name];
+    if (action === "get") {
+      return { command: "security", args: ["find-generic-password", ...base, "-w"] };
+    }
+    if (action === "delete") {
+      return { command: "security", args: ["delete-generic-password", ...base] };
+    }
+    return { command: "security", args: ["add-generic-password", "-U", ...base] };
+  }
+  if (platform === "linux") {
+    const attrs = ["service", service, "account", name];
+    if (action === "get") {
+      return { command: "secret-tool", args: ["lookup", ...attrs] };
+    }
+    if (action === "delete") {
+      return { command: "secret-tool", args: ["clear", ...attrs] };
+    }
+    return { command: "secret-tool", args: ["store", "--label", `OpenClaw ${name}`, ...attrs] };
+  }
+  throw new Error(`No OS keyring support on ${platform}; use the secrets file instead.`);
+}
+
+/** Quotes one argument for `security -i`, which splits lines on whitespace and double quotes. */
+function quoteSecurityArg(value: string): string {
+  if (/[\r\n]/.test(value) || value.includes("\0")) {
+    throw new Error("Keychain values cannot contain line breaks; use the secrets file instead.");
+  }
+  return `"${value.replace(/["\\]/g, "\\$&")}"`;
+}
+
+function readKeyringSecret(
+  name: string,
+  options: Required<Pick<SecretStoreOptions, "exec" | "platform">> & { service: string },
+): string | undefined {
+  if (!isKeyringSupported(options.platform)) {
+    return undefined;
+  }
+  let cache = keyringCache.get(options.exec);
+  if (!cache) {
+    cache = new Map();
+    keyringCache.set(options.exec, cache);
+  }
+  const key = `${options.platform}\u0000${options.service}\u0000${name}`;
+  const cached = cache.get(key);
+  if (cached && ("value" in cached || Date.now() < cached.missUntilMs)) {
+    return "value" in cached ? cached.value : undefined;
+  }
+  let value: string | undefined;
+  try {
+    const { command, args } = keyringCommand(options.platform, "get", options.service, name);
+    value = options.exec(command, args).replace(/\r?\n$/, "") || undefined;
+  } catch {
+    value = undefined;
+  }
+  cache.set(
+    key,
+    value === undefined ? { missUntilMs: Date.now() + KEYRING_MISS_TTL_MS } : { value },
+  );
+  return value;
+}
+
+/**
+ * Returns a lookup for `${secret:NAME}` config references. Sources are tried in
+ * `secrets.sources` order; the secrets file is read at most once per resolver, keyring
+ * entries at most once per process once found (see `clearKeyringSecretCache`).
+ */
+export function createSecretResolver(
+  options: SecretStoreOptions = {},
+): (name: string) => { value: string; source: SecretSource } | undefined {
+  const env = options.env ?? process.env;
+  const platform = options.platform ?? process.platform;
+  const exec = options.exec ?? defaultExec;
+  const service = options.config?.keyringService?.trim() || DEFAULT_KEYRING_SERVICE;
+  let fileSecrets: Record<string, string> | null = null;
+  const cache = new Map<string, { value: string; source: SecretSource } | undefined>();
+
+  const lookup = (source: SecretSource, name: string): string | undefined => {
+    switch (source) {
+      case "env":
+        return env[name] || undefined;
+      case "file":
+        fileSecrets ??= readSecretsFile(resolveSecretsFilePath(options.config, env), platform);
+        return fileSecrets[name] || undefined;
+      case "keyring":
+        return readKeyringSecret(name, { exec, platform, service });
+      default:
+        return undefined;
+    }
+  };
+
+  return (name) => {
+    if (cache.has(name)) {
+      return cache.get(name);
+    }
+    let found: { value: string; source: SecretSource } | undefined;
+    for (const source of resolveSecretSources(options.config)) {
+      const value = lookup(source, name);
+      if (value !== undefined) {
+        found = { value, source };
+        break;
+      }
+    }
+    cache.set(name, found);
+    return found;
+  };
+}
+
+function assertSecretName(name: string): void {
+  if (!SECRET_NAME_PATTERN.test(name)) {
+    throw new Error(`Invalid secret name "${name}" (use letters, digits, "_", "-", or ".")`);
+  }
+}
+
+/** Stores a secret in the secrets file (default) or the OS keyring. */
+export function setSecret(
+  params: SecretStoreOptions & { name: string; value: string; target: "file" | "keyring" },
+): string {
+  assertSecretName(params.name);
+  const platform = params.platform ?? process.platform;
+  if (params.target === "keyring") {
+    const service = params.config?.keyringService?.trim() || DEFAULT_KEYRING_SERVICE;
+    const { command, args } = keyringCommand(platform, "set", service, params.name);
+    const exec = params.exec ?? defaultExec;
+    if (platform === "darwin") {
+      // `security` only takes the password as an argument, so send the whole command to its
+      // interactive mode on stdin; the value never shows up in another process's argv.
+      exec(command, ["-i"], `${[...args, "-w", params.value].map(quoteSecurityArg).join(" ")}\n`);
+    } else {
+      exec(command, args, params.value);
+    }
+    clearKeyringSecretCache(exec);
+    return `keyring service "${service}"`;
+  }
+  const filePath = resolveSecretsFilePath(params.config, params.env);
+  const secrets = readSecretsFile(filePath, platform);
+  writeSecretsFile(filePath, { ...secrets, [params.name]: params.value });
+  return filePath;
+}
+
+/** Removes a secret from the file and the keyring. Returns where it was found. */
+export function removeSecret(params: SecretStoreOptions & { name: string }): string[] {
+  assertSecretName(params.name);
+  const platform = params.platform ?? process.platform;
+  const removed: string[] = [];
+  const filePath = resolveSecretsFilePath(params.config, params.env);
+  const secrets = readSecretsFile(filePath, platform);
+  if (params.name in secrets) {
+    delete secrets[params.name];
+    writeSecretsFile(filePath, secrets);
+    removed.push(filePath);
+  }
+  if (isKeyringSupported(platform)) {
+    const service = params.config?.keyringService?.trim() || DEFAULT_KEYRING_SERVICE;
+    try {
+      const exec = params.exec ?? defaultExec;
+      const { command, args } = keyringCommand(platform, "delete", service, params.name);
+      exec(command, args);
+      clearKeyringSecretCache(exec);
+      removed.push(`keyring service "${service}"`);
+    } catch {
+      // Not in the keyring.
+    }
+  }
+  return removed;
+}
+
+/** Names stored in the secrets file (keyrings can't be enumerated by service portably). */
+export function listFileSecretNames(options: SecretStoreOptions = {}): string[] {
+  const filePath = resolveSecretsFilePath(options.config, options.env);
+  return Object.keys(readSecretsFile(filePath, options.platform ?? process.platform)).toSorted();
+}
@@ -528,13 +528,16 @@ export function collectGatewayHttpSessionKeyOverrideFindings(
 ): SecurityAuditFinding[] {
   const findings: SecurityAuditFinding[] = [];
   const chatCompletionsEnabled = cfg.gateway?.http?.endpoints?.chatCompletions?.enabled === true;
+  const chatEnabled = cfg.gateway?.http?.endpoints?.chat?.enabled === true;
   const responsesEnabled = cfg.gateway?.http?.endpoints?.responses?.enabled === true;
-  if (!chatCompletionsEnabled && !responsesEnabled) {
+  if (!chatCompletionsEnabled && !chatEnabled && !responsesEnabled) {
     return findings;
   }
 
   const enabledEndpoints = [
     chatCompletionsEnabled ? "/v1/chat/completions" : null,
+    chatEnabled ? "/v1/chat" : null,
+    chatEnabled ? "/v1/chat/ws" : null,
     responsesEnabled ? "/v1/responses" : null,
   ].filter((entry): entry is string => Boolean(entry));
 
@@ -562,10 +565,14 @@ export function collectGatewayHttpNoAuthFindings(
   }
 
   const chatCompletionsEnabled = cfg.gateway?.http?.endpoints?.chatCompletions?.enabled === true;
+  const chatEnabled = cfg.gateway?.http?.endpoints?.chat?.enabled === true;
   const responsesEnabled = cfg.gateway?.http?.endpoints?.responses?.enabled === true;
   const enabledEndpoints = [
     "/tools/invoke",
+    "/v1/tools/:name",
     chatCompletionsEnabled ? "/v1/chat/completions" : null,
+    chatEnabled ? "/v1/chat" : null,
+    chatEnabled ? "/v1/chat/ws" : null,
     responsesEnabled ? "/v1/responses" : null,
   ].filter((entry): entry is string => Boolean(entry));
 
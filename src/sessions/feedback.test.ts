@@ -0,0 +1,116 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import {
+  loadFeedback,
+  parseFeedbackRating,
+  readLastTurnTrace,
+  recordFeedback,
+  resolveFeedbackPath,
+} from "./feedback.js";
+
+function transcriptLines(): string {
+  const messages = [
+    { role: "user", content: "old question" },
+    { role: "assistant", content: [{ type: "text", text: "old answer" }] },
+    { role: "user", content: [{ type: "text", text: "Is the greenhouse too warm?" }] },
+    {
+      role: "assistant",
+      content: [
+        { type: "text", text: "Checking." },
+        { type: "toolCall", id: "call-1", name: "read_sensor", arguments: { id: "temp" } },
+      ],
+    },
+    {
+      role: "toolResult",
+      toolCallId: "call-1",
+      content: [{ type: "text", text: "31.5" }],
+      isError: false,
+    },
+    { role: "assistant", content: [{ type: "text", text: "Yes, it is 31.5°C." }] },
+  ];
+  return `${[
+    JSON.stringify({ type: "session", id: "sess-1" }),
+    ...messages.map((message) => JSON.stringify({ type: "message", message })),
+  ].join("\n")}\n`;
+}
+
+describe("feedback", () => {
+  let dir = "";
+  let storePath = "";
+
+  beforeEach(() => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-feedback-"));
+    storePath = path.join(dir, "sessions.json");
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("parses ratings from words, signs, and emoji", () => {
+    expect(parseFeedbackRating("UP")).toBe("up");
+    expect(parseFeedbackRating("+1")).toBe("up");
+    expect(parseFeedbackRating("👍🏽")).toBe("up");
+    expect(parseFeedbackRating("bad")).toBe("down");
+    expect(parseFeedbackRating("👎")).toBe("down");
+    expect(parseFeedbackRating("🔥")).toBeUndefined();
+    expect(parseFeedbackRating(undefined)).toBeUndefined();
+  });
+
+  it("reads the last turn with its tool calls", async () => {
+    const file = path.join(dir, "sess-1.jsonl");
+    fs.writeFileSync(file, transcriptLines());
+
+    expect(await readLastTurnTrace(file)).toEqual({
+      prompt: "Is the greenhouse too warm?",
+      reply: "Yes, it is 31.5°C.",
+      tools: [{ name: "read_sensor", args: { id: "temp" }, isError: false, result: "31.5" }],
+    });
+    expect(await readLastTurnTrace(path.join(dir, "missing.jsonl"))).toEqual({});
+  });
+
+  it("records ratings with the turn trace and loads them newest first", async () => {
+    fs.writeFileSync(path.join(dir, "sess-1.jsonl"), transcriptLines());
+    fs.writeFileSync(
+      storePath,
+      JSON.stringify({ "agent:main:main": { sessionId: "sess-1", updatedAt: 1 } }),
+    );
+
+    const first = await recordFeedback({
+      storePath,
+      sessionKey: "agent:main:main",
+      rating: "down",
+      source: "command",
+      note: "  wrong unit  ",
+      channel: "telegram",
+      now: 1000,
+    });
+    await recordFeedback({
+      storePath,
+      sessionKey: "agent:main:other",
+      rating: "up",
+      source: "reaction",
+      messageId: "42",
+      now: 2000,
+    });
+
+    expect(first).toMatchObject({
+      ts: 1000,
+      rating: "down",
+      sessionId: "sess-1",
+      note: "wrong unit",
+      prompt: "Is the greenhouse too warm?",
+      tools: [{ name: "read_sensor" }],
+    });
+    expect(fs.existsSync(resolveFeedbackPath(storePath))).toBe(true);
+    expect(loadFeedback(storePath).map((record) => record.ts)).toEqual([2000, 1000]);
+    expect(loadFeedback(storePath, { rating: "down" })).toHaveLength(1);
+    expect(loadFeedback(storePath, { sessionKey: "agent:main:other" })[0]).toMatchObject({
+      source: "reaction",
+      messageId: "42",
+    });
+    expect(loadFeedback(storePath, { limit: 1 })).toHaveLength(1);
+  });
+});
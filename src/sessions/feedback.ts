@@ -0,0 +1,246 @@
+import fs from "node:fs";
+import path from "node:path";
+import {
+  loadSessionStore,
+  resolveSessionFilePath,
+  resolveSessionFilePathOptions,
+} from "../config/sessions.js";
+
+export type FeedbackRating = "up" | "down";
+
+export type FeedbackToolStep = {
+  name: string;
+  args?: unknown;
+  isError?: boolean;
+  result?: string;
+};
+
+/** One thumbs-up/down entry in `feedback.jsonl`, next to the agent's session store. */
+export type FeedbackRecord = {
+  ts: number;
+  rating: FeedbackRating;
+  sessionKey: string;
+  sessionId?: string;
+  note?: string;
+  source: "command" | "reaction";
+  channel?: string;
+  messageId?: string;
+  /** User message that started the rated turn. */
+  prompt?: string;
+  /** Final assistant text of the rated turn. */
+  reply?: string;
+  tools?: FeedbackToolStep[];
+};
+
+type TranscriptToolCall = { type?: unknown; id?: unknown; name?: unknown; arguments?: unknown };
+
+const FEEDBACK_FILE = "feedback.jsonl";
+const MAX_TEXT_CHARS = 4000;
+const MAX_TOOL_RESULT_CHARS = 500;
+
+const RATING_ALIASES: Record<string, FeedbackRating> = {
+  up: "up",
+  good: "up",
+  "+": "up",
+  "+1": "up",
+  "👍": "up",
+  down: "down",
+  bad: "down",
+  "-": "down",
+  "-1": "down",
+  "👎": "down",
+};
+
+/** Accepts up/down, good/bad, +1/-1, and 👍/👎 (any skin tone). */
+export function parseFeedbackRating(raw: string | undefined): FeedbackRating | undefined {
+  const normalized = raw
+    ?.trim()
+    .toLowerCase()
+    .replace(/[\uFE0F\u{1F3FB}-\u{1F3FF}]/gu, "");
+  return normalized ? RATING_ALIASES[normalized] : undefined;
+}
+
+export function resolveFeedbackPath(storePath: string): string {
+  return path.join(path.dirname(storePath), FEEDBACK_FILE);
+}
+
+function truncate(text: string, maxChars: number): string {
+  return text.length > maxChars ? `${text.slice(0, maxChars - 1)}…` : text;
+}
+
+function joinText(content: unknown): string {
+  if (typeof content === "string") {
+    return content.trim();
+  }
+  if (!Array.isArray(content)) {
+    return "";
+  }
+  return content
+    .map((block) =>
+      block && typeof block === "object" && (block as { type?: unknown }).type === "text"
+        ? String((block as { text?: unknown }).text ?? "")
+        : "",
+    )
+    .join("")
+    .trim();
+}
+
+/**
+ * The last turn of a transcript: the user message that started it, the final assistant text,
+ * and every tool call in between with its (truncated) result.
+ */
+export async function readLastTurnTrace(
+  sessionFile: string,
+): Promise<Pick<FeedbackRecord, "prompt" | "reply" | "tools">> {
+  let content: string;
+  try {
+    content = await fs.promises.readFile(sessionFile, "utf-8");
+  } catch {
+    return {};
+  }
+  const messages: Array<Record<string, unknown>> = [];
+  for (const line of content.split("\n")) {
+    if (!line.trim()) {
+      continue;
+    }
+    try {
+      const parsed = JSON.parse(line) as { type?: unknown; message?: unknown };
+      if (parsed?.type === "message" && parsed.message && typeof parsed.message === "object") {
+        messages.push(parsed.message as Record<string, unknown>);
+      }
+    } catch {
+      // ignore bad lines
+    }
+  }
+  const start = messages.findLastIndex((message) => message.role === "user");
+  if (start < 0) {
+    return {};
+  }
+  const prompt = joinText(messages[start]?.content);
+  let reply = "";
+  const tools: FeedbackToolStep[] = [];
+  const toolsById = new Map<string, FeedbackToolStep>();
+  for (const message of messages.slice(start + 1)) {
+    if (message.role === "assistant") {
+      const text = joinText(message.content);
+      if (text) {
+        reply = text;
+      }
+      for (const block of Array.isArray(message.content) ? message.content : []) {
+        const call = block as TranscriptToolCall;
+        if (call?.type !== "toolCall" || typeof call.name !== "string") {
+          continue;
+        }
+        const step: FeedbackToolStep = { name: call.name, args: call.arguments };
+        tools.push(step);
+        if (typeof call.id === "string") {
+          toolsById.set(call.id, step);
+        }
+      }
+    } else if (message.role === "toolResult") {
+      const step =
+        typeof message.toolCallId === "string" ? toolsById.get(message.toolCallId) : undefined;
+      if (step) {
+        step.isError = message.isError === true;
+        step.result = truncate(joinText(message.content), MAX_TOOL_RESULT_CHARS);
+      }
+    }
+  }
+  return {
+    ...(prompt ? { prompt: truncate(prompt, MAX_TEXT_CHARS) } : {}),
+    ...(reply ? { reply: truncate(reply, MAX_TEXT_CHARS) } : {}),
+    ...(tools.length > 0 ? { tools } : {}),
+  };
+}
+
+function resolveSessionTranscript(params: {
+  storePath: string;
+  sessionKey: string;
+  agentId?: string;
+}): { sessionId?: string; sessionFile?: string } {
+  const entry = loadSessionStore(params.storePath)[params.sessionKey];
+  if (!entry?.sessionId) {
+    return {};
+  }
+  try {
+    const sessionFile = resolveSessionFilePath(
+      entry.sessionId,
+      entry,
+      resolveSessionFilePathOptions({ agentId: params.agentId, storePath: params.storePath }),
+    );
+    return { sessionId: entry.sessionId, sessionFile };
+  } catch {
+    return { sessionId: entry.sessionId };
+  }
+}
+
+/**
+ * Rate the session's latest turn. The prompt, reply, and tool trace are copied into the record,
+ * so ratings stay readable after the session is reset or compacted.
+ */
+export async function recordFeedback(params: {
+  storePath: string;
+  sessionKey: string;
+  agentId?: string;
+  rating: FeedbackRating;
+  source: FeedbackRecord["source"];
+  note?: string;
+  channel?: string;
+  messageId?: string;
+  now?: number;
+}): Promise<FeedbackRecord> {
+  const { sessionId, sessionFile } = resolveSessionTranscript(params);
+  const trace = sessionFile ? await readLastTurnTrace(sessionFile) : {};
+  const note = params.note?.trim();
+  const record: FeedbackRecord = {
+    ts: params.now ?? Date.now(),
+    rating: params.rating,
+    sessionKey: params.sessionKey,
+    ...(sessionId ? { sessionId } : {}),
+    ...(note ? { note } : {}),
+    source: params.source,
+    ...(params.channel ? { channel: params.channel } : {}),
+    ...(params.messageId ? { messageId: params.messageId } : {}),
+    ...trace,
+  };
+  const filePath = resolveFeedbackPath(params.storePath);
+  await fs.promises.mkdir(path.dirname(filePath), { recursive: true });
+  await fs.promises.appendFile(filePath, `${JSON.stringify(record)}\n`, {
+    encoding: "utf-8",
+    mode: 0o600,
+  });
+  return record;
+}
+
+/** Feedback records, newest first. */
+export function loadFeedback(
+  storePath: string,
+  opts?: { rating?: FeedbackRating; sessionKey?: string; limit?: number },
+): FeedbackRecord[] {
+  let content: string;
+  try {
+    content = fs.readFileSync(resolveFeedbackPath(storePath), "utf-8");
+  } catch {
+    return [];
+  }
+  const records: FeedbackRecord[] = [];
+  for (const line of content.split("\n")) {
+    if (!line.trim()) {
+      continue;
+    }
+    try {
+      const record = JSON.parse(line) as FeedbackRecord;
+      if (opts?.rating && record.rating !== opts.rating) {
+        continue;
+      }
+      if (opts?.sessionKey && record.sessionKey !== opts.sessionKey) {
+        continue;
+      }
+      records.push(record);
+    } catch {
+      // ignore bad lines
+    }
+  }
+  records.reverse();
+  return opts?.limit !== undefined ? records.slice(0, opts.limit) : records;
+}
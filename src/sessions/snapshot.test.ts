@@ -0,0 +1,131 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { addTask, listTasks } from "../tasks/store.js";
+import {
+  createSessionSnapshot,
+  parseSessionSnapshot,
+  restoreSessionSnapshot,
+} from "./snapshot.js";
+
+const SESSION_KEY = "agent:main:main";
+const TRANSCRIPT = `${JSON.stringify({ type: "session", id: "sess-1" })}\n${JSON.stringify({
+  type: "message",
+  message: { role: "user", content: "flash the board tonight" },
+})}\n`;
+
+describe("session snapshots", () => {
+  let dir = "";
+  let source: { storePath: string; workspaceDir: string; taskStorePath: string };
+  let target: typeof source;
+
+  const setupMachine = (name: string) => {
+    const root = path.join(dir, name);
+    fs.mkdirSync(path.join(root, "sessions"), { recursive: true });
+    fs.mkdirSync(path.join(root, "workspace", "memory"), { recursive: true });
+    return {
+      storePath: path.join(root, "sessions", "sessions.json"),
+      workspaceDir: path.join(root, "workspace"),
+      taskStorePath: path.join(root, "tasks.json"),
+    };
+  };
+
+  beforeEach(async () => {
+    dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-snapshot-"));
+    source = setupMachine("a");
+    target = setupMachine("b");
+    fs.writeFileSync(path.join(path.dirname(source.storePath), "sess-1.jsonl"), TRANSCRIPT);
+    fs.writeFileSync(
+      source.storePath,
+      JSON.stringify({
+        [SESSION_KEY]: {
+          sessionId: "sess-1",
+          updatedAt: 1,
+          sessionFile: "sess-1.jsonl",
+          modelOverride: "claude-opus-4-5",
+        },
+      }),
+    );
+    fs.writeFileSync(path.join(source.workspaceDir, "MEMORY.md"), "Board: esp32-s3\n");
+    fs.writeFileSync(path.join(source.workspaceDir, "memory", "2026-10-01.md"), "fw v2.0\n");
+    await addTask(source.taskStorePath, {
+      agentId: "main",
+      title: "flash v2.1",
+      priority: "high",
+      sessionKey: SESSION_KEY,
+      reminderJobId: "job-1",
+    });
+  });
+
+  afterEach(() => {
+    fs.rmSync(dir, { recursive: true, force: true });
+  });
+
+  it("round-trips history, memory refs, and open tasks to another machine", async () => {
+    const snapshot = await createSessionSnapshot({
+      ...source,
+      agentId: "main",
+      sessionKey: SESSION_KEY,
+    });
+    expect(snapshot.entry).not.toHaveProperty("sessionFile");
+    expect(snapshot.memory.map((ref) => ref.path).toSorted()).toEqual([
+      "MEMORY.md",
+      "memory/2026-10-01.md",
+    ]);
+    fs.writeFileSync(path.join(target.workspaceDir, "MEMORY.md"), "Board: esp32-s3\n");
+    fs.writeFileSync(path.join(target.workspaceDir, "memory", "2026-10-01.md"), "fw v1.9\n");
+
+    const result = await restoreSessionSnapshot({
+      ...target,
+      agentId: "main",
+      snapshot: parseSessionSnapshot(JSON.stringify(snapshot)),
+    });
+
+    expect(fs.readFileSync(result.transcriptPath, "utf-8")).toBe(TRANSCRIPT);
+    const store = JSON.parse(fs.readFileSync(target.storePath, "utf-8"));
+    expect(store[SESSION_KEY]).toMatchObject({
+      sessionId: "sess-1",
+      modelOverride: "claude-opus-4-5",
+    });
+    expect(result.memory.toSorted((a, b) => a.path.localeCompare(b.path))).toEqual([
+      { path: "MEMORY.md", status: "ok" },
+      { path: "memory/2026-10-01.md", status: "changed" },
+    ]);
+    expect(result.tasksRestored).toBe(1);
+    const [task] = await listTasks(target.taskStorePath, { agentId: "main" });
+    expect(task).toMatchObject({ title: "flash v2.1", sessionKey: SESSION_KEY });
+    expect(task).not.toHaveProperty("reminderJobId");
+  });
+
+  it("refuses to overwrite an existing session without force", async () => {
+    const snapshot = await createSessionSnapshot({
+      ...source,
+      agentId: "main",
+      sessionKey: SESSION_KEY,
+    });
+
+    await expect(restoreSessionSnapshot({ ...source, agentId: "main", snapshot })).rejects.toThrow(
+      "Session already exists",
+    );
+    const copy = await restoreSessionSnapshot({
+      ...source,
+      agentId: "main",
+      snapshot,
+      sessionKey: "agent:main:restored",
+    });
+    expect(copy.sessionKey).toBe("agent:main:restored");
+    expect(copy.tasksRestored).toBe(0);
+    expect(copy.sessionId).not.toBe("sess-1");
+    const original = path.join(path.dirname(source.storePath), "sess-1.jsonl");
+    expect(fs.readFileSync(original, "utf-8")).toBe(TRANSCRIPT);
+    expect(JSON.parse(fs.readFileSync(copy.transcriptPath, "utf-8").split("\n")[0])).toEqual({
+      type: "session",
+      id: copy.sessionId,
+    });
+    const store = JSON.parse(fs.readFileSync(source.storePath, "utf-8"));
+    expect(store["agent:main:restored"].sessionId).toBe(copy.sessionId);
+    expect(store[SESSION_KEY].sessionId).toBe("sess-1");
+    expect(() => parseSessionSnapshot('{"version":2}')).toThrow("Unsupported session snapshot");
+  });
+});
@@ -0,0 +1,209 @@
+import crypto from "node:crypto";
+import fs from "node:fs/promises";
+import path from "node:path";
+import {
+  loadSessionStore,
+  resolveSessionFilePath,
+  resolveSessionFilePathOptions,
+  resolveSessionTranscriptPathInDir,
+  type SessionEntry,
+  updateSessionStore,
+} from "../config/sessions.js";
+import { hashText, listMemoryFiles } from "../memory/internal.js";
+import { type AgentTask, importTasks, listTasks } from "../tasks/store.js";
+
+export const SESSION_SNAPSHOT_VERSION = 1;
+
+/** Memory files are referenced, not copied: the workspace is usually synced or versioned. */
+export type SessionSnapshotMemoryRef = {
+  path: string;
+  bytes: number;
+  sha256: string;
+};
+
+export type SessionSnapshot = {
+  version: typeof SESSION_SNAPSHOT_VERSION;
+  createdAt: number;
+  agentId: string;
+  sessionKey: string;
+  entry: SessionEntry;
+  /** Raw JSONL transcript (full history, including compaction entries). */
+  transcript: string;
+  memory: SessionSnapshotMemoryRef[];
+  /** Open tasks tied to the session. Their cron reminders are not carried over. */
+  tasks: AgentTask[];
+};
+
+export type SessionSnapshotRestoreResult = {
+  sessionKey: string;
+  sessionId: string;
+  transcriptPath: string;
+  memory: Array<{ path: string; status: "ok" | "missing" | "changed" }>;
+  tasksRestored: number;
+};
+
+/** Entry fields that only make sense on the machine/run that produced them. */
+function stripMachineLocalFields(entry: SessionEntry): SessionEntry {
+  const { sessionFile: _sessionFile, pendingPlan: _pendingPlan, ...rest } = entry;
+  return { ...rest, abortedLastRun: undefined };
+}
+
+async function readMemoryRefs(workspaceDir: string): Promise<SessionSnapshotMemoryRef[]> {
+  const files = await listMemoryFiles(workspaceDir);
+  const refs: SessionSnapshotMemoryRef[] = [];
+  for (const absPath of files) {
+    const content = await fs.readFile(absPath, "utf-8").catch(() => undefined);
+    if (content === undefined) {
+      continue;
+    }
+    refs.push({
+      path: path.relative(workspaceDir, absPath).split(path.sep).join("/"),
+      bytes: Buffer.byteLength(content),
+      sha256: hashText(content),
+    });
+  }
+  return refs;
+}
+
+export async function createSessionSnapshot(params: {
+  agentId: string;
+  sessionKey: string;
+  storePath: string;
+  workspaceDir: string;
+  taskStorePath: string;
+  now?: number;
+}): Promise<SessionSnapshot> {
+  const store = loadSessionStore(params.storePath, { skipCache: true });
+  const entry = store[params.sessionKey];
+  if (!entry?.sessionId) {
+    throw new Error(`Session not found: ${params.sessionKey}`);
+  }
+  const transcriptPath = resolveSessionFilePath(
+    entry.sessionId,
+    entry,
+    resolveSessionFilePathOptions({ agentId: params.agentId, storePath: params.storePath }),
+  );
+  const transcript = await fs.readFile(transcriptPath, "utf-8").catch(() => "");
+  const tasks = await listTasks(params.taskStorePath, { agentId: params.agentId });
+  return {
+    version: SESSION_SNAPSHOT_VERSION,
+    createdAt: params.now ?? Date.now(),
+    agentId: params.agentId,
+    sessionKey: params.sessionKey,
+    entry: stripMachineLocalFields(entry),
+    transcript,
+    memory: await readMemoryRefs(params.workspaceDir),
+    tasks: tasks.filter((task) => task.sessionKey === params.sessionKey),
+  };
+}
+
+export function parseSessionSnapshot(raw: string): SessionSnapshot {
+  const parsed = JSON.parse(raw) as Partial<SessionSnapshot>;
+  if (parsed.version !== SESSION_SNAPSHOT_VERSION) {
+    throw new Error(`Unsupported session snapshot version: ${String(parsed.version)}`);
+  }
+  if (
+    typeof parsed.sessionKey !== "string" ||
+    typeof parsed.agentId !== "string" ||
+    typeof parsed.entry?.sessionId !== "string" ||
+    typeof parsed.transcript !== "string"
+  ) {
+    throw new Error("Invalid session snapshot: missing session key, entry, or transcript");
+  }
+  return {
+    ...parsed,
+    memory: Array.isArray(parsed.memory) ? parsed.memory : [],
+    tasks: Array.isArray(parsed.tasks) ? parsed.tasks : [],
+  } as SessionSnapshot;
+}
+
+/** Point the transcript header at a freshly minted session id. */
+function retargetTranscript(transcript: string, sessionId: string): string {
+  const newline = transcript.indexOf("\n");
+  const firstLine = newline === -1 ? transcript : transcript.slice(0, newline);
+  try {
+    const header = JSON.parse(firstLine) as { type?: unknown; id?: unknown };
+    if (header?.type !== "session") {
+      return transcript;
+    }
+    const rest = newline === -1 ? "" : transcript.slice(newline);
+    return `${JSON.stringify({ ...header, id: sessionId })}${rest}`;
+  } catch {
+    return transcript;
+  }
+}
+
+async function fileExists(filePath: string): Promise<boolean> {
+  return fs.access(filePath).then(
+    () => true,
+    () => false,
+  );
+}
+
+/**
+ * Writes the transcript and session entry back. Restoring under another session key, or onto a
+ * transcript file owned by a different entry, mints a fresh session id so the copy never
+ * overwrites an existing conversation. Refuses to replace an existing session unless
+ * `force` is set; memory refs are only compared against the target workspace.
+ */
+export async function restoreSessionSnapshot(params: {
+  snapshot: SessionSnapshot;
+  agentId: string;
+  storePath: string;
+  workspaceDir: string;
+  taskStorePath: string;
+  sessionKey?: string;
+  force?: boolean;
+  now?: number;
+}): Promise<SessionSnapshotRestoreResult> {
+  const { snapshot } = params;
+  const sessionKey = params.sessionKey?.trim() || snapshot.sessionKey;
+  const sessionsDir = path.dirname(path.resolve(params.storePath));
+  let sessionId = snapshot.entry.sessionId;
+  let transcriptPath = resolveSessionTranscriptPathInDir(sessionId, sessionsDir);
+
+  await updateSessionStore(params.storePath, async (store) => {
+    if (store[sessionKey] && !params.force) {
+      throw new Error(`Session already exists: ${sessionKey} (use --force to replace it)`);
+    }
+    const ownedByTarget = store[sessionKey]?.sessionId === sessionId;
+    if (
+      sessionKey !== snapshot.sessionKey ||
+      (!ownedByTarget && (await fileExists(transcriptPath)))
+    ) {
+      sessionId = crypto.randomUUID();
+      transcriptPath = resolveSessionTranscriptPathInDir(sessionId, sessionsDir);
+    }
+    const transcript =
+      sessionId === snapshot.entry.sessionId
+        ? snapshot.transcript
+        : retargetTranscript(snapshot.transcript, sessionId);
+    await fs.mkdir(sessionsDir, { recursive: true });
+    await fs.writeFile(transcriptPath, transcript, { encoding: "utf-8", mode: 0o600 });
+    store[sessionKey] = {
+      ...stripMachineLocalFields(snapshot.entry),
+      sessionId,
+      updatedAt: params.now ?? Date.now(),
+    };
+  });
+
+  const memory: SessionSnapshotRestoreResult["memory"] = [];
+  for (const ref of snapshot.memory) {
+    const content = await fs
+      .readFile(path.join(params.workspaceDir, ref.path), "utf-8")
+      .catch(() => undefined);
+    const status =
+      content === undefined ? "missing" : hashText(content) === ref.sha256 ? "ok" : "changed";
+    memory.push({ path: ref.path, status });
+  }
+
+  const tasksRestored = await importTasks(
+    params.taskStorePath,
+    snapshot.tasks.map(({ reminderJobId: _reminderJobId, ...task }) => ({
+      ...task,
+      agentId: params.agentId,
+      sessionKey,
+    })),
+  );
+  return { sessionKey, sessionId, transcriptPath, memory, tasksRestored };
+}
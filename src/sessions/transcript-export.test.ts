@@ -0,0 +1,129 @@
+import { describe, expect, it } from "vitest";
+import type { OpenClawConfig } from "../config/config.js";
+import { buildTranscriptExport, renderTranscriptMarkdown } from "./transcript-export.js";
+
+const line = (value: unknown) => JSON.stringify(value);
+
+const TRANSCRIPT = [
+  line({ type: "session", id: "sess-1" }),
+  line({
+    type: "message",
+    timestamp: "2026-10-01T10:00:00.000Z",
+    message: {
+      role: "user",
+      content: "What does this scope trace show?\n[media attached: /tmp/scope.png (image/png)]",
+    },
+  }),
+  line({
+    type: "message",
+    message: {
+      role: "assistant",
+      model: "claude-opus-4-5",
+      content: [
+        { type: "thinking", thinking: "check the log first" },
+        { type: "toolCall", id: "call-1", name: "read", arguments: { path: "uart.log" } },
+      ],
+      usage: { input: 1000, output: 50, cost: { total: 0.02 } },
+    },
+  }),
+  line({
+    type: "message",
+    message: {
+      role: "toolResult",
+      toolCallId: "call-1",
+      content: [{ type: "text", text: "```\nbrownout at 3.1V\n```" }],
+      isError: false,
+    },
+  }),
+  line({
+    type: "message",
+    message: {
+      role: "assistant",
+      provider: "local",
+      model: "qwen",
+      content: [{ type: "text", text: "Brownout during flash.\nMEDIA:/tmp/annotated.png" }],
+      usage: { input: 2350, output: 100 },
+    },
+  }),
+  line({ type: "message", message: { role: "user", content: "thanks" } }),
+].join("\n");
+
+const config: OpenClawConfig = {
+  models: {
+    providers: {
+      local: {
+        baseUrl: "http://127.0.0.1:8080/v1",
+        models: [
+          {
+            id: "qwen",
+            name: "qwen",
+            reasoning: false,
+            input: ["text"],
+            cost: { input: 1, output: 10, cacheRead: 0, cacheWrite: 0 },
+            contextWindow: 32_000,
+            maxTokens: 4096,
+          },
+        ],
+      },
+    },
+  },
+};
+
+describe("transcript export", () => {
+  it("groups messages into turns with tool calls, images, and per-turn cost", () => {
+    const data = buildTranscriptExport({
+      transcript: TRANSCRIPT,
+      sessionKey: "agent:main:main",
+      sessionId: "sess-1",
+      config,
+      now: 0,
+    });
+
+    expect(data.turns).toHaveLength(2);
+    const [first, second] = data.turns;
+    expect(first.user).toEqual({
+      text: "What does this scope trace show?\n[media attached: /tmp/scope.png (image/png)]",
+      images: [{ kind: "file", path: "/tmp/scope.png" }],
+    });
+    expect(first.steps).toEqual([
+      { type: "toolCall", id: "call-1", name: "read", arguments: { path: "uart.log" } },
+      {
+        type: "toolResult",
+        id: "call-1",
+        name: "read",
+        isError: false,
+        text: "```\nbrownout at 3.1V\n```",
+      },
+      {
+        type: "assistant",
+        text: "Brownout during flash.",
+        model: "qwen",
+        images: [{ kind: "file", path: "/tmp/annotated.png" }],
+      },
+    ]);
+    expect(first.tokens.total).toBe(3500);
+    // 0.02 recorded + (2350 * 1 + 100 * 10) / 1e6 estimated from config
+    expect(first.costUsd).toBeCloseTo(0.02335);
+    expect(second.costUsd).toBeUndefined();
+    expect(data.totals).toEqual({ tokens: 3500, costUsd: first.costUsd });
+  });
+
+  it("renders Markdown with fenced tool calls and results", () => {
+    const markdown = renderTranscriptMarkdown(
+      buildTranscriptExport({
+        transcript: TRANSCRIPT,
+        sessionKey: "agent:main:main",
+        sessionId: "sess-1",
+        config,
+        now: 0,
+      }),
+    );
+
+    expect(markdown).toContain("# Session agent:main:main");
+    expect(markdown).toContain("## Turn 1 · 2026-10-01T10:00:00.000Z · 3.5k tokens · $0.02");
+    expect(markdown).toContain('**Tool call** `read`\n\n```json\n{\n  "path": "uart.log"\n}\n```');
+    expect(markdown).toContain("````\n```\nbrownout at 3.1V\n```\n````");
+    expect(markdown).toContain("Images: `/tmp/annotated.png`");
+    expect(markdown).not.toContain("check the log first");
+  });
+});
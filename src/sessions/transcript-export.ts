@@ -0,0 +1,313 @@
+import { detectImageReferences } from "../agents/pi-embedded-runner/run/images.js";
+import { normalizeUsage, type UsageLike } from "../agents/usage.js";
+import { stripInboundMetadata } from "../auto-reply/reply/strip-inbound-meta.js";
+import type { OpenClawConfig } from "../config/config.js";
+import { splitMediaFromOutput } from "../media/parse.js";
+import { stripEnvelope, stripMessageIdHints } from "../shared/chat-envelope.js";
+import {
+  estimateUsageCost,
+  formatCost,
+  formatTokenCount,
+  resolveCostDisplayCurrency,
+  resolveModelCostConfig,
+} from "../utils/usage-format.js";
+
+export const TRANSCRIPT_EXPORT_VERSION = 1;
+
+export type TranscriptExportFormat = "markdown" | "json";
+
+/** Inline images are summarized (type and size), not embedded; file references keep their path. */
+export type TranscriptExportImage =
+  | { kind: "inline"; mimeType?: string; bytes: number }
+  | { kind: "file"; path: string };
+
+export type TranscriptExportStep =
+  | { type: "assistant"; text: string; model?: string; images?: TranscriptExportImage[] }
+  | { type: "toolCall"; id?: string; name: string; arguments: unknown }
+  | {
+      type: "toolResult";
+      id?: string;
+      name: string;
+      isError: boolean;
+      text: string;
+      images?: TranscriptExportImage[];
+    };
+
+export type TranscriptExportTurn = {
+  index: number;
+  timestamp?: string;
+  user?: { text: string; images?: TranscriptExportImage[] };
+  steps: TranscriptExportStep[];
+  tokens: { input: number; output: number; cacheRead: number; cacheWrite: number; total: number };
+  /** USD; undefined when no assistant message in the turn has a known price. */
+  costUsd?: number;
+};
+
+export type TranscriptExport = {
+  version: typeof TRANSCRIPT_EXPORT_VERSION;
+  exportedAt: string;
+  sessionKey: string;
+  sessionId: string;
+  turns: TranscriptExportTurn[];
+  totals: { tokens: number; costUsd?: number };
+};
+
+type ContentBlock = Record<string, unknown>;
+
+function contentBlocks(content: unknown): ContentBlock[] {
+  if (typeof content === "string") {
+    return [{ type: "text", text: content }];
+  }
+  return Array.isArray(content)
+    ? content.filter((block): block is ContentBlock => Boolean(block) && typeof block === "object")
+    : [];
+}
+
+function blockText(blocks: ContentBlock[]): string {
+  return blocks
+    .filter((block) => block.type === "text" && typeof block.text === "string")
+    .map((block) => block.text as string)
+    .join("\n")
+    .trim();
+}
+
+function inlineImages(blocks: ContentBlock[]): TranscriptExportImage[] {
+  return blocks
+    .filter((block) => block.type === "image")
+    .map((block) => ({
+      kind: "inline" as const,
+      mimeType: typeof block.mimeType === "string" ? block.mimeType : undefined,
+      // base64 -> bytes
+      bytes: typeof block.data === "string" ? Math.floor((block.data.length * 3) / 4) : 0,
+    }));
+}
+
+function withImages<T extends object>(value: T, images: TranscriptExportImage[]): T {
+  return images.length > 0 ? { ...value, images } : value;
+}
+
+function emptyTurn(index: number, timestamp?: string): TranscriptExportTurn {
+  return {
+    index,
+    timestamp,
+    steps: [],
+    tokens: { input: 0, output: 0, cacheRead: 0, cacheWrite: 0, total: 0 },
+  };
+}
+
+function parseTimestamp(record: Record<string, unknown>, message: Record<string, unknown>) {
+  if (typeof record.timestamp === "string") {
+    return record.timestamp;
+  }
+  return typeof message.timestamp === "number"
+    ? new Date(message.timestamp).toISOString()
+    : undefined;
+}
+
+function addAssistantUsage(
+  turn: TranscriptExportTurn,
+  message: Record<string, unknown>,
+  config?: OpenClawConfig,
+) {
+  const usageRaw = message.usage as UsageLike | undefined;
+  const usage = usageRaw ? normalizeUsage(usageRaw) : undefined;
+  if (!usage) {
+    return;
+  }
+  turn.tokens.input += usage.input ?? 0;
+  turn.tokens.output += usage.output ?? 0;
+  turn.tokens.cacheRead += usage.cacheRead ?? 0;
+  turn.tokens.cacheWrite += usage.cacheWrite ?? 0;
+  turn.tokens.total +=
+    usage.total ??
+    (usage.input ?? 0) + (usage.output ?? 0) + (usage.cacheRead ?? 0) + (usage.cacheWrite ?? 0);
+  const recorded = (usageRaw as { cost?: { total?: unknown } }).cost?.total;
+  const cost =
+    typeof recorded === "number" && Number.isFinite(recorded)
+      ? recorded
+      : estimateUsageCost({
+          usage,
+          cost: resolveModelCostConfig({
+            provider: typeof message.provider === "string" ? message.provider : undefined,
+            model: typeof message.model === "string" ? message.model : undefined,
+            config,
+          }),
+        });
+  if (cost !== undefined) {
+    turn.costUsd = (turn.costUsd ?? 0) + cost;
+  }
+}
+
+/**
+ * Groups a session transcript (JSONL) into turns: each user message with the assistant replies,
+ * tool calls, and tool results that follow it. Thinking blocks and compaction entries are omitted.
+ */
+export function buildTranscriptExport(params: {
+  transcript: string;
+  sessionKey: string;
+  sessionId: string;
+  config?: OpenClawConfig;
+  now?: number;
+}): TranscriptExport {
+  const turns: TranscriptExportTurn[] = [];
+  const toolNames = new Map<string, string>();
+  let turn: TranscriptExportTurn | undefined;
+
+  for (const line of params.transcript.split("\n")) {
+    let record: Record<string, unknown>;
+    try {
+      record = JSON.parse(line) as Record<string, unknown>;
+    } catch {
+      continue;
+    }
+    const message = record?.message as Record<string, unknown> | undefined;
+    if (record?.type !== "message" || !message || typeof message !== "object") {
+      continue;
+    }
+    const blocks = contentBlocks(message.content);
+    const timestamp = parseTimestamp(record, message);
+    if (message.role === "user") {
+      const raw = blockText(blocks);
+      const text = stripMessageIdHints(stripEnvelope(stripInboundMetadata(raw))).trim();
+      turn = emptyTurn(turns.length + 1, timestamp);
+      turns.push(turn);
+      turn.user = withImages({ text }, [
+        ...inlineImages(blocks),
+        ...detectImageReferences(raw).map((ref) => ({
+          kind: "file" as const,
+          path: ref.resolved,
+        })),
+      ]);
+      continue;
+    }
+    if (!turn) {
+      turn = emptyTurn(turns.length + 1, timestamp);
+      turns.push(turn);
+    }
+    if (message.role === "assistant") {
+      const split = splitMediaFromOutput(blockText(blocks));
+      const media = (split.mediaUrls ?? []).map((url) => ({ kind: "file" as const, path: url }));
+      if (split.text.trim() || media.length > 0) {
+        const model = typeof message.model === "string" ? message.model : undefined;
+        turn.steps.push(
+          withImages({ type: "assistant" as const, text: split.text.trim(), model }, media),
+        );
+      }
+      for (const block of blocks) {
+        if (block.type !== "toolCall" && block.type !== "tool_use") {
+          continue;
+        }
+        const id = typeof block.id === "string" ? block.id : undefined;
+        const name = typeof block.name === "string" ? block.name : "tool";
+        if (id) {
+          toolNames.set(id, name);
+        }
+        turn.steps.push({
+          type: "toolCall",
+          id,
+          name,
+          arguments: block.arguments ?? block.input ?? {},
+        });
+      }
+      addAssistantUsage(turn, message, params.config);
+    } else if (message.role === "toolResult" || message.role === "tool") {
+      const id = typeof message.toolCallId === "string" ? message.toolCallId : undefined;
+      const name =
+        (typeof message.toolName === "string" ? message.toolName : undefined) ??
+        (id ? toolNames.get(id) : undefined) ??
+        "tool";
+      turn.steps.push(
+        withImages(
+          {
+            type: "toolResult" as const,
+            id,
+            name,
+            isError: message.isError === true,
+            text: blockText(blocks),
+          },
+          inlineImages(blocks),
+        ),
+      );
+    }
+  }
+
+  const priced = turns.filter((entry) => entry.costUsd !== undefined);
+  return {
+    version: TRANSCRIPT_EXPORT_VERSION,
+    exportedAt: new Date(params.now ?? Date.now()).toISOString(),
+    sessionKey: params.sessionKey,
+    sessionId: params.sessionId,
+    turns,
+    totals: {
+      tokens: turns.reduce((sum, entry) => sum + entry.tokens.total, 0),
+      costUsd:
+        priced.length > 0
+          ? priced.reduce((sum, entry) => sum + (entry.costUsd ?? 0), 0)
+          : undefined,
+    },
+  };
+}
+
+function fence(text: string, lang = ""): string {
+  const longest = Math.max(2, ...(text.match(/`+/g) ?? []).map((run) => run.length));
+  const marker = "`".repeat(longest + 1);
+  return `${marker}${lang}\n${text}\n${marker}`;
+}
+
+function formatImages(images: TranscriptExportImage[] | undefined): string[] {
+  if (!images?.length) {
+    return [];
+  }
+  const list = images.map((image) =>
+    image.kind === "file"
+      ? `\`${image.path}\``
+      : `inline ${image.mimeType ?? "image"} (${Math.ceil(image.bytes / 1024)} KB)`,
+  );
+  return [`Images: ${list.join(", ")}`, ""];
+}
+
+/** Markdown rendering for lab notebooks; costs use the `cost.currency` display currency. */
+export function renderTranscriptMarkdown(
+  data: TranscriptExport,
+  opts?: { config?: OpenClawConfig },
+): string {
+  const currency = resolveCostDisplayCurrency({ config: opts?.config });
+  const totalCost = formatCost(data.totals.costUsd, currency) ?? "n/a";
+  const lines = [
+    `# Session ${data.sessionKey}`,
+    "",
+    `- Session id: ${data.sessionId}`,
+    `- Exported: ${data.exportedAt}`,
+    `- Turns: ${data.turns.length}`,
+    `- Tokens: ${formatTokenCount(data.totals.tokens)}`,
+    `- Cost: ${totalCost}`,
+    "",
+  ];
+  for (const turn of data.turns) {
+    const meta = [
+      turn.timestamp,
+      `${formatTokenCount(turn.tokens.total)} tokens`,
+      formatCost(turn.costUsd, currency),
+    ].filter(Boolean);
+    lines.push(`## Turn ${turn.index} · ${meta.join(" · ")}`, "");
+    if (turn.user) {
+      lines.push("**User**", "", turn.user.text || "_(no text)_", "");
+      lines.push(...formatImages(turn.user.images));
+    }
+    for (const step of turn.steps) {
+      if (step.type === "assistant") {
+        const model = step.model ? ` (${step.model})` : "";
+        lines.push(`**Assistant**${model}`, "", step.text || "_(media only)_", "");
+        lines.push(...formatImages(step.images));
+      } else if (step.type === "toolCall") {
+        lines.push(`**Tool call** \`${step.name}\``, "");
+        lines.push(fence(JSON.stringify(step.arguments, null, 2), "json"), "");
+      } else {
+        const status = step.isError ? " (error)" : "";
+        lines.push(`**Tool result** \`${step.name}\`${status}`, "");
+        lines.push(fence(step.text || "(no output)"), "", ...formatImages(step.images));
+      }
+    }
+  }
+  return `${lines.join("\n").trimEnd()}\n`;
+}
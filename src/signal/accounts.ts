@@ -2,6 +2,7 @@ import { createAccountListHelpers } from "../channels/plugins/account-helpers.js
 import type { OpenClawConfig } from "../config/config.js";
 import type { SignalAccountConfig } from "../config/types.js";
 import { normalizeAccountId } from "../routing/session-key.js";
+import { SIGNAL_SOCKET_URL_PREFIX } from "./socket.js";
 
 export type ResolvedSignalAccount = {
   accountId: string;
@@ -46,10 +47,14 @@ export function resolveSignalAccount(params: {
   const enabled = baseEnabled && accountEnabled;
   const host = merged.httpHost?.trim() || "127.0.0.1";
   const port = merged.httpPort ?? 8080;
-  const baseUrl = merged.httpUrl?.trim() || `http://${host}:${port}`;
+  const socketPath = merged.socketPath?.trim();
+  const baseUrl = socketPath
+    ? `${SIGNAL_SOCKET_URL_PREFIX}${socketPath}`
+    : merged.httpUrl?.trim() || `http://${host}:${port}`;
   const configured = Boolean(
     merged.account?.trim() ||
     merged.httpUrl?.trim() ||
+    socketPath ||
     merged.cliPath?.trim() ||
     merged.httpHost?.trim() ||
     typeof merged.httpPort === "number" ||
@@ -1,6 +1,12 @@
 import { resolveFetch } from "../infra/fetch.js";
 import { generateSecureUuid } from "../infra/secure-random.js";
 import { fetchWithTimeout } from "../utils/fetch-timeout.js";
+import {
+  isSignalSocketUrl,
+  resolveSignalSocketPath,
+  signalSocketRequest,
+  streamSignalSocketEvents,
+} from "./socket.js";
 
 export type SignalRpcOptions = {
   baseUrl: string;
@@ -67,12 +73,20 @@ function parseSignalRpcResponse<T>(text: string, status: number): SignalRpcRespo
   return rpc;
 }
 
+function unwrapSignalRpcResult<T>(parsed: SignalRpcResponse<T>): T {
+  if (parsed.error) {
+    const code = parsed.error.code ?? "unknown";
+    const msg = parsed.error.message ?? "Signal RPC error";
+    throw new Error(`Signal RPC ${code}: ${msg}`);
+  }
+  return parsed.result as T;
+}
+
 export async function signalRpcRequest<T = unknown>(
   method: string,
   params: Record<string, unknown> | undefined,
   opts: SignalRpcOptions,
 ): Promise<T> {
-  const baseUrl = normalizeBaseUrl(opts.baseUrl);
   const id = generateSecureUuid();
   const body = JSON.stringify({
     jsonrpc: "2.0",
@@ -80,6 +94,16 @@ export async function signalRpcRequest<T = unknown>(
     params,
     id,
   });
+  if (isSignalSocketUrl(opts.baseUrl)) {
+    const line = await signalSocketRequest({
+      socketPath: resolveSignalSocketPath(opts.baseUrl),
+      id,
+      body,
+      timeoutMs: opts.timeoutMs ?? DEFAULT_TIMEOUT_MS,
+    });
+    return unwrapSignalRpcResult<T>(parseSignalRpcResponse<T>(line, 200));
+  }
+  const baseUrl = normalizeBaseUrl(opts.baseUrl);
   const res = await fetchWithTimeout(
     `${baseUrl}/api/v1/rpc`,
     {
@@ -97,19 +121,21 @@ export async function signalRpcRequest<T = unknown>(
   if (!text) {
     throw new Error(`Signal RPC empty response (status ${res.status})`);
   }
-  const parsed = parseSignalRpcResponse<T>(text, res.status);
-  if (parsed.error) {
-    const code = parsed.error.code ?? "unknown";
-    const msg = parsed.error.message ?? "Signal RPC error";
-    throw new Error(`Signal RPC ${code}: ${msg}`);
-  }
-  return parsed.result as T;
+  return unwrapSignalRpcResult<T>(parseSignalRpcResponse<T>(text, res.status));
 }
 
 export async function signalCheck(
   baseUrl: string,
   timeoutMs = DEFAULT_TIMEOUT_MS,
 ): Promise<{ ok: boolean; status?: number | null; error?: string | null }> {
+  if (isSignalSocketUrl(baseUrl)) {
+    try {
+      await signalRpcRequest("version", undefined, { baseUrl, timeoutMs });
+      return { ok: true, status: null, error: null };
+    } catch (err) {
+      return { ok: false, status: null, error: err instanceof Error ? err.message : String(err) };
+    }
+  }
   const normalized = normalizeBaseUrl(baseUrl);
   try {
     const res = await fetchWithTimeout(
@@ -137,6 +163,15 @@ export async function streamSignalEvents(params: {
   abortSignal?: AbortSignal;
   onEvent: (event: SignalSseEvent) => void;
 }): Promise<void> {
+  if (isSignalSocketUrl(params.baseUrl)) {
+    await streamSignalSocketEvents({
+      socketPath: resolveSignalSocketPath(params.baseUrl),
+      account: params.account,
+      abortSignal: params.abortSignal,
+      onEvent: params.onEvent,
+    });
+    return;
+  }
   const baseUrl = normalizeBaseUrl(params.baseUrl);
   const url = new URL(`${baseUrl}/api/v1/events`);
   if (params.account) {
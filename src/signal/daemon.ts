@@ -6,6 +6,8 @@ export type SignalDaemonOpts = {
   account?: string;
   httpHost: string;
   httpPort: number;
+  /** Bind a JSON-RPC unix socket instead of the HTTP server. */
+  socketPath?: string;
   receiveMode?: "on-start" | "manual";
   ignoreAttachments?: boolean;
   ignoreStories?: boolean;
@@ -69,7 +71,11 @@ function buildDaemonArgs(opts: SignalDaemonOpts): string[] {
     args.push("-a", opts.account);
   }
   args.push("daemon");
-  args.push("--http", `${opts.httpHost}:${opts.httpPort}`);
+  if (opts.socketPath) {
+    args.push("--socket", opts.socketPath);
+  } else {
+    args.push("--http", `${opts.httpHost}:${opts.httpPort}`);
+  }
   args.push("--no-receive-stdout");
 
   if (opts.receiveMode) {
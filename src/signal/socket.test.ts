@@ -0,0 +1,112 @@
+import fs from "node:fs/promises";
+import net from "node:net";
+import os from "node:os";
+import path from "node:path";
+import { afterEach, beforeEach, describe, expect, it } from "vitest";
+import { signalCheck, signalRpcRequest, streamSignalEvents } from "./client.js";
+import { isSignalSocketUrl, resolveSignalSocketPath } from "./socket.js";
+
+describe("signal-cli JSON-RPC socket transport", () => {
+  let dir: string;
+  let socketPath: string;
+  let server: net.Server;
+  let clients: net.Socket[];
+
+  const listen = async (onRequest: (socket: net.Socket, request: any) => void) => {
+    server = net.createServer((socket) => {
+      clients.push(socket);
+      let buffer = "";
+      socket.on("data", (chunk) => {
+        buffer += chunk.toString();
+        let lineEnd = buffer.indexOf("\n");
+        while (lineEnd !== -1) {
+          const line = buffer.slice(0, lineEnd);
+          buffer = buffer.slice(lineEnd + 1);
+          onRequest(socket, JSON.parse(line));
+          lineEnd = buffer.indexOf("\n");
+        }
+      });
+    });
+    await new Promise<void>((resolve) => server.listen(socketPath, resolve));
+  };
+
+  beforeEach(async () => {
+    dir = await fs.mkdtemp(path.join(os.tmpdir(), "openclaw-signal-socket-"));
+    socketPath = path.join(dir, "signal.sock");
+    clients = [];
+  });
+
+  afterEach(async () => {
+    for (const client of clients) {
+      client.destroy();
+    }
+    await new Promise<void>((resolve) => (server ? server.close(() => resolve()) : resolve()));
+    await fs.rm(dir, { recursive: true, force: true });
+  });
+
+  it("recognizes unix: base URLs", () => {
+    expect(isSignalSocketUrl("unix:/run/signal-cli/socket")).toBe(true);
+    expect(isSignalSocketUrl("http://127.0.0.1:8080")).toBe(false);
+    expect(resolveSignalSocketPath("unix:///run/signal-cli/socket")).toBe("/run/signal-cli/socket");
+    expect(resolveSignalSocketPath("unix:/run/signal-cli/socket")).toBe("/run/signal-cli/socket");
+  });
+
+  it("sends requests and skips notifications until the matching response", async () => {
+    await listen((socket, request) => {
+      socket.write(`${JSON.stringify({ jsonrpc: "2.0", method: "receive", params: {} })}\n`);
+      socket.write(
+        `${JSON.stringify({ jsonrpc: "2.0", id: request.id, result: { timestamp: 42 } })}\n`,
+      );
+    });
+    const result = await signalRpcRequest<{ timestamp: number }>(
+      "send",
+      { message: "hi" },
+      { baseUrl: `unix:${socketPath}` },
+    );
+    expect(result).toEqual({ timestamp: 42 });
+    await expect(signalCheck(`unix:${socketPath}`)).resolves.toMatchObject({ ok: true });
+  });
+
+  it("surfaces JSON-RPC errors", async () => {
+    await listen((socket, request) => {
+      const error = { code: -1, message: "nope" };
+      socket.write(`${JSON.stringify({ jsonrpc: "2.0", id: request.id, error })}\n`);
+    });
+    await expect(signalRpcRequest("send", {}, { baseUrl: `unix:${socketPath}` })).rejects.toThrow(
+      "Signal RPC -1: nope",
+    );
+  });
+
+  it("reports an unreachable socket", async () => {
+    const res = await signalCheck(`unix:${path.join(dir, "missing.sock")}`, 500);
+    expect(res.ok).toBe(false);
+  });
+
+  it("streams receive notifications for the configured account", async () => {
+    await listen(() => {});
+    const abort = new AbortController();
+    const events: Array<{ event?: string; data?: string }> = [];
+    const done = streamSignalEvents({
+      baseUrl: `unix:${socketPath}`,
+      account: "+15550001111",
+      abortSignal: abort.signal,
+      onEvent: (event) => {
+        events.push(event);
+        abort.abort();
+      },
+    });
+    await new Promise((resolve) => setTimeout(resolve, 50));
+    const [client] = clients;
+    const notify = (params: unknown) =>
+      client?.write(`${JSON.stringify({ jsonrpc: "2.0", method: "receive", params })}\n`);
+    notify({ account: "+15559999999", envelope: { source: "other" } });
+    notify({ account: "+15550001111", envelope: { source: "+15552223333" } });
+    await done;
+    expect(events).toEqual([
+      {
+        event: "receive",
+        data: JSON.stringify({ account: "+15550001111", envelope: { source: "+15552223333" } }),
+      },
+    ]);
+  });
+});
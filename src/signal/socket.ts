@@ -0,0 +1,137 @@
+import net from "node:net";
+
+/** Base URL prefix that selects the signal-cli JSON-RPC socket transport (`daemon --socket`). */
+export const SIGNAL_SOCKET_URL_PREFIX = "unix:";
+
+export function isSignalSocketUrl(baseUrl: string): boolean {
+  return baseUrl.trim().toLowerCase().startsWith(SIGNAL_SOCKET_URL_PREFIX);
+}
+
+export function resolveSignalSocketPath(baseUrl: string): string {
+  const socketPath = baseUrl
+    .trim()
+    .slice(SIGNAL_SOCKET_URL_PREFIX.length)
+    .replace(/^\/\/(?=\/)/, "");
+  if (!socketPath) {
+    throw new Error("Signal socket path is required");
+  }
+  return socketPath;
+}
+
+/**
+ * Read newline-delimited JSON-RPC messages from a socket. The daemon writes one JSON
+ * object per line: responses (with `id`) and `receive` notifications.
+ */
+function onSocketLines(socket: net.Socket, onLine: (line: string) => void): void {
+  let buffer = "";
+  socket.setEncoding("utf8");
+  socket.on("data", (chunk: string) => {
+    buffer += chunk;
+    let lineEnd = buffer.indexOf("\n");
+    while (lineEnd !== -1) {
+      const line = buffer.slice(0, lineEnd).trim();
+      buffer = buffer.slice(lineEnd + 1);
+      if (line) {
+        onLine(line);
+      }
+      lineEnd = buffer.indexOf("\n");
+    }
+  });
+}
+
+function readMessageId(line: string): unknown {
+  try {
+    return (JSON.parse(line) as { id?: unknown })?.id;
+  } catch {
+    return undefined;
+  }
+}
+
+/**
+ * Send one JSON-RPC request over a fresh socket connection and return the raw response line.
+ * Notifications that arrive on the same connection are ignored.
+ */
+export async function signalSocketRequest(params: {
+  socketPath: string;
+  id: string;
+  body: string;
+  timeoutMs: number;
+}): Promise<string> {
+  return await new Promise<string>((resolve, reject) => {
+    const socket = net.createConnection({ path: params.socketPath });
+    const timer = setTimeout(() => {
+      finish(new Error(`Signal socket request timed out after ${params.timeoutMs}ms`));
+    }, params.timeoutMs);
+    let settled = false;
+    const finish = (err: Error | null, line?: string) => {
+      if (settled) {
+        return;
+      }
+      settled = true;
+      clearTimeout(timer);
+      socket.destroy();
+      if (err) {
+        reject(err);
+      } else {
+        resolve(line ?? "");
+      }
+    };
+    socket.once("connect", () => socket.write(`${params.body}\n`));
+    socket.once("error", (err) => finish(err));
+    socket.once("close", () => finish(new Error("Signal socket closed before response")));
+    onSocketLines(socket, (line) => {
+      if (readMessageId(line) === params.id) {
+        finish(null, line);
+      }
+    });
+  });
+}
+
+/**
+ * Stream `receive` notifications from the daemon socket until it closes or the signal aborts.
+ * Each notification's params (`{ envelope, account }`) is passed through as the event data,
+ * matching the payload of the HTTP daemon's SSE `receive` events.
+ */
+export async function streamSignalSocketEvents(params: {
+  socketPath: string;
+  account?: string;
+  abortSignal?: AbortSignal;
+  onEvent: (event: { event: string; data: string }) => void;
+}): Promise<void> {
+  if (params.abortSignal?.aborted) {
+    return;
+  }
+  await new Promise<void>((resolve, reject) => {
+    const socket = net.createConnection({ path: params.socketPath });
+    const onAbort = () => socket.destroy();
+    params.abortSignal?.addEventListener("abort", onAbort, { once: true });
+    let failed = false;
+    socket.once("error", (err) => {
+      failed = true;
+      params.abortSignal?.removeEventListener("abort", onAbort);
+      reject(err);
+    });
+    socket.once("close", () => {
+      params.abortSignal?.removeEventListener("abort", onAbort);
+      if (!failed) {
+        resolve();
+      }
+    });
+    onSocketLines(socket, (line) => {
+      let message: { method?: string; params?: { account?: string } };
+      try {
+        message = JSON.parse(line) as typeof message;
+      } catch {
+        return;
+      }
+      if (message.method !== "receive" || !message.params) {
+        return;
+      }
+      // Multi-account daemons broadcast every account's messages to each client.
+      if (params.account && message.params.account && message.params.account !== params.account) {
+        return;
+      }
+      params.onEvent({ event: "receive", data: JSON.stringify(message.params) });
+    });
+  });
+}
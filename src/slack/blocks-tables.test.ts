@@ -0,0 +1,48 @@
+import { describe, expect, it } from "vitest";
+import { buildSlackTableBlocks } from "./blocks-tables.js";
+
+describe("buildSlackTableBlocks", () => {
+  it("renders two-column tables as section fields between text sections", () => {
+    const blocks = buildSlackTableBlocks(
+      [
+        "Lab readout:",
+        "",
+        "| Sensor | Value |",
+        "| --- | --- |",
+        "| Temp | 21.5 °C |",
+        "| Humidity | 40% |",
+        "",
+        "All nominal.",
+      ].join("\n"),
+    );
+    expect(blocks).toEqual([
+      { type: "section", text: { type: "mrkdwn", text: "Lab readout:" } },
+      {
+        type: "section",
+        fields: [
+          { type: "mrkdwn", text: "*Sensor*" },
+          { type: "mrkdwn", text: "*Value*" },
+          { type: "mrkdwn", text: "*Temp*" },
+          { type: "mrkdwn", text: "21.5 °C" },
+          { type: "mrkdwn", text: "*Humidity*" },
+          { type: "mrkdwn", text: "40%" },
+        ],
+      },
+      { type: "section", text: { type: "mrkdwn", text: "All nominal." } },
+    ]);
+  });
+
+  it("splits long tables across sections", () => {
+    const rows = Array.from({ length: 7 }, (_, i) => `| s${i} | ${i} |`);
+    const blocks = buildSlackTableBlocks(["| k | v |", "|---|---|", ...rows].join("\n"));
+    expect(blocks).toHaveLength(2);
+    expect((blocks?.[0] as { fields: unknown[] }).fields).toHaveLength(10);
+    expect((blocks?.[1] as { fields: unknown[] }).fields).toHaveLength(6);
+  });
+
+  it("falls back for text without tables, wide tables, and fenced tables", () => {
+    expect(buildSlackTableBlocks("just text")).toBeUndefined();
+    expect(buildSlackTableBlocks("| a | b | c |\n|---|---|---|\n| 1 | 2 | 3 |")).toBeUndefined();
+    expect(buildSlackTableBlocks("```\n| a | b |\n|---|---|\n| 1 | 2 |\n```")).toBeUndefined();
+  });
+});
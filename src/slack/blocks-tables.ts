@@ -0,0 +1,120 @@
+import type { KnownBlock } from "@slack/web-api";
+import { parseFenceSpans } from "../markdown/fences.js";
+import { markdownToSlackMrkdwn } from "./format.js";
+
+const SLACK_MAX_BLOCKS = 50;
+const SLACK_SECTION_TEXT_LIMIT = 3000;
+const SLACK_FIELD_TEXT_LIMIT = 2000;
+// Section blocks take at most 10 fields: a header pair plus four rows, or five rows.
+const SLACK_MAX_SECTION_FIELDS = 10;
+
+type Segment = { kind: "text"; markdown: string } | { kind: "table"; rows: string[][] };
+
+const TABLE_SEPARATOR_RE = /^\s*\|?\s*:?-{3,}:?\s*(\|\s*:?-{3,}:?\s*)*\|?\s*$/;
+
+function splitTableRow(line: string): string[] {
+  const trimmed = line.trim().replace(/^\|/, "").replace(/\|$/, "");
+  return trimmed.split(/(?<!\\)\|/).map((cell) => cell.trim().replace(/\\\|/g, "|"));
+}
+
+function isTableRow(line: string): boolean {
+  return line.includes("|") && line.trim().length > 0;
+}
+
+function splitSegments(markdown: string): Segment[] {
+  const fenceSpans = parseFenceSpans(markdown);
+  const lines = markdown.split("\n");
+  const segments: Segment[] = [];
+  let text: string[] = [];
+  let offset = 0;
+  const offsets = lines.map((line) => {
+    const start = offset;
+    offset += line.length + 1;
+    return start;
+  });
+  const insideFence = (index: number) =>
+    fenceSpans.some((span) => offsets[index] >= span.start && offsets[index] < span.end);
+  const flushText = () => {
+    const joined = text.join("\n").trim();
+    if (joined) {
+      segments.push({ kind: "text", markdown: joined });
+    }
+    text = [];
+  };
+
+  for (let i = 0; i < lines.length; i++) {
+    const line = lines[i] ?? "";
+    const next = lines[i + 1];
+    if (
+      !insideFence(i) &&
+      isTableRow(line) &&
+      next !== undefined &&
+      TABLE_SEPARATOR_RE.test(next) &&
+      next.includes("-")
+    ) {
+      const rows = [splitTableRow(line)];
+      let j = i + 2;
+      while (j < lines.length && isTableRow(lines[j] ?? "") && !insideFence(j)) {
+        rows.push(splitTableRow(lines[j] ?? ""));
+        j++;
+      }
+      flushText();
+      segments.push({ kind: "table", rows });
+      i = j - 1;
+      continue;
+    }
+    text.push(line);
+  }
+  flushText();
+  return segments;
+}
+
+function renderField(markdown: string, bold: boolean): string {
+  const mrkdwn = markdownToSlackMrkdwn(markdown).trim() || " ";
+  const text = bold ? `*${mrkdwn}*` : mrkdwn;
+  return text.slice(0, SLACK_FIELD_TEXT_LIMIT);
+}
+
+/**
+ * Render a reply as Block Kit when it carries two-column Markdown tables (status/sensor
+ * readouts): each table becomes a section field grid, surrounding text becomes mrkdwn
+ * sections. Returns undefined when the reply has no tables or does not fit Block Kit limits,
+ * so callers fall back to plain mrkdwn.
+ */
+export function buildSlackTableBlocks(markdown: string): KnownBlock[] | undefined {
+  const segments = splitSegments(markdown);
+  if (!segments.some((segment) => segment.kind === "table")) {
+    return undefined;
+  }
+  const blocks: KnownBlock[] = [];
+  for (const segment of segments) {
+    if (segment.kind === "text") {
+      const mrkdwn = markdownToSlackMrkdwn(segment.markdown).trim();
+      if (!mrkdwn) {
+        continue;
+      }
+      if (mrkdwn.length > SLACK_SECTION_TEXT_LIMIT) {
+        return undefined;
+      }
+      blocks.push({ type: "section", text: { type: "mrkdwn", text: mrkdwn } });
+      continue;
+    }
+    if (segment.rows.some((row) => row.length !== 2)) {
+      return undefined;
+    }
+    const [header, ...body] = segment.rows;
+    const fields = [
+      ...(header && header.some(Boolean) ? header.map((cell) => renderField(cell, true)) : []),
+      ...body.flatMap((row) => row.map((cell, index) => renderField(cell, index === 0))),
+    ];
+    for (let i = 0; i < fields.length; i += SLACK_MAX_SECTION_FIELDS) {
+      blocks.push({
+        type: "section",
+        fields: fields
+          .slice(i, i + SLACK_MAX_SECTION_FIELDS)
+          .map((text) => ({ type: "mrkdwn" as const, text })),
+      });
+    }
+  }
+  return blocks.length > 0 && blocks.length <= SLACK_MAX_BLOCKS ? blocks : undefined;
+}
@@ -0,0 +1,47 @@
+import { describe, expect, it } from "vitest";
+import { buildSlackInteractiveBlocks, SLACK_QUICK_REPLY_ACTION_PREFIX } from "./interactive.js";
+
+describe("buildSlackInteractiveBlocks", () => {
+  it("renders a section and one actions block per row", () => {
+    const blocks = buildSlackInteractiveBlocks(
+      {
+        rows: [
+          [
+            { label: "Allow", value: "/approve abc allow-once", style: "success" },
+            { label: "Deny", value: "/approve abc deny", style: "danger" },
+          ],
+        ],
+      },
+      "Exec approval required",
+    );
+    expect(blocks).toEqual([
+      { type: "section", text: { type: "mrkdwn", text: "Exec approval required" } },
+      {
+        type: "actions",
+        block_id: "openclaw_reply_0",
+        elements: [
+          {
+            type: "button",
+            action_id: `${SLACK_QUICK_REPLY_ACTION_PREFIX}0:0`,
+            text: { type: "plain_text", text: "Allow" },
+            value: "/approve abc allow-once",
+            style: "primary",
+          },
+          {
+            type: "button",
+            action_id: `${SLACK_QUICK_REPLY_ACTION_PREFIX}0:1`,
+            text: { type: "plain_text", text: "Deny" },
+            value: "/approve abc deny",
+            style: "danger",
+          },
+        ],
+      },
+    ]);
+  });
+
+  it("falls back to plain text when there are no buttons or the text is too long", () => {
+    expect(buildSlackInteractiveBlocks(undefined, "hi")).toBeUndefined();
+    const rows = [[{ label: "Ok", value: "/ok" }]];
+    expect(buildSlackInteractiveBlocks({ rows }, "x".repeat(3001))).toBeUndefined();
+  });
+});
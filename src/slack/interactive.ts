@@ -0,0 +1,50 @@
+import type { Block, KnownBlock } from "@slack/web-api";
+import { normalizeInteractiveReply, type InteractiveReply } from "../channels/interactive.js";
+import { markdownToSlackMrkdwn } from "./format.js";
+
+/** Action id prefix for quick-reply buttons; clicks are handled as messages from the clicker. */
+export const SLACK_QUICK_REPLY_ACTION_PREFIX = "openclaw:reply:";
+
+// Block Kit limits: section text 3000, button label 75, button value 2000, 25 elements per row.
+const SECTION_TEXT_MAX = 3000;
+const BUTTON_LABEL_MAX = 75;
+const BUTTON_VALUE_MAX = 2000;
+const ACTIONS_PER_BLOCK_MAX = 25;
+
+/**
+ * Render text plus interactive buttons as Block Kit. Returns undefined when there is nothing
+ * to render or the text is too long for a single section (callers then send plain text).
+ */
+export function buildSlackInteractiveBlocks(
+  reply: InteractiveReply | undefined,
+  text: string,
+): (Block | KnownBlock)[] | undefined {
+  const normalized = normalizeInteractiveReply(reply);
+  const mrkdwn = markdownToSlackMrkdwn(text).trim();
+  if (!normalized || mrkdwn.length > SECTION_TEXT_MAX) {
+    return undefined;
+  }
+  const blocks: (Block | KnownBlock)[] = [];
+  if (mrkdwn) {
+    blocks.push({ type: "section", text: { type: "mrkdwn", text: mrkdwn } });
+  }
+  normalized.rows.forEach((row, rowIndex) => {
+    const elements = row
+      .filter((button) => button.value.length <= BUTTON_VALUE_MAX)
+      .slice(0, ACTIONS_PER_BLOCK_MAX)
+      .map((button, index) => ({
+        type: "button" as const,
+        action_id: `${SLACK_QUICK_REPLY_ACTION_PREFIX}${rowIndex}:${index}`,
+        text: { type: "plain_text" as const, text: button.label.slice(0, BUTTON_LABEL_MAX) },
+        value: button.value,
+        // Slack has no green buttons; "success" maps to primary.
+        ...(button.style
+          ? { style: button.style === "danger" ? ("danger" as const) : ("primary" as const) }
+          : {}),
+      }));
+    if (elements.length > 0) {
+      blocks.push({ type: "actions", block_id: `openclaw_reply_${rowIndex}`, elements });
+    }
+  });
+  return blocks;
+}
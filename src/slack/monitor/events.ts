@@ -21,5 +21,8 @@ export function registerSlackMonitorEvents(params: {
   registerSlackMemberEvents({ ctx: params.ctx });
   registerSlackChannelEvents({ ctx: params.ctx });
   registerSlackPinEvents({ ctx: params.ctx });
-  registerSlackInteractionEvents({ ctx: params.ctx });
+  registerSlackInteractionEvents({
+    ctx: params.ctx,
+    handleSlackMessage: params.handleSlackMessage,
+  });
 }
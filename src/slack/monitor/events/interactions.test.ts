@@ -173,6 +173,55 @@ describe("registerSlackInteractionEvents", () => {
     expect(app.client.chat.update).toHaveBeenCalledTimes(1);
   });
 
+  it("routes quick-reply buttons as a message from the clicker", async () => {
+    enqueueSystemEventMock.mockClear();
+    const { ctx, app, getHandler } = createContext();
+    const handleSlackMessage = vi.fn().mockResolvedValue(undefined);
+    registerSlackInteractionEvents({ ctx: ctx as never, handleSlackMessage });
+
+    await getHandler()!({
+      ack: vi.fn().mockResolvedValue(undefined),
+      body: {
+        user: { id: "U123" },
+        channel: { id: "C1" },
+        container: { channel_id: "C1", message_ts: "100.200", thread_ts: "100.100" },
+        message: {
+          ts: "100.200",
+          text: "Exec approval required",
+          blocks: [
+            {
+              type: "actions",
+              block_id: "openclaw_reply_0",
+              elements: [{ type: "button", action_id: "openclaw:reply:0:0" }],
+            },
+          ],
+        },
+      },
+      action: {
+        type: "button",
+        action_id: "openclaw:reply:0:0",
+        block_id: "openclaw_reply_0",
+        action_ts: "100.300",
+        value: "/approve abc allow-once",
+        text: { type: "plain_text", text: "Allow once" },
+      },
+    });
+
+    expect(handleSlackMessage).toHaveBeenCalledWith(
+      {
+        type: "message",
+        user: "U123",
+        channel: "C1",
+        text: "/approve abc allow-once",
+        ts: "100.300",
+        thread_ts: "100.100",
+      },
+      { source: "message", wasMentioned: true },
+    );
+    expect(enqueueSystemEventMock).not.toHaveBeenCalled();
+    expect(app.client.chat.update).toHaveBeenCalledTimes(1);
+  });
+
   it("captures select values and updates action rows for non-button actions", async () => {
     enqueueSystemEventMock.mockClear();
     const { ctx, app, getHandler } = createContext();
@@ -1,8 +1,10 @@
 import type { SlackActionMiddlewareArgs } from "@slack/bolt";
 import type { Block, KnownBlock } from "@slack/web-api";
 import { enqueueSystemEvent } from "../../../infra/system-events.js";
+import { SLACK_QUICK_REPLY_ACTION_PREFIX } from "../../interactive.js";
 import { parseSlackModalPrivateMetadata } from "../../modal-metadata.js";
 import type { SlackMonitorContext } from "../context.js";
+import type { SlackMessageHandler } from "../message-handler.js";
 import { escapeSlackMrkdwn } from "../mrkdwn.js";
 
 // Prefix for OpenClaw-generated action IDs to scope our handler
@@ -506,8 +508,11 @@ function registerModalLifecycleHandler(params: {
   });
 }
 
-export function registerSlackInteractionEvents(params: { ctx: SlackMonitorContext }) {
-  const { ctx } = params;
+export function registerSlackInteractionEvents(params: {
+  ctx: SlackMonitorContext;
+  handleSlackMessage?: SlackMessageHandler;
+}) {
+  const { ctx, handleSlackMessage } = params;
   if (typeof ctx.app.action !== "function") {
     return;
   }
@@ -577,21 +582,40 @@ export function registerSlackInteractionEvents(params: { ctx: SlackMonitorContex
         `slack:interaction action=${actionId} type=${actionSummary.actionType ?? "unknown"} user=${userId} channel=${channelId}`,
       );
 
-      // Send a system event to notify the agent about the button click
-      // Pass undefined (not "unknown") to allow proper main session fallback
-      const sessionKey = ctx.resolveSlackSystemEventSessionKey({
-        channelId: channelId,
-        channelType: undefined,
-      });
+      const quickReplyText = actionId.startsWith(SLACK_QUICK_REPLY_ACTION_PREFIX)
+        ? actionSummary.value?.trim()
+        : undefined;
+      if (quickReplyText && handleSlackMessage && channelId && typedBody.user?.id) {
+        // Quick replies arrive as a message from the clicker, so sender gating still applies.
+        const actionTs = (typedAction as { action_ts?: string }).action_ts;
+        await handleSlackMessage(
+          {
+            type: "message",
+            user: typedBody.user.id,
+            channel: channelId,
+            text: quickReplyText,
+            ts: actionTs ?? (Date.now() / 1000).toFixed(6),
+            thread_ts: threadTs,
+          },
+          { source: "message", wasMentioned: true },
+        );
+      } else {
+        // Send a system event to notify the agent about the button click
+        // Pass undefined (not "unknown") to allow proper main session fallback
+        const sessionKey = ctx.resolveSlackSystemEventSessionKey({
+          channelId: channelId,
+          channelType: undefined,
+        });
 
-      // Build context key - only include defined values to avoid "unknown" noise
-      const contextParts = ["slack:interaction", channelId, messageTs, actionId].filter(Boolean);
-      const contextKey = contextParts.join(":");
+        // Build context key - only include defined values to avoid "unknown" noise
+        const contextParts = ["slack:interaction", channelId, messageTs, actionId].filter(Boolean);
+        const contextKey = contextParts.join(":");
 
-      enqueueSystemEvent(`Slack interaction: ${JSON.stringify(eventPayload)}`, {
-        sessionKey,
-        contextKey,
-      });
+        enqueueSystemEvent(`Slack interaction: ${JSON.stringify(eventPayload)}`, {
+          sessionKey,
+          contextKey,
+        });
+      }
 
       const originalBlocks = typedBody.message?.blocks;
       if (!Array.isArray(originalBlocks) || !channelId || !messageTs) {
@@ -6,6 +6,7 @@ import type { ReplyPayload } from "../../auto-reply/types.js";
 import type { MarkdownTableMode } from "../../config/types.base.js";
 import type { RuntimeEnv } from "../../runtime.js";
 import { markdownToSlackMrkdwnChunks } from "../format.js";
+import { buildSlackInteractiveBlocks } from "../interactive.js";
 import { sendMessageSlack } from "../send.js";
 
 export async function deliverReplies(params: {
@@ -34,10 +35,12 @@ export async function deliverReplies(params: {
       if (!trimmed || isSilentReplyText(trimmed, SILENT_REPLY_TOKEN)) {
         continue;
       }
+      const blocks = buildSlackInteractiveBlocks(payload.interactive, trimmed);
       await sendMessageSlack(params.target, trimmed, {
         token: params.token,
         threadTs,
         accountId: params.accountId,
+        ...(blocks ? { blocks } : {}),
       });
     } else {
       let first = true;
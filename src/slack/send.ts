@@ -17,6 +17,7 @@ import type { SlackTokenSource } from "./accounts.js";
 import { resolveSlackAccount } from "./accounts.js";
 import { buildSlackBlocksFallbackText } from "./blocks-fallback.js";
 import { validateSlackBlocksArray } from "./blocks-input.js";
+import { buildSlackTableBlocks } from "./blocks-tables.js";
 import { createSlackWebClient } from "./client.js";
 import { markdownToSlackMrkdwnChunks } from "./format.js";
 import { parseSlackTarget } from "./targets.js";
@@ -274,6 +275,29 @@ export async function sendMessageSlack(
     channel: "slack",
     accountId: account.accountId,
   });
+  const tableBlocks =
+    account.config.tableBlocks && !opts.mediaUrl
+      ? buildSlackTableBlocks(trimmedMessage)
+      : undefined;
+  if (tableBlocks) {
+    const [fallbackText = trimmedMessage] = markdownToSlackMrkdwnChunks(
+      trimmedMessage,
+      chunkLimit,
+      { tableMode },
+    );
+    const response = await postSlackMessageBestEffort({
+      client,
+      channelId,
+      text: fallbackText,
+      threadTs: opts.threadTs,
+      identity: opts.identity,
+      blocks: tableBlocks,
+    });
+    return {
+      messageId: response.ts ?? "unknown",
+      channelId,
+    };
+  }
   const chunkMode = resolveChunkMode(cfg, "slack", account.accountId);
   const markdownChunks =
     chunkMode === "newline"
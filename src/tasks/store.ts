@@ -0,0 +1,140 @@
+import { randomUUID } from "node:crypto";
+import path from "node:path";
+import { createAsyncLock, readJsonFile, writeJsonAtomic } from "../infra/json-files.js";
+import { CONFIG_DIR } from "../utils.js";
+
+export const DEFAULT_TASK_STORE_PATH = path.join(CONFIG_DIR, "tasks", "tasks.json");
+
+export const TASK_PRIORITIES = ["high", "normal", "low"] as const;
+export type TaskPriority = (typeof TASK_PRIORITIES)[number];
+
+export type AgentTask = {
+  id: string;
+  agentId: string;
+  title: string;
+  notes?: string;
+  priority: TaskPriority;
+  /** Epoch ms; a one-shot cron job wakes the session at this time. */
+  dueAt?: number;
+  sessionKey?: string;
+  /** Cron job id of the due-time reminder, removed when the task is completed early. */
+  reminderJobId?: string;
+  status: "open" | "done";
+  createdAt: number;
+  completedAt?: number;
+  result?: string;
+};
+
+type TaskStoreFile = { version: 1; tasks: AgentTask[] };
+
+const withTaskStoreLock = createAsyncLock();
+
+const PRIORITY_RANK: Record<TaskPriority, number> = { high: 0, normal: 1, low: 2 };
+
+async function loadTaskStore(storePath: string): Promise<TaskStoreFile> {
+  const parsed = await readJsonFile<Partial<TaskStoreFile>>(storePath);
+  const tasks = Array.isArray(parsed?.tasks) ? parsed.tasks : [];
+  return { version: 1, tasks: tasks.filter((task) => task && typeof task.id === "string") };
+}
+
+async function updateTaskStore<T>(
+  storePath: string,
+  mutate: (store: TaskStoreFile) => T,
+): Promise<T> {
+  return await withTaskStoreLock(async () => {
+    const store = await loadTaskStore(storePath);
+    const result = mutate(store);
+    await writeJsonAtomic(storePath, store);
+    return result;
+  });
+}
+
+/** Open tasks first, then by priority, then earliest due time, then oldest. */
+export function sortTasks(tasks: AgentTask[]): AgentTask[] {
+  return tasks.toSorted((a, b) => {
+    if (a.status !== b.status) {
+      return a.status === "open" ? -1 : 1;
+    }
+    const byPriority = PRIORITY_RANK[a.priority] - PRIORITY_RANK[b.priority];
+    if (byPriority !== 0) {
+      return byPriority;
+    }
+    const aDue = a.dueAt ?? Number.POSITIVE_INFINITY;
+    const bDue = b.dueAt ?? Number.POSITIVE_INFINITY;
+    if (aDue !== bDue) {
+      return aDue < bDue ? -1 : 1;
+    }
+    return a.createdAt - b.createdAt;
+  });
+}
+
+export async function addTask(
+  storePath: string,
+  input: Omit<AgentTask, "id" | "status" | "createdAt"> & { now?: number },
+): Promise<AgentTask> {
+  const { now, ...rest } = input;
+  const task: AgentTask = {
+    ...rest,
+    id: randomUUID().slice(0, 8),
+    status: "open",
+    createdAt: now ?? Date.now(),
+  };
+  return await updateTaskStore(storePath, (store) => {
+    store.tasks.push(task);
+    return task;
+  });
+}
+
+/** Adds tasks carried over from elsewhere (e.g. a session snapshot); known ids are skipped. */
+export async function importTasks(storePath: string, tasks: AgentTask[]): Promise<number> {
+  return await updateTaskStore(storePath, (store) => {
+    const known = new Set(store.tasks.map((task) => task.id));
+    const fresh = tasks.filter((task) => !known.has(task.id));
+    store.tasks.push(...fresh);
+    return fresh.length;
+  });
+}
+
+export async function updateTask(
+  storePath: string,
+  params: { agentId: string; id: string; patch: Partial<Omit<AgentTask, "id" | "agentId">> },
+): Promise<AgentTask | null> {
+  return await updateTaskStore(storePath, (store) => {
+    const task = store.tasks.find(
+      (entry) => entry.id === params.id && entry.agentId === params.agentId,
+    );
+    if (!task) {
+      return null;
+    }
+    Object.assign(task, params.patch);
+    return { ...task };
+  });
+}
+
+export async function listTasks(
+  storePath: string,
+  opts: { agentId: string; includeDone?: boolean },
+): Promise<AgentTask[]> {
+  const store = await loadTaskStore(storePath);
+  return sortTasks(
+    store.tasks.filter(
+      (task) => task.agentId === opts.agentId && (opts.includeDone || task.status === "open"),
+    ),
+  );
+}
+
+export async function completeTask(
+  storePath: string,
+  params: { agentId: string; id: string; result?: string; now?: number },
+): Promise<AgentTask | null> {
+  const result = params.result?.trim();
+  return await updateTask(storePath, {
+    agentId: params.agentId,
+    id: params.id,
+    patch: {
+      status: "done",
+      completedAt: params.now ?? Date.now(),
+      ...(result ? { result } : {}),
+    },
+  });
+}
@@ -24,6 +24,7 @@ import { MediaFetchError } from "../media/fetch.js";
 import { readChannelAllowFromStore } from "../pairing/pairing-store.js";
 import { resolveAgentRoute } from "../routing/resolve-route.js";
 import { resolveThreadSessionKeys } from "../routing/session-key.js";
+import { parseFeedbackRating, recordFeedback } from "../sessions/feedback.js";
 import { withTelegramApiErrorLogging } from "./api-logging.js";
 import {
   isSenderAllowed,
@@ -565,8 +566,9 @@ export const registerTelegramHandlers = ({
       const peerId = isGroup ? buildTelegramGroupPeerId(chatId, resolvedThreadId) : String(chatId);
       const parentPeer = buildTelegramParentPeer({ isGroup, resolvedThreadId, chatId });
       // Fresh config for bindings lookup; other routing inputs are payload-derived.
+      const cfg = loadConfig();
       const route = resolveAgentRoute({
-        cfg: loadConfig(),
+        cfg,
         channel: "telegram",
         accountId,
         peer: { kind: isGroup ? "group" : "direct", id: peerId },
@@ -584,6 +586,24 @@ export const registerTelegramHandlers = ({
         });
         logVerbose(`telegram: reaction event enqueued: ${text}`);
       }
+
+      // 👍/👎 on one of our replies also rates the session's latest turn.
+      const rating = addedReactions
+        .map((r) => parseFeedbackRating(r.emoji))
+        .find((value) => value !== undefined);
+      if (rating && wasSentByBot(chatId, messageId)) {
+        await recordFeedback({
+          storePath: resolveStorePath(cfg.session?.store, { agentId: route.agentId }),
+          sessionKey,
+          agentId: route.agentId,
+          rating,
+          source: "reaction",
+          channel: "telegram",
+          messageId: String(messageId),
+        }).catch((err) => {
+          logVerbose(`telegram: failed to record reaction feedback: ${String(err)}`);
+        });
+      }
     } catch (err) {
       runtime.error?.(danger(`telegram reaction handler failed: ${String(err)}`));
     }
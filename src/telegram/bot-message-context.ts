@@ -70,6 +70,8 @@ import {
   resolveTelegramStatusReactionEmojis,
 } from "./status-reaction-variants.js";
 
+const AUDIO_PLACEHOLDER_RE = /^<media:audio>(\s*\([^)]*\))?$/i;
+
 export type TelegramMediaRef = {
   path: string;
   contentType?: string;
@@ -429,7 +431,7 @@ export const buildTelegramMessageContext = async ({
   }
 
   // Replace audio placeholder with transcript when preflight succeeds.
-  if (hasAudio && bodyText === "<media:audio>" && preflightTranscript) {
+  if (hasAudio && AUDIO_PLACEHOLDER_RE.test(bodyText) && preflightTranscript) {
     bodyText = preflightTranscript;
   }
 
@@ -24,9 +24,9 @@ import type { TelegramMessageContext } from "./bot-message-context.js";
 import type { TelegramBotOptions } from "./bot.js";
 import { deliverReplies } from "./bot/delivery.js";
 import type { TelegramStreamMode } from "./bot/types.js";
-import type { TelegramInlineButtons } from "./button-types.js";
 import { createTelegramDraftStream } from "./draft-stream.js";
 import { renderTelegramHtmlText } from "./format.js";
+import { resolveTelegramReplyButtons } from "./interactive.js";
 import {
   type ArchivedPreview,
   createLaneDeliveryStateTracker,
@@ -415,9 +415,7 @@ export const dispatchTelegramMessage = async ({
       dispatcherOptions: {
         ...prefixOptions,
         deliver: async (payload, info) => {
-          const previewButtons = (
-            payload.channelData?.telegram as { buttons?: TelegramInlineButtons } | undefined
-          )?.buttons;
+          const previewButtons = resolveTelegramReplyButtons(payload);
           const segments = splitTextIntoLaneSegments(payload.text);
           const hasMedia = Boolean(payload.mediaUrl) || (payload.mediaUrls?.length ?? 0) > 0;
 
@@ -426,11 +424,7 @@ export const dispatchTelegramMessage = async ({
             if (!buffered) {
               return;
             }
-            const bufferedButtons = (
-              buffered.payload.channelData?.telegram as
-                | { buttons?: TelegramInlineButtons }
-                | undefined
-            )?.buttons;
+            const bufferedButtons = resolveTelegramReplyButtons(buffered.payload);
             await deliverLaneText({
               laneName: "answer",
               text: buffered.text,
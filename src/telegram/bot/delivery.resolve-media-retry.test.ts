@@ -113,7 +113,10 @@ describe("resolveMedia getFile retry", () => {
   it("retries getFile on transient failure and succeeds on second attempt", async () => {
     const result = await expectTransientGetFileRetrySuccess();
     expect(result).toEqual(
-      expect.objectContaining({ path: "/tmp/file_0.oga", placeholder: "<media:audio>" }),
+      expect.objectContaining({
+        path: "/tmp/file_0.oga",
+        placeholder: "<media:audio> (voice note)",
+      }),
     );
   });
 
@@ -211,7 +211,10 @@ export function resolveTelegramMediaPlaceholder(
   if (msg.video || msg.video_note) {
     return "<media:video>";
   }
-  if (msg.audio || msg.voice) {
+  if (msg.voice) {
+    return "<media:audio> (voice note)";
+  }
+  if (msg.audio) {
     return "<media:audio>";
   }
   if (msg.document) {
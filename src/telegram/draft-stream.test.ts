@@ -239,6 +239,35 @@ describe("createTelegramDraftStream", () => {
       expect.stringContaining("telegram stream preview stopped (text length 127 > 100)"),
     );
   });
+  it("backs off on 429 and retries the edit after retry_after", async () => {
+    vi.useFakeTimers();
+    try {
+      const api = createMockDraftApi();
+      const rateLimited = Object.assign(new Error("Too Many Requests: retry after 3"), {
+        error_code: 429,
+        parameters: { retry_after: 3 },
+      });
+      api.editMessageText.mockRejectedValueOnce(rateLimited);
+      const warn = vi.fn();
+      const stream = createDraftStream(api, { warn });
+
+      stream.update("Hello");
+      await stream.flush();
+      stream.update("Hello again");
+      await stream.flush();
+      expect(api.editMessageText).toHaveBeenCalledTimes(1);
+
+      await vi.advanceTimersByTimeAsync(2_000);
+      expect(api.editMessageText).toHaveBeenCalledTimes(1);
+
+      await vi.advanceTimersByTimeAsync(1_000);
+      expect(api.editMessageText).toHaveBeenCalledTimes(2);
+      expect(api.editMessageText).toHaveBeenLastCalledWith(123, 17, "Hello again");
+      expect(warn).not.toHaveBeenCalled();
+    } finally {
+      vi.useRealTimers();
+    }
+  });
 });
 
 describe("draft stream initial message debounce", () => {
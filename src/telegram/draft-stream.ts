@@ -1,5 +1,6 @@
 import type { Bot } from "grammy";
 import { createFinalizableDraftLifecycle } from "../channels/draft-stream-controls.js";
+import { getTelegramRetryAfterMs } from "../infra/retry-policy.js";
 import { buildTelegramThreadParams, type TelegramThreadSpec } from "./bot/helpers.js";
 
 const TELEGRAM_STREAM_MAX_CHARS = 4096;
@@ -97,6 +98,8 @@ export function createTelegramDraftStream(params: {
       }
     }
 
+    const previousText = lastSentText;
+    const previousParseMode = lastSentParseMode;
     lastSentText = renderedText;
     lastSentParseMode = renderedParseMode;
     try {
@@ -135,6 +138,15 @@ export function createTelegramDraftStream(params: {
       streamMessageId = normalizedMessageId;
       return true;
     } catch (err) {
+      // Rate limited (429): keep the preview and retry once Telegram's retry_after has passed.
+      const retryAfterMs = getTelegramRetryAfterMs(err);
+      if (retryAfterMs !== undefined && !streamState.final) {
+        lastSentText = previousText;
+        lastSentParseMode = previousParseMode;
+        loop.backoff(retryAfterMs);
+        params.log?.(`telegram stream preview rate limited; retrying in ${retryAfterMs}ms`);
+        return false;
+      }
       streamState.stopped = true;
       params.warn?.(
         `telegram stream preview failed: ${err instanceof Error ? err.message : String(err)}`,
@@ -0,0 +1,31 @@
+import { describe, expect, it } from "vitest";
+import { buildTelegramInteractiveButtons, resolveTelegramReplyButtons } from "./interactive.js";
+
+describe("telegram interactive buttons", () => {
+  it("maps buttons to callback_data and drops values over 64 bytes", () => {
+    expect(
+      buildTelegramInteractiveButtons({
+        rows: [
+          [
+            { label: "Allow", value: "/approve abc allow-once", style: "success" },
+            { label: "Too long", value: `/say ${"x".repeat(64)}` },
+          ],
+          [{ label: "Nope", value: `/say ${"y".repeat(64)}` }],
+        ],
+      }),
+    ).toEqual([[{ text: "Allow", callback_data: "/approve abc allow-once", style: "success" }]]);
+  });
+
+  it("prefers explicit channelData buttons", () => {
+    const explicit = [[{ text: "Raw", callback_data: "raw" }]];
+    expect(
+      resolveTelegramReplyButtons({
+        channelData: { telegram: { buttons: explicit } },
+        interactive: { rows: [[{ label: "Ignored", value: "/x" }]] },
+      }),
+    ).toBe(explicit);
+    expect(
+      resolveTelegramReplyButtons({ interactive: { rows: [[{ label: "Go", value: "/go" }]] } }),
+    ).toEqual([[{ text: "Go", callback_data: "/go" }]]);
+  });
+});
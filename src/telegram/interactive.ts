@@ -0,0 +1,43 @@
+import type { ReplyPayload } from "../auto-reply/types.js";
+import { normalizeInteractiveReply, type InteractiveReply } from "../channels/interactive.js";
+import type { TelegramInlineButton, TelegramInlineButtons } from "./button-types.js";
+
+const MAX_CALLBACK_DATA_BYTES = 64;
+
+/**
+ * Map interactive buttons to an inline keyboard. The button value becomes callback_data,
+ * which the callback_query handler feeds back as a message; values over Telegram's 64-byte
+ * limit are dropped.
+ */
+export function buildTelegramInteractiveButtons(
+  reply: InteractiveReply | undefined,
+): TelegramInlineButtons | undefined {
+  const normalized = normalizeInteractiveReply(reply);
+  if (!normalized) {
+    return undefined;
+  }
+  const rows = normalized.rows
+    .map((row) =>
+      row
+        .filter((button) => Buffer.byteLength(button.value, "utf8") <= MAX_CALLBACK_DATA_BYTES)
+        .map(
+          (button): TelegramInlineButton => ({
+            text: button.label,
+            callback_data: button.value,
+            ...(button.style ? { style: button.style } : {}),
+          }),
+        ),
+    )
+    .filter((row) => row.length > 0);
+  return rows.length > 0 ? rows : undefined;
+}
+
+/** Explicit `channelData.telegram.buttons` win; otherwise map the payload's interactive buttons. */
+export function resolveTelegramReplyButtons(
+  payload: Pick<ReplyPayload, "channelData" | "interactive">,
+): TelegramInlineButtons | undefined {
+  const telegramData = payload.channelData?.telegram as
+    | { buttons?: TelegramInlineButtons }
+    | undefined;
+  return telegramData?.buttons ?? buildTelegramInteractiveButtons(payload.interactive);
+}
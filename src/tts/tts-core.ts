@@ -10,6 +10,7 @@ import {
 } from "../agents/model-selection.js";
 import { resolveModel } from "../agents/pi-embedded-runner/model.js";
 import type { OpenClawConfig } from "../config/config.js";
+import { runCommandWithTimeout } from "../process/exec.js";
 import type {
   ResolvedTtsConfig,
   ResolvedTtsModelOverrides,
@@ -139,7 +140,12 @@ export function parseTtsDirectives(
             if (!policy.allowProvider) {
               break;
             }
-            if (rawValue === "openai" || rawValue === "elevenlabs" || rawValue === "edge") {
+            if (
+              rawValue === "openai" ||
+              rawValue === "elevenlabs" ||
+              rawValue === "edge" ||
+              rawValue === "piper"
+            ) {
               overrides.provider = rawValue;
             } else {
               warnings.push(`unsupported provider "${rawValue}"`);
@@ -671,3 +677,53 @@ export async function edgeTTS(params: {
   });
   await tts.ttsPromise(text, outputPath);
 }
+
+export function buildPiperArgs(
+  config: ResolvedTtsConfig["piper"],
+  outputPath: string,
+): string[] {
+  const args = [config.command, "--model", config.model ?? "", "--output_file", outputPath];
+  if (config.config) {
+    args.push("--config", config.config);
+  }
+  if (config.speaker !== undefined) {
+    args.push("--speaker", String(config.speaker));
+  }
+  if (config.lengthScale !== undefined) {
+    args.push("--length_scale", String(config.lengthScale));
+  }
+  if (config.noiseScale !== undefined) {
+    args.push("--noise_scale", String(config.noiseScale));
+  }
+  if (config.noiseW !== undefined) {
+    args.push("--noise_w", String(config.noiseW));
+  }
+  if (config.sentenceSilence !== undefined) {
+    args.push("--sentence_silence", String(config.sentenceSilence));
+  }
+  return args;
+}
+
+export async function piperTTS(params: {
+  text: string;
+  outputPath: string;
+  config: ResolvedTtsConfig["piper"];
+  timeoutMs: number;
+}): Promise<void> {
+  const { text, outputPath, config, timeoutMs } = params;
+  if (!config.model) {
+    throw new Error("no model configured");
+  }
+  const result = await runCommandWithTimeout(buildPiperArgs(config, outputPath), {
+    timeoutMs: config.timeoutMs ?? timeoutMs,
+    // Piper reads one utterance per line; keep the reply as a single utterance.
+    input: `${text.replace(/\s*\n\s*/g, " ").trim()}\n`,
+  });
+  if (result.termination === "timeout" || result.termination === "no-output-timeout") {
+    throw new Error("piper timed out");
+  }
+  if (result.code !== 0) {
+    const detail = result.stderr.trim().split("\n").pop() || `exit code ${result.code}`;
+    throw new Error(`piper failed (${detail})`);
+  }
+}
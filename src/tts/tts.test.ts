@@ -53,6 +53,7 @@ const {
   summarizeText,
   resolveOutputFormat,
   resolveEdgeOutputFormat,
+  buildPiperArgs,
 } = _test;
 
 const mockAssistantMessage = (content: AssistantMessage["content"]): AssistantMessage => ({
@@ -218,6 +219,42 @@ describe("tts", () => {
     });
   });
 
+  describe("piper", () => {
+    const baseCfg: OpenClawConfig = {
+      agents: { defaults: { model: { primary: "openai/gpt-4o-mini" } } },
+      messages: {
+        tts: {
+          piper: { model: "/models/en_US-lessac-medium.onnx", speaker: 2, lengthScale: 1.1 },
+        },
+      },
+    };
+
+    it("builds the piper command line from config", () => {
+      const config = resolveTtsConfig(baseCfg);
+      expect(buildPiperArgs(config.piper, "/tmp/voice.wav")).toEqual([
+        "piper",
+        "--model",
+        "/models/en_US-lessac-medium.onnx",
+        "--output_file",
+        "/tmp/voice.wav",
+        "--speaker",
+        "2",
+        "--length_scale",
+        "1.1",
+      ]);
+    });
+
+    it("is only configured once a voice model is set", () => {
+      expect(tts.isTtsProviderConfigured(resolveTtsConfig(baseCfg), "piper")).toBe(true);
+      expect(
+        tts.isTtsProviderConfigured(
+          resolveTtsConfig({ ...baseCfg, messages: { tts: {} } }),
+          "piper",
+        ),
+      ).toBe(false);
+    });
+  });
+
   describe("parseTtsDirectives", () => {
     it("extracts overrides and strips directives when enabled", () => {
       const policy = resolveModelOverridePolicy({ enabled: true, allowProvider: true });
@@ -417,6 +454,19 @@ describe("tts", () => {
         });
       }
     });
+
+    it("prefers a configured piper model over edge when no API keys are set", () => {
+      withEnv(
+        { OPENAI_API_KEY: undefined, ELEVENLABS_API_KEY: undefined, XI_API_KEY: undefined },
+        () => {
+          const config = resolveTtsConfig({
+            ...baseCfg,
+            messages: { tts: { piper: { model: "/models/voice.onnx" } } },
+          });
+          expect(getTtsProvider(config, "/tmp/tts-prefs-piper.json")).toBe("piper");
+        },
+      );
+    });
   });
 
   describe("maybeApplyTtsToPayload", () => {
@@ -27,6 +27,7 @@ import { stripMarkdown } from "../line/markdown-to-line.js";
 import { isVoiceCompatibleAudio } from "../media/audio.js";
 import { CONFIG_DIR, resolveUserPath } from "../utils.js";
 import {
+  buildPiperArgs,
   edgeTTS,
   elevenLabsTTS,
   inferEdgeExtension,
@@ -37,6 +38,7 @@ import {
   OPENAI_TTS_VOICES,
   openaiTTS,
   parseTtsDirectives,
+  piperTTS,
   scheduleCleanup,
   summarizeText,
 } from "./tts-core.js";
@@ -55,6 +57,7 @@ const DEFAULT_OPENAI_VOICE = "alloy";
 const DEFAULT_EDGE_VOICE = "en-US-MichelleNeural";
 const DEFAULT_EDGE_LANG = "en-US";
 const DEFAULT_EDGE_OUTPUT_FORMAT = "audio-24khz-48kbitrate-mono-mp3";
+const DEFAULT_PIPER_COMMAND = "piper";
 
 const DEFAULT_ELEVENLABS_VOICE_SETTINGS = {
   stability: 0.5,
@@ -128,6 +131,17 @@ export type ResolvedTtsConfig = {
     proxy?: string;
     timeoutMs?: number;
   };
+  piper: {
+    command: string;
+    model?: string;
+    config?: string;
+    speaker?: number;
+    lengthScale?: number;
+    noiseScale?: number;
+    noiseW?: number;
+    sentenceSilence?: number;
+    timeoutMs?: number;
+  };
   prefsPath?: string;
   maxTextLength: number;
   timeoutMs: number;
@@ -303,6 +317,17 @@ export function resolveTtsConfig(cfg: OpenClawConfig): ResolvedTtsConfig {
       proxy: raw.edge?.proxy?.trim() || undefined,
       timeoutMs: raw.edge?.timeoutMs,
     },
+    piper: {
+      command: raw.piper?.command?.trim() || DEFAULT_PIPER_COMMAND,
+      model: raw.piper?.model?.trim() ? resolveUserPath(raw.piper.model.trim()) : undefined,
+      config: raw.piper?.config?.trim() ? resolveUserPath(raw.piper.config.trim()) : undefined,
+      speaker: raw.piper?.speaker,
+      lengthScale: raw.piper?.lengthScale,
+      noiseScale: raw.piper?.noiseScale,
+      noiseW: raw.piper?.noiseW,
+      sentenceSilence: raw.piper?.sentenceSilence,
+      timeoutMs: raw.piper?.timeoutMs,
+    },
     prefsPath: raw.prefsPath,
     maxTextLength: raw.maxTextLength ?? DEFAULT_MAX_TEXT_LENGTH,
     timeoutMs: raw.timeoutMs ?? DEFAULT_TIMEOUT_MS,
@@ -441,6 +466,9 @@ export function getTtsProvider(config: ResolvedTtsConfig, prefsPath: string): Tt
   if (resolveTtsApiKey(config, "elevenlabs")) {
     return "elevenlabs";
   }
+  if (config.piper.model) {
+    return "piper";
+  }
   return "edge";
 }
 
@@ -508,7 +536,7 @@ export function resolveTtsApiKey(
   return undefined;
 }
 
-export const TTS_PROVIDERS = ["openai", "elevenlabs", "edge"] as const;
+export const TTS_PROVIDERS = ["openai", "elevenlabs", "edge", "piper"] as const;
 
 export function resolveTtsProviderOrder(primary: TtsProvider): TtsProvider[] {
   return [primary, ...TTS_PROVIDERS.filter((provider) => provider !== primary)];
@@ -518,6 +546,9 @@ export function isTtsProviderConfigured(config: ResolvedTtsConfig, provider: Tts
   if (provider === "edge") {
     return config.edge.enabled;
   }
+  if (provider === "piper") {
+    return Boolean(config.piper.model);
+  }
   return Boolean(resolveTtsApiKey(config, provider));
 }
 
@@ -628,6 +659,43 @@ export async function textToSpeech(params: {
         };
       }
 
+      if (provider === "piper") {
+        if (!config.piper.model) {
+          errors.push("piper: no model configured");
+          continue;
+        }
+
+        const tempRoot = resolvePreferredOpenClawTmpDir();
+        mkdirSync(tempRoot, { recursive: true, mode: 0o700 });
+        const tempDir = mkdtempSync(path.join(tempRoot, "tts-"));
+        const audioPath = path.join(tempDir, `voice-${Date.now()}.wav`);
+        try {
+          await piperTTS({
+            text: params.text,
+            outputPath: audioPath,
+            config: config.piper,
+            timeoutMs: config.timeoutMs,
+          });
+        } catch (err) {
+          try {
+            rmSync(tempDir, { recursive: true, force: true });
+          } catch {
+            // ignore cleanup errors
+          }
+          throw err;
+        }
+
+        scheduleCleanup(tempDir);
+        return {
+          success: true,
+          audioPath,
+          latencyMs: Date.now() - providerStart,
+          provider,
+          outputFormat: "wav",
+          voiceCompatible: isVoiceCompatibleAudio({ fileName: audioPath }),
+        };
+      }
+
       const apiKey = resolveTtsApiKey(config, provider);
       if (!apiKey) {
         errors.push(`${provider}: no API key`);
@@ -722,8 +790,8 @@ export async function textToSpeechTelephony(params: {
   for (const provider of providers) {
     const providerStart = Date.now();
     try {
-      if (provider === "edge") {
-        errors.push("edge: unsupported for telephony");
+      if (provider === "edge" || provider === "piper") {
+        errors.push(`${provider}: unsupported for telephony`);
         continue;
       }
 
@@ -944,4 +1012,5 @@ export const _test = {
   summarizeText,
   resolveOutputFormat,
   resolveEdgeOutputFormat,
+  buildPiperArgs,
 };
@@ -0,0 +1,110 @@
+import fs from "node:fs";
+import os from "node:os";
+import path from "node:path";
+import { describe, expect, it } from "vitest";
+import {
+  createMultilineInput,
+  createStreamPrinter,
+  formatToolEventLine,
+  loadChatHistory,
+} from "./chat-repl.js";
+
+describe("createMultilineInput", () => {
+  it("returns single lines immediately", () => {
+    const input = createMultilineInput();
+    expect(input.push("hello")).toBe("hello");
+    expect(input.pending).toBe(false);
+  });
+
+  it("joins backslash continuations", () => {
+    const input = createMultilineInput();
+    expect(input.push("first \\")).toBeNull();
+    expect(input.pending).toBe(true);
+    expect(input.push("second")).toBe("first \nsecond");
+    expect(input.pending).toBe(false);
+  });
+
+  it('collects """ blocks verbatim', () => {
+    const input = createMultilineInput();
+    expect(input.push('"""')).toBeNull();
+    expect(input.push("line one \\")).toBeNull();
+    expect(input.push("")).toBeNull();
+    expect(input.push("line two")).toBeNull();
+    expect(input.push('"""')).toBe("line one \\\n\nline two");
+  });
+
+  it("drops pending input on reset", () => {
+    const input = createMultilineInput();
+    input.push('"""');
+    input.push("draft");
+    input.reset();
+    expect(input.pending).toBe(false);
+    expect(input.push("fresh")).toBe("fresh");
+  });
+});
+
+describe("createStreamPrinter", () => {
+  it("writes only the new suffix of cumulative text", () => {
+    const chunks: string[] = [];
+    const printer = createStreamPrinter((chunk) => chunks.push(chunk));
+    printer.update("Hel");
+    printer.update("Hello");
+    printer.finish("Hello!");
+    expect(chunks).toEqual(["Hel", "lo", "!", "\n"]);
+  });
+
+  it("reprints on a new line when text is rewritten", () => {
+    const chunks: string[] = [];
+    const printer = createStreamPrinter((chunk) => chunks.push(chunk));
+    printer.update("draft");
+    printer.update("Final");
+    printer.finish("Final");
+    expect(chunks.join("")).toBe("draft\nFinal\n");
+  });
+
+  it("breaks the line before tool output", () => {
+    const chunks: string[] = [];
+    const printer = createStreamPrinter((chunk) => chunks.push(chunk));
+    printer.update("Let me check");
+    printer.breakLine();
+    chunks.push("[tool]\n");
+    printer.update("Done");
+    printer.finish("");
+    expect(chunks.join("")).toBe("Let me check\n[tool]\nDone\n");
+  });
+});
+
+describe("formatToolEventLine", () => {
+  it("summarizes tool starts with their args", () => {
+    const line = formatToolEventLine({ phase: "start", name: "web_search", args: { q: "x" } });
+    expect(line).toContain("web_search");
+    expect(line).toContain('{"q":"x"}');
+  });
+
+  it("marks failed results and skips updates", () => {
+    expect(formatToolEventLine({ phase: "result", name: "exec", isError: true })).toContain(
+      "✗ exec",
+    );
+    expect(formatToolEventLine({ phase: "update", name: "exec" })).toBeNull();
+  });
+
+  it("prints results only when tool output is enabled", () => {
+    const data = { phase: "result", name: "exec", result: "line 1\nline 2" };
+    expect(formatToolEventLine(data)).not.toContain("line 1");
+    expect(formatToolEventLine(data, { toolOutput: true })).toContain("line 1 line 2");
+  });
+});
+
+describe("loadChatHistory", () => {
+  it("reads non-empty lines and tolerates a missing file", () => {
+    const dir = fs.mkdtempSync(path.join(os.tmpdir(), "openclaw-chat-history-"));
+    try {
+      const file = path.join(dir, "history");
+      expect(loadChatHistory(file)).toEqual([]);
+      fs.writeFileSync(file, "newest\n\noldest\n");
+      expect(loadChatHistory(file)).toEqual(["newest", "oldest"]);
+    } finally {
+      fs.rmSync(dir, { recursive: true, force: true });
+    }
+  });
+});
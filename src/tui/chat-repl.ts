@@ -0,0 +1,431 @@
+import { randomUUID } from "node:crypto";
+import fs from "node:fs";
+import path from "node:path";
+import readline from "node:readline";
+import { resolveDefaultAgentId } from "../agents/agent-scope.js";
+import { loadConfig } from "../config/config.js";
+import { resolveStateDir } from "../config/paths.js";
+import { normalizeMainKey } from "../routing/session-key.js";
+import { theme } from "../terminal/theme.js";
+import { GatewayChatClient } from "./gateway-chat.js";
+import { resolveTuiSessionKey } from "./tui.js";
+import { asString, extractTextFromMessage, isCommandMessage } from "./tui-formatters.js";
+import { TuiStreamAssembler } from "./tui-stream-assembler.js";
+import type { AgentEvent, ChatEvent, SessionScope } from "./tui-types.js";
+
+export type ChatReplOptions = {
+  url?: string;
+  token?: string;
+  password?: string;
+  session?: string;
+  thinking?: string;
+  timeoutMs?: number;
+  /** Print tool results inline (the Gateway only sends them at verbose level "full"). */
+  toolOutput?: boolean;
+};
+
+const HISTORY_SIZE = 1000;
+const TOOL_SUMMARY_CHARS = 160;
+const TOOL_OUTPUT_CHARS = 800;
+const PROMPT = "you> ";
+const CONTINUATION_PROMPT = "...> ";
+const BLOCK_DELIMITER = '"""';
+
+export function resolveChatHistoryPath(env: NodeJS.ProcessEnv = process.env): string {
+  return path.join(resolveStateDir(env), "chat", "history");
+}
+
+/** History file: one entry per line, newest first (readline's order). */
+export function loadChatHistory(filePath: string): string[] {
+  try {
+    return fs
+      .readFileSync(filePath, "utf8")
+      .split("\n")
+      .filter((line) => line.length > 0)
+      .slice(0, HISTORY_SIZE);
+  } catch {
+    return [];
+  }
+}
+
+function saveChatHistory(filePath: string, history: string[]) {
+  try {
+    fs.mkdirSync(path.dirname(filePath), { recursive: true });
+    fs.writeFileSync(filePath, `${history.slice(0, HISTORY_SIZE).join("\n")}\n`, {
+      mode: 0o600,
+    });
+  } catch {
+    // History is a convenience; never fail the session over it.
+  }
+}
+
+/**
+ * Multi-line input: a line ending in `\` continues on the next line, and lines between two
+ * `"""` lines are sent as one message.
+ */
+export function createMultilineInput() {
+  let lines: string[] = [];
+  let inBlock = false;
+  return {
+    get pending() {
+      return inBlock || lines.length > 0;
+    },
+    reset() {
+      lines = [];
+      inBlock = false;
+    },
+    push(line: string): string | null {
+      if (line.trim() === BLOCK_DELIMITER) {
+        if (!inBlock) {
+          inBlock = true;
+          return null;
+        }
+        const text = lines.join("\n");
+        lines = [];
+        inBlock = false;
+        return text;
+      }
+      if (inBlock) {
+        lines.push(line);
+        return null;
+      }
+      if (line.endsWith("\\")) {
+        lines.push(line.slice(0, -1));
+        return null;
+      }
+      lines.push(line);
+      const text = lines.join("\n");
+      lines = [];
+      return text;
+    },
+  };
+}
+
+/**
+ * Prints streamed assistant text to a line-oriented terminal: appends what is new since the
+ * last update, and starts over on a fresh line when the text was rewritten.
+ */
+export function createStreamPrinter(write: (chunk: string) => void) {
+  let printed = "";
+  let lineOpen = false;
+  const update = (text: string) => {
+    if (text.startsWith(printed)) {
+      const next = text.slice(printed.length);
+      if (next) {
+        write(next);
+        lineOpen = !next.endsWith("\n");
+      }
+    } else {
+      write(`${lineOpen ? "\n" : ""}${text}`);
+      lineOpen = !text.endsWith("\n");
+    }
+    printed = text;
+  };
+  return {
+    update,
+    /** End the current text line so tool lines start at column 0. */
+    breakLine() {
+      if (lineOpen) {
+        write("\n");
+        lineOpen = false;
+      }
+      // Text after a tool call continues as a new segment.
+      printed = "";
+    },
+    finish(text: string) {
+      if (text && text !== printed) {
+        update(text);
+      }
+      if (lineOpen) {
+        write("\n");
+      }
+      printed = "";
+      lineOpen = false;
+    },
+  };
+}
+
+function truncate(text: string, max: number): string {
+  return text.length > max ? `${text.slice(0, max - 1)}…` : text;
+}
+
+function summarizeToolValue(value: unknown, max: number): string {
+  if (value === undefined || value === null) {
+    return "";
+  }
+  if (typeof value === "string") {
+    return truncate(value.replace(/\s+/g, " ").trim(), max);
+  }
+  const text = extractTextFromMessage(value);
+  if (text) {
+    return truncate(text.replace(/\s+/g, " ").trim(), max);
+  }
+  try {
+    return truncate(JSON.stringify(value), max);
+  } catch {
+    return "";
+  }
+}
+
+/** One inline line per tool step; null for steps that are not worth a line. */
+export function formatToolEventLine(
+  data: Record<string, unknown>,
+  opts?: { toolOutput?: boolean },
+): string | null {
+  const phase = asString(data.phase, "");
+  const name = asString(data.name, "tool");
+  if (phase === "start") {
+    const args = summarizeToolValue(data.args, TOOL_SUMMARY_CHARS);
+    return theme.muted(`  → ${name}${args ? ` ${args}` : ""}`);
+  }
+  if (phase === "result") {
+    const failed = data.isError === true;
+    const mark = failed ? theme.error(`  ✗ ${name}`) : theme.success(`  ✓ ${name}`);
+    const output = opts?.toolOutput ? summarizeToolValue(data.result, TOOL_OUTPUT_CHARS) : "";
+    return output ? `${mark} ${theme.muted(output)}` : mark;
+  }
+  return null;
+}
+
+function printHelp(write: (line: string) => void) {
+  write(
+    [
+      "Commands:",
+      "  /new            start a fresh session",
+      "  /session <key>  switch session",
+      "  /abort          abort the running reply (or press Ctrl+C)",
+      "  /exit           quit (or press Ctrl+D)",
+      `Multi-line: end a line with \\ to continue, or wrap the message in ${BLOCK_DELIMITER} lines.`,
+    ].join("\n"),
+  );
+}
+
+/**
+ * Line-mode chat against the Gateway: readline prompt with persistent history, streamed
+ * replies, and inline tool steps. Works in plain terminals and over SSH where the full-screen
+ * TUI does not fit.
+ */
+export async function runChatRepl(opts: ChatReplOptions): Promise<void> {
+  const config = loadConfig();
+  const resolveSessionKey = (raw?: string) =>
+    resolveTuiSessionKey({
+      raw,
+      sessionScope: (config.session?.scope ?? "per-sender") as SessionScope,
+      currentAgentId: resolveDefaultAgentId(config),
+      sessionMainKey: normalizeMainKey(config.session?.mainKey),
+    });
+  let sessionKey = resolveSessionKey(opts.session);
+
+  const out = process.stdout;
+  const writeLine = (line: string) => out.write(`${line}\n`);
+  const printer = createStreamPrinter((chunk) => out.write(chunk));
+  const input = createMultilineInput();
+  const historyPath = resolveChatHistoryPath();
+  let assembler = new TuiStreamAssembler();
+  let activeRunId: string | null = null;
+  let finishRun: (() => void) | null = null;
+
+  const client = new GatewayChatClient({
+    url: opts.url,
+    token: opts.token,
+    password: opts.password,
+  });
+
+  const endRun = () => {
+    activeRunId = null;
+    assembler = new TuiStreamAssembler();
+    const done = finishRun;
+    finishRun = null;
+    done?.();
+  };
+
+  client.onEvent = (evt) => {
+    if (!activeRunId || !evt.payload || typeof evt.payload !== "object") {
+      return;
+    }
+    if (evt.event === "chat") {
+      const chat = evt.payload as ChatEvent;
+      if (chat.runId !== activeRunId || chat.sessionKey !== sessionKey) {
+        return;
+      }
+      if (chat.state === "delta") {
+        const text = assembler.ingestDelta(chat.runId, chat.message, false);
+        if (text) {
+          printer.update(text);
+        }
+      } else if (chat.state === "final") {
+        if (isCommandMessage(chat.message)) {
+          printer.finish("");
+          writeLine(theme.muted(extractTextFromMessage(chat.message)));
+        } else {
+          printer.finish(assembler.finalize(chat.runId, chat.message, false));
+        }
+        endRun();
+      } else {
+        const reason =
+          chat.state === "aborted" ? "run aborted" : `run error: ${chat.errorMessage ?? "unknown"}`;
+        printer.finish("");
+        writeLine(theme.error(reason));
+        endRun();
+      }
+      return;
+    }
+    if (evt.event === "agent") {
+      const agent = evt.payload as AgentEvent;
+      if (agent.runId !== activeRunId || agent.stream !== "tool") {
+        return;
+      }
+      const line = formatToolEventLine(agent.data ?? {}, { toolOutput: opts.toolOutput });
+      if (line) {
+        printer.breakLine();
+        writeLine(line);
+      }
+    }
+  };
+  client.onDisconnected = (reason) => {
+    if (activeRunId) {
+      printer.finish("");
+      writeLine(theme.error(`gateway disconnected${reason ? `: ${reason}` : ""}`));
+      endRun();
+    }
+  };
+
+  client.start();
+  await client.waitForReady();
+  writeLine(theme.heading(`openclaw chat - ${client.connection.url} - session ${sessionKey}`));
+  writeLine(theme.muted("Type /help for commands. Ctrl+D to quit."));
+
+  const rl = readline.createInterface({
+    input: process.stdin,
+    output: out,
+    terminal: Boolean(process.stdin.isTTY),
+    history: loadChatHistory(historyPath),
+    historySize: HISTORY_SIZE,
+    removeHistoryDuplicates: true,
+  });
+  rl.on("history", (history: string[]) => saveChatHistory(historyPath, history));
+
+  const prompt = () => {
+    rl.setPrompt(input.pending ? CONTINUATION_PROMPT : PROMPT);
+    rl.prompt();
+  };
+
+  const abortActiveRun = async () => {
+    if (!activeRunId) {
+      return;
+    }
+    try {
+      await client.abortChat({ sessionKey, runId: activeRunId });
+    } catch (err) {
+      writeLine(theme.error(`abort failed: ${String(err)}`));
+    }
+  };
+
+  const handleCommand = async (text: string): Promise<boolean> => {
+    const [command, ...rest] = text.split(/\s+/);
+    const arg = rest.join(" ").trim();
+    switch (command) {
+      case "/exit":
+      case "/quit":
+        rl.close();
+        return true;
+      case "/help":
+        printHelp(writeLine);
+        return true;
+      case "/new":
+        await client.resetSession(sessionKey, "new");
+        writeLine(theme.muted(`new session started (${sessionKey})`));
+        return true;
+      case "/session":
+        if (!arg) {
+          writeLine(theme.muted(`session ${sessionKey}`));
+          return true;
+        }
+        sessionKey = resolveSessionKey(arg);
+        writeLine(theme.muted(`switched to session ${sessionKey}`));
+        return true;
+      case "/abort":
+        await abortActiveRun();
+        return true;
+      default:
+        // Other slash commands (/status, /model, ...) go to the agent like any message.
+        return false;
+    }
+  };
+
+  const send = async (message: string) => {
+    const finished = new Promise<void>((resolve) => {
+      finishRun = resolve;
+    });
+    try {
+      const { runId } = await client.sendChat({
+        sessionKey,
+        message,
+        thinking: opts.thinking,
+        timeoutMs: opts.timeoutMs,
+        runId: randomUUID(),
+      });
+      activeRunId = runId;
+    } catch (err) {
+      finishRun = null;
+      writeLine(theme.error(`send failed: ${String(err)}`));
+      return;
+    }
+    await finished;
+  };
+
+  let busy = false;
+  rl.on("line", (line) => {
+    if (busy) {
+      return;
+    }
+    const text = input.push(line);
+    if (text === null) {
+      prompt();
+      return;
+    }
+    const trimmed = text.trim();
+    if (!trimmed) {
+      prompt();
+      return;
+    }
+    busy = true;
+    rl.pause();
+    void (async () => {
+      try {
+        if (!(trimmed.startsWith("/") && (await handleCommand(trimmed)))) {
+          await send(text);
+        }
+      } catch (err) {
+        writeLine(theme.error(String(err)));
+      } finally {
+        busy = false;
+        rl.resume();
+        prompt();
+      }
+    })();
+  });
+
+  rl.on("SIGINT", () => {
+    if (activeRunId) {
+      void abortActiveRun();
+      return;
+    }
+    if (input.pending) {
+      input.reset();
+      out.write("\n");
+      prompt();
+      return;
+    }
+    rl.close();
+  });
+
+  prompt();
+  await new Promise<void>((resolve) => {
+    rl.on("close", () => {
+      out.write("\n");
+      client.stop();
+      resolve();
+    });
+  });
+}
@@ -0,0 +1,74 @@
+import type { Component } from "@mariozechner/pi-tui";
+import { describe, expect, it } from "vitest";
+import { stripAnsi, visibleWidth } from "../../terminal/ansi.js";
+import { DashboardLayout, renderDashboardSidebar } from "./dashboard-layout.js";
+
+class StaticLines implements Component {
+  constructor(private readonly lines: string[]) {}
+
+  render() {
+    return this.lines;
+  }
+
+  invalidate() {}
+}
+
+describe("renderDashboardSidebar", () => {
+  it("lists tool activity and nodes within the sidebar width", () => {
+    const lines = renderDashboardSidebar({
+      width: 30,
+      now: 3_000,
+      tools: [
+        {
+          toolCallId: "t2",
+          name: "exec",
+          status: "running",
+          detail: "tail -f /var/log/syslog and a very long command",
+          startedAt: 1_000,
+        },
+        { toolCallId: "t1", name: "web_search", status: "ok", startedAt: 0, endedAt: 400 },
+      ],
+      nodes: [
+        {
+          nodeId: "n1",
+          displayName: "Kitchen iPad",
+          platform: "ios",
+          connected: true,
+          caps: ["camera", "location"],
+        },
+        { nodeId: "n2", connected: false },
+      ],
+    }).map(stripAnsi);
+
+    expect(lines[0]).toBe("Tool activity");
+    expect(lines[1]).toMatch(/^● exec tail -f .*… 2\.0s$/);
+    expect(lines[2]).toMatch(/^✓ web_search +400ms$/);
+    expect(lines).toContain("● Kitchen iPad (ios)");
+    expect(lines).toContain("  camera, location");
+    expect(lines).toContain("○ n2");
+    for (const line of lines) {
+      expect(visibleWidth(line)).toBeLessThanOrEqual(30);
+    }
+  });
+
+  it("shows placeholders before data arrives", () => {
+    const lines = renderDashboardSidebar({ width: 30, tools: [], nodes: null }).map(stripAnsi);
+    expect(lines).toEqual(["Tool activity", "no tool calls yet", "", "Nodes", "loading…"]);
+  });
+});
+
+describe("DashboardLayout", () => {
+  it("bottom-aligns the sidebar next to the conversation", () => {
+    const main = new StaticLines(["one", "two", "three"]);
+    const layout = new DashboardLayout(main, () => ["side"], 10);
+    const lines = layout.render(80).map(stripAnsi);
+    expect(lines).toHaveLength(3);
+    expect(lines[0]?.trimEnd()).toBe(`one${" ".repeat(64)} │`);
+    expect(lines[2]).toBe(`three${" ".repeat(62)} │ side`);
+  });
+
+  it("falls back to the conversation alone on narrow terminals", () => {
+    const layout = new DashboardLayout(new StaticLines(["only"]), () => ["side"], 36);
+    expect(layout.render(80)).toEqual(["only"]);
+  });
+});
@@ -0,0 +1,128 @@
+import type { Component } from "@mariozechner/pi-tui";
+import { visibleWidth } from "../../terminal/ansi.js";
+import type { GatewayNodeSummary } from "../gateway-chat.js";
+import { theme } from "../theme/theme.js";
+import type { ToolActivityEntry } from "../tui-tool-activity.js";
+
+const SEPARATOR = " │ ";
+const DEFAULT_SIDEBAR_WIDTH = 36;
+const MIN_MAIN_WIDTH = 60;
+const MAX_NODES = 8;
+
+function fit(text: string, width: number): string {
+  const chars = Array.from(text);
+  if (chars.length <= width) {
+    return text;
+  }
+  return width <= 1 ? chars.slice(0, width).join("") : `${chars.slice(0, width - 1).join("")}…`;
+}
+
+function padVisible(line: string, width: number): string {
+  const gap = width - visibleWidth(line);
+  return gap > 0 ? `${line}${" ".repeat(gap)}` : line;
+}
+
+function formatDuration(ms: number): string {
+  if (ms < 1000) {
+    return `${Math.max(0, Math.round(ms))}ms`;
+  }
+  const seconds = ms / 1000;
+  if (seconds < 60) {
+    return `${seconds < 10 ? seconds.toFixed(1) : Math.round(seconds)}s`;
+  }
+  return `${Math.floor(seconds / 60)}m ${Math.round(seconds % 60)}s`;
+}
+
+function renderToolLine(entry: ToolActivityEntry, width: number, now: number): string {
+  const elapsed = formatDuration((entry.endedAt ?? now) - entry.startedAt);
+  const label = entry.detail ? `${entry.name} ${entry.detail.replace(/\s+/g, " ")}` : entry.name;
+  const body = fit(label, Math.max(1, width - elapsed.length - 3));
+  const line = `${padVisible(body, width - elapsed.length - 3)} ${elapsed}`;
+  if (entry.status === "running") {
+    return `${theme.accent("●")} ${line}`;
+  }
+  const mark = entry.status === "error" ? theme.error("✗") : theme.success("✓");
+  return `${mark} ${line}`;
+}
+
+function renderNodeLines(node: GatewayNodeSummary, width: number): string[] {
+  const name = node.displayName?.trim() || node.nodeId;
+  const label = fit(node.platform ? `${name} (${node.platform})` : name, width - 2);
+  const lines = [
+    node.connected ? `${theme.success("●")} ${label}` : `${theme.dim("○")} ${theme.dim(label)}`,
+  ];
+  const caps = node.caps?.filter(Boolean) ?? [];
+  if (node.connected && caps.length > 0) {
+    lines.push(theme.dim(`  ${fit(caps.join(", "), width - 2)}`));
+  }
+  return lines;
+}
+
+/** Sidebar content: recent tool calls of this session, then paired nodes and their sensors. */
+export function renderDashboardSidebar(params: {
+  width: number;
+  tools: ToolActivityEntry[];
+  nodes: GatewayNodeSummary[] | null;
+  now?: number;
+}): string[] {
+  const { width } = params;
+  const now = params.now ?? Date.now();
+  const lines: string[] = [theme.header(fit("Tool activity", width))];
+  if (params.tools.length === 0) {
+    lines.push(theme.dim("no tool calls yet"));
+  } else {
+    for (const entry of params.tools) {
+      lines.push(renderToolLine(entry, width, now));
+    }
+  }
+  lines.push("", theme.header(fit("Nodes", width)));
+  if (params.nodes === null) {
+    lines.push(theme.dim("loading…"));
+  } else if (params.nodes.length === 0) {
+    lines.push(theme.dim("no paired nodes"));
+  } else {
+    for (const node of params.nodes.slice(0, MAX_NODES)) {
+      lines.push(...renderNodeLines(node, width));
+    }
+    if (params.nodes.length > MAX_NODES) {
+      lines.push(theme.dim(`+${params.nodes.length - MAX_NODES} more`));
+    }
+  }
+  return lines;
+}
+
+/**
+ * Renders the conversation next to a fixed-width sidebar. The sidebar is bottom-aligned so it
+ * stays next to the editor while the conversation scrolls. Falls back to the conversation
+ * alone on narrow terminals.
+ */
+export class DashboardLayout implements Component {
+  constructor(
+    private readonly main: Component,
+    private readonly renderSidebar: (width: number) => string[],
+    private readonly sidebarWidth = DEFAULT_SIDEBAR_WIDTH,
+  ) {}
+
+  render(width: number): string[] {
+    const mainWidth = width - this.sidebarWidth - SEPARATOR.length;
+    if (mainWidth < MIN_MAIN_WIDTH) {
+      return this.main.render(width);
+    }
+    const mainLines = this.main.render(mainWidth);
+    const sideLines = this.renderSidebar(this.sidebarWidth);
+    const rows = Math.max(mainLines.length, sideLines.length);
+    const mainOffset = rows - mainLines.length;
+    const sideOffset = rows - sideLines.length;
+    const separator = theme.border(SEPARATOR);
+    const lines: string[] = [];
+    for (let row = 0; row < rows; row++) {
+      const left = padVisible(mainLines[row - mainOffset] ?? "", mainWidth);
+      lines.push(`${left}${separator}${sideLines[row - sideOffset] ?? ""}`);
+    }
+    return lines;
+  }
+
+  invalidate(): void {
+    this.main.invalidate();
+  }
+}
@@ -100,6 +100,14 @@ export type GatewayModelChoice = {
   reasoning?: boolean;
 };
 
+export type GatewayNodeSummary = {
+  nodeId: string;
+  displayName?: string;
+  platform?: string;
+  caps?: string[];
+  connected?: boolean;
+};
+
 export class GatewayChatClient {
   private client: GatewayClient;
   private readyPromise: Promise<void>;
@@ -224,6 +232,11 @@ export class GatewayChatClient {
     return await this.client.request("status");
   }
 
+  async listNodes(): Promise<GatewayNodeSummary[]> {
+    const res = await this.client.request<{ nodes?: GatewayNodeSummary[] }>("node.list", {});
+    return Array.isArray(res?.nodes) ? res.nodes : [];
+  }
+
   async listModels(): Promise<GatewayModelChoice[]> {
     const res = await this.client.request<{ models?: GatewayModelChoice[] }>("models.list");
     return Array.isArray(res?.models) ? res.models : [];
@@ -29,6 +29,7 @@ type EventHandlerContext = {
   isLocalRunId?: (runId: string) => boolean;
   forgetLocalRunId?: (runId: string) => void;
   clearLocalRunIds?: () => void;
+  onToolEvent?: (data: Record<string, unknown>) => void;
 };
 
 export function createEventHandlers(context: EventHandlerContext) {
@@ -42,6 +43,7 @@ export function createEventHandlers(context: EventHandlerContext) {
     isLocalRunId,
     forgetLocalRunId,
     clearLocalRunIds,
+    onToolEvent,
   } = context;
   const finalizedRuns = new Map<string, number>();
   const sessionRuns = new Map<string, number>();
@@ -246,6 +248,8 @@ export function createEventHandlers(context: EventHandlerContext) {
       return;
     }
     if (evt.stream === "tool") {
+      // The dashboard activity pane tracks tools regardless of the chat log's verbose level.
+      onToolEvent?.(evt.data ?? {});
       const verbose = state.sessionInfo.verboseLevel ?? "off";
       const allowToolEvents = verbose !== "off";
       const allowToolOutput = verbose === "full";
@@ -0,0 +1,35 @@
+import { describe, expect, it } from "vitest";
+import { createToolActivityTracker } from "./tui-tool-activity.js";
+
+describe("createToolActivityTracker", () => {
+  it("tracks tool calls from start to result", () => {
+    let now = 1_000;
+    const tracker = createToolActivityTracker({ now: () => now });
+    expect(
+      tracker.ingest({ phase: "start", toolCallId: "t1", name: "exec", args: { command: "ls" } }),
+    ).toBe(true);
+    expect(tracker.list()).toEqual([
+      { toolCallId: "t1", name: "exec", status: "running", detail: "ls", startedAt: 1_000 },
+    ]);
+
+    now = 2_500;
+    expect(tracker.ingest({ phase: "result", toolCallId: "t1", isError: true })).toBe(true);
+    expect(tracker.list()[0]).toMatchObject({ status: "error", endedAt: 2_500 });
+  });
+
+  it("ignores updates, unknown results, and events without a call id", () => {
+    const tracker = createToolActivityTracker();
+    expect(tracker.ingest({ phase: "update", toolCallId: "t1" })).toBe(false);
+    expect(tracker.ingest({ phase: "result", toolCallId: "missing" })).toBe(false);
+    expect(tracker.ingest({ phase: "start", name: "exec" })).toBe(false);
+    expect(tracker.list()).toEqual([]);
+  });
+
+  it("keeps the newest calls first within the limit", () => {
+    const tracker = createToolActivityTracker({ limit: 2 });
+    for (const id of ["a", "b", "c"]) {
+      tracker.ingest({ phase: "start", toolCallId: id, name: id });
+    }
+    expect(tracker.list().map((entry) => entry.toolCallId)).toEqual(["c", "b"]);
+  });
+});
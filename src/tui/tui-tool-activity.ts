@@ -0,0 +1,87 @@
+import { asString } from "./tui-formatters.js";
+
+export type ToolActivityStatus = "running" | "ok" | "error";
+
+export type ToolActivityEntry = {
+  toolCallId: string;
+  name: string;
+  status: ToolActivityStatus;
+  detail?: string;
+  startedAt: number;
+  endedAt?: number;
+};
+
+const DETAIL_ARG_KEYS = ["command", "query", "path", "file_path", "url", "action"];
+
+function summarizeToolArgs(args: unknown): string | undefined {
+  if (typeof args === "string") {
+    return args.trim() || undefined;
+  }
+  if (!args || typeof args !== "object") {
+    return undefined;
+  }
+  const record = args as Record<string, unknown>;
+  for (const key of DETAIL_ARG_KEYS) {
+    const value = record[key];
+    if (typeof value === "string" && value.trim()) {
+      return value.trim();
+    }
+  }
+  const first = Object.values(record).find(
+    (value): value is string => typeof value === "string" && value.trim().length > 0,
+  );
+  return first?.trim();
+}
+
+/**
+ * Keeps the most recent tool calls of the TUI session for the dashboard's activity pane.
+ * Fed with raw `agent` tool events; entries are returned newest first.
+ */
+export function createToolActivityTracker(params?: { limit?: number; now?: () => number }) {
+  const limit = Math.max(1, params?.limit ?? 12);
+  const now = params?.now ?? (() => Date.now());
+  const entries = new Map<string, ToolActivityEntry>();
+
+  return {
+    ingest(data: Record<string, unknown>): boolean {
+      const toolCallId = asString(data.toolCallId, "");
+      const phase = asString(data.phase, "");
+      if (!toolCallId) {
+        return false;
+      }
+      if (phase === "start") {
+        entries.delete(toolCallId);
+        entries.set(toolCallId, {
+          toolCallId,
+          name: asString(data.name, "tool"),
+          status: "running",
+          detail: summarizeToolArgs(data.args),
+          startedAt: now(),
+        });
+        while (entries.size > limit) {
+          const [oldest] = entries.keys();
+          if (oldest === undefined) {
+            break;
+          }
+          entries.delete(oldest);
+        }
+        return true;
+      }
+      if (phase === "result") {
+        const entry = entries.get(toolCallId);
+        if (!entry) {
+          return false;
+        }
+        entry.status = data.isError === true ? "error" : "ok";
+        entry.endedAt = now();
+        return true;
+      }
+      return false;
+    },
+    list(): ToolActivityEntry[] {
+      return [...entries.values()].toReversed();
+    },
+  };
+}
+
+export type ToolActivityTracker = ReturnType<typeof createToolActivityTracker>;
@@ -8,6 +8,8 @@ export type TuiOptions = {
   timeoutMs?: number;
   historyLimit?: number;
   message?: string;
+  /** Show the conversation next to a tool activity + nodes sidebar. */
+  dashboard?: boolean;
 };
 
 export type ChatEvent = {
@@ -19,7 +19,8 @@ import {
 import { getSlashCommands } from "./commands.js";
 import { ChatLog } from "./components/chat-log.js";
 import { CustomEditor } from "./components/custom-editor.js";
-import { GatewayChatClient } from "./gateway-chat.js";
+import { DashboardLayout, renderDashboardSidebar } from "./components/dashboard-layout.js";
+import { GatewayChatClient, type GatewayNodeSummary } from "./gateway-chat.js";
 import { editorTheme, theme } from "./theme/theme.js";
 import { createCommandHandlers } from "./tui-command-handlers.js";
 import { createEventHandlers } from "./tui-event-handlers.js";
@@ -27,6 +28,7 @@ import { formatTokens } from "./tui-formatters.js";
 import { createLocalShellRunner } from "./tui-local-shell.js";
 import { createOverlayHandlers } from "./tui-overlays.js";
 import { createSessionActions } from "./tui-session-actions.js";
+import { createToolActivityTracker } from "./tui-tool-activity.js";
 import type {
   AgentSummary,
   SessionInfo,
@@ -36,6 +38,8 @@ import type {
 } from "./tui-types.js";
 import { buildWaitingStatusMessage, defaultWaitingPhrases } from "./tui-waiting.js";
 
+const DASHBOARD_NODES_REFRESH_MS = 15_000;
+
 export { resolveFinalAssistantText } from "./tui-formatters.js";
 export type { TuiOptions } from "./tui-types.js";
 
@@ -467,9 +471,18 @@ export async function runTui(opts: TuiOptions) {
   const footer = new Text("", 1, 0);
   const chatLog = new ChatLog();
   const editor = new CustomEditor(tui, editorTheme);
+  const toolActivity = createToolActivityTracker();
+  let dashboardNodes: GatewayNodeSummary[] | null = null;
+  let dashboardTimer: NodeJS.Timeout | null = null;
   const root = new Container();
   root.addChild(header);
-  root.addChild(chatLog);
+  root.addChild(
+    opts.dashboard
+      ? new DashboardLayout(chatLog, (width) =>
+          renderDashboardSidebar({ width, tools: toolActivity.list(), nodes: dashboardNodes }),
+        )
+      : chatLog,
+  );
   root.addChild(statusContainer);
   root.addChild(footer);
   root.addChild(editor);
@@ -762,13 +775,33 @@ export async function runTui(opts: TuiOptions) {
     isLocalRunId,
     forgetLocalRunId,
     clearLocalRunIds,
+    onToolEvent: opts.dashboard
+      ? (data) => {
+          if (toolActivity.ingest(data)) {
+            tui.requestRender();
+          }
+        }
+      : undefined,
   });
 
+  const refreshDashboardNodes = async () => {
+    try {
+      dashboardNodes = await client.listNodes();
+    } catch {
+      // Keep the last known list; the connection status line reports gateway trouble.
+      dashboardNodes ??= [];
+    }
+    tui.requestRender();
+  };
+
   const requestExit = () => {
     if (exitRequested) {
       return;
     }
     exitRequested = true;
+    if (dashboardTimer) {
+      clearInterval(dashboardTimer);
+    }
     client.stop();
     tui.stop();
     process.exit(0);
@@ -893,6 +926,14 @@ export async function runTui(opts: TuiOptions) {
       updateFooter();
       tui.requestRender();
     })();
+    if (opts.dashboard) {
+      void refreshDashboardNodes();
+      dashboardTimer ??= setInterval(() => {
+        if (isConnected) {
+          void refreshDashboardNodes();
+        }
+      }, DASHBOARD_NODES_REFRESH_MS);
+    }
   };
 
   client.onDisconnected = (reason) => {
@@ -9,14 +9,70 @@ declare module "node-llama-cpp" {
     getEmbeddingFor: (text: string) => Promise<LlamaEmbedding>;
   };
 
+  export type LlamaContextSequence = {
+    tokenMeter: { usedInputTokens: number; usedOutputTokens: number };
+    dispose: () => void;
+  };
+
+  export type LlamaContext = {
+    getSequence: () => LlamaContextSequence;
+    dispose: () => Promise<void>;
+  };
+
   export type LlamaModel = {
     createEmbeddingContext: () => Promise<LlamaEmbeddingContext>;
+    createContext: (params?: {
+      contextSize?: number | "auto";
+      threads?: number;
+    }) => Promise<LlamaContext>;
+    dispose: () => Promise<void>;
   };
 
   export type Llama = {
-    loadModel: (params: { modelPath: string }) => Promise<LlamaModel>;
+    loadModel: (params: {
+      modelPath: string;
+      gpuLayers?: number | "auto" | "max";
+    }) => Promise<LlamaModel>;
+  };
+
+  export type ChatHistoryFunctionCall = {
+    type: "functionCall";
+    name: string;
+    params: unknown;
+    result: unknown;
   };
 
+  export type ChatHistoryItem =
+    | { type: "system"; text: string }
+    | { type: "user"; text: string }
+    | { type: "model"; response: Array<string | ChatHistoryFunctionCall> };
+
+  export type ChatModelFunctions = Record<
+    string,
+    { description?: string; params?: Record<string, unknown> }
+  >;
+
+  export class LlamaChat {
+    constructor(params: { contextSequence: LlamaContextSequence });
+    generateResponse(
+      history: ChatHistoryItem[],
+      options?: {
+        functions?: ChatModelFunctions;
+        maxTokens?: number;
+        temperature?: number;
+        topP?: number;
+        customStopTriggers?: string[];
+        signal?: AbortSignal;
+        stopOnAbortSignal?: boolean;
+      },
+    ): Promise<{
+      response: string;
+      functionCalls?: Array<{ functionName: string; params: unknown; raw: unknown }>;
+      metadata: { stopReason: string };
+    }>;
+    dispose(params?: { disposeSequence?: boolean }): void;
+  }
+
   export function getLlama(params: { logLevel: LlamaLogLevel }): Promise<Llama>;
   export function resolveModelFile(modelPath: string, cacheDir?: string): Promise<string>;
 }
@@ -1,6 +1,7 @@
 declare module "pdfjs-dist/legacy/build/pdf.mjs" {
   export type TextItem = {
     str: string;
+    hasEOL?: boolean;
   };
 
   export type TextMarkedContent = {
@@ -2,8 +2,10 @@ import { describe, expect, it } from "vitest";
 import type { OpenClawConfig } from "../config/config.js";
 import {
   estimateUsageCost,
+  formatCost,
   formatTokenCount,
   formatUsd,
+  resolveCostDisplayCurrency,
   resolveModelCostConfig,
 } from "./usage-format.js";
 
@@ -21,6 +23,34 @@ describe("usage-format", () => {
     expect(formatUsd(0.0042)).toBe("$0.0042");
   });
 
+  it("formats costs in a configured display currency", () => {
+    const config = {
+      cost: { currency: { code: "eur", usdRate: 0.5 } },
+    } as unknown as OpenClawConfig;
+    const eur = resolveCostDisplayCurrency({ config });
+    expect(eur).toEqual({ code: "EUR", usdRate: 0.5 });
+    expect(formatCost(3, eur)).toBe("€1.50");
+    expect(formatCost(0.01, eur)).toBe("€0.0050");
+    expect(formatCost(1.234)).toBe("$1.23");
+    expect(formatCost(undefined, eur)).toBeUndefined();
+  });
+
+  it("falls back to USD without a usable rate", () => {
+    const config = { cost: { currency: { code: "GBP", usdRate: 0.8 } } } as OpenClawConfig;
+    expect(resolveCostDisplayCurrency({ config, code: "JPY" })).toEqual({
+      code: "USD",
+      usdRate: 1,
+    });
+    expect(resolveCostDisplayCurrency({ config, code: "JPY", usdRate: 150 })).toEqual({
+      code: "JPY",
+      usdRate: 150,
+    });
+    expect(resolveCostDisplayCurrency({ config, usdRate: 0.75 })).toEqual({
+      code: "GBP",
+      usdRate: 0.75,
+    });
+  });
+
   it("resolves model cost config and estimates usage cost", () => {
     const config = {
       models: {
@@ -1,3 +1,4 @@
+import { getOpenRouterCatalogEntry } from "../agents/openrouter-catalog.js";
 import type { NormalizedUsage } from "../agents/usage.js";
 import type { OpenClawConfig } from "../config/config.js";
 
@@ -43,6 +44,58 @@ export function formatUsd(value?: number): string | undefined {
   return `$${value.toFixed(4)}`;
 }
 
+export type CostDisplayCurrency = {
+  code: string;
+  /** Units of `code` per 1 USD. */
+  usdRate: number;
+};
+
+export const USD_DISPLAY_CURRENCY: CostDisplayCurrency = { code: "USD", usdRate: 1 };
+
+/**
+ * Resolve the display currency from `cost.currency`, with optional CLI overrides.
+ * Falls back to USD when a non-USD code has no usable rate.
+ */
+export function resolveCostDisplayCurrency(params?: {
+  config?: OpenClawConfig;
+  code?: string;
+  usdRate?: number;
+}): CostDisplayCurrency {
+  const configured = params?.config?.cost?.currency;
+  const code = (params?.code ?? configured?.code)?.trim().toUpperCase();
+  if (!code || code === "USD") {
+    return USD_DISPLAY_CURRENCY;
+  }
+  const configuredCode = configured?.code?.trim().toUpperCase();
+  const usdRate = params?.usdRate ?? (code === configuredCode ? configured?.usdRate : undefined);
+  if (typeof usdRate !== "number" || !Number.isFinite(usdRate) || usdRate <= 0) {
+    return USD_DISPLAY_CURRENCY;
+  }
+  return { code, usdRate };
+}
+
+/** Format a canonical USD amount in the display currency. */
+export function formatCost(valueUsd?: number, currency?: CostDisplayCurrency): string | undefined {
+  if (!currency || currency.code === "USD") {
+    return formatUsd(valueUsd);
+  }
+  if (valueUsd === undefined || !Number.isFinite(valueUsd)) {
+    return undefined;
+  }
+  const value = valueUsd * currency.usdRate;
+  const digits = value >= 0.01 ? 2 : 4;
+  try {
+    return new Intl.NumberFormat("en-US", {
+      style: "currency",
+      currency: currency.code,
+      minimumFractionDigits: digits,
+      maximumFractionDigits: digits,
+    }).format(value);
+  } catch {
+    return `${value.toFixed(digits)} ${currency.code}`;
+  }
+}
+
 export function resolveModelCostConfig(params: {
   provider?: string;
   model?: string;
@@ -55,7 +108,18 @@ export function resolveModelCostConfig(params: {
   }
   const providers = params.config?.models?.providers ?? {};
   const entry = providers[provider]?.models?.find((item) => item.id === model);
-  return entry?.cost;
+  if (entry?.cost) {
+    return entry.cost;
+  }
+  // OpenRouter models are pass-through; price them from the startup catalog.
+  return provider.toLowerCase() === "openrouter"
+    ? getOpenRouterCatalogEntry(model)?.cost
+    : undefined;
+}
+
+/** True when every rate is zero, as for local runtimes (Ollama, vLLM, llama.cpp). */
+export function isZeroCostModel(cost?: ModelCostConfig): boolean {
+  return Boolean(cost && !cost.input && !cost.output && !cost.cacheRead && !cost.cacheWrite);
 }
 
 const toNumber = (value: number | undefined): number =>
@@ -191,6 +191,11 @@ describe("web inbound helpers", () => {
         audioMessage: {},
       } as unknown as import("@whiskeysockets/baileys").proto.IMessage),
     ).toBe("<media:audio>");
+    expect(
+      extractMediaPlaceholder({
+        audioMessage: { ptt: true },
+      } as unknown as import("@whiskeysockets/baileys").proto.IMessage),
+    ).toBe("<media:audio> (voice note)");
   });
 
   it("extracts WhatsApp location messages", () => {
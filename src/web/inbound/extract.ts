@@ -135,7 +135,7 @@ export function extractMediaPlaceholder(
     return "<media:video>";
   }
   if (message.audioMessage) {
-    return "<media:audio>";
+    return message.audioMessage.ptt ? "<media:audio> (voice note)" : "<media:audio>";
   }
   if (message.documentMessage) {
     return "<media:document>";